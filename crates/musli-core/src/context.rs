@@ -3,10 +3,51 @@
 use core::fmt;
 use core::str;
 
-use crate::de::{DecodeBytes, DecodeUnsized, DecodeUnsizedBytes};
+use crate::de::{Coercion, DecodeBytes, DecodeUnsized, DecodeUnsizedBytes};
 use crate::no_std;
 use crate::{Buf, Decode, Decoder};
 
+/// A checkpoint of the diagnostics collected by a context, as returned by
+/// [`Context::checkpoint`].
+///
+/// The counters are only interpreted by the context which produced the
+/// checkpoint. Contexts which don't collect diagnostics return the default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Checkpoint {
+    /// The mark of the context.
+    pub mark: usize,
+    /// The number of errors collected.
+    pub errors: usize,
+    /// The number of errors which were dropped.
+    pub dropped_errors: usize,
+    /// The length of the path being processed.
+    pub path: usize,
+}
+
+impl Checkpoint {
+    /// Construct a checkpoint from the mark, number of errors and path length
+    /// of a context.
+    #[inline]
+    pub const fn new(mark: usize, errors: usize, path: usize) -> Self {
+        Self {
+            mark,
+            errors,
+            dropped_errors: 0,
+            path,
+        }
+    }
+
+    /// Also record the number of errors which were dropped.
+    #[inline]
+    pub const fn with_dropped_errors(self, dropped_errors: usize) -> Self {
+        Self {
+            dropped_errors,
+            ..self
+        }
+    }
+}
+
 /// Provides ergonomic access to the serialization context.
 ///
 /// This is used to among other things report diagnostics.
@@ -155,6 +196,44 @@ pub trait Context {
         Self::Mark::default()
     }
 
+    /// Capture a checkpoint of the diagnostics collected by the context.
+    ///
+    /// This is used when decoding speculatively, like when each variant of an
+    /// untagged enum is tried in turn. If an attempt fails the context can be
+    /// rolled back using [`Context::restore`], discarding any errors reported
+    /// and any fields or variants entered while making it.
+    #[inline(always)]
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint::default()
+    }
+
+    /// Restore the diagnostics of the context to a checkpoint previously
+    /// returned by [`Context::checkpoint`].
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn restore(&self, checkpoint: Checkpoint) {}
+
+    /// Test if decoding should recover from errors where possible.
+    ///
+    /// When this returns `true`, fields in containers marked with
+    /// `#[musli(recover)]` which fail to decode have their errors recorded
+    /// and are replaced with their default value so that decoding can
+    /// continue.
+    #[inline(always)]
+    fn recover(&self) -> bool {
+        false
+    }
+
+    /// Indicate that decoding recovered from an error reported after the
+    /// given checkpoint.
+    ///
+    /// Unlike [`Context::restore`], this keeps any errors which were reported
+    /// and only unwinds the fields and variants which were entered while
+    /// making the failed attempt.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn recovered(&self, checkpoint: Checkpoint) {}
+
     /// Report that an invalid variant tag was encountered.
     #[inline(always)]
     fn invalid_variant_tag<T>(&self, _: &'static str, tag: &T) -> Self::Error
@@ -179,6 +258,14 @@ pub trait Context {
         self.message(format_args!("Cannot decode uninhabitable types"))
     }
 
+    /// Trying to encode a variant which is skipped through `#[musli(skip)]`.
+    #[inline(always)]
+    fn skipped_variant(&self, _: &'static str, variant: &'static str) -> Self::Error {
+        self.message(format_args!(
+            "Variant {variant} is skipped and cannot be encoded"
+        ))
+    }
+
     /// Encountered an unsupported field tag.
     #[inline(always)]
     fn invalid_field_tag<T>(&self, _: &'static str, tag: &T) -> Self::Error
@@ -222,6 +309,41 @@ pub trait Context {
         }
     }
 
+    /// Encountered a field tag which is not known by the type being decoded,
+    /// as reported by types using `#[musli(deny_unknown_fields)]`.
+    ///
+    /// The `mark` indicates where the field tag started.
+    #[inline(always)]
+    fn unknown_field_tag<T>(&self, _: &'static str, mark: Self::Mark, tag: &T) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.marked_message(mark, format_args!("Unknown field tag {tag:?}"))
+    }
+
+    /// Encountered a string field tag which is not known by the type being
+    /// decoded, as reported by types using `#[musli(deny_unknown_fields)]`.
+    ///
+    /// The `mark` indicates where the field tag started.
+    #[inline(always)]
+    fn unknown_field_string_tag(
+        &self,
+        _: &'static str,
+        mark: Self::Mark,
+        field: Self::Buf<'_>,
+    ) -> Self::Error {
+        // SAFETY: Getting the slice does not overlap any interleaving operations.
+        let bytes = field.as_slice();
+
+        // NB: The field has already been written using its debug
+        // representation.
+        if let Ok(string) = str::from_utf8(bytes) {
+            self.marked_message(mark, format_args!("Unknown field tag {string}"))
+        } else {
+            self.marked_message(mark, format_args!("Unknown field tag {bytes:?}"))
+        }
+    }
+
     /// Missing variant field required to decode.
     #[allow(unused_variables)]
     #[inline(always)]
@@ -264,6 +386,53 @@ pub trait Context {
         self.message("Failed to allocate")
     }
 
+    /// Check that a sequence of `len` elements is within the limits of the
+    /// context.
+    ///
+    /// This is called by collections before reserving space for the number of
+    /// elements they've been told to expect, and again as elements are
+    /// decoded.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn check_sequence_len(&self, len: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Check that a map of `len` entries is within the limits of the context.
+    ///
+    /// This is called by maps before reserving space for the number of entries
+    /// they've been told to expect, and again as entries are decoded.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn check_map_len(&self, len: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Check that a string or byte array of `len` bytes is within the limits of
+    /// the context before it is copied into an owned value.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn check_bytes_len(&self, len: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// Account for `n` sequence elements or map entries having been decoded,
+    /// erroring if this exceeds the total budget of the context.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn consume_elements(&self, n: usize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    /// The policy to use when decoding numbers into a numeric type which
+    /// doesn't exactly match the one they were encoded as.
+    ///
+    /// See [`Coercion`] for details.
+    #[inline(always)]
+    fn coercion(&self) -> Coercion {
+        Coercion::Widening
+    }
+
     /// Indicate that we've entered a struct with the given `name`.
     ///
     /// The `name` variable corresponds to the identifiers of the struct.
@@ -0,0 +1,74 @@
+/// The policy used when a number is decoded into a numeric type which doesn't
+/// exactly match how it was encoded.
+///
+/// The policy is provided by [`Context::coercion`] and consulted by the
+/// numeric decoding methods of formats which are self-describing, like
+/// `descriptive`, `json` and `value`. Formats which don't record the type of
+/// numbers, like `storage` and `wire`, can't tell the difference and are
+/// unaffected.
+///
+/// Regardless of the policy, a number which is out of range for the type it's
+/// being decoded into always results in an error.
+///
+/// [`Context::coercion`]: crate::Context::coercion
+///
+/// # Compatibility
+///
+/// Before this policy was introduced, the `value` format converted numbers
+/// with `as` casts. This is no longer the case under the default
+/// [`Coercion::Widening`] policy:
+///
+/// * Decoding an integer from a float is an error, where the float used to be
+///   truncated. [`Coercion::Lenient`] accepts floats without a fractional
+///   part.
+/// * Decoding a float from an integer which it can't represent exactly is an
+///   error, where it used to be rounded to the nearest float.
+///
+/// To keep the old behavior, decode the number as the type it was encoded as
+/// and convert it with `as`.
+///
+/// # Examples
+///
+/// ```
+/// use musli::de::Coercion;
+///
+/// assert_eq!(Coercion::default(), Coercion::Widening);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coercion {
+    /// Only decode numbers into the exact type they were encoded as.
+    ///
+    /// * In `descriptive` a `u8` can only be decoded from a number tagged as a
+    ///   `u8`, and a float only from a float of the same width.
+    /// * In `value` the variant of [`Number`] must match the type being
+    ///   decoded.
+    /// * JSON only has one type of number, so integers must be written as
+    ///   plain integers without a fraction or an exponent. Any number can be
+    ///   decoded as a float.
+    ///
+    /// [`Number`]: https://docs.rs/musli/latest/musli/value/enum.Number.html
+    Strict,
+    /// Convert numbers between types as long as no information is lost. This
+    /// is the default.
+    ///
+    /// * Integers are converted between any width and signedness as long as
+    ///   they are in range of the type being decoded, so a `u8` can be decoded
+    ///   as a `u64`, and a `u64` of `42` can be decoded as an `i8`.
+    /// * Integers are converted into floats if the float can represent them
+    ///   exactly.
+    /// * Floats are converted between widths if the value can be represented
+    ///   exactly.
+    /// * JSON numbers are parsed according to the `parse-full` feature.
+    #[default]
+    Widening,
+    /// In addition to [`Coercion::Widening`], accept numbers in forms commonly
+    /// produced by loosely typed producers.
+    ///
+    /// * Floats without a fractional part, like `1.0`, are converted into
+    ///   integers.
+    /// * Strings containing numbers, like `"42"`, are parsed in `json` and
+    ///   `value`.
+    /// * JSON integers may use the full number syntax, like `1e3`, even when
+    ///   the `parse-full` feature is not enabled.
+    Lenient,
+}
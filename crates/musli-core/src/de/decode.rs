@@ -42,4 +42,23 @@ pub trait Decode<'de, M>: Sized {
     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
         D: Decoder<'de, Mode = M>;
+
+    /// Decode the given input into an existing value, reusing any allocations
+    /// it holds where possible.
+    ///
+    /// By default this decodes a new value which replaces the existing one.
+    /// Strings and collections instead clear themselves and are refilled, and
+    /// the [`Decode` derive] decodes each field of a struct in place.
+    ///
+    /// If decoding fails, the value might be left partially decoded.
+    ///
+    /// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+    #[inline]
+    fn decode_in_place<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        *place = Self::decode(cx, decoder)?;
+        Ok(())
+    }
 }
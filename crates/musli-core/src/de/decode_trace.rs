@@ -6,6 +6,11 @@ use super::Decoder;
 /// container such as a [`HashMap<K, V>`] where `K` would have to implement
 /// [`fmt::Display`].
 ///
+/// Types which can't contain a map, and types using the [`Decode` derive], forward
+/// this to their [`Decode`] implementation.
+///
+/// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+/// [`Decode`]: crate::Decode
 /// [`HashMap<K, V>`]: std::collections::HashMap
 /// [`fmt::Display`]: std::fmt::Display
 pub trait DecodeTrace<'de, M>: Sized {
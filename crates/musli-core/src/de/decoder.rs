@@ -1,6 +1,7 @@
 #![allow(unused_variables)]
 
 use core::fmt;
+use core::ops::ControlFlow;
 
 use crate::expecting::{self, Expecting};
 use crate::hint::{MapHint, SequenceHint};
@@ -205,6 +206,16 @@ pub trait Decoder<'de>: Sized {
         )))
     }
 
+    /// Test if the current decoder can be buffered through
+    /// [`Decoder::decode_buffer`].
+    ///
+    /// This allows a type to only buffer in formats which support it, and
+    /// otherwise decode values in the order in which they were encoded.
+    #[inline]
+    fn is_bufferable(&self) -> bool {
+        false
+    }
+
     /// Decode a unit.
     ///
     /// # Examples
@@ -1293,6 +1304,61 @@ pub trait Decoder<'de>: Sized {
         )))
     }
 
+    /// Decode a sequence one element at a time, passing each decoded element
+    /// to the provided callback without collecting them.
+    ///
+    /// The callback can stop decoding early by returning
+    /// [`ControlFlow::Break`], in which case the value it breaks with is
+    /// returned. Since not all formats support skipping, the remaining
+    /// elements of the sequence are still decoded and discarded so that the
+    /// decoder is left positioned after the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    ///
+    /// use musli::{Decode, Decoder};
+    ///
+    /// struct Sum(u64);
+    ///
+    /// impl<'de, M> Decode<'de, M> for Sum {
+    ///     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de, Mode = M>,
+    ///     {
+    ///         let mut sum = 0;
+    ///
+    ///         decoder.decode_sequence_each(|value: u32| {
+    ///             sum += u64::from(value);
+    ///             ControlFlow::<()>::Continue(())
+    ///         })?;
+    ///
+    ///         Ok(Self(sum))
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn decode_sequence_each<T, F, B>(self, mut f: F) -> Result<ControlFlow<B>, Self::Error>
+    where
+        T: Decode<'de, Self::Mode>,
+        F: FnMut(T) -> ControlFlow<B>,
+    {
+        self.decode_sequence(|seq| {
+            let mut output = ControlFlow::Continue(());
+
+            while let Some(value) = seq.try_decode_next()? {
+                let value = value.decode::<T>()?;
+
+                if output.is_continue() {
+                    output = f(value);
+                }
+            }
+
+            Ok(output)
+        })
+    }
+
     /// Decode a map who's size is not known at compile time.
     ///
     /// # Examples
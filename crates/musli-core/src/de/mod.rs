@@ -27,6 +27,9 @@ pub use musli_macros::Decode;
 mod as_decoder;
 pub use self::as_decoder::AsDecoder;
 
+mod coercion;
+pub use self::coercion::Coercion;
+
 mod decode;
 pub use self::decode::Decode;
 
@@ -77,7 +80,35 @@ pub use self::visitor::Visitor;
 
 /// Decode to an owned value.
 ///
-/// This is a simpler bound to use than `for<'de> Decode<'de, M>`.
+/// This is a simpler bound to use than `for<'de> Decode<'de, M>`, and is
+/// implemented for every type which doesn't borrow from the input it's decoded
+/// from. It's the equivalent of serde's `DeserializeOwned`.
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, DecodeOwned, Encode};
+/// use musli::mode::Binary;
+/// use musli::storage::Error;
+///
+/// #[derive(Debug, PartialEq, Encode, Decode)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// fn load<T>(bytes: &[u8]) -> Result<T, Error>
+/// where
+///     T: DecodeOwned<Binary>,
+/// {
+///     musli::storage::from_slice(bytes)
+/// }
+///
+/// let person = Person { name: String::from("Aristotle"), age: 61 };
+/// let bytes = musli::storage::to_vec(&person)?;
+/// assert_eq!(load::<Person>(&bytes)?, person);
+/// # Ok::<_, Error>(())
+/// ```
 pub trait DecodeOwned<M>: for<'de> Decode<'de, M> {}
 
 impl<M, D> DecodeOwned<M> for D where D: for<'de> Decode<'de, M> {}
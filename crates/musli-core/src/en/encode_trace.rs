@@ -6,6 +6,11 @@ use super::Encoder;
 /// container such as a [`HashMap<K, V>`] where `K` would have to implement
 /// [`fmt::Display`].
 ///
+/// Types which can't contain a map, and types using the [`Encode` derive], forward
+/// this to their [`Encode`] implementation.
+///
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+/// [`Encode`]: crate::Encode
 /// [`HashMap<K, V>`]: std::collections::HashMap
 /// [`fmt::Display`]: std::fmt::Display
 pub trait EncodeTrace<M> {
@@ -949,6 +949,41 @@ pub trait Encoder: Sized {
         )))
     }
 
+    /// Encode a value that implements [`Display`] as a string.
+    ///
+    /// This forwards to [`Encoder::collect_string`], which formats can override
+    /// to write the value as it's being rendered.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    ///
+    /// use musli::{Encode, Encoder};
+    ///
+    /// struct Host {
+    ///     addr: Ipv4Addr,
+    /// }
+    ///
+    /// impl<M> Encode<M> for Host {
+    ///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder,
+    ///     {
+    ///         encoder.encode_display(&self.addr)
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_display<T>(self, value: &T) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.collect_string(value)
+    }
+
     /// Encode an optional value that is present.
     ///
     /// # Examples
@@ -1352,6 +1387,24 @@ pub trait Encoder: Sized {
         map.finish_map()
     }
 
+    /// Encode a map, handing the map encoder to `f` which is responsible for
+    /// finishing it.
+    ///
+    /// This is used to implement `#[musli(flatten)]` and is not intended to be
+    /// called directly.
+    #[doc(hidden)]
+    #[inline]
+    fn __encode_map_then<F>(
+        self,
+        hint: &MapHint,
+        f: F,
+    ) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    where
+        F: FnOnce(Self::EncodeMap) -> Result<Self::Ok, <Self::Cx as Context>::Error>,
+    {
+        f(self.encode_map(hint)?)
+    }
+
     /// Encode a map through pairs with a known length `len`.
     ///
     /// # Examples
@@ -0,0 +1,199 @@
+use core::cell::Cell;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::hint::SequenceHint;
+use crate::Context;
+
+use super::{Encode, Encoder, SequenceEncoder};
+
+/// An adapter which encodes the elements produced by an iterator as a
+/// sequence, without collecting them first.
+///
+/// All formats require the length of a sequence to be known before it is
+/// encoded. The length used is determined like this:
+/// * If a length was specified through [`IterEncoder::with_len`], it is used
+///   and the elements are streamed directly into the encoder. Encoding fails
+///   if the iterator produces a different number of elements.
+/// * If the iterator reports an exact [`Iterator::size_hint`], such as for
+///   [`ExactSizeIterator`]s, the elements are streamed directly into the
+///   encoder the same way.
+/// * Otherwise the elements are buffered into a `Vec` before being encoded,
+///   which requires the `alloc` feature. Without it encoding fails.
+///
+/// Per format this means:
+/// * `storage` and `wire` write the length as a prefix before the elements.
+/// * `descriptive` writes the length as part of the sequence type tag.
+/// * `json` doesn't use the length, but since the adapter can't tell which
+///   format it is being encoded with an iterator of unknown length is still
+///   buffered.
+///
+/// Since [`Encode`] only has access to the adapter by reference, the iterator
+/// is consumed the first time the adapter is encoded. Encoding it again
+/// results in an error.
+///
+/// # Examples
+///
+/// ```
+/// use musli::en::IterEncoder;
+/// use musli::storage::Encoding;
+/// # use musli::storage::Error;
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// let bytes = ENCODING.to_vec(&IterEncoder::new((0..4u32).map(|n| n * 2)))?;
+/// let values: Vec<u32> = ENCODING.from_slice(&bytes)?;
+/// assert_eq!(values, [0, 2, 4, 6]);
+/// # Ok::<_, Error>(())
+/// ```
+pub struct IterEncoder<I> {
+    iter: Cell<Option<I>>,
+    len: Option<usize>,
+}
+
+impl<I> IterEncoder<I>
+where
+    I: Iterator,
+{
+    /// Construct a new adapter over the given iterator.
+    ///
+    /// The length of the sequence is taken from [`Iterator::size_hint`] if it
+    /// is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::en::IterEncoder;
+    ///
+    /// let exact = IterEncoder::new([1, 2, 3].into_iter());
+    /// assert_eq!(exact.known_len(), Some(3));
+    ///
+    /// let unknown = IterEncoder::new((0..10).filter(|n| n % 3 == 0));
+    /// assert_eq!(unknown.known_len(), None);
+    /// ```
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        let len = match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        };
+
+        Self {
+            iter: Cell::new(Some(iter)),
+            len,
+        }
+    }
+
+    /// Construct a new adapter over the given iterator which is known to
+    /// produce exactly `len` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::en::IterEncoder;
+    ///
+    /// let iter = IterEncoder::with_len((0..10).filter(|n| n % 3 == 0), 4);
+    /// assert_eq!(iter.known_len(), Some(4));
+    /// ```
+    #[inline]
+    pub fn with_len(iter: I, len: usize) -> Self {
+        Self {
+            iter: Cell::new(Some(iter)),
+            len: Some(len),
+        }
+    }
+
+    /// Get the length of the sequence if it is known up front.
+    #[inline]
+    pub fn known_len(&self) -> Option<usize> {
+        self.len
+    }
+}
+
+impl<I> fmt::Debug for IterEncoder<I> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterEncoder")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, M> Encode<M> for IterEncoder<I>
+where
+    I: Iterator,
+    I::Item: Encode<M>,
+{
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let Some(iter) = self.iter.take() else {
+            return Err(cx.message("Iterator has already been encoded"));
+        };
+
+        let Some(len) = self.len else {
+            return encode_buffered(cx, encoder, iter);
+        };
+
+        let hint = SequenceHint::with_size(len);
+
+        encoder.encode_sequence_fn(&hint, |seq| {
+            let mut index = 0;
+
+            for value in iter {
+                if index == len {
+                    return Err(cx.message(format_args!(
+                        "Iterator produced more than the expected {len} elements"
+                    )));
+                }
+
+                cx.enter_sequence_index(index);
+                seq.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            if index != len {
+                return Err(cx.message(format_args!(
+                    "Iterator produced {index} elements, but expected {len}"
+                )));
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn encode_buffered<E, I>(cx: &E::Cx, encoder: E, iter: I) -> Result<E::Ok, E::Error>
+where
+    E: Encoder,
+    I: Iterator,
+    I::Item: Encode<E::Mode>,
+{
+    let values = iter.collect::<Vec<_>>();
+    let hint = SequenceHint::with_size(values.len());
+
+    encoder.encode_sequence_fn(&hint, |seq| {
+        for (index, value) in values.into_iter().enumerate() {
+            cx.enter_sequence_index(index);
+            seq.push(value)?;
+            cx.leave_sequence_index();
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(not(feature = "alloc"))]
+fn encode_buffered<E, I>(cx: &E::Cx, _: E, _: I) -> Result<E::Ok, E::Error>
+where
+    E: Encoder,
+    I: Iterator,
+    I::Item: Encode<E::Mode>,
+{
+    Err(cx.message("Encoding an iterator of unknown length requires the alloc feature"))
+}
@@ -45,9 +45,15 @@ pub use self::entries_encoder::EntriesEncoder;
 mod entry_encoder;
 pub use self::entry_encoder::EntryEncoder;
 
+mod iter_encoder;
+pub use self::iter_encoder::IterEncoder;
+
 mod map_encoder;
 pub use self::map_encoder::MapEncoder;
 
+mod packed_size;
+pub use self::packed_size::PackedSize;
+
 mod sequence_encoder;
 pub use self::sequence_encoder::SequenceEncoder;
 
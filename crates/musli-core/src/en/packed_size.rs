@@ -0,0 +1,77 @@
+/// Trait for types which are always encoded into the same number of bytes.
+///
+/// The sizes reported by this trait apply to the `storage` format configured
+/// to use fixed-width integers through [`Integer::Fixed`], in the default
+/// `Binary` mode which is the only mode it is derived for. Other formats and
+/// configurations add metadata to values, or encode numbers with a variable
+/// width.
+///
+/// This is typically implemented through the `#[musli(packed_size)]`
+/// attribute on a packed struct using the [`Encode` derive], which sums up the
+/// sizes of its fields. If any field has a variable size, like a `String` or
+/// an [`Option`], the size of the struct is `None`.
+///
+/// Since the size is a constant, it can be verified at compile time. To decode
+/// values while checking that exactly the expected number of bytes are used,
+/// see `Encoding::from_packed_slice` in the `storage` module of [`musli`].
+///
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+/// [`Integer::Fixed`]: https://docs.rs/musli/latest/musli/options/enum.Integer.html
+/// [`musli`]: https://docs.rs/musli
+///
+/// # Examples
+///
+/// ```
+/// use musli::Encode;
+/// use musli::en::PackedSize;
+///
+/// #[derive(Encode)]
+/// #[musli(packed, packed_size)]
+/// struct Record {
+///     id: u32,
+///     flags: u8,
+///     #[musli(packed)]
+///     position: [f32; 3],
+/// }
+///
+/// const _: () = assert!(matches!(Record::PACKED_SIZE, Some(17)));
+///
+/// #[derive(Encode)]
+/// #[musli(packed, packed_size)]
+/// struct Named {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// assert_eq!(Named::PACKED_SIZE, None);
+/// ```
+pub trait PackedSize {
+    /// The number of bytes the value is encoded into by [`Encode`], or `None`
+    /// if it varies.
+    ///
+    /// [`Encode`]: crate::Encode
+    const PACKED_SIZE: Option<usize>;
+
+    /// The number of bytes the value is encoded into by [`EncodePacked`],
+    /// which is used for fields marked with `#[musli(packed)]`, or `None` if
+    /// it varies or the type isn't packed differently.
+    ///
+    /// [`EncodePacked`]: crate::en::EncodePacked
+    const PACKED_FIELD_SIZE: Option<usize> = None;
+}
+
+impl<T> PackedSize for &T
+where
+    T: ?Sized + PackedSize,
+{
+    const PACKED_SIZE: Option<usize> = T::PACKED_SIZE;
+    const PACKED_FIELD_SIZE: Option<usize> = T::PACKED_FIELD_SIZE;
+}
+
+impl<T> PackedSize for &mut T
+where
+    T: ?Sized + PackedSize,
+{
+    const PACKED_SIZE: Option<usize> = T::PACKED_SIZE;
+    const PACKED_FIELD_SIZE: Option<usize> = T::PACKED_FIELD_SIZE;
+}
@@ -0,0 +1,637 @@
+//! Encoders and decoders used to implement `#[musli(flatten)]`.
+//!
+//! This is a private module of musli, and is not intended for use outside of
+//! the derives.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::de::{AsDecoder, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SizeHint};
+use crate::en::{Encode, Encoder, MapEncoder};
+use crate::hint::MapHint;
+use crate::Context;
+
+/// Encoder which determines how many entries a flattened value contributes to
+/// the map it is being flattened into.
+///
+/// This relies on the size hint provided when encoding the map, the closure
+/// which would have encoded the entries is never called. This also covers
+/// values which themselves contain flattened fields.
+pub struct FlattenLen<'a, C: ?Sized> {
+    cx: &'a C,
+}
+
+impl<'a, C: ?Sized> FlattenLen<'a, C> {
+    /// Construct a new length encoder.
+    #[inline]
+    pub fn new(cx: &'a C) -> Self {
+        Self { cx }
+    }
+}
+
+#[crate::encoder(crate)]
+impl<'a, C> Encoder for FlattenLen<'a, C>
+where
+    C: ?Sized + Context,
+{
+    type Cx = C;
+    type Ok = usize;
+
+    #[inline]
+    fn cx(&self) -> &C {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "map to flatten")
+    }
+
+    #[inline]
+    fn encode<T>(self, value: T) -> Result<Self::Ok, C::Error>
+    where
+        T: Encode<Self::Mode>,
+    {
+        value.encode(self.cx, self)
+    }
+
+    #[inline]
+    fn encode_map_fn<F>(self, hint: &MapHint, _: F) -> Result<Self::Ok, C::Error>
+    where
+        F: FnOnce(&mut Self::EncodeMap) -> Result<(), C::Error>,
+    {
+        Ok(hint.size)
+    }
+
+    #[inline]
+    fn __encode_map_then<F>(self, hint: &MapHint, _: F) -> Result<Self::Ok, C::Error>
+    where
+        F: FnOnce(Self::EncodeMap) -> Result<Self::Ok, C::Error>,
+    {
+        Ok(hint.size)
+    }
+}
+
+/// Encoder which inserts the entries of a map directly into the map `M` of
+/// the containing value.
+///
+/// Once the flattened value finishes its map, the containing map is handed to
+/// `F` which encodes any remaining entries and finishes it.
+pub struct FlattenEncoder<'a, C: ?Sized, M, F> {
+    cx: &'a C,
+    map: M,
+    then: F,
+}
+
+impl<'a, C: ?Sized, M, F> FlattenEncoder<'a, C, M, F> {
+    /// Construct a new flattening encoder.
+    #[inline]
+    pub fn new(cx: &'a C, map: M, then: F) -> Self {
+        Self { cx, map, then }
+    }
+}
+
+#[crate::encoder(crate)]
+impl<'a, C, M, F> Encoder for FlattenEncoder<'a, C, M, F>
+where
+    C: ?Sized + Context,
+    M: MapEncoder<Cx = C>,
+    F: FnOnce(M) -> Result<M::Ok, C::Error>,
+{
+    type Cx = C;
+    type Ok = M::Ok;
+    type EncodeMap = FlattenMapEncoder<M, F>;
+
+    #[inline]
+    fn cx(&self) -> &C {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "map to flatten")
+    }
+
+    #[inline]
+    fn encode<T>(self, value: T) -> Result<Self::Ok, C::Error>
+    where
+        T: Encode<Self::Mode>,
+    {
+        value.encode(self.cx, self)
+    }
+
+    #[inline]
+    fn encode_map(self, _: &MapHint) -> Result<Self::EncodeMap, C::Error> {
+        Ok(FlattenMapEncoder {
+            map: self.map,
+            then: self.then,
+        })
+    }
+}
+
+/// Map encoder returned by [`FlattenEncoder`].
+pub struct FlattenMapEncoder<M, F> {
+    map: M,
+    then: F,
+}
+
+impl<M, F> MapEncoder for FlattenMapEncoder<M, F>
+where
+    M: MapEncoder,
+    F: FnOnce(M) -> Result<M::Ok, <M::Cx as Context>::Error>,
+{
+    type Cx = M::Cx;
+    type Ok = M::Ok;
+    type EncodeEntry<'this> = M::EncodeEntry<'this> where Self: 'this;
+
+    #[inline]
+    fn encode_entry(&mut self) -> Result<Self::EncodeEntry<'_>, <M::Cx as Context>::Error> {
+        self.map.encode_entry()
+    }
+
+    #[inline]
+    fn finish_map(self) -> Result<Self::Ok, <M::Cx as Context>::Error> {
+        (self.then)(self.map)
+    }
+}
+
+/// The names of the fields of a type which is encoded as a map, used to reject
+/// names which are shared with flattened fields at compile time.
+#[derive(Clone, Copy)]
+pub struct Names {
+    /// The name of the type the names belong to.
+    pub ty: &'static str,
+    /// The names of fields which are not flattened and known at compile time.
+    pub names: &'static [Name],
+    /// The names of flattened fields.
+    pub flattened: &'static [Names],
+}
+
+impl Names {
+    /// Panic if any name is used by more than one field, including the fields
+    /// of flattened fields.
+    pub const fn check(&self) {
+        self.check_against(self);
+    }
+
+    const fn check_against(&self, root: &Names) {
+        let mut index = 0;
+
+        while index < self.names.len() {
+            if root.count(&self.names[index]) > 1 {
+                duplicate(root.ty, &self.names[index]);
+            }
+
+            index += 1;
+        }
+
+        let mut index = 0;
+
+        while index < self.flattened.len() {
+            self.flattened[index].check_against(root);
+            index += 1;
+        }
+    }
+
+    const fn count(&self, name: &Name) -> usize {
+        let mut count = 0;
+        let mut index = 0;
+
+        while index < self.names.len() {
+            if self.names[index].eq(name) {
+                count += 1;
+            }
+
+            index += 1;
+        }
+
+        let mut index = 0;
+
+        while index < self.flattened.len() {
+            count += self.flattened[index].count(name);
+            index += 1;
+        }
+
+        count
+    }
+}
+
+/// A field name which is known at compile time.
+#[derive(Clone, Copy)]
+pub enum Name {
+    /// A string name.
+    String(&'static str),
+    /// An index.
+    Index(u128),
+}
+
+impl Name {
+    const fn eq(&self, other: &Name) -> bool {
+        match (self, other) {
+            (Name::String(a), Name::String(b)) => {
+                let (a, b) = (a.as_bytes(), b.as_bytes());
+
+                if a.len() != b.len() {
+                    return false;
+                }
+
+                let mut index = 0;
+
+                while index < a.len() {
+                    if a[index] != b[index] {
+                        return false;
+                    }
+
+                    index += 1;
+                }
+
+                true
+            }
+            (Name::Index(a), Name::Index(b)) => *a == *b,
+            _ => false,
+        }
+    }
+}
+
+/// Panic with a message describing a name which is used by more than one
+/// field.
+const fn duplicate(ty: &str, name: &Name) -> ! {
+    let message = Message::new()
+        .push("`")
+        .push(ty)
+        .push("` has more than one field named `");
+
+    let message = match name {
+        Name::String(name) => message.push(name),
+        Name::Index(index) => message.push_index(*index),
+    };
+
+    let message = message.push("`, which is not supported with #[musli(flatten)]");
+
+    match core::str::from_utf8(message.as_bytes()) {
+        Ok(message) => panic!("{}", message),
+        Err(..) => panic!("More than one field has the same name"),
+    }
+}
+
+/// A fixed capacity message which can be built at compile time.
+struct Message {
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl Message {
+    const fn new() -> Self {
+        Self {
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+
+    /// Push a string, which is left out entirely if it doesn't fit.
+    const fn push(mut self, string: &str) -> Self {
+        let bytes = string.as_bytes();
+
+        if bytes.len() > self.buf.len() - self.len {
+            return self;
+        }
+
+        let mut index = 0;
+
+        while index < bytes.len() {
+            self.buf[self.len] = bytes[index];
+            self.len += 1;
+            index += 1;
+        }
+
+        self
+    }
+
+    const fn push_index(mut self, mut index: u128) -> Self {
+        let mut digits = [0u8; 39];
+        let mut len = 0;
+
+        loop {
+            digits[digits.len() - 1 - len] = b'0' + (index % 10) as u8;
+            len += 1;
+            index /= 10;
+
+            if index == 0 {
+                break;
+            }
+        }
+
+        if len > self.buf.len() - self.len {
+            return self;
+        }
+
+        while len > 0 {
+            self.buf[self.len] = digits[digits.len() - len];
+            self.len += 1;
+            len -= 1;
+        }
+
+        self
+    }
+
+    const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+}
+
+/// Names of the fields of a type which can be flattened when encoding.
+pub trait EncodeNames<M> {
+    /// The names of the fields.
+    const NAMES: Names;
+
+    /// Fails to evaluate if a name is shared with a flattened field.
+    const CHECK: () = Self::NAMES.check();
+}
+
+/// Names of the fields of a type which can be flattened when decoding.
+pub trait DecodeNames<M> {
+    /// The names of the fields.
+    const NAMES: Names;
+
+    /// Fails to evaluate if a name is shared with a flattened field.
+    const CHECK: () = Self::NAMES.check();
+
+    /// Test if the key being decoded names a field of the type which is not
+    /// flattened.
+    fn is_known<'de, D>(cx: &D::Cx, decoder: D) -> Result<bool, <D::Cx as Context>::Error>
+    where
+        D: Decoder<'de, Mode = M>;
+}
+
+/// Decoder which exposes the entries of a buffered map which are not known to
+/// the container `N`, so that they can be decoded by one of its flattened
+/// fields.
+pub struct FlattenDecoder<'a, C: ?Sized, B, N> {
+    cx: &'a C,
+    buffer: &'a B,
+    _marker: PhantomData<N>,
+}
+
+impl<'a, C: ?Sized, B, N> FlattenDecoder<'a, C, B, N> {
+    /// Construct a new flattening decoder.
+    #[inline]
+    pub fn new(cx: &'a C, buffer: &'a B) -> Self {
+        Self {
+            cx,
+            buffer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[crate::decoder(crate)]
+impl<'de, C, B, N> Decoder<'de> for FlattenDecoder<'de, C, B, N>
+where
+    C: ?Sized + Context,
+    B: AsDecoder<Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    type Cx = C;
+    type DecodeBuffer = Self;
+    type DecodeMap = FlattenMapDecoder<'de, C, FlattenEntries<'de, B>, N>;
+    type DecodeMapHint = FlattenMapDecoder<'de, C, FlattenEntries<'de, B>, N>;
+    type DecodeMapEntries = FlattenMapDecoder<'de, C, FlattenEntries<'de, B>, N>;
+
+    #[inline]
+    fn cx(&self) -> &C {
+        self.cx
+    }
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "flattened map")
+    }
+
+    #[inline]
+    fn skip(self) -> Result<(), C::Error> {
+        Ok(())
+    }
+
+    #[inline]
+    fn is_bufferable(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
+        Ok(self)
+    }
+
+    #[inline]
+    fn decode_map<F, O>(self, f: F) -> Result<O, C::Error>
+    where
+        F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
+    {
+        let mut map = self.decode_map_entries()?;
+        let output = f(&mut map)?;
+        map.end_entries()?;
+        Ok(output)
+    }
+
+    #[inline]
+    fn decode_map_hint<F, O>(self, _: &MapHint, f: F) -> Result<O, C::Error>
+    where
+        F: FnOnce(&mut Self::DecodeMapHint) -> Result<O, C::Error>,
+    {
+        self.decode_map(f)
+    }
+
+    #[inline]
+    fn decode_map_entries(self) -> Result<Self::DecodeMapEntries, C::Error> {
+        Ok(FlattenMapDecoder {
+            cx: self.cx,
+            probe: self.buffer.as_decoder()?.decode_map_entries()?,
+            entries: self.buffer.as_decoder()?.decode_map_entries()?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, C, B, N> AsDecoder for FlattenDecoder<'a, C, B, N>
+where
+    C: ?Sized + Context,
+    B: AsDecoder<Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    type Cx = C;
+    type Decoder<'this> = FlattenDecoder<'this, C, B, N> where Self: 'this;
+
+    #[inline]
+    fn as_decoder(&self) -> Result<Self::Decoder<'_>, C::Error> {
+        Ok(FlattenDecoder::new(self.cx, self.buffer))
+    }
+}
+
+/// The entries of the buffer `B` which is being flattened.
+type FlattenEntries<'a, B> = <<B as AsDecoder>::Decoder<'a> as Decoder<'a>>::DecodeMapEntries;
+
+/// Map decoder returned by [`FlattenDecoder`].
+///
+/// Every entry is first inspected through `probe`, entries which are known to
+/// the container `N` are then skipped over in `entries`.
+pub struct FlattenMapDecoder<'a, C: ?Sized, E, N> {
+    cx: &'a C,
+    probe: E,
+    entries: E,
+    _marker: PhantomData<N>,
+}
+
+impl<'a, 'de, C, E, N> FlattenMapDecoder<'a, C, E, N>
+where
+    C: ?Sized + Context,
+    E: EntriesDecoder<'de, Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    /// Skip over entries which are known to the container, returning `false`
+    /// once there are no more entries.
+    fn advance(&mut self) -> Result<bool, C::Error> {
+        loop {
+            let Some(key) = self.probe.decode_entry_key()? else {
+                return Ok(false);
+            };
+
+            let known = N::is_known(self.cx, key)?;
+            self.probe.decode_entry_value()?.skip()?;
+
+            if !known {
+                return Ok(true);
+            }
+
+            let Some(key) = self.entries.decode_entry_key()? else {
+                return Ok(false);
+            };
+
+            key.skip()?;
+            self.entries.decode_entry_value()?.skip()?;
+        }
+    }
+}
+
+impl<'a, 'de, C, E, N> MapDecoder<'de> for FlattenMapDecoder<'a, C, E, N>
+where
+    C: ?Sized + Context,
+    E: EntriesDecoder<'de, Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    type Cx = C;
+    type DecodeEntry<'this> = FlattenEntryDecoder<'this, C, E> where Self: 'this;
+    type DecodeRemainingEntries<'this> = FlattenRemainingEntries<'this, 'a, C, E, N> where Self: 'this;
+
+    #[inline]
+    fn decode_entry(&mut self) -> Result<Option<Self::DecodeEntry<'_>>, C::Error> {
+        if !self.advance()? {
+            return Ok(None);
+        }
+
+        Ok(Some(FlattenEntryDecoder {
+            cx: self.cx,
+            entries: &mut self.entries,
+        }))
+    }
+
+    #[inline]
+    fn decode_remaining_entries(&mut self) -> Result<Self::DecodeRemainingEntries<'_>, C::Error> {
+        Ok(FlattenRemainingEntries { map: self })
+    }
+}
+
+impl<'a, 'de, C, E, N> EntriesDecoder<'de> for FlattenMapDecoder<'a, C, E, N>
+where
+    C: ?Sized + Context,
+    E: EntriesDecoder<'de, Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    type Cx = C;
+    type DecodeEntryKey<'this> = E::DecodeEntryKey<'this> where Self: 'this;
+    type DecodeEntryValue<'this> = E::DecodeEntryValue<'this> where Self: 'this;
+
+    #[inline]
+    fn decode_entry_key(&mut self) -> Result<Option<Self::DecodeEntryKey<'_>>, C::Error> {
+        if !self.advance()? {
+            return Ok(None);
+        }
+
+        self.entries.decode_entry_key()
+    }
+
+    #[inline]
+    fn decode_entry_value(&mut self) -> Result<Self::DecodeEntryValue<'_>, C::Error> {
+        self.entries.decode_entry_value()
+    }
+
+    #[inline]
+    fn end_entries(self) -> Result<(), C::Error> {
+        self.probe.end_entries()?;
+        self.entries.end_entries()
+    }
+}
+
+/// Entry decoder returned by [`FlattenMapDecoder`].
+pub struct FlattenEntryDecoder<'a, C: ?Sized, E> {
+    cx: &'a C,
+    entries: &'a mut E,
+}
+
+impl<'a, 'de, C, E> EntryDecoder<'de> for FlattenEntryDecoder<'a, C, E>
+where
+    C: ?Sized + Context,
+    E: EntriesDecoder<'de, Cx = C>,
+{
+    type Cx = C;
+    type DecodeKey<'this> = E::DecodeEntryKey<'this> where Self: 'this;
+    type DecodeValue = E::DecodeEntryValue<'a>;
+
+    #[inline]
+    fn size_hint(&self) -> SizeHint {
+        self.entries.size_hint()
+    }
+
+    #[inline]
+    fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
+        match self.entries.decode_entry_key()? {
+            Some(key) => Ok(key),
+            None => Err(self.cx.message("Missing flattened map entry")),
+        }
+    }
+
+    #[inline]
+    fn decode_value(self) -> Result<Self::DecodeValue, C::Error> {
+        self.entries.decode_entry_value()
+    }
+}
+
+/// Remaining entries returned by [`FlattenMapDecoder`].
+pub struct FlattenRemainingEntries<'this, 'a, C: ?Sized, E, N> {
+    map: &'this mut FlattenMapDecoder<'a, C, E, N>,
+}
+
+impl<'this, 'a, 'de, C, E, N> EntriesDecoder<'de> for FlattenRemainingEntries<'this, 'a, C, E, N>
+where
+    C: ?Sized + Context,
+    E: EntriesDecoder<'de, Cx = C>,
+    N: DecodeNames<C::Mode>,
+{
+    type Cx = C;
+    type DecodeEntryKey<'b> = E::DecodeEntryKey<'b> where Self: 'b;
+    type DecodeEntryValue<'b> = E::DecodeEntryValue<'b> where Self: 'b;
+
+    #[inline]
+    fn decode_entry_key(&mut self) -> Result<Option<Self::DecodeEntryKey<'_>>, C::Error> {
+        self.map.decode_entry_key()
+    }
+
+    #[inline]
+    fn decode_entry_value(&mut self) -> Result<Self::DecodeEntryValue<'_>, C::Error> {
+        self.map.decode_entry_value()
+    }
+
+    #[inline]
+    fn end_entries(self) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
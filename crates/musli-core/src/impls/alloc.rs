@@ -14,9 +14,9 @@ use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::collections::{HashMap, HashSet};
-#[cfg(all(feature = "std", any(unix, windows)))]
+#[cfg(feature = "std")]
 use std::ffi::{OsStr, OsString};
-#[cfg(all(feature = "std", any(unix, windows)))]
+#[cfg(feature = "std")]
 use std::path::{Path, PathBuf};
 
 use crate::de::{
@@ -31,7 +31,7 @@ use crate::hint::{MapHint, SequenceHint};
 use crate::internal::size_hint;
 use crate::Context;
 
-#[cfg(all(feature = "std", any(unix, windows)))]
+#[cfg(feature = "std")]
 use super::PlatformTag;
 
 impl<M> Encode<M> for String {
@@ -74,35 +74,103 @@ impl<'de, M> Decode<'de, M> for String {
             }
 
             #[inline]
-            fn visit_ref(self, _: &C, string: &str) -> Result<Self::Ok, C::Error> {
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                cx.check_bytes_len(string.len())?;
                 Ok(string.to_owned())
             }
         }
 
         decoder.decode_string(Visitor)
     }
+
+    #[inline]
+    fn decode_in_place<D>(_: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor<'a>(&'a mut String);
+
+        impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor<'_>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = ();
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "string")
+            }
+
+            #[inline]
+            fn visit_owned(self, _: &C, value: String) -> Result<Self::Ok, C::Error> {
+                *self.0 = value;
+                Ok(())
+            }
+
+            #[inline]
+            fn visit_borrowed(self, cx: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
+                self.visit_ref(cx, string)
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                cx.check_bytes_len(string.len())?;
+                self.0.clear();
+                self.0.push_str(string);
+                Ok(())
+            }
+        }
+
+        decoder.decode_string(Visitor(place))
+    }
 }
 
-impl<'de, M> Decode<'de, M> for Box<str> {
+impl<M> EncodeTrace<M> for String {
     #[inline]
-    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    fn trace_encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        Encode::<M>::encode(self, cx, encoder)
+    }
+}
+
+impl<'de, M> DecodeTrace<'de, M> for String {
+    #[inline]
+    fn trace_decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
         D: Decoder<'de, Mode = M>,
     {
-        Ok(decoder.decode::<String>()?.into())
+        <Self as Decode<'de, M>>::decode(cx, decoder)
     }
 }
 
-impl<'de, M, T> Decode<'de, M> for Box<[T]>
+impl<M, T> Encode<M> for Cow<'_, [T]>
 where
-    T: Decode<'de, M>,
+    T: Clone + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_ref().encode(cx, encoder)
+    }
+}
+
+/// Since each element has to be decoded, this always produces a
+/// [`Cow::Owned`]. To borrow bytes from the input, use `#[musli(bytes)]` with
+/// a `Cow<'de, [u8]>` instead.
+impl<'de, M, T> Decode<'de, M> for Cow<'_, [T]>
+where
+    T: Clone + Decode<'de, M>,
 {
     #[inline]
     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
         D: Decoder<'de, Mode = M>,
     {
-        Ok(decoder.decode::<Vec<T>>()?.into())
+        Ok(Cow::Owned(decoder.decode::<Vec<T>>()?))
     }
 }
 
@@ -255,12 +323,16 @@ macro_rules! sequence {
                 D: Decoder<'de, Mode = M>,
             {
                 decoder.decode_sequence(|$access| {
+                    $cx.check_sequence_len($access.size_hint().or_default())?;
+
                     let mut out = $factory;
 
                     let mut index = 0;
 
                     while let Some(value) = $access.try_decode_next()? {
                         $cx.enter_sequence_index(index);
+                        $cx.check_sequence_len(index.wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
                         out.$insert(T::decode($cx, value)?);
                         $cx.leave_sequence_index();
                         index = index.wrapping_add(1);
@@ -269,6 +341,93 @@ macro_rules! sequence {
                     Ok(out)
                 })
             }
+
+            #[inline]
+            fn decode_in_place<D>($cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                place.clear();
+
+                decoder.decode_sequence(|$access| {
+                    $cx.check_sequence_len($access.size_hint().or_default())?;
+
+                    let mut index = 0;
+
+                    while let Some(value) = $access.try_decode_next()? {
+                        $cx.enter_sequence_index(index);
+                        $cx.check_sequence_len(index.wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
+                        place.$insert(T::decode($cx, value)?);
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(())
+                })
+            }
+        }
+
+        $(#[$($meta)*])*
+        impl<M, T $(, $extra)*> EncodeTrace<M> for $ty<T $(, $extra)*>
+        where
+            T: EncodeTrace<M>,
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            #[inline]
+            fn trace_encode<E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                let hint = SequenceHint::with_size(self.len());
+
+                encoder.encode_sequence_fn(&hint, |seq| {
+                    let mut index = 0;
+
+                    for value in self {
+                        $cx.enter_sequence_index(index);
+                        value.trace_encode($cx, seq.encode_next()?)?;
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(())
+                })
+            }
+        }
+
+        /// Elements are decoded through [`DecodeTrace`], so that the path to
+        /// an error composes through nested containers.
+        $(#[$($meta)*])*
+        impl<'de, M, T $(, $extra)*> DecodeTrace<'de, M> for $ty<T $(, $extra)*>
+        where
+            T: DecodeTrace<'de, M> $(+ $trait0 $(+ $trait)*)*,
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            #[inline]
+            fn trace_decode<D>($cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                decoder.decode_sequence(|$access| {
+                    $cx.check_sequence_len($access.size_hint().or_default())?;
+
+                    let mut out = $factory;
+
+                    let mut index = 0;
+
+                    while let Some(value) = $access.try_decode_next()? {
+                        $cx.enter_sequence_index(index);
+                        $cx.check_sequence_len(index.wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
+                        out.$insert(T::trace_decode($cx, value)?);
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(out)
+                })
+            }
         }
 
         $(#[$($meta)*])*
@@ -367,7 +526,7 @@ macro_rules! map {
         impl<'de, M, K, V $(, $extra)*> EncodeTrace<M> for $ty<K, V $(, $extra)*>
         where
             K: fmt::Display + Encode<M>,
-            V: Encode<M>,
+            V: EncodeTrace<M>,
             $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
@@ -382,7 +541,7 @@ macro_rules! map {
                         $cx.enter_map_key(k);
                         map.encode_entry_fn(|entry| {
                             entry.encode_key()?.encode(k)?;
-                            entry.encode_value()?.encode(v)?;
+                            v.trace_encode($cx, entry.encode_value()?)?;
                             Ok(())
                         })?;
                         $cx.leave_map_key();
@@ -406,22 +565,46 @@ macro_rules! map {
                 D: Decoder<'de, Mode = M>,
             {
                 decoder.decode_map(|$access| {
+                    $cx.check_map_len($access.size_hint().or_default())?;
+
                     let mut out = $with_capacity;
 
                     while let Some((key, value)) = $access.entry()? {
+                        $cx.check_map_len(out.len().wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
                         out.insert(key, value);
                     }
 
                     Ok(out)
                 })
             }
+
+            #[inline]
+            fn decode_in_place<D>($cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                place.clear();
+
+                decoder.decode_map(|$access| {
+                    $cx.check_map_len($access.size_hint().or_default())?;
+
+                    while let Some((key, value)) = $access.entry()? {
+                        $cx.check_map_len(place.len().wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
+                        place.insert(key, value);
+                    }
+
+                    Ok(())
+                })
+            }
         }
 
         $(#[$($meta)*])*
         impl<'de, K, V, M $(, $extra)*> DecodeTrace<'de, M> for $ty<K, V $(, $extra)*>
         where
             K: fmt::Display + Decode<'de, M> $(+ $key_bound0 $(+ $key_bound)*)*,
-            V: Decode<'de, M>,
+            V: DecodeTrace<'de, M>,
             $($extra: $extra_bound0 $(+ $extra_bound)*),*
         {
             #[inline]
@@ -430,12 +613,16 @@ macro_rules! map {
                 D: Decoder<'de, Mode = M>,
             {
                 decoder.decode_map(|$access| {
+                    $cx.check_map_len($access.size_hint().or_default())?;
+
                     let mut out = $with_capacity;
 
                     while let Some(mut entry) = $access.decode_entry()? {
+                        $cx.check_map_len(out.len().wrapping_add(1))?;
+                        $cx.consume_elements(1)?;
                         let key = entry.decode_key()?.decode()?;
                         $cx.enter_map_key(&key);
-                        let value = entry.decode_value()?.decode()?;
+                        let value = V::trace_decode($cx, entry.decode_value()?)?;
                         out.insert(key, value);
                         $cx.leave_map_key();
                     }
@@ -538,13 +725,134 @@ macro_rules! smart_pointer {
                 }
             }
 
+            impl<M, T> EncodeTrace<M> for $ty<T>
+            where
+                T: ?Sized + EncodeTrace<M>,
+            {
+                #[inline]
+                fn trace_encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    self.as_ref().trace_encode(cx, encoder)
+                }
+            }
+
+            impl<'de, M, T> DecodeTrace<'de, M> for $ty<T>
+            where
+                T: DecodeTrace<'de, M>,
+            {
+                #[inline]
+                fn trace_decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    Ok($ty::new(T::trace_decode(cx, decoder)?))
+                }
+            }
+
+            impl<'de, M> Decode<'de, M> for $ty<str> {
+                #[inline]
+                fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    struct Visitor;
+
+                    impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor
+                    where
+                        C: ?Sized + Context,
+                    {
+                        type Ok = $ty<str>;
+
+                        #[inline]
+                        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            write!(f, "string")
+                        }
+
+                        #[inline]
+                        fn visit_owned(self, _: &C, value: String) -> Result<Self::Ok, C::Error> {
+                            Ok($ty::from(value))
+                        }
+
+                        #[inline]
+                        fn visit_borrowed(self, cx: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
+                            self.visit_ref(cx, string)
+                        }
+
+                        #[inline]
+                        fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                            cx.check_bytes_len(string.len())?;
+                            Ok($ty::from(string))
+                        }
+                    }
+
+                    decoder.decode_string(Visitor)
+                }
+            }
+
+            impl<'de, M, T> Decode<'de, M> for $ty<[T]>
+            where
+                T: Decode<'de, M>,
+            {
+                #[inline]
+                fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    Ok($ty::from(decoder.decode::<Vec<T>>()?))
+                }
+            }
+
+            impl<M> EncodeBytes<M> for $ty<[u8]> {
+                #[inline]
+                fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    encoder.encode_bytes(self.as_ref())
+                }
+            }
+
+            /// Borrowed bytes are copied directly into the allocation, rather
+            /// than first being collected into a [`Vec<u8>`].
             impl<'de, M> DecodeBytes<'de, M> for $ty<[u8]> {
                 #[inline]
-                fn decode_bytes<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
                 where
                     D: Decoder<'de, Mode = M>,
                 {
-                    Ok($ty::from(<Vec<u8>>::decode_bytes(cx, decoder)?))
+                    struct Visitor;
+
+                    impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+                    where
+                        C: ?Sized + Context,
+                    {
+                        type Ok = $ty<[u8]>;
+
+                        #[inline]
+                        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                            write!(f, "bytes")
+                        }
+
+                        #[inline]
+                        fn visit_owned(self, _: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
+                            Ok($ty::from(bytes))
+                        }
+
+                        #[inline]
+                        fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
+                            self.visit_ref(cx, bytes)
+                        }
+
+                        #[inline]
+                        fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                            cx.check_bytes_len(bytes.len())?;
+                            Ok($ty::from(bytes))
+                        }
+                    }
+
+                    decoder.decode_bytes(Visitor)
                 }
             }
 
@@ -558,8 +866,8 @@ macro_rules! smart_pointer {
                 }
             }
 
-            #[cfg(all(feature = "std", any(unix, windows)))]
-            #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+            #[cfg(feature = "std")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
             impl<'de, M> Decode<'de, M> for $ty<Path> where PlatformTag: Decode<'de, M> {
                 #[inline]
                 fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -570,8 +878,8 @@ macro_rules! smart_pointer {
                 }
             }
 
-            #[cfg(all(feature = "std", any(unix, windows)))]
-            #[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+            #[cfg(feature = "std")]
+            #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
             impl<'de, M> Decode<'de, M> for $ty<OsStr> where PlatformTag: Decode<'de, M> {
                 #[inline]
                 fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -587,8 +895,8 @@ macro_rules! smart_pointer {
 
 smart_pointer!(Box, Arc, Rc);
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<M> Encode<M> for OsStr
 where
     PlatformTag: Encode<M>,
@@ -638,10 +946,31 @@ where
             Ok(())
         })
     }
+
+    /// On other platforms the string is encoded as UTF-8 using the same
+    /// representation as on unix.
+    #[cfg(not(any(unix, windows)))]
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        use crate::en::VariantEncoder;
+
+        let Some(string) = self.to_str() else {
+            return Err(cx.message("OsStr is not valid UTF-8"));
+        };
+
+        encoder.encode_variant_fn(|variant| {
+            variant.encode_tag()?.encode(PlatformTag::Unix)?;
+            variant.encode_data()?.encode_bytes(string.as_bytes())?;
+            Ok(())
+        })
+    }
 }
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<M> Encode<M> for OsString
 where
     PlatformTag: Encode<M>,
@@ -655,8 +984,8 @@ where
     }
 }
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<'de, M> Decode<'de, M> for OsString
 where
     PlatformTag: Decode<'de, M>,
@@ -672,15 +1001,43 @@ where
             let tag = variant.decode_tag()?.decode::<PlatformTag>()?;
 
             match tag {
-                #[cfg(not(unix))]
+                #[cfg(windows)]
                 PlatformTag::Unix => Err(cx.message("Unsupported OsString::Unix variant")),
+                #[cfg(not(any(unix, windows)))]
+                PlatformTag::Unix => {
+                    let bytes = Vec::<u8>::decode_bytes(cx, variant.decode_value()?)?;
+                    let string = String::from_utf8(bytes).map_err(cx.map())?;
+                    Ok(OsString::from(string))
+                }
                 #[cfg(unix)]
                 PlatformTag::Unix => {
                     use std::os::unix::ffi::OsStringExt;
-                    Ok(OsString::from_vec(variant.decode_value()?.decode()?))
+                    let bytes = Vec::<u8>::decode_bytes(cx, variant.decode_value()?)?;
+                    Ok(OsString::from_vec(bytes))
                 }
-                #[cfg(not(windows))]
+                #[cfg(unix)]
                 PlatformTag::Windows => Err(cx.message("Unsupported OsString::Windows variant")),
+                #[cfg(not(any(unix, windows)))]
+                PlatformTag::Windows => {
+                    let bytes = Vec::<u8>::decode_bytes(cx, variant.decode_value()?)?;
+
+                    if bytes.len() % 2 != 0 {
+                        return Err(cx.message("Odd number of bytes in OsString::Windows variant"));
+                    }
+
+                    let mut wide = Vec::with_capacity(bytes.len() / 2);
+
+                    for pair in bytes.chunks_exact(2) {
+                        let &[a, b] = pair else {
+                            continue;
+                        };
+
+                        wide.push(u16::from_le_bytes([a, b]));
+                    }
+
+                    let string = String::from_utf16(&wide).map_err(cx.map())?;
+                    Ok(OsString::from(string))
+                }
                 #[cfg(windows)]
                 PlatformTag::Windows => {
                     use std::os::windows::ffi::OsStringExt;
@@ -699,7 +1056,13 @@ where
                         }
 
                         #[inline]
-                        fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                        fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                            if bytes.len() % 2 != 0 {
+                                return Err(
+                                    cx.message("Odd number of bytes in OsString::Windows variant")
+                                );
+                            }
+
                             let mut buf = Vec::with_capacity(bytes.len() / 2);
 
                             for pair in bytes.chunks_exact(2) {
@@ -721,8 +1084,8 @@ where
     }
 }
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<M> Encode<M> for Path
 where
     PlatformTag: Encode<M>,
@@ -736,8 +1099,8 @@ where
     }
 }
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<M> Encode<M> for PathBuf
 where
     PlatformTag: Encode<M>,
@@ -751,8 +1114,8 @@ where
     }
 }
 
-#[cfg(all(feature = "std", any(unix, windows)))]
-#[cfg_attr(doc_cfg, doc(cfg(all(feature = "std", any(unix, windows)))))]
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 impl<'de, M> Decode<'de, M> for PathBuf
 where
     PlatformTag: Decode<'de, M>,
@@ -776,16 +1139,6 @@ impl<M> EncodeBytes<M> for Vec<u8> {
     }
 }
 
-impl<M> EncodeBytes<M> for Box<[u8]> {
-    #[inline]
-    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
-    where
-        E: Encoder<Mode = M>,
-    {
-        encoder.encode_bytes(self.as_ref())
-    }
-}
-
 impl<'de, M> DecodeBytes<'de, M> for Vec<u8> {
     #[inline]
     fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
@@ -806,12 +1159,13 @@ impl<'de, M> DecodeBytes<'de, M> for Vec<u8> {
             }
 
             #[inline]
-            fn visit_borrowed(self, _: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                Ok(bytes.to_vec())
+            fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
+                self.visit_ref(cx, bytes)
             }
 
             #[inline]
-            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+            fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                cx.check_bytes_len(bytes.len())?;
                 Ok(bytes.to_vec())
             }
         }
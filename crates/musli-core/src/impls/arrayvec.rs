@@ -0,0 +1,175 @@
+use core::fmt;
+
+use arrayvec::{ArrayString, ArrayVec};
+
+use crate::de::{Decode, Decoder, SequenceDecoder, UnsizedVisitor};
+use crate::en::{Encode, EncodePacked, Encoder, SequenceEncoder};
+use crate::hint::SequenceHint;
+use crate::Context;
+
+impl<M, T, const N: usize> Encode<M> for ArrayVec<T, N>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let hint = SequenceHint::with_size(self.len());
+
+        encoder.encode_sequence_fn(&hint, |seq| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                seq.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Decoding errors if the sequence has more than `N` elements.
+impl<'de, M, T, const N: usize> Decode<'de, M> for ArrayVec<T, N>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mut out = ArrayVec::new();
+        decode_into(cx, decoder, &mut out)?;
+        Ok(out)
+    }
+
+    #[inline]
+    fn decode_in_place<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        place.clear();
+        decode_into(cx, decoder, place)
+    }
+}
+
+/// Decode a sequence into an empty [`ArrayVec`].
+#[inline]
+fn decode_into<'de, M, D, T, const N: usize>(
+    cx: &D::Cx,
+    decoder: D,
+    out: &mut ArrayVec<T, N>,
+) -> Result<(), D::Error>
+where
+    D: Decoder<'de, Mode = M>,
+    T: Decode<'de, M>,
+{
+    decoder.decode_sequence(|seq| {
+        if let Some(len) = seq.size_hint().into_option() {
+            if len > N {
+                return Err(cx.message(format_args!(
+                    "Sequence of {len} elements exceeds the ArrayVec capacity of {N}"
+                )));
+            }
+        }
+
+        let mut index = 0;
+
+        while let Some(value) = seq.try_decode_next()? {
+            cx.enter_sequence_index(index);
+
+            if out.is_full() {
+                return Err(cx.message(format_args!(
+                    "Sequence exceeds the ArrayVec capacity of {N}"
+                )));
+            }
+
+            cx.consume_elements(1)?;
+            out.push(T::decode(cx, value)?);
+            cx.leave_sequence_index();
+            index = index.wrapping_add(1);
+        }
+
+        Ok(())
+    })
+}
+
+impl<M, T, const N: usize> EncodePacked<M> for ArrayVec<T, N>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<M, const N: usize> Encode<M> for ArrayString<N> {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_str().encode(cx, encoder)
+    }
+}
+
+/// Decoding errors if the string is longer than `N` bytes.
+impl<'de, M, const N: usize> Decode<'de, M> for ArrayString<N> {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor<const N: usize>;
+
+        impl<'de, C, const N: usize> UnsizedVisitor<'de, C, str> for Visitor<N>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = ArrayString<N>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "string of at most {N} bytes")
+            }
+
+            #[inline]
+            fn visit_borrowed(self, cx: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
+                self.visit_ref(cx, string)
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                match ArrayString::from(string) {
+                    Ok(string) => Ok(string),
+                    Err(error) => Err(cx.message(format_args!(
+                        "String of {} bytes exceeds the ArrayString capacity of {N}",
+                        error.element().len()
+                    ))),
+                }
+            }
+        }
+
+        decoder.decode_string(Visitor)
+    }
+}
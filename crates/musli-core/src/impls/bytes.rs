@@ -0,0 +1,134 @@
+use core::fmt;
+use core::marker;
+
+use alloc::vec::Vec;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::de::{Decode, DecodeBytes, Decoder, UnsizedVisitor};
+use crate::en::{Encode, EncodeBytes, Encoder};
+use crate::Context;
+
+/// A buffer which can be constructed from decoded bytes.
+trait FromBytes: Sized {
+    /// Construct from an owned vector without copying.
+    fn from_vec(bytes: Vec<u8>) -> Self;
+
+    /// Construct by copying a slice.
+    fn from_slice(bytes: &[u8]) -> Self;
+}
+
+impl FromBytes for Bytes {
+    #[inline]
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        Bytes::from(bytes)
+    }
+
+    #[inline]
+    fn from_slice(bytes: &[u8]) -> Self {
+        Bytes::copy_from_slice(bytes)
+    }
+}
+
+impl FromBytes for BytesMut {
+    #[inline]
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        BytesMut::from(Bytes::from(bytes))
+    }
+
+    #[inline]
+    fn from_slice(bytes: &[u8]) -> Self {
+        BytesMut::from(bytes)
+    }
+}
+
+#[inline]
+fn decode_with<'de, D, T>(decoder: D) -> Result<T, D::Error>
+where
+    D: Decoder<'de>,
+    T: FromBytes,
+{
+    struct Visitor<T>(marker::PhantomData<T>);
+
+    impl<'de, C, T> UnsizedVisitor<'de, C, [u8]> for Visitor<T>
+    where
+        C: ?Sized + Context,
+        T: FromBytes,
+    {
+        type Ok = T;
+
+        #[inline]
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "bytes")
+        }
+
+        #[inline]
+        fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
+            cx.check_bytes_len(bytes.len())?;
+            Ok(T::from_vec(bytes))
+        }
+
+        #[inline]
+        fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
+            self.visit_ref(cx, bytes)
+        }
+
+        #[inline]
+        fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+            cx.check_bytes_len(bytes.len())?;
+            Ok(T::from_slice(bytes))
+        }
+    }
+
+    decoder.decode_bytes(Visitor(marker::PhantomData))
+}
+
+macro_rules! bytes {
+    ($ty:ty) => {
+        /// Encoded as bytes by reference into the buffer without copying.
+        impl<M> Encode<M> for $ty {
+            #[inline]
+            fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                encoder.encode_bytes(self.as_ref())
+            }
+        }
+
+        impl<M> EncodeBytes<M> for $ty {
+            #[inline]
+            fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                encoder.encode_bytes(self.as_ref())
+            }
+        }
+
+        /// Decoded from bytes, which are copied unless the decoder provides
+        /// them as an owned vector.
+        impl<'de, M> Decode<'de, M> for $ty {
+            #[inline]
+            fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                decode_with(decoder)
+            }
+        }
+
+        impl<'de, M> DecodeBytes<'de, M> for $ty {
+            #[inline]
+            fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                decode_with(decoder)
+            }
+        }
+    };
+}
+
+bytes!(Bytes);
+bytes!(BytesMut);
@@ -0,0 +1,58 @@
+use core::cell::{Cell, RefCell};
+
+use crate::{Context, Decode, Decoder, Encode, Encoder};
+
+impl<M, T> Encode<M> for Cell<T>
+where
+    T: Copy + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.get().encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Cell<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(Cell::new)
+    }
+}
+
+/// Encoding a [`RefCell`] which is currently mutably borrowed results in an
+/// error.
+impl<M, T> Encode<M> for RefCell<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let value = self.try_borrow().map_err(cx.map_message())?;
+        value.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for RefCell<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(RefCell::new)
+    }
+}
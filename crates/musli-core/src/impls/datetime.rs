@@ -0,0 +1,299 @@
+use core::fmt;
+use core::str;
+
+use time::format_description::well_known::Rfc3339;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder};
+use crate::en::{Encode, Encoder};
+use crate::mode::{Binary, Text};
+
+use super::time::{decode_pair, encode_pair};
+
+/// The julian day of the unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+/// Formats a date and time as RFC 3339, assuming that it has been validated
+/// using [`validate`].
+struct Rfc3339Display {
+    date: Date,
+    time: Option<time::Time>,
+    offset: Option<UtcOffset>,
+}
+
+impl fmt::Display for Rfc3339Display {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            u8::from(date.month()),
+            date.day()
+        )?;
+
+        let Some(time) = self.time else {
+            return Ok(());
+        };
+
+        write!(
+            f,
+            "T{:02}:{:02}:{:02}",
+            time.hour(),
+            time.minute(),
+            time.second()
+        )?;
+
+        let mut nanos = time.nanosecond();
+
+        if nanos != 0 {
+            let mut digits = 9;
+
+            while nanos % 10 == 0 {
+                nanos /= 10;
+                digits -= 1;
+            }
+
+            write!(f, ".{nanos:0digits$}")?;
+        }
+
+        let Some(offset) = self.offset else {
+            return Ok(());
+        };
+
+        if offset.is_utc() {
+            return f.write_str("Z");
+        }
+
+        let sign = if offset.is_negative() { '-' } else { '+' };
+
+        write!(
+            f,
+            "{sign}{:02}:{:02}",
+            offset.whole_hours().unsigned_abs(),
+            offset.minutes_past_hour().unsigned_abs()
+        )
+    }
+}
+
+/// Validate that the given date and offset can be represented in RFC 3339.
+fn validate<C>(cx: &C, date: Date, offset: Option<UtcOffset>) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+{
+    let year = date.year();
+
+    if !(0..=9999).contains(&year) {
+        return Err(cx.message(format_args!("Year {year} can't be represented in RFC 3339")));
+    }
+
+    if let Some(offset) = offset {
+        if offset.seconds_past_minute() != 0 {
+            return Err(cx.message(format_args!(
+                "Offset {offset} can't be represented in RFC 3339"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse RFC 3339 which is missing a suffix, like a date without a time or a
+/// date and time without an offset.
+fn parse_with_suffix<C>(cx: &C, string: &str, suffix: &str) -> Result<OffsetDateTime, C::Error>
+where
+    C: ?Sized + Context,
+{
+    let mut buf = [0u8; 64];
+
+    let Some(out) = buf.get_mut(..string.len() + suffix.len()) else {
+        return Err(cx.message(format_args!("Invalid RFC 3339 string {string:?}")));
+    };
+
+    let (head, tail) = out.split_at_mut(string.len());
+    head.copy_from_slice(string.as_bytes());
+    tail.copy_from_slice(suffix.as_bytes());
+
+    let string = str::from_utf8(out).map_err(cx.map())?;
+    OffsetDateTime::parse(string, &Rfc3339).map_err(cx.map())
+}
+
+/// Decode seconds and nanoseconds since the unix epoch in UTC.
+#[inline]
+fn decode_timestamp<'de, D>(cx: &D::Cx, decoder: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Decoder<'de, Mode = Binary>,
+{
+    let mark = cx.mark();
+    let (secs, nanos) = decode_pair::<Binary, D, i64>(cx, decoder)?;
+    let timestamp = i128::from(secs) * 1_000_000_000 + i128::from(nanos);
+
+    match OffsetDateTime::from_unix_timestamp_nanos(timestamp) {
+        Ok(time) => Ok(time),
+        Err(..) => Err(cx.marked_message(
+            mark,
+            format_args!("Timestamp {secs}s {nanos}ns is out of range"),
+        )),
+    }
+}
+
+impl Encode<Binary> for OffsetDateTime {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encode_pair(encoder, self.unix_timestamp(), self.nanosecond())
+    }
+}
+
+impl Encode<Text> for OffsetDateTime {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        validate(cx, self.date(), Some(self.offset()))?;
+
+        encoder.encode_display(&Rfc3339Display {
+            date: self.date(),
+            time: Some(self.time()),
+            offset: Some(self.offset()),
+        })
+    }
+}
+
+impl<'de> Decode<'de, Binary> for OffsetDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        decode_timestamp(cx, decoder)
+    }
+}
+
+impl<'de> Decode<'de, Text> for OffsetDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| {
+            OffsetDateTime::parse(string, &Rfc3339).map_err(cx.map())
+        })
+    }
+}
+
+impl Encode<Binary> for PrimitiveDateTime {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        let utc = self.assume_utc();
+        encode_pair(encoder, utc.unix_timestamp(), utc.nanosecond())
+    }
+}
+
+impl Encode<Text> for PrimitiveDateTime {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        validate(cx, self.date(), None)?;
+
+        encoder.encode_display(&Rfc3339Display {
+            date: self.date(),
+            time: Some(self.time()),
+            offset: None,
+        })
+    }
+}
+
+impl<'de> Decode<'de, Binary> for PrimitiveDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let time = decode_timestamp(cx, decoder)?;
+        Ok(PrimitiveDateTime::new(time.date(), time.time()))
+    }
+}
+
+impl<'de> Decode<'de, Text> for PrimitiveDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| {
+            let time = parse_with_suffix(cx, string, "Z")?;
+            Ok(PrimitiveDateTime::new(time.date(), time.time()))
+        })
+    }
+}
+
+impl Encode<Binary> for Date {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_i32(self.to_julian_day() - UNIX_EPOCH_JULIAN_DAY)
+    }
+}
+
+impl Encode<Text> for Date {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        validate(cx, *self, None)?;
+
+        encoder.encode_display(&Rfc3339Display {
+            date: *self,
+            time: None,
+            offset: None,
+        })
+    }
+}
+
+impl<'de> Decode<'de, Binary> for Date {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let mark = cx.mark();
+        let days = decoder.decode_i32()?;
+
+        let date = days
+            .checked_add(UNIX_EPOCH_JULIAN_DAY)
+            .and_then(|day| Date::from_julian_day(day).ok());
+
+        match date {
+            Some(date) => Ok(date),
+            None => Err(cx.marked_message(
+                mark,
+                format_args!("Date {days} days from the unix epoch is out of range"),
+            )),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Text> for Date {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder
+            .decode_unsized(|string: &str| Ok(parse_with_suffix(cx, string, "T00:00:00Z")?.date()))
+    }
+}
@@ -0,0 +1,263 @@
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+
+use heapless::{IndexMap, String, Vec};
+
+use crate::de::{Decode, Decoder, MapDecoder, SequenceDecoder, UnsizedVisitor};
+use crate::en::{Encode, EncodePacked, Encoder, MapEncoder, SequenceEncoder};
+use crate::hint::{MapHint, SequenceHint};
+use crate::Context;
+
+impl<M, T, const N: usize> Encode<M> for Vec<T, N>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let hint = SequenceHint::with_size(self.len());
+
+        encoder.encode_sequence_fn(&hint, |seq| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                seq.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Decoding errors if the sequence has more than `N` elements.
+impl<'de, M, T, const N: usize> Decode<'de, M> for Vec<T, N>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mut out = Vec::new();
+        decode_vec_into(cx, decoder, &mut out)?;
+        Ok(out)
+    }
+
+    #[inline]
+    fn decode_in_place<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        place.clear();
+        decode_vec_into(cx, decoder, place)
+    }
+}
+
+/// Decode a sequence into an empty [`Vec`].
+#[inline]
+fn decode_vec_into<'de, M, D, T, const N: usize>(
+    cx: &D::Cx,
+    decoder: D,
+    out: &mut Vec<T, N>,
+) -> Result<(), D::Error>
+where
+    D: Decoder<'de, Mode = M>,
+    T: Decode<'de, M>,
+{
+    decoder.decode_sequence(|seq| {
+        if let Some(len) = seq.size_hint().into_option() {
+            if len > N {
+                return Err(cx.message(format_args!(
+                    "Sequence of {len} elements exceeds the heapless::Vec capacity of {N}"
+                )));
+            }
+        }
+
+        let mut index = 0;
+
+        while let Some(value) = seq.try_decode_next()? {
+            cx.enter_sequence_index(index);
+            cx.consume_elements(1)?;
+
+            if out.push(T::decode(cx, value)?).is_err() {
+                return Err(cx.message(format_args!(
+                    "Sequence exceeds the heapless::Vec capacity of {N}"
+                )));
+            }
+
+            cx.leave_sequence_index();
+            index = index.wrapping_add(1);
+        }
+
+        Ok(())
+    })
+}
+
+impl<M, T, const N: usize> EncodePacked<M> for Vec<T, N>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<M, const N: usize> Encode<M> for String<N> {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_str().encode(cx, encoder)
+    }
+}
+
+/// Decoding errors if the string is longer than `N` bytes.
+impl<'de, M, const N: usize> Decode<'de, M> for String<N> {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor<const N: usize>;
+
+        impl<'de, C, const N: usize> UnsizedVisitor<'de, C, str> for Visitor<N>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = String<N>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "string of at most {N} bytes")
+            }
+
+            #[inline]
+            fn visit_borrowed(self, cx: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
+                self.visit_ref(cx, string)
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                let mut out = String::new();
+
+                if out.push_str(string).is_err() {
+                    return Err(cx.message(format_args!(
+                        "String of {} bytes exceeds the heapless::String capacity of {N}",
+                        string.len()
+                    )));
+                }
+
+                Ok(out)
+            }
+        }
+
+        decoder.decode_string(Visitor)
+    }
+}
+
+impl<M, K, V, S, const N: usize> Encode<M> for IndexMap<K, V, S, N>
+where
+    K: Encode<M>,
+    V: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let hint = MapHint::with_size(self.len());
+
+        encoder.encode_map_fn(&hint, |map| {
+            for (k, v) in self {
+                map.insert_entry(k, v)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Decoding errors if the map has more than `N` entries.
+impl<'de, M, K, V, S, const N: usize> Decode<'de, M> for IndexMap<K, V, S, N>
+where
+    K: Decode<'de, M> + Eq + Hash,
+    V: Decode<'de, M>,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mut out = IndexMap::default();
+        decode_map_into(cx, decoder, &mut out)?;
+        Ok(out)
+    }
+
+    #[inline]
+    fn decode_in_place<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        place.clear();
+        decode_map_into(cx, decoder, place)
+    }
+}
+
+/// Decode a map into an empty [`IndexMap`].
+#[inline]
+fn decode_map_into<'de, M, D, K, V, S, const N: usize>(
+    cx: &D::Cx,
+    decoder: D,
+    out: &mut IndexMap<K, V, S, N>,
+) -> Result<(), D::Error>
+where
+    D: Decoder<'de, Mode = M>,
+    K: Decode<'de, M> + Eq + Hash,
+    V: Decode<'de, M>,
+    S: BuildHasher,
+{
+    decoder.decode_map(|map| {
+        if let Some(len) = map.size_hint().into_option() {
+            if len > N {
+                return Err(cx.message(format_args!(
+                    "Map of {len} entries exceeds the heapless::IndexMap capacity of {N}"
+                )));
+            }
+        }
+
+        while let Some((key, value)) = map.entry()? {
+            cx.consume_elements(1)?;
+
+            if out.insert(key, value).is_err() {
+                return Err(cx.message(format_args!(
+                    "Map exceeds the heapless::IndexMap capacity of {N}"
+                )));
+            }
+        }
+
+        Ok(())
+    })
+}
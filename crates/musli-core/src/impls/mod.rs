@@ -1,24 +1,50 @@
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 mod alloc;
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "arrayvec")))]
+mod arrayvec;
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+mod bytes;
+mod cell;
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+mod datetime;
+#[cfg(feature = "heapless")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "heapless")))]
+mod heapless;
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 mod net;
+mod packed_size;
 mod range;
+#[cfg(feature = "smallvec")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "smallvec")))]
+mod smallvec;
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+mod sync;
+mod time;
 mod tuples;
+#[cfg(feature = "uuid")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "uuid")))]
+mod uuid;
 
 use core::ffi::CStr;
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
-    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
 };
 use core::{fmt, marker};
 
 use crate::de::{
-    Decode, DecodeBytes, DecodePacked, DecodeUnsized, DecodeUnsizedBytes, Decoder, SequenceDecoder,
-    UnsizedVisitor, VariantDecoder,
+    Decode, DecodeBytes, DecodePacked, DecodeTrace, DecodeUnsized, DecodeUnsizedBytes, Decoder,
+    SequenceDecoder, UnsizedVisitor, VariantDecoder,
+};
+use crate::en::{
+    Encode, EncodeBytes, EncodePacked, EncodeTrace, Encoder, SequenceEncoder, VariantEncoder,
 };
-use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder, SequenceEncoder, VariantEncoder};
 use crate::hint::SequenceHint;
 use crate::Context;
 
@@ -75,8 +101,24 @@ impl<'de, M, T> Decode<'de, M> for marker::PhantomData<T> {
 macro_rules! atomic_impl {
     ($size:literal $(, $ty:ident)*) => {
         $(
+            /// The value of the atomic is loaded using [`Ordering::Relaxed`],
+            /// so encoding provides no synchronization with other accesses.
+            ///
+            /// [`Ordering::Relaxed`]: core::sync::atomic::Ordering::Relaxed
+            #[cfg(target_has_atomic = $size)]
+            impl<M> Encode<M> for core::sync::atomic::$ty {
+                #[inline]
+                fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    self.load(core::sync::atomic::Ordering::Relaxed).encode(cx, encoder)
+                }
+            }
+
             #[cfg(target_has_atomic = $size)]
             impl<'de, M> Decode<'de, M> for core::sync::atomic::$ty {
+                #[inline]
                 fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
                 where
                     D: Decoder<'de>,
@@ -188,21 +230,41 @@ where
     {
         let mark = cx.mark();
 
+        let mismatch = |len: usize| {
+            cx.marked_message(
+                mark,
+                format_args!(
+                    "Array with length {len} does not have the expected {N} number of elements"
+                ),
+            )
+        };
+
         decoder.decode_sequence(|seq| {
-            let mut array = crate::internal::FixedVec::new();
+            if let Some(len) = seq.size_hint().into_option() {
+                if len != N {
+                    return Err(mismatch(len));
+                }
+            }
+
+            // Elements which have been decoded are dropped by the fixed vector
+            // if decoding fails part of the way through.
+            let mut array = crate::internal::FixedVec::<T, N>::new();
+            let mut len = 0;
 
             while let Some(item) = seq.try_decode_next()? {
-                array.try_push(item.decode()?).map_err(cx.map())?;
+                if len < N {
+                    array.try_push(item.decode()?).map_err(cx.map())?;
+                } else {
+                    // Skip over any excess elements so that the actual length
+                    // can be reported.
+                    item.skip()?;
+                }
+
+                len += 1;
             }
 
-            if array.len() != N {
-                return Err(cx.marked_message(
-                    mark,
-                    format_args!(
-                        "Array with length {} does not have the expected {N} number of elements",
-                        array.len()
-                    ),
-                ));
+            if len != N {
+                return Err(mismatch(len));
             }
 
             Ok(array.into_inner())
@@ -363,6 +425,13 @@ impl<'de, M> Decode<'de, M> for &'de str {
             fn visit_borrowed(self, _: &C, string: &'de str) -> Result<Self::Ok, C::Error> {
                 Ok(string)
             }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, _: &str) -> Result<Self::Ok, C::Error> {
+                Err(cx.message(
+                    "Expected a string borrowed from the source, but it had to be decoded into a buffer",
+                ))
+            }
         }
 
         decoder.decode_string(Visitor)
@@ -448,6 +517,13 @@ impl<'de, M> Decode<'de, M> for &'de [u8] {
             fn visit_borrowed(self, _: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
                 Ok(bytes)
             }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, _: &[u8]) -> Result<Self::Ok, C::Error> {
+                Err(cx.message(
+                    "Expected bytes borrowed from the source, but they had to be decoded into a buffer",
+                ))
+            }
         }
 
         decoder.decode_bytes(Visitor)
@@ -518,6 +594,84 @@ where
     }
 }
 
+impl<T, M> EncodeTrace<M> for Option<T>
+where
+    T: EncodeTrace<M>,
+{
+    #[inline]
+    fn trace_encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        match self {
+            Some(value) => value.trace_encode(cx, encoder.encode_some()?),
+            None => encoder.encode_none(),
+        }
+    }
+}
+
+impl<'de, M, T> DecodeTrace<'de, M> for Option<T>
+where
+    T: DecodeTrace<'de, M>,
+{
+    #[inline]
+    fn trace_decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        if let Some(decoder) = decoder.decode_option()? {
+            Ok(Some(T::trace_decode(cx, decoder)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Implement tracing for types which can't contain a map, by forwarding to
+/// their regular implementations.
+macro_rules! trace_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<M> EncodeTrace<M> for $ty {
+                #[inline]
+                fn trace_encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+                where
+                    E: Encoder<Mode = M>,
+                {
+                    Encode::<M>::encode(self, cx, encoder)
+                }
+            }
+
+            impl<'de, M> DecodeTrace<'de, M> for $ty {
+                #[inline]
+                fn trace_decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+                where
+                    D: Decoder<'de, Mode = M>,
+                {
+                    <Self as Decode<'de, M>>::decode(cx, decoder)
+                }
+            }
+        )*
+    };
+}
+
+trace_leaf! {
+    (), bool, char,
+    usize, isize, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64,
+}
+
+impl<M> EncodeTrace<M> for str {
+    #[inline]
+    fn trace_encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        Encode::<M>::encode(self, cx, encoder)
+    }
+}
+
+/// The tag of a [`Result`], which is encoded the same way as the tag of a
+/// derived enum with `Ok` and `Err` variants.
 #[derive(Encode, Decode)]
 #[musli(crate)]
 enum ResultTag {
@@ -532,15 +686,25 @@ where
     ResultTag: Encode<M>,
 {
     #[inline]
-    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
         E: Encoder<Mode = M>,
     {
         let variant = encoder.encode_variant()?;
 
         match self {
-            Ok(ok) => variant.insert_variant(ResultTag::Ok, ok),
-            Err(err) => variant.insert_variant(ResultTag::Err, err),
+            Ok(ok) => {
+                cx.enter_variant("Ok", "Ok");
+                let output = variant.insert_variant(ResultTag::Ok, ok)?;
+                cx.leave_variant();
+                Ok(output)
+            }
+            Err(err) => {
+                cx.enter_variant("Err", "Err");
+                let output = variant.insert_variant(ResultTag::Err, err)?;
+                cx.leave_variant();
+                Ok(output)
+            }
         }
     }
 }
@@ -552,7 +716,7 @@ where
     ResultTag: Decode<'de, M>,
 {
     #[inline]
-    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
         D: Decoder<'de, Mode = M>,
     {
@@ -560,39 +724,82 @@ where
             let tag = variant.decode_tag()?.decode()?;
 
             Ok(match tag {
-                ResultTag::Ok => Ok(variant.decode_value()?.decode()?),
-                ResultTag::Err => Err(variant.decode_value()?.decode()?),
+                ResultTag::Ok => {
+                    cx.enter_variant("Ok", "Ok");
+                    let value = variant.decode_value()?.decode()?;
+                    cx.leave_variant();
+                    Ok(value)
+                }
+                ResultTag::Err => {
+                    cx.enter_variant("Err", "Err");
+                    let value = variant.decode_value()?.decode()?;
+                    cx.leave_variant();
+                    Err(value)
+                }
             })
         })
     }
 }
 
-impl<T, M> Encode<M> for Wrapping<T>
-where
-    T: Encode<M>,
-{
-    #[inline]
-    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
-    where
-        E: Encoder<Mode = M>,
-    {
-        self.0.encode(cx, encoder)
-    }
-}
+macro_rules! transparent_number {
+    ($ty:ident) => {
+        impl<T, M> Encode<M> for $ty<T>
+        where
+            T: Encode<M>,
+        {
+            #[inline]
+            fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                self.0.encode(cx, encoder)
+            }
+        }
 
-impl<'de, M, T> Decode<'de, M> for Wrapping<T>
-where
-    T: Decode<'de, M>,
-{
-    #[inline]
-    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
-    where
-        D: Decoder<'de, Mode = M>,
-    {
-        Ok(Wrapping(decoder.decode()?))
-    }
+        impl<'de, M, T> Decode<'de, M> for $ty<T>
+        where
+            T: Decode<'de, M>,
+        {
+            #[inline]
+            fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                Ok($ty(decoder.decode()?))
+            }
+        }
+
+        impl<T, M> EncodePacked<M> for $ty<T>
+        where
+            T: EncodePacked<M>,
+        {
+            #[inline]
+            fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                E: Encoder<Mode = M>,
+            {
+                self.0.encode_packed(cx, encoder)
+            }
+        }
+
+        impl<'de, M, T> DecodePacked<'de, M> for $ty<T>
+        where
+            T: DecodePacked<'de, M>,
+        {
+            #[inline]
+            fn decode_packed<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                Ok($ty(T::decode_packed(cx, decoder)?))
+            }
+        }
+    };
 }
 
+transparent_number!(Wrapping);
+transparent_number!(Saturating);
+
 impl<M> Encode<M> for CStr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -8,7 +8,6 @@ use crate::mode::{Binary, Text};
 
 #[derive(Encode, Decode)]
 #[musli(crate)]
-#[musli(mode = Text, name_all = "kebab-case")]
 enum IpAddrTag {
     Ipv4,
     Ipv6,
@@ -16,7 +15,6 @@ enum IpAddrTag {
 
 #[derive(Encode, Decode)]
 #[musli(crate)]
-#[musli(mode = Text, name_all = "kebab-case")]
 enum SocketAddrTag {
     V4,
     V6,
@@ -38,7 +36,7 @@ impl Encode<Text> for Ipv4Addr {
     where
         E: Encoder,
     {
-        encoder.collect_string(self)
+        encoder.encode_display(self)
     }
 }
 
@@ -78,7 +76,7 @@ impl Encode<Text> for Ipv6Addr {
     where
         E: Encoder<Mode = Text>,
     {
-        encoder.collect_string(self)
+        encoder.encode_display(self)
     }
 }
 
@@ -102,16 +100,11 @@ impl<'de> Decode<'de, Text> for Ipv6Addr {
     }
 }
 
-impl<M> Encode<M> for IpAddr
-where
-    IpAddrTag: Encode<M>,
-    Ipv4Addr: Encode<M>,
-    Ipv6Addr: Encode<M>,
-{
+impl Encode<Binary> for IpAddr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
-        E: Encoder<Mode = M>,
+        E: Encoder<Mode = Binary>,
     {
         let variant = encoder.encode_variant()?;
 
@@ -122,16 +115,21 @@ where
     }
 }
 
-impl<'de, M> Decode<'de, M> for IpAddr
-where
-    IpAddrTag: Decode<'de, M>,
-    Ipv4Addr: Decode<'de, M>,
-    Ipv6Addr: Decode<'de, M>,
-{
+impl Encode<Text> for IpAddr {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.encode_display(self)
+    }
+}
+
+impl<'de> Decode<'de, Binary> for IpAddr {
     #[inline]
     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
-        D: Decoder<'de, Mode = M>,
+        D: Decoder<'de, Mode = Binary>,
     {
         decoder.decode_variant(|variant| {
             let tag = variant.decode_tag()?.decode()?;
@@ -144,6 +142,16 @@ where
     }
 }
 
+impl<'de> Decode<'de, Text> for IpAddr {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        decoder.decode_unsized(|string: &str| IpAddr::from_str(string).map_err(cx.map()))
+    }
+}
+
 impl Encode<Binary> for SocketAddrV4 {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -164,7 +172,7 @@ impl Encode<Text> for SocketAddrV4 {
     where
         E: Encoder<Mode = Text>,
     {
-        encoder.collect_string(self)
+        encoder.encode_display(self)
     }
 }
 
@@ -210,7 +218,7 @@ impl Encode<Text> for SocketAddrV6 {
     where
         E: Encoder<Mode = Text>,
     {
-        encoder.collect_string(self)
+        encoder.encode_display(self)
     }
 }
 
@@ -234,16 +242,11 @@ impl<'de> Decode<'de, Text> for SocketAddrV6 {
     }
 }
 
-impl<M> Encode<M> for SocketAddr
-where
-    SocketAddrTag: Encode<M>,
-    SocketAddrV4: Encode<M>,
-    SocketAddrV6: Encode<M>,
-{
+impl Encode<Binary> for SocketAddr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
-        E: Encoder<Mode = M>,
+        E: Encoder<Mode = Binary>,
     {
         let variant = encoder.encode_variant()?;
 
@@ -254,16 +257,21 @@ where
     }
 }
 
-impl<'de, M> Decode<'de, M> for SocketAddr
-where
-    SocketAddrTag: Decode<'de, M>,
-    SocketAddrV4: Decode<'de, M>,
-    SocketAddrV6: Decode<'de, M>,
-{
+impl Encode<Text> for SocketAddr {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.encode_display(self)
+    }
+}
+
+impl<'de> Decode<'de, Binary> for SocketAddr {
     #[inline]
     fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
     where
-        D: Decoder<'de, Mode = M>,
+        D: Decoder<'de, Mode = Binary>,
     {
         decoder.decode_variant(|variant| {
             let tag = variant.decode_tag()?.decode()?;
@@ -276,6 +284,16 @@ where
     }
 }
 
+impl<'de> Decode<'de, Text> for SocketAddr {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        decoder.decode_unsized(|string: &str| SocketAddr::from_str(string).map_err(cx.map()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Decode, Encode};
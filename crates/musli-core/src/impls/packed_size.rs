@@ -0,0 +1,119 @@
+//! Implementations of [`PackedSize`] for core types.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::__priv::packed_size;
+use crate::en::PackedSize;
+
+macro_rules! sized {
+    ($($ty:ty => $size:expr),* $(,)?) => {
+        $(
+            impl PackedSize for $ty {
+                const PACKED_SIZE: Option<usize> = $size;
+            }
+        )*
+    };
+}
+
+sized! {
+    bool => Some(1),
+    char => Some(4),
+    u8 => Some(1),
+    u16 => Some(2),
+    u32 => Some(4),
+    u64 => Some(8),
+    u128 => Some(16),
+    i8 => Some(1),
+    i16 => Some(2),
+    i32 => Some(4),
+    i64 => Some(8),
+    i128 => Some(16),
+    f32 => Some(4),
+    f64 => Some(8),
+    NonZeroU8 => Some(1),
+    NonZeroU16 => Some(2),
+    NonZeroU32 => Some(4),
+    NonZeroU64 => Some(8),
+    NonZeroU128 => Some(16),
+    NonZeroI8 => Some(1),
+    NonZeroI16 => Some(2),
+    NonZeroI32 => Some(4),
+    NonZeroI64 => Some(8),
+    NonZeroI128 => Some(16),
+    // Pointer-sized integers are encoded like lengths, which are configured
+    // separately.
+    usize => None,
+    isize => None,
+    NonZeroUsize => None,
+    NonZeroIsize => None,
+    str => None,
+}
+
+#[cfg(feature = "alloc")]
+sized!(String => None);
+
+impl<T> PackedSize for [T] {
+    const PACKED_SIZE: Option<usize> = None;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PackedSize for Vec<T> {
+    const PACKED_SIZE: Option<usize> = None;
+}
+
+impl<T> PackedSize for Option<T> {
+    const PACKED_SIZE: Option<usize> = None;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PackedSize for Box<T>
+where
+    T: ?Sized + PackedSize,
+{
+    const PACKED_SIZE: Option<usize> = T::PACKED_SIZE;
+    const PACKED_FIELD_SIZE: Option<usize> = T::PACKED_FIELD_SIZE;
+}
+
+/// Arrays are prefixed with their length unless they are packed.
+impl<T, const N: usize> PackedSize for [T; N]
+where
+    T: PackedSize,
+{
+    const PACKED_SIZE: Option<usize> = None;
+
+    const PACKED_FIELD_SIZE: Option<usize> = match T::PACKED_SIZE {
+        Some(size) => size.checked_mul(N),
+        None => None,
+    };
+}
+
+macro_rules! tuple {
+    () => {};
+
+    ($ty0:ident $(, $ty:ident)* $(,)?) => {
+        /// Tuples are prefixed with their length unless they are packed.
+        impl<$ty0 $(, $ty)*> PackedSize for ($ty0, $($ty),*)
+        where
+            $ty0: PackedSize,
+            $($ty: PackedSize),*
+        {
+            const PACKED_SIZE: Option<usize> = None;
+
+            const PACKED_FIELD_SIZE: Option<usize> =
+                packed_size(&[$ty0::PACKED_SIZE $(, $ty::PACKED_SIZE)*]);
+        }
+
+        tuple!($($ty),*);
+    };
+}
+
+tuple!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
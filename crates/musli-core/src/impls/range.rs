@@ -1,50 +1,150 @@
-use core::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use core::any::TypeId;
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
-use crate::en::SequenceEncoder;
-use crate::hint::SequenceHint;
+use crate::context::Context;
+use crate::de::{EntryDecoder, MapDecoder, VariantDecoder};
+use crate::en::{MapEncoder, SequenceEncoder, VariantEncoder};
+use crate::hint::{MapHint, SequenceHint};
+use crate::mode::Text;
 use crate::{Decode, Decoder, Encode, Encoder};
 
-macro_rules! implement {
-    ($ty:ident $(<$type:ident>)? { $($field:ident),* }, $count:expr) => {
-        impl<M, $($type)*> Encode<M> for $ty $(<$type>)*
-        where
-            $($type: Encode<M>,)*
-        {
-            #[inline]
-            #[allow(unused)]
-            fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
-            where
-                E: Encoder<Mode = M>,
-            {
-                static HINT: SequenceHint = SequenceHint::with_size($count);
+/// The fields of an encoded range.
+enum RangeField {
+    Start,
+    End,
+    Unknown,
+}
 
-                encoder.encode_sequence_fn(&HINT, |tuple| {
-                    $(tuple.encode_next()?.encode(&self.$field)?;)*
-                    Ok(())
+/// The variants of an encoded bound.
+enum BoundTag {
+    Included,
+    Excluded,
+    Unbounded,
+}
+
+/// Test if ranges are encoded as a map in the given mode, which is only the
+/// case for [`Text`]. Every other mode encodes them as a sequence of the
+/// bounds which are present.
+#[inline]
+fn is_text<M>() -> bool
+where
+    M: 'static,
+{
+    TypeId::of::<M>() == TypeId::of::<Text>()
+}
+
+/// In the text mode, ranges are encoded as a map with a `start` and an `end`
+/// entry, where bounds which are absent are omitted.
+#[inline]
+fn encode_range<M, E, T>(encoder: E, start: Option<&T>, end: Option<&T>) -> Result<E::Ok, E::Error>
+where
+    E: Encoder<Mode = M>,
+    T: Encode<M>,
+{
+    let hint = MapHint::with_size(usize::from(start.is_some()) + usize::from(end.is_some()));
+
+    encoder.encode_map_fn(&hint, |map| {
+        if let Some(start) = start {
+            map.insert_entry("start", start)?;
+        }
+
+        if let Some(end) = end {
+            map.insert_entry("end", end)?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Decode the `start` and `end` entries of a range, skipping over any unknown
+/// entries.
+#[inline]
+fn decode_range<'de, M, D, T>(decoder: D) -> Result<(Option<T>, Option<T>), D::Error>
+where
+    D: Decoder<'de, Mode = M>,
+    T: Decode<'de, M>,
+{
+    decoder.decode_map(|map| {
+        let mut start = None;
+        let mut end = None;
+
+        while let Some(mut entry) = map.decode_entry()? {
+            let field = entry.decode_key()?.decode_unsized(|key: &str| {
+                Ok(match key {
+                    "start" => RangeField::Start,
+                    "end" => RangeField::End,
+                    _ => RangeField::Unknown,
                 })
+            })?;
+
+            match field {
+                RangeField::Start => start = Some(entry.decode_value()?.decode()?),
+                RangeField::End => end = Some(entry.decode_value()?.decode()?),
+                RangeField::Unknown => entry.decode_value()?.skip()?,
             }
         }
 
-        impl<'de, M, $($type)*> Decode<'de, M> for $ty $(<$type>)*
-        where
-            $($type: Decode<'de, M>,)*
-        {
-            #[inline]
-            fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
-            where
-                D: Decoder<'de, Mode = M>,
-            {
-                let ($($field,)*) = decoder.decode()?;
-                Ok($ty { $($field,)* })
-            }
+        Ok((start, end))
+    })
+}
+
+/// Require that a bound of a range is present.
+#[inline]
+fn required<C, T>(cx: &C, name: &'static str, field: &str, value: Option<T>) -> Result<T, C::Error>
+where
+    C: ?Sized + Context,
+{
+    match value {
+        Some(value) => Ok(value),
+        None => Err(cx.expected_tag(name, field)),
+    }
+}
+
+impl<M> Encode<M> for RangeFull
+where
+    M: 'static,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        if is_text::<M>() {
+            return encode_range::<M, E, ()>(encoder, None, None);
+        }
+
+        static HINT: SequenceHint = SequenceHint::with_size(0);
+        encoder.encode_sequence_fn(&HINT, |_| Ok(()))
+    }
+}
+
+impl<'de, M> Decode<'de, M> for RangeFull
+where
+    M: 'static,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        if is_text::<M>() {
+            decode_range::<M, D, ()>(decoder)?;
+            return Ok(..);
         }
+
+        decoder.decode_sequence(|_| Ok(..))
     }
 }
 
-macro_rules! implement_new {
-    ($ty:ident { $($field:ident),* }, $count:expr) => {
+macro_rules! implement {
+    (
+        $ty:ident { $($field:ident),* }, $count:expr,
+        |$this:ident| $encode:expr,
+        |$cx:ident, $start:ident, $end:ident| $decode:expr
+    ) => {
         impl<M, T> Encode<M> for $ty<T>
         where
+            M: 'static,
             T: Encode<M>,
         {
             #[inline]
@@ -52,10 +152,16 @@ macro_rules! implement_new {
             where
                 E: Encoder<Mode = M>,
             {
+                if is_text::<M>() {
+                    let $this = self;
+                    let (start, end) = $encode;
+                    return encode_range(encoder, start, end);
+                }
+
                 static HINT: SequenceHint = SequenceHint::with_size($count);
 
                 encoder.encode_sequence_fn(&HINT, |tuple| {
-                    $(tuple.encode_next()?.encode(self.$field())?;)*
+                    $(tuple.encode_next()?.encode(&self.$field)?;)*
                     Ok(())
                 })
             }
@@ -63,23 +169,149 @@ macro_rules! implement_new {
 
         impl<'de, M, T> Decode<'de, M> for $ty<T>
         where
+            M: 'static,
             T: Decode<'de, M>,
         {
             #[inline]
-            fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            fn decode<D>($cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
             where
                 D: Decoder<'de, Mode = M>,
             {
-                let ($($field,)*) = Decode::decode(cx, decoder)?;
-                Ok($ty::new($($field,)*))
+                if is_text::<M>() {
+                    let ($start, $end) = decode_range(decoder)?;
+                    return $decode;
+                }
+
+                let ($($field,)*) = decoder.decode()?;
+                Ok($ty { $($field,)* })
             }
         }
+    };
+}
+
+implement! {
+    Range { start, end }, 2,
+    |this| (Some(&this.start), Some(&this.end)),
+    |cx, start, end| {
+        let start = required(cx, "Range", "start", start)?;
+        let end = required(cx, "Range", "end", end)?;
+        Ok(start..end)
     }
 }
 
-implement!(RangeFull {}, 0);
-implement!(Range<T> { start, end }, 2);
-implement!(RangeFrom<T> { start }, 1);
-implement!(RangeTo<T> { end }, 1);
-implement!(RangeToInclusive<T> { end }, 1);
-implement_new!(RangeInclusive { start, end }, 2);
+implement! {
+    RangeFrom { start }, 1,
+    |this| (Some(&this.start), None),
+    |cx, start, _end| Ok(required(cx, "RangeFrom", "start", start)?..)
+}
+
+implement! {
+    RangeTo { end }, 1,
+    |this| (None, Some(&this.end)),
+    |cx, _start, end| Ok(..required(cx, "RangeTo", "end", end)?)
+}
+
+implement! {
+    RangeToInclusive { end }, 1,
+    |this| (None, Some(&this.end)),
+    |cx, _start, end| Ok(..=required(cx, "RangeToInclusive", "end", end)?)
+}
+
+/// Only the bounds of the range are encoded, so a range which has been
+/// exhausted by iteration is not preserved as such.
+impl<M, T> Encode<M> for RangeInclusive<T>
+where
+    M: 'static,
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        if is_text::<M>() {
+            return encode_range(encoder, Some(self.start()), Some(self.end()));
+        }
+
+        static HINT: SequenceHint = SequenceHint::with_size(2);
+
+        encoder.encode_sequence_fn(&HINT, |tuple| {
+            tuple.encode_next()?.encode(self.start())?;
+            tuple.encode_next()?.encode(self.end())?;
+            Ok(())
+        })
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for RangeInclusive<T>
+where
+    M: 'static,
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let (start, end) = if is_text::<M>() {
+            let (start, end) = decode_range(decoder)?;
+            let start = required(cx, "RangeInclusive", "start", start)?;
+            let end = required(cx, "RangeInclusive", "end", end)?;
+            (start, end)
+        } else {
+            Decode::decode(cx, decoder)?
+        };
+
+        Ok(RangeInclusive::new(start, end))
+    }
+}
+
+/// A [`Bound`] is encoded as a variant tagged with the name of the variant,
+/// where `Unbounded` has an empty value.
+impl<M, T> Encode<M> for Bound<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        match self {
+            Bound::Included(value) => encoder.encode_variant()?.insert_variant("Included", value),
+            Bound::Excluded(value) => encoder.encode_variant()?.insert_variant("Excluded", value),
+            Bound::Unbounded => encoder.encode_unit_variant("Unbounded"),
+        }
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Bound<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_variant(|variant| {
+            let tag = variant.decode_tag()?.decode_unsized(|tag: &str| {
+                Ok(match tag {
+                    "Included" => BoundTag::Included,
+                    "Excluded" => BoundTag::Excluded,
+                    "Unbounded" => BoundTag::Unbounded,
+                    tag => return Err(cx.invalid_variant_tag("Bound", tag)),
+                })
+            })?;
+
+            Ok(match tag {
+                BoundTag::Included => Bound::Included(variant.decode_value()?.decode()?),
+                BoundTag::Excluded => Bound::Excluded(variant.decode_value()?.decode()?),
+                BoundTag::Unbounded => {
+                    variant.decode_value()?.decode::<()>()?;
+                    Bound::Unbounded
+                }
+            })
+        })
+    }
+}
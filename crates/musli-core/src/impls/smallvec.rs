@@ -0,0 +1,115 @@
+use smallvec::{Array, SmallVec};
+
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, EncodePacked, Encoder, SequenceEncoder};
+use crate::hint::SequenceHint;
+use crate::internal::size_hint;
+use crate::Context;
+
+impl<M, A> Encode<M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let hint = SequenceHint::with_size(self.len());
+
+        encoder.encode_sequence_fn(&hint, |seq| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                seq.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Decoding spills over onto the heap if the sequence doesn't fit inline.
+impl<'de, M, A> Decode<'de, M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            cx.check_sequence_len(seq.size_hint().or_default())?;
+
+            let mut out = SmallVec::with_capacity(size_hint::cautious(seq.size_hint()));
+            let mut index = 0;
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+                cx.check_sequence_len(index.wrapping_add(1))?;
+                cx.consume_elements(1)?;
+                out.push(A::Item::decode(cx, value)?);
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(out)
+        })
+    }
+
+    #[inline]
+    fn decode_in_place<D>(cx: &D::Cx, decoder: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        place.clear();
+
+        decoder.decode_sequence(|seq| {
+            cx.check_sequence_len(seq.size_hint().or_default())?;
+
+            let mut index = 0;
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+                cx.check_sequence_len(index.wrapping_add(1))?;
+                cx.consume_elements(1)?;
+                place.push(A::Item::decode(cx, value)?);
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<M, A> EncodePacked<M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::{Mutex, RwLock};
+
+use crate::{Context, Decode, Decoder, Encode, Encoder};
+
+/// Encoding a [`Mutex`] locks it, and results in an error if it is poisoned.
+impl<M, T> Encode<M> for Mutex<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let Ok(value) = self.lock() else {
+            return Err(cx.message("Mutex is poisoned"));
+        };
+
+        value.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Mutex<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(Mutex::new)
+    }
+}
+
+/// Encoding a [`RwLock`] acquires a read lock, and results in an error if it
+/// is poisoned.
+impl<M, T> Encode<M> for RwLock<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let Ok(value) = self.read() else {
+            return Err(cx.message("RwLock is poisoned"));
+        };
+
+        value.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for RwLock<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(RwLock::new)
+    }
+}
@@ -0,0 +1,137 @@
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::context::Context;
+use crate::en::SequenceEncoder;
+use crate::hint::SequenceHint;
+use crate::{Decode, Decoder, Encode, Encoder};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+static HINT: SequenceHint = SequenceHint::with_size(2);
+
+/// Encode seconds and nanoseconds as a pair.
+#[inline]
+pub(super) fn encode_pair<M, E, S>(encoder: E, secs: S, nanos: u32) -> Result<E::Ok, E::Error>
+where
+    E: Encoder<Mode = M>,
+    S: Encode<M>,
+{
+    encoder.encode_sequence_fn(&HINT, |seq| {
+        seq.encode_next()?.encode(secs)?;
+        seq.encode_next()?.encode(nanos)?;
+        Ok(())
+    })
+}
+
+/// Decode seconds and nanoseconds as a pair, validating the nanoseconds.
+#[inline]
+pub(super) fn decode_pair<'de, M, D, S>(cx: &D::Cx, decoder: D) -> Result<(S, u32), D::Error>
+where
+    D: Decoder<'de, Mode = M>,
+    S: Decode<'de, M>,
+{
+    let mark = cx.mark();
+    let (secs, nanos): (S, u32) = decoder.decode()?;
+
+    if nanos >= NANOS_PER_SEC {
+        return Err(cx.marked_message(
+            mark,
+            format_args!("Nanoseconds {nanos} out of range, expected less than {NANOS_PER_SEC}"),
+        ));
+    }
+
+    Ok((secs, nanos))
+}
+
+/// A [`Duration`] is encoded as a sequence of whole seconds as a [`u64`]
+/// followed by the subsecond nanoseconds as a [`u32`].
+impl<M> Encode<M> for Duration {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encode_pair(encoder, self.as_secs(), self.subsec_nanos())
+    }
+}
+
+impl<'de, M> Decode<'de, M> for Duration {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let (secs, nanos) = decode_pair::<M, D, u64>(cx, decoder)?;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+/// A [`SystemTime`] is encoded as a sequence of whole seconds relative to
+/// [`UNIX_EPOCH`] as an [`i64`], followed by the subsecond nanoseconds as a
+/// [`u32`].
+///
+/// The nanoseconds always count forward, so a time half a second before the
+/// epoch is encoded as `-1` seconds and `500_000_000` nanoseconds.
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<M> Encode<M> for SystemTime {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let (secs, nanos) = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => (i64::try_from(after.as_secs()).ok(), after.subsec_nanos()),
+            Err(error) => {
+                let before = error.duration();
+                let secs = i64::try_from(before.as_secs()).ok();
+
+                match before.subsec_nanos() {
+                    0 => (secs.and_then(i64::checked_neg), 0),
+                    nanos => (
+                        secs.and_then(|secs| secs.checked_neg()?.checked_sub(1)),
+                        NANOS_PER_SEC - nanos,
+                    ),
+                }
+            }
+        };
+
+        let Some(secs) = secs else {
+            return Err(cx.message("SystemTime out of range for encoding"));
+        };
+
+        encode_pair(encoder, secs, nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+impl<'de, M> Decode<'de, M> for SystemTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mark = cx.mark();
+        let (secs, nanos) = decode_pair::<M, D, i64>(cx, decoder)?;
+
+        let time = if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::new(secs.unsigned_abs(), nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+                .and_then(|time| time.checked_add(Duration::from_nanos(u64::from(nanos))))
+        };
+
+        match time {
+            Some(time) => Ok(time),
+            None => Err(cx.marked_message(
+                mark,
+                format_args!("SystemTime {secs}s {nanos}ns is out of range"),
+            )),
+        }
+    }
+}
@@ -3,6 +3,7 @@
 use crate::de::{Decode, DecodePacked, Decoder, SequenceDecoder};
 use crate::en::{Encode, EncodePacked, Encoder, SequenceEncoder};
 use crate::hint::SequenceHint;
+use crate::Context;
 
 macro_rules! count {
     (_) => { 1 };
@@ -67,15 +68,58 @@ macro_rules! declare {
             $($ty: Decode<'de, M>),*
         {
             #[inline]
-            fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
             where
                 D: Decoder<'de, Mode = M>,
             {
-                static HINT: SequenceHint = SequenceHint::with_size(count!($ident0 $($ident)*));
+                const LEN: usize = count!($ident0 $($ident)*);
+                static HINT: SequenceHint = SequenceHint::with_size(LEN);
+
+                let mark = cx.mark();
+
+                let mismatch = |len: usize| {
+                    cx.marked_message(
+                        mark,
+                        format_args!(
+                            "Tuple with length {len} does not have the expected {LEN} number of elements"
+                        ),
+                    )
+                };
 
                 decoder.decode_sequence_hint(&HINT, |tuple| {
-                    let $ident0 = tuple.next()?;
-                    $(let $ident = tuple.next()?;)*
+                    if let Some(len) = tuple.size_hint().into_option() {
+                        if len != LEN {
+                            return Err(mismatch(len));
+                        }
+                    }
+
+                    let mut len = 0;
+
+                    let Some($ident0) = tuple.try_next()? else {
+                        return Err(mismatch(len));
+                    };
+
+                    len += 1;
+
+                    $(
+                        let Some($ident) = tuple.try_next()? else {
+                            return Err(mismatch(len));
+                        };
+
+                        len += 1;
+                    )*
+
+                    // Skip over any excess elements so that the actual length
+                    // can be reported.
+                    while let Some(item) = tuple.try_decode_next()? {
+                        item.skip()?;
+                        len += 1;
+                    }
+
+                    if len != LEN {
+                        return Err(mismatch(len));
+                    }
+
                     Ok(($ident0, $($ident),*))
                 })
             }
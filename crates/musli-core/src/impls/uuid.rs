@@ -0,0 +1,47 @@
+use uuid::Uuid;
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder};
+use crate::en::{Encode, Encoder};
+use crate::mode::{Binary, Text};
+
+impl Encode<Binary> for Uuid {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_array(self.as_bytes())
+    }
+}
+
+impl Encode<Text> for Uuid {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        let mut buf = Uuid::encode_buffer();
+        encoder.encode_string(self.hyphenated().encode_lower(&mut buf))
+    }
+}
+
+impl<'de> Decode<'de, Binary> for Uuid {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        decoder.decode_array::<16>().map(Uuid::from_bytes)
+    }
+}
+
+impl<'de> Decode<'de, Text> for Uuid {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| Uuid::try_parse(string).map_err(cx.map()))
+    }
+}
@@ -19,7 +19,7 @@ extern crate std;
 
 mod context;
 #[doc(inline)]
-pub use self::context::Context;
+pub use self::context::{Checkpoint, Context};
 
 mod allocator;
 #[doc(inline)]
@@ -31,7 +31,7 @@ pub use self::buf::Buf;
 
 pub mod de;
 #[doc(inline)]
-pub use self::de::{Decode, Decoder};
+pub use self::de::{Decode, DecodeOwned, Decoder};
 
 pub mod en;
 #[doc(inline)]
@@ -42,9 +42,11 @@ pub mod mode;
 pub mod no_std;
 
 mod expecting;
+mod flatten;
 mod impls;
 mod internal;
 mod never;
+mod remote;
 
 /// This is an attribute macro that must be used when implementing a
 /// [`Encoder`].
@@ -195,11 +197,16 @@ pub mod __priv {
     use crate::context::Context;
     use crate::de::{Decoder, EntryDecoder};
 
+    pub use ::core::convert::From;
     pub use ::core::fmt;
     pub use ::core::option::Option;
     pub use ::core::result::Result;
 
+    pub use crate::flatten::{
+        DecodeNames, EncodeNames, FlattenDecoder, FlattenEncoder, FlattenLen, Name, Names,
+    };
     pub use crate::never::Never;
+    pub use crate::remote::{DecodeRemote, EncodeRemote};
 
     #[inline(always)]
     pub fn write<O, T>(out: &mut O, value: T) -> Result<(), crate::buf::Error>
@@ -218,6 +225,38 @@ pub mod __priv {
         ::core::default::Default::default()
     }
 
+    /// Compare a decoded name against a candidate, ignoring ASCII case.
+    #[inline(always)]
+    pub fn eq_ignore_ascii_case<A, B>(a: &A, b: &B) -> bool
+    where
+        A: ?Sized + AsRef<[u8]>,
+        B: ?Sized + AsRef<[u8]>,
+    {
+        a.as_ref().eq_ignore_ascii_case(b.as_ref())
+    }
+
+    /// Sum up the packed sizes of fields, which is `None` if any of them is
+    /// `None`.
+    pub const fn packed_size(sizes: &[Option<usize>]) -> Option<usize> {
+        let mut total = 0usize;
+        let mut index = 0;
+
+        while index < sizes.len() {
+            let Some(size) = sizes[index] else {
+                return None;
+            };
+
+            let Some(next) = total.checked_add(size) else {
+                return None;
+            };
+
+            total = next;
+            index += 1;
+        }
+
+        Some(total)
+    }
+
     /// Note that this returns `true` if skipping was unsupported.
     #[inline(always)]
     pub fn skip<'de, D>(decoder: D) -> Result<bool, D::Error>
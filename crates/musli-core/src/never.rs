@@ -256,7 +256,7 @@ impl<'de, C: ?Sized + Context> SequenceDecoder<'de> for Never<(), C> {
     }
 }
 
-impl<C: ?Sized + Context, O: 'static> Encoder for Never<O, C> {
+impl<C: ?Sized + Context, O> Encoder for Never<O, C> {
     type Cx = C;
     type Error = C::Error;
     type Ok = O;
@@ -311,7 +311,7 @@ where
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeNext<'this> = Self where Self: 'this;
@@ -327,7 +327,7 @@ impl<O: 'static, C: ?Sized + Context> SequenceEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> MapEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> MapEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeEntry<'this> = Self where Self: 'this;
@@ -342,7 +342,7 @@ impl<O: 'static, C: ?Sized + Context> MapEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> EntryEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> EntryEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeKey<'this> = Self where Self: 'this;
@@ -364,7 +364,7 @@ impl<O: 'static, C: ?Sized + Context> EntryEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeEntryKey<'this> = Self where Self: 'this;
@@ -386,7 +386,7 @@ impl<O: 'static, C: ?Sized + Context> EntriesEncoder for Never<O, C> {
     }
 }
 
-impl<O: 'static, C: ?Sized + Context> VariantEncoder for Never<O, C> {
+impl<O, C: ?Sized + Context> VariantEncoder for Never<O, C> {
     type Cx = C;
     type Ok = O;
     type EncodeTag<'this> = Self where Self: 'this;
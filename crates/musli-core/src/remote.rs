@@ -0,0 +1,26 @@
+//! Traits used to implement `#[musli(remote = ..)]`.
+//!
+//! This is a private module of musli, and is not intended for use outside of
+//! the derives.
+
+use crate::de::Decoder;
+use crate::en::Encoder;
+
+/// Encode the remote type `T` through the mirror type implementing this trait.
+pub trait EncodeRemote<M, T>
+where
+    T: ?Sized,
+{
+    /// Encode the given remote value.
+    fn encode_remote<E>(value: &T, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>;
+}
+
+/// Decode the remote type `T` through the mirror type implementing this trait.
+pub trait DecodeRemote<'de, M, T> {
+    /// Decode the remote value.
+    fn decode_remote<D>(cx: &D::Cx, decoder: D) -> Result<T, D::Error>
+    where
+        D: Decoder<'de, Mode = M>;
+}
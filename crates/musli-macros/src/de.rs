@@ -1,10 +1,12 @@
+use std::iter;
+
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Token;
 
-use crate::expander::{NameMethod, StructKind};
+use crate::expander::{Expander, NameMethod, StructKind};
 use crate::internals::apply;
 use crate::internals::attr::{EnumTagging, Packing};
 use crate::internals::build::{Body, Build, BuildData, Enum, Field, Variant};
@@ -14,9 +16,13 @@ use crate::internals::Result;
 struct Ctxt<'a> {
     ctx_var: &'a Ident,
     decoder_var: &'a Ident,
+    d_param: &'a Ident,
     name_var: &'a Ident,
     trace: bool,
     trace_body: bool,
+    /// Decode into the existing value behind this variable, rather than
+    /// constructing a new one.
+    place: Option<&'a Ident>,
 }
 
 pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
@@ -31,9 +37,11 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
     let cx = Ctxt {
         ctx_var: &ctx_var,
         decoder_var: &root_decoder_var,
+        d_param: &d_param,
         name_var: &tag_var,
         trace: true,
         trace_body: true,
+        place: None,
     };
 
     let body = match &e.data {
@@ -41,6 +49,24 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
         BuildData::Enum(en) => decode_enum(&cx, &e, en)?,
     };
 
+    // Structs decoded from a map can reuse the existing values of their
+    // fields, anything else uses the default implementation.
+    let place_var = e.cx.ident("place");
+
+    let in_place_body = match &e.data {
+        BuildData::Struct(st) if e.remote.is_none() && supports_in_place(st) => {
+            let cx = Ctxt {
+                place: Some(&place_var),
+                ..cx
+            };
+
+            let body = decode_tagged(&cx, &e, st, None)?;
+            let result_ok = &e.tokens.result_ok;
+            Some(quote!(#result_ok({ #body })))
+        }
+        _ => None,
+    };
+
     if e.cx.has_errors() {
         return Err(());
     }
@@ -65,18 +91,39 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
     let Tokens {
         context_t,
         result,
+        decode_remote_t,
         decode_t,
         decoder_t,
+        trace_decode_t,
         ..
     } = e.tokens;
 
-    if !e.bounds.is_empty() && !e.decode_bounds.is_empty() {
+    if !e.bounds.is_empty() || !e.decode_bounds.is_empty() {
         generics.make_where_clause().predicates.extend(
             e.bounds
                 .iter()
                 .chain(e.decode_bounds.iter())
                 .map(|(_, v)| v.clone()),
         );
+    } else {
+        let bounds = e.infer_decode_bounds(&lt.lifetime);
+
+        if !bounds.is_empty() {
+            generics.make_where_clause().predicates.extend(bounds);
+        }
+    }
+
+    let mode_ident = e.expansion.mode_path(e.tokens).as_path();
+
+    // Flattened fields are decoded from the entries which are unknown to the
+    // container, which is determined through its names.
+    if crate::en::has_flattened(&e) {
+        let priv_decode_names = &e.tokens.priv_decode_names;
+
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(Self: #priv_decode_names<#mode_ident>));
     }
 
     let (impl_generics, _, where_clause) = generics.split_for_impl();
@@ -86,9 +133,41 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
 
     if cfg!(not(feature = "verbose")) {
         attributes.push(syn::parse_quote!(#[allow(clippy::just_underscores_and_digits)]));
+    } else {
+        // Verbose identifiers are derived from field names, which might not be
+        // snake case.
+        attributes.push(syn::parse_quote!(#[allow(non_snake_case)]));
     }
 
-    let mode_ident = e.expansion.mode_path(e.tokens).as_path();
+    let decode_in_place = in_place_body.map(|body| {
+        quote! {
+            #[inline]
+            fn decode_in_place<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param, #place_var: &mut Self) -> #result<(), <#d_param::Cx as #context_t>::Error>
+            where
+                #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+            {
+                #body
+            }
+        }
+    });
+
+    if let Some(remote) = e.remote {
+        return Ok(quote! {
+            const _: () = {
+                #[automatically_derived]
+                #(#attributes)*
+                impl #impl_generics #decode_remote_t<#lt, #mode_ident, #remote> for #type_ident #type_generics #where_clause {
+                    #[inline]
+                    fn decode_remote<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<#remote, <#d_param::Cx as #context_t>::Error>
+                    where
+                        #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+                    {
+                        #body
+                    }
+                }
+            };
+        });
+    }
 
     Ok(quote! {
         const _: () = {
@@ -102,11 +181,154 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
                 {
                     #body
                 }
+
+                #decode_in_place
+            }
+
+            // Derived types trace their own fields, so they can be traced
+            // inside of a container like any other value.
+            #[automatically_derived]
+            impl #impl_generics #trace_decode_t<#lt, #mode_ident> for #type_ident #type_generics #where_clause {
+                #[inline]
+                fn trace_decode<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<Self, <#d_param::Cx as #context_t>::Error>
+                where
+                    #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+                {
+                    <Self as #decode_t<#lt, #mode_ident>>::decode(#ctx_var, #root_decoder_var)
+                }
             }
         };
     })
 }
 
+/// Expand the names of the fields of a struct which is decoded from a map,
+/// which allows it to be flattened.
+///
+/// This also tests if a key names one of its fields, so that only the entries
+/// which are unknown to the struct are decoded by its flattened fields.
+pub(crate) fn expand_decode_names(e: &Build<'_>) -> Result<TokenStream> {
+    let st = match &e.data {
+        BuildData::Struct(st) if st.packing == Packing::Tagged => st,
+        _ => return Ok(TokenStream::new()),
+    };
+
+    let Tokens {
+        context_t,
+        decoder_t,
+        priv_decode_names,
+        result,
+        result_ok,
+        ..
+    } = e.tokens;
+
+    let ctx_var = e.cx.ident("ctx");
+    let decoder_var = e.cx.ident("decoder");
+    let name_var = e.cx.ident("name");
+    let d_param = e.cx.type_with_span("D", Span::call_site());
+    // The lifetime of the key is separate from any lifetime of the type.
+    let lt = syn::Lifetime::new("'__de", Span::call_site());
+
+    let fields = st
+        .unskipped_fields
+        .iter()
+        .filter(|f| f.flatten.is_none())
+        .collect::<Vec<_>>();
+
+    let body = match st.name_method {
+        NameMethod::Value => {
+            let arms = fields
+                .iter()
+                .map(|f| output_arm(f.pattern, &f.name, f.aliases, &name_var, None));
+
+            let name_type = &st.name_type;
+            let decode_t_decode = &e.decode_t_decode;
+
+            quote! {
+                let #name_var: #name_type = #decode_t_decode(#ctx_var, #decoder_var)?;
+                #result_ok(match #name_var { #(#arms => true,)* _ => false })
+            }
+        }
+        NameMethod::Unsized(method) => {
+            let fold = st
+                .name_case_insensitive
+                .map(|_| &e.tokens.priv_eq_ignore_ascii_case);
+
+            let arms = fields
+                .iter()
+                .map(|f| output_arm(f.pattern, &f.name, f.aliases, &name_var, fold));
+
+            let visit_type = &st.name_type;
+            let method = method.as_method_name();
+
+            quote! {
+                #decoder_t::#method(#decoder_var, |#name_var: &#visit_type| {
+                    #result_ok(match #name_var { #(#arms => true,)* _ => false })
+                })
+            }
+        }
+    };
+
+    let mode_ident = e.expansion.mode_path(e.tokens).as_path();
+
+    let is_known = quote! {
+        #[inline]
+        fn is_known<#lt, #d_param>(#ctx_var: &#d_param::Cx, #decoder_var: #d_param) -> #result<bool, <#d_param::Cx as #context_t>::Error>
+        where
+            #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+        {
+            #body
+        }
+    };
+
+    Ok(e.expand_names(st, priv_decode_names, true, is_known))
+}
+
+/// Expand the `decode` function of a mirror type implemented through
+/// `#[musli(remote = ..)]`, which can be used with `#[musli(with = ..)]`.
+pub(crate) fn expand_remote_decode(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    e.cx.reset();
+
+    let type_ident = &e.input.ident;
+
+    let ctx_var = e.cx.ident("ctx");
+    let decoder_var = e.cx.ident("decoder");
+    let d_param = e.cx.type_with_span("D", Span::call_site());
+    let lt = syn::Lifetime::new("'de", Span::mixed_site());
+
+    let Tokens {
+        context_t,
+        decode_remote_t,
+        decoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #type_ident #type_generics #where_clause {
+            /// Decode the remote type through this mirror type.
+            #[inline]
+            pub fn decode<#lt, #d_param>(#ctx_var: &#d_param::Cx, #decoder_var: #d_param) -> #result<#remote, <#d_param::Cx as #context_t>::Error>
+            where
+                #d_param: #decoder_t<#lt>,
+                Self: #decode_remote_t<#lt, #d_param::Mode, #remote>,
+            {
+                <Self as #decode_remote_t<#lt, #d_param::Mode, #remote>>::decode_remote(#ctx_var, #decoder_var)
+            }
+        }
+    }
+}
+
+/// Test if the given struct can be decoded in place, which requires it to be
+/// decoded from a map without any flattened fields.
+fn supports_in_place(st: &Body<'_>) -> bool {
+    !matches!(st.kind, StructKind::Empty)
+        && st.packing == Packing::Tagged
+        && st.unskipped_fields.iter().all(|f| f.flatten.is_none())
+}
+
 fn decode_struct(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Tokens { result_ok, .. } = b.tokens;
 
@@ -123,8 +345,8 @@ fn decode_struct(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStr
 fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     let Ctxt {
         ctx_var,
-        name_var,
         decoder_var,
+        name_var,
         ..
     } = *cx;
 
@@ -133,17 +355,19 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
         context_t,
         decoder_t,
         fmt,
+        from_t,
         option_none,
         option_some,
         option,
         priv_write,
         result_err,
         result_ok,
+        result,
         skip_field,
         skip,
         map_decoder_t,
-        struct_field_decoder_t,
         map_hint,
+        struct_field_decoder_t,
         variant_decoder_t,
         ..
     } = b.tokens;
@@ -171,40 +395,34 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     let outcome_type = b.cx.type_with_span("Outcome", Span::call_site());
     let outcome_var = b.cx.ident("outcome");
     let output_var = b.cx.ident("output");
-    let struct_decoder_var = b.cx.ident("struct_decoder");
     let struct_hint_static = b.cx.ident("STRUCT_HINT");
     let struct_var = b.cx.ident("st");
     let value_var = b.cx.ident("value");
     let variant_decoder_var = b.cx.ident("variant_decoder");
     let variant_tag_var = b.cx.ident("variant_tag");
+    let other_var = b.cx.ident("other");
     let tag_static = b.cx.ident("TAG");
     let content_static = b.cx.ident("CONTENT");
 
     let mut output_arms = Vec::new();
 
-    let mut fallback = match en.fallback {
-        Some(ident) => {
-            quote! {{
-                if #skip(#variant_decoder_t::decode_value(#variant_decoder_var)?)? {
-                    return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, &#variant_tag_var));
-                }
-
-                Self::#ident {}
-            }}
-        }
-        None => quote! {
-            return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, &#variant_tag_var))
-        },
-    };
+    // The field of the fallback variant which captures unknown tags.
+    let capture = en.fallback.as_ref().and_then(|f| f.capture.as_deref());
 
     let decode_name;
     let output_enum;
     let name_type;
 
+    // The pattern matching tags which do not belong to any variant, how such a
+    // tag is reported in errors, and how it is stored in the fallback variant.
+    let unknown_pat;
+    let unknown_tag;
+    let captured;
+
     match en.name_method {
         NameMethod::Value => {
-            for v in &en.variants {
-                let arm = output_arm(v.pattern, &v.name, &binding_var);
+            for v in en.variants.iter().filter(|v| v.capture.is_none()) {
+                let arm = output_arm(v.pattern, &v.name, v.aliases, &binding_var, None);
                 output_arms.push((v, arm, &v.name));
             }
 
@@ -212,31 +430,70 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
             decode_name = quote!(#decode_t_decode(#ctx_var, #variant_decoder_var));
             output_enum = None;
-            fallback = quote!(_ => #fallback);
             name_type = en.name_type.clone();
+
+            if capture.is_some() {
+                unknown_pat = quote!(#other_var);
+                unknown_tag = quote!(&#other_var);
+                captured = quote!(#from_t::from(#other_var));
+            } else {
+                unknown_pat = quote!(_);
+                unknown_tag = quote!(&#variant_tag_var);
+                captured = TokenStream::new();
+            }
         }
         NameMethod::Unsized(method) => {
             let mut variants = Vec::new();
             let output_type = b.cx.type_with_span("VariantTag", en.span);
 
-            for v in &en.variants {
-                let (pat, variant) =
-                    unsized_arm(b, v.span, v.index, &v.name, v.pattern, &output_type);
+            // Unknown tags which are captured are passed along as an error,
+            // so known tags are wrapped in `Ok` instead of `Some`.
+            let known = if capture.is_some() {
+                result_ok
+            } else {
+                option_some
+            };
+
+            for v in en.variants.iter().filter(|v| v.capture.is_none()) {
+                let (pat, variant) = unsized_arm(
+                    b,
+                    v.span,
+                    v.index,
+                    &v.name,
+                    v.aliases,
+                    v.pattern,
+                    &output_type,
+                    known,
+                );
 
                 output_arms.push((v, OutputArm { pat, cond: None }, &v.name));
                 variants.push(variant);
             }
 
-            let arms = variants.iter().map(|o| o.as_arm(&binding_var, option_some));
+            let fold = en
+                .name_case_insensitive
+                .map(|_| &b.tokens.priv_eq_ignore_ascii_case);
+
+            let arms = variants.iter().map(|o| o.as_arm(&binding_var, known, fold));
 
             let visit_type = &en.name_type;
             let method = method.as_method_name();
 
+            // Without a fallback variant we report the unknown tag while we
+            // still have access to it.
+            let unknown = match (&en.fallback, capture) {
+                (_, Some(..)) => quote!(#value_var => #result_err(#from_t::from(#value_var))),
+                (Some(..), None) => quote!(_ => #option_none),
+                (None, _) => quote! {
+                    #value_var => return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, #value_var))
+                },
+            };
+
             decode_name = quote! {
                 #decoder_t::#method(#variant_decoder_var, |#value_var: &#visit_type| {
                     #result_ok(match #value_var {
                         #(#arms,)*
-                        _ => #option_none,
+                        #unknown,
                     })
                 })
             };
@@ -273,24 +530,69 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 }
             });
 
-            fallback = quote!(#option_none => { #fallback });
-            name_type = syn::parse_quote!(#option<#output_type>);
+            match capture {
+                Some(field) => {
+                    let ty = field.ty;
+                    unknown_pat = quote!(#result_err(#other_var));
+                    unknown_tag = quote!(&#other_var);
+                    captured = quote!(#other_var);
+                    name_type = syn::parse_quote!(#result<#output_type, #ty>);
+                }
+                None => {
+                    unknown_pat = quote!(#option_none);
+                    unknown_tag = quote!(&#variant_tag_var);
+                    captured = TokenStream::new();
+                    name_type = syn::parse_quote!(#option<#output_type>);
+                }
+            }
         }
     }
 
+    // Construct the fallback variant for an unknown tag, or report it if there
+    // is none. Any `content` belonging to the unknown variant is skipped.
+    let fallback = |content: Option<TokenStream>| match &en.fallback {
+        Some(fallback) => {
+            let skip = content.map(|content| {
+                quote! {
+                    if #skip(#content)? {
+                        return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, #unknown_tag));
+                    }
+                }
+            });
+
+            let construct = fallback.construct(&captured);
+
+            quote!({
+                #skip
+                #construct
+            })
+        }
+        None => quote! {
+            return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, #unknown_tag))
+        },
+    };
+
     match en.enum_tagging {
         EnumTagging::Empty => {
             let mut arms = Vec::new();
 
+            let fold = match en.name_method {
+                NameMethod::Unsized(..) => en
+                    .name_case_insensitive
+                    .map(|_| &b.tokens.priv_eq_ignore_ascii_case),
+                NameMethod::Value => None,
+            };
+
             for v in &en.variants {
                 let path = &v.st.path;
-                let pat = output_arm(v.pattern, &v.name, &binding_var);
+                let pat = output_arm(v.pattern, &v.name, v.aliases, &binding_var, fold);
                 arms.push(quote!(#pat => #result_ok(#path {})));
             }
 
-            match en.fallback {
-                Some(ident) => {
-                    arms.push(quote!(_ => #result_ok(Self::#ident {})));
+            match &en.fallback {
+                Some(fallback) => {
+                    let construct = fallback.construct(TokenStream::new());
+                    arms.push(quote!(_ => #result_ok(#construct)));
                 }
                 None => {
                     arms.push(quote!(#value_var => #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, &#value_var))));
@@ -321,6 +623,10 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             }
         }
         EnumTagging::Default => {
+            let fallback = fallback(Some(
+                quote!(#variant_decoder_t::decode_value(#variant_decoder_var)?),
+            ));
+
             let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
                 let name = &v.st.name;
 
@@ -376,7 +682,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
                     let #output_var = match #variant_tag_var {
                         #(#arms,)*
-                        #fallback
+                        #unknown_pat => #fallback
                     };
 
                     #result_ok(#output_var)
@@ -387,6 +693,9 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             }})
         }
         EnumTagging::Internal { tag } => {
+            // The content is buffered, so there's nothing to skip.
+            let fallback = fallback(None);
+
             let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
                 let name = &v.st.name;
 
@@ -433,7 +742,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                     field_alloc = None;
 
                     let name_type = &en.name_type;
-                    let tag_arm = output_arm(None, tag, &binding_var);
+                    let tag_arm = output_arm(None, tag, &[], &binding_var, None);
 
                     decode_match = quote! {
                         let #value_var: #name_type = #decode_t_decode(#ctx_var, #field_name_var)?;
@@ -464,7 +773,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                     let visit_type = &en.name_type;
                     let method = method.as_method_name();
 
-                    let tag_arm = output_arm(None, tag, &binding_var);
+                    let tag_arm = output_arm(None, tag, &[], &binding_var, None);
 
                     let decode_outcome = quote! {
                         #decoder_t::#method(#field_name_var, |#value_var: &#visit_type| {
@@ -539,7 +848,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
                 let #output_var = match #variant_tag_var {
                     #(#arms,)*
-                    #fallback
+                    #unknown_pat => #fallback
                 };
 
                 #leave
@@ -547,10 +856,16 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             }})
         }
         EnumTagging::Adjacent { tag, content } => {
-            let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
+            let mut arms = Vec::new();
+            let mut missing_arms = Vec::new();
+
+            for (v, pat, tag_value) in &output_arms {
                 let name = &v.st.name;
 
-                let decode = decode_variant(cx, b, v, &body_decoder_var, &variant_tag_var).ok()?;
+                let Ok(decode) = decode_variant(cx, b, v, &body_decoder_var, &variant_tag_var)
+                else {
+                    continue;
+                };
 
                 let enter = cx.trace.then(|| {
                     let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
@@ -567,51 +882,92 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                     }
                 });
 
-                Some(quote! {
+                arms.push(quote! {
                     #pat => {
                         #enter
                         let #output_var = #decode;
                         #leave
                         #output_var
                     }
-                })
-            });
+                });
 
-            let decode_t_decode = &b.decode_t_decode;
+                // Empty variants are encoded without content, every other
+                // variant requires it.
+                missing_arms.push(match v.st.kind {
+                    StructKind::Empty => {
+                        let path = &v.st.path;
+                        quote!(#pat => #result_ok(#path {}))
+                    }
+                    _ => quote! {
+                        #pat => #result_err(#context_t::missing_variant_field(#ctx_var, #type_name, &#content_static))
+                    },
+                });
+            }
 
-            let field_alloc;
-            let outcome_enum;
-            let decode_match;
+            let content_fallback = fallback(Some(quote!(#body_decoder_var)));
 
-            match en.name_method {
-                NameMethod::Value => {
-                    field_alloc = None;
-                    outcome_enum = None;
+            // Without content the fallback is produced as the result of the
+            // map decoder.
+            let missing_fallback = match &en.fallback {
+                Some(fallback) => {
+                    let construct = fallback.construct(&captured);
+                    quote!(#result_ok(#construct))
+                }
+                None => quote! {
+                    #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, #unknown_tag))
+                },
+            };
 
+            let decode_body = quote! {
+                match #variant_tag_var {
+                    #(#arms,)*
+                    #unknown_pat => #content_fallback,
+                }
+            };
+
+            let decode_missing = quote! {
+                match #variant_tag_var {
+                    #(#missing_arms,)*
+                    #unknown_pat => #missing_fallback,
+                }
+            };
+
+            let tag_arm = output_arm(None, tag, &[], &binding_var, None);
+            let content_arm = output_arm(None, content, &[], &binding_var, None);
+
+            let (field_alloc, outcome_enum) = match en.name_method {
+                NameMethod::Value => (None, None),
+                NameMethod::Unsized(..) => {
+                    let field_alloc = quote! {
+                        let #option_some(mut #field_alloc_var) = #context_t::alloc(#ctx_var) else {
+                            return #result_err(#context_t::alloc_failed(#ctx_var));
+                        };
+                    };
+
+                    let outcome_enum = quote! {
+                        enum #outcome_type { Tag, Content, Skip }
+                    };
+
+                    (Some(field_alloc), Some(outcome_enum))
+                }
+            };
+
+            // Match the key of an entry against the tag and the content,
+            // skipping over any other field.
+            let decode_field = |on_tag: TokenStream, on_content: TokenStream| match en.name_method {
+                NameMethod::Value => {
+                    let decode_t_decode = &b.decode_t_decode;
                     let name_type = &en.name_type;
-                    let tag_arm = output_arm(None, tag, &binding_var);
-                    let content_arm = output_arm(None, content, &binding_var);
 
-                    decode_match = quote! {
+                    quote! {
                         let #value_var: #name_type = #decode_t_decode(#ctx_var, #field_name_var)?;
 
                         match #value_var {
                             #tag_arm => {
-                                let #variant_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
-                                let #variant_tag_var: #name_type = #decode_name?;
-                                #name_var = #option_some(#variant_tag_var);
+                                #on_tag
                             }
                             #content_arm => {
-                                let #option_some(#variant_tag_var) = #name_var else {
-                                    return #result_err(#context_t::missing_adjacent_tag(#ctx_var, #type_name, &#content));
-                                };
-
-                                let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
-
-                                break #result_ok(match #variant_tag_var {
-                                    #(#arms,)*
-                                    #fallback
-                                });
+                                #on_content
                             }
                             #field_var => {
                                 if #skip_field(#entry_var)? {
@@ -619,26 +975,13 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 }
                             }
                         }
-                    };
+                    }
                 }
                 NameMethod::Unsized(method) => {
-                    field_alloc = Some(quote! {
-                        let #option_some(mut #field_alloc_var) = #context_t::alloc(#ctx_var) else {
-                            return #result_err(#context_t::alloc_failed(#ctx_var));
-                        };
-                    });
-
-                    outcome_enum = Some(quote! {
-                        enum #outcome_type { Tag, Content, Skip }
-                    });
-
                     let visit_type = &en.name_type;
                     let method = method.as_method_name();
 
-                    let tag_arm = output_arm(None, tag, &binding_var);
-                    let content_arm = output_arm(None, content, &binding_var);
-
-                    decode_match = quote! {
+                    quote! {
                         let #outcome_var: #outcome_type = #decoder_t::#method(#field_name_var, |#value_var: &#visit_type| {
                             #result_ok(match #value_var {
                                 #tag_arm => #outcome_type::Tag,
@@ -655,20 +998,10 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
                         match #outcome_var {
                             #outcome_type::Tag => {
-                                let #variant_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
-                                #name_var = #option_some(#decode_name?);
+                                #on_tag
                             }
                             #outcome_type::Content => {
-                                let #option_some(#variant_tag_var) = #name_var else {
-                                    return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#tag));
-                                };
-
-                                let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
-
-                                break #result_ok(match #variant_tag_var {
-                                    #(#arms,)*
-                                    #fallback
-                                });
+                                #on_content
                             }
                             #outcome_type::Skip => {
                                 if #skip_field(#entry_var)? {
@@ -676,10 +1009,49 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                                 }
                             }
                         }
-                    };
+                    }
+                }
+            };
+
+            let skip_entry = |field: &Ident| {
+                quote! {
+                    if #skip_field(#entry_var)? {
+                        return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#field));
+                    }
                 }
             };
 
+            // Decoding the tag when the content follows it.
+            let decode_streaming = decode_field(
+                quote! {
+                    let #variant_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
+                    #name_var = #option_some(#decode_name?);
+                },
+                quote! {
+                    let #option_some(#variant_tag_var) = #name_var.take() else {
+                        return #result_err(#context_t::missing_adjacent_tag(#ctx_var, #type_name, &#content_static));
+                    };
+
+                    let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
+                    break #result_ok(#decode_body);
+                },
+            );
+
+            // Scanning a buffered map first for the tag, and then for the
+            // content.
+            let decode_buffered_tag = decode_field(
+                quote!(break #struct_field_decoder_t::decode_value(#entry_var)?;),
+                skip_entry(&content_static),
+            );
+
+            let decode_buffered_content = decode_field(
+                skip_entry(&tag_static),
+                quote! {
+                    let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
+                    break #result_ok(#decode_body);
+                },
+            );
+
             let enter = cx.trace.then(|| {
                 quote! {
                     #context_t::enter_enum(#ctx_var, #type_name);
@@ -694,6 +1066,10 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
             let static_type = en.static_type();
 
+            // The tag is usually followed by the content, which is what we
+            // encode, so this can be decoded as it's being read. Only decoders
+            // which can buffer might have the content before the tag, in
+            // which case the map is scanned twice.
             Ok(quote! {{
                 static #tag_static: #static_type = #tag;
                 static #content_static: #static_type = #content;
@@ -701,32 +1077,215 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 #output_enum
                 #outcome_enum
 
-                static #struct_hint_static: #map_hint = #map_hint::with_size(2);
-
                 #enter
 
-                #decoder_t::decode_map_hint(#decoder_var, &#struct_hint_static, move |#struct_decoder_var| {
-                    let mut #name_var = #option_none;
+                let #output_var = if #decoder_t::is_bufferable(&#decoder_var) {
+                    let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+                    let #struct_var = #as_decoder_t::as_decoder(&#buffer_var)?;
 
-                    let #output_var = loop {
-                        let #option_some(mut #entry_var) = #map_decoder_t::decode_entry(#struct_decoder_var)? else {
-                            return #result_err(#context_t::expected_field_adjacent(#ctx_var, #type_name, &#tag_static, &#content_static));
+                    let #variant_tag_var: #name_type = #decoder_t::decode_map(#struct_var, |#struct_var| {
+                        let #variant_decoder_var = loop {
+                            let #option_some(mut #entry_var) = #map_decoder_t::decode_entry(#struct_var)? else {
+                                return #result_err(#context_t::missing_variant_field(#ctx_var, #type_name, &#tag_static));
+                            };
+
+                            let #field_name_var = #struct_field_decoder_t::decode_key(&mut #entry_var)?;
+
+                            #field_alloc
+                            #decode_buffered_tag
                         };
 
-                        let #field_name_var = #struct_field_decoder_t::decode_key(&mut #entry_var)?;
+                        #decode_name
+                    })?;
 
-                        #field_alloc
-                        #decode_match
-                    };
+                    let #struct_var = #as_decoder_t::as_decoder(&#buffer_var)?;
 
-                    #leave
-                    #result_ok(#output_var)
-                })?
+                    #decoder_t::decode_map(#struct_var, move |#struct_var| {
+                        loop {
+                            let #option_some(mut #entry_var) = #map_decoder_t::decode_entry(#struct_var)? else {
+                                break #decode_missing;
+                            };
+
+                            let #field_name_var = #struct_field_decoder_t::decode_key(&mut #entry_var)?;
+
+                            #field_alloc
+                            #decode_buffered_content
+                        }
+                    })?
+                } else {
+                    static #struct_hint_static: #map_hint = #map_hint::with_size(2);
+
+                    #decoder_t::decode_map_hint(#decoder_var, &#struct_hint_static, move |#struct_var| {
+                        let mut #name_var: #option<#name_type> = #option_none;
+
+                        loop {
+                            let #option_some(mut #entry_var) = #map_decoder_t::decode_entry(#struct_var)? else {
+                                let #option_some(#variant_tag_var) = #name_var else {
+                                    return #result_err(#context_t::missing_variant_field(#ctx_var, #type_name, &#tag_static));
+                                };
+
+                                break #decode_missing;
+                            };
+
+                            let #field_name_var = #struct_field_decoder_t::decode_key(&mut #entry_var)?;
+
+                            #field_alloc
+                            #decode_streaming
+                        }
+                    })?
+                };
+
+                #leave
+                #result_ok(#output_var)
             }})
         }
+        EnumTagging::Untagged => decode_untagged(cx, b, en),
     }
 }
 
+/// Decode an untagged enum by buffering its content and trying each variant in
+/// order until one of them succeeds.
+fn decode_untagged(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        d_param,
+        ..
+    } = *cx;
+
+    let Tokens {
+        as_decoder_t,
+        context_t,
+        decoder_t,
+        result,
+        result_err,
+        result_ok,
+        ..
+    } = b.tokens;
+
+    let type_name = en.name;
+
+    let buffer_decoder_var = b.cx.ident("buffer_decoder");
+    let buffer_var = b.cx.ident("buffer");
+    let checkpoint_var = b.cx.ident("checkpoint");
+    let output_var = b.cx.ident("output");
+    let variant_tag_var = b.cx.ident("variant_tag");
+    let name_static = b.cx.ident("NAME");
+    let tag_static = b.cx.ident("TAG");
+    let label = syn::Lifetime::new("'untagged", Span::call_site());
+
+    let static_type = en.static_type();
+
+    let mut attempted = Vec::new();
+    let mut attempts = Vec::new();
+
+    for v in &en.variants {
+        // The fallback variant is only used if nothing else matches.
+        if en.fallback.as_ref().is_some_and(|fallback| {
+            v.st.path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == *fallback.ident)
+        }) {
+            continue;
+        }
+
+        let Ok(decode) = decode_variant(cx, b, v, &buffer_decoder_var, &variant_tag_var) else {
+            continue;
+        };
+
+        let name = &v.st.name;
+        let tag_value = &v.name;
+
+        let enter = cx.trace.then(|| {
+            let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
+
+            quote! {
+                #tag_decl
+                #context_t::enter_variant(#ctx_var, #name, #formatted_tag);
+            }
+        });
+
+        let leave = cx.trace.then(|| {
+            quote! {
+                #context_t::leave_variant(#ctx_var);
+            }
+        });
+
+        // Only tagged bodies report the variant they belong to.
+        let variant_tag = matches!(
+            (v.st.kind, v.st.packing),
+            (StructKind::Indexed(..) | StructKind::Named, Packing::Tagged)
+        )
+        .then(|| {
+            quote! {
+                static #name_static: #static_type = #tag_value;
+                let #variant_tag_var = &#name_static;
+            }
+        });
+
+        attempted.push(name.value());
+
+        attempts.push(quote! {
+            let #output_var = (|| -> #result<_, <#d_param::Cx as #context_t>::Error> {
+                #variant_tag
+                #enter
+                let #buffer_decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+                let #output_var = #decode;
+                #leave
+                #result_ok(#output_var)
+            })();
+
+            if let #result_ok(#output_var) = #output_var {
+                break #label #output_var;
+            }
+
+            #context_t::restore(#ctx_var, #checkpoint_var);
+        });
+    }
+
+    let fallback = match &en.fallback {
+        Some(fallback) => fallback.construct(TokenStream::new()),
+        None => {
+            let message = format!(
+                "No variant of {} matched, tried: {}",
+                type_name.value(),
+                attempted.join(", ")
+            );
+
+            quote!(return #result_err(#context_t::message(#ctx_var, #message)))
+        }
+    };
+
+    let label = (!attempts.is_empty()).then(|| quote!(#label:));
+
+    let enter = cx.trace.then(|| {
+        quote! {
+            #context_t::enter_enum(#ctx_var, #type_name);
+        }
+    });
+
+    let leave = cx.trace.then(|| {
+        quote! {
+            #context_t::leave_enum(#ctx_var);
+        }
+    });
+
+    Ok(quote! {{
+        #enter
+        let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+        let #checkpoint_var = #context_t::checkpoint(#ctx_var);
+
+        let #output_var = #label {
+            #(#attempts)*
+            #fallback
+        };
+
+        #leave
+        #result_ok(#output_var)
+    }})
+}
+
 fn decode_variant(
     cx: &Ctxt<'_>,
     b: &Build,
@@ -737,6 +1296,7 @@ fn decode_variant(
     let cx = Ctxt {
         decoder_var,
         trace_body: false,
+        place: None,
         ..*cx
     };
 
@@ -790,6 +1350,80 @@ fn decode_empty(cx: &Ctxt, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream>
     }})
 }
 
+/// Wrap the decoding of a field in a container marked with
+/// `#[musli(recover)]`.
+///
+/// If the context asks for it, the value of the field is buffered so that an
+/// error while decoding it can be recovered from by substituting its default
+/// value, leaving the decoder positioned at the next field.
+fn decode_recover(
+    b: &Build<'_>,
+    cx: &Ctxt,
+    f: &Field<'_>,
+    decode: TokenStream,
+    struct_decoder_var: &Ident,
+) -> TokenStream {
+    let Ctxt { ctx_var, .. } = *cx;
+
+    let Tokens {
+        as_decoder_t,
+        context_t,
+        decoder_t,
+        default_function,
+        option_some,
+        result_err,
+        result_ok,
+        ..
+    } = b.tokens;
+
+    let buffer_var = b.cx.ident("buffer");
+    let checkpoint_var = b.cx.ident("checkpoint");
+    let value_var = b.cx.ident("value");
+
+    let var = &f.var;
+    let decode_path = &f.decode_path.1;
+
+    let default = match f.default_attr {
+        Some((_, Some(path))) => quote!(#path()),
+        _ => quote!(#default_function()),
+    };
+
+    let (ok, recovered) = match cx.place {
+        Some(place) => {
+            let member = &f.member;
+
+            (
+                quote!(#place.#member = #value_var; #var = true;),
+                quote!(#place.#member = #default; #var = true;),
+            )
+        }
+        None => (
+            quote!(#var = #option_some(#value_var);),
+            quote!(#var = #option_some(#default);),
+        ),
+    };
+
+    quote! {
+        if #context_t::recover(#ctx_var) {
+            let #checkpoint_var = #context_t::checkpoint(#ctx_var);
+            let #buffer_var = #decoder_t::decode_buffer(#struct_decoder_var)?;
+            let #struct_decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+
+            match #decode_path(#ctx_var, #struct_decoder_var) {
+                #result_ok(#value_var) => {
+                    #ok
+                }
+                #result_err(..) => {
+                    #context_t::recovered(#ctx_var, #checkpoint_var);
+                    #recovered
+                }
+            }
+        } else {
+            #decode
+        }
+    }
+}
+
 /// Decode something tagged.
 ///
 /// If `variant_name` is specified it implies that a tagged enum is being
@@ -808,6 +1442,7 @@ fn decode_tagged(
     } = *cx;
 
     let Tokens {
+        as_decoder_t,
         context_t,
         decoder_t,
         default_function,
@@ -822,10 +1457,13 @@ fn decode_tagged(
         map_decoder_t,
         struct_field_decoder_t,
         map_hint,
+        priv_decode_names,
+        priv_flatten_decoder,
         ..
     } = b.tokens;
 
     let field_alloc_var = b.cx.ident("field_alloc");
+    let mark_var = b.cx.ident("mark");
     let struct_decoder_var = b.cx.ident("struct_decoder");
     let struct_hint_static = b.cx.ident("STRUCT_HINT");
     let type_decoder_var = b.cx.ident("type_decoder");
@@ -837,20 +1475,31 @@ fn decode_tagged(
     let mut assigns = Punctuated::<_, Token![,]>::new();
 
     let mut fields_with = Vec::new();
+    let mut skipped_fields = Vec::new();
+    let mut flattened = Vec::new();
+    let mut in_place_fallbacks = Vec::new();
 
     for f in &st.all_fields {
         let tag = &f.name;
         let var = &f.var;
         let decode_path = &f.decode_path.1;
 
-        let expr = match &f.skip {
+        let expr = match f.skip {
             Some(span) => {
-                let ty = f.ty;
+                // Skipped fields are still known, so they are ignored rather
+                // than reported when unknown fields are denied.
+                if st.deny_unknown_fields.is_some() {
+                    skipped_fields.push(f);
+                }
+
+                let default = skipped_default(b.tokens, f, span);
 
-                match &f.default_attr {
-                    Some((_, Some(path))) => syn::Expr::Verbatim(quote_spanned!(*span => #path())),
-                    _ => syn::Expr::Verbatim(quote_spanned!(*span => #default_function::<#ty>())),
+                if let Some(place) = cx.place {
+                    let member = &f.member;
+                    in_place_fallbacks.push(quote!(#place.#member = #default;));
                 }
+
+                syn::Expr::Verbatim(default)
             }
             None => {
                 let formatted_tag = match &st.name_format_with {
@@ -881,28 +1530,82 @@ fn decode_tagged(
                     }
                 });
 
-                let decode = quote! {
-                    #var = #option_some(#decode_path(#ctx_var, #struct_decoder_var)?);
-                };
+                // Flattened fields are decoded separately from the buffered
+                // map, so they never match an entry.
+                if f.flatten.is_some() {
+                    flattened.push((f, enter, leave));
+                    syn::Expr::Path(syn::ExprPath {
+                        attrs: Vec::new(),
+                        qself: None,
+                        path: syn::Path::from(var.clone()),
+                    })
+                } else {
+                    let mut decode = match (cx.place, &f.decode_in_place_path) {
+                        (Some(place), Some(decode_in_place_path)) => {
+                            let member = &f.member;
+
+                            quote! {
+                                #decode_in_place_path(#ctx_var, #struct_decoder_var, &mut #place.#member)?;
+                                #var = true;
+                            }
+                        }
+                        (Some(place), None) => {
+                            let member = &f.member;
 
-                fields_with.push((f, decode, (enter, leave)));
+                            quote! {
+                                #place.#member = #decode_path(#ctx_var, #struct_decoder_var)?;
+                                #var = true;
+                            }
+                        }
+                        (None, _) => quote! {
+                            #var = #option_some(#decode_path(#ctx_var, #struct_decoder_var)?);
+                        },
+                    };
 
-                let fallback = match f.default_attr {
-                    Some((span, None)) => quote_spanned!(span => #default_function()),
-                    Some((_, Some(path))) => quote!(#path()),
-                    None => quote! {
-                        return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag))
-                    },
-                };
+                    if st.recover.is_some() {
+                        decode = decode_recover(b, cx, f, decode, &struct_decoder_var);
+                    }
+
+                    fields_with.push((f, decode, (enter, leave)));
+
+                    // Fields which are conditionally omitted when encoding are
+                    // implicitly defaulted when decoding.
+                    let fallback = match (f.default_attr, f.skip_encoding_if) {
+                        (Some((span, None)), _) | (None, Some(&(span, _))) => {
+                            quote_spanned!(span => #default_function())
+                        }
+                        (Some((_, Some(path))), _) => quote!(#path()),
+                        (None, None) if st.recover.is_some() => quote! {
+                            if #context_t::recover(#ctx_var) {
+                                let _ = #context_t::expected_tag(#ctx_var, #type_name, &#tag);
+                                #default_function()
+                            } else {
+                                return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag));
+                            }
+                        },
+                        (None, None) => quote! {
+                            return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag))
+                        },
+                    };
+
+                    let var = &f.var;
 
-                let var = &f.var;
+                    if let Some(place) = cx.place {
+                        let member = &f.member;
 
-                syn::Expr::Verbatim(quote! {
-                    match #var {
-                        #option_some(#var) => #var,
-                        #option_none => #fallback,
+                        in_place_fallbacks.push(match (f.default_attr, f.skip_encoding_if) {
+                            (None, None) if st.recover.is_none() => quote!(if !#var { #fallback; }),
+                            _ => quote!(if !#var { #place.#member = #fallback; }),
+                        });
                     }
-                })
+
+                    syn::Expr::Verbatim(quote! {
+                        match #var {
+                            #option_some(#var) => #var,
+                            #option_none => #fallback,
+                        }
+                    })
+                }
             }
         };
 
@@ -933,15 +1636,21 @@ fn decode_tagged(
         }
     };
 
+    let mark = st.deny_unknown_fields.is_some().then(|| {
+        quote! {
+            let #mark_var = #context_t::mark(#ctx_var);
+        }
+    });
+
     let body;
     let name_type: syn::Type;
 
     match st.name_method {
         NameMethod::Value => {
-            let mut arms = Vec::with_capacity(fields_with.len());
+            let mut arms = Vec::with_capacity(fields_with.len() + skipped_fields.len());
 
             for (f, decode, (enter, leave)) in fields_with {
-                let arm = output_arm(f.pattern, &f.name, &binding_var);
+                let arm = output_arm(f.pattern, &f.name, f.aliases, &binding_var, None);
 
                 arms.push(quote! {
                     #arm => {
@@ -953,7 +1662,19 @@ fn decode_tagged(
                 });
             }
 
-            body = quote!(match #name_var { #(#arms,)* _ => { #skip_field } });
+            for f in skipped_fields {
+                let arm = output_arm(f.pattern, &f.name, f.aliases, &binding_var, None);
+                arms.push(quote!(#arm => { #skip_field }));
+            }
+
+            let unknown = match st.deny_unknown_fields {
+                Some(..) => quote! {
+                    return #result_err(#context_t::unknown_field_tag(#ctx_var, #type_name, #mark_var, &#name_var));
+                },
+                None => skip_field,
+            };
+
+            body = quote!(match #name_var { #(#arms,)* _ => { #unknown } });
 
             field_alloc = None;
 
@@ -972,36 +1693,73 @@ fn decode_tagged(
             let mut outputs = Vec::with_capacity(fields_with.len());
             let mut name_arms = Vec::with_capacity(fields_with.len());
 
-            for (f, decode, trace) in fields_with {
-                let (name_pat, name_variant) =
-                    unsized_arm(b, f.span, f.index, &f.name, f.pattern, &output_type);
+            for (f, decode, (enter, leave)) in fields_with {
+                let (name_pat, name_variant) = unsized_arm(
+                    b,
+                    f.span,
+                    f.index,
+                    &f.name,
+                    f.aliases,
+                    f.pattern,
+                    &output_type,
+                    option_some,
+                );
 
                 outputs.push(name_variant);
-                name_arms.push((name_pat, decode, trace));
+
+                name_arms.push(quote! {
+                    #name_pat => {
+                        #enter
+                        let #struct_decoder_var = #struct_field_decoder_t::decode_value(#struct_decoder_var)?;
+                        #decode
+                        #leave
+                    }
+                });
             }
 
-            if !name_arms.is_empty() {
-                let arms = name_arms
-                    .into_iter()
-                    .map(|(name_pat, decode, (enter, leave))| {
-                        quote! {
-                            #name_pat => {
-                                #enter
-                                let #struct_decoder_var = #struct_field_decoder_t::decode_value(#struct_decoder_var)?;
-                                #decode
-                                #leave
-                            }
-                        }
-                    });
+            for f in skipped_fields {
+                let (name_pat, name_variant) = unsized_arm(
+                    b,
+                    f.span,
+                    f.index,
+                    &f.name,
+                    f.aliases,
+                    f.pattern,
+                    &output_type,
+                    option_some,
+                );
+
+                outputs.push(name_variant);
+                name_arms.push(quote!(#name_pat => { #skip_field }));
+            }
+
+            let unknown = match st.deny_unknown_fields {
+                Some(..) => quote! {
+                    return #result_err(#context_t::unknown_field_string_tag(#ctx_var, #type_name, #mark_var, #field_alloc_var));
+                },
+                None => skip_field,
+            };
 
+            if !name_arms.is_empty() {
                 body = quote! {
-                    match #name_var { #(#arms,)* #name_var => { #skip_field } }
+                    match #name_var { #(#name_arms,)* #name_var => { #unknown } }
                 }
+            } else if st.deny_unknown_fields.is_some() {
+                body = quote! {
+                    let _ = #name_var;
+                    #unknown
+                };
             } else {
-                body = skip_field;
+                body = unknown;
             }
 
-            let arms = outputs.iter().map(|o| o.as_arm(&binding_var, option_some));
+            let fold = st
+                .name_case_insensitive
+                .map(|_| &b.tokens.priv_eq_ignore_ascii_case);
+
+            let arms = outputs
+                .iter()
+                .map(|o| o.as_arm(&binding_var, option_some, fold));
 
             field_alloc = Some(quote! {
                 let #option_some(mut #field_alloc_var) = #context_t::alloc(#ctx_var) else {
@@ -1053,13 +1811,50 @@ fn decode_tagged(
     }
 
     let path = &st.path;
-    let fields_len = st.unskipped_fields.len();
+    let fields_len = st.unskipped_fields.len() - flattened.len();
 
     let decls = st
         .unskipped_fields
         .iter()
         .map(|f| &**f)
-        .map(|Field { var, ty, .. }| quote!(let mut #var: #option<#ty> = #option_none;));
+        .filter(|f| f.flatten.is_none())
+        .map(|Field { var, ty, .. }| match cx.place {
+            Some(..) => quote!(let mut #var = false;),
+            None => quote!(let mut #var: #option<#ty> = #option_none;),
+        });
+
+    // When decoding in place, fields which weren't present are assigned
+    // their fallback value.
+    let output = match cx.place {
+        Some(..) => quote!(#(#in_place_fallbacks)* #result_ok(())),
+        None => quote!(#result_ok(#path { #assigns })),
+    };
+
+    // Containers with flattened fields are buffered, since every flattened
+    // field has to see the entries which are not recognized by the container.
+    let buffer = (!flattened.is_empty()).then(|| {
+        let buffer_var = b.cx.ident("buffer");
+
+        let flattened = flattened.iter().map(|(f, enter, leave)| {
+            let var = &f.var;
+            let decode_path = &f.decode_path.1;
+
+            quote! {
+                #enter
+                let #var = #decode_path(#ctx_var, #priv_flatten_decoder::<_, _, Self>::new(#ctx_var, &#buffer_var))?;
+                #leave
+            }
+        });
+
+        let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+
+        quote! {
+            let () = <Self as #priv_decode_names<#mode_ident>>::CHECK;
+            let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+            #(#flattened)*
+            let #decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+        }
+    });
 
     let enter = (cx.trace && cx.trace_body).then(|| {
         quote! {
@@ -1078,12 +1873,14 @@ fn decode_tagged(
         #(#decls)*
 
         #enter
+        #buffer
 
         static #struct_hint_static: #map_hint = #map_hint::with_size(#fields_len);
 
         #decoder_t::decode_map_hint(#decoder_var, &#struct_hint_static, move |#type_decoder_var| {
             while let #option_some(mut #struct_decoder_var) = #map_decoder_t::decode_entry(#type_decoder_var)? {
                 #field_alloc
+                #mark
 
                 let #name_var: #name_type = {
                     let #struct_decoder_var = #struct_field_decoder_t::decode_key(&mut #struct_decoder_var)?;
@@ -1094,7 +1891,7 @@ fn decode_tagged(
             }
 
             #leave
-            #result_ok(#path { #assigns })
+            #output
         })?
     }})
 }
@@ -1118,6 +1915,13 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
     let decode_path = &f.decode_path.1;
     let member = &f.member;
 
+    let skipped = st.all_fields.iter().flat_map(|f| {
+        let span = f.skip?;
+        let member = &f.member;
+        let expr = skipped_default(b.tokens, f, span);
+        Some(quote!(#member: #expr))
+    });
+
     let enter = (cx.trace && cx.trace_body).then(|| {
         quote! {
             #context_t::enter_struct(#ctx_var, #type_name);
@@ -1134,7 +1938,8 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
         #enter
 
         let #output_var = #path {
-            #member: #decode_path(#ctx_var, #decoder_var)?
+            #member: #decode_path(#ctx_var, #decoder_var)?,
+            #(#skipped,)*
         };
 
         #leave
@@ -1163,9 +1968,15 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
 
     let mut assign = Vec::new();
 
-    for f in &st_.unskipped_fields {
-        if let Some((span, _)) = f.default_attr {
-            b.packed_default_diagnostics(span);
+    for f in &st_.all_fields {
+        // Skipped fields don't occupy a slot in the pack, so they are
+        // constructed directly without consulting the decoder.
+        let skipped = f.skip.map(|span| skipped_default(b.tokens, f, span));
+
+        if skipped.is_none() {
+            if let Some((span, _)) = f.default_attr {
+                b.packed_default_diagnostics(span);
+            }
         }
 
         let (_, decode_path) = &f.decode_path;
@@ -1173,6 +1984,11 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
         let field_decoder = &field_decoder;
 
         assign.push(move |ident: &syn::Ident, tokens: &mut TokenStream| {
+            if let Some(expr) = &skipped {
+                tokens.extend(quote!(#member: #expr));
+                return;
+            }
+
             tokens.extend(quote! {
                 #member: {
                     let #field_decoder = #pack_decoder_t::decode_next(#ident)?;
@@ -1210,6 +2026,24 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
     }})
 }
 
+/// Construct the value of a field which has been marked with
+/// `#[musli(skip)]`.
+///
+/// This uses the `#[musli(default = <path>)]` function if one is specified,
+/// or [`Default::default`] otherwise. The latter is spanned to the `skip`
+/// attribute, so a missing `Default` implementation is reported there.
+fn skipped_default(tokens: &Tokens, f: &Field<'_>, span: Span) -> TokenStream {
+    let ty = f.ty;
+
+    match &f.default_attr {
+        Some((_, Some(path))) => quote_spanned!(span => #path()),
+        _ => {
+            let default_function = &tokens.default_function;
+            quote_spanned!(span => #default_function::<#ty>())
+        }
+    }
+}
+
 /// Output type used when indirectly encoding a variant or field as type which
 /// might require special handling. Like a string.
 pub(crate) struct NameVariant<'a> {
@@ -1219,34 +2053,36 @@ pub(crate) struct NameVariant<'a> {
     variant: Ident,
     /// The tag this variant corresponds to.
     name: &'a syn::Expr,
+    /// Additional tags which are accepted for this variant.
+    aliases: &'a [(Span, syn::Expr)],
     /// The pattern being matched.
     pattern: Option<&'a syn::Pat>,
 }
 
 impl NameVariant<'_> {
     /// Generate the pattern for this output.
-    pub(crate) fn as_arm(&self, binding_var: &syn::Ident, option_some: &syn::Path) -> syn::Arm {
+    pub(crate) fn as_arm(
+        &self,
+        binding_var: &syn::Ident,
+        wrap: &syn::Path,
+        fold: Option<&syn::Path>,
+    ) -> syn::Arm {
         let body = syn::Expr::Path(syn::ExprPath {
             attrs: Vec::new(),
             qself: None,
             path: self.path.clone(),
         });
 
-        let arm = output_arm(self.pattern, self.name, binding_var);
+        let arm = output_arm(self.pattern, self.name, self.aliases, binding_var, fold);
 
         syn::Arm {
             attrs: Vec::new(),
             pat: arm.pat,
-            guard: arm.cond.map(|_| {
-                let name = self.name;
-
-                (
-                    <syn::Token![if]>::default(),
-                    syn::parse_quote!(*#binding_var == #name),
-                )
-            }),
+            guard: arm
+                .cond
+                .map(|cond| (<syn::Token![if]>::default(), Box::new(cond.to_expr()))),
             fat_arrow_token: <Token![=>]>::default(),
-            body: Box::new(build_call(option_some, [body])),
+            body: Box::new(build_call(wrap, [body])),
             comma: None,
         }
     }
@@ -1288,8 +2124,10 @@ fn unsized_arm<'a>(
     span: Span,
     index: usize,
     name: &'a syn::Expr,
+    aliases: &'a [(Span, syn::Expr)],
     pattern: Option<&'a syn::Pat>,
     output: &Ident,
+    wrap: &syn::Path,
 ) -> (syn::Pat, NameVariant<'a>) {
     let variant = b.cx.type_with_span(format_args!("Variant{}", index), span);
 
@@ -1300,38 +2138,38 @@ fn unsized_arm<'a>(
         path: path.clone(),
         variant,
         name,
+        aliases,
         pattern,
     };
 
-    let option_some = &b.tokens.option_some;
-    (syn::parse_quote!(#option_some(#path)), output)
+    (syn::parse_quote!(#wrap(#path)), output)
 }
 
+/// A guard comparing the binding against each name it might match.
 struct Condition<'a> {
-    if_: syn::Token![if],
-    star: syn::Token![*],
     ident: &'a syn::Ident,
-    equals: syn::Token![==],
-    expr: &'a syn::Expr,
+    exprs: Vec<&'a syn::Expr>,
+    /// Function used to compare names, instead of equality.
+    fold: Option<&'a syn::Path>,
 }
 
-impl ToTokens for Condition<'_> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.if_.to_tokens(tokens);
-        self.star.to_tokens(tokens);
-        self.ident.to_tokens(tokens);
-        self.equals.to_tokens(tokens);
-        self.expr.to_tokens(tokens);
+impl Condition<'_> {
+    /// Construct the guard expression, without the leading `if`.
+    fn to_expr(&self) -> syn::Expr {
+        let ident = self.ident;
+        let exprs = &self.exprs;
+
+        match self.fold {
+            Some(fold) => syn::parse_quote!(#(#fold(#ident, &#exprs))||*),
+            None => syn::parse_quote!(#(*#ident == #exprs)||*),
+        }
     }
 }
 
-fn condition<'a>(ident: &'a syn::Ident, expr: &'a syn::Expr) -> Condition<'a> {
-    Condition {
-        if_: <syn::Token![if]>::default(),
-        star: <syn::Token![*]>::default(),
-        ident,
-        equals: <syn::Token![==]>::default(),
-        expr,
+impl ToTokens for Condition<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        <syn::Token![if]>::default().to_tokens(tokens);
+        self.to_expr().to_tokens(tokens);
     }
 }
 
@@ -1345,10 +2183,16 @@ fn ref_pattern(ident: &syn::Ident) -> syn::Pat {
     })
 }
 
+/// Construct the arm matching a name or any of its aliases.
+///
+/// If `fold` is specified, names are compared through it instead of being
+/// matched exactly.
 fn output_arm<'a>(
     pat: Option<&'a syn::Pat>,
     name: &'a syn::Expr,
+    aliases: &'a [(Span, syn::Expr)],
     binding: &'a syn::Ident,
+    fold: Option<&'a syn::Path>,
 ) -> OutputArm<'a> {
     if let Some(pat) = pat {
         return OutputArm {
@@ -1357,13 +2201,40 @@ fn output_arm<'a>(
         };
     }
 
-    if let Some(pat) = expr_to_pat(name) {
+    let exprs = iter::once(name)
+        .chain(aliases.iter().map(|(_, alias)| alias))
+        .collect::<Vec<_>>();
+
+    // Names which are folded can't be matched through patterns.
+    let cases = match fold {
+        Some(..) => None,
+        None => exprs
+            .iter()
+            .map(|expr| expr_to_pat(expr))
+            .collect::<Option<Vec<_>>>(),
+    };
+
+    if let Some(mut cases) = cases {
+        let pat = if cases.len() == 1 {
+            cases.remove(0)
+        } else {
+            syn::Pat::Or(syn::PatOr {
+                attrs: Vec::new(),
+                leading_vert: None,
+                cases: cases.into_iter().collect(),
+            })
+        };
+
         return OutputArm { pat, cond: None };
     }
 
     OutputArm {
         pat: ref_pattern(binding),
-        cond: Some(condition(binding, name)),
+        cond: Some(Condition {
+            ident: binding,
+            exprs,
+            fold,
+        }),
     }
 }
 
@@ -1,16 +1,18 @@
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::Token;
 
-use crate::internals::attr::{EnumTagging, Packing};
-use crate::internals::build::{Body, Build, BuildData, Enum, Variant};
+use crate::expander::{Expander, StructKind};
+use crate::internals::attr::{EnumTagging, FieldEncoding, Packing};
+use crate::internals::build::{Body, Build, BuildData, Enum, Field, Variant};
 use crate::internals::tokens::Tokens;
-use crate::internals::Result;
+use crate::internals::{Result, ATTR};
 
 struct Ctxt<'a> {
     ctx_var: &'a syn::Ident,
     encoder_var: &'a syn::Ident,
+    e_param: &'a syn::Ident,
     trace: bool,
 }
 
@@ -27,13 +29,16 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
     let cx = Ctxt {
         ctx_var: &ctx_var,
         encoder_var: &encoder_var,
+        e_param: &e_param,
         trace: true,
     };
 
     let Tokens {
+        encode_remote_t,
         encode_t,
         encoder_t,
         result,
+        trace_encode_t,
         ..
     } = e.tokens;
 
@@ -54,6 +59,25 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
         where_clause
             .predicates
             .extend(e.bounds.iter().map(|(_, v)| v.clone()));
+    } else {
+        let bounds = e.infer_encode_bounds();
+
+        if !bounds.is_empty() {
+            impl_generics.make_where_clause().predicates.extend(bounds);
+        }
+    }
+
+    let mode_ident = e.expansion.mode_path(e.tokens).as_path();
+
+    // Names which are shared with flattened fields are checked through the
+    // names of the container.
+    if has_flattened(&e) {
+        let priv_encode_names = &e.tokens.priv_encode_names;
+
+        impl_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(Self: #priv_encode_names<#mode_ident>));
     }
 
     let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
@@ -63,9 +87,31 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
 
     if cfg!(not(feature = "verbose")) {
         attributes.push(syn::parse_quote!(#[allow(clippy::just_underscores_and_digits)]));
+    } else {
+        // Verbose identifiers are derived from field names, which might not be
+        // snake case.
+        attributes.push(syn::parse_quote!(#[allow(non_snake_case)]));
     }
 
-    let mode_ident = e.expansion.mode_path(e.tokens).as_path();
+    if let Some(remote) = e.remote {
+        let value_var = &e.self_value;
+
+        return Ok(quote! {
+            const _: () = {
+                #[automatically_derived]
+                #(#attributes)*
+                impl #impl_generics #encode_remote_t<#mode_ident, #remote> for #type_ident #type_generics #where_clause {
+                    #[inline]
+                    fn encode_remote<#e_param>(#value_var: &#remote, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                    where
+                        #e_param: #encoder_t<Mode = #mode_ident>,
+                    {
+                        #body
+                    }
+                }
+            };
+        });
+    }
 
     Ok(quote! {
         const _: () = {
@@ -80,10 +126,137 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
                     #body
                 }
             }
+
+            // Derived types trace their own fields, so they can be traced
+            // inside of a container like any other value.
+            #[automatically_derived]
+            impl #impl_generics #trace_encode_t<#mode_ident> for #type_ident #type_generics #where_clause {
+                #[inline]
+                fn trace_encode<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                where
+                    #e_param: #encoder_t<Mode = #mode_ident>,
+                {
+                    <Self as #encode_t<#mode_ident>>::encode(self, #ctx_var, #encoder_var)
+                }
+            }
         };
     })
 }
 
+/// Expand the names of the fields of a struct which is encoded as a map, which
+/// allows it to be flattened.
+pub(crate) fn expand_encode_names(e: &Build<'_>) -> TokenStream {
+    match &e.data {
+        BuildData::Struct(st) if st.packing == Packing::Tagged => {
+            e.expand_names(st, &e.tokens.priv_encode_names, false, TokenStream::new())
+        }
+        _ => TokenStream::new(),
+    }
+}
+
+/// Test if the build is for a struct with flattened fields.
+pub(crate) fn has_flattened(e: &Build<'_>) -> bool {
+    match &e.data {
+        BuildData::Struct(st) => st.unskipped_fields.iter().any(|f| f.flatten.is_some()),
+        BuildData::Enum(..) => false,
+    }
+}
+
+/// Expand the `PackedSize` implementation requested through
+/// `#[musli(packed_size)]`, which sums up the sizes of all encoded fields.
+pub(crate) fn expand_packed_size(e: &Build<'_>) -> Result<TokenStream> {
+    let Some(span) = e.packed_size_span else {
+        return Ok(TokenStream::new());
+    };
+
+    let st = match &e.data {
+        BuildData::Struct(st) if st.packing == Packing::Packed => st,
+        _ => {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(packed_size)] is only supported on packed structs"),
+            );
+
+            return Err(());
+        }
+    };
+
+    let Tokens {
+        option_none,
+        option,
+        packed_size_t,
+        priv_packed_size,
+        ..
+    } = e.tokens;
+
+    let sizes = st.unskipped_fields.iter().map(|f| {
+        let ty = f.ty;
+
+        match f.encode_trait {
+            Some(FieldEncoding::Default | FieldEncoding::Trace) => {
+                quote!(<#ty as #packed_size_t>::PACKED_SIZE)
+            }
+            Some(FieldEncoding::Packed) => quote!(<#ty as #packed_size_t>::PACKED_FIELD_SIZE),
+            Some(FieldEncoding::Bytes) | None => quote!(#option_none),
+        }
+    });
+
+    let type_ident = &e.input.ident;
+
+    let mut generics = e.input.generics.clone();
+    let bounds = e.infer_packed_size_bounds();
+
+    if !bounds.is_empty() {
+        generics.make_where_clause().predicates.extend(bounds);
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #packed_size_t for #type_ident #type_generics #where_clause {
+            const PACKED_SIZE: #option<usize> = #priv_packed_size(&[#(#sizes),*]);
+        }
+    })
+}
+
+/// Expand the `encode` function of a mirror type implemented through
+/// `#[musli(remote = ..)]`, which can be used with `#[musli(with = ..)]`.
+pub(crate) fn expand_remote_encode(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    e.cx.reset();
+
+    let type_ident = &e.input.ident;
+
+    let value_var = e.remote_value_var();
+    let encoder_var = e.cx.ident("encoder");
+    let ctx_var = e.cx.ident("ctx");
+    let e_param = e.cx.type_with_span("E", Span::call_site());
+
+    let Tokens {
+        encode_remote_t,
+        encoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #type_ident #type_generics #where_clause {
+            /// Encode the remote type through this mirror type.
+            #[inline]
+            pub fn encode<#e_param>(#value_var: &#remote, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+            where
+                #e_param: #encoder_t,
+                Self: #encode_remote_t<#e_param::Mode, #remote>,
+            {
+                <Self as #encode_remote_t<#e_param::Mode, #remote>>::encode_remote(#value_var, #ctx_var, #encoder_var)
+            }
+        }
+    }
+}
+
 /// Encode a struct.
 fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -102,7 +275,7 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
     let pack_var = b.cx.ident("pack");
     let output_var = b.cx.ident("output");
 
-    let (encoders, tests) = insert_fields(cx, b, st, &pack_var)?;
+    let (encoders, tests, flattened) = insert_fields(cx, b, st, &pack_var)?;
 
     let type_name = &st.name;
 
@@ -129,6 +302,16 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
                 #output_var
             }};
         }
+        Packing::Tagged if !flattened.is_empty() => {
+            let body = encode_flattened(cx, b, st, &encoders, &tests, &flattened);
+
+            encode = quote! {{
+                #enter
+                let #output_var = #body?;
+                #leave
+                #output_var
+            }};
+        }
         Packing::Tagged => {
             let decls = tests.iter().map(|t| &t.decl);
             let (build_hint, hint) = length_test(st.unskipped_fields.len(), &tests).build(b);
@@ -165,17 +348,160 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
     Ok(quote!(#result_ok(#encode)))
 }
 
+/// Encode a struct with flattened fields.
+///
+/// The size of each flattened field is determined up front, after which the
+/// map is handed to each flattened field in turn. The remaining entries are
+/// encoded by a continuation once the flattened field finishes its map.
+fn encode_flattened(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st: &Body<'_>,
+    encoders: &[TokenStream],
+    tests: &[FieldTest<'_>],
+    flattened: &[FlattenField<'_>],
+) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        e_param,
+        ..
+    } = *cx;
+
+    let Tokens {
+        encoder_t,
+        map_encoder_t,
+        priv_flatten_encoder,
+        priv_flatten_len,
+        result,
+        ..
+    } = b.tokens;
+
+    let map_var = b.cx.ident("map");
+    let then_var = b.cx.ident("then");
+
+    let mut decls = tests
+        .iter()
+        .map(|t| t.decl.to_token_stream())
+        .collect::<Vec<_>>();
+    let mut length = length_test(st.unskipped_fields.len() - flattened.len(), tests);
+    length.kind = LengthTestKind::Dynamic;
+
+    for FlattenField { field: f, test, .. } in flattened {
+        let encode_path = &f.encode_path.1;
+        let access = &f.self_access;
+        let len_var =
+            b.cx.ident_with_span(&f.var.to_string(), f.var.span(), "_len");
+
+        let len = quote!(#encode_path(#access, #ctx_var, #priv_flatten_len::new(#ctx_var))?);
+
+        match test {
+            Some(decl) => {
+                let var = &f.var;
+                decls.push(decl.to_token_stream());
+                decls.push(quote!(let #len_var = if #var { #len } else { 0 };));
+            }
+            None => {
+                decls.push(quote!(let #len_var = #len;));
+            }
+        }
+
+        length.expressions.push(quote!(#len_var));
+    }
+
+    let (build_hint, hint) = length.build(b);
+
+    let segment = |entries: &[TokenStream], tail: TokenStream| {
+        if entries.is_empty() {
+            return (None, tail);
+        }
+
+        let mutability = <Token![mut]>::default();
+
+        let body = quote! {
+            let #encoder_var = &mut #map_var;
+            #(#entries)*
+            #tail
+        };
+
+        (Some(mutability), body)
+    };
+
+    let mut tail = quote!(#map_encoder_t::finish_map(#map_var));
+    let mut end = encoders.len();
+
+    for FlattenField {
+        index,
+        field: f,
+        test,
+    } in flattened.iter().rev()
+    {
+        let (mutability, body) = segment(&encoders[*index..end], tail);
+        end = *index;
+
+        let encode_path = &f.encode_path.1;
+        let access = &f.self_access;
+
+        let flatten = quote! {
+            #encode_path(#access, #ctx_var, #priv_flatten_encoder::new(#ctx_var, #map_var, #then_var))
+        };
+
+        let flatten = match test {
+            Some(..) => {
+                let var = &f.var;
+                quote!(if #var { #flatten } else { #then_var(#map_var) })
+            }
+            None => flatten,
+        };
+
+        tail = quote! {{
+            let #then_var = move |#mutability #map_var: <#e_param as #encoder_t>::EncodeMap| -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error> {
+                #body
+            };
+
+            #flatten
+        }};
+    }
+
+    let (mutability, body) = segment(&encoders[..end], tail);
+
+    let priv_encode_names = &b.tokens.priv_encode_names;
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+
+    quote! {{
+        let () = <Self as #priv_encode_names<#mode_ident>>::CHECK;
+        #(#decls)*
+        #build_hint
+        #encoder_t::__encode_map_then(#encoder_var, &#hint, move |#mutability #map_var| {
+            #body
+        })
+    }}
+}
+
 struct FieldTest<'st> {
     decl: syn::Stmt,
     var: &'st syn::Ident,
 }
 
+/// A field which is flattened into the containing map.
+struct FlattenField<'st> {
+    /// The number of entries which are encoded before this field.
+    index: usize,
+    field: &'st Field<'st>,
+    /// Declaration of the skip test, if the field has one.
+    test: Option<syn::Stmt>,
+}
+
 fn insert_fields<'st>(
     cx: &Ctxt<'_>,
     b: &Build<'_>,
     st: &'st Body<'_>,
     pack_var: &syn::Ident,
-) -> Result<(Vec<TokenStream>, Vec<FieldTest<'st>>)> {
+) -> Result<(
+    Vec<TokenStream>,
+    Vec<FieldTest<'st>>,
+    Vec<FlattenField<'st>>,
+)> {
     let Ctxt {
         ctx_var,
         encoder_var,
@@ -202,8 +528,25 @@ fn insert_fields<'st>(
 
     let mut encoders = Vec::with_capacity(st.all_fields.len());
     let mut tests = Vec::with_capacity(st.all_fields.len());
+    let mut flattened = Vec::new();
 
     for f in &st.unskipped_fields {
+        if f.flatten.is_some() {
+            let test = f.skip_encoding_if.as_ref().map(|(_, path)| {
+                let var = &f.var;
+                let access = &f.self_access;
+                syn::parse_quote!(let #var = !#path(#access);)
+            });
+
+            flattened.push(FlattenField {
+                index: encoders.len(),
+                field: f,
+                test,
+            });
+
+            continue;
+        }
+
         let encode_path = &f.encode_path.1;
         let access = &f.self_access;
         let name = &f.name;
@@ -277,7 +620,7 @@ fn insert_fields<'st>(
         encoders.push(encode);
     }
 
-    Ok((encoders, tests))
+    Ok((encoders, tests, flattened))
 }
 
 /// Encode an internally tagged enum.
@@ -308,11 +651,21 @@ fn encode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum<'_>) -> Result<TokenStrea
         variants.push(quote!(#pattern => #encode));
     }
 
+    for v in &en.skipped_variants {
+        let path = &v.st.path;
+        let variant_name = v.st.name;
+
+        variants.push(quote! {
+            #path { .. } => return #result_err(#context_t::skipped_variant(#ctx_var, #type_name, #variant_name))
+        });
+    }
+
     // Special case: uninhabitable types.
     Ok(if variants.is_empty() {
         quote!(#result_err(#context_t::uninhabitable(#ctx_var, #type_name)))
     } else {
-        quote!(#result_ok(match self { #(#variants),* }))
+        let self_value = &b.self_value;
+        quote!(#result_ok(match #self_value { #(#variants),* }))
     })
 }
 
@@ -325,7 +678,7 @@ fn encode_variant(
 ) -> Result<(syn::PatStruct, TokenStream)> {
     let pack_var = b.cx.ident("pack");
 
-    let (encoders, tests) = insert_fields(cx, b, &v.st, &pack_var)?;
+    let (encoders, tests, _) = insert_fields(cx, b, &v.st, &pack_var)?;
 
     let Ctxt {
         ctx_var,
@@ -353,6 +706,20 @@ fn encode_variant(
 
     let type_name = v.st.name;
 
+    // A variant which captured an unknown tag is encoded using that tag.
+    let (name_decl, name_ref) = match &v.capture {
+        Some(f) => (None, f.self_access.to_token_stream()),
+        None => {
+            let static_type = en.static_type();
+            let name = &v.name;
+
+            (
+                Some(quote!(static #name_static: #static_type = #name;)),
+                quote!(&#name_static),
+            )
+        }
+    };
+
     let mut encode;
 
     match en.enum_tagging {
@@ -366,7 +733,7 @@ fn encode_variant(
                 #encode_t_encode(&#name_static, #ctx_var, #encoder_var)?
             }};
         }
-        EnumTagging::Default => {
+        EnumTagging::Default | EnumTagging::Untagged => {
             match v.st.packing {
                 Packing::Transparent => {
                     let f = &v.st.unskipped_fields[0];
@@ -403,17 +770,15 @@ fn encode_variant(
                 }
             }
 
-            if let Packing::Tagged = en.enum_packing {
+            if let (EnumTagging::Default, Packing::Tagged) = (en.enum_tagging, en.enum_packing) {
                 let encode_t_encode = &b.encode_t_encode;
-                let name = &v.name;
-                let static_type = en.static_type();
 
                 encode = quote! {{
                     #encoder_t::encode_variant_fn(#encoder_var, move |#variant_encoder| {
                         let #tag_encoder = #variant_encoder_t::encode_tag(#variant_encoder)?;
-                        static #name_static: #static_type = #name;
+                        #name_decl
 
-                        #encode_t_encode(&#name_static, #ctx_var, #tag_encoder)?;
+                        #encode_t_encode(#name_ref, #ctx_var, #tag_encoder)?;
 
                         let #encoder_var = #variant_encoder_t::encode_data(#variant_encoder)?;
                         #encode;
@@ -423,8 +788,6 @@ fn encode_variant(
             }
         }
         EnumTagging::Internal { tag } => {
-            let name = &v.name;
-
             let static_type = en.static_type();
 
             let decls = tests.iter().map(|t| &t.decl);
@@ -440,8 +803,8 @@ fn encode_variant(
 
                 #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#encoder_var| {
                     static #tag_static: #static_type = #tag;
-                    static #name_static: #static_type = #name;
-                    #map_encoder_t::insert_entry(#encoder_var, #tag_static, #name_static)?;
+                    #name_decl
+                    #map_encoder_t::insert_entry(#encoder_var, #tag_static, #name_ref)?;
                     #(#decls)*
                     #(#encoders)*
                     #result_ok(())
@@ -451,7 +814,6 @@ fn encode_variant(
         EnumTagging::Adjacent { tag, content } => {
             let encode_t_encode = &b.encode_t_encode;
 
-            let name = &v.name;
             let static_type = en.static_type();
 
             let decls = tests.iter().map(|t| &t.decl);
@@ -463,35 +825,50 @@ fn encode_variant(
             let pair = b.cx.ident("pair");
             let content_tag = b.cx.ident("content_tag");
 
-            encode = quote! {{
-                static #hint: #map_hint = #map_hint::with_size(2);
-                #build_hint
+            // Empty variants, and variants which captured an unknown tag, are
+            // encoded without content.
+            if matches!(v.st.kind, StructKind::Empty) || v.capture.is_some() {
+                encode = quote! {{
+                    static #hint: #map_hint = #map_hint::with_size(1);
 
-                #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#struct_encoder| {
-                    static #tag_static: #static_type = #tag;
-                    static #name_static: #static_type = #name;
-                    static #content_static: #static_type = #content;
+                    #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#struct_encoder| {
+                        static #tag_static: #static_type = #tag;
+                        #name_decl
+                        #map_encoder_t::insert_entry(#struct_encoder, #tag_static, #name_ref)?;
+                        #result_ok(())
+                    })?
+                }};
+            } else {
+                encode = quote! {{
+                    static #hint: #map_hint = #map_hint::with_size(2);
+                    #build_hint
 
-                    #map_encoder_t::insert_entry(#struct_encoder, #tag_static, #name_static)?;
+                    #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#struct_encoder| {
+                        static #tag_static: #static_type = #tag;
+                        static #content_static: #static_type = #content;
+                        #name_decl
 
-                    #map_encoder_t::encode_entry_fn(#struct_encoder, move |#pair| {
-                        let #content_tag = #map_entry_encoder_t::encode_key(#pair)?;
-                        #encode_t_encode(&#content_static, #ctx_var, #content_tag)?;
+                        #map_encoder_t::insert_entry(#struct_encoder, #tag_static, #name_ref)?;
 
-                        let #content_struct = #map_entry_encoder_t::encode_value(#pair)?;
+                        #map_encoder_t::encode_entry_fn(#struct_encoder, move |#pair| {
+                            let #content_tag = #map_entry_encoder_t::encode_key(#pair)?;
+                            #encode_t_encode(&#content_static, #ctx_var, #content_tag)?;
+
+                            let #content_struct = #map_entry_encoder_t::encode_value(#pair)?;
+
+                            #encoder_t::encode_map_fn(#content_struct, &#inner_hint, move |#encoder_var| {
+                                #(#decls)*
+                                #(#encoders)*
+                                #result_ok(())
+                            })?;
 
-                        #encoder_t::encode_map_fn(#content_struct, &#inner_hint, move |#encoder_var| {
-                            #(#decls)*
-                            #(#encoders)*
                             #result_ok(())
                         })?;
 
                         #result_ok(())
-                    })?;
-
-                    #result_ok(())
-                })?
-            }};
+                    })?
+                }};
+            }
         }
     }
 
@@ -507,7 +884,11 @@ fn encode_variant(
     if cx.trace {
         let output_var = b.cx.ident("output");
 
-        let (decl, name) = en.name_format(&name_static, &v.name);
+        let (decl, name) = match &v.capture {
+            Some(..) => (None, syn::parse_quote!(#name_ref)),
+            None => en.name_format(&name_static, &v.name),
+        };
+
         let enter = quote!(#context_t::enter_variant(#ctx_var, #type_name, #name));
         let leave = quote!(#context_t::leave_variant(#ctx_var));
 
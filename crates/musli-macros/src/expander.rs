@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 
 use proc_macro2::{Span, TokenStream};
 use syn::spanned::Spanned;
+use syn::Token;
 
 use crate::internals::attr::{self, ModeIdent, ModeKind, TypeAttr};
 use crate::internals::build::Build;
@@ -64,6 +65,8 @@ pub(crate) struct VariantData<'a> {
     pub(crate) index: usize,
     pub(crate) attr: attr::VariantAttr,
     pub(crate) ident: &'a syn::Ident,
+    /// The explicit discriminant of the variant, if any.
+    pub(crate) discriminant: Option<&'a syn::Expr>,
     pub(crate) fields: Vec<FieldData<'a>>,
     pub(crate) kind: StructKind,
 }
@@ -94,6 +97,8 @@ pub(crate) struct Expander<'a> {
     pub(crate) data: Data<'a>,
     pub(crate) tokens: Tokens,
     pub(crate) default: Vec<ModeIdent>,
+    /// The remote type being implemented through `#[musli(remote = ..)]`.
+    pub(crate) remote: Option<syn::Path>,
 }
 
 impl<'a> Expander<'a> {
@@ -137,6 +142,7 @@ impl<'a> Expander<'a> {
                         name: syn::LitStr::new(&variant.ident.to_string(), variant.ident.span()),
                         attr: attr::variant_attrs(&cx, &variant.attrs),
                         ident: &variant.ident,
+                        discriminant: variant.discriminant.as_ref().map(|(_, expr)| expr),
                         fields: fields(&cx, &variant.fields),
                         kind: match &variant.fields {
                             syn::Fields::Unit => StructKind::Empty,
@@ -156,6 +162,20 @@ impl<'a> Expander<'a> {
 
         let prefix = type_attr.crate_or_default(default_crate);
 
+        // Generic arguments need to be written with a turbofish, since the
+        // remote path is used in expression and pattern position.
+        let remote = type_attr.remote_path().map(|(_, path)| {
+            let mut path = path.clone();
+
+            for segment in &mut path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token = Some(<Token![::]>::default());
+                }
+            }
+
+            path
+        });
+
         let default = vec![
             ModeIdent {
                 kind: ModeKind::Binary,
@@ -174,9 +194,36 @@ impl<'a> Expander<'a> {
             data,
             tokens: Tokens::new(input.ident.span(), prefix),
             default,
+            remote,
+        }
+    }
+
+    /// The path used to construct and match on the type being implemented.
+    ///
+    /// This is `Self`, unless a remote type is being implemented.
+    pub(crate) fn self_path(&self, span: Span) -> syn::Path {
+        match &self.remote {
+            Some(remote) => remote.clone(),
+            None => syn::Path::from(syn::Ident::new("Self", span)),
+        }
+    }
+
+    /// The expression which refers to the value being encoded.
+    ///
+    /// This is `self`, unless a remote type is being implemented in which case
+    /// the value is passed in as an argument.
+    pub(crate) fn self_value(&self) -> syn::Path {
+        match &self.remote {
+            Some(..) => syn::Path::from(self.remote_value_var()),
+            None => <Token![self]>::default().into(),
         }
     }
 
+    /// The argument holding the value of a remote type being encoded.
+    pub(crate) fn remote_value_var(&self) -> syn::Ident {
+        syn::Ident::new("value", Span::mixed_site())
+    }
+
     /// Coerce into errors.
     pub(crate) fn into_errors(self) -> Vec<syn::Error> {
         self.cx.into_errors()
@@ -220,9 +267,20 @@ impl<'a> Expander<'a> {
         let mut out = TokenStream::new();
 
         for build in builds {
+            // The size is only known for the binary storage format, so it is
+            // only expanded for the default binary mode.
+            if build.expansion.mode_ident.kind == ModeKind::Binary {
+                out.extend(crate::en::expand_packed_size(&build)?);
+            }
+
+            out.extend(crate::en::expand_encode_names(&build));
             out.extend(crate::en::expand_insert_entry(build)?);
         }
 
+        if let Some(remote) = &self.remote {
+            out.extend(crate::en::expand_remote_encode(self, remote));
+        }
+
         Ok(out)
     }
 
@@ -234,9 +292,14 @@ impl<'a> Expander<'a> {
         let mut out = TokenStream::new();
 
         for build in builds {
+            out.extend(crate::de::expand_decode_names(&build)?);
             out.extend(crate::de::expand_decode_entry(build)?);
         }
 
+        if let Some(remote) = &self.remote {
+            out.extend(crate::de::expand_remote_decode(self, remote));
+        }
+
         Ok(out)
     }
 }
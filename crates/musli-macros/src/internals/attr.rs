@@ -63,6 +63,9 @@ pub(crate) enum EnumTagging<'a> {
         tag: &'a syn::Expr,
         content: &'a syn::Expr,
     },
+    /// Only the content of the variant is encoded, and decoding tries each
+    /// variant in order.
+    Untagged,
 }
 
 /// If the type is tagged or not.
@@ -203,12 +206,26 @@ layer! {
         name_method: NameMethod,
         /// `#[musli(name_format_with)]`.
         name_format_with: syn::Path,
+        /// `#[musli(remote = <path>)]`.
+        remote: syn::Path,
         /// If `#[musli(tag = <expr>)]` is specified.
         tag: syn::Expr,
         /// If `#[musli(content = <expr>)]` is specified.
         content: syn::Expr,
+        /// If `#[musli(untagged)]` is specified.
+        untagged: (),
+        /// If `#[musli(deny_unknown_fields)]` is specified.
+        deny_unknown_fields: (),
         /// `#[musli(packed)]` or `#[musli(transparent)]`.
         packing: Packing,
+        /// If `#[musli(packed_size)]` is specified.
+        packed_size: (),
+        /// If `#[musli(tag_from_repr)]` is specified.
+        tag_from_repr: (),
+        /// If `#[musli(recover)]` is specified.
+        recover: (),
+        /// If `#[musli(name_case_insensitive)]` is specified.
+        name_case_insensitive: (),
         @multiple
         /// Bounds in a where predicate.
         bounds: syn::WherePredicate,
@@ -240,6 +257,12 @@ impl TypeAttr {
         })
     }
 
+    /// Get the remote type being implemented through
+    /// `#[musli(remote = <path>)]`, if any.
+    pub(crate) fn remote_path(&self) -> Option<&(Span, syn::Path)> {
+        self.root.remote.any.as_ref()
+    }
+
     /// Get the configured crate, or fallback to default.
     pub(crate) fn crate_or_default(&self, default: &str) -> syn::Path {
         if let Some((_, krate)) = self.root.krate.any.as_ref() {
@@ -296,6 +319,18 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(untagged)]
+            if meta.path.is_ident("untagged") {
+                new.untagged.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(deny_unknown_fields)]
+            if meta.path.is_ident("deny_unknown_fields") {
+                new.deny_unknown_fields.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(crate = <path>)]
             if meta.path.is_ident("crate") {
                 let path = if meta.input.parse::<Option<Token![=]>>()?.is_some() {
@@ -323,6 +358,13 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(remote = <path>)]
+            if meta.path.is_ident("remote") {
+                meta.input.parse::<Token![=]>()?;
+                new.remote.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
             // #[musli(bound = {..})]
             if meta.path.is_ident("bound") {
                 meta.input.parse::<Token![=]>()?;
@@ -349,6 +391,30 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(packed_size)]
+            if meta.path.is_ident("packed_size") {
+                new.packed_size.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(tag_from_repr)]
+            if meta.path.is_ident("tag_from_repr") {
+                new.tag_from_repr.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(recover)]
+            if meta.path.is_ident("recover") {
+                new.recover.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(name_case_insensitive)]
+            if meta.path.is_ident("name_case_insensitive") {
+                new.name_case_insensitive.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(name_all = "..")]
             if meta.path.is_ident("name_all") {
                 new.name_all
@@ -462,7 +528,13 @@ layer! {
         packing: Packing,
         /// `#[musli(default)]`.
         default_variant: (),
+        /// `#[musli(other)]`.
+        other: (),
+        /// `#[musli(skip)]`.
+        skip: (),
         @multiple
+        /// Additional names which the variant is decoded from.
+        alias: syn::Expr,
     }
 }
 
@@ -534,6 +606,13 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
                 return Ok(());
             }
 
+            // #[musli(alias = <expr>)]
+            if meta.path.is_ident("alias") {
+                meta.input.parse::<Token![=]>()?;
+                new.alias.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
             // #[musli(pattern = <expr>)]
             if meta.path.is_ident("pattern") {
                 meta.input.parse::<Token![=]>()?;
@@ -548,6 +627,18 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
                 return Ok(());
             }
 
+            // #[musli(other)]
+            if meta.path.is_ident("other") {
+                new.other.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(skip)]
+            if meta.path.is_ident("skip") {
+                new.skip.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(packed)]
             if meta.path.is_ident("packed") {
                 new.packing.push((meta.path.span(), Packing::Packed));
@@ -624,9 +715,13 @@ layer! {
         is_default: Option<syn::Path>,
         /// Use a default value for the field if it's not available.
         skip: (),
+        /// Inline the entries of the field into the containing map.
+        flatten: (),
         /// Field encoding to use.
         encoding: FieldEncoding,
         @multiple
+        /// Additional names which the field is decoded from.
+        alias: syn::Expr,
     }
 }
 
@@ -644,6 +739,39 @@ impl Field {
         }
     }
 
+    /// The trait encoding used to encode the field, unless it is encoded
+    /// through a custom path like `#[musli(with = ..)]`.
+    pub(crate) fn encode_trait(&self, mode: Mode<'_>) -> Option<FieldEncoding> {
+        if self.encode_path(mode).is_some() {
+            return None;
+        }
+
+        Some(self.encoding(mode).map(|&(_, e)| e).unwrap_or_default())
+    }
+
+    /// The trait encoding used to decode the field, unless it is decoded
+    /// through a custom path like `#[musli(with = ..)]`.
+    pub(crate) fn decode_trait(&self, mode: Mode<'_>) -> Option<FieldEncoding> {
+        if self.decode_path(mode).is_some() {
+            return None;
+        }
+
+        Some(self.encoding(mode).map(|&(_, e)| e).unwrap_or_default())
+    }
+
+    /// Expand in-place decode of the given field, if it uses the default
+    /// [`Decode`] implementation.
+    pub(crate) fn decode_in_place_path(&self, mode: Mode<'_>) -> Option<syn::Path> {
+        if self.decode_path(mode).is_some() {
+            return None;
+        }
+
+        match self.encoding(mode).map(|&(_, e)| e).unwrap_or_default() {
+            FieldEncoding::Default => Some(mode.decode_t_decode_in_place()),
+            _ => None,
+        }
+    }
+
     /// Expand decode of the given field.
     pub(crate) fn decode_path_expanded(&self, mode: Mode<'_>, span: Span) -> (Span, syn::Path) {
         let decode_path = self.decode_path(mode);
@@ -723,6 +851,22 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // parse #[musli(encode_with = <path>)]
+            if meta.path.is_ident("encode_with") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.encode_path.push((path.span(), path));
+                return Ok(());
+            }
+
+            // parse #[musli(decode_with = <path>)]
+            if meta.path.is_ident("decode_with") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.decode_path.push((path.span(), path));
+                return Ok(());
+            }
+
             // #[musli(skip_encoding_if = <path>)]
             if meta.path.is_ident("skip_encoding_if") {
                 meta.input.parse::<Token![=]>()?;
@@ -745,6 +889,13 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(alias = <expr>)]
+            if meta.path.is_ident("alias") {
+                meta.input.parse::<Token![=]>()?;
+                new.alias.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
             // #[musli(pattern = <expr>)]
             if meta.path.is_ident("pattern") {
                 meta.input.parse::<Token![=]>()?;
@@ -771,6 +922,12 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(flatten)]
+            if meta.path.is_ident("flatten") {
+                new.flatten.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(trace)]
             if meta.path.is_ident("trace") {
                 new.encoding.push((meta.path.span(), FieldEncoding::Trace));
@@ -1,6 +1,9 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
 use std::rc::Rc;
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
 use syn::punctuated::Punctuated;
 use syn::Token;
 
@@ -23,10 +26,18 @@ pub(crate) struct Build<'a> {
     pub(crate) bounds: &'a [(Span, syn::WherePredicate)],
     pub(crate) decode_bounds: &'a [(Span, syn::WherePredicate)],
     pub(crate) expansion: Expansion<'a>,
+    pub(crate) only: Only,
     pub(crate) data: BuildData<'a>,
     pub(crate) decode_t_decode: syn::Path,
     pub(crate) encode_t_encode: syn::Path,
     pub(crate) enum_tagging_span: Option<Span>,
+    pub(crate) untagged_span: Option<Span>,
+    /// The span of `#[musli(packed_size)]`, if specified.
+    pub(crate) packed_size_span: Option<Span>,
+    /// The remote type being implemented through `#[musli(remote = ..)]`.
+    pub(crate) remote: Option<&'a syn::Path>,
+    /// The expression which refers to the value being encoded.
+    pub(crate) self_value: syn::Path,
 }
 
 impl Build<'_> {
@@ -57,6 +68,96 @@ impl Build<'_> {
         );
     }
 
+    /// Infer the bounds of an `Encode` implementation, used unless bounds are
+    /// specified through `#[musli(bound = {..})]`.
+    pub(crate) fn infer_encode_bounds(&self) -> Vec<syn::WherePredicate> {
+        let mode = self.expansion.as_mode(self.tokens, self.only);
+        self.infer_bounds(|f| Some(mode.encode_t_bound(f.encode_trait?)))
+    }
+
+    /// Infer the bounds of a `Decode` implementation, used unless bounds are
+    /// specified through `#[musli(bound = {..})]` or
+    /// `#[musli(decode_bound = {..})]`.
+    pub(crate) fn infer_decode_bounds(&self, lt: &syn::Lifetime) -> Vec<syn::WherePredicate> {
+        let mode = self.expansion.as_mode(self.tokens, self.only);
+        self.infer_bounds(|f| Some(mode.decode_t_bound(f.decode_trait?, lt)))
+    }
+
+    /// Infer the bounds of a `PackedSize` implementation, which requires the
+    /// type of every field which is encoded through a trait to know its size.
+    pub(crate) fn infer_packed_size_bounds(&self) -> Vec<syn::WherePredicate> {
+        self.infer_bounds(|f| match f.encode_trait? {
+            FieldEncoding::Bytes => None,
+            _ => Some(self.tokens.packed_size_t.clone()),
+        })
+    }
+
+    /// Require that the type of every field which mentions a type parameter
+    /// implements the trait it's encoded through.
+    ///
+    /// Field types which refer to the type being derived are left out, since
+    /// bounding a type by its own implementation is cyclic. Recursion through
+    /// other types needs explicit bounds.
+    fn infer_bounds(
+        &self,
+        bound: impl Fn(&Field<'_>) -> Option<syn::Path>,
+    ) -> Vec<syn::WherePredicate> {
+        let params = self
+            .input
+            .generics
+            .type_params()
+            .map(|p| p.ident.to_string())
+            .collect::<HashSet<_>>();
+
+        if params.is_empty() {
+            return Vec::new();
+        }
+
+        let fields: Vec<&Field<'_>> = match &self.data {
+            BuildData::Struct(st) => st.unskipped_fields.iter().map(|f| &**f).collect(),
+            BuildData::Enum(en) => en
+                .variants
+                .iter()
+                .flat_map(|v| v.st.unskipped_fields.iter().map(|f| &**f))
+                .collect(),
+        };
+
+        let type_ident = self.input.ident.to_string();
+
+        let mut seen = HashSet::new();
+        let mut bounds = Vec::new();
+
+        for f in fields {
+            let Some(bound) = bound(f) else {
+                continue;
+            };
+
+            let ty = f.ty.to_token_stream();
+
+            let mut mentions_param = false;
+            let mut recursive = false;
+
+            visit_idents(ty.clone(), &mut |ident| {
+                let ident = ident.to_string();
+                mentions_param |= params.contains(&ident);
+                recursive |= ident == type_ident || ident == "Self";
+            });
+
+            if !mentions_param || recursive {
+                continue;
+            }
+
+            if !seen.insert((ty.to_string(), bound.to_token_stream().to_string())) {
+                continue;
+            }
+
+            let ty = f.ty;
+            bounds.push(syn::parse_quote_spanned!(f.span => #ty: #bound));
+        }
+
+        bounds
+    }
+
     /// Validate encode attributes.
     pub(crate) fn validate_encode(&self) -> Result<()> {
         self.validate()
@@ -80,6 +181,15 @@ impl Build<'_> {
 
                     return Err(());
                 }
+
+                if let Some(span) = self.untagged_span {
+                    self.cx.error_span(
+                        span,
+                        format_args!("#[{ATTR}(untagged)] is only supported on enums"),
+                    );
+
+                    return Err(());
+                }
             }
             BuildData::Enum(..) => (),
         }
@@ -88,6 +198,99 @@ impl Build<'_> {
     }
 }
 
+impl Build<'_> {
+    /// Expand an implementation of `names_t` for the struct `st`, which exposes
+    /// the names of its fields so that names which are shared with flattened
+    /// fields can be rejected at compile time.
+    pub(crate) fn expand_names(
+        &self,
+        st: &Body<'_>,
+        names_t: &syn::Path,
+        aliases: bool,
+        items: TokenStream,
+    ) -> TokenStream {
+        let Tokens {
+            priv_name,
+            priv_names,
+            ..
+        } = self.tokens;
+
+        let mode_ident = self.expansion.mode_path(self.tokens).as_path();
+
+        let mut names = Vec::new();
+        let mut flattened = Vec::new();
+        let mut generics = self.input.generics.clone();
+
+        for f in &st.unskipped_fields {
+            if f.flatten.is_some() {
+                let ty = f.ty;
+
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(#ty: #names_t<#mode_ident>));
+
+                flattened.push(quote!(<#ty as #names_t<#mode_ident>>::NAMES));
+                continue;
+            }
+
+            if f.pattern.is_some() {
+                continue;
+            }
+
+            let aliases = if aliases { f.aliases } else { &[] };
+
+            // Only names which are literals can be compared at compile time.
+            for name in iter::once(&f.name).chain(aliases.iter().map(|(_, alias)| alias)) {
+                let syn::Expr::Lit(syn::ExprLit { lit, .. }) = name else {
+                    continue;
+                };
+
+                match lit {
+                    syn::Lit::Str(string) => {
+                        names.push(quote!(#priv_name::String(#string)));
+                    }
+                    syn::Lit::Int(int) => {
+                        if let Ok(index) = int.base10_parse::<u128>() {
+                            names.push(quote!(#priv_name::Index(#index)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let type_ident = &self.input.ident;
+        let type_name = st.name;
+
+        // Generic containers are checked when they are used instead.
+        let check = (!flattened.is_empty() && self.input.generics.params.is_empty()).then(|| {
+            quote! {
+                const _: () = <#type_ident as #names_t<#mode_ident>>::CHECK;
+            }
+        });
+
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            const _: () = {
+                #[automatically_derived]
+                impl #impl_generics #names_t<#mode_ident> for #type_ident #type_generics #where_clause {
+                    const NAMES: #priv_names = #priv_names {
+                        ty: #type_name,
+                        names: &[#(#names),*],
+                        flattened: &[#(#flattened),*],
+                    };
+
+                    #items
+                }
+
+                #check
+            };
+        }
+    }
+}
+
 /// Build model for enums and structs.
 pub(crate) enum BuildData<'a> {
     Struct(Body<'a>),
@@ -105,6 +308,13 @@ pub(crate) struct Body<'a> {
     pub(crate) packing: Packing,
     pub(crate) kind: StructKind,
     pub(crate) path: syn::Path,
+    pub(crate) deny_unknown_fields: Option<Span>,
+    /// The span of `#[musli(recover)]`, if fields should recover from errors
+    /// when the context asks for it.
+    pub(crate) recover: Option<Span>,
+    /// The span of `#[musli(name_case_insensitive)]`, if field names should
+    /// be matched ignoring ASCII case.
+    pub(crate) name_case_insensitive: Option<Span>,
 }
 
 impl Body<'_> {
@@ -112,6 +322,47 @@ impl Body<'_> {
         if self.packing == Packing::Transparent && !matches!(&self.unskipped_fields[..], [_]) {
             cx.transparent_diagnostics(self.span, &self.unskipped_fields);
         }
+
+        if self.packing == Packing::Tagged {
+            validate_names(
+                cx,
+                "field",
+                self.name_case_insensitive.is_some(),
+                self.unskipped_fields
+                    .iter()
+                    .filter(|f| f.pattern.is_none() && f.flatten.is_none())
+                    .map(|f| names(f.name_span, &f.name, f.aliases)),
+            );
+
+            if let Some(span) = self.deny_unknown_fields {
+                if self.unskipped_fields.iter().any(|f| f.flatten.is_some()) {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(deny_unknown_fields)] cannot be combined with #[{ATTR}(flatten)] fields"
+                        ),
+                    );
+                }
+            }
+        } else {
+            for f in &self.unskipped_fields {
+                if let Some((span, _)) = f.skip_encoding_if {
+                    cx.map_only_diagnostics(*span, "skip_encoding_if", self.packing);
+                }
+
+                if let Some(span) = f.flatten {
+                    cx.map_only_diagnostics(span, "flatten", self.packing);
+                }
+            }
+
+            if let Some(span) = self.deny_unknown_fields {
+                cx.map_only_diagnostics(span, "deny_unknown_fields", self.packing);
+            }
+
+            if let Some(span) = self.recover {
+                cx.map_only_diagnostics(span, "recover", self.packing);
+            }
+        }
     }
 
     pub(crate) fn name_format(&self, value: &syn::Expr) -> syn::Expr {
@@ -140,11 +391,17 @@ pub(crate) struct Enum<'a> {
     pub(crate) enum_tagging: EnumTagging<'a>,
     pub(crate) enum_packing: Packing,
     pub(crate) variants: Vec<Variant<'a>>,
-    pub(crate) fallback: Option<&'a syn::Ident>,
+    /// Variants which are skipped through `#[musli(skip)]`, these cannot be
+    /// encoded and are never decoded.
+    pub(crate) skipped_variants: Vec<Variant<'a>>,
+    pub(crate) fallback: Option<Fallback<'a>>,
     pub(crate) name_type: syn::Type,
     pub(crate) name_method: NameMethod,
     pub(crate) name_format_with: Option<&'a (Span, syn::Path)>,
     pub(crate) packing_span: Option<&'a (Span, Packing)>,
+    /// The span of `#[musli(name_case_insensitive)]`, if variant names should
+    /// be matched ignoring ASCII case.
+    pub(crate) name_case_insensitive: Option<Span>,
 }
 
 impl Enum<'_> {
@@ -179,13 +436,43 @@ impl Enum<'_> {
     }
 }
 
+/// The variant which unknown tags are decoded into.
+pub(crate) struct Fallback<'a> {
+    pub(crate) ident: &'a syn::Ident,
+    pub(crate) path: syn::Path,
+    /// The field the unknown tag is stored in, if any.
+    pub(crate) capture: Option<Rc<Field<'a>>>,
+}
+
+impl Fallback<'_> {
+    /// Construct the fallback variant, storing `tag` in the capturing field.
+    pub(crate) fn construct(&self, tag: impl ToTokens) -> TokenStream {
+        let path = &self.path;
+
+        match &self.capture {
+            Some(field) => {
+                let member = &field.member;
+                quote!(#path { #member: #tag })
+            }
+            None => quote!(#path {}),
+        }
+    }
+}
+
 pub(crate) struct Variant<'a> {
     pub(crate) span: Span,
     pub(crate) index: usize,
     pub(crate) name: syn::Expr,
+    /// The span of an explicit `#[musli(name = ..)]`, or of the item itself.
+    pub(crate) name_span: Span,
+    pub(crate) aliases: &'a [(Span, syn::Expr)],
     pub(crate) pattern: Option<&'a syn::Pat>,
+    /// The variant is skipped through `#[musli(skip)]`.
+    pub(crate) skip: Option<Span>,
     pub(crate) st: Body<'a>,
     pub(crate) patterns: Punctuated<syn::FieldPat, Token![,]>,
+    /// The field of a `#[musli(other)]` variant which holds the unknown tag.
+    pub(crate) capture: Option<Rc<Field<'a>>>,
 }
 
 pub(crate) struct Field<'a> {
@@ -193,12 +480,26 @@ pub(crate) struct Field<'a> {
     pub(crate) index: usize,
     pub(crate) encode_path: (Span, syn::Path),
     pub(crate) decode_path: (Span, syn::Path),
+    /// The trait the field is encoded through, unless it uses a custom
+    /// encoding path.
+    pub(crate) encode_trait: Option<FieldEncoding>,
+    /// The trait the field is decoded through, unless it uses a custom
+    /// decoding path.
+    pub(crate) decode_trait: Option<FieldEncoding>,
+    /// Path used to decode the field in place, if it uses the default
+    /// [`Decode`] implementation.
+    pub(crate) decode_in_place_path: Option<syn::Path>,
     pub(crate) name: syn::Expr,
+    /// The span of an explicit `#[musli(name = ..)]`, or of the item itself.
+    pub(crate) name_span: Span,
+    pub(crate) aliases: &'a [(Span, syn::Expr)],
     pub(crate) pattern: Option<&'a syn::Pat>,
     /// Skip field entirely and always initialize with the specified expresion,
     /// or default value through `default_attr`.
     pub(crate) skip: Option<Span>,
     pub(crate) skip_encoding_if: Option<&'a (Span, syn::Path)>,
+    /// Inline the entries of the field into the containing map.
+    pub(crate) flatten: Option<Span>,
     /// Fill with default value, if missing.
     pub(crate) default_attr: Option<(Span, Option<&'a syn::Path>)>,
     pub(crate) self_access: syn::Expr,
@@ -238,10 +539,15 @@ pub(crate) fn setup<'a>(
         bounds: e.type_attr.bounds(mode),
         decode_bounds: e.type_attr.decode_bounds(mode),
         expansion,
+        only,
         data,
         decode_t_decode: mode.decode_t_decode(FieldEncoding::Default),
         encode_t_encode: mode.encode_t_encode(FieldEncoding::Default),
         enum_tagging_span: e.type_attr.enum_tagging_span(mode),
+        untagged_span: e.type_attr.untagged(mode).map(|&(span, ())| span),
+        packed_size_span: e.type_attr.packed_size(mode).map(|&(span, ())| span),
+        remote: e.remote.as_ref(),
+        self_value: e.self_value(),
     })
 }
 
@@ -268,7 +574,14 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
         ),
     };
 
-    let path = syn::Path::from(syn::Ident::new("Self", e.input.ident.span()));
+    if let Some(&(span, ())) = e.type_attr.tag_from_repr(mode) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(tag_from_repr)] is only supported on enums"),
+        );
+    }
+
+    let path = e.self_path(e.input.ident.span());
 
     for f in &data.fields {
         let field = Rc::new(setup_field(e, mode, f, name_all, packing, None));
@@ -291,6 +604,15 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
         packing,
         kind: data.kind,
         path,
+        deny_unknown_fields: e
+            .type_attr
+            .deny_unknown_fields(mode)
+            .map(|&(span, ())| span),
+        recover: e.type_attr.recover(mode).map(|&(span, ())| span),
+        name_case_insensitive: e
+            .type_attr
+            .name_case_insensitive(mode)
+            .map(|&(span, ())| span),
     };
 
     body.validate(&e.cx);
@@ -299,13 +621,41 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
 
 fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> Enum<'a> {
     let mut variants = Vec::with_capacity(data.variants.len());
+    let mut skipped_variants = Vec::new();
     let mut fallback = None;
 
     let packing_span = e.type_attr.packing(mode);
 
-    let enum_tagging = match e.type_attr.enum_tagging(mode) {
-        Some(enum_tagging) => enum_tagging,
-        None => {
+    let enum_tagging = match (e.type_attr.untagged(mode), e.type_attr.enum_tagging(mode)) {
+        (Some(&(span, ())), enum_tagging) => {
+            if enum_tagging.is_some() {
+                e.cx.error_span(span, format_args!("#[{ATTR}(untagged)] cannot be combined with #[{ATTR}(tag)] or #[{ATTR}(content)]"));
+            }
+
+            match packing_span {
+                Some(&(span, Packing::Packed)) => {
+                    e.cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(packed)] cannot be combined with #[{ATTR}(untagged)]"
+                        ),
+                    );
+                }
+                Some(&(span, Packing::Transparent)) => {
+                    e.cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}(transparent)] cannot be combined with #[{ATTR}(untagged)]"
+                        ),
+                    );
+                }
+                _ => (),
+            }
+
+            EnumTagging::Untagged
+        }
+        (None, Some(enum_tagging)) => enum_tagging,
+        (None, None) => {
             if data
                 .variants
                 .iter()
@@ -318,7 +668,10 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         }
     };
 
-    if !matches!(enum_tagging, EnumTagging::Default | EnumTagging::Empty) {
+    if matches!(
+        enum_tagging,
+        EnumTagging::Internal { .. } | EnumTagging::Adjacent { .. }
+    ) {
         match packing_span {
             Some((_, Packing::Tagged)) => (),
             Some(&(span, Packing::Packed)) => {
@@ -337,28 +690,209 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         .map(|&(_, p)| p)
         .unwrap_or_default();
 
-    let (_, name_type, name_method) = split_name(
+    let (_, mut name_type, mut name_method) = split_name(
         mode.kind,
         e.type_attr.name_type(mode),
         e.type_attr.name_all(mode),
         e.type_attr.name_method(mode),
     );
 
+    let mut discriminants = None;
+
+    if let Some(&(span, ())) = e.type_attr.tag_from_repr(mode) {
+        if let Some(repr) = setup_tag_from_repr(e, mode, span) {
+            discriminants = Some(Discriminants::new(repr.clone()));
+            name_type = syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: repr.into(),
+            });
+            name_method = NameMethod::Value;
+        }
+    }
+
     for v in &data.variants {
-        variants.push(setup_variant(e, mode, v, &mut fallback));
+        let discriminant = discriminants.as_mut().map(|d| d.next(v.discriminant));
+        let variant = setup_variant(e, mode, v, discriminant, &mut fallback);
+
+        if variant.skip.is_some() {
+            skipped_variants.push(variant);
+        } else {
+            variants.push(variant);
+        }
     }
 
+    let name_case_insensitive = e
+        .type_attr
+        .name_case_insensitive(mode)
+        .map(|&(span, ())| span);
+
+    validate_names(
+        &e.cx,
+        "variant",
+        name_case_insensitive.is_some(),
+        variants
+            .iter()
+            .filter(|v| v.pattern.is_none() && v.capture.is_none())
+            .map(|v| names(v.name_span, &v.name, v.aliases)),
+    );
+
     Enum {
         span: data.span,
         name: &data.name,
         enum_tagging,
         enum_packing,
         variants,
+        skipped_variants,
         fallback,
         name_type,
         name_method,
         name_format_with: e.type_attr.name_format_with(mode),
         packing_span,
+        name_case_insensitive,
+    }
+}
+
+/// Validate the use of `#[musli(tag_from_repr)]` and get the integer
+/// representation of the enum.
+fn setup_tag_from_repr(e: &Expander<'_>, mode: Mode<'_>, span: Span) -> Option<syn::Ident> {
+    for (name, conflict) in [
+        (
+            "name_type",
+            e.type_attr.name_type(mode).map(|&(span, _)| span),
+        ),
+        (
+            "name_all",
+            e.type_attr.name_all(mode).map(|&(span, _)| span),
+        ),
+        (
+            "name_method",
+            e.type_attr.name_method(mode).map(|&(span, _)| span),
+        ),
+    ] {
+        if let Some(conflict) = conflict {
+            e.cx.error_span(
+                conflict,
+                format_args!("#[{ATTR}({name})] cannot be combined with #[{ATTR}(tag_from_repr)]"),
+            );
+        }
+    }
+
+    let repr = integer_repr(&e.input.attrs);
+
+    if repr.is_none() {
+        e.cx.error_span(
+            span,
+            format_args!(
+                "#[{ATTR}(tag_from_repr)] requires an integer representation, like #[repr(u8)]"
+            ),
+        );
+    }
+
+    repr
+}
+
+/// Find the integer type in a `#[repr(..)]` attribute, if any.
+fn integer_repr(attrs: &[syn::Attribute]) -> Option<syn::Ident> {
+    const INTEGERS: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    let mut repr = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("repr") {
+            continue;
+        }
+
+        // Other representations like `C` or `align(..)` are ignored, and
+        // malformed attributes are reported by the compiler.
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGERS.contains(&ident.to_string().as_str()) {
+                    repr = Some(ident.clone());
+                }
+            }
+
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+
+            Ok(())
+        });
+    }
+
+    repr
+}
+
+/// Computes the discriminant of each variant for `#[musli(tag_from_repr)]`.
+///
+/// Variants without an explicit discriminant have the value of the previous
+/// variant plus one, so discriminants which aren't integer literals are
+/// expanded as an expression relative to the last explicit one.
+struct Discriminants<'a> {
+    repr: syn::Ident,
+    base: Option<&'a syn::Expr>,
+    value: i128,
+}
+
+impl<'a> Discriminants<'a> {
+    fn new(repr: syn::Ident) -> Self {
+        Self {
+            repr,
+            base: None,
+            value: -1,
+        }
+    }
+
+    /// Get the tag of the next variant.
+    fn next(&mut self, discriminant: Option<&'a syn::Expr>) -> syn::Expr {
+        match discriminant.map(|expr| (expr, int_literal(expr))) {
+            Some((_, Some(value))) => {
+                self.base = None;
+                self.value = value;
+            }
+            Some((expr, None)) => {
+                self.base = Some(expr);
+                self.value = 0;
+            }
+            None => {
+                self.value += 1;
+            }
+        }
+
+        let repr = &self.repr;
+
+        if let Some(base) = self.base {
+            let offset = syn::LitInt::new(&format!("{}{repr}", self.value), repr.span());
+            return syn::parse_quote!((#base) as #repr + #offset);
+        }
+
+        let lit = syn::LitInt::new(&format!("{}{repr}", self.value.unsigned_abs()), repr.span());
+
+        if self.value < 0 {
+            syn::parse_quote!(-#lit)
+        } else {
+            syn::parse_quote!(#lit)
+        }
+    }
+}
+
+/// Parse an integer literal, which might be negated.
+fn int_literal(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(..),
+            expr,
+            ..
+        }) => int_literal(expr)?.checked_neg(),
+        syn::Expr::Group(syn::ExprGroup { expr, .. })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. }) => int_literal(expr),
+        _ => None,
     }
 }
 
@@ -366,7 +900,8 @@ fn setup_variant<'a>(
     e: &'a Expander<'_>,
     mode: Mode<'_>,
     data: &'a VariantData<'a>,
-    fallback: &mut Option<&'a syn::Ident>,
+    discriminant: Option<syn::Expr>,
+    fallback: &mut Option<Fallback<'a>>,
 ) -> Variant<'a> {
     let mut unskipped_fields = Vec::with_capacity(data.fields.len());
     let mut all_fields = Vec::with_capacity(data.fields.len());
@@ -398,30 +933,84 @@ fn setup_variant<'a>(
         e.type_attr.name_method(mode),
     );
 
-    let name = expander::expand_name(data, mode, type_name_all, Some(data.ident));
+    let name = match discriminant {
+        Some(discriminant) => {
+            if let Some(&(span, _)) = data.attr.name(mode) {
+                e.cx.error_span(
+                    span,
+                    format_args!(
+                        "#[{ATTR}(name)] cannot be combined with #[{ATTR}(tag_from_repr)]"
+                    ),
+                );
+            }
+
+            discriminant
+        }
+        None => expander::expand_name(data, mode, type_name_all, Some(data.ident)),
+    };
+
+    let name_span = data.attr.name(mode).map_or(data.span, |&(span, _)| span);
 
+    let aliases = data.attr.alias(mode);
     let pattern = data.attr.pattern(mode).map(|(_, p)| p);
 
-    let mut path = syn::Path::from(syn::Ident::new("Self", data.span));
+    let mut path = e.self_path(data.span);
     path.segments.push(data.ident.clone().into());
 
+    let other = data.attr.other(mode).map(|&(span, ())| span);
+    let skip = data.attr.skip(mode).map(|&(span, ())| span);
+    let mut is_fallback = false;
+
+    if let Some(span) = skip {
+        if data.attr.default_variant(mode).is_some() {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(skip)] cannot be combined with #[{ATTR}(default)]"),
+            );
+        }
+
+        if other.is_some() {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(skip)] cannot be combined with #[{ATTR}(other)]"),
+            );
+        }
+    }
+
     if let Some((span, _)) = data.attr.default_variant(mode) {
         if !data.fields.is_empty() {
             e.cx.error_span(
                 *span,
                 format_args!("#[{ATTR}(default)] variant must be empty"),
             );
-        } else if fallback.is_some() {
+        } else if fallback.is_some() || other.is_some() {
             e.cx.error_span(
                 *span,
                 format_args!("#[{ATTR}(default)] only one fallback variant is supported",),
             );
         } else {
-            *fallback = Some(data.ident);
+            is_fallback = true;
+        }
+    }
+
+    if let Some(span) = other {
+        if data.fields.len() > 1 {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(other)] variant must be empty or have a single field"),
+            );
+        } else if fallback.is_some() {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(other)] only one fallback variant is supported"),
+            );
+        } else {
+            is_fallback = true;
         }
     }
 
     let mut patterns = Punctuated::default();
+    let mut capture = None;
 
     for f in &data.fields {
         let field = Rc::new(setup_field(
@@ -433,13 +1022,48 @@ fn setup_variant<'a>(
             Some(&mut patterns),
         ));
 
-        if field.skip.is_none() {
+        if let Some(span) = field.flatten {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(flatten)] is only supported on fields of structs"),
+            );
+        }
+
+        // The field capturing the tag is not part of the body of the
+        // variant.
+        if is_fallback && other.is_some() {
+            capture = Some(field.clone());
+        } else if field.skip.is_none() {
             unskipped_fields.push(field.clone());
         }
 
         all_fields.push(field);
     }
 
+    if let (Some(span), Some(..)) = (other, &capture) {
+        if variant_packing != Packing::Tagged {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(other)] variant which captures the tag cannot be packed or transparent"),
+            );
+        }
+
+        if e.type_attr.untagged(mode).is_some() {
+            e.cx.error_span(
+                span,
+                format_args!("#[{ATTR}(other)] variant cannot capture the tag of an untagged enum"),
+            );
+        }
+    }
+
+    if is_fallback {
+        *fallback = Some(Fallback {
+            ident: data.ident,
+            path: path.clone(),
+            capture: capture.clone(),
+        });
+    }
+
     let st = Body {
         span: data.span,
         name: &data.name,
@@ -451,6 +1075,21 @@ fn setup_variant<'a>(
         name_method,
         name_format_with: data.attr.name_format_with(mode),
         path,
+        // Applies to every variant which is encoded as a map.
+        deny_unknown_fields: e
+            .type_attr
+            .deny_unknown_fields(mode)
+            .filter(|_| variant_packing == Packing::Tagged)
+            .map(|&(span, ())| span),
+        recover: e
+            .type_attr
+            .recover(mode)
+            .filter(|_| variant_packing == Packing::Tagged)
+            .map(|&(span, ())| span),
+        name_case_insensitive: e
+            .type_attr
+            .name_case_insensitive(mode)
+            .map(|&(span, ())| span),
     };
 
     st.validate(&e.cx);
@@ -459,9 +1098,13 @@ fn setup_variant<'a>(
         span: data.span,
         index: data.index,
         name,
+        name_span,
+        aliases,
         pattern,
+        skip,
         patterns,
         st,
+        capture,
     }
 }
 
@@ -475,12 +1118,18 @@ fn setup_field<'a>(
 ) -> Field<'a> {
     let encode_path = data.attr.encode_path_expanded(mode, data.span);
     let decode_path = data.attr.decode_path_expanded(mode, data.span);
+    let decode_in_place_path = data.attr.decode_in_place_path(mode);
+    let encode_trait = data.attr.encode_trait(mode);
+    let decode_trait = data.attr.decode_trait(mode);
 
     let name = expander::expand_name(data, mode, name_all, data.ident);
+    let name_span = data.attr.name(mode).map_or(data.span, |&(span, _)| span);
+    let aliases = data.attr.alias(mode);
     let pattern = data.attr.pattern(mode).map(|(_, p)| p);
 
     let skip = data.attr.skip(mode).map(|&(s, ())| s);
     let skip_encoding_if = data.attr.skip_encoding_if(mode);
+    let flatten = data.attr.flatten(mode).map(|&(s, ())| s);
     let default_attr = data
         .attr
         .is_default(mode)
@@ -541,7 +1190,7 @@ fn setup_field<'a>(
             base: Box::new(syn::Expr::Path(syn::ExprPath {
                 attrs: Vec::new(),
                 qself: None,
-                path: <Token![self]>::default().into(),
+                path: e.self_value(),
             })),
             dot_token: <Token![.]>::default(),
             member: member.clone(),
@@ -567,10 +1216,16 @@ fn setup_field<'a>(
         index: data.index,
         encode_path,
         decode_path,
+        encode_trait,
+        decode_trait,
+        decode_in_place_path,
         name,
+        name_span,
+        aliases,
         pattern,
         skip,
         skip_encoding_if,
+        flatten,
         default_attr,
         self_access,
         member,
@@ -580,6 +1235,88 @@ fn setup_field<'a>(
     }
 }
 
+/// Visit every identifier in a stream of tokens.
+fn visit_idents(tokens: TokenStream, f: &mut dyn FnMut(&proc_macro2::Ident)) {
+    for tt in tokens {
+        match tt {
+            proc_macro2::TokenTree::Ident(ident) => f(&ident),
+            proc_macro2::TokenTree::Group(group) => visit_idents(group.stream(), f),
+            _ => {}
+        }
+    }
+}
+
+/// Iterate over the primary name and the aliases of a field or variant.
+fn names<'a>(
+    span: Span,
+    name: &'a syn::Expr,
+    aliases: &'a [(Span, syn::Expr)],
+) -> impl Iterator<Item = (Span, &'a syn::Expr)> {
+    iter::once((span, name)).chain(aliases.iter().map(|(span, alias)| (*span, alias)))
+}
+
+/// Emit an error for every name which is claimed more than once.
+fn validate_names<'a, I>(
+    cx: &Ctxt,
+    what: &str,
+    ignore_case: bool,
+    items: impl IntoIterator<Item = I>,
+) where
+    I: IntoIterator<Item = (Span, &'a syn::Expr)>,
+{
+    let mut seen = HashMap::new();
+
+    for (index, names) in items.into_iter().enumerate() {
+        for (span, name) in names {
+            let key = name_key(name, ignore_case);
+
+            let Some(existing) = seen.insert(key.clone(), index) else {
+                continue;
+            };
+
+            if ignore_case {
+                // Names of the same item which only differ by case are
+                // redundant, but don't conflict.
+                if existing == index {
+                    continue;
+                }
+
+                cx.error_span(
+                    span,
+                    format_args!(
+                        "#[{ATTR}] name {key} is claimed by more than one {what} when ignoring case"
+                    ),
+                );
+            } else {
+                cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}] name {key} is claimed by more than one {what}"),
+                );
+            }
+        }
+    }
+}
+
+/// Normalize a name so that literals which are spelled differently but
+/// compare equal are detected as conflicting.
+fn name_key(expr: &syn::Expr, ignore_case: bool) -> String {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_digits().to_owned(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) if ignore_case => format!("{:?}", lit.value().to_ascii_lowercase()),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => format!("{:?}", lit.value()),
+        expr => expr.to_token_stream().to_string(),
+    }
+}
+
 fn split_name(
     kind: Option<&ModeKind>,
     name_type: Option<&(Span, syn::Type)>,
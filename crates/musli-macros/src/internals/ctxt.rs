@@ -5,7 +5,7 @@ use std::rc::Rc;
 
 use proc_macro2::Span;
 
-use super::attr::{ModeIdent, ModeKind};
+use super::attr::{ModeIdent, ModeKind, Packing};
 use super::build::Field;
 use super::ATTR;
 
@@ -57,6 +57,23 @@ impl Ctxt {
         }
     }
 
+    /// Emit diagnostics for an attribute like `#[musli(skip_encoding_if)]`
+    /// which requires a container encoded as a map.
+    pub(crate) fn map_only_diagnostics(&self, span: Span, attr: &str, packing: Packing) {
+        let packing = match packing {
+            Packing::Packed => "packed",
+            Packing::Transparent => "transparent",
+            Packing::Tagged => return,
+        };
+
+        self.error_span(
+            span,
+            format_args!(
+                "#[{ATTR}({attr})] can only be used in containers encoded as maps, not #[{ATTR}({packing})]",
+            ),
+        );
+    }
+
     /// Register a new mode.
     pub(crate) fn register_mode(&self, mode: ModeIdent) {
         self.inner
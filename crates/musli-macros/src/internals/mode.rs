@@ -86,6 +86,79 @@ impl<'a> Mode<'a> {
 
         decode_t
     }
+
+    /// Construct a typed in-place decode call.
+    pub(crate) fn decode_t_decode_in_place(&self) -> syn::Path {
+        let mut decode_t = self.tokens.decode_t.clone();
+
+        if let Some(segment) = decode_t.segments.last_mut() {
+            add_mode_argument(&self.mode_path, segment);
+        }
+
+        decode_t
+            .segments
+            .push(syn::PathSegment::from(syn::Ident::new(
+                "decode_in_place",
+                decode_t.span(),
+            )));
+
+        decode_t
+    }
+    /// Construct the encode trait a field must implement, for use in bounds.
+    pub(crate) fn encode_t_bound(&self, encoding: FieldEncoding) -> syn::Path {
+        let mut encode_t = match encoding {
+            FieldEncoding::Packed => self.tokens.encode_packed_t.clone(),
+            FieldEncoding::Bytes => self.tokens.encode_bytes_t.clone(),
+            FieldEncoding::Trace => self.tokens.trace_encode_t.clone(),
+            FieldEncoding::Default => self.tokens.encode_t.clone(),
+        };
+
+        if let Some(segment) = encode_t.segments.last_mut() {
+            segment.arguments = bound_arguments(&self.mode_path, None);
+        }
+
+        encode_t
+    }
+
+    /// Construct the decode trait a field must implement, for use in bounds.
+    pub(crate) fn decode_t_bound(&self, encoding: FieldEncoding, lt: &syn::Lifetime) -> syn::Path {
+        let mut decode_t = match encoding {
+            FieldEncoding::Packed => self.tokens.decode_packed_t.clone(),
+            FieldEncoding::Bytes => self.tokens.decode_bytes_t.clone(),
+            FieldEncoding::Trace => self.tokens.trace_decode_t.clone(),
+            FieldEncoding::Default => self.tokens.decode_t.clone(),
+        };
+
+        if let Some(segment) = decode_t.segments.last_mut() {
+            segment.arguments = bound_arguments(&self.mode_path, Some(lt));
+        }
+
+        decode_t
+    }
+}
+
+fn bound_arguments(mode_path: &ModePath<'_>, lt: Option<&syn::Lifetime>) -> syn::PathArguments {
+    let mut arguments = syn::AngleBracketedGenericArguments {
+        colon2_token: None,
+        lt_token: <Token![<]>::default(),
+        args: Punctuated::default(),
+        gt_token: <Token![>]>::default(),
+    };
+
+    if let Some(lt) = lt {
+        arguments
+            .args
+            .push(syn::GenericArgument::Lifetime(lt.clone()));
+    }
+
+    arguments
+        .args
+        .push(syn::GenericArgument::Type(syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: mode_path.as_path(),
+        })));
+
+    syn::PathArguments::AngleBracketed(arguments)
 }
 
 fn add_mode_argument(moded_ident: &ModePath<'_>, last: &mut syn::PathSegment) {
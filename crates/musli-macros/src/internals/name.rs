@@ -61,75 +61,96 @@ impl NameAll {
 
     /// Apply the given rename to the input string.
     pub(crate) fn apply(&self, input: &str) -> Option<String> {
-        let feed: fn(output: &mut String, open: bool, count: usize, c: char) = match self {
+        let (separator, casing) = match self {
             Self::Index => return None,
             Self::Name => return Some(input.to_string()),
-            Self::PascalCase => |output, open, _, c| {
-                if open {
-                    output.extend(c.to_uppercase());
-                } else {
-                    output.extend(c.to_lowercase());
-                }
-            },
-            Self::CamelCase => |output, open, count, c| {
-                if open && count > 0 {
-                    output.extend(c.to_uppercase());
-                } else {
-                    output.extend(c.to_lowercase());
-                }
-            },
-            Self::SnakeCase => |output, _, _, c| {
-                output.extend(c.to_lowercase());
-            },
-            Self::ScreamingSnakeCase => |output, _, _, c| {
-                output.extend(c.to_uppercase());
-            },
-            Self::KebabCase => |output, _, _, c| {
-                output.extend(c.to_lowercase());
-            },
-            Self::ScreamingKebabCase => |output, _, _, c| {
-                output.extend(c.to_uppercase());
-            },
-        };
-
-        let prefix = match self {
-            Self::SnakeCase => Some('_'),
-            Self::ScreamingSnakeCase => Some('_'),
-            Self::KebabCase => Some('-'),
-            Self::ScreamingKebabCase => Some('-'),
-            _ => None,
+            Self::PascalCase => (None, Casing::Capitalized),
+            Self::CamelCase => (None, Casing::Camel),
+            Self::SnakeCase => (Some('_'), Casing::Lower),
+            Self::ScreamingSnakeCase => (Some('_'), Casing::Upper),
+            Self::KebabCase => (Some('-'), Casing::Lower),
+            Self::ScreamingKebabCase => (Some('-'), Casing::Upper),
         };
 
         let mut output = String::new();
-        let mut count = 0;
-        let mut group = true;
-
-        for c in input.chars() {
-            if matches!(c, '_') {
-                if !group {
-                    count += 1;
-                }
 
-                group = true;
-                continue;
+        for (index, word) in words(input).enumerate() {
+            if index > 0 {
+                output.extend(separator);
             }
 
-            if char::is_uppercase(c) && !group {
-                group = true;
-                count += 1;
+            for (n, c) in word.chars().enumerate() {
+                let upper = match casing {
+                    Casing::Lower => false,
+                    Casing::Upper => true,
+                    Casing::Capitalized => n == 0,
+                    Casing::Camel => n == 0 && index > 0,
+                };
+
+                if upper {
+                    output.extend(c.to_uppercase());
+                } else {
+                    output.extend(c.to_lowercase());
+                }
             }
+        }
+
+        Some(output)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Casing {
+    Lower,
+    Upper,
+    Capitalized,
+    Camel,
+}
 
-            let group = take(&mut group);
+/// Split an identifier into words.
+///
+/// Words are separated by underscores, or by an uppercase character which
+/// follows a lowercase character or a digit. In a run of consecutive uppercase
+/// characters, the last one starts a new word if it's followed by a lowercase
+/// character, so that `HTTPServer` is split into `HTTP` and `Server`.
+///
+/// Digits never start a new word, so they stay attached to the letters they
+/// directly follow, like `Field1` or `HTTP2`. A run of digits which is
+/// separated by an underscore is its own word, so `field_1` consists of the
+/// words `field` and `1`.
+fn words(input: &str) -> impl Iterator<Item = &str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut chars = input.char_indices().peekable();
+    let mut prev = None::<char>;
+
+    while let Some((n, c)) = chars.next() {
+        if c == '_' {
+            words.extend(take(&mut start).map(|start| &input[start..n]));
+            prev = None;
+            continue;
+        }
+
+        if let (Some(s), Some(p)) = (start, prev) {
+            if c.is_uppercase() {
+                let next_lower = chars.peek().is_some_and(|&(_, c)| c.is_lowercase());
 
-            if group && count > 0 {
-                output.extend(prefix);
+                if p.is_lowercase() || p.is_numeric() || (p.is_uppercase() && next_lower) {
+                    words.push(&input[s..n]);
+                    start = None;
+                }
             }
+        }
 
-            feed(&mut output, group, count, c);
+        if start.is_none() {
+            start = Some(n);
         }
 
-        Some(output)
+        prev = Some(c);
     }
+
+    words.extend(start.map(|start| &input[start..]));
+    words.into_iter()
 }
 
 impl fmt::Display for NameAll {
@@ -181,3 +202,43 @@ fn test_rename() {
     test("HelloWorld", "SCREAMING-KEBAB-CASE", "HELLO-WORLD");
     test("__Hello__World__", "SCREAMING-KEBAB-CASE", "HELLO-WORLD");
 }
+
+#[test]
+fn test_rename_words() {
+    #[track_caller]
+    fn test(input: &str, rename: &str, expected: &str) {
+        let rename = NameAll::parse(rename).unwrap();
+        assert_eq!(rename.apply(input).unwrap(), expected);
+    }
+
+    test("HTTPServer", "snake_case", "http_server");
+    test("HTTPServer", "camelCase", "httpServer");
+    test("HTTPServer", "PascalCase", "HttpServer");
+    test("HTTPServer", "kebab-case", "http-server");
+    test("XMLHttpRequest", "SCREAMING_SNAKE_CASE", "XML_HTTP_REQUEST");
+    test("IOError", "snake_case", "io_error");
+    test("ID", "snake_case", "id");
+    test("ID", "camelCase", "id");
+    test("user_id", "camelCase", "userId");
+
+    test("field1", "PascalCase", "Field1");
+    test("field1", "camelCase", "field1");
+    test("field1", "snake_case", "field1");
+    test("field_1", "PascalCase", "Field1");
+    test("field_1", "camelCase", "field1");
+    test("field_1", "snake_case", "field_1");
+    test("field_1", "kebab-case", "field-1");
+    test("field_1", "SCREAMING_SNAKE_CASE", "FIELD_1");
+    test("Field1", "PascalCase", "Field1");
+    test("Field1", "camelCase", "field1");
+    test("Field1", "snake_case", "field1");
+    test("Field1", "kebab-case", "field1");
+    test("field_12_value", "camelCase", "field12Value");
+    test("Field12Value", "snake_case", "field12_value");
+    test("field_12_value", "snake_case", "field_12_value");
+    test("_1", "snake_case", "1");
+    test("HTTP2Server", "snake_case", "http2_server");
+    test("Vec3D", "snake_case", "vec3_d");
+    test("sha256_sum", "PascalCase", "Sha256Sum");
+    test("sha256_sum", "SCREAMING-KEBAB-CASE", "SHA256-SUM");
+}
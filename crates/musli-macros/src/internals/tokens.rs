@@ -5,14 +5,17 @@ pub(crate) struct Tokens {
     pub(crate) context_t: syn::Path,
     pub(crate) decode_bytes_t: syn::Path,
     pub(crate) decode_packed_t: syn::Path,
+    pub(crate) decode_remote_t: syn::Path,
     pub(crate) decode_t: syn::Path,
     pub(crate) decoder_t: syn::Path,
     pub(crate) default_function: syn::Path,
     pub(crate) encode_bytes_t: syn::Path,
     pub(crate) encode_packed_t: syn::Path,
+    pub(crate) encode_remote_t: syn::Path,
     pub(crate) encode_t: syn::Path,
     pub(crate) encoder_t: syn::Path,
     pub(crate) fmt: syn::Path,
+    pub(crate) from_t: syn::Path,
     pub(crate) map_decoder_t: syn::Path,
     pub(crate) map_encoder_t: syn::Path,
     pub(crate) map_entry_encoder_t: syn::Path,
@@ -21,6 +24,16 @@ pub(crate) struct Tokens {
     pub(crate) option_some: syn::Path,
     pub(crate) option: syn::Path,
     pub(crate) pack_decoder_t: syn::Path,
+    pub(crate) packed_size_t: syn::Path,
+    pub(crate) priv_decode_names: syn::Path,
+    pub(crate) priv_encode_names: syn::Path,
+    pub(crate) priv_eq_ignore_ascii_case: syn::Path,
+    pub(crate) priv_flatten_decoder: syn::Path,
+    pub(crate) priv_flatten_encoder: syn::Path,
+    pub(crate) priv_flatten_len: syn::Path,
+    pub(crate) priv_name: syn::Path,
+    pub(crate) priv_names: syn::Path,
+    pub(crate) priv_packed_size: syn::Path,
     pub(crate) priv_write: syn::Path,
     pub(crate) result_err: syn::Path,
     pub(crate) result_ok: syn::Path,
@@ -43,14 +56,17 @@ impl Tokens {
             context_t: path(span, &prefix, ["Context"]),
             decode_bytes_t: path(span, &prefix, ["de", "DecodeBytes"]),
             decode_packed_t: path(span, &prefix, ["de", "DecodePacked"]),
+            decode_remote_t: path(span, &prefix, ["__priv", "DecodeRemote"]),
             decode_t: path(span, &prefix, ["de", "Decode"]),
             decoder_t: path(span, &prefix, ["de", "Decoder"]),
             default_function: path(span, &prefix, ["__priv", "default"]),
             encode_bytes_t: path(span, &prefix, ["en", "EncodeBytes"]),
             encode_packed_t: path(span, &prefix, ["en", "EncodePacked"]),
+            encode_remote_t: path(span, &prefix, ["__priv", "EncodeRemote"]),
             encode_t: path(span, &prefix, ["en", "Encode"]),
             encoder_t: path(span, &prefix, ["en", "Encoder"]),
             fmt: path(span, &prefix, ["__priv", "fmt"]),
+            from_t: path(span, &prefix, ["__priv", "From"]),
             map_decoder_t: path(span, &prefix, ["de", "MapDecoder"]),
             map_encoder_t: path(span, &prefix, ["en", "MapEncoder"]),
             map_entry_encoder_t: path(span, &prefix, ["en", "EntryEncoder"]),
@@ -59,6 +75,16 @@ impl Tokens {
             option_some: path(span, &prefix, ["__priv", "Some"]),
             option: path(span, &prefix, ["__priv", "Option"]),
             pack_decoder_t: path(span, &prefix, ["de", "SequenceDecoder"]),
+            packed_size_t: path(span, &prefix, ["en", "PackedSize"]),
+            priv_decode_names: path(span, &prefix, ["__priv", "DecodeNames"]),
+            priv_encode_names: path(span, &prefix, ["__priv", "EncodeNames"]),
+            priv_eq_ignore_ascii_case: path(span, &prefix, ["__priv", "eq_ignore_ascii_case"]),
+            priv_flatten_decoder: path(span, &prefix, ["__priv", "FlattenDecoder"]),
+            priv_flatten_encoder: path(span, &prefix, ["__priv", "FlattenEncoder"]),
+            priv_flatten_len: path(span, &prefix, ["__priv", "FlattenLen"]),
+            priv_name: path(span, &prefix, ["__priv", "Name"]),
+            priv_names: path(span, &prefix, ["__priv", "Names"]),
+            priv_packed_size: path(span, &prefix, ["__priv", "packed_size"]),
             priv_write: path(span, &prefix, ["__priv", "write"]),
             result_err: path(span, &prefix, ["__priv", "Err"]),
             result_ok: path(span, &prefix, ["__priv", "Ok"]),
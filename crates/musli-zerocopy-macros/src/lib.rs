@@ -15,6 +15,7 @@ use proc_macro::TokenStream;
 
 #[cfg(feature = "sneaky-fields")]
 mod sneaky_fields;
+mod unsized_zero_copy;
 mod visit;
 mod zero_copy;
 
@@ -29,6 +30,17 @@ pub fn zero_copy(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(UnsizedZeroCopy, attributes(zero_copy))]
+pub fn unsized_zero_copy(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let expander = unsized_zero_copy::Expander::new(&input);
+
+    match expander.expand() {
+        Ok(stream) => stream.into(),
+        Err(errors) => to_compile_errors(errors).into(),
+    }
+}
+
 #[proc_macro_derive(Visit, attributes(visit))]
 pub fn visit(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as syn::DeriveInput);
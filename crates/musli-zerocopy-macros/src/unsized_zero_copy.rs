@@ -0,0 +1,304 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Token};
+
+#[derive(Default)]
+struct Ctxt {
+    errors: RefCell<Vec<syn::Error>>,
+}
+
+impl Ctxt {
+    fn error(&self, error: syn::Error) {
+        self.errors.borrow_mut().push(error);
+    }
+}
+
+pub struct Expander<'a> {
+    input: &'a DeriveInput,
+}
+
+impl<'a> Expander<'a> {
+    pub fn new(input: &'a DeriveInput) -> Self {
+        Self { input }
+    }
+}
+
+impl<'a> Expander<'a> {
+    pub fn expand(&self) -> Result<TokenStream, Vec<syn::Error>> {
+        let cx = Ctxt::default();
+
+        let Ok(output) = expand(&cx, self.input) else {
+            return Err(cx.errors.into_inner());
+        };
+
+        let errors = cx.errors.into_inner();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(output)
+    }
+}
+
+fn expand(cx: &Ctxt, input: &DeriveInput) -> Result<TokenStream, ()> {
+    let mut krate: syn::Path = syn::parse_quote!(musli_zerocopy);
+    let mut repr_c = false;
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            let result = attr.parse_nested_meta(|meta: ParseNestedMeta| {
+                if meta.path.is_ident("C") {
+                    repr_c = true;
+                    return Ok(());
+                }
+
+                Err(syn::Error::new_spanned(
+                    meta.path,
+                    "UnsizedZeroCopy: only #[repr(C)] is supported",
+                ))
+            });
+
+            if let Err(error) = result {
+                cx.error(error);
+            }
+        }
+
+        if attr.path().is_ident("zero_copy") {
+            let result = attr.parse_nested_meta(|meta: ParseNestedMeta| {
+                if meta.path.is_ident("crate") {
+                    if meta.input.parse::<Option<Token![=]>>()?.is_some() {
+                        krate = meta.input.parse()?;
+                    } else {
+                        krate = syn::parse_quote!(crate);
+                    }
+
+                    return Ok(());
+                }
+
+                Err(syn::Error::new(
+                    meta.input.span(),
+                    "UnsizedZeroCopy: Unsupported attribute",
+                ))
+            });
+
+            if let Err(error) = result {
+                cx.error(error);
+            }
+        }
+    }
+
+    if !repr_c {
+        cx.error(syn::Error::new(
+            Span::call_site(),
+            "UnsizedZeroCopy: struct must be marked with repr(C)",
+        ));
+        return Err(());
+    }
+
+    let syn::Data::Struct(st) = &input.data else {
+        cx.error(syn::Error::new(
+            Span::call_site(),
+            "UnsizedZeroCopy: only supported for structs",
+        ));
+        return Err(());
+    };
+
+    let mut members = Vec::new();
+    let mut types = Vec::new();
+
+    for (index, field) in st.fields.iter().enumerate() {
+        for attr in &field.attrs {
+            if attr.path().is_ident("zero_copy") {
+                cx.error(syn::Error::new_spanned(
+                    attr,
+                    "UnsizedZeroCopy: Unsupported attribute",
+                ));
+            }
+        }
+
+        members.push(match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(index)),
+        });
+
+        types.push(&field.ty);
+    }
+
+    let (Some(tail_member), Some(tail)) = (members.pop(), types.pop()) else {
+        cx.error(syn::Error::new(
+            Span::call_site(),
+            "UnsizedZeroCopy: struct must have a trailing unsized field",
+        ));
+        return Err(());
+    };
+
+    let error: syn::Path = syn::parse_quote!(#krate::Error);
+    let mem: syn::Path = syn::parse_quote!(#krate::__private::mem);
+    let ptr: syn::Path = syn::parse_quote!(#krate::__private::ptr);
+    let padder: syn::Path = syn::parse_quote!(#krate::buf::Padder);
+    let result: syn::Path = syn::parse_quote!(#krate::__private::result::Result);
+    let zero_copy: syn::Path = syn::parse_quote!(#krate::__private::ZeroCopy);
+    let unsized_zero_copy: syn::Path = syn::parse_quote!(#krate::__private::UnsizedZeroCopy);
+    let unsized_zero_copy_sealed: syn::Path =
+        syn::parse_quote!(#krate::__private::UnsizedZeroCopySealed);
+    let pointee: syn::Path = syn::parse_quote!(#krate::__private::Pointee);
+    let pointee_sealed: syn::Path = syn::parse_quote!(#krate::__private::PointeeSealed);
+    let packable: syn::Path = syn::parse_quote!(#krate::__private::Packable);
+    let byte_order: syn::Path = syn::parse_quote!(#krate::__private::ByteOrder);
+    let size: syn::Path = syn::parse_quote!(#krate::__private::Size);
+    let reference: syn::Path = syn::parse_quote!(#krate::__private::Ref);
+    let load_ref: syn::Path = syn::parse_quote!(#krate::__private::LoadRef);
+    let load_ref_mut: syn::Path = syn::parse_quote!(#krate::__private::LoadRefMut);
+    let buf: syn::Path = syn::parse_quote!(#krate::__private::Buf);
+    let private: syn::Path = syn::parse_quote!(#krate::__private);
+
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+
+    {
+        let where_clause = generics.make_where_clause();
+
+        for ty in &types {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ty: #zero_copy));
+        }
+
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#tail: #unsized_zero_copy + #pointee<Metadata = usize>));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let names = members.iter().map(field_name).collect::<Vec<_>>();
+    let tail_name = field_name(&tail_member);
+
+    // The offset of the trailing field, as laid out by `repr(C)`.
+    let tail_offset = quote! {
+        #private::tail_offset(
+            &[#((#mem::size_of::<#types>(), #mem::align_of::<#types>())),*],
+            <#tail as #unsized_zero_copy>::ALIGN,
+        )
+    };
+
+    let align = quote! {
+        #private::max_align(&[#(#mem::align_of::<#types>(),)* <#tail as #unsized_zero_copy>::ALIGN])
+    };
+
+    // The struct is padded if there's padding between the header fields, or
+    // if there's trailing padding since the struct is more aligned than its
+    // trailing field.
+    let padded = quote! {
+        #tail_offset != (0 #(+ #mem::size_of::<#types>())*)
+            || #align != <#tail as #unsized_zero_copy>::ALIGN
+            #(|| <#types as #zero_copy>::PADDED)*
+            || <#tail as #unsized_zero_copy>::PADDED
+    };
+
+    let validate_header = (!types.is_empty()).then(|| {
+        quote! {
+            // SAFETY: We've systematically ensured that we're only
+            // validating over fields within the header of this type.
+            let mut validator = #private::header_validator::<Self>(data, len, offset)?;
+            #(validator.validate::<#types>()
+                .map_err(|error| #error::__field(error, &#names))?;)*
+        }
+    });
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #pointee_sealed for #name #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics #unsized_zero_copy_sealed for #name #ty_generics #where_clause {}
+
+        #[automatically_derived]
+        impl #impl_generics #pointee for #name #ty_generics #where_clause {
+            type Metadata = usize;
+        }
+
+        #[automatically_derived]
+        unsafe impl #impl_generics #unsized_zero_copy for #name #ty_generics #where_clause {
+            const ALIGN: usize = #align;
+            const PADDED: bool = #padded;
+
+            #[inline]
+            fn as_ptr(&self) -> *const u8 {
+                (self as *const Self).cast::<u8>()
+            }
+
+            #[inline]
+            fn metadata(&self) -> usize {
+                <#tail as #unsized_zero_copy>::metadata(&self.#tail_member)
+            }
+
+            #[inline]
+            unsafe fn pad(&self, padder: &mut #padder<'_, Self>) {
+                #(#padder::pad::<#types>(padder);)*
+                #padder::pad_unsized::<#tail>(padder, &self.#tail_member);
+            }
+
+            #[inline]
+            unsafe fn validate_unsized<__E: #byte_order, __O: #size>(
+                data: #ptr::NonNull<u8>,
+                len: usize,
+                metadata: <usize as #packable>::Packed<__O>,
+            ) -> #result<usize, #error> {
+                let offset = #tail_offset;
+                #validate_header
+                #private::validate_tail::<#tail, __E, __O>(data, len, offset, Self::ALIGN, metadata)
+                    .map_err(|error| #error::__field(error, &#tail_name))
+            }
+
+            #[inline]
+            unsafe fn with_metadata(data: #ptr::NonNull<u8>, metadata: usize) -> *const Self {
+                #private::slice_dst::<Self>(data, metadata)
+            }
+
+            #[inline]
+            unsafe fn with_metadata_mut(data: #ptr::NonNull<u8>, metadata: usize) -> *mut Self {
+                #private::slice_dst_mut::<Self>(data, metadata)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #load_ref for #name #ty_generics #where_clause {
+            #[inline]
+            fn __load<__E: #byte_order, __O: #size>(
+                ptr: #reference<Self, __E, __O>,
+                buf: &#buf,
+            ) -> #result<&Self, #error> {
+                #buf::__load_unsized(buf, ptr)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #load_ref_mut for #name #ty_generics #where_clause {
+            #[inline]
+            fn __load_mut<__E: #byte_order, __O: #size>(
+                ptr: #reference<Self, __E, __O>,
+                buf: &mut #buf,
+            ) -> #result<&mut Self, #error> {
+                #buf::__load_unsized_mut(buf, ptr)
+            }
+        }
+    })
+}
+
+/// Construct the name of a field used in error diagnostics.
+fn field_name(member: &syn::Member) -> syn::LitStr {
+    let name = match member {
+        syn::Member::Named(ident) => ident.unraw().to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    };
+
+    syn::LitStr::new(&name, member.span())
+}
@@ -5,6 +5,7 @@ use std::cell::RefCell;
 
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::meta::ParseNestedMeta;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
@@ -50,11 +51,23 @@ impl Expander {
 }
 
 fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
-    let (attrs, name, mut generics, data) = (input.attrs, input.ident, input.generics, input.data);
+    let (attrs, vis, name, mut generics, data) = (
+        input.attrs,
+        input.vis,
+        input.ident,
+        input.generics,
+        input.data,
+    );
 
     let mut r = ReprAttr::default();
     let mut krate: syn::Path = syn::parse_quote!(musli_zerocopy);
     let mut swap_bytes_self = false;
+    let mut owned: Option<syn::Path> = None;
+    let mut debug = false;
+    let mut eq = false;
+    let mut hash = false;
+    let mut zerocopy: Option<(Span, bool)> = None;
+    let mut bytemuck: Option<Span> = None;
 
     for attr in &attrs {
         if attr.path().is_ident("repr") {
@@ -90,6 +103,53 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("owned") {
+                    meta.input.parse::<Token![=]>()?;
+                    owned = Some(meta.input.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("debug") {
+                    debug = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("eq") {
+                    eq = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("hash") {
+                    hash = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("zerocopy") {
+                    let mut unaligned = false;
+
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|meta| {
+                            if meta.path.is_ident("unaligned") {
+                                unaligned = true;
+                                return Ok(());
+                            }
+
+                            Err(syn::Error::new(
+                                meta.input.span(),
+                                "ZeroCopy: Unsupported zerocopy attribute",
+                            ))
+                        })?;
+                    }
+
+                    zerocopy = Some((meta.path.span(), unaligned));
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("bytemuck") {
+                    bytemuck = Some(meta.path.span());
+                    return Ok(());
+                }
+
                 Err(syn::Error::new(
                     meta.input.span(),
                     "ZeroCopy: Unsupported attribute",
@@ -120,6 +180,32 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
     let zero_copy: syn::Path = syn::parse_quote!(#krate::__private::ZeroCopy);
     let zero_sized: syn::Path = syn::parse_quote!(#krate::__private::ZeroSized);
     let byte_order: syn::Path = syn::parse_quote!(#krate::__private::ByteOrder);
+    let load_owned: syn::Path = syn::parse_quote!(#krate::__private::LoadOwned);
+    let buf: syn::Path = syn::parse_quote!(#krate::__private::Buf);
+    let debug_in: syn::Path = syn::parse_quote!(#krate::__private::DebugIn);
+    let debug_value: syn::Path = syn::parse_quote!(#krate::__private::DebugValue);
+    let fmt: syn::Path = syn::parse_quote!(#krate::__private::fmt);
+    let eq_in: syn::Path = syn::parse_quote!(#krate::__private::EqIn);
+    let hash_in: syn::Path = syn::parse_quote!(#krate::__private::HashIn);
+    let hasher: syn::Path = syn::parse_quote!(#krate::__private::hash::Hasher);
+    let default: syn::Path = syn::parse_quote!(::core::default::Default);
+    let zc: syn::Path = syn::parse_quote!(#krate::__private::zerocopy);
+    let bm: syn::Path = syn::parse_quote!(#krate::__private::bytemuck);
+    let fields_trait: syn::Path = syn::parse_quote!(#krate::__private::Fields);
+    let field_offset: syn::Path = syn::parse_quote!(#krate::__private::FieldOffset);
+    let field_offset_fn: syn::Path = syn::parse_quote!(#krate::__private::field_offset);
+
+    // NB: The owned path is used both as a type and in expressions, so make
+    // sure any generic arguments use the turbofish syntax.
+    let owned = owned.map(|mut path| {
+        for segment in &mut path.segments {
+            if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token = Some(<Token![::]>::default());
+            }
+        }
+
+        path
+    });
 
     let endianness = quote::format_ident!("__E");
 
@@ -136,6 +222,13 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
     // error.
     let check_fields;
     let type_impls;
+    let impl_fields;
+    let to_owned_block;
+    let debug_block;
+    let eq_block;
+    let hash_block;
+    let mut impl_zerocopy = None;
+    let mut impl_bytemuck = None;
     let mut check_zero_sized = Vec::new();
 
     match &data {
@@ -150,8 +243,11 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                         <#ty as #zero_copy>::pad(#padder::transparent::<#ty>(padder));
                     };
 
+                    let name = field_name(None, member);
+
                     validate = quote! {
-                        <#ty as #zero_copy>::validate(#validator::transparent::<#ty>(validator))?;
+                        <#ty as #zero_copy>::validate(#validator::transparent::<#ty>(validator))
+                            .map_err(|error| #error::__field(error, &#name))?;
                     };
 
                     let ignored_members = &output.ignored_members;
@@ -165,6 +261,7 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                 }
                 _ => {
                     let types = &output.types;
+                    let names = output.members.iter().map(|member| field_name(None, member));
 
                     match r.repr_packed {
                         Some((_, align)) => {
@@ -176,7 +273,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                                 // SAFETY: We've systematically ensured that we're
                                 // only validating over fields within the size of
                                 // this type.
-                                #(#validator::validate_with::<#types>(validator, #align)?;)*
+                                #(#validator::validate_with::<#types>(validator, #align)
+                                    .map_err(|error| #error::__field(error, &#names))?;)*
                             };
                         }
                         _ => {
@@ -188,7 +286,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                                 // SAFETY: We've systematically ensured that we're
                                 // only validating over fields within the size of
                                 // this type.
-                                #(#validator::validate::<#types>(validator)?;)*
+                                #(#validator::validate::<#types>(validator)
+                                    .map_err(|error| #error::__field(error, &#names))?;)*
                             };
                         }
                     }
@@ -257,8 +356,228 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
             };
 
             type_impls = None;
+
+            // Offsets of every field, including ignored ones, which are used
+            // by `ref_field!` to project references.
+            impl_fields = (!st.fields.is_empty()).then(|| {
+                let all_types = st.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+                let packed = match r.repr_packed {
+                    Some((_, align)) => quote!(#align),
+                    None => quote!(::core::primitive::usize::MAX),
+                };
+
+                let offsets = (0..all_types.len()).map(|index| match repr {
+                    Repr::Transparent => quote!(0),
+                    _ => quote! {
+                        #field_offset_fn(
+                            &[#((#mem::size_of::<#all_types>(), #mem::align_of::<#all_types>())),*],
+                            #packed,
+                            #index,
+                        )
+                    },
+                });
+
+                let members = output.exhaustive.iter();
+
+                let decls = st.fields.iter().map(|f| {
+                    let (vis, ty) = (&f.vis, &f.ty);
+                    let ident = f.ident.as_ref().map(|ident| quote!(#ident:));
+                    quote!(#vis #ident #field_offset<#name #ty_generics, #ty>)
+                });
+
+                let decl = match &st.fields {
+                    syn::Fields::Named(..) => quote! {
+                        #vis struct __ZeroCopyFields #generics #where_clause { #(#decls,)* }
+                    },
+                    _ => quote! {
+                        #vis struct __ZeroCopyFields #generics (#(#decls,)*) #where_clause;
+                    },
+                };
+
+                quote! {
+                    const _: () = {
+                        #[allow(dead_code)]
+                        #decl
+
+                        #[automatically_derived]
+                        impl #impl_generics #fields_trait for #name #ty_generics #where_clause {
+                            type Fields = __ZeroCopyFields #ty_generics;
+
+                            const FIELDS: Self::Fields = __ZeroCopyFields {
+                                #(#members: #field_offset::new(#offsets),)*
+                            };
+                        }
+                    };
+                }
+            });
+
+            to_owned_block = owned.as_ref().map(|owned| {
+                let Fields {
+                    members,
+                    ignored_members,
+                    ..
+                } = &output;
+
+                // NB: Fields in packed structs can't be referenced, so they
+                // are copied out first.
+                let access = members.iter().map(|member| match r.repr_packed {
+                    Some(..) => quote!(&{ self.#member }),
+                    None => quote!(&self.#member),
+                });
+
+                quote! {
+                    #owned {
+                        #(#members: #load_owned::to_owned_in(#access, buf)?,)*
+                        #(#ignored_members: #default::default(),)*
+                    }
+                }
+            });
+
+            debug_block = debug.then(|| {
+                let access = output.members.iter().map(|member| match r.repr_packed {
+                    Some(..) => quote!(&{ self.#member }),
+                    None => quote!(&self.#member),
+                });
+
+                build_debug(&debug_value, &name, &st.fields, &output, access)
+            });
+
+            eq_block = eq.then(|| {
+                let (access, other_access): (Vec<_>, Vec<_>) = output
+                    .members
+                    .iter()
+                    .map(|member| match r.repr_packed {
+                        Some(..) => (quote!(&{ self.#member }), quote!(&{ other.#member })),
+                        None => (quote!(&self.#member), quote!(&other.#member)),
+                    })
+                    .unzip();
+
+                quote! {
+                    true #(&& #eq_in::eq_in(#access, buf, #other_access, other_buf)?)*
+                }
+            });
+
+            hash_block = hash.then(|| {
+                let access = output.members.iter().map(|member| match r.repr_packed {
+                    Some(..) => quote!(&{ self.#member }),
+                    None => quote!(&self.#member),
+                });
+
+                quote! {
+                    #(#hash_in::hash_in(#access, buf, hasher)?;)*
+                }
+            });
+
+            // NB: Includes ignored fields, since they are part of the layout
+            // of the type as far as other crates are concerned.
+            let all_types = st.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+            // Assertions which must hold for a type to be safely viewed as
+            // bytes, and constructed from arbitrary bytes.
+            let assert_plain = |what: &str| {
+                let padded_message =
+                    format!("ZeroCopy: type must not contain padding to implement {what}");
+                let any_bits_message = format!("ZeroCopy: type must not contain fields which require validation to implement {what}");
+
+                quote! {
+                    const _: () = ::core::assert!(!<#name as #zero_copy>::PADDED, #padded_message);
+                    const _: () = ::core::assert!(<#name as #zero_copy>::ANY_BITS, #any_bits_message);
+                }
+            };
+
+            for (span, what) in [
+                (zerocopy.map(|(span, _)| span), "zerocopy"),
+                (bytemuck, "bytemuck"),
+            ] {
+                if let (Some(span), false) = (span, generics.params.is_empty()) {
+                    cx.error(syn::Error::new(
+                        span,
+                        format!("ZeroCopy: {what} interop is not supported for generic types"),
+                    ));
+                }
+            }
+
+            if let Some((_, unaligned)) = zerocopy {
+                let types = &all_types;
+
+                let unaligned = unaligned.then(|| {
+                    quote! {
+                        const _: () = ::core::assert!(
+                            #mem::align_of::<#name>() == 1,
+                            "ZeroCopy: type must have an alignment of 1 to implement `zerocopy::Unaligned`",
+                        );
+
+                        // SAFETY: The type is asserted above to have an
+                        // alignment of 1.
+                        #[automatically_derived]
+                        unsafe impl #zc::Unaligned for #name where #(#types: #zc::Unaligned,)* {
+                            fn only_derive_is_allowed_to_implement_this_trait() {}
+                        }
+                    }
+                });
+
+                let assert_plain = assert_plain("`zerocopy::AsBytes` and `zerocopy::FromBytes`");
+
+                impl_zerocopy = Some(quote! {
+                    #assert_plain
+
+                    // SAFETY: Every field is `FromZeroes`.
+                    #[automatically_derived]
+                    unsafe impl #zc::FromZeroes for #name where #(#types: #zc::FromZeroes,)* {
+                        fn only_derive_is_allowed_to_implement_this_trait() {}
+                    }
+
+                    // SAFETY: Every field is `FromBytes`, and the type is
+                    // asserted above to accept any bit pattern.
+                    #[automatically_derived]
+                    unsafe impl #zc::FromBytes for #name where #(#types: #zc::FromBytes,)* {
+                        fn only_derive_is_allowed_to_implement_this_trait() {}
+                    }
+
+                    // SAFETY: Every field is `AsBytes`, and the type is
+                    // asserted above to not contain any padding.
+                    #[automatically_derived]
+                    unsafe impl #zc::AsBytes for #name where #(#types: #zc::AsBytes,)* {
+                        fn only_derive_is_allowed_to_implement_this_trait() {}
+                    }
+
+                    #unaligned
+                });
+            }
+
+            if bytemuck.is_some() {
+                let types = &all_types;
+                let assert_plain = assert_plain("`bytemuck::Pod`");
+
+                impl_bytemuck = Some(quote! {
+                    #assert_plain
+
+                    // SAFETY: Every field is `Zeroable`.
+                    #[automatically_derived]
+                    unsafe impl #bm::Zeroable for #name where #(#types: #bm::Zeroable,)* {}
+
+                    // SAFETY: Every field is `Pod`, and the type is asserted
+                    // above to not contain any padding and to accept any bit
+                    // pattern.
+                    #[automatically_derived]
+                    unsafe impl #bm::Pod for #name where #(#types: #bm::Pod,)* {}
+                });
+            }
         }
         syn::Data::Enum(en) => {
+            for (span, what) in [
+                (zerocopy.map(|(span, _)| span), "zerocopy"),
+                (bytemuck, "bytemuck"),
+            ] {
+                if let Some(span) = span {
+                    cx.error(syn::Error::new(
+                        span,
+                        format!("ZeroCopy: {what} interop is only supported for structs"),
+                    ));
+                }
+            }
+
             if let Some((span, _)) = r.repr_packed {
                 cx.error(syn::Error::new(
                     span,
@@ -286,6 +605,10 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
             let mut padded_variants = Vec::new();
             let mut byte_ordered_variants = Vec::new();
             let mut variant_fields = Vec::new();
+            let mut owned_variants = Vec::new();
+            let mut debug_variants = Vec::new();
+            let mut eq_variants = Vec::new();
+            let mut hash_variants = Vec::new();
 
             let mut enumerator = Enumerator::new(num, ty.span());
 
@@ -311,9 +634,15 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     const #discriminant_const: #ty = #discriminant;
                 });
 
+                let names = output
+                    .members
+                    .iter()
+                    .map(|member| field_name(Some(&variant.ident), member));
+
                 validate_variants.push(quote! {
                     #discriminant_const => {
-                        #(#validator::validate::<#types>(validator)?;)*
+                        #(#validator::validate::<#types>(validator)
+                            .map_err(|error| #error::__field(error, &#names))?;)*
                     }
                 });
 
@@ -338,6 +667,53 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     }
                 });
 
+                if let Some(owned) = &owned {
+                    owned_variants.push(quote! {
+                        Self::#ident { #(#assigns),* } => {
+                            #owned::#ident {
+                                #(#members: #load_owned::to_owned_in(#variables, buf)?,)*
+                                #(#ignored_members: #default::default(),)*
+                            }
+                        }
+                    });
+                }
+
+                if debug {
+                    let block = build_debug(
+                        &debug_value,
+                        ident,
+                        &variant.fields,
+                        &output,
+                        variables.iter().map(|variable| quote!(#variable)),
+                    );
+
+                    debug_variants.push(quote! {
+                        Self::#ident { #(#assigns),* } => #block
+                    });
+                }
+
+                if eq {
+                    let other_variables = variables
+                        .iter()
+                        .map(|variable| quote::format_ident!("__other_{}", variable))
+                        .collect::<Vec<_>>();
+
+                    eq_variants.push(quote! {
+                        (Self::#ident { #(#assigns),* }, Self::#ident { #(#members: #other_variables,)* .. }) => {
+                            true #(&& #eq_in::eq_in(#variables, buf, #other_variables, other_buf)?)*
+                        }
+                    });
+                }
+
+                if hash {
+                    hash_variants.push(quote! {
+                        Self::#ident { #(#assigns),* } => {
+                            #hasher::write_usize(hasher, #index);
+                            #(#hash_in::hash_in(#variables, buf, hasher)?;)*
+                        }
+                    });
+                }
+
                 pad_variants.push(quote! {
                     #discriminant_const => {
                         #(#padder::pad::<#types>(padder);)*
@@ -400,6 +776,40 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                 }
             };
 
+            to_owned_block = owned.as_ref().map(|_| {
+                quote! {
+                    match self {
+                        #(#owned_variants),*
+                    }
+                }
+            });
+
+            debug_block = debug.then(|| {
+                quote! {
+                    match self {
+                        #(#debug_variants),*
+                    }
+                }
+            });
+
+            eq_block = eq.then(|| {
+                quote! {
+                    match (self, other) {
+                        #(#eq_variants,)*
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            });
+
+            hash_block = hash.then(|| {
+                quote! {
+                    match self {
+                        #(#hash_variants,)*
+                    }
+                }
+            });
+
             impl_zero_sized = None;
             any_bits = quote!(false);
             padded = quote!(false #(|| #padded_variants)*);
@@ -419,6 +829,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                 };
             );
 
+            impl_fields = None;
+
             type_impls = Some(quote! {
                 #[cfg(test)]
                 impl #impl_generics #name #ty_generics #where_clause {
@@ -457,6 +869,64 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
         (swap_bytes_block, can_swap_bytes)
     };
 
+    let impl_load_owned = match (&owned, to_owned_block) {
+        (Some(owned), Some(to_owned_block)) => Some(quote! {
+            #[automatically_derived]
+            impl #impl_generics #load_owned for #name #ty_generics #where_clause {
+                type Owned = #owned;
+
+                #[inline]
+                #[allow(unused_variables)]
+                fn to_owned_in(&self, buf: &#buf) -> #result<Self::Owned, #error> {
+                    #result::Ok(#to_owned_block)
+                }
+            }
+        }),
+        _ => None,
+    };
+
+    let impl_debug_in = debug_block.map(|debug_block| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #debug_in for #name #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn fmt_in(&self, buf: &#buf, depth: usize, f: &mut #fmt::Formatter<'_>) -> #fmt::Result {
+                    #debug_block
+                }
+            }
+        }
+    });
+
+    let impl_eq_in = eq_block.map(|eq_block| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #eq_in for #name #ty_generics #where_clause {
+                #[inline]
+                #[allow(unused_variables)]
+                fn eq_in(&self, buf: &#buf, other: &Self, other_buf: &#buf) -> #result<bool, #error> {
+                    #result::Ok(#eq_block)
+                }
+            }
+        }
+    });
+
+    let impl_hash_in = hash_block.map(|hash_block| {
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #hash_in for #name #ty_generics #where_clause {
+                #[inline]
+                #[allow(unused_variables)]
+                fn hash_in<__H>(&self, buf: &#buf, hasher: &mut __H) -> #result<(), #error>
+                where
+                    __H: #hasher,
+                {
+                    #hash_block
+                    #result::Ok(())
+                }
+            }
+        }
+    });
+
     Ok(quote! {
         #check_zero_sized
 
@@ -466,6 +936,20 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
 
         #type_impls
 
+        #impl_fields
+
+        #impl_load_owned
+
+        #impl_debug_in
+
+        #impl_eq_in
+
+        #impl_hash_in
+
+        #impl_zerocopy
+
+        #impl_bytemuck
+
         #[automatically_derived]
         unsafe impl #impl_generics #zero_copy for #name #ty_generics #where_clause {
             const ANY_BITS: bool = #any_bits;
@@ -555,6 +1039,55 @@ fn build_field_exhaustive_pattern<const N: usize>(
     }
 }
 
+/// Build an expression which debug prints the given fields through
+/// `DebugValue`, where `access` produces a reference to each non-ignored field.
+fn build_debug(
+    debug_value: &syn::Path,
+    name: &syn::Ident,
+    fields: &syn::Fields,
+    output: &Fields<'_>,
+    access: impl Iterator<Item = TokenStream>,
+) -> TokenStream {
+    let name = syn::LitStr::new(&name.unraw().to_string(), name.span());
+
+    let values = access.map(|access| quote!(&#debug_value::new(#access, buf, depth)));
+
+    match fields {
+        syn::Fields::Named(..) => {
+            let names = output.members.iter().map(|member| field_name(None, member));
+
+            quote! {
+                f.debug_struct(#name)
+                    #(.field(#names, #values))*
+                    .finish()
+            }
+        }
+        syn::Fields::Unnamed(..) => {
+            quote! {
+                f.debug_tuple(#name)
+                    #(.field(#values))*
+                    .finish()
+            }
+        }
+        syn::Fields::Unit => quote!(f.write_str(#name)),
+    }
+}
+
+/// Construct the name of a field used in error diagnostics.
+fn field_name(variant: Option<&syn::Ident>, member: &syn::Member) -> syn::LitStr {
+    let name = match member {
+        syn::Member::Named(ident) => ident.unraw().to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    };
+
+    let name = match variant {
+        Some(variant) => format!("{}.{name}", variant.unraw()),
+        None => name,
+    };
+
+    syn::LitStr::new(&name, member.span())
+}
+
 #[derive(Default)]
 struct Fields<'a> {
     types: Vec<&'a syn::Type>,
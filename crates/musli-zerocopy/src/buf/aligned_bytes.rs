@@ -0,0 +1,87 @@
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use crate::buf::DefaultAlignment;
+
+/// A fixed-size array of bytes which is aligned like `A`.
+///
+/// This is useful as the backing storage for a [`SliceMut`] when constructing
+/// buffers without an allocator, since it guarantees that the buffer is
+/// aligned for all types with an alignment up to and including that of `A`.
+///
+/// [`SliceMut`]: crate::SliceMut
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{Buf, SliceMut};
+/// use musli_zerocopy::buf::AlignedBytes;
+///
+/// let mut bytes = AlignedBytes::<64, u64>::new();
+/// let mut buf = SliceMut::with_alignment::<u64>(&mut bytes);
+///
+/// let first = buf.try_store(&1u64)?;
+/// let second = buf.try_store_unsized("second")?;
+///
+/// let buf = Buf::new(buf.as_slice());
+///
+/// assert_eq!(buf.load(first)?, &1);
+/// assert_eq!(buf.load(second)?, "second");
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[repr(C)]
+pub struct AlignedBytes<const N: usize, A = DefaultAlignment> {
+    _align: [A; 0],
+    bytes: [u8; N],
+}
+
+impl<const N: usize, A> AlignedBytes<N, A> {
+    /// Construct a new zeroed array of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::buf::AlignedBytes;
+    ///
+    /// let bytes = AlignedBytes::<16, u32>::new();
+    /// assert_eq!(bytes.len(), 16);
+    /// assert_eq!(bytes.as_ptr() as usize % 4, 0);
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            _align: [],
+            bytes: [0; N],
+        }
+    }
+}
+
+impl<const N: usize, A> Default for AlignedBytes<N, A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize, A> Deref for AlignedBytes<N, A> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, A> DerefMut for AlignedBytes<N, A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.bytes
+    }
+}
+
+impl<const N: usize, A> fmt::Debug for AlignedBytes<N, A> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.bytes.fmt(f)
+    }
+}
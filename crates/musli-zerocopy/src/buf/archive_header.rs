@@ -0,0 +1,146 @@
+use crate::endian::{ByteOrder, Endian, Little};
+use crate::ZeroCopy;
+
+/// Marker for an archive written in little endian byte order.
+const LITTLE_ENDIAN: u8 = 0;
+/// Marker for an archive written in big endian byte order.
+const BIG_ENDIAN: u8 = 1;
+
+/// A standard header describing how to read an archive back out of a buffer.
+///
+/// This carries all the pieces of data needed to safely read a buffer, namely
+/// a magic value and a version identifying the format, the byte order and
+/// alignment the buffer was written with, and the location of the root value.
+///
+/// The header is appended to the end of a buffer with
+/// [`OwnedBuf::finish_with_header()`] and validated with
+/// [`Buf::open_with_header()`]. Numerical fields in the header are always
+/// stored in little endian byte order, so that it can be read regardless of
+/// the byte order of the rest of the archive.
+///
+/// [`OwnedBuf::finish_with_header()`]: crate::buf::OwnedBuf::finish_with_header
+/// [`Buf::open_with_header()`]: crate::buf::Buf::open_with_header
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::endian::Native;
+/// use musli_zerocopy::{DefaultSize, OwnedBuf};
+///
+/// let mut buf = OwnedBuf::new();
+/// let root = buf.store_unsized("Hello World!");
+/// let header = buf.finish_with_header(*b"EXAMPLE1", 1, root)?;
+///
+/// let header = buf.load(header)?;
+/// assert_eq!(header.magic(), *b"EXAMPLE1");
+/// assert_eq!(header.version(), 1);
+/// assert_eq!(header.root_offset(), root.offset() as u32);
+/// assert_eq!(header.root_metadata(), 12);
+///
+/// let root = buf.open_with_header::<str, Native, DefaultSize>(*b"EXAMPLE1", 1)?;
+/// assert_eq!(buf.load(root)?, "Hello World!");
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct ArchiveHeader {
+    magic: [u8; 8],
+    version: Endian<u32, Little>,
+    byte_order: u8,
+    _reserved: [u8; 3],
+    align: Endian<u32, Little>,
+    root_offset: Endian<u32, Little>,
+    root_metadata: Endian<u32, Little>,
+}
+
+impl ArchiveHeader {
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new<E>(
+        magic: [u8; 8],
+        version: u32,
+        align: u32,
+        root_offset: u32,
+        root_metadata: u32,
+    ) -> Self
+    where
+        E: ByteOrder,
+    {
+        Self {
+            magic,
+            version: Endian::new(version),
+            byte_order: if is_little::<E>() {
+                LITTLE_ENDIAN
+            } else {
+                BIG_ENDIAN
+            },
+            _reserved: [0; 3],
+            align: Endian::new(align),
+            root_offset: Endian::new(root_offset),
+            root_metadata: Endian::new(root_metadata),
+        }
+    }
+
+    /// The magic value identifying the format of the archive.
+    #[inline]
+    pub fn magic(&self) -> [u8; 8] {
+        self.magic
+    }
+
+    /// The version of the format of the archive.
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self.version.to_ne()
+    }
+
+    /// Test if the archive was written in little endian byte order.
+    ///
+    /// Returns `None` if the header contains an unknown byte order.
+    #[inline]
+    pub fn is_little_endian(&self) -> Option<bool> {
+        match self.byte_order {
+            LITTLE_ENDIAN => Some(true),
+            BIG_ENDIAN => Some(false),
+            _ => None,
+        }
+    }
+
+    /// The alignment the buffer containing the archive must have.
+    #[inline]
+    pub fn alignment(&self) -> u32 {
+        self.align.to_ne()
+    }
+
+    /// The offset of the root value of the archive.
+    #[inline]
+    pub fn root_offset(&self) -> u32 {
+        self.root_offset.to_ne()
+    }
+
+    /// The raw metadata of the root value of the archive, such as the length
+    /// of a slice. This is zero for sized values.
+    #[inline]
+    pub fn root_metadata(&self) -> u32 {
+        self.root_metadata.to_ne()
+    }
+}
+
+/// Test if the byte order `E` is little endian.
+#[inline]
+pub(crate) fn is_little<E>() -> bool
+where
+    E: ByteOrder,
+{
+    // NB: Swapping is a no-op only if `E` is the native byte order.
+    (E::swap_u32(1) == 1) == cfg!(target_endian = "little")
+}
+
+/// The name of the byte order for diagnostics.
+#[inline]
+pub(crate) fn byte_order_name(little: bool) -> &'static str {
+    if little {
+        "little"
+    } else {
+        "big"
+    }
+}
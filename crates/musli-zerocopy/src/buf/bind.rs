@@ -8,7 +8,14 @@ mod sealed {
 
     pub trait Sealed {}
 
-    impl<K, V, E: ByteOrder, O: Size> Sealed for crate::phf::map::MapRef<K, V, E, O>
+    impl<K, V, E: ByteOrder, O: Size, H> Sealed for crate::phf::map::MapRef<K, V, E, O, H>
+    where
+        K: ZeroCopy,
+        V: ZeroCopy,
+    {
+    }
+
+    impl<K, V, E: ByteOrder, O: Size, H> Sealed for crate::phf::multimap::MultimapRef<K, V, E, O, H>
     where
         K: ZeroCopy,
         V: ZeroCopy,
@@ -22,7 +29,21 @@ mod sealed {
     {
     }
 
-    impl<T, E: ByteOrder, O: Size> Sealed for crate::phf::set::SetRef<T, E, O> where T: ZeroCopy {}
+    impl<K, V, E: ByteOrder, O: Size> Sealed for crate::sorted::map::MapRef<K, V, E, O>
+    where
+        K: ZeroCopy,
+        V: ZeroCopy,
+    {
+    }
+
+    impl<K, V, E: ByteOrder, O: Size> Sealed for crate::swiss::ordered_map::OrderedMapRef<K, V, E, O>
+    where
+        K: ZeroCopy,
+        V: ZeroCopy,
+    {
+    }
+
+    impl<T, E: ByteOrder, O: Size, H> Sealed for crate::phf::set::SetRef<T, E, O, H> where T: ZeroCopy {}
 
     impl<T, E: ByteOrder, O: Size> Sealed for crate::swiss::set::SetRef<T, E, O> where T: ZeroCopy {}
 }
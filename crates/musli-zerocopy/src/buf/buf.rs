@@ -1,6 +1,7 @@
 use core::alloc::Layout;
 use core::fmt;
-use core::mem::{align_of, size_of, MaybeUninit};
+use core::hash::Hasher;
+use core::mem::{align_of, size_of, size_of_val, MaybeUninit};
 use core::ops::{Index, IndexMut, Range};
 use core::ptr::{read_unaligned, NonNull};
 use core::slice::SliceIndex;
@@ -8,12 +9,13 @@ use core::slice::SliceIndex;
 #[cfg(feature = "alloc")]
 use alloc::borrow::{Cow, ToOwned};
 
+use crate::buf::archive_header::{self, ArchiveHeader};
 #[cfg(feature = "alloc")]
 use crate::buf::OwnedBuf;
 use crate::buf::{self, Bindable, Load, LoadMut, Validator};
 use crate::endian::ByteOrder;
-use crate::error::{Error, ErrorKind};
-use crate::pointer::{Ref, Size};
+use crate::error::{Error, ErrorKind, IntoRepr};
+use crate::pointer::{Packable, Pointee, Ref, Size};
 use crate::traits::{UnsizedZeroCopy, ZeroCopy};
 
 /// A buffer wrapping a slice of bytes.
@@ -157,6 +159,38 @@ impl Buf {
     }
 
     /// Get the alignment of the current buffer.
+    ///
+    /// This is the largest power of two which divides the address of the
+    /// underlying data, which can be used to determine up front whether the
+    /// buffer is suitable for loading a value or needs to be copied into an
+    /// aligned buffer through [`Buf::to_aligned`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::mem::align_of;
+    ///
+    /// use musli_zerocopy::{Buf, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::with_alignment::<u64>();
+    /// buf.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    ///
+    /// assert!(buf.alignment() >= 8);
+    ///
+    /// let unaligned = Buf::new(&buf.as_slice()[2..]);
+    /// assert_eq!(unaligned.alignment(), 2);
+    ///
+    /// if !unaligned.is_aligned::<u64>() {
+    ///     let message = format!(
+    ///         "buffer must be {}-byte aligned, got {}",
+    ///         align_of::<u64>(),
+    ///         unaligned.alignment()
+    ///     );
+    ///
+    ///     assert_eq!(message, "buffer must be 8-byte aligned, got 2");
+    /// }
+    /// ```
+    #[inline]
     pub fn alignment(&self) -> usize {
         // NB: Maximum alignment supported by Rust is 2^29.
         1usize << (self.data.as_ptr() as usize).trailing_zeros().min(29)
@@ -297,6 +331,101 @@ impl Buf {
         self.data.get_mut(index)
     }
 
+    /// Get a view of the given `range` of the current buffer as a nested
+    /// [`Buf`].
+    ///
+    /// Offset zero in the returned buffer corresponds to the start of `range`,
+    /// and any loads performed through it are bounded by the view rather than
+    /// the containing buffer. This is useful when multiple independent
+    /// archives are embedded inside of one container.
+    ///
+    /// No alignment is required of the start of the view, but since loads
+    /// check alignment against the address of the data being loaded they will
+    /// error if the view is not suitably aligned. Use
+    /// [`Buf::slice_with_alignment`] to check the alignment of the view up
+    /// front.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `range` is out of bounds of the current buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, Ref};
+    ///
+    /// let mut first = OwnedBuf::new();
+    /// let number = first.store(&42u64);
+    ///
+    /// let mut second = OwnedBuf::new();
+    /// let string = second.store_unsized("Hello World!");
+    ///
+    /// let mut container = OwnedBuf::with_alignment::<u64>();
+    /// container.extend_from_slice(first.as_slice());
+    /// let start = container.len();
+    /// container.extend_from_slice(second.as_slice());
+    ///
+    /// let first = container.slice(0..start)?;
+    /// assert_eq!(first.load(number)?, &42);
+    ///
+    /// let second = container.slice(start..container.len())?;
+    /// assert_eq!(second.load(string)?, "Hello World!");
+    ///
+    /// // Loads are bounded by the view.
+    /// assert!(first.load(Ref::<u64>::new(start)).is_err());
+    /// assert!(container.slice(start..container.len() + 1).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn slice(&self, range: Range<usize>) -> Result<&Buf, Error> {
+        let data = self.inner_get_unaligned(range.start, range.end)?;
+        Ok(Buf::new(data))
+    }
+
+    /// Get a view of the given `range` of the current buffer as a nested
+    /// [`Buf`], ensuring that the start of the view is aligned to `align`.
+    ///
+    /// See [`Buf::slice`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `range` is out of bounds of the current buffer, or if the
+    /// start of the view is not aligned to `align`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut section = OwnedBuf::new();
+    /// let number = section.store(&42u32);
+    ///
+    /// let mut container = OwnedBuf::with_alignment::<u64>();
+    /// container.extend_from_slice(&[0, 0]);
+    /// container.extend_from_slice(section.as_slice());
+    /// container.extend_from_slice(&[0, 0]);
+    /// container.extend_from_slice(section.as_slice());
+    ///
+    /// let error = container.slice_with_alignment(2..6, 4).unwrap_err();
+    /// assert!(error.is_alignment());
+    ///
+    /// let section = container.slice_with_alignment(8..12, 4)?;
+    /// assert_eq!(section.load(number)?, &42);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn slice_with_alignment(&self, range: Range<usize>, align: usize) -> Result<&Buf, Error> {
+        assert!(align.is_power_of_two(), "Alignment is not a power of two");
+
+        // SAFETY: align is a power of two.
+        let data = unsafe { self.inner_get(range.start, range.end, align)? };
+        Ok(Buf::new(data))
+    }
+
     /// Load the given value as a reference.
     ///
     /// # Errors
@@ -329,6 +458,171 @@ impl Buf {
         ptr.load(self)
     }
 
+    /// Construct a reference to a sub-range of the string referenced by
+    /// `ptr`, checking that the range is in bounds and falls on character
+    /// boundaries.
+    ///
+    /// Only the bytes at the boundaries of the range are inspected, so this
+    /// does not validate the string itself. This happens once the returned
+    /// reference is loaded.
+    ///
+    /// See [`Ref::slice`] for the unchecked variant.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the range is out of bounds of the string, if either end of
+    /// the range is not on a character boundary, or if the offset of the
+    /// substring can't be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let text = buf.store_unsized("Grüße, Jürgen");
+    ///
+    /// let greeting = buf.substr(text, 0..7)?;
+    /// assert_eq!(buf.load(greeting)?, "Grüße");
+    ///
+    /// let error = buf.substr(text, 0..3).unwrap_err();
+    /// assert!(error.is_utf8());
+    ///
+    /// assert!(buf.substr(text, 0..100).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn substr<E, O>(
+        &self,
+        ptr: Ref<str, E, O>,
+        range: Range<usize>,
+    ) -> Result<Ref<str, E, O>, Error>
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        let len = ptr.len();
+
+        if range.start > range.end || range.end > len {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds { range, len }));
+        }
+
+        for index in [range.start, range.end] {
+            // NB: Both ends of the string are always boundaries.
+            if index == 0 || index == len {
+                continue;
+            }
+
+            let Some(at) = ptr.offset().checked_add(index) else {
+                return Err(Error::new(ErrorKind::Overflow {
+                    at: ptr.offset(),
+                    len: index,
+                }));
+            };
+
+            let Some(&b) = self.data.get(at) else {
+                return Err(Error::new(ErrorKind::IndexOutOfBounds {
+                    index: at,
+                    len: self.len(),
+                }));
+            };
+
+            // This is bit magic equivalent to: b < 128 || b >= 192
+            if (b as i8) < -0x40 {
+                return Err(Error::new(ErrorKind::NotCharBoundary { index }));
+            }
+        }
+
+        let Some(offset) = ptr.offset().checked_add(range.start) else {
+            return Err(Error::new(ErrorKind::Overflow {
+                at: ptr.offset(),
+                len: range.start,
+            }));
+        };
+
+        Ref::try_with_metadata(offset, range.end - range.start)
+    }
+
+    /// Construct a value implementing [`fmt::Debug`] which prints `value`
+    /// with any references it contains resolved against this buffer.
+    ///
+    /// References are followed up to a default depth, which can be changed
+    /// with [`DebugValue::with_depth`]. References which fail to load are
+    /// printed as `<invalid: ..>` rather than aborting.
+    ///
+    /// See [`DebugIn`] for how to implement this for custom types.
+    ///
+    /// [`DebugValue::with_depth`]: crate::buf::DebugValue::with_depth
+    /// [`DebugIn`]: crate::buf::DebugIn
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, Ref};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let strings = buf.store_strings(["first", "second"]);
+    /// assert_eq!(format!("{:?}", buf.debug(strings)), r#"["first", "second"]"#);
+    ///
+    /// let broken = Ref::<str>::with_metadata(0, 100);
+    /// assert!(format!("{:?}", buf.debug(broken)).starts_with("<invalid: "));
+    /// ```
+    #[inline]
+    pub fn debug<T>(&self, value: T) -> buf::DebugValue<'_, T>
+    where
+        T: buf::DebugIn,
+    {
+        buf::DebugValue::with_default_depth(value, self)
+    }
+
+    /// Hash the logical contents of `value` stored in this buffer into
+    /// `hasher`.
+    ///
+    /// Any references are followed and the values they point to are hashed
+    /// instead, so the result does not depend on where values are stored or on
+    /// any padding. See [`HashIn`] for more.
+    ///
+    /// [`HashIn`]: buf::HashIn
+    ///
+    /// # Errors
+    ///
+    /// Errors if any reference followed fails to load.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut a = OwnedBuf::new();
+    /// let first = a.store_unsized("Hello World");
+    /// let first = a.store(&first);
+    ///
+    /// let mut b = OwnedBuf::new();
+    /// b.store(&42u64);
+    /// let second = b.store_unsized("Hello World");
+    /// let second = b.store(&second);
+    ///
+    /// let mut hasher_a = DefaultHasher::new();
+    /// a.hash_value(first, &mut hasher_a)?;
+    ///
+    /// let mut hasher_b = DefaultHasher::new();
+    /// b.hash_value(second, &mut hasher_b)?;
+    ///
+    /// assert_eq!(hasher_a.finish(), hasher_b.finish());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn hash_value<T, H>(&self, value: T, hasher: &mut H) -> Result<(), Error>
+    where
+        T: buf::HashIn,
+        H: Hasher,
+    {
+        value.hash_in(self, hasher)
+    }
+
     /// Load a value of type `T` at the given `offset`.
     ///
     /// # Errors
@@ -419,6 +713,307 @@ impl Buf {
         self.load_sized_unaligned::<T>(offset)
     }
 
+    /// Load an unaligned value by copy.
+    ///
+    /// Unlike [`load()`], this does not require the value referenced to be
+    /// aligned within the buffer. Instead the value is copied into a correctly
+    /// aligned temporary, validated and returned. This is slower, but allows
+    /// for loading values out of buffers whose alignment can't be controlled.
+    ///
+    /// [`load()`]: Self::load
+    ///
+    /// # Errors
+    ///
+    /// This will error if the reference is out of bounds or if the memory it
+    /// references is not valid for the type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, Ref};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.extend_from_slice(&[0]);
+    ///
+    /// // Store the number one byte into the buffer, which is misaligned.
+    /// let number = Ref::<u32>::new(buf.len());
+    /// buf.extend_from_slice(&0x01020304u32.to_ne_bytes());
+    ///
+    /// assert!(buf.load(number).is_err());
+    /// assert_eq!(buf.load_unaligned(number)?, 0x01020304u32);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn load_unaligned<T, E, O>(&self, ptr: Ref<T, E, O>) -> Result<T, Error>
+    where
+        T: ZeroCopy,
+        E: ByteOrder,
+        O: Size,
+    {
+        self.load_sized_unaligned::<T>(ptr.offset())
+    }
+
+    /// Validate the [`ArchiveHeader`] at the end of the buffer and return a
+    /// reference to the root value it points to.
+    ///
+    /// This checks that the header has the `expected_magic` value and the
+    /// `expected_version`, that the archive was written with the byte order
+    /// `E`, and that the buffer is aligned as required by the archive. For
+    /// the default [`Native`] byte order this means that the archive must have
+    /// been written on a host with the same endianness.
+    ///
+    /// The header is typically written using
+    /// [`OwnedBuf::finish_with_header()`].
+    ///
+    /// [`Native`]: crate::endian::Native
+    ///
+    /// # Errors
+    ///
+    /// Errors if the buffer is too small to contain a header, or if any of the
+    /// checks above fail. Mismatched magic values, versions and byte orders
+    /// can be distinguished using [`Error::is_magic_mismatch()`],
+    /// [`Error::is_version_mismatch()`] and [`Error::is_byte_order_mismatch()`]
+    /// respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::{Buf, DefaultSize, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let root = buf.store_slice(&[1u32, 2, 3]);
+    /// buf.finish_with_header(*b"EXAMPLE1", 3, root)?;
+    ///
+    /// let root = buf.open_with_header::<[u32], Native, DefaultSize>(*b"EXAMPLE1", 3)?;
+    /// assert_eq!(buf.load(root)?, &[1, 2, 3]);
+    ///
+    /// let error = buf
+    ///     .open_with_header::<[u32], Native, DefaultSize>(*b"EXAMPLE1", 2)
+    ///     .unwrap_err();
+    /// assert!(error.is_version_mismatch());
+    ///
+    /// let error = Buf::new(b"short")
+    ///     .open_with_header::<u32, Native, DefaultSize>(*b"EXAMPLE1", 3)
+    ///     .unwrap_err();
+    /// assert!(error.is_out_of_bounds());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn open_with_header<T, E, O>(
+        &self,
+        expected_magic: [u8; 8],
+        expected_version: u32,
+    ) -> Result<Ref<T, E, O>, Error>
+    where
+        T: ?Sized + Pointee,
+        E: ByteOrder,
+        O: Size,
+    {
+        let Some(offset) = self.len().checked_sub(size_of::<ArchiveHeader>()) else {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds {
+                range: 0..size_of::<ArchiveHeader>(),
+                len: self.len(),
+            }));
+        };
+
+        let header = self.load_sized_unaligned::<ArchiveHeader>(offset)?;
+
+        if header.magic() != expected_magic {
+            return Err(Error::new(ErrorKind::HeaderMagicMismatch {
+                expected: expected_magic,
+                actual: header.magic(),
+            }));
+        }
+
+        if header.version() != expected_version {
+            return Err(Error::new(ErrorKind::HeaderVersionMismatch {
+                expected: expected_version,
+                actual: header.version(),
+            }));
+        }
+
+        let little = archive_header::is_little::<E>();
+
+        if header.is_little_endian() != Some(little) {
+            return Err(Error::new(ErrorKind::HeaderByteOrderMismatch {
+                expected: archive_header::byte_order_name(little),
+                actual: match header.is_little_endian() {
+                    Some(little) => archive_header::byte_order_name(little),
+                    None => "unknown",
+                },
+            }));
+        }
+
+        let align = header.alignment() as usize;
+
+        if !align.is_power_of_two() || !buf::is_aligned_with(self.as_ptr(), align) {
+            return Err(Error::new(ErrorKind::UnalignedStorage {
+                addr: self.as_ptr() as usize,
+                align,
+            }));
+        }
+
+        let Some(metadata) = T::Metadata::from_u32(header.root_metadata()) else {
+            return Err(Error::new(ErrorKind::InvalidMetadataRange {
+                metadata: header.root_metadata().into_repr(),
+                max: 0u32.into_repr(),
+            }));
+        };
+
+        Ref::try_with_metadata(header.root_offset() as usize, metadata)
+    }
+
+    /// Load a slice as a reference to an array of length `N`.
+    ///
+    /// The length of the array is usually inferred from how the result is
+    /// used.
+    ///
+    /// Note that arrays are [`ZeroCopy`] themselves, so if the length is known
+    /// when storing them they can be stored and loaded directly through a
+    /// `Ref<[T; N]>`. This is useful when the array was stored as a slice.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the length of the slice is not `N`, or for the same reasons as
+    /// [`load()`].
+    ///
+    /// [`load()`]: Self::load
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let corners = buf.store_slice(&[[1u32, 2], [3, 4], [5, 6], [7, 8]]);
+    /// let array = buf.store(&[1u32, 2, 3]);
+    ///
+    /// let [a, b, c, d] = buf.load_array(corners)?;
+    /// assert_eq!([a, b, c, d], [&[1, 2], &[3, 4], &[5, 6], &[7, 8]]);
+    /// assert_eq!(buf.load(array)?, &[1, 2, 3]);
+    ///
+    /// let array: Result<&[[u32; 2]; 3], _> = buf.load_array(corners);
+    /// assert_eq!(array.unwrap_err().to_string(), "Expected a slice of length 3 but found 4");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn load_array<E, O, T, const N: usize>(&self, ptr: Ref<[T], E, O>) -> Result<&[T; N], Error>
+    where
+        E: ByteOrder,
+        O: Size,
+        T: ZeroCopy,
+    {
+        let error = |actual| {
+            Error::new(ErrorKind::ArrayLengthMismatch {
+                expected: N,
+                actual,
+            })
+        };
+
+        if ptr.len() != N {
+            return Err(error(ptr.len()));
+        }
+
+        let slice = self.load(ptr)?;
+        <&[T; N]>::try_from(slice).map_err(|_| error(slice.len()))
+    }
+
+    /// Load the raw bytes making up a slice stored in the buffer.
+    ///
+    /// The slice is validated as if it was loaded with [`load()`] before its
+    /// bytes are returned, which is useful when passing stored data to APIs
+    /// that accept `&[u8]`.
+    ///
+    /// [`load()`]: Self::load
+    ///
+    /// # Errors
+    ///
+    /// This will error if the slice is out of bounds, not correctly aligned,
+    /// or if any of its elements are not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = buf.store_slice(&[1u16, 2, 3]);
+    /// let empty = buf.store_slice::<u32>(&[]);
+    ///
+    /// let expected = [1u16, 2, 3].map(u16::to_ne_bytes).concat();
+    /// assert_eq!(buf.load_bytes_of(values)?, &expected[..]);
+    /// assert!(buf.load_bytes_of(empty)?.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn load_bytes_of<T, E, O>(&self, ptr: Ref<[T], E, O>) -> Result<&[u8], Error>
+    where
+        T: ZeroCopy,
+        E: ByteOrder,
+        O: Size,
+    {
+        let values = self.load(ptr)?;
+        let start = ptr.offset();
+        // NB: The length can't overflow since the slice was successfully
+        // loaded from the buffer.
+        Ok(&self.data[start..start + size_of_val(values)])
+    }
+
+    /// Load a type implementing [`zerocopy::FromBytes`] from a byte slice
+    /// previously stored in the buffer.
+    ///
+    /// This is primarily useful in combination with
+    /// [`OwnedBuf::store_as_bytes()`] to interoperate with types which derive
+    /// the traits from the [`zerocopy`] crate.
+    ///
+    /// # Errors
+    ///
+    /// This will error if the referenced bytes are out of bounds, if they are
+    /// not correctly aligned for `T`, or if their length doesn't match the
+    /// size of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let bytes = buf.store_as_bytes(&[1u32, 2, 3]);
+    ///
+    /// assert_eq!(buf.load_from_bytes::<[u32; 3], _, _>(bytes)?, &[1, 2, 3]);
+    /// assert!(buf.load_from_bytes::<[u32; 2], _, _>(bytes).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "zerocopy")]
+    pub fn load_from_bytes<T, E, O>(&self, ptr: Ref<[u8], E, O>) -> Result<&T, Error>
+    where
+        T: zerocopy::FromBytes,
+        E: ByteOrder,
+        O: Size,
+    {
+        let bytes = self.load(ptr)?;
+
+        if let Some(value) = T::ref_from(bytes) {
+            return Ok(value);
+        }
+
+        let range = ptr.offset()..ptr.offset() + bytes.len();
+
+        if bytes.len() != size_of::<T>() {
+            return Err(Error::new(ErrorKind::LayoutMismatch {
+                range,
+                layout: Layout::new::<T>(),
+            }));
+        }
+
+        Err(Error::new(ErrorKind::AlignmentRangeMismatch {
+            addr: bytes.as_ptr() as usize,
+            range,
+            align: align_of::<T>(),
+        }))
+    }
+
     /// Load the given value as a mutable reference.
     ///
     /// # Errors
@@ -453,6 +1048,68 @@ impl Buf {
         ptr.load_mut(self)
     }
 
+    /// Overwrite the value stored at the location of `ptr` with `value`.
+    ///
+    /// Any padding in `T` is re-initialized to zeros, which ensures that the
+    /// byte representation of the buffer remains deterministic. This is in
+    /// contrast to assigning to a reference returned by [`load_mut()`], where
+    /// the padding bytes of the assigned value are left unspecified.
+    ///
+    /// Unlike loading, storing does not require the location to be aligned.
+    ///
+    /// [`load_mut()`]: Self::load_mut
+    ///
+    /// # Errors
+    ///
+    /// This will error if the location of `ptr` is out of bounds of the
+    /// current buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+    ///
+    /// #[derive(Debug, PartialEq, ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Counter {
+    ///     flag: u8,
+    ///     count: u32,
+    /// }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let first = buf.store(&Counter { flag: 1, count: 10 });
+    /// let slice = buf.store_slice(&[1u32, 2, 3]);
+    ///
+    /// buf.store_at(first, &Counter { flag: 2, count: 20 })?;
+    /// buf.load_mut(slice)?[1] = 42;
+    ///
+    /// assert_eq!(buf.load(first)?, &Counter { flag: 2, count: 20 });
+    /// assert_eq!(buf.load(slice)?, &[1, 42, 3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn store_at<T, E: ByteOrder, O: Size>(
+        &mut self,
+        ptr: Ref<T, E, O>,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: ZeroCopy,
+    {
+        let start = ptr.offset();
+        let end = end_of::<T>(start)?;
+        let data = self.inner_get_mut_unaligned(start, end)?;
+
+        // SAFETY: We've checked that the destination is in bounds and of the
+        // size of `T` above.
+        unsafe {
+            buf::store_unaligned(NonNull::new_unchecked(data.as_mut_ptr()), value);
+        }
+
+        Ok(())
+    }
+
     /// Bind the current buffer to a value.
     ///
     /// This provides a more convenient API for complex types like
@@ -701,6 +1358,28 @@ impl Buf {
         Ok(data)
     }
 
+    #[doc(hidden)]
+    #[inline]
+    pub fn __load_unsized<T, O, E>(&self, unsize: Ref<T, E, O>) -> Result<&T, Error>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+        O: Size,
+        E: ByteOrder,
+    {
+        self.load_unsized(unsize)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn __load_unsized_mut<T, O, E>(&mut self, unsize: Ref<T, E, O>) -> Result<&mut T, Error>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+        O: Size,
+        E: ByteOrder,
+    {
+        self.load_unsized_mut(unsize)
+    }
+
     /// Load an unsized reference.
     #[inline]
     pub(crate) fn load_unsized<T, O, E>(&self, unsize: Ref<T, E, O>) -> Result<&T, Error>
@@ -716,7 +1395,8 @@ impl Buf {
         // buffer slice.
         unsafe {
             let (buf, remaining) = self.get_range_from(start, T::ALIGN)?;
-            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)?;
+            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)
+                .map_err(|error| error.with_base(start).with_root::<T>(start, buf.as_ptr()))?;
             Ok(&*T::with_metadata(buf, metadata))
         }
     }
@@ -739,7 +1419,8 @@ impl Buf {
         // buffer slice.
         unsafe {
             let (buf, remaining) = self.get_mut_range_from(start, T::ALIGN)?;
-            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)?;
+            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)
+                .map_err(|error| error.with_base(start).with_root::<T>(start, buf.as_ptr()))?;
             Ok(&mut *T::with_metadata_mut(buf, metadata))
         }
     }
@@ -751,7 +1432,7 @@ impl Buf {
         T: ZeroCopy,
     {
         unsafe {
-            let end = offset + size_of::<T>();
+            let end = end_of::<T>(offset)?;
 
             // SAFETY: align_of::<T>() is always a power of two.
             let buf = self.inner_get(offset, end, align_of::<T>())?;
@@ -760,7 +1441,8 @@ impl Buf {
                 // SAFETY: We've checked the size and alignment of the buffer above.
                 // The remaining safety requirements depend on the implementation of
                 // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+                T::validate(&mut Validator::from_slice(buf))
+                    .map_err(|error| error.with_root::<T>(offset, buf.as_ptr()))?;
             }
 
             // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
@@ -843,7 +1525,7 @@ impl Buf {
         }
 
         let start = a.max(b);
-        let end = start + size_of::<T>();
+        let end = end_of::<T>(start)?;
 
         if end > self.data.len() {
             return Err(Error::new(ErrorKind::OutOfRangeBounds {
@@ -877,7 +1559,7 @@ impl Buf {
     where
         T: ZeroCopy,
     {
-        let end = offset + size_of::<T>();
+        let end = end_of::<T>(offset)?;
 
         unsafe {
             // SAFETY: align_of::<T>() is always a power of two.
@@ -887,7 +1569,8 @@ impl Buf {
                 // SAFETY: We've checked the size and alignment of the buffer above.
                 // The remaining safety requirements depend on the implementation of
                 // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+                T::validate(&mut Validator::from_slice(buf))
+                    .map_err(|error| error.with_root::<T>(offset, buf.as_ptr()))?;
             }
 
             // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
@@ -896,28 +1579,41 @@ impl Buf {
         }
     }
 
-    /// Load the given sized value as a reference.
+    /// Load the given sized value by copy, without requiring it to be aligned.
     #[inline]
     pub(crate) fn load_sized_unaligned<T>(&self, start: usize) -> Result<T, Error>
     where
         T: ZeroCopy,
     {
-        let end = start + size_of::<T>();
+        let end = end_of::<T>(start)?;
 
         unsafe {
             // SAFETY: align_of::<T>() is always a power of two.
             let buf = self.inner_get_unaligned(start, end)?;
 
-            if !T::ANY_BITS {
-                // SAFETY: We've checked the size and alignment of the buffer above.
-                // The remaining safety requirements depend on the implementation of
-                // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+            if T::ANY_BITS {
+                // SAFETY: Implementing ANY_BITS is unsafe, and requires that
+                // the type being coerced into can really inhabit any bit
+                // pattern.
+                return Ok(read_unaligned(buf.as_ptr().cast()));
             }
 
-            // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
-            // type being coerced into can really inhabit any bit pattern.
-            Ok(read_unaligned(buf.as_ptr().cast()))
+            // NB: Validation might construct references to fields, so it has
+            // to be performed over a correctly aligned copy of the value.
+            let mut value = MaybeUninit::<T>::uninit();
+
+            value
+                .as_mut_ptr()
+                .cast::<u8>()
+                .copy_from_nonoverlapping(buf.as_ptr(), size_of::<T>());
+
+            // SAFETY: The copy is correctly aligned and sized, and every
+            // byte in it has been initialized from the buffer. The remaining
+            // safety requirements depend on the implementation of validate.
+            T::validate(&mut Validator::new(NonNull::from(&mut value).cast()))
+                .map_err(|error| error.with_root::<T>(start, value.as_ptr().cast()))?;
+
+            Ok(value.assume_init())
         }
     }
 
@@ -959,6 +1655,7 @@ impl Buf {
     /// assert!(buf.is_aligned::<Align4096>() || !buf.is_aligned::<Align4096>());
     /// ```
     #[inline]
+    #[doc(alias = "is_aligned_for")]
     pub fn is_aligned<T>(&self) -> bool {
         buf::is_aligned_with(self.as_ptr(), align_of::<T>())
     }
@@ -1113,3 +1810,19 @@ where
         &mut self.data[index]
     }
 }
+
+/// Calculate the end of a value of type `T` stored at `start`.
+///
+/// Since `usize` might be as small as 32 bits, this can overflow for offsets
+/// well within the range of a [`Size`], so the calculation has to be checked.
+#[inline]
+fn end_of<T>(start: usize) -> Result<usize, Error> {
+    let Some(end) = start.checked_add(size_of::<T>()) else {
+        return Err(Error::new(ErrorKind::Overflow {
+            at: start,
+            len: size_of::<T>(),
+        }));
+    };
+
+    Ok(end)
+}
@@ -0,0 +1,94 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::buf::OwnedBuf;
+use crate::endian::ByteOrder;
+use crate::pointer::Size;
+
+/// A guard which restores an [`OwnedBuf`] to the length it had when the
+/// checkpoint was created, unless [`Checkpoint::commit`] is called.
+///
+/// The checkpoint dereferences to the buffer it guards, so anything can be
+/// stored through it.
+///
+/// See [`OwnedBuf::checkpoint`].
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+///
+/// fn try_store(buf: &mut OwnedBuf, values: &[u32]) -> Option<()> {
+///     let mut checkpoint = buf.checkpoint();
+///
+///     for &value in values {
+///         if value == 0 {
+///             return None;
+///         }
+///
+///         checkpoint.store(&value);
+///     }
+///
+///     checkpoint.commit();
+///     Some(())
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// assert!(try_store(&mut buf, &[1, 2, 0]).is_none());
+/// assert_eq!(buf.len(), 0);
+///
+/// assert!(try_store(&mut buf, &[1, 2, 3]).is_some());
+/// assert_eq!(buf.len(), 12);
+/// ```
+pub struct Checkpoint<'a, E: ByteOrder, O: Size> {
+    buf: &'a mut OwnedBuf<E, O>,
+    len: usize,
+    committed: bool,
+}
+
+impl<'a, E: ByteOrder, O: Size> Checkpoint<'a, E, O> {
+    #[inline]
+    pub(crate) fn new(buf: &'a mut OwnedBuf<E, O>) -> Self {
+        let len = buf.len();
+
+        Self {
+            buf,
+            len,
+            committed: false,
+        }
+    }
+
+    /// Commit everything stored since the checkpoint was created, preventing
+    /// the buffer from being restored.
+    #[inline]
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<E: ByteOrder, O: Size> Deref for Checkpoint<'_, E, O> {
+    type Target = OwnedBuf<E, O>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.buf
+    }
+}
+
+impl<E: ByteOrder, O: Size> DerefMut for Checkpoint<'_, E, O> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buf
+    }
+}
+
+impl<E: ByteOrder, O: Size> Drop for Checkpoint<'_, E, O> {
+    #[inline]
+    fn drop(&mut self) {
+        // The buffer might have been shortened further through the guard, in
+        // which case there is nothing to restore.
+        if !self.committed && self.buf.len() > self.len {
+            self.buf.truncate(self.len);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::buf::{Buf, Load};
+use crate::endian::ByteOrder;
+use crate::pointer::{Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// The default depth to which references are followed by [`DebugValue`].
+const DEFAULT_DEPTH: usize = 8;
+
+/// Trait used to debug print a value with any references it contains
+/// resolved against a [`Buf`].
+///
+/// This is used through [`Buf::debug`], and can be implemented for custom
+/// types using the `debug` attribute of the
+/// [`ZeroCopy`][derive@crate::ZeroCopy] derive.
+///
+/// References which fail to load are printed as `<invalid: ..>` with the
+/// error that caused them to fail, and references past the maximum depth are
+/// printed as-is.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(debug)]
+/// struct Person {
+///     name: Ref<str>,
+///     age: u32,
+///     friends: Ref<[Ref<str>]>,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let name = buf.store_unsized("Aristotle");
+/// let friends = buf.store_strings(["Plato"]);
+/// let person = buf.store(&Person { name, age: 61, friends });
+///
+/// assert_eq!(
+///     format!("{:?}", buf.debug(person)),
+///     r#"Person { name: "Aristotle", age: 61, friends: ["Plato"] }"#
+/// );
+///
+/// let broken = Ref::<Person>::new(buf.len());
+/// assert!(format!("{:?}", buf.debug(broken)).starts_with("<invalid: "));
+/// ```
+pub trait DebugIn {
+    /// Format the value, following references into `buf` up to the given
+    /// `depth`.
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+/// A value associated with a buffer which implements [`fmt::Debug`] through
+/// [`DebugIn`].
+///
+/// See [`Buf::debug`].
+pub struct DebugValue<'buf, T> {
+    value: T,
+    buf: &'buf Buf,
+    depth: usize,
+}
+
+impl<'buf, T> DebugValue<'buf, T>
+where
+    T: DebugIn,
+{
+    /// Construct a new debug value with the given depth.
+    #[inline]
+    pub fn new(value: T, buf: &'buf Buf, depth: usize) -> Self {
+        Self { value, buf, depth }
+    }
+
+    #[inline]
+    pub(crate) fn with_default_depth(value: T, buf: &'buf Buf) -> Self {
+        Self::new(value, buf, DEFAULT_DEPTH)
+    }
+
+    /// Set the maximum depth to which references are followed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let string = buf.store_unsized("Hello");
+    /// let string = buf.store(&string);
+    ///
+    /// assert_eq!(format!("{:?}", buf.debug(string)), r#""Hello""#);
+    /// assert_eq!(
+    ///     format!("{:?}", buf.debug(string).with_depth(1)),
+    ///     "Ref<str> { offset: 0, metadata: 5 }"
+    /// );
+    /// ```
+    #[inline]
+    pub fn with_depth(self, depth: usize) -> Self {
+        Self { depth, ..self }
+    }
+}
+
+impl<T> fmt::Debug for DebugValue<'_, T>
+where
+    T: DebugIn,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_in(self.buf, self.depth, f)
+    }
+}
+
+impl<T> DebugIn for &T
+where
+    T: ?Sized + DebugIn,
+{
+    #[inline]
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_in(buf, depth, f)
+    }
+}
+
+macro_rules! impl_debug {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl DebugIn for $ty {
+                #[inline]
+                fn fmt_in(&self, _: &Buf, _: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(self, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_debug! {
+    (), bool, char, f32, f64, str,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>,
+    Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>,
+    Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>,
+}
+
+impl<T: ?Sized> DebugIn for PhantomData<T> {
+    #[inline]
+    fn fmt_in(&self, _: &Buf, _: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+impl<T> DebugIn for [T]
+where
+    T: DebugIn,
+{
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|value| DebugValue::new(value, buf, depth)))
+            .finish()
+    }
+}
+
+impl<T, const N: usize> DebugIn for [T; N]
+where
+    T: DebugIn,
+{
+    #[inline]
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self[..].fmt_in(buf, depth, f)
+    }
+}
+
+/// Load a reference and format the loaded value with one less depth.
+fn fmt_ref<P>(ptr: &P, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    P: fmt::Debug + Load,
+    P::Target: DebugIn,
+{
+    if depth == 0 {
+        return fmt::Debug::fmt(ptr, f);
+    }
+
+    match ptr.load(buf) {
+        Ok(value) => value.fmt_in(buf, depth - 1, f),
+        Err(error) => write!(f, "<invalid: {error}>"),
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> DebugIn for Ref<T, E, O>
+where
+    T: ZeroCopy + DebugIn,
+{
+    #[inline]
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_ref(self, buf, depth, f)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> DebugIn for Ref<[T], E, O>
+where
+    T: ZeroCopy + DebugIn,
+{
+    #[inline]
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_ref(self, buf, depth, f)
+    }
+}
+
+impl<E: ByteOrder, O: Size> DebugIn for Ref<str, E, O> {
+    #[inline]
+    fn fmt_in(&self, buf: &Buf, depth: usize, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_ref(self, buf, depth, f)
+    }
+}
@@ -0,0 +1,167 @@
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::buf::Buf;
+use crate::endian::ByteOrder;
+use crate::error::Error;
+use crate::pointer::{Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// Trait used to structurally compare two values which are stored in
+/// different buffers.
+///
+/// Comparing two [`Ref`] values with [`PartialEq`] only compares their offsets
+/// and metadata. This instead loads what they point to from their respective
+/// buffers and compares the loaded values, recursively following any
+/// references they contain.
+///
+/// This can be implemented for custom types using the `eq` attribute of the
+/// [`ZeroCopy`][derive@crate::ZeroCopy] derive.
+///
+/// # Errors
+///
+/// Errors if any reference followed fails to load.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::buf::EqIn;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(eq)]
+/// struct Person {
+///     name: Ref<str>,
+///     age: u32,
+/// }
+///
+/// let mut a = OwnedBuf::new();
+/// let name = a.store_unsized("Aristotle");
+/// let person_a = a.store(&Person { name, age: 61 });
+///
+/// let mut b = OwnedBuf::new();
+/// b.store_unsized("Plato");
+/// let name = b.store_unsized("Aristotle");
+/// let person_b = b.store(&Person { name, age: 61 });
+///
+/// assert_ne!(person_a, person_b);
+/// assert!(person_a.eq_in(&a, &person_b, &b)?);
+///
+/// let other = b.store(&Person { name, age: 62 });
+/// assert!(!person_a.eq_in(&a, &other, &b)?);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait EqIn {
+    /// Test if `self` stored in `buf` is structurally equal to `other` stored
+    /// in `other_buf`.
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error>;
+}
+
+impl<T> EqIn for &T
+where
+    T: ?Sized + EqIn,
+{
+    #[inline]
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        (**self).eq_in(buf, *other, other_buf)
+    }
+}
+
+macro_rules! impl_eq {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl EqIn for $ty {
+                #[inline]
+                fn eq_in(&self, _: &Buf, other: &Self, _: &Buf) -> Result<bool, Error> {
+                    Ok(*self == *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_eq! {
+    (), bool, char, f32, f64, str,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>,
+    Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>,
+    Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>,
+}
+
+impl<T: ?Sized> EqIn for PhantomData<T> {
+    #[inline]
+    fn eq_in(&self, _: &Buf, _: &Self, _: &Buf) -> Result<bool, Error> {
+        Ok(true)
+    }
+}
+
+impl<T> EqIn for [T]
+where
+    T: EqIn,
+{
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        if self.len() != other.len() {
+            return Ok(false);
+        }
+
+        for (a, b) in self.iter().zip(other) {
+            if !a.eq_in(buf, b, other_buf)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+impl<T, const N: usize> EqIn for [T; N]
+where
+    T: EqIn,
+{
+    #[inline]
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        self[..].eq_in(buf, &other[..], other_buf)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> EqIn for Ref<T, E, O>
+where
+    T: ZeroCopy + EqIn,
+{
+    #[inline]
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        buf.load(*self)?
+            .eq_in(buf, other_buf.load(*other)?, other_buf)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> EqIn for Ref<[T], E, O>
+where
+    T: ZeroCopy + EqIn,
+{
+    #[inline]
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        // NB: Differing lengths can be determined without loading anything.
+        if self.len() != other.len() {
+            return Ok(false);
+        }
+
+        buf.load(*self)?
+            .eq_in(buf, other_buf.load(*other)?, other_buf)
+    }
+}
+
+impl<E: ByteOrder, O: Size> EqIn for Ref<str, E, O> {
+    #[inline]
+    fn eq_in(&self, buf: &Buf, other: &Self, other_buf: &Buf) -> Result<bool, Error> {
+        Ok(buf.load(*self)? == other_buf.load(*other)?)
+    }
+}
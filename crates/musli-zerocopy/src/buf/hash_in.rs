@@ -0,0 +1,208 @@
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::buf::Buf;
+use crate::endian::ByteOrder;
+use crate::error::Error;
+use crate::pointer::{Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// Trait used to hash the logical contents of a value stored in a [`Buf`].
+///
+/// Hashing a [`Ref`] with [`Hash`] only hashes its offset and metadata. This
+/// instead loads what it points to and hashes the loaded value, recursively
+/// following any references it contains. Neither offsets nor padding affect
+/// the result, so the same data stored in different places hashes the same.
+///
+/// This is used through [`Buf::hash_value`], and can be implemented for custom
+/// types using the `hash` attribute of the
+/// [`ZeroCopy`][derive@crate::ZeroCopy] derive.
+///
+/// # Errors
+///
+/// Errors if any reference followed fails to load.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(hash)]
+/// struct Person {
+///     name: Ref<str>,
+///     age: u32,
+/// }
+///
+/// let mut a = OwnedBuf::new();
+/// let name = a.store_unsized("Aristotle");
+/// let person_a = a.store(&Person { name, age: 61 });
+///
+/// let mut b = OwnedBuf::new();
+/// b.store_unsized("Plato");
+/// let name = b.store_unsized("Aristotle");
+/// let person_b = b.store(&Person { name, age: 61 });
+///
+/// let mut hasher_a = DefaultHasher::new();
+/// a.hash_value(person_a, &mut hasher_a)?;
+///
+/// let mut hasher_b = DefaultHasher::new();
+/// b.hash_value(person_b, &mut hasher_b)?;
+///
+/// assert_ne!(person_a, person_b);
+/// assert_eq!(hasher_a.finish(), hasher_b.finish());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait HashIn {
+    /// Feed the logical contents of `self` stored in `buf` into `hasher`.
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher;
+}
+
+impl<T> HashIn for &T
+where
+    T: ?Sized + HashIn,
+{
+    #[inline]
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        (**self).hash_in(buf, hasher)
+    }
+}
+
+macro_rules! impl_hash {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HashIn for $ty {
+                #[inline]
+                fn hash_in<H>(&self, _: &Buf, hasher: &mut H) -> Result<(), Error>
+                where
+                    H: Hasher,
+                {
+                    self.hash(hasher);
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_hash! {
+    (), bool, char, str,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>,
+    Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>,
+    Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>,
+}
+
+macro_rules! impl_float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HashIn for $ty {
+                #[inline]
+                fn hash_in<H>(&self, _: &Buf, hasher: &mut H) -> Result<(), Error>
+                where
+                    H: Hasher,
+                {
+                    self.to_bits().hash(hasher);
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+impl<T: ?Sized> HashIn for PhantomData<T> {
+    #[inline]
+    fn hash_in<H>(&self, _: &Buf, _: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        Ok(())
+    }
+}
+
+impl<T> HashIn for [T]
+where
+    T: HashIn,
+{
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        hasher.write_usize(self.len());
+
+        for value in self {
+            value.hash_in(buf, hasher)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> HashIn for [T; N]
+where
+    T: HashIn,
+{
+    #[inline]
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        self[..].hash_in(buf, hasher)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> HashIn for Ref<T, E, O>
+where
+    T: ZeroCopy + HashIn,
+{
+    #[inline]
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        buf.load(*self)?.hash_in(buf, hasher)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> HashIn for Ref<[T], E, O>
+where
+    T: ZeroCopy + HashIn,
+{
+    #[inline]
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        buf.load(*self)?.hash_in(buf, hasher)
+    }
+}
+
+impl<E: ByteOrder, O: Size> HashIn for Ref<str, E, O> {
+    #[inline]
+    fn hash_in<H>(&self, buf: &Buf, hasher: &mut H) -> Result<(), Error>
+    where
+        H: Hasher,
+    {
+        buf.load(*self)?.hash(hasher);
+        Ok(())
+    }
+}
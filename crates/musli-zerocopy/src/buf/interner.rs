@@ -0,0 +1,140 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+
+use crate::buf::OwnedBuf;
+use crate::endian::{ByteOrder, Native};
+use crate::pointer::{DefaultSize, Ref, Size};
+
+/// A wrapper around an [`OwnedBuf`] which deduplicates stored strings.
+///
+/// Every string stored through [`Interner::store`] is cached, so that storing
+/// the same string again returns the previously stored reference instead of
+/// growing the buffer.
+///
+/// The cache is invalidated when the buffer is cleared through
+/// [`Interner::clear`]. Cached references are also verified against the
+/// buffer before they are handed out, so modifying the underlying buffer
+/// through [`Interner::buf_mut`] is safe, it just causes the affected strings
+/// to be stored again.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::buf::Interner;
+///
+/// let mut interner = Interner::new(OwnedBuf::new());
+///
+/// let first = interner.store("en-US");
+/// let len = interner.buf().len();
+/// let second = interner.store("en-US");
+///
+/// assert_eq!(first, second);
+/// assert_eq!(interner.buf().len(), len);
+///
+/// let buf = interner.into_inner();
+/// assert_eq!(buf.load(second)?, "en-US");
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Interner<E: ByteOrder = Native, O: Size = DefaultSize> {
+    buf: OwnedBuf<E, O>,
+    cache: BTreeMap<Box<str>, Ref<str, E, O>>,
+}
+
+impl<E: ByteOrder, O: Size> Interner<E, O> {
+    /// Construct a new interner wrapping the given buffer.
+    ///
+    /// Strings which are already stored in the buffer are not deduplicated
+    /// against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::buf::Interner;
+    ///
+    /// let interner = Interner::new(OwnedBuf::new());
+    /// assert!(interner.buf().is_empty());
+    /// ```
+    #[inline]
+    pub fn new(buf: OwnedBuf<E, O>) -> Self {
+        Self {
+            buf,
+            cache: BTreeMap::new(),
+        }
+    }
+
+    /// Store a string, or return the reference to a previously stored
+    /// identical string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::buf::Interner;
+    ///
+    /// let mut interner = Interner::new(OwnedBuf::new());
+    ///
+    /// let a = interner.store("noun");
+    /// let b = interner.store("verb");
+    /// let c = interner.store("noun");
+    ///
+    /// assert_eq!(a, c);
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn store(&mut self, string: &str) -> Ref<str, E, O> {
+        if let Some(reference) = self.cache.get(string) {
+            if matches!(self.buf.load(*reference), Ok(stored) if stored == string) {
+                return *reference;
+            }
+        }
+
+        let reference = self.buf.store_unsized(string);
+        self.cache.insert(string.into(), reference);
+        reference
+    }
+
+    /// Clear the underlying buffer and the cache of stored strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::buf::Interner;
+    ///
+    /// let mut interner = Interner::new(OwnedBuf::new());
+    /// interner.store("noun");
+    /// interner.clear();
+    /// assert!(interner.buf().is_empty());
+    ///
+    /// let noun = interner.store("noun");
+    /// assert_eq!(interner.buf().load(noun)?, "noun");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.cache.clear();
+    }
+
+    /// Access the underlying buffer.
+    #[inline]
+    pub fn buf(&self) -> &OwnedBuf<E, O> {
+        &self.buf
+    }
+
+    /// Access the underlying buffer mutably.
+    ///
+    /// Any modifications to previously stored strings will cause them to be
+    /// stored again the next time they are interned.
+    #[inline]
+    pub fn buf_mut(&mut self) -> &mut OwnedBuf<E, O> {
+        &mut self.buf
+    }
+
+    /// Coerce the interner into its underlying buffer.
+    #[inline]
+    pub fn into_inner(self) -> OwnedBuf<E, O> {
+        self.buf
+    }
+}
@@ -1,7 +1,9 @@
+use core::ffi::CStr;
+
 use crate::buf::Buf;
 use crate::endian::ByteOrder;
 use crate::error::Error;
-use crate::pointer::{Ref, Size};
+use crate::pointer::{Pointee, Ref, RelativeRef, Size};
 use crate::traits::ZeroCopy;
 
 /// Trait used for loading any kind of reference through [`Buf::load`].
@@ -33,62 +35,132 @@ pub trait LoadMut: Load {
     fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error>;
 }
 
+/// Helper trait used to implement [`Load`] for [`Ref<T>`].
+///
+/// This is implemented for every type which can be loaded through a reference,
+/// including types deriving [`UnsizedZeroCopy`] which can't implement [`Load`]
+/// for [`Ref<T>`] themselves since it's a foreign type.
+///
+/// [`UnsizedZeroCopy`]: derive@crate::UnsizedZeroCopy
+#[doc(hidden)]
+pub trait LoadRef: Pointee {
+    #[doc(hidden)]
+    fn __load<E: ByteOrder, O: Size>(ptr: Ref<Self, E, O>, buf: &Buf) -> Result<&Self, Error>;
+}
+
+/// Helper trait used to implement [`LoadMut`] for [`Ref<T>`].
+///
+/// See [`LoadRef`] for why this exists.
+#[doc(hidden)]
+pub trait LoadRefMut: LoadRef {
+    #[doc(hidden)]
+    fn __load_mut<E: ByteOrder, O: Size>(
+        ptr: Ref<Self, E, O>,
+        buf: &mut Buf,
+    ) -> Result<&mut Self, Error>;
+}
+
 impl<T, E: ByteOrder, O: Size> Load for Ref<T, E, O>
 where
-    T: ZeroCopy,
+    T: ?Sized + LoadRef,
 {
     type Target = T;
 
     #[inline]
     fn load<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
-        buf.load_sized::<T>(self.offset())
+        T::__load(*self, buf)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> LoadMut for Ref<T, E, O>
+where
+    T: ?Sized + LoadRefMut,
+{
+    #[inline]
+    fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error> {
+        T::__load_mut(*self, buf)
     }
 }
 
-impl<T, E: ByteOrder, O: Size> Load for Ref<[T], E, O>
+impl<T> LoadRef for T
 where
     T: ZeroCopy,
 {
-    type Target = [T];
+    #[inline]
+    fn __load<E: ByteOrder, O: Size>(ptr: Ref<Self, E, O>, buf: &Buf) -> Result<&Self, Error> {
+        buf.load_sized::<T>(ptr.offset())
+    }
+}
 
+impl<T> LoadRef for [T]
+where
+    T: ZeroCopy,
+{
     #[inline]
-    fn load<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
-        buf.load_unsized(*self)
+    fn __load<E: ByteOrder, O: Size>(ptr: Ref<Self, E, O>, buf: &Buf) -> Result<&Self, Error> {
+        buf.load_unsized(ptr)
     }
 }
 
-impl<E: ByteOrder, O: Size> Load for Ref<str, E, O> {
-    type Target = str;
+impl LoadRef for str {
+    #[inline]
+    fn __load<E: ByteOrder, O: Size>(ptr: Ref<Self, E, O>, buf: &Buf) -> Result<&Self, Error> {
+        buf.load_unsized(ptr)
+    }
+}
 
+impl LoadRef for CStr {
     #[inline]
-    fn load<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
-        buf.load_unsized(*self)
+    fn __load<E: ByteOrder, O: Size>(ptr: Ref<Self, E, O>, buf: &Buf) -> Result<&Self, Error> {
+        buf.load_unsized(ptr)
     }
 }
 
-impl<T, E: ByteOrder, O: Size> LoadMut for Ref<T, E, O>
+impl<T> LoadRefMut for T
 where
     T: ZeroCopy,
 {
     #[inline]
-    fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error> {
-        buf.load_sized_mut::<T>(self.offset())
+    fn __load_mut<E: ByteOrder, O: Size>(
+        ptr: Ref<Self, E, O>,
+        buf: &mut Buf,
+    ) -> Result<&mut Self, Error> {
+        buf.load_sized_mut::<T>(ptr.offset())
     }
 }
 
-impl<T, E: ByteOrder, O: Size> LoadMut for Ref<[T], E, O>
+impl<T> LoadRefMut for [T]
 where
     T: ZeroCopy,
 {
     #[inline]
-    fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error> {
-        buf.load_unsized_mut(*self)
+    fn __load_mut<E: ByteOrder, O: Size>(
+        ptr: Ref<Self, E, O>,
+        buf: &mut Buf,
+    ) -> Result<&mut Self, Error> {
+        buf.load_unsized_mut(ptr)
     }
 }
 
-impl<E: ByteOrder, O: Size> LoadMut for Ref<str, E, O> {
+impl LoadRefMut for str {
     #[inline]
-    fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error> {
-        buf.load_unsized_mut(*self)
+    fn __load_mut<E: ByteOrder, O: Size>(
+        ptr: Ref<Self, E, O>,
+        buf: &mut Buf,
+    ) -> Result<&mut Self, Error> {
+        buf.load_unsized_mut(ptr)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> Load for &RelativeRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    Ref<T, E, O>: Load,
+{
+    type Target = <Ref<T, E, O> as Load>::Target;
+
+    #[inline]
+    fn load<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
+        self.resolve(buf)?.load(buf)
     }
 }
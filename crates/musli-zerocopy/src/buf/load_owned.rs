@@ -0,0 +1,196 @@
+use core::array;
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::buf::Buf;
+use crate::endian::ByteOrder;
+use crate::error::Error;
+use crate::pointer::{Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// Trait used to convert a zero-copy value into an owned value which is
+/// independent of the [`Buf`] it was loaded from.
+///
+/// References are followed recursively, so that a [`Ref<str>`] is converted
+/// into a `String` and a [`Ref<[T]>`] into a `Vec<T::Owned>`.
+///
+/// This can be implemented for custom types using the `owned` attribute of
+/// the [`ZeroCopy`][derive@crate::ZeroCopy] derive, which names an owned type
+/// with the same fields or variants where each field type is the
+/// [`LoadOwned::Owned`] type of the corresponding zero-copy field. Ignored
+/// fields are constructed using [`Default`].
+///
+/// [`Ref<str>`]: crate::pointer::Ref
+/// [`Ref<[T]>`]: crate::pointer::Ref
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::buf::LoadOwned;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(owned = OwnedPerson)]
+/// struct Person {
+///     name: Ref<str>,
+///     age: u32,
+///     pets: Ref<[Pet]>,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct OwnedPerson {
+///     name: String,
+///     age: u32,
+///     pets: Vec<OwnedPet>,
+/// }
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(u8)]
+/// #[zero_copy(owned = OwnedPet)]
+/// enum Pet {
+///     Cat { name: Ref<str> },
+///     Fish,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// enum OwnedPet {
+///     Cat { name: String },
+///     Fish,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let name = buf.store_unsized("Aristotle");
+/// let cat = buf.store_unsized("Nyx");
+/// let pets = buf.store_slice(&[Pet::Cat { name: cat }, Pet::Fish]);
+///
+/// let person = buf.store(&Person { name, age: 61, pets });
+///
+/// let person = person.to_owned_in(&buf)?;
+///
+/// assert_eq!(person, OwnedPerson {
+///     name: String::from("Aristotle"),
+///     age: 61,
+///     pets: vec![OwnedPet::Cat { name: String::from("Nyx") }, OwnedPet::Fish],
+/// });
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait LoadOwned {
+    /// The owned type being produced.
+    type Owned;
+
+    /// Convert the value into its owned counterpart, loading anything it
+    /// references from `buf`.
+    fn to_owned_in(&self, buf: &Buf) -> Result<Self::Owned, Error>;
+}
+
+macro_rules! impl_copy {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl LoadOwned for $ty {
+                type Owned = $ty;
+
+                #[inline]
+                fn to_owned_in(&self, _: &Buf) -> Result<Self::Owned, Error> {
+                    Ok(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_copy! {
+    (), bool, char, f32, f64,
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+    Option<NonZeroU8>, Option<NonZeroU16>, Option<NonZeroU32>,
+    Option<NonZeroU64>, Option<NonZeroU128>, Option<NonZeroUsize>,
+    Option<NonZeroI8>, Option<NonZeroI16>, Option<NonZeroI32>,
+    Option<NonZeroI64>, Option<NonZeroI128>, Option<NonZeroIsize>,
+}
+
+impl<T: ?Sized> LoadOwned for PhantomData<T> {
+    type Owned = PhantomData<T>;
+
+    #[inline]
+    fn to_owned_in(&self, _: &Buf) -> Result<Self::Owned, Error> {
+        Ok(PhantomData)
+    }
+}
+
+impl<T, const N: usize> LoadOwned for [T; N]
+where
+    T: LoadOwned,
+{
+    type Owned = [T::Owned; N];
+
+    fn to_owned_in(&self, buf: &Buf) -> Result<Self::Owned, Error> {
+        let mut error = None;
+
+        let values = array::from_fn(|index| {
+            if error.is_some() {
+                return None;
+            }
+
+            match self[index].to_owned_in(buf) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    error = Some(e);
+                    None
+                }
+            }
+        });
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        // NB: Every element has been initialized since no error was raised.
+        Ok(values.map(|value: Option<T::Owned>| value.expect("missing element")))
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> LoadOwned for Ref<T, E, O>
+where
+    T: ZeroCopy + LoadOwned,
+{
+    type Owned = T::Owned;
+
+    #[inline]
+    fn to_owned_in(&self, buf: &Buf) -> Result<Self::Owned, Error> {
+        buf.load(*self)?.to_owned_in(buf)
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> LoadOwned for Ref<[T], E, O>
+where
+    T: ZeroCopy + LoadOwned,
+{
+    type Owned = Vec<T::Owned>;
+
+    #[inline]
+    fn to_owned_in(&self, buf: &Buf) -> Result<Self::Owned, Error> {
+        buf.load(*self)?
+            .iter()
+            .map(|value| value.to_owned_in(buf))
+            .collect()
+    }
+}
+
+impl<E: ByteOrder, O: Size> LoadOwned for Ref<str, E, O> {
+    type Owned = String;
+
+    #[inline]
+    fn to_owned_in(&self, buf: &Buf) -> Result<Self::Owned, Error> {
+        Ok(String::from(buf.load(*self)?))
+    }
+}
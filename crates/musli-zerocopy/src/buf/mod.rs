@@ -97,11 +97,28 @@ pub use self::bind::Bindable;
 mod bind;
 
 pub use self::load::{Load, LoadMut};
-mod load;
+pub(crate) mod load;
+
+pub use self::debug_in::{DebugIn, DebugValue};
+mod debug_in;
+
+pub use self::eq_in::EqIn;
+mod eq_in;
+
+pub use self::hash_in::HashIn;
+mod hash_in;
+
+#[cfg(feature = "alloc")]
+pub use self::load_owned::LoadOwned;
+#[cfg(feature = "alloc")]
+mod load_owned;
 
 pub use self::visit::Visit;
 pub(crate) mod visit;
 
+#[cfg(feature = "alloc")]
+pub(crate) mod sort;
+
 pub use self::validator::Validator;
 mod validator;
 
@@ -111,14 +128,38 @@ mod padder;
 pub use self::store_buf::StoreBuf;
 mod store_buf;
 
+pub use self::store_report::StoreReport;
+mod store_report;
+
 #[cfg(feature = "alloc")]
 pub use self::owned_buf::OwnedBuf;
 #[cfg(feature = "alloc")]
 mod owned_buf;
 
+#[cfg(feature = "alloc")]
+pub use self::checkpoint::Checkpoint;
+#[cfg(feature = "alloc")]
+mod checkpoint;
+
+#[cfg(feature = "alloc")]
+pub use self::interner::Interner;
+#[cfg(feature = "alloc")]
+mod interner;
+
 pub use self::slice_mut::SliceMut;
 mod slice_mut;
 
+pub use self::aligned_bytes::AlignedBytes;
+mod aligned_bytes;
+
+pub use self::archive_header::ArchiveHeader;
+pub(crate) mod archive_header;
+
+#[cfg(feature = "stats")]
+pub use self::stats::BufStats;
+#[cfg(feature = "stats")]
+mod stats;
+
 use core::mem::size_of;
 use core::ptr::NonNull;
 
@@ -146,6 +187,10 @@ pub fn max_capacity_for_align(align: usize) -> usize {
 /// wrapped in a [`Buf`] if it is already correctly aligned, or inside of an
 /// allocated [`OwnedBuf`].
 ///
+/// This means that no copy is performed for inputs which are already
+/// suitably aligned, like memory mapped files. A copy can be forced through
+/// [`Cow::into_owned`].
+///
 /// # Examples
 ///
 /// ```no_run
@@ -217,7 +262,7 @@ pub(crate) fn is_aligned_with(ptr: *const u8, align: usize) -> bool {
 
 /// Calculate padding with the assumption that alignment is a power of two.
 #[inline(always)]
-pub(crate) fn padding_to(len: usize, align: usize) -> usize {
+pub(crate) const fn padding_to(len: usize, align: usize) -> usize {
     let mask = align - 1;
     (align - (len & mask)) & mask
 }
@@ -6,21 +6,62 @@ use core::ops::{Deref, DerefMut};
 use core::ptr::NonNull;
 use core::slice::{self, SliceIndex};
 
+use ::alloc::vec::Vec;
 use alloc::alloc;
 
-use crate::buf::{self, Buf, DefaultAlignment, Padder, StoreBuf};
+#[cfg(feature = "stats")]
+use crate::buf::BufStats;
+use crate::buf::{self, ArchiveHeader, Buf, Checkpoint, DefaultAlignment, Padder, StoreBuf};
 use crate::endian::{ByteOrder, Native};
-use crate::error::Error;
-use crate::mem::MaybeUninit;
-use crate::pointer::{DefaultSize, Ref, Size};
+use crate::error::{Error, ErrorKind, IntoRepr};
+use crate::mem::{MaybeUninit, UninitSlice};
+use crate::pointer::{DefaultSize, Packable, Pointee, Ref, Size};
 use crate::traits::{UnsizedZeroCopy, ZeroCopy};
 
+/// Record a statistic if statistics are enabled for the buffer.
+#[cfg(feature = "stats")]
+macro_rules! record {
+    ($this:expr, $method:ident $(, $arg:expr)?) => {
+        if let Some(stats) = &mut $this.stats {
+            stats.$method($($arg)?);
+        }
+    };
+}
+
+/// Statistics are disabled, so recording them compiles away.
+#[cfg(not(feature = "stats"))]
+macro_rules! record {
+    ($this:expr, $method:ident $(, $arg:expr)?) => {};
+}
+
 /// An allocating buffer with dynamic alignment.
 ///
 /// By default this buffer starts out having the same alignment as `usize`,
 /// making it platform specific. But this alignment can grow in demand to the
 /// types being stored in it.
 ///
+/// # Padding
+///
+/// All padding written by the buffer is zeroed. This includes padding inside
+/// of stored values as determined by [`ZeroCopy::pad`], and padding inserted
+/// between values to align them. Storing the same values in the same order
+/// therefore always produces identical bytes, regardless of what was
+/// previously in memory.
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Padded { a: u8, b: u32 }
+///
+/// let mut buf = OwnedBuf::new();
+/// buf.store(&1u8);
+/// buf.store(&Padded { a: 2, b: 3 });
+///
+/// assert_eq!(&buf[..8], &[1, 0, 0, 0, 2, 0, 0, 0]);
+/// ```
+///
 /// # Examples
 ///
 /// ```
@@ -43,6 +84,9 @@ pub struct OwnedBuf<E: ByteOrder = Native, O: Size = DefaultSize> {
     requested: usize,
     /// The current alignment.
     align: usize,
+    /// Statistics collected by the buffer, if enabled.
+    #[cfg(feature = "stats")]
+    stats: Option<BufStats>,
     /// Holding onto the current pointer size.
     _marker: PhantomData<(E, O)>,
 }
@@ -120,15 +164,44 @@ impl OwnedBuf {
     /// assert_eq!(buf.requested(), 8);
     /// ```
     pub const fn with_alignment<T>() -> Self {
-        let align = align_of::<T>();
+        Self::with_alignment_raw(align_of::<T>())
+    }
+
+    /// Construct a new empty buffer with the alignment `align` which is only
+    /// known at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified alignment is not a power of two.
+    ///
+    /// ```should_panic
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// OwnedBuf::with_alignment_raw(3);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let buf = OwnedBuf::with_alignment_raw(16);
+    /// assert!(buf.is_empty());
+    /// assert!(buf.alignment() >= 16);
+    /// assert_eq!(buf.requested(), 16);
+    /// ```
+    pub const fn with_alignment_raw(align: usize) -> Self {
+        assert!(align.is_power_of_two(), "Alignment is not a power of two");
 
         Self {
-            // SAFETY: Alignment is asserted through `T`.
+            // SAFETY: Alignment is asserted to be a power of two above.
             data: unsafe { dangling(align) },
             len: 0,
             capacity: 0,
             requested: align,
             align,
+            #[cfg(feature = "stats")]
+            stats: None,
             _marker: PhantomData,
         }
     }
@@ -164,8 +237,43 @@ impl OwnedBuf {
     /// assert!(buf.alignment() >= 2);
     /// ```
     pub fn with_capacity_and_alignment<T>(capacity: usize) -> Self {
-        // SAFETY: Alignment of `T` is always a power of two.
-        unsafe { Self::with_capacity_and_custom_alignment(capacity, align_of::<T>()) }
+        Self::with_capacity_and_alignment_raw(capacity, align_of::<T>())
+    }
+
+    /// Allocate a new buffer with the given `capacity` and the alignment
+    /// `align` which is only known at runtime.
+    ///
+    /// The buffer must allocate for at least the given `capacity`, but might
+    /// allocate more. If the capacity specified is `0` it will not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified capacity and memory layout are illegal, which
+    /// happens if:
+    /// * The alignment is not a power of two.
+    /// * The specified capacity causes the needed memory to overflow
+    ///   `isize::MAX`.
+    ///
+    /// ```should_panic
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// OwnedBuf::with_capacity_and_alignment_raw(16, 12);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let buf = OwnedBuf::with_capacity_and_alignment_raw(6, 32);
+    /// assert!(buf.capacity() >= 6);
+    /// assert!(buf.alignment() >= 32);
+    /// assert_eq!(buf.requested(), 32);
+    /// ```
+    pub fn with_capacity_and_alignment_raw(capacity: usize, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "Alignment is not a power of two");
+        // SAFETY: Alignment is asserted to be a power of two above.
+        unsafe { Self::with_capacity_and_custom_alignment(capacity, align) }
     }
 }
 
@@ -191,6 +299,8 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
             capacity: this.capacity,
             requested: this.requested,
             align: this.align,
+            #[cfg(feature = "stats")]
+            stats: this.stats,
             _marker: PhantomData,
         }
     }
@@ -216,10 +326,57 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
             capacity: this.capacity,
             requested: this.requested,
             align: this.align,
+            #[cfg(feature = "stats")]
+            stats: this.stats,
             _marker: PhantomData,
         }
     }
 
+    /// Enable collecting [`BufStats`] for the buffer, such as how many bytes
+    /// of padding have been inserted to align values.
+    ///
+    /// This requires the `stats` feature to be enabled. When the feature is
+    /// not enabled, buffers carry no statistics and recording them has no
+    /// cost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let buf = OwnedBuf::new();
+    /// assert!(buf.stats().is_none());
+    ///
+    /// let mut buf = buf.with_stats();
+    /// buf.store(&1u8);
+    /// buf.store(&1u64);
+    ///
+    /// let stats = buf.stats().expect("stats are enabled");
+    /// assert_eq!(stats.sized_stores(), 2);
+    /// assert_eq!(stats.padding_bytes(), 7);
+    /// ```
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn with_stats(mut self) -> Self {
+        if self.stats.is_none() {
+            self.stats = Some(BufStats::default());
+        }
+
+        self
+    }
+
+    /// Access the statistics collected by the buffer, or `None` if they have
+    /// not been enabled through [`with_stats()`].
+    ///
+    /// This requires the `stats` feature to be enabled.
+    ///
+    /// [`with_stats()`]: Self::with_stats
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn stats(&self) -> Option<&BufStats> {
+        self.stats.as_ref()
+    }
+
     // # Safety
     //
     // The specified alignment must be a power of two.
@@ -233,6 +390,8 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
                 capacity: 0,
                 requested: align,
                 align,
+                #[cfg(feature = "stats")]
+                stats: None,
                 _marker: PhantomData,
             };
         }
@@ -252,6 +411,8 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
                 capacity,
                 requested: align,
                 align,
+                #[cfg(feature = "stats")]
+                stats: None,
                 _marker: PhantomData,
             }
         }
@@ -295,6 +456,89 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
         self.len = 0;
     }
 
+    /// Shorten the buffer to the given length `len`, discarding everything
+    /// which has been stored past it.
+    ///
+    /// This won't cause any reallocations.
+    ///
+    /// Any references which point to data past the truncation point are
+    /// effectively dangling. Since loads are validated this is not unsafe, but
+    /// loading them will either error or produce unrelated data if the buffer
+    /// is written to again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is larger than the current length of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let first = buf.store(&1u32);
+    /// let len = buf.len();
+    /// let second = buf.store(&2u32);
+    ///
+    /// buf.truncate(len);
+    ///
+    /// assert_eq!(buf.len(), 4);
+    /// assert_eq!(buf.load(first)?, &1);
+    /// assert!(buf.load(second).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        assert!(
+            len <= self.len,
+            "Truncated length {len} is larger than the current length {}",
+            self.len
+        );
+
+        self.len = len;
+    }
+
+    /// Construct a [`Checkpoint`] of the current buffer, which will restore
+    /// the buffer to its current length when dropped unless it has been
+    /// committed.
+    ///
+    /// This is useful when speculatively storing data which might have to be
+    /// discarded, without having to clone the buffer up front.
+    ///
+    /// Like with [`truncate()`], any references handed out past the
+    /// checkpoint will be dangling once it's been restored.
+    ///
+    /// [`truncate()`]: Self::truncate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.store(&1u32);
+    ///
+    /// {
+    ///     let mut checkpoint = buf.checkpoint();
+    ///     checkpoint.store(&2u32);
+    ///     assert_eq!(checkpoint.len(), 8);
+    /// }
+    ///
+    /// assert_eq!(buf.len(), 4);
+    ///
+    /// let mut checkpoint = buf.checkpoint();
+    /// let third = checkpoint.store(&3u32);
+    /// checkpoint.commit();
+    ///
+    /// assert_eq!(buf.len(), 8);
+    /// assert_eq!(buf.load(third)?, &3);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn checkpoint(&mut self) -> Checkpoint<'_, E, O> {
+        Checkpoint::new(self)
+    }
+
     /// Test if the buffer is empty.
     ///
     /// # Examples
@@ -557,6 +801,126 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
         unsafe { &mut *(self.data.as_ptr().add(at) as *mut MaybeUninit<T>) }
     }
 
+    /// Store a slice of `len` elements which might or might not have been
+    /// initialized.
+    ///
+    /// Like [`store_uninit()`], the memory of the slice is zeroed, and its
+    /// elements can be written in any order through
+    /// [`load_uninit_slice_mut()`]. Once every element has been written,
+    /// [`UninitSlice::assume_init`] produces a reference to the slice.
+    ///
+    /// [`store_uninit()`]: Self::store_uninit()
+    /// [`load_uninit_slice_mut()`]: Self::load_uninit_slice_mut()
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let slice = buf.store_uninit_slice::<u32>(3);
+    ///
+    /// let elements = buf.load_uninit_slice_mut(slice);
+    ///
+    /// for (n, element) in elements.iter_mut().enumerate().rev() {
+    ///     element.write(&(n as u32 * 10));
+    /// }
+    ///
+    /// let slice = slice.assume_init();
+    /// assert_eq!(buf.load(slice)?, &[0, 10, 20]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn store_uninit_slice<T>(&mut self, len: usize) -> UninitSlice<T, E, O>
+    where
+        T: ZeroCopy,
+    {
+        let Some(size) = size_of::<T>().checked_mul(len) else {
+            panic!("Slice length {len} overflows");
+        };
+
+        // SAFETY: We've just reserved capacity for this write.
+        unsafe {
+            self.next_offset_with_and_reserve(align_of::<T>(), size);
+            let offset = self.len;
+            self.data.as_ptr().add(self.len).write_bytes(0, size);
+            self.len += size;
+            UninitSlice::new(Ref::with_metadata(offset, len))
+        }
+    }
+
+    /// Access the elements of a slice that might not have been initialized.
+    ///
+    /// Each element can be written any number of times before the slice is
+    /// initialized with [`UninitSlice::assume_init`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice does not fit within the [`len()`] of the current
+    /// structure. This might happen if you try and use a slice constructed
+    /// from a different [`OwnedBuf`] instance.
+    ///
+    /// [`len()`]: Self::len()
+    ///
+    /// ```should_panic
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf1 = OwnedBuf::new();
+    /// buf1.store(&1u32);
+    ///
+    /// let mut buf2 = OwnedBuf::new();
+    /// buf2.store(&10u32);
+    ///
+    /// let slice = buf2.store_uninit_slice::<u32>(2);
+    ///
+    /// buf1.load_uninit_slice_mut(slice);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let slice = buf.store_uninit_slice::<u64>(2);
+    ///
+    /// buf.load_uninit_slice_mut(slice)[1].write(&2);
+    /// buf.load_uninit_slice_mut(slice)[0].write(&1);
+    /// buf.load_uninit_slice_mut(slice)[1].write(&3);
+    ///
+    /// let slice = slice.assume_init();
+    /// assert_eq!(buf.load(slice)?, &[1, 3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn load_uninit_slice_mut<T, U: ByteOrder, I: Size>(
+        &mut self,
+        uninit: UninitSlice<T, U, I>,
+    ) -> &mut [MaybeUninit<T>]
+    where
+        T: ZeroCopy,
+    {
+        let at = uninit.offset();
+        let len = uninit.len();
+
+        let end = size_of::<T>()
+            .checked_mul(len)
+            .and_then(|size| at.checked_add(size));
+
+        assert!(
+            matches!(end, Some(end) if end <= self.len),
+            "Length overflow"
+        );
+
+        // SAFETY: `MaybeUninit<T>` has no representation requirements, is
+        // unaligned and has the same size as `T`.
+        unsafe { slice::from_raw_parts_mut(self.data.as_ptr().add(at) as *mut MaybeUninit<T>, len) }
+    }
+
     /// Insert a value with the given size.
     ///
     /// The memory for `T` will be initialized at [`next_offset<T>()`] and the
@@ -686,6 +1050,7 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
     where
         T: ZeroCopy,
     {
+        record!(self, record_sized);
         let offset = self.len;
         let ptr = NonNull::new_unchecked(self.data.as_ptr().add(offset));
         buf::store_unaligned(ptr, value);
@@ -693,6 +1058,44 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
         Ref::new(offset)
     }
 
+    /// Overwrite the value behind a reference which has previously been
+    /// stored in the buffer with `value`.
+    ///
+    /// This is an alias for [`Buf::store_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+    ///
+    /// #[derive(Debug, PartialEq, ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Header { count: u8, checksum: u32 }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let header = buf.store(&Header { count: 0, checksum: 0 });
+    /// let values = buf.store_slice(&[1u32, 2, 3]);
+    ///
+    /// let checksum = buf.load(values)?.iter().sum();
+    /// buf.replace(header, &Header { count: 3, checksum })?;
+    ///
+    /// assert_eq!(buf.load(header)?, &Header { count: 3, checksum: 6 });
+    /// assert_eq!(&buf[..8], &[3, 0, 0, 0, 6, 0, 0, 0]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn replace<T, U: ByteOrder, I: Size>(
+        &mut self,
+        reference: Ref<T, U, I>,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: ZeroCopy,
+    {
+        Buf::store_at(self, reference, value)
+    }
+
     /// Write a value to the buffer.
     ///
     /// ```
@@ -711,6 +1114,15 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
     /// ```
     #[inline]
     pub fn store_unsized<T>(&mut self, value: &T) -> Ref<T, E, O>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+    {
+        record!(self, record_unsized);
+        self.store_unsized_untracked(value)
+    }
+
+    #[inline]
+    fn store_unsized_untracked<T>(&mut self, value: &T) -> Ref<T, E, O>
     where
         T: ?Sized + UnsizedZeroCopy,
     {
@@ -764,7 +1176,241 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
     where
         T: ZeroCopy,
     {
-        self.store_unsized(values)
+        record!(self, record_slice);
+        self.store_unsized_untracked(values)
+    }
+
+    /// Store the bytes of a value implementing [`zerocopy::AsBytes`], aligned
+    /// according to the value.
+    ///
+    /// This allows for types deriving the traits from the [`zerocopy`] crate
+    /// to be stored in the buffer. The stored value can be loaded again using
+    /// [`Buf::load_from_bytes()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// buf.store(&1u8);
+    /// let bytes = buf.store_as_bytes(&0x01020304u32);
+    ///
+    /// assert_eq!(bytes.offset(), 4);
+    /// assert_eq!(buf.load(bytes)?, &0x01020304u32.to_ne_bytes()[..]);
+    /// assert_eq!(buf.load_from_bytes::<u32, _, _>(bytes)?, &0x01020304u32);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "zerocopy")]
+    pub fn store_as_bytes<T>(&mut self, value: &T) -> Ref<[u8], E, O>
+    where
+        T: ?Sized + zerocopy::AsBytes,
+    {
+        let align = core::mem::align_of_val(value);
+        self.next_offset_with_and_reserve(align, size_of_val(value));
+        self.store_unsized(value.as_bytes())
+    }
+
+    /// Finish the buffer by appending an [`ArchiveHeader`] pointing to the
+    /// given `root`.
+    ///
+    /// The header records `magic` and `version` which identifies the format,
+    /// together with the byte order and requested alignment of the buffer. It
+    /// is always stored at the very end of the buffer, where it can be
+    /// validated and used to recover `root` using
+    /// [`Buf::open_with_header()`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the offset or metadata of `root` doesn't fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::{DefaultSize, OwnedBuf, Ref, ZeroCopy};
+    ///
+    /// #[derive(ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Custom { field: u32, string: Ref<str> }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let string = buf.store_unsized("Hello World!");
+    /// let root = buf.store(&Custom { field: 42, string });
+    /// buf.finish_with_header(*b"EXAMPLE1", 1, root)?;
+    ///
+    /// let root = buf.open_with_header::<Custom, Native, DefaultSize>(*b"EXAMPLE1", 1)?;
+    /// let custom = buf.load(root)?;
+    /// assert_eq!(custom.field, 42);
+    /// assert_eq!(buf.load(custom.string)?, "Hello World!");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn finish_with_header<T>(
+        &mut self,
+        magic: [u8; 8],
+        version: u32,
+        root: Ref<T, E, O>,
+    ) -> Result<Ref<ArchiveHeader, E, O>, Error>
+    where
+        T: ?Sized + Pointee,
+    {
+        let offset = root.offset();
+
+        let Ok(root_offset) = u32::try_from(offset) else {
+            return Err(Error::new(ErrorKind::InvalidOffsetRange {
+                offset: offset.into_repr(),
+                max: u32::MAX.into_repr(),
+            }));
+        };
+
+        let metadata = T::Metadata::packed_as_usize::<E, O>(root.metadata());
+
+        let Ok(root_metadata) = u32::try_from(metadata) else {
+            return Err(Error::new(ErrorKind::InvalidMetadataRange {
+                metadata: metadata.into_repr(),
+                max: u32::MAX.into_repr(),
+            }));
+        };
+
+        let align = self.requested.max(align_of::<ArchiveHeader>());
+
+        let Ok(align) = u32::try_from(align) else {
+            return Err(Error::new(ErrorKind::InvalidOffsetRange {
+                offset: align.into_repr(),
+                max: u32::MAX.into_repr(),
+            }));
+        };
+
+        let header = ArchiveHeader::new::<E>(magic, version, align, root_offset, root_metadata);
+        Ok(self.store(&header))
+    }
+
+    /// Store a collection of strings, followed by a slice of references to
+    /// each stored string.
+    ///
+    /// This is a convenience over storing each string with
+    /// [`store_unsized()`], collecting the references and storing them with
+    /// [`store_slice()`]. The slice of references is correctly aligned
+    /// regardless of the length of the strings preceding it.
+    ///
+    /// [`store_unsized()`]: Self::store_unsized
+    /// [`store_slice()`]: Self::store_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let strings = buf.store_strings(["first", "second", "third"]);
+    /// let empty = buf.store_strings(Vec::<String>::new());
+    ///
+    /// assert_eq!(strings.len(), 3);
+    /// assert!(empty.is_empty());
+    ///
+    /// let mut out = Vec::new();
+    ///
+    /// for string in strings.iter() {
+    ///     out.push(buf.load(*buf.load(string)?)?);
+    /// }
+    ///
+    /// assert_eq!(out, ["first", "second", "third"]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn store_strings<I>(&mut self, iter: I) -> Ref<[Ref<str, E, O>], E, O>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.store_unsized_refs(iter)
+    }
+
+    /// Store a collection of byte strings, followed by a slice of references
+    /// to each stored byte string.
+    ///
+    /// This is the byte string equivalent of [`store_strings()`].
+    ///
+    /// [`store_strings()`]: Self::store_strings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let bytes = buf.store_byte_strings([&b"first"[..], &b"second"[..]]);
+    /// assert_eq!(bytes.len(), 2);
+    ///
+    /// let second = bytes.get(1).expect("Missing element 1");
+    /// assert_eq!(buf.load(*buf.load(second)?)?, b"second");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn store_byte_strings<I>(&mut self, iter: I) -> Ref<[Ref<[u8], E, O>], E, O>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        self.store_unsized_refs(iter)
+    }
+
+    /// Store a collection of slices, followed by a slice of references to each
+    /// stored slice.
+    ///
+    /// This is useful for storing jagged arrays, like a `Vec<Vec<T>>`. Every
+    /// inner slice is stored first, after which the slice of references is
+    /// stored and correctly aligned regardless of what precedes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let tokens = vec![vec![1u32, 2, 3], vec![], vec![4]];
+    /// let nested = buf.store_nested_slices(&tokens);
+    ///
+    /// assert_eq!(nested.len(), 3);
+    ///
+    /// let mut out = Vec::new();
+    ///
+    /// for slice in buf.load(nested)? {
+    ///     out.push(buf.load(*slice)?.to_vec());
+    /// }
+    ///
+    /// assert_eq!(out, tokens);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn store_nested_slices<T, I>(&mut self, iter: I) -> Ref<[Ref<[T], E, O>], E, O>
+    where
+        T: ZeroCopy,
+        I: IntoIterator,
+        I::Item: AsRef<[T]>,
+    {
+        self.store_unsized_refs(iter)
+    }
+
+    /// Store every unsized value in `iter` followed by a slice of references
+    /// to them.
+    fn store_unsized_refs<T, I>(&mut self, iter: I) -> Ref<[Ref<T, E, O>], E, O>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+        Ref<T, E, O>: ZeroCopy,
+        I: IntoIterator,
+        I::Item: AsRef<T>,
+    {
+        let refs = iter
+            .into_iter()
+            .map(|value| self.store_unsized(value.as_ref()))
+            .collect::<Vec<_>>();
+
+        self.store_slice(&refs)
     }
 
     /// Extend the buffer from a slice.
@@ -904,11 +1550,66 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
     pub fn align_in_place(&mut self) {
         // SAFETY: self.requested is guaranteed to be a power of two.
         if !buf::is_aligned_with(self.as_ptr(), self.requested) {
+            // Nothing has been allocated yet, so we only need to make sure
+            // that the dangling pointer is sufficiently aligned.
+            if self.capacity == 0 {
+                // SAFETY: self.requested is a non-zero power of two.
+                self.data = unsafe { dangling(self.requested) };
+                self.align = self.requested;
+                return;
+            }
+
             let (old_layout, new_layout) = self.layouts(self.capacity);
             self.alloc_new(old_layout, new_layout);
         }
     }
 
+    /// Raise the requested alignment of the buffer to at least `align` and
+    /// ensure that the underlying allocation is aligned in-place.
+    ///
+    /// This is the same as [`align_in_place()`] after the alignment has been
+    /// requested, so it is a no-op if the current allocation already satisfies
+    /// the requested alignment. In contrast to [`request_align()`] this does
+    /// not pad the buffer.
+    ///
+    /// [`align_in_place()`]: Self::align_in_place
+    /// [`request_align()`]: Self::request_align
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified alignment is not a power of two.
+    ///
+    /// ```should_panic
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.align_in_place_with(3);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// fn build(buf: &mut OwnedBuf) {
+    ///     buf.extend_from_slice(&[1, 2, 3]);
+    ///     buf.align_in_place_with(64);
+    /// }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// build(&mut buf);
+    ///
+    /// assert_eq!(buf.requested(), 64);
+    /// assert!(buf.is_aligned_with(64));
+    /// assert_eq!(buf.as_slice(), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn align_in_place_with(&mut self, align: usize) {
+        assert!(align.is_power_of_two(), "Alignment is not a power of two");
+        self.requested = self.requested.max(align);
+        self.align_in_place();
+    }
+
     /// Request that the current buffer should have at least the specified
     /// alignment and zero-initialize the buffer up to the next position which
     /// matches the given alignment.
@@ -973,12 +1674,61 @@ impl<E: ByteOrder, O: Size> OwnedBuf<E, O> {
         self.ensure_aligned_and_reserve(align_of::<T>(), size_of::<T>());
     }
 
+    /// Request that the current buffer should have at least the alignment
+    /// `align` which is only known at runtime, and zero-initialize the buffer
+    /// up to the next position which matches it.
+    ///
+    /// This is the runtime equivalent of [`request_align()`], with the
+    /// difference that the underlying buffer is immediately reallocated if the
+    /// current allocation doesn't satisfy the requested alignment.
+    ///
+    /// [`request_align()`]: Self::request_align
+    ///
+    /// # Panics
+    ///
+    /// Panics if the specified alignment is not a power of two.
+    ///
+    /// ```should_panic
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.request_alignment(24);
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.extend_from_slice(&[1, 2, 3]);
+    ///
+    /// let align = 32;
+    /// buf.request_alignment(align);
+    ///
+    /// assert_eq!(buf.requested(), 32);
+    /// assert!(buf.is_aligned_with(32));
+    /// assert_eq!(buf.len(), 32);
+    /// assert_eq!(&buf.as_slice()[..4], &[1, 2, 3, 0]);
+    /// ```
+    #[inline]
+    pub fn request_alignment(&mut self, align: usize) {
+        assert!(align.is_power_of_two(), "Alignment is not a power of two");
+        self.requested = self.requested.max(align);
+        self.ensure_aligned_and_reserve(align, 0);
+        self.align_in_place();
+    }
+
     /// Ensure that the current buffer is aligned under the assumption that it needs to be allocated.
     #[inline]
     fn ensure_aligned_and_reserve(&mut self, align: usize, reserve: usize) {
         let extra = buf::padding_to(self.len, align);
         self.reserve(extra + reserve);
 
+        if extra > 0 {
+            record!(self, record_padding, extra);
+        }
+
         // SAFETY: The length is ensures to be within the address space.
         unsafe {
             self.data.as_ptr().add(self.len).write_bytes(0, extra);
@@ -1230,6 +1980,10 @@ impl<E: ByteOrder, O: Size> Clone for OwnedBuf<E, O> {
             // Set requested to the same as original.
             new.requested = self.requested;
             new.len = self.len;
+            #[cfg(feature = "stats")]
+            {
+                new.stats = self.stats;
+            }
             ManuallyDrop::into_inner(new)
         }
     }
@@ -3,7 +3,7 @@ use core::mem::{align_of, size_of, size_of_val, transmute};
 use core::ptr::NonNull;
 
 use crate::buf;
-use crate::traits::ZeroCopy;
+use crate::traits::{UnsizedZeroCopy, ZeroCopy};
 
 /// A struct padder as provided to the [`ZeroCopy::pad`] method.
 ///
@@ -95,6 +95,37 @@ impl<'a, T: ?Sized> Padder<'a, T> {
         self.offset += size_of::<F>();
     }
 
+    /// Pad around the trailing unsized field `value` with zeros.
+    ///
+    /// This is typically not called directly, but rather is implemented by the
+    /// [`UnsizedZeroCopy`] derive.
+    ///
+    /// [`UnsizedZeroCopy`]: derive@crate::UnsizedZeroCopy
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is the last field in the struct
+    /// being padded, and that every field before it has been padded in order.
+    #[inline]
+    pub unsafe fn pad_unsized<F>(&mut self, value: &F)
+    where
+        F: ?Sized + UnsizedZeroCopy,
+    {
+        let count = buf::padding_to(self.offset, F::ALIGN);
+        // zero out padding.
+        self.data.as_ptr().add(self.offset).write_bytes(0, count);
+        self.offset += count;
+
+        if F::PADDED {
+            let ptr = NonNull::new_unchecked(self.data.as_ptr().add(self.offset));
+            let mut padder = Padder::new(ptr);
+            value.pad(&mut padder);
+            padder.remaining_unsized(value);
+        }
+
+        self.offset += size_of_val(value);
+    }
+
     /// Specific method to both pad for a discriminant and load it
     /// simultaneously for inspection.
     ///
@@ -10,7 +10,7 @@ use alloc::borrow::Cow;
 
 use crate::buf::{self, Buf, DefaultAlignment, Padder, StoreBuf};
 use crate::endian::{ByteOrder, Native};
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::mem::MaybeUninit;
 use crate::pointer::{DefaultSize, Ref, Size};
 use crate::traits::{UnsizedZeroCopy, ZeroCopy};
@@ -707,6 +707,197 @@ where
         self.store_unsized(values)
     }
 
+    /// Try to write a value to the buffer.
+    ///
+    /// In contrast to [`store()`], this returns an error instead of panicking
+    /// if the underlying slice doesn't have the capacity to store the value,
+    /// or if it isn't aligned in memory for `T`.
+    ///
+    /// [`store()`]: Self::store
+    ///
+    /// # Errors
+    ///
+    /// Errors if the underlying slice is too small or not aligned for `T`, or
+    /// if the offset of the value can't be represented by the [`Size`] of the
+    /// buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, SliceMut};
+    /// use musli_zerocopy::buf::AlignedBytes;
+    ///
+    /// let mut bytes = AlignedBytes::<8, u32>::new();
+    /// let mut buf = SliceMut::with_alignment::<u32>(&mut bytes);
+    ///
+    /// let first = buf.try_store(&1u32)?;
+    /// let second = buf.try_store(&2u32)?;
+    /// assert!(buf.try_store(&3u32).unwrap_err().is_capacity());
+    ///
+    /// let buf = Buf::new(buf.as_slice());
+    /// assert_eq!(buf.load(first)?, &1);
+    /// assert_eq!(buf.load(second)?, &2);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn try_store<T>(&mut self, value: &T) -> Result<Ref<T, E, O>, Error>
+    where
+        T: ZeroCopy,
+    {
+        let offset = self.try_next_offset_with_and_reserve(align_of::<T>(), size_of::<T>())?;
+        let reference = Ref::try_with_metadata(offset, ())?;
+
+        // SAFETY: We've ensured that there is capacity for the value above.
+        unsafe {
+            let ptr = NonNull::new_unchecked(self.data.as_ptr().add(offset));
+            buf::store_unaligned(ptr, value);
+        }
+
+        self.len += size_of::<T>();
+        Ok(reference)
+    }
+
+    /// Try to write an unsized value to the buffer.
+    ///
+    /// In contrast to [`store_unsized()`], this returns an error instead of
+    /// panicking if the underlying slice doesn't have the capacity to store
+    /// the value, or if it isn't aligned in memory for `T`.
+    ///
+    /// [`store_unsized()`]: Self::store_unsized
+    ///
+    /// # Errors
+    ///
+    /// Errors if the underlying slice is too small or not aligned for `T`, or
+    /// if the offset or metadata of the value can't be represented by the
+    /// [`Size`] of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, SliceMut};
+    ///
+    /// let mut bytes = [0; 8];
+    /// let mut buf = SliceMut::with_alignment::<()>(&mut bytes);
+    ///
+    /// let hello = buf.try_store_unsized("hello")?;
+    /// assert!(buf.try_store_unsized("world").unwrap_err().is_capacity());
+    ///
+    /// let buf = Buf::new(buf.as_slice());
+    /// assert_eq!(buf.load(hello)?, "hello");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn try_store_unsized<T>(&mut self, value: &T) -> Result<Ref<T, E, O>, Error>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+    {
+        let size = size_of_val(value);
+        let offset = self.try_next_offset_with_and_reserve(T::ALIGN, size)?;
+        let reference = Ref::try_with_metadata(offset, value.metadata())?;
+
+        // SAFETY: We've ensured that there is capacity for the value above.
+        unsafe {
+            let ptr = NonNull::new_unchecked(self.data.as_ptr().add(offset));
+            ptr.as_ptr().copy_from_nonoverlapping(value.as_ptr(), size);
+
+            if T::PADDED {
+                let mut padder = Padder::new(ptr);
+                value.pad(&mut padder);
+                padder.remaining_unsized(value);
+            }
+        }
+
+        self.len += size;
+        Ok(reference)
+    }
+
+    /// Try to insert a slice into the buffer.
+    ///
+    /// In contrast to [`store_slice()`], this returns an error instead of
+    /// panicking if the underlying slice doesn't have the capacity to store
+    /// the values, or if it isn't aligned in memory for `T`.
+    ///
+    /// [`store_slice()`]: Self::store_slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, SliceMut};
+    /// use musli_zerocopy::buf::AlignedBytes;
+    ///
+    /// let mut bytes = AlignedBytes::<16, u16>::new();
+    /// let mut buf = SliceMut::with_alignment::<u16>(&mut bytes);
+    ///
+    /// let values = buf.try_store_slice(&[1u16, 2, 3, 4])?;
+    /// assert!(buf.try_store_slice(&[5u16, 6, 7, 8, 9]).unwrap_err().is_capacity());
+    ///
+    /// let buf = Buf::new(buf.as_slice());
+    /// assert_eq!(buf.load(values)?, &[1, 2, 3, 4]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline(always)]
+    pub fn try_store_slice<T>(&mut self, values: &[T]) -> Result<Ref<[T], E, O>, Error>
+    where
+        T: ZeroCopy,
+    {
+        self.try_store_unsized(values)
+    }
+
+    /// Try to construct a reference to a value that might not have been
+    /// initialized yet.
+    ///
+    /// In contrast to [`store_uninit()`], this returns an error instead of
+    /// panicking if the underlying slice doesn't have the capacity to store
+    /// the value, or if it isn't aligned in memory for `T`.
+    ///
+    /// [`store_uninit()`]: Self::store_uninit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, SliceMut, Ref, ZeroCopy};
+    /// use musli_zerocopy::buf::AlignedBytes;
+    ///
+    /// #[derive(ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Custom { field: u32, string: Ref<str> }
+    ///
+    /// let mut bytes = AlignedBytes::<32>::new();
+    /// let mut buf = SliceMut::new(&mut bytes);
+    ///
+    /// let reference = buf.try_store_uninit::<Custom>()?;
+    /// let string = buf.try_store_unsized("Hello!")?;
+    ///
+    /// buf.load_uninit_mut(reference).write(&Custom { field: 42, string });
+    ///
+    /// let reference = reference.assume_init();
+    /// let buf = Buf::new(buf.as_slice());
+    ///
+    /// let custom = buf.load(reference)?;
+    /// assert_eq!(custom.field, 42);
+    /// assert_eq!(buf.load(custom.string)?, "Hello!");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn try_store_uninit<T>(&mut self) -> Result<Ref<MaybeUninit<T>, E, O>, Error>
+    where
+        T: ZeroCopy,
+    {
+        let offset = self.try_next_offset_with_and_reserve(align_of::<T>(), size_of::<T>())?;
+        let reference = Ref::try_with_metadata(offset, ())?;
+
+        // SAFETY: We've ensured that there is capacity for the value above.
+        unsafe {
+            self.data
+                .as_ptr()
+                .add(offset)
+                .write_bytes(0, size_of::<T>());
+        }
+
+        self.len += size_of::<T>();
+        Ok(reference)
+    }
+
     /// Extend the buffer from a slice.
     ///
     /// Note that this only extends the underlying buffer but does not ensure
@@ -879,6 +1070,46 @@ where
         self.len
     }
 
+    /// Like [`next_offset_with_and_reserve()`], but errors instead of
+    /// panicking if the underlying slice is not aligned by `align` or doesn't
+    /// have the capacity to reserve `reserve` bytes.
+    ///
+    /// Returns the offset at which the value should be written.
+    ///
+    /// [`next_offset_with_and_reserve()`]: Self::next_offset_with_and_reserve
+    fn try_next_offset_with_and_reserve(
+        &mut self,
+        align: usize,
+        reserve: usize,
+    ) -> Result<usize, Error> {
+        if !buf::is_aligned_with(self.as_ptr(), align) {
+            return Err(Error::new(ErrorKind::UnalignedStorage {
+                addr: self.as_ptr() as usize,
+                align,
+            }));
+        }
+
+        let requested = self.requested.max(align);
+
+        let needed = self
+            .len
+            .checked_add(buf::padding_to(self.len, align))
+            .and_then(|len| len.checked_add(reserve));
+
+        match needed {
+            Some(needed) if needed.max(requested) <= self.capacity => {}
+            needed => {
+                return Err(Error::new(ErrorKind::InsufficientCapacity {
+                    capacity: self.capacity,
+                    needed: needed.map_or(usize::MAX, |needed| needed.max(requested)),
+                }));
+            }
+        }
+
+        self.next_offset_with_and_reserve(align, reserve);
+        Ok(self.len)
+    }
+
     // Ensure that the new capacity is available or panic.
     #[inline]
     fn ensure_capacity(&mut self, new_capacity: usize) {
@@ -0,0 +1,56 @@
+//! Sorting with comparisons that can fail.
+
+use core::cmp::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::error::Error;
+
+/// Stable sort of `entries` using a comparison function which might fail.
+///
+/// Unlike [`slice::sort_by`] the first error produced by `compare` aborts the
+/// sort and is returned, in which case `entries` is left untouched.
+pub(crate) fn try_sort_by<T, F>(entries: &mut Vec<T>, mut compare: F) -> Result<(), Error>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, Error>,
+{
+    let len = entries.len();
+
+    let mut order = (0..len).collect::<Vec<_>>();
+    let mut scratch = Vec::with_capacity(len);
+    let mut width = 1;
+
+    while width < len {
+        scratch.clear();
+        let mut start = 0;
+
+        while start < len {
+            let mid = start.saturating_add(width).min(len);
+            let end = mid.saturating_add(width).min(len);
+            let (mut a, mut b) = (start, mid);
+
+            while a < mid && b < end {
+                // Only take from the right run if it is strictly less, which
+                // keeps the sort stable.
+                if compare(&entries[order[b]], &entries[order[a]])?.is_lt() {
+                    scratch.push(order[b]);
+                    b += 1;
+                } else {
+                    scratch.push(order[a]);
+                    a += 1;
+                }
+            }
+
+            scratch.extend_from_slice(&order[a..mid]);
+            scratch.extend_from_slice(&order[b..end]);
+            start = end;
+        }
+
+        core::mem::swap(&mut order, &mut scratch);
+        width = width.saturating_mul(2);
+    }
+
+    let mut slots = entries.drain(..).map(Some).collect::<Vec<_>>();
+    entries.extend(order.into_iter().filter_map(|index| slots[index].take()));
+    Ok(())
+}
@@ -0,0 +1,106 @@
+/// Statistics collected by an [`OwnedBuf`] which has been constructed with
+/// [`OwnedBuf::with_stats()`].
+///
+/// This requires the `stats` feature to be enabled.
+///
+/// Statistics are accumulated over the lifetime of the buffer, so they are not
+/// reset by operations such as [`OwnedBuf::clear()`] or
+/// [`OwnedBuf::truncate()`].
+///
+/// [`OwnedBuf`]: crate::buf::OwnedBuf
+/// [`OwnedBuf::with_stats()`]: crate::buf::OwnedBuf::with_stats
+/// [`OwnedBuf::clear()`]: crate::buf::OwnedBuf::clear
+/// [`OwnedBuf::truncate()`]: crate::buf::OwnedBuf::truncate
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+///
+/// let mut buf = OwnedBuf::new().with_stats();
+///
+/// buf.store(&1u8);
+/// buf.store(&2u32);
+/// buf.store_unsized("hello");
+/// buf.store_slice(&[1u64, 2, 3]);
+///
+/// let stats = buf.stats().expect("stats are enabled");
+///
+/// assert_eq!(stats.sized_stores(), 2);
+/// assert_eq!(stats.unsized_stores(), 1);
+/// assert_eq!(stats.slice_stores(), 1);
+/// assert_eq!(stats.padding_bytes(), 3 + 3);
+/// assert_eq!(stats.largest_padding(), 3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BufStats {
+    padding_bytes: usize,
+    largest_padding: usize,
+    sized_stores: usize,
+    unsized_stores: usize,
+    slice_stores: usize,
+}
+
+impl BufStats {
+    /// The total number of padding bytes which have been inserted to align
+    /// values stored in the buffer.
+    #[inline]
+    pub fn padding_bytes(&self) -> usize {
+        self.padding_bytes
+    }
+
+    /// The largest number of padding bytes which were inserted to align a
+    /// single value.
+    #[inline]
+    pub fn largest_padding(&self) -> usize {
+        self.largest_padding
+    }
+
+    /// The number of sized values which have been stored, such as through
+    /// [`OwnedBuf::store()`].
+    ///
+    /// [`OwnedBuf::store()`]: crate::buf::OwnedBuf::store
+    #[inline]
+    pub fn sized_stores(&self) -> usize {
+        self.sized_stores
+    }
+
+    /// The number of unsized values which have been stored through
+    /// [`OwnedBuf::store_unsized()`].
+    ///
+    /// [`OwnedBuf::store_unsized()`]: crate::buf::OwnedBuf::store_unsized
+    #[inline]
+    pub fn unsized_stores(&self) -> usize {
+        self.unsized_stores
+    }
+
+    /// The number of slices which have been stored through
+    /// [`OwnedBuf::store_slice()`].
+    ///
+    /// [`OwnedBuf::store_slice()`]: crate::buf::OwnedBuf::store_slice
+    #[inline]
+    pub fn slice_stores(&self) -> usize {
+        self.slice_stores
+    }
+
+    #[inline]
+    pub(crate) fn record_padding(&mut self, padding: usize) {
+        self.padding_bytes += padding;
+        self.largest_padding = self.largest_padding.max(padding);
+    }
+
+    #[inline]
+    pub(crate) fn record_sized(&mut self) {
+        self.sized_stores += 1;
+    }
+
+    #[inline]
+    pub(crate) fn record_unsized(&mut self) {
+        self.unsized_stores += 1;
+    }
+
+    #[inline]
+    pub(crate) fn record_slice(&mut self) {
+        self.slice_stores += 1;
+    }
+}
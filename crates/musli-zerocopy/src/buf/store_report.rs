@@ -0,0 +1,26 @@
+/// Statistics collected while constructing a map or set.
+///
+/// This is returned by [`phf::store_map_with_report`] and
+/// [`swiss::store_map_with_report`].
+///
+/// [`phf::store_map_with_report`]: crate::phf::store_map_with_report
+/// [`swiss::store_map_with_report`]: crate::swiss::store_map_with_report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StoreReport {
+    /// The number of entries stored.
+    pub entries: usize,
+    /// The number of buckets used, which for a perfect hash function is the
+    /// number of displacements and for a [SwissTable] the number of slots in
+    /// the table.
+    ///
+    /// [SwissTable]: https://abseil.io/about/design/swisstables
+    pub buckets: usize,
+    /// The number of hash keys which had to be discarded before a perfect
+    /// hash function was found. This is always zero for maps which are not
+    /// based on a perfect hash function.
+    pub retries: usize,
+    /// The total number of bytes written to the buffer, including any control
+    /// bytes and padding.
+    pub bytes: usize,
+}
@@ -4,6 +4,8 @@ use core::array;
 
 use anyhow::Result;
 
+use crate::endian::Native;
+use crate::error::{Error, ErrorKind};
 use crate::mem::MaybeUninit;
 use crate::{Ref, ZeroCopy};
 
@@ -188,3 +190,733 @@ fn test_packing() {
 
     const _: () = assert!(!Packed1::PADDED);
 }
+
+#[test]
+fn store_strings_long_and_unaligned() -> Result<()> {
+    let long = "a".repeat(1 << 16);
+    let strings = ["x", long.as_str(), "", "abc"];
+
+    let mut buf = OwnedBuf::new();
+    let refs = buf.store_strings(strings);
+
+    assert_eq!(refs.len(), strings.len());
+    assert_eq!(refs.offset() % core::mem::align_of::<Ref<str>>(), 0);
+
+    for (index, expected) in strings.iter().enumerate() {
+        let string = refs.get(index).expect("missing string");
+        assert_eq!(buf.load(*buf.load(string)?)?, *expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn store_nested_slices_with_empty() -> Result<()> {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    let mut buf = OwnedBuf::new();
+
+    let empty = buf.store_nested_slices(Vec::<Vec<u8>>::new());
+    assert!(empty.is_empty());
+
+    // Unaligned inner slices of bytes followed by wider elements.
+    let bytes = buf.store_nested_slices([&[1u8, 2, 3][..], &[][..], &[4][..]]);
+    let wide = buf.store_nested_slices([vec![u64::MAX], vec![], vec![1, 2]]);
+
+    assert_eq!(bytes.offset() % core::mem::align_of::<Ref<[u8]>>(), 0);
+    assert_eq!(wide.offset() % core::mem::align_of::<Ref<[u64]>>(), 0);
+
+    let load = |slice: Ref<[Ref<[u64]>]>| -> Result<Vec<Vec<u64>>> {
+        let mut out = Vec::new();
+
+        for slice in buf.load(slice)? {
+            out.push(buf.load(*slice)?.to_vec());
+        }
+
+        Ok(out)
+    };
+
+    assert_eq!(load(wide)?, [vec![u64::MAX], vec![], vec![1, 2]]);
+
+    let bytes = buf.load(bytes)?;
+    assert_eq!(buf.load(bytes[0])?, &[1, 2, 3]);
+    assert!(buf.load(bytes[1])?.is_empty());
+    assert_eq!(buf.load(bytes[2])?, &[4]);
+    Ok(())
+}
+
+#[test]
+fn substr_boundaries() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    buf.store_unsized("prefix");
+    let text = buf.store_unsized("a€b");
+
+    assert_eq!(buf.load(buf.substr(text, 1..4)?)?, "€");
+    assert_eq!(buf.load(buf.substr(text, 5..5)?)?, "");
+    assert_eq!(buf.load(buf.substr(text, 0..5)?)?, "a€b");
+
+    for range in [2..4, 1..3, 0..2] {
+        assert!(buf.substr(text, range).is_err_and(|e| e.is_utf8()));
+    }
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let error = buf.substr(text, 3..1).unwrap_err();
+    assert!(error.is_out_of_bounds());
+    assert!(buf.substr(text, 0..6).is_err_and(|e| e.is_out_of_bounds()));
+
+    // The offset of the substring doesn't fit in the offset type.
+    let end = Ref::<str, Native, u32>::with_metadata(u32::MAX - 1, 8);
+    assert!(buf.substr(end, 8..8).is_err());
+
+    let end = Ref::<str, Native, usize>::with_metadata(usize::MAX - 1, 8);
+    assert!(buf.substr(end, 8..8).is_err_and(|e| e.is_arithmetic()));
+    Ok(())
+}
+
+#[test]
+fn aligned_buf_borrows_when_aligned() -> Result<()> {
+    use alloc::borrow::Cow;
+
+    let mut owned = OwnedBuf::with_alignment::<u64>();
+    owned.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let bytes = owned.as_slice();
+
+    let aligned = super::aligned_buf::<u64>(bytes);
+    assert!(matches!(aligned, Cow::Borrowed(..)));
+    assert_eq!(aligned.as_ptr(), bytes.as_ptr());
+
+    let unaligned = super::aligned_buf::<u64>(&bytes[1..]);
+    assert!(matches!(unaligned, Cow::Owned(..)));
+    assert!(unaligned.is_aligned::<u64>());
+    assert_eq!(&unaligned[..], &bytes[1..]);
+
+    // Forcing a copy of an aligned buffer preserves its alignment.
+    let forced = super::aligned_buf::<u64>(bytes).into_owned();
+    assert_ne!(forced.as_ptr(), bytes.as_ptr());
+    assert!(forced.is_aligned::<u64>());
+    assert_eq!(forced.as_slice(), bytes);
+    Ok(())
+}
+
+#[test]
+fn interner_deduplicates() -> Result<()> {
+    let mut interner = super::Interner::new(OwnedBuf::new());
+
+    let first = interner.store("hello");
+    let len = interner.buf().len();
+    let second = interner.store("hello");
+
+    assert_eq!(first.offset(), second.offset());
+    assert_eq!(interner.buf().len(), len);
+
+    interner.clear();
+    let _ = interner.store("other");
+    let third = interner.store("hello");
+    assert_eq!(interner.buf().load(third)?, "hello");
+
+    interner.buf_mut().clear();
+    let fourth = interner.store("hello");
+    assert_eq!(interner.buf().load(fourth)?, "hello");
+    Ok(())
+}
+
+#[test]
+fn store_at_zeroes_padding() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    let a = buf.store(&Inner {
+        first: 1,
+        second: 2,
+    });
+    let slice = buf.store_slice(&[
+        Inner {
+            first: 3,
+            second: 4,
+        },
+        Inner {
+            first: 5,
+            second: 6,
+        },
+    ]);
+
+    // Dirty the padding of the stored values.
+    buf.as_mut_slice().fill(0xff);
+
+    buf.store_at(
+        a,
+        &Inner {
+            first: 10,
+            second: 20,
+        },
+    )?;
+
+    for (index, value) in [(0, 30), (1, 40)] {
+        let element = slice.get(index).expect("missing element");
+        buf.store_at(
+            element,
+            &Inner {
+                first: value,
+                second: value as u64,
+            },
+        )?;
+    }
+
+    buf.load_mut(slice)?[1].second = 50;
+
+    let mut expected = OwnedBuf::new();
+    expected.store(&Inner {
+        first: 10,
+        second: 20,
+    });
+    expected.store_slice(&[
+        Inner {
+            first: 30,
+            second: 30,
+        },
+        Inner {
+            first: 40,
+            second: 50,
+        },
+    ]);
+
+    assert_eq!(buf.as_slice(), expected.as_slice());
+    Ok(())
+}
+
+#[test]
+fn store_at_overflow() {
+    let mut buf = OwnedBuf::new();
+    buf.store(&0u64);
+
+    let ptr = Ref::<u64, Native, usize>::new(usize::MAX - 2);
+
+    assert_eq!(
+        buf.store_at(ptr, &42u64),
+        Err(Error::new(ErrorKind::Overflow {
+            at: usize::MAX - 2,
+            len: 8,
+        }))
+    );
+}
+
+#[test]
+fn store_uninit_slice_out_of_order() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    buf.store(&1u8);
+
+    let slice = buf.store_uninit_slice::<Inner>(3);
+    assert_eq!(slice.offset() % core::mem::align_of::<Inner>(), 0);
+    assert_eq!(slice.len(), 3);
+
+    for index in [2, 0, 1, 0] {
+        buf.load_uninit_slice_mut(slice)[index].write(&Inner {
+            first: index as u8,
+            second: index as u64 * 10,
+        });
+    }
+
+    let slice = slice.assume_init();
+
+    assert_eq!(
+        buf.load(slice)?,
+        &[
+            Inner {
+                first: 0,
+                second: 0,
+            },
+            Inner {
+                first: 1,
+                second: 10,
+            },
+            Inner {
+                first: 2,
+                second: 20,
+            },
+        ]
+    );
+
+    let empty = buf.store_uninit_slice::<Inner>(0);
+    assert!(empty.is_empty());
+    assert!(buf.load(empty.assume_init())?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn load_unaligned_round_trip() -> Result<()> {
+    use crate::Buf;
+
+    #[derive(Debug, PartialEq, ZeroCopy)]
+    #[zero_copy(crate)]
+    #[repr(C)]
+    struct Validated {
+        flag: bool,
+        value: u64,
+        character: char,
+    }
+
+    let mut source = OwnedBuf::new();
+    let value = source.store(&Validated {
+        flag: true,
+        value: 0x0102030405060708,
+        character: 'ä',
+    });
+    let bytes = &source.as_slice()[value.offset()..];
+
+    for misalign in 1..=8 {
+        let mut buf = OwnedBuf::with_alignment::<u64>();
+        buf.extend_from_slice(&[0xff; 8][..misalign]);
+        buf.extend_from_slice(bytes);
+
+        let reference = Ref::<Validated>::new(misalign);
+
+        if misalign % 8 != 0 {
+            assert!(buf.load(reference).is_err());
+        }
+
+        assert_eq!(
+            buf.load_unaligned(reference)?,
+            Validated {
+                flag: true,
+                value: 0x0102030405060708,
+                character: 'ä',
+            }
+        );
+
+        // An invalid bool is still caught by validation.
+        let mut broken = buf.as_slice().to_vec();
+        broken[misalign] = 2;
+        assert!(Buf::new(&broken).load_unaligned(reference).is_err());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn canonical_padding_with_dirty_memory() -> Result<()> {
+    use core::mem::MaybeUninit;
+    use core::ptr::addr_of_mut;
+
+    #[derive(ZeroCopy)]
+    #[zero_copy(crate)]
+    #[repr(C)]
+    struct Padded {
+        a: u8,
+        b: u32,
+        c: u16,
+    }
+
+    // Construct a value where every padding byte is dirty.
+    fn dirty(a: u8, b: u32, c: u16) -> Padded {
+        let mut value = MaybeUninit::<Padded>::uninit();
+
+        unsafe {
+            value.as_mut_ptr().write_bytes(0xff, 1);
+            let ptr = value.as_mut_ptr();
+            addr_of_mut!((*ptr).a).write(a);
+            addr_of_mut!((*ptr).b).write(b);
+            addr_of_mut!((*ptr).c).write(c);
+            value.assume_init()
+        }
+    }
+
+    fn build(buf: &mut OwnedBuf) {
+        buf.store(&1u8);
+        buf.store(&dirty(2, 3, 4));
+        buf.store(&5u8);
+        buf.store_slice(&[dirty(6, 7, 8), dirty(9, 10, 11)]);
+    }
+
+    let mut first = OwnedBuf::new();
+    build(&mut first);
+
+    // Dirty the scratch memory of the second buffer before reusing it.
+    let mut second = OwnedBuf::new();
+    second.fill(0xff, 256);
+    second.clear();
+    build(&mut second);
+
+    assert_eq!(first.as_slice(), second.as_slice());
+    assert_eq!(&first.as_slice()[..8], &[1, 0, 0, 0, 2, 0, 0, 0]);
+    Ok(())
+}
+
+#[test]
+fn runtime_alignment() -> Result<()> {
+    for align in [1, 2, 4, 8, 16, 32, 64, 128] {
+        let mut buf = OwnedBuf::with_capacity_and_alignment_raw(16, align);
+        assert_eq!(buf.requested(), align);
+        assert!(buf.is_aligned_with(align));
+
+        let number = buf.store(&42u32);
+        buf.request_alignment(align * 2);
+
+        assert_eq!(buf.requested(), (align * 2).max(4));
+        assert!(buf.is_aligned_with(align * 2));
+        assert_eq!(buf.len() % (align * 2), 0);
+        assert_eq!(buf.load(number)?, &42);
+    }
+
+    let buf = OwnedBuf::with_alignment_raw(64);
+    assert_eq!(buf.requested(), 64);
+    assert!(buf.is_aligned_with(64));
+    Ok(())
+}
+
+#[test]
+fn slice_mut_try_store() -> Result<()> {
+    use super::{AlignedBytes, Buf, SliceMut};
+
+    let mut bytes = AlignedBytes::<32, u64>::new();
+
+    {
+        let mut buf = SliceMut::with_alignment::<u64>(&mut bytes);
+
+        let inner = buf.try_store(&Inner {
+            first: 1,
+            second: 2,
+        })?;
+
+        let string = buf.try_store_unsized("hello")?;
+        let len = buf.len();
+
+        let error = buf.try_store(&Inner {
+            first: 3,
+            second: 4,
+        });
+
+        assert!(error.unwrap_err().is_capacity());
+        assert_eq!(buf.len(), len);
+
+        let buf = Buf::new(buf.as_slice());
+        assert_eq!(
+            buf.load(inner)?,
+            &Inner {
+                first: 1,
+                second: 2
+            }
+        );
+        assert_eq!(buf.load(string)?, "hello");
+    }
+
+    // A slice which is only aligned by 1 can't store a `u64`.
+    let mut buf = SliceMut::with_alignment::<()>(&mut bytes[1..]);
+    let error = buf.try_store(&1u64).unwrap_err();
+    assert!(error.is_alignment());
+    assert_eq!(error.expected_alignment(), Some(8));
+    assert!(buf.is_empty());
+    Ok(())
+}
+
+#[test]
+fn archive_header() -> Result<()> {
+    use core::mem::size_of;
+
+    use super::{ArchiveHeader, Buf};
+    use crate::endian::{Big, Little, Native};
+    use crate::DefaultSize;
+
+    let mut buf = OwnedBuf::with_alignment::<u64>();
+    let string = buf.store_unsized("Hello World!");
+    let root = buf.store(&Inner {
+        first: 1,
+        second: 2,
+    });
+    let header = buf.finish_with_header(*b"MUSLITST", 7, root)?;
+
+    assert_eq!(header.offset() + size_of::<ArchiveHeader>(), buf.len());
+
+    let header = *buf.load(header)?;
+    assert_eq!(header.magic(), *b"MUSLITST");
+    assert_eq!(header.version(), 7);
+    assert_eq!(header.alignment(), 8);
+    assert_eq!(header.root_metadata(), 0);
+    assert_eq!(
+        header.is_little_endian(),
+        Some(cfg!(target_endian = "little"))
+    );
+
+    let opened = buf.open_with_header::<Inner, Native, DefaultSize>(*b"MUSLITST", 7)?;
+    assert_eq!(opened, root);
+    assert_eq!(
+        buf.load(opened)?,
+        &Inner {
+            first: 1,
+            second: 2
+        }
+    );
+    assert_eq!(buf.load(string)?, "Hello World!");
+
+    let error = buf
+        .open_with_header::<Inner, Native, DefaultSize>(*b"MUSLITSX", 7)
+        .unwrap_err();
+    assert!(error.is_magic_mismatch());
+
+    let error = buf
+        .open_with_header::<Inner, Native, DefaultSize>(*b"MUSLITST", 8)
+        .unwrap_err();
+    assert!(error.is_version_mismatch());
+    assert!(!error.is_byte_order_mismatch());
+
+    let mut big = OwnedBuf::new().with_byte_order::<Big>();
+    let root = big.store_slice(&[1u32, 2, 3]);
+    big.finish_with_header(*b"MUSLITST", 7, root)?;
+
+    let error = big
+        .open_with_header::<[u32], Little, DefaultSize>(*b"MUSLITST", 7)
+        .unwrap_err();
+    assert!(error.is_byte_order_mismatch());
+    assert!(!error.is_version_mismatch());
+
+    let opened = big.open_with_header::<[u32], Big, DefaultSize>(*b"MUSLITST", 7)?;
+    assert_eq!(opened, root);
+    assert_eq!(opened.len(), 3);
+
+    // Misalign the archive by copying it into an offset buffer.
+    let mut unaligned = OwnedBuf::with_alignment::<u64>();
+    unaligned.store(&0u8);
+    unaligned.extend_from_slice(buf.as_slice());
+    let misaligned = Buf::new(&unaligned.as_slice()[1..]);
+
+    let error = misaligned
+        .open_with_header::<Inner, Native, DefaultSize>(*b"MUSLITST", 7)
+        .unwrap_err();
+    assert!(error.is_alignment());
+    assert_eq!(error.expected_alignment(), Some(8));
+
+    let error = Buf::new(&[0; 4])
+        .open_with_header::<Inner, Native, DefaultSize>(*b"MUSLITST", 7)
+        .unwrap_err();
+    assert!(error.is_out_of_bounds());
+    Ok(())
+}
+
+#[test]
+fn load_array() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let slice = buf.store_slice(&[1u64, 2, 3, 4]);
+    let empty = buf.store_slice::<u64>(&[]);
+
+    assert_eq!(buf.load_array(slice)?, &[1u64, 2, 3, 4]);
+    assert_eq!(buf.load_array(empty)?, &[0u64; 0]);
+
+    let [a, b, c, d] = buf.load_array(slice)?;
+    assert_eq!([a, b, c, d], [&1, &2, &3, &4]);
+
+    let array: Result<&[u64; 3], Error> = buf.load_array(slice);
+    assert!(array.is_err());
+    let array: Result<&[u64; 5], Error> = buf.load_array(slice);
+    assert!(array.is_err());
+    let array: Result<&[u64; 1], Error> = buf.load_array(empty);
+    assert!(array.is_err());
+
+    // Out of bounds slices are still rejected if the length matches.
+    let out_of_bounds = Ref::<[u64]>::with_metadata(slice.offset() + 8, 4);
+    let array: Result<&[u64; 4], Error> = buf.load_array(out_of_bounds);
+    assert!(array.unwrap_err().is_out_of_bounds());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "stats")]
+fn buf_stats() -> Result<()> {
+    let mut buf = OwnedBuf::new().with_stats();
+
+    buf.store(&1u8);
+    buf.store(&Inner {
+        first: 2,
+        second: 3,
+    });
+    buf.store_unsized("abc");
+    buf.store_slice(&[1u32, 2]);
+
+    let stats = *buf.stats().expect("stats are enabled");
+    assert_eq!(stats.sized_stores(), 2);
+    assert_eq!(stats.unsized_stores(), 1);
+    assert_eq!(stats.slice_stores(), 1);
+    assert_eq!(stats.padding_bytes(), 7 + 1);
+    assert_eq!(stats.largest_padding(), 7);
+
+    // Statistics are carried over when changing the buffer parameters, and
+    // are not reset by truncation.
+    let mut buf = buf.with_size::<u16>();
+    buf.truncate(1);
+    buf.store(&1u32);
+
+    let stats = buf.stats().expect("stats are enabled");
+    assert_eq!(stats.sized_stores(), 3);
+    assert_eq!(stats.padding_bytes(), 7 + 1 + 3);
+    assert_eq!(stats.largest_padding(), 7);
+
+    let buf = OwnedBuf::new();
+    assert!(buf.stats().is_none());
+    Ok(())
+}
+
+#[test]
+fn c_str() -> Result<()> {
+    use core::ffi::CStr;
+
+    use alloc::string::ToString;
+
+    let mut buf = OwnedBuf::new();
+
+    let hello = CStr::from_bytes_with_nul(b"hello\0")?;
+    let empty = CStr::from_bytes_with_nul(b"\0")?;
+
+    let hello_ref = buf.store_unsized(hello);
+    let empty_ref = buf.store_unsized(empty);
+
+    assert_eq!(hello_ref.len(), 6);
+    assert_eq!(empty_ref.len(), 1);
+    assert_eq!(buf.load(hello_ref)?, hello);
+    assert_eq!(buf.load(empty_ref)?, empty);
+    assert_eq!(&buf[..], b"hello\0\0");
+
+    let bytes = buf.store_slice(b"a\0b\0");
+
+    // Interior NUL byte.
+    let interior = Ref::<CStr>::with_metadata(bytes.offset(), 4);
+    let error = buf.load(interior).unwrap_err();
+    assert!(error.to_string().contains("nul"), "{error}");
+
+    // Not NUL-terminated.
+    let unterminated = Ref::<CStr>::with_metadata(bytes.offset(), 1);
+    assert!(buf.load(unterminated).is_err());
+
+    // A zero-length reference can't even hold the terminator.
+    let zero = Ref::<CStr>::with_metadata(bytes.offset(), 0);
+    assert!(buf.load(zero).is_err());
+
+    let terminated = Ref::<CStr>::with_metadata(bytes.offset(), 2);
+    assert_eq!(buf.load(terminated)?.to_bytes(), b"a");
+
+    let out_of_bounds = Ref::<CStr>::with_metadata(bytes.offset(), 5);
+    assert!(buf.load(out_of_bounds).unwrap_err().is_out_of_bounds());
+    Ok(())
+}
+
+#[test]
+fn replace() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let header = buf.store(&Inner {
+        first: 0,
+        second: 0,
+    });
+    let values = buf.store_slice(&[1u64, 2, 3]);
+
+    // Make the padding in the first value dirty to ensure it's zeroed again.
+    buf.as_mut_slice()[1..8].fill(0xff);
+
+    let second = buf.load(values)?.iter().sum();
+    buf.replace(header, &Inner { first: 3, second })?;
+
+    assert_eq!(header.offset(), 0);
+    assert_eq!(
+        buf.load(header)?,
+        &Inner {
+            first: 3,
+            second: 6
+        }
+    );
+    assert_eq!(&buf[..8], &[3, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(buf.load(values)?, &[1, 2, 3]);
+
+    let last = Ref::<u64>::new(values.offset() + 16);
+    buf.replace(last, &4)?;
+    assert_eq!(buf.load(values)?, &[1, 2, 4]);
+
+    let len = buf.len();
+    let error = buf.replace(Ref::<u64>::new(len - 4), &5).unwrap_err();
+    assert!(error.is_out_of_bounds());
+    assert_eq!(buf.len(), len);
+    Ok(())
+}
+
+#[test]
+fn slice_validation_fast_path() -> Result<()> {
+    use super::validator::take_field_validations;
+
+    #[derive(ZeroCopy)]
+    #[zero_copy(crate)]
+    #[repr(C)]
+    struct Numbers {
+        first: u32,
+        rest: [u32; 64],
+    }
+
+    #[derive(ZeroCopy)]
+    #[zero_copy(crate)]
+    #[repr(C)]
+    struct Chars {
+        first: char,
+        rest: [char; 4],
+    }
+
+    let mut buf = OwnedBuf::new();
+
+    let numbers = buf.store_slice(&[42u32; 1024]);
+    let inner = buf.store_slice(&[
+        Inner {
+            first: 1,
+            second: 2,
+        },
+        Inner {
+            first: 3,
+            second: 4,
+        },
+    ]);
+    let numbers_struct = buf.store(&Numbers {
+        first: 1,
+        rest: [2; 64],
+    });
+    let chars = buf.store_slice(&['a', 'b', 'c']);
+    let chars_struct = buf.store(&Chars {
+        first: 'a',
+        rest: ['b'; 4],
+    });
+
+    let _ = take_field_validations();
+
+    assert_eq!(buf.load(numbers)?.len(), 1024);
+    assert_eq!(buf.load(inner)?.len(), 2);
+    assert_eq!(buf.load(numbers_struct)?.rest, [2; 64]);
+    assert_eq!(take_field_validations(), 0);
+
+    assert_eq!(buf.load(chars)?, &['a', 'b', 'c']);
+    assert_eq!(take_field_validations(), 3);
+
+    assert_eq!(buf.load(chars_struct)?.rest, ['b'; 4]);
+    assert_eq!(take_field_validations(), 2 + 4);
+    Ok(())
+}
+
+#[test]
+fn nested_slice() -> Result<()> {
+    let mut section = OwnedBuf::new();
+    let values = section.store_slice(&[1u32, 2, 3]);
+    let string = section.store_unsized("hello");
+
+    let mut container = OwnedBuf::with_alignment::<u64>();
+    container.extend_from_slice(&[0xff; 8]);
+    container.extend_from_slice(section.as_slice());
+    container.extend_from_slice(&[0xff; 8]);
+
+    let outer = container.slice_with_alignment(8..container.len(), 8)?;
+    let view = outer.slice_with_alignment(0..section.len(), 4)?;
+
+    assert_eq!(view.len(), section.len());
+    assert_eq!(view.load(values)?, &[1, 2, 3]);
+    assert_eq!(view.load(string)?, "hello");
+
+    // Loads which are in bounds of the parent are still bounded by the view.
+    let error = view.load(Ref::<u8>::new(section.len())).unwrap_err();
+    assert!(error.is_out_of_bounds());
+    assert_eq!(outer.load(Ref::<u8>::new(section.len()))?, &0xff);
+
+    assert!(view.slice(0..section.len() + 1).is_err());
+    assert!(view.slice(0..0)?.is_empty());
+    assert!(container.slice_with_alignment(4..8, 8).is_err());
+    Ok(())
+}
@@ -97,7 +97,7 @@ impl<'a, T: ?Sized> Validator<'a, T> {
         // appropriately above.
         unsafe {
             self.align_with(align_of::<F>());
-            F::validate(&mut Validator::new(self.data))?;
+            validate_field::<F>(self.data)?;
             let output = self.data.cast::<F>().as_ref();
             self.advance::<F>();
             Ok(output)
@@ -234,9 +234,7 @@ impl<'a, T: ?Sized> Validator<'a, T> {
         F: ZeroCopy,
     {
         self.align_with(align);
-        F::validate(&mut Validator::new(self.data))?;
-        self.advance::<F>();
-        Ok(())
+        self.validate_only::<F>()
     }
 
     /// Only validate the given field without aligning it.
@@ -254,7 +252,7 @@ impl<'a, T: ?Sized> Validator<'a, T> {
     {
         // SAFETY: We've ensured that the provided buffer is aligned and sized
         // appropriately above.
-        F::validate(&mut Validator::new(self.data))?;
+        validate_field::<F>(self.data).map_err(|error| error.with_address(self.data.as_ptr()))?;
         self.advance::<F>();
         Ok(())
     }
@@ -280,3 +278,42 @@ impl<'a, T: ?Sized> Validator<'a, T> {
         start..end
     }
 }
+
+/// Validate a single field at the given pointer.
+///
+/// Fields which can inhabit any bit pattern have nothing to validate, so this
+/// is a no-op for them. This is what allows slices and arrays of primitive
+/// types to be validated in constant time.
+///
+/// # Safety
+///
+/// The pointer must point to an initialized and aligned region of memory at
+/// least the size of `F`.
+#[inline]
+unsafe fn validate_field<F>(data: NonNull<u8>) -> Result<(), Error>
+where
+    F: ZeroCopy,
+{
+    if F::ANY_BITS {
+        return Ok(());
+    }
+
+    #[cfg(test)]
+    FIELD_VALIDATIONS.with(|count| count.set(count.get() + 1));
+
+    F::validate(&mut Validator::new(data))
+}
+
+#[cfg(test)]
+std::thread_local! {
+    /// Number of fields which have been validated on the current thread, used
+    /// in tests to assert that the fast path in [`validate_field`] is taken.
+    static FIELD_VALIDATIONS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+/// Take the number of fields validated on the current thread since the last
+/// call.
+#[cfg(test)]
+pub(crate) fn take_field_validations() -> usize {
+    FIELD_VALIDATIONS.with(|count| count.replace(0))
+}
@@ -1,3 +1,5 @@
+use core::ffi::CStr;
+
 use crate::buf::{Buf, Load};
 use crate::endian::ByteOrder;
 use crate::error::Error;
@@ -65,6 +67,18 @@ impl Visit for str {
     }
 }
 
+impl Visit for CStr {
+    type Target = CStr;
+
+    #[inline]
+    fn visit<V, O>(&self, _: &Buf, visitor: V) -> Result<O, Error>
+    where
+        V: FnOnce(&Self::Target) -> O,
+    {
+        Ok(visitor(self))
+    }
+}
+
 impl<T> Visit for [T] {
     type Target = [T];
 
@@ -1,9 +1,15 @@
 use core::alloc::Layout;
 use core::any::type_name;
+use core::ffi::FromBytesWithNulError;
 use core::fmt;
 use core::ops::{Range, RangeFrom};
 use core::str::Utf8Error;
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 mod sealed {
     pub trait Sealed {}
     impl Sealed for () {}
@@ -101,17 +107,472 @@ impl fmt::Display for Repr {
     }
 }
 
+/// The context in which an error was raised during validation.
+///
+/// This is only recorded once validation has failed, and is boxed so that it
+/// doesn't affect the size of the error.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+struct Context {
+    /// The name of the type being loaded.
+    type_name: Option<&'static str>,
+    /// The location of the field which failed validation, if known.
+    ///
+    /// While validating this is the address of the field, once the type being
+    /// loaded has been recorded it's converted into an absolute offset.
+    at: Option<usize>,
+    /// Field names, from the innermost to the outermost.
+    fields: Vec<&'static str>,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+
+        if let Some(name) = self.type_name {
+            f.write_str(name)?;
+            separator = ".";
+        }
+
+        for field in self.fields.iter().rev() {
+            f.write_str(separator)?;
+            f.write_str(field)?;
+            separator = ".";
+        }
+
+        if let (Some(..), Some(at)) = (self.type_name, self.at) {
+            write!(f, " @ {at:#06x}")?;
+        }
+
+        Ok(())
+    }
+}
+
 /// Müsli's zero copy error type.
+///
+/// Errors raised while validating a value carry the context in which they
+/// happened, such as the type being loaded, the offset of the field which
+/// failed and the path leading up to it. This is included when the error is
+/// displayed, and is only recorded if the `alloc` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Flags {
+///     enabled: bool,
+/// }
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Person {
+///     age: u8,
+///     flags: Flags,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+/// let person = buf.store(&Person { age: 42, flags: Flags { enabled: true } });
+///
+/// // Corrupt the `enabled` field.
+/// buf.as_mut_slice()[1] = 2;
+///
+/// let Err(error) = buf.load(person) else {
+///     panic!("expected error");
+/// };
+///
+/// assert_eq!(error.type_name(), Some("Person"));
+/// assert_eq!(error.offset(), Some(1));
+/// assert!(error.path().eq(["flags", "enabled"]));
+/// assert_eq!(error.to_string(), "Person.flags.enabled @ 0x0001: Illegal bool representation 2");
+/// ```
 #[derive(Debug)]
-#[cfg_attr(test, derive(PartialEq))]
 pub struct Error {
     kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    context: Option<Box<Context>>,
 }
 
 impl Error {
     #[inline]
     pub(crate) const fn new(kind: ErrorKind) -> Self {
-        Self { kind }
+        Self {
+            kind,
+            #[cfg(feature = "alloc")]
+            context: None,
+        }
+    }
+
+    /// The name of the type which was being loaded when the error occured, if
+    /// known.
+    #[inline]
+    pub fn type_name(&self) -> Option<&'static str> {
+        #[cfg(feature = "alloc")]
+        if let Some(context) = &self.context {
+            return context.type_name;
+        }
+
+        None
+    }
+
+    /// The absolute offset of the field which failed validation, if known.
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        #[cfg(feature = "alloc")]
+        if let Some(context) = &self.context {
+            context.type_name?;
+            return context.at;
+        }
+
+        None
+    }
+
+    /// Iterate over the path of fields which were being validated when the
+    /// error occured, from the outermost to the innermost.
+    #[inline]
+    pub fn path(&self) -> impl DoubleEndedIterator<Item = &'static str> + '_ {
+        #[cfg(feature = "alloc")]
+        let fields = self
+            .context
+            .iter()
+            .flat_map(|context| context.fields.iter().rev().copied());
+        #[cfg(not(feature = "alloc"))]
+        let fields = core::iter::empty();
+        fields
+    }
+
+    /// Access the context of the error, recording it if necessary.
+    #[cfg(feature = "alloc")]
+    #[cold]
+    fn context_mut(&mut self) -> &mut Context {
+        self.context.get_or_insert_with(Box::default)
+    }
+
+    /// Record the type of the value being loaded when the error occured.
+    ///
+    /// The value was loaded from `offset` and validated at the address `data`,
+    /// which is used to convert the address of the field which failed into an
+    /// absolute offset.
+    #[cold]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+    pub(crate) fn with_root<T: ?Sized>(mut self, offset: usize, data: *const u8) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            let context = self.context_mut();
+
+            if context.type_name.is_none() {
+                context.type_name = Some(short_type_name::<T>());
+
+                context.at = Some(match context.at {
+                    None => offset,
+                    Some(address) => offset.wrapping_add(address.wrapping_sub(data as usize)),
+                });
+            }
+        }
+
+        self
+    }
+
+    /// Record the address of the field which is being validated, unless a more
+    /// specific one has already been recorded.
+    #[cold]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+    pub(crate) fn with_address(mut self, data: *const u8) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            let context = self.context_mut();
+
+            if context.type_name.is_none() && context.at.is_none() {
+                context.at = Some(data as usize);
+            }
+        }
+
+        self
+    }
+
+    /// Record that the error happened while validating the field `name`.
+    #[cold]
+    #[doc(hidden)]
+    #[cfg_attr(not(feature = "alloc"), allow(unused_mut, unused_variables))]
+    pub fn __field(mut self, name: &'static &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        self.context_mut().fields.push(name);
+        self
+    }
+
+    /// Test if the error was caused by trying to access data outside of the
+    /// bounds of a buffer or a slice.
+    ///
+    /// This typically indicates that the buffer is too short, see
+    /// [`required_len()`] for how to get the length which would have been
+    /// needed.
+    ///
+    /// [`required_len()`]: Self::required_len
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, Ref};
+    ///
+    /// let buf = Buf::new(b"Hello");
+    /// let error = buf.load(Ref::<str>::with_metadata(2, 12)).unwrap_err();
+    ///
+    /// assert!(error.is_out_of_bounds());
+    /// assert!(!error.is_utf8());
+    /// assert_eq!(error.required_len(), Some(14));
+    /// assert_eq!(error.range(), Some(2..14));
+    /// ```
+    pub fn is_out_of_bounds(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::OutOfRangeBounds { .. }
+                | ErrorKind::OutOfRangeFromBounds { .. }
+                | ErrorKind::IndexOutOfBounds { .. }
+                | ErrorKind::ControlRangeOutOfBounds { .. }
+                | ErrorKind::StrideOutOfBounds { .. }
+        )
+    }
+
+    /// Test if the error was caused by data not being aligned as required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, Ref};
+    ///
+    /// let buf = Buf::new(&[0, 0, 0, 0, 0, 0, 0, 0]);
+    /// let aligned = buf.is_aligned_with(4);
+    ///
+    /// let result = buf.load(Ref::<u32>::new(if aligned { 1 } else { 0 }));
+    /// let error = result.unwrap_err();
+    ///
+    /// assert!(error.is_alignment());
+    /// assert_eq!(error.expected_alignment(), Some(4));
+    /// ```
+    pub fn is_alignment(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::AlignmentRangeMismatch { .. }
+                | ErrorKind::AlignmentRangeFromMismatch { .. }
+                | ErrorKind::UnalignedStorage { .. }
+        )
+    }
+
+    /// Test if the error was caused by a fixed buffer not having the capacity
+    /// to store a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::SliceMut;
+    ///
+    /// let mut bytes = [0; 4];
+    /// let mut buf = SliceMut::with_alignment::<()>(&mut bytes);
+    ///
+    /// let error = buf.try_store_unsized("too long").unwrap_err();
+    /// assert!(error.is_capacity());
+    /// ```
+    pub fn is_capacity(&self) -> bool {
+        match &self.kind {
+            ErrorKind::InsufficientCapacity { .. } => true,
+            #[cfg(feature = "alloc")]
+            ErrorKind::CapacityError => true,
+            _ => false,
+        }
+    }
+
+    /// Test if the error was caused by an archive header having a different
+    /// magic value than the one expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::{DefaultSize, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let root = buf.store(&42u32);
+    /// buf.finish_with_header(*b"EXAMPLE1", 1, root)?;
+    ///
+    /// let error = buf
+    ///     .open_with_header::<u32, Native, DefaultSize>(*b"EXAMPLE2", 1)
+    ///     .unwrap_err();
+    /// assert!(error.is_magic_mismatch());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn is_magic_mismatch(&self) -> bool {
+        matches!(self.kind, ErrorKind::HeaderMagicMismatch { .. })
+    }
+
+    /// Test if the error was caused by an archive header having a different
+    /// version than the one expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::{DefaultSize, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let root = buf.store(&42u32);
+    /// buf.finish_with_header(*b"EXAMPLE1", 2, root)?;
+    ///
+    /// let error = buf
+    ///     .open_with_header::<u32, Native, DefaultSize>(*b"EXAMPLE1", 1)
+    ///     .unwrap_err();
+    /// assert!(error.is_version_mismatch());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn is_version_mismatch(&self) -> bool {
+        matches!(self.kind, ErrorKind::HeaderVersionMismatch { .. })
+    }
+
+    /// Test if the error was caused by an archive header indicating that the
+    /// archive was written with a different byte order than the one it is
+    /// being opened with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{endian, DefaultSize, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new().with_byte_order::<endian::Big>();
+    /// let root = buf.store(&42u32);
+    /// buf.finish_with_header(*b"EXAMPLE1", 1, root)?;
+    ///
+    /// let error = buf
+    ///     .open_with_header::<u32, endian::Little, DefaultSize>(*b"EXAMPLE1", 1)
+    ///     .unwrap_err();
+    /// assert!(error.is_byte_order_mismatch());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn is_byte_order_mismatch(&self) -> bool {
+        matches!(self.kind, ErrorKind::HeaderByteOrderMismatch { .. })
+    }
+
+    /// Test if the error was caused by a string not being valid UTF-8, or by
+    /// a string range which does not fall on character boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, Ref};
+    ///
+    /// let buf = Buf::new(&[0xff, 0xff]);
+    /// let error = buf.load(Ref::<str>::with_metadata(0, 2)).unwrap_err();
+    ///
+    /// assert!(error.is_utf8());
+    /// assert!(error.utf8_error().is_some());
+    /// ```
+    pub fn is_utf8(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::Utf8Error { .. } | ErrorKind::NotCharBoundary { .. }
+        )
+    }
+
+    /// Test if the error was caused by a value which has an illegal bit
+    /// pattern, such as an unknown enum discriminant or an invalid `char`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{Buf, Ref};
+    ///
+    /// let buf = Buf::new(&[2]);
+    /// let error = buf.load(Ref::<bool>::zero()).unwrap_err();
+    ///
+    /// assert!(error.is_illegal_value());
+    /// ```
+    pub fn is_illegal_value(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::NonZeroZeroed { .. }
+                | ErrorKind::IllegalDiscriminant { .. }
+                | ErrorKind::IllegalChar { .. }
+                | ErrorKind::IllegalBool { .. }
+                | ErrorKind::FixedStrOverflow { .. }
+                | ErrorKind::FixedStrNonZeroTail { .. }
+                | ErrorKind::IllegalNanos { .. }
+        )
+    }
+
+    /// Test if the error was caused by an arithmetic overflow or underflow
+    /// while calculating an offset or a length.
+    pub fn is_arithmetic(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::LengthOverflow { .. }
+                | ErrorKind::Underflow { .. }
+                | ErrorKind::Overflow { .. }
+        )
+    }
+
+    /// The length a buffer would have needed to have for the operation to not
+    /// have failed because it was out of bounds.
+    ///
+    /// This is only available for errors caused by reading past the end of a
+    /// buffer, in which case [`is_out_of_bounds()`] returns `true`.
+    ///
+    /// [`is_out_of_bounds()`]: Self::is_out_of_bounds
+    pub fn required_len(&self) -> Option<usize> {
+        match &self.kind {
+            ErrorKind::OutOfRangeBounds { range, .. } => Some(range.end),
+            ErrorKind::OutOfRangeFromBounds { range, .. } => Some(range.start),
+            _ => None,
+        }
+    }
+
+    /// The byte range which caused the error, if any.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match &self.kind {
+            ErrorKind::AlignmentRangeMismatch { range, .. }
+            | ErrorKind::LayoutMismatch { range, .. }
+            | ErrorKind::OutOfRangeBounds { range, .. }
+            | ErrorKind::NonZeroZeroed { range }
+            | ErrorKind::ControlRangeOutOfBounds { range, .. } => Some(range.clone()),
+            _ => None,
+        }
+    }
+
+    /// The alignment which was expected, if the error was caused by data not
+    /// being aligned.
+    pub fn expected_alignment(&self) -> Option<usize> {
+        match &self.kind {
+            ErrorKind::AlignmentRangeMismatch { align, .. }
+            | ErrorKind::AlignmentRangeFromMismatch { align, .. }
+            | ErrorKind::UnalignedStorage { align, .. } => Some(*align),
+            ErrorKind::LayoutMismatch { layout, .. } => Some(layout.align()),
+            _ => None,
+        }
+    }
+
+    /// The underlying UTF-8 error, if the error was caused by a string not
+    /// being valid UTF-8.
+    pub fn utf8_error(&self) -> Option<&Utf8Error> {
+        match &self.kind {
+            ErrorKind::Utf8Error { error } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Adjust ranges in the error which are relative to `base` so that they
+    /// are absolute within the buffer.
+    ///
+    /// Adjusted values saturate, since the offsets being reported might be
+    /// arbitrarily large.
+    #[inline]
+    pub(crate) fn with_base(mut self, base: usize) -> Self {
+        if let ErrorKind::OutOfRangeBounds { range, len } = &mut self.kind {
+            range.start = range.start.saturating_add(base);
+            range.end = range.end.saturating_add(base);
+            *len = len.saturating_add(base);
+        }
+
+        self
     }
 
     #[inline(always)]
@@ -124,18 +585,52 @@ impl Error {
     }
 }
 
+/// Errors are compared by kind, the context in which they were raised is
+/// diagnostic only.
+#[cfg(test)]
+impl PartialEq for Error {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+    }
+}
+
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "alloc")]
+        if let Some(context) = &self.context {
+            write!(f, "{context}: ")?;
+        }
+
         self.kind.fmt(f)
     }
 }
 
+/// Get the name of a type without its module path.
+#[cfg(feature = "alloc")]
+fn short_type_name<T: ?Sized>() -> &'static str {
+    let name = type_name::<T>();
+
+    // Slices, arrays and tuples are kept as-is.
+    if name.starts_with(['[', '(']) {
+        return name;
+    }
+
+    let end = name.find('<').unwrap_or(name.len());
+
+    match name[..end].rfind("::") {
+        Some(index) => &name[index + 2..],
+        None => name,
+    }
+}
+
 #[cfg(feature = "std")]
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match &self.kind {
             ErrorKind::Utf8Error { error } => Some(error),
+            ErrorKind::CStrError { error } => Some(error),
             _ => None,
         }
     }
@@ -152,6 +647,11 @@ pub(crate) enum ErrorKind {
         offset: Repr,
         max: Repr,
     },
+    InvalidDeltaRange {
+        from: usize,
+        to: usize,
+        ty: &'static str,
+    },
     InvalidMetadata {
         ty: &'static str,
         packed: &'static str,
@@ -213,6 +713,21 @@ pub(crate) enum ErrorKind {
     Utf8Error {
         error: Utf8Error,
     },
+    CStrError {
+        error: FromBytesWithNulError,
+    },
+    FixedStrOverflow {
+        len: usize,
+        capacity: usize,
+    },
+    FixedStrNonZeroTail {
+        index: usize,
+    },
+    IllegalNanos {
+        nanos: u32,
+    },
+    #[cfg(feature = "std")]
+    TimestampOutOfRange,
     Underflow {
         at: usize,
         len: usize,
@@ -224,6 +739,37 @@ pub(crate) enum ErrorKind {
     StackOverflow {
         capacity: usize,
     },
+    NotInBuffer {
+        addr: usize,
+        range: Range<usize>,
+    },
+    NotCharBoundary {
+        index: usize,
+    },
+    InsufficientCapacity {
+        capacity: usize,
+        needed: usize,
+    },
+    UnalignedStorage {
+        addr: usize,
+        align: usize,
+    },
+    ArrayLengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    HeaderMagicMismatch {
+        expected: [u8; 8],
+        actual: [u8; 8],
+    },
+    HeaderVersionMismatch {
+        expected: u32,
+        actual: u32,
+    },
+    HeaderByteOrderMismatch {
+        expected: &'static str,
+        actual: &'static str,
+    },
     #[cfg(feature = "alloc")]
     CapacityError,
     #[cfg(feature = "alloc")]
@@ -242,6 +788,9 @@ impl fmt::Display for ErrorKind {
             ErrorKind::InvalidOffsetRange { offset, max } => {
                 write!(f, "Offset {offset} not in legal range 0-{max}",)
             }
+            ErrorKind::InvalidDeltaRange { from, to, ty } => {
+                write!(f, "Delta from {from} to {to} not in legal range of `{ty}`")
+            }
             ErrorKind::InvalidMetadata { ty, packed } => {
                 write!(
                     f,
@@ -311,7 +860,75 @@ impl fmt::Display for ErrorKind {
             ErrorKind::StackOverflow { capacity } => {
                 write!(f, "Stack with capacity {capacity} overflowed")
             }
+            ErrorKind::NotInBuffer { addr, range } => {
+                write!(
+                    f,
+                    "Reference at address {addr:x} is not located inside of buffer range {range:x?}"
+                )
+            }
+            ErrorKind::NotCharBoundary { index } => {
+                write!(f, "Index {index} is not on a character boundary")
+            }
+            ErrorKind::InsufficientCapacity { capacity, needed } => {
+                write!(
+                    f,
+                    "Underlying slice has the capacity {capacity}, but {needed} bytes are needed"
+                )
+            }
+            ErrorKind::UnalignedStorage { addr, align } => {
+                write!(
+                    f,
+                    "Underlying slice at address {addr:x} is not aligned by {align}"
+                )
+            }
+            ErrorKind::ArrayLengthMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Expected a slice of length {expected} but found {actual}"
+                )
+            }
+            ErrorKind::HeaderMagicMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive header magic mismatch, expected {expected:?} but found {actual:?}"
+                )
+            }
+            ErrorKind::HeaderVersionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive header version mismatch, expected {expected} but found {actual}"
+                )
+            }
+            ErrorKind::HeaderByteOrderMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive header byte order mismatch, expected {expected} endian but found {actual}"
+                )
+            }
             ErrorKind::Utf8Error { error } => error.fmt(f),
+            ErrorKind::CStrError { error } => error.fmt(f),
+            ErrorKind::FixedStrOverflow { len, capacity } => {
+                write!(
+                    f,
+                    "String of length {len} does not fit in a fixed string with capacity {capacity}"
+                )
+            }
+            ErrorKind::FixedStrNonZeroTail { index } => {
+                write!(
+                    f,
+                    "Unused byte at index {index} of fixed string is not zero"
+                )
+            }
+            ErrorKind::IllegalNanos { nanos } => {
+                write!(
+                    f,
+                    "Nanoseconds {nanos} is out of range, must be less than one second"
+                )
+            }
+            #[cfg(feature = "std")]
+            ErrorKind::TimestampOutOfRange => {
+                write!(f, "Timestamp is out of range")
+            }
             #[cfg(feature = "alloc")]
             ErrorKind::CapacityError => {
                 write!(f, "Out of capacity")
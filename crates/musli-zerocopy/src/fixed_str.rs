@@ -0,0 +1,351 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::str;
+
+use crate::buf::{Buf, Padder, Validator, Visit};
+use crate::endian::ByteOrder;
+use crate::error::{Error, ErrorKind};
+use crate::traits::ZeroCopy;
+
+/// A string stored inline with a fixed capacity of `N` bytes.
+///
+/// This is useful for short strings like currency codes or tickers, where a
+/// [`Ref<str>`] would cost more space than the string itself, and storing it
+/// out-of-line would cost an extra indirection when loading it. Since the
+/// string is stored inline it can also be used as a key in [`phf`] and
+/// [`swiss`] maps, which then become entirely self-contained.
+///
+/// The string is represented as a single byte indicating its length, followed
+/// by `N` bytes of string data. Since the length is a single byte, the
+/// capacity `N` can at most be `255`.
+///
+/// # Validation
+///
+/// When a fixed string is loaded from a buffer:
+/// * The length must not exceed the capacity `N`.
+/// * The used prefix of the string data must be valid UTF-8.
+/// * All unused bytes after the used prefix must be zero.
+///
+/// Requiring the unused bytes to be zeroed means that every string has
+/// exactly one valid representation, so that equal strings always produce
+/// identical bytes. Fixed strings constructed through this type always uphold
+/// this.
+///
+/// [`Ref<str>`]: crate::Ref
+/// [`phf`]: crate::phf
+/// [`swiss`]: crate::swiss
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{FixedStr, OwnedBuf};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let code = FixedStr::<3>::try_from("SEK")?;
+/// let code = buf.store(&code);
+///
+/// let code = buf.load(code)?;
+/// assert_eq!(code, "SEK");
+/// assert_eq!(code.len(), 3);
+///
+/// assert!(FixedStr::<3>::try_from("EURO").is_err());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// Using fixed strings as keys in a map:
+///
+/// ```
+/// use musli_zerocopy::{swiss, FixedStr, OwnedBuf};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map(&mut buf, [
+///     (FixedStr::<4>::try_from("SEK")?, 1u32),
+///     (FixedStr::<4>::try_from("EUR")?, 2u32),
+/// ])?;
+///
+/// let map = buf.bind(map)?;
+/// assert_eq!(map.get("SEK")?, Some(&1));
+/// assert_eq!(map.get("EUR")?, Some(&2));
+/// assert_eq!(map.get("USD")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct FixedStr<const N: usize> {
+    len: u8,
+    data: [u8; N],
+}
+
+impl<const N: usize> FixedStr<N> {
+    /// Compile-time check that the capacity fits in the length byte.
+    const CAPACITY: usize = {
+        assert!(
+            N <= u8::MAX as usize,
+            "FixedStr capacity must not exceed 255"
+        );
+        N
+    };
+
+    /// Construct a new empty fixed string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::FixedStr;
+    ///
+    /// let string = FixedStr::<8>::new();
+    /// assert!(string.is_empty());
+    /// assert_eq!(string, "");
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        let _ = Self::CAPACITY;
+
+        Self {
+            len: 0,
+            data: [0; N],
+        }
+    }
+
+    /// Get the string as a `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::FixedStr;
+    ///
+    /// let string = FixedStr::<8>::try_from("Hello")?;
+    /// assert_eq!(string.as_str(), "Hello");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The length and contents of the string are checked on
+        // construction and when it is loaded.
+        unsafe { str::from_utf8_unchecked(self.data.get_unchecked(..self.len as usize)) }
+    }
+
+    /// Get the length of the string in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::FixedStr;
+    ///
+    /// let string = FixedStr::<8>::try_from("åäö")?;
+    /// assert_eq!(string.len(), 6);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Test if the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::FixedStr;
+    ///
+    /// assert!(FixedStr::<8>::try_from("")?.is_empty());
+    /// assert!(!FixedStr::<8>::try_from("a")?.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the capacity of the string in bytes, which is `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::FixedStr;
+    ///
+    /// assert_eq!(FixedStr::<8>::new().capacity(), 8);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+}
+
+impl<const N: usize> Default for FixedStr<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Construct a fixed string, erroring if the string is longer than `N` bytes.
+impl<const N: usize> TryFrom<&str> for FixedStr<N> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut this = Self::new();
+
+        let Some(data) = this.data.get_mut(..value.len()) else {
+            return Err(Error::new(ErrorKind::FixedStrOverflow {
+                len: value.len(),
+                capacity: N,
+            }));
+        };
+
+        data.copy_from_slice(value.as_bytes());
+        this.len = value.len() as u8;
+        Ok(this)
+    }
+}
+
+impl<const N: usize> Deref for FixedStr<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Borrow<str> for FixedStr<N> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> PartialEq for FixedStr<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for FixedStr<N> {}
+
+impl<const N: usize> PartialEq<str> for FixedStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedStr<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<FixedStr<N>> for str {
+    #[inline]
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<FixedStr<N>> for &str {
+    #[inline]
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialOrd for FixedStr<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for FixedStr<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Fixed strings hash the same as the `str` they contain, which is required
+/// for lookups through [`Borrow<str>`] to work.
+impl<const N: usize> Hash for FixedStr<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+unsafe impl<const N: usize> ZeroCopy for FixedStr<N> {
+    const ANY_BITS: bool = false;
+    const PADDED: bool = false;
+    const CAN_SWAP_BYTES: bool = true;
+
+    #[inline]
+    unsafe fn pad(_: &mut Padder<'_, Self>) {}
+
+    #[inline]
+    unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error> {
+        let len = validator.byte() as usize;
+        let data = validator.field::<[u8; N]>()?;
+
+        if len > Self::CAPACITY {
+            return Err(Error::new(ErrorKind::FixedStrOverflow { len, capacity: N }));
+        }
+
+        let (used, unused) = data.split_at(len);
+
+        str::from_utf8(used).map_err(|error| Error::new(ErrorKind::Utf8Error { error }))?;
+
+        if let Some(index) = unused.iter().position(|&b| b != 0) {
+            return Err(Error::new(ErrorKind::FixedStrNonZeroTail {
+                index: len + index,
+            }));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn swap_bytes<E: ByteOrder>(self) -> Self {
+        self
+    }
+}
+
+impl<const N: usize> Visit for FixedStr<N> {
+    type Target = str;
+
+    #[inline]
+    fn visit<V, O>(&self, _: &Buf, visitor: V) -> Result<O, Error>
+    where
+        V: FnOnce(&Self::Target) -> O,
+    {
+        Ok(visitor(self.as_str()))
+    }
+}
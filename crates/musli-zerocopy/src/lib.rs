@@ -44,6 +44,8 @@
 //!   functions.
 //! * [`swiss`] is a port of the [`hashbrown` crate] which is a Google
 //!   SwissTable implementation.
+//! * [`sorted`] provides maps which store their entries sorted by key, which
+//!   are cheap to build and support range queries.
 //! * [`trie`] is an implementation of a prefix-trie, which supports efficient
 //!   multi-value byte-prefixed lookups.
 //!
@@ -248,6 +250,32 @@
 //! > let alignment = buf.requested();
 //! > ```
 //!
+//! Rather than managing this yourself, [`OwnedBuf::finish_with_header()`] can
+//! be used to append an [`ArchiveHeader`] recording all of the above, which is
+//! then checked when reading the buffer back using
+//! [`Buf::open_with_header()`]:
+//!
+//! ```
+//! # use musli_zerocopy::endian::Native;
+//! # use musli_zerocopy::{DefaultSize, OwnedBuf, Ref, ZeroCopy};
+//! # #[derive(ZeroCopy)]
+//! # #[repr(C)]
+//! # struct Custom { field: u32, string: Ref<str> }
+//! let mut buf = OwnedBuf::new();
+//! let string = buf.store_unsized("Hello World!");
+//! let root = buf.store(&Custom { field: 42, string });
+//! buf.finish_with_header(*b"CUSTOM00", 1, root)?;
+//!
+//! let custom = buf.open_with_header::<Custom, Native, DefaultSize>(*b"CUSTOM00", 1)?;
+//! let custom = buf.load(custom)?;
+//! assert_eq!(custom.field, 42);
+//! # Ok::<_, musli_zerocopy::Error>(())
+//! ```
+//!
+//! [`ArchiveHeader`]: crate::buf::ArchiveHeader
+//! [`OwnedBuf::finish_with_header()`]: crate::buf::OwnedBuf::finish_with_header
+//! [`Buf::open_with_header()`]: crate::buf::Buf::open_with_header
+//!
 //! The following is an example of reading the type directly out of a newtype
 //! aligned `&'static [u8]` buffer:
 //!
@@ -414,6 +442,14 @@
 //! The pointer width on the system is checked at compile time, while trying to
 //! use an offset or a size larger than `2^32` will result in a panic.
 //!
+//! Only 32 and 64-bit targets are supported, and trying to build for any other
+//! pointer width, such as 16-bit AVR, results in a compile error. On 32-bit
+//! targets like `wasm32` a `usize` is no wider than the default `u32` [`Size`],
+//! so offset calculations could wrap around well below the limit of a [`Size`].
+//! Methods on [`Ref`] which compute new offsets, like `offset_by`, panic if the
+//! result overflows and have checked alternatives like `checked_offset_by` which
+//! return `None` instead.
+//!
 //! Example of using an address larger than `2^32` causing a panic:
 //!
 //! ```should_panic
@@ -515,6 +551,7 @@
 //! [`Ref<T, E, O>`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/pointer/struct.Ref.html
 //! [`requested()`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/struct.OwnedBuf.html#method.requested
 //! [`Size`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/pointer/trait.Size.html
+//! [`sorted`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/sorted/index.html
 //! [`swiss`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/swiss/index.html
 //! [`trie`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/trie/index.html
 //! [`with_byte_order::<E>()`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/buf/struct.OwnedBuf.html#method.with_byte_order
@@ -549,6 +586,14 @@ pub mod slice;
 
 pub mod trie;
 
+pub mod tuple;
+
+pub mod time;
+
+#[doc(inline)]
+pub use self::fixed_str::FixedStr;
+mod fixed_str;
+
 #[doc(inline)]
 pub use self::error::Error;
 mod error;
@@ -563,6 +608,7 @@ mod traits;
 pub(crate) mod sip;
 
 pub mod phf;
+pub mod sorted;
 pub mod swiss;
 
 #[doc(inline)]
@@ -826,16 +872,270 @@ pub use musli_zerocopy_macros::Visit;
 /// #[zero_copy(crate = zerocopy)]
 /// struct Custom { field: u32 }
 /// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(owned = <path>)]`
+///
+/// Implements [`LoadOwned`] for the type, which converts it into the specified
+/// owned type. The owned type must have the same fields (or variants and
+/// fields for enums), where each field has the [`LoadOwned::Owned`] type of
+/// the corresponding field. Fields which are marked with
+/// `#[zero_copy(ignore)]` are constructed with [`Default`].
+///
+/// [`LoadOwned`]: crate::buf::LoadOwned
+/// [`LoadOwned::Owned`]: crate::buf::LoadOwned::Owned
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::buf::LoadOwned;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(owned = OwnedCustom)]
+/// struct Custom { field: u32, name: Ref<str> }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct OwnedCustom { field: u32, name: String }
+///
+/// let mut buf = OwnedBuf::new();
+/// let name = buf.store_unsized("Hello");
+/// let custom = buf.store(&Custom { field: 42, name });
+///
+/// let owned = custom.to_owned_in(&buf)?;
+/// assert_eq!(owned, OwnedCustom { field: 42, name: String::from("Hello") });
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(debug)]`
+///
+/// Implements [`DebugIn`] for the type, which allows it to be printed through
+/// [`Buf::debug`] with the contents of any references it contains. Every
+/// field must implement [`DebugIn`], except for those marked with
+/// `#[zero_copy(ignore)]` which are not printed.
+///
+/// [`DebugIn`]: crate::buf::DebugIn
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(debug)]
+/// struct Custom { field: u32, name: Ref<str> }
+///
+/// let mut buf = OwnedBuf::new();
+/// let name = buf.store_unsized("Hello");
+/// let custom = buf.store(&Custom { field: 42, name });
+///
+/// assert_eq!(
+///     format!("{:?}", buf.debug(custom)),
+///     r#"Custom { field: 42, name: "Hello" }"#
+/// );
+/// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(eq)]`
+///
+/// Implements [`EqIn`] for the type, which compares two values stored in
+/// different buffers field by field, following any references they contain.
+/// Every field must implement [`EqIn`], except for those marked with
+/// `#[zero_copy(ignore)]` which are not compared.
+///
+/// [`EqIn`]: crate::buf::EqIn
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::buf::EqIn;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(eq)]
+/// struct Custom { field: u32, name: Ref<str> }
+///
+/// let mut a = OwnedBuf::new();
+/// let name = a.store_unsized("Hello");
+/// let custom_a = a.store(&Custom { field: 42, name });
+///
+/// let mut b = OwnedBuf::new();
+/// b.store_unsized("World");
+/// let name = b.store_unsized("Hello");
+/// let custom_b = b.store(&Custom { field: 42, name });
+///
+/// assert!(custom_a.eq_in(&a, &custom_b, &b)?);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(hash)]`
+///
+/// Implements [`HashIn`] for the type, which hashes the logical contents of a
+/// stored value field by field, following any references it contains. Every
+/// field must implement [`HashIn`], except for those marked with
+/// `#[zero_copy(ignore)]` which are not hashed.
+///
+/// [`HashIn`]: crate::buf::HashIn
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(hash)]
+/// struct Custom { field: u32, name: Ref<str> }
+///
+/// let mut a = OwnedBuf::new();
+/// let name = a.store_unsized("Hello");
+/// let custom_a = a.store(&Custom { field: 42, name });
+///
+/// let mut b = OwnedBuf::new();
+/// b.store_unsized("World");
+/// let name = b.store_unsized("Hello");
+/// let custom_b = b.store(&Custom { field: 42, name });
+///
+/// let mut hasher_a = DefaultHasher::new();
+/// a.hash_value(custom_a, &mut hasher_a)?;
+///
+/// let mut hasher_b = DefaultHasher::new();
+/// b.hash_value(custom_b, &mut hasher_b)?;
+///
+/// assert_eq!(hasher_a.finish(), hasher_b.finish());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(zerocopy)]`
+///
+/// Implements `FromZeroes`, `FromBytes` and `AsBytes` from the [`zerocopy`
+/// crate] for the type. This requires the `zerocopy` feature to be enabled.
+///
+/// This is only supported for non-generic structs where every field
+/// implements the corresponding trait. The type must not contain any padding
+/// and must not contain any fields which require validation, which is
+/// checked at compile time. As a consequence, types containing enums, `bool`,
+/// `char` or `NonZero*` can never implement these traits.
+///
+/// Specifying `#[zero_copy(zerocopy(unaligned))]` additionally implements
+/// `Unaligned`, which requires the type to have an alignment of 1.
+///
+/// [`zerocopy` crate]: https://docs.rs/zerocopy
+///
+/// ```
+/// # #[cfg(feature = "zerocopy")] {
+/// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+/// use zerocopy::{AsBytes, FromBytes};
+///
+/// #[derive(Debug, PartialEq, ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(zerocopy)]
+/// struct Custom { field: u32, other: [u16; 2] }
+///
+/// let custom = Custom { field: 42, other: [1, 2] };
+///
+/// let mut buf = OwnedBuf::new();
+/// let bytes = buf.store_as_bytes(&custom);
+///
+/// assert_eq!(buf.load(bytes)?, custom.as_bytes());
+/// assert_eq!(Custom::ref_from(buf.load(bytes)?), Some(&custom));
+/// assert_eq!(buf.load_from_bytes::<Custom, _, _>(bytes)?, &custom);
+/// # }
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(bytemuck)]`
+///
+/// Implements `Zeroable` and `Pod` from the [`bytemuck` crate] for the type.
+/// This requires the `bytemuck` feature to be enabled.
+///
+/// The same restrictions apply as for `#[zero_copy(zerocopy)]`, and since
+/// `Pod` requires it the type must also implement [`Copy`].
+///
+/// [`bytemuck` crate]: https://docs.rs/bytemuck
+///
+/// ```
+/// # #[cfg(feature = "bytemuck")] {
+/// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(bytemuck)]
+/// struct Vertex { position: [f32; 3], color: u32 }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let vertices = buf.store_slice(&[
+///     Vertex { position: [0.0, 1.0, 0.0], color: 0xff0000ff },
+///     Vertex { position: [1.0, 0.0, 0.0], color: 0x00ff00ff },
+/// ]);
+///
+/// let bytes: &[u8] = bytemuck::cast_slice(buf.load(vertices)?);
+/// assert_eq!(bytes, buf.load_bytes_of(vertices)?);
+/// # }
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
 #[doc(inline)]
 pub use musli_zerocopy_macros::ZeroCopy;
 
+/// Derive macro to implement [`UnsizedZeroCopy`] for a struct whose last
+/// field is unsized, such as `[T]` or `str`.
+///
+/// This allows for storing a fixed header immediately followed by its payload,
+/// rather than storing the payload separately behind a [`Ref`]. The length of
+/// the trailing field is carried as the metadata of the [`Ref`] pointing to the
+/// struct.
+///
+/// The following are the requirements for deriving unsized structs:
+/// * The struct must be `#[repr(C)]`.
+/// * All fields except the last must implement [`ZeroCopy`].
+/// * The last field must implement [`UnsizedZeroCopy`].
+///
+/// Every field is validated when the struct is loaded, including that the
+/// trailing field fits within the buffer.
+///
+/// Making the struct generic over its trailing field allows values to be
+/// constructed through an unsizing coercion.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, UnsizedZeroCopy};
+///
+/// #[derive(UnsizedZeroCopy)]
+/// #[repr(C)]
+/// struct Record<T: ?Sized = [u32]> {
+///     kind: u8,
+///     data: T,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let record: &Record = &Record { kind: 1, data: [1, 2, 3] };
+/// let record = buf.store_unsized(record);
+/// assert_eq!(record.metadata(), 3);
+///
+/// let record = buf.load(record)?;
+/// assert_eq!(record.kind, 1);
+/// assert_eq!(&record.data, &[1, 2, 3]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[doc(inline)]
+pub use musli_zerocopy_macros::UnsizedZeroCopy;
+
 #[cfg(test)]
 mod tests;
 
 #[doc(hidden)]
 pub mod __private {
-    use core::fmt;
-
     pub mod result {
         pub use ::core::result::Result;
     }
@@ -844,15 +1144,191 @@ pub mod __private {
         pub use ::core::mem::{align_of, size_of};
     }
 
-    pub use crate::buf::{Buf, Visit};
+    pub mod fmt {
+        pub use ::core::fmt::{Formatter, Result};
+    }
+
+    pub mod hash {
+        pub use ::core::hash::Hasher;
+    }
+
+    pub mod ptr {
+        pub use ::core::ptr::NonNull;
+    }
+
+    #[cfg(feature = "zerocopy")]
+    pub mod zerocopy {
+        pub use ::zerocopy::{AsBytes, FromBytes, FromZeroes, Unaligned};
+    }
+
+    #[cfg(feature = "bytemuck")]
+    pub mod bytemuck {
+        pub use ::bytemuck::{Pod, Zeroable};
+    }
+
+    use core::mem::size_of_val;
+    use core::ptr::NonNull;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::buf::LoadOwned;
+    pub use crate::buf::load::{LoadRef, LoadRefMut};
+    pub use crate::buf::{Buf, DebugIn, DebugValue, EqIn, HashIn, Visit};
     pub use crate::endian::ByteOrder;
-    pub use crate::traits::{ZeroCopy, ZeroSized};
+    pub use crate::pointer::pointee::sealed::Sealed as PointeeSealed;
+    pub use crate::pointer::fields::{FieldOffset, Fields};
+    pub use crate::pointer::{Packable, Pointee, Ref, Size};
+    pub use crate::traits::sealed::Sealed as UnsizedZeroCopySealed;
+    pub use crate::traits::{UnsizedZeroCopy, ZeroCopy, ZeroSized};
+
+    use crate::buf::{self, Validator};
+    use crate::error::{Error, ErrorKind};
 
     #[inline(always)]
     pub fn unknown_discriminant<D>(discriminant: D)
     where
-        D: fmt::Display,
+        D: ::core::fmt::Display,
     {
         core::unreachable!("Unknown discriminant `{discriminant}`, this is a bug since it should be present in the type being padded.")
     }
+
+    /// Calculate the offset of the trailing field of a `repr(C)` struct with
+    /// the given leading `(size, align)` fields.
+    pub const fn tail_offset(fields: &[(usize, usize)], tail_align: usize) -> usize {
+        let mut offset = 0;
+        let mut n = 0;
+
+        while n < fields.len() {
+            let (size, align) = fields[n];
+            offset += buf::padding_to(offset, align) + size;
+            n += 1;
+        }
+
+        offset + buf::padding_to(offset, tail_align)
+    }
+
+    /// Calculate the offset of the field at `index` of a `repr(C)` struct with
+    /// the given `(size, align)` fields, where alignments are capped to
+    /// `packed`.
+    pub const fn field_offset(fields: &[(usize, usize)], packed: usize, index: usize) -> usize {
+        let mut offset = 0;
+        let mut n = 0;
+
+        loop {
+            let (size, align) = fields[n];
+            let align = if align < packed { align } else { packed };
+            offset += buf::padding_to(offset, align);
+
+            if n == index {
+                return offset;
+            }
+
+            offset += size;
+            n += 1;
+        }
+    }
+
+    /// Calculate the largest of the given alignments.
+    pub const fn max_align(aligns: &[usize]) -> usize {
+        let mut max = 1;
+        let mut n = 0;
+
+        while n < aligns.len() {
+            if aligns[n] > max {
+                max = aligns[n];
+            }
+
+            n += 1;
+        }
+
+        max
+    }
+
+    /// Construct a validator over the header of an unsized struct, ensuring
+    /// that the header fits within `len`.
+    #[inline]
+    pub unsafe fn header_validator<'a, T>(
+        data: NonNull<u8>,
+        len: usize,
+        header: usize,
+    ) -> Result<Validator<'a, T>, Error>
+    where
+        T: ?Sized,
+    {
+        if header > len {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds {
+                range: 0..header,
+                len,
+            }));
+        }
+
+        Ok(Validator::new(data))
+    }
+
+    /// Validate the trailing field of an unsized struct at `offset`, ensuring
+    /// that the whole struct including any trailing padding up to `align` fits
+    /// within `len`.
+    #[inline]
+    pub unsafe fn validate_tail<T, E, O>(
+        data: NonNull<u8>,
+        len: usize,
+        offset: usize,
+        align: usize,
+        metadata: <T::Metadata as Packable>::Packed<O>,
+    ) -> Result<T::Metadata, Error>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+        E: ByteOrder,
+        O: Size,
+    {
+        let tail = NonNull::new_unchecked(data.as_ptr().add(offset));
+        let metadata = T::validate_unsized::<E, O>(tail, len - offset, metadata)?;
+
+        let end = offset + size_of_val(&*T::with_metadata(tail, metadata));
+        let end = end + buf::padding_to(end, align);
+
+        if end > len {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds {
+                range: 0..end,
+                len,
+            }));
+        }
+
+        Ok(metadata)
+    }
+
+    /// Construct a pointer to an unsized struct whose trailing field has
+    /// `len` as its metadata.
+    #[inline]
+    pub unsafe fn slice_dst<T>(data: NonNull<u8>, len: usize) -> *const T
+    where
+        T: ?Sized,
+    {
+        union Cast<T: ?Sized> {
+            from: *const [u8],
+            to: *const T,
+        }
+
+        Cast {
+            from: core::ptr::slice_from_raw_parts(data.as_ptr(), len),
+        }
+        .to
+    }
+
+    /// Construct a mutable pointer to an unsized struct whose trailing field
+    /// has `len` as its metadata.
+    #[inline]
+    pub unsafe fn slice_dst_mut<T>(data: NonNull<u8>, len: usize) -> *mut T
+    where
+        T: ?Sized,
+    {
+        union Cast<T: ?Sized> {
+            from: *mut [u8],
+            to: *mut T,
+        }
+
+        Cast {
+            from: core::ptr::slice_from_raw_parts_mut(data.as_ptr(), len),
+        }
+        .to
+    }
 }
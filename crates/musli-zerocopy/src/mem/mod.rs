@@ -2,3 +2,6 @@
 
 pub use self::maybe_uninit::MaybeUninit;
 mod maybe_uninit;
+
+pub use self::uninit_slice::UninitSlice;
+mod uninit_slice;
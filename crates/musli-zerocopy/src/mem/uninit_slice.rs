@@ -0,0 +1,115 @@
+use core::fmt;
+
+use crate::endian::{ByteOrder, Native};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// A reference to a slice which might or might not have been initialized.
+///
+/// This is constructed through [`OwnedBuf::store_uninit_slice`], and its
+/// elements can be written through [`OwnedBuf::load_uninit_slice_mut`]. Once
+/// every element has been written, [`UninitSlice::assume_init`] produces a
+/// reference to the initialized slice.
+///
+/// Since the memory of the slice is zeroed when it's stored, this is not
+/// unsafe. Loading a slice which has not been fully written will at worst
+/// produce garbled values or fail validation.
+///
+/// [`OwnedBuf::store_uninit_slice`]: crate::buf::OwnedBuf::store_uninit_slice
+/// [`OwnedBuf::load_uninit_slice_mut`]: crate::buf::OwnedBuf::load_uninit_slice_mut
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let slice = buf.store_uninit_slice(2);
+///
+/// let first = buf.store_unsized("first");
+/// let second = buf.store_unsized("second");
+///
+/// let elements = buf.load_uninit_slice_mut(slice);
+/// elements[0].write(&first);
+/// elements[1].write(&second);
+///
+/// let slice = slice.assume_init();
+/// assert_eq!(slice.offset(), 0);
+///
+/// let strings = buf.load(slice)?;
+/// assert_eq!(buf.load(strings[0])?, "first");
+/// assert_eq!(buf.load(strings[1])?, "second");
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct UninitSlice<T, E: ByteOrder = Native, O: Size = DefaultSize>
+where
+    T: ZeroCopy,
+{
+    slice: Ref<[T], E, O>,
+}
+
+impl<T, E: ByteOrder, O: Size> UninitSlice<T, E, O>
+where
+    T: ZeroCopy,
+{
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn new(slice: Ref<[T], E, O>) -> Self {
+        Self { slice }
+    }
+
+    /// Get the offset of the slice.
+    #[inline]
+    pub fn offset(self) -> usize {
+        self.slice.offset()
+    }
+
+    /// Get the number of elements in the slice.
+    #[inline]
+    pub fn len(self) -> usize {
+        self.slice.len()
+    }
+
+    /// Test if the slice is empty.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.slice.is_empty()
+    }
+
+    /// Assume that every element in the slice is initialized.
+    ///
+    /// Like [`Ref::assume_init`] this isn't unsafe, since the slice would
+    /// have to be validated in order to be loaded anyways.
+    ///
+    /// [`Ref::assume_init`]: crate::Ref::assume_init
+    #[inline]
+    pub fn assume_init(self) -> Ref<[T], E, O> {
+        self.slice
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> fmt::Debug for UninitSlice<T, E, O>
+where
+    T: ZeroCopy,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UninitSlice")
+            .field("offset", &self.offset())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> Clone for UninitSlice<T, E, O>
+where
+    T: ZeroCopy,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E: ByteOrder, O: Size> Copy for UninitSlice<T, E, O> where T: ZeroCopy {}
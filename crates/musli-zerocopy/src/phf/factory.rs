@@ -1,11 +1,15 @@
 #![allow(clippy::type_complexity)]
 
 use core::hash::Hash;
+use core::mem::size_of;
 
-use crate::buf::{StoreBuf, Visit};
+use alloc::vec::Vec;
+
+use crate::buf::sort::try_sort_by;
+use crate::buf::{StoreBuf, StoreReport, Visit};
 use crate::error::Error;
-use crate::phf::hashing::HashKey;
-use crate::phf::{Entry, MapRef, SetRef};
+use crate::phf::generator::HashState;
+use crate::phf::{Entry, Hasher, MapRef, MultimapRef, SetRef, Sip};
 use crate::Ref;
 use crate::ZeroCopy;
 
@@ -71,9 +75,362 @@ where
     I: IntoIterator<Item = (K, V)>,
     I::IntoIter: ExactSizeIterator,
 {
+    store_map_with_hasher(buf, entries, Sip)
+}
+
+/// Store a map based on a perfect hash function into a buffer using the
+/// custom `hasher`.
+///
+/// The hasher is part of the type of the returned [`MapRef`], so the map can
+/// only be queried using the same hasher.
+///
+/// See [`store_map`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf::{self, Fx};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let first = buf.store_unsized("first");
+/// let second = buf.store_unsized("second");
+///
+/// let map = phf::store_map_with_hasher(&mut buf, [(first, 1u32), (second, 2u32)], Fx)?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get("second")?, Some(&2));
+/// assert_eq!(map.get("third")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_hasher<H, K, V, S, I>(
+    buf: &mut S,
+    entries: I,
+    _hasher: H,
+) -> Result<MapRef<K, V, S::ByteOrder, S::Size, H>, Error>
+where
+    H: Hasher,
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, V)>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let entries = entries.into_iter().map(|(k, v)| Entry::new(k, v));
+    let (state, entries, displacements) =
+        store_raw::<H, _, _, _, _>(buf, entries, |entry| &entry.key)?;
+    Ok(MapRef::new(state.key, entries, displacements))
+}
+
+/// Store a map based on a perfect hash function into a buffer, returning a
+/// [`StoreReport`] with statistics about its construction.
+///
+/// See [`store_map`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let (map, report) = phf::store_map_with_report(&mut buf, (0..64u32).map(|n| (n, n * 2)))?;
+///
+/// assert_eq!(report.entries, 64);
+/// assert_eq!(report.buckets, 13);
+/// assert_eq!(report.bytes, buf.len());
+///
+/// let map = buf.bind(map)?;
+/// assert_eq!(map.get(&10u32)?, Some(&20));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_report<K, V, S, I>(
+    buf: &mut S,
+    entries: I,
+) -> Result<(MapRef<K, V, S::ByteOrder, S::Size>, StoreReport), Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, V)>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let start = buf.len();
     let entries = entries.into_iter().map(|(k, v)| Entry::new(k, v));
-    let (key, entries, displacements) = store_raw(buf, entries, |entry| &entry.key)?;
-    Ok(MapRef::new(key, entries, displacements))
+    let (state, entries, displacements) =
+        store_raw::<Sip, _, _, _, _>(buf, entries, |entry| &entry.key)?;
+
+    let report = StoreReport {
+        entries: entries.len(),
+        buckets: displacements.len(),
+        retries: state.retries,
+        bytes: buf.len() - start,
+    };
+
+    Ok((MapRef::new(state.key, entries, displacements), report))
+}
+
+/// Store a map based on a perfect hash function into a buffer, using
+/// `store_key` to materialize each key into the buffer as the map is being
+/// constructed.
+///
+/// This is useful when converting an existing collection, like a `HashMap`
+/// or a `BTreeMap`, since the keys don't have to be stored up front and
+/// collected before calling [`store_map`]. Keys are stored after the entries
+/// of the map in the buffer.
+///
+/// See [`store_map`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf;
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::from("first"), 1u32);
+/// map.insert(String::from("second"), 2u32);
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_map_with_keys(&mut buf, map, |buf, key| {
+///     buf.store_unsized(key.as_str())
+/// })?;
+///
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get("second")?, Some(&2));
+/// assert_eq!(map.get("third")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_keys<Q, K, V, I, S, F>(
+    buf: &mut S,
+    entries: I,
+    store_key: F,
+) -> Result<MapRef<K, V, S::ByteOrder, S::Size>, Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    I: IntoIterator<Item = (Q, V)>,
+    I::IntoIter: ExactSizeIterator,
+    S: ?Sized + StoreBuf,
+    F: FnMut(&mut S, Q) -> K,
+{
+    store_map_with_keys_and_hasher(buf, entries, Sip, store_key)
+}
+
+/// Store a map based on a perfect hash function into a buffer using the
+/// custom `hasher`, using `store_key` to materialize each key into the buffer
+/// as the map is being constructed.
+///
+/// The hasher is part of the type of the returned [`MapRef`], so the map can
+/// only be queried using the same hasher.
+///
+/// See [`store_map_with_keys`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf::{self, Fx};
+///
+/// let map = HashMap::from([(String::from("first"), 1u32), (String::from("second"), 2u32)]);
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_map_with_keys_and_hasher(&mut buf, map, Fx, |buf, key| {
+///     buf.store_unsized(key.as_str())
+/// })?;
+///
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get("second")?, Some(&2));
+/// assert_eq!(map.get("third")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_keys_and_hasher<H, Q, K, V, I, S, F>(
+    buf: &mut S,
+    entries: I,
+    _hasher: H,
+    mut store_key: F,
+) -> Result<MapRef<K, V, S::ByteOrder, S::Size, H>, Error>
+where
+    H: Hasher,
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    I: IntoIterator<Item = (Q, V)>,
+    I::IntoIter: ExactSizeIterator,
+    S: ?Sized + StoreBuf,
+    F: FnMut(&mut S, Q) -> K,
+{
+    let iter = entries.into_iter();
+    let len = iter.len();
+
+    // Reserve space for the entries up front, so that keys can be stored
+    // after them without an intermediate allocation.
+    let offset = buf.next_offset::<Entry<K, V>>();
+    buf.fill(0, size_of::<Entry<K, V>>().wrapping_mul(len));
+
+    let mut count = 0;
+
+    for (q, v) in iter.take(len) {
+        let k = store_key(buf, q);
+        let at = Ref::<Entry<K, V>, S::ByteOrder, S::Size>::new(
+            offset + count * size_of::<Entry<K, V>>(),
+        );
+        buf.as_mut_buf().store_at(at, &Entry::new(k, v))?;
+        count += 1;
+    }
+
+    let entries = Ref::with_metadata(offset, count);
+    let (state, displacements) =
+        build_hash::<H, _, _, _, _>(buf, entries, |entry: &Entry<K, V>| &entry.key)?;
+    Ok(MapRef::new(state.key, entries, displacements))
+}
+
+/// Store a multimap based on a perfect hash function into a buffer.
+///
+/// Entries which share the same key are grouped together, and the values of
+/// each group are stored as a single slice in the order in which they were
+/// provided. The perfect hash function is then constructed over the distinct
+/// keys.
+///
+/// Grouping is performed by sorting the entries by key rather than by hashing
+/// them, which is why keys must also implement [`Ord`].
+///
+/// This returns a [`MultimapRef`] which can be bound into a [`Multimap`]
+/// through the [`bind()`] method for convenience.
+///
+/// [`Multimap`]: crate::phf::Multimap
+/// [`bind()`]: crate::buf::Buf::bind
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let hello = buf.store_unsized("hello");
+/// let world = buf.store_unsized("world");
+///
+/// let map = phf::store_multimap(&mut buf, [(hello, 1u32), (world, 2), (hello, 3)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map.get("hello")?, &[1, 3]);
+/// assert_eq!(map.get("world")?, &[2]);
+/// assert_eq!(map.get("other")?, &[]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_multimap<K, V, S, I>(
+    buf: &mut S,
+    entries: I,
+) -> Result<MultimapRef<K, V, S::ByteOrder, S::Size>, Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Ord + Hash,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, V)>,
+{
+    store_multimap_with_hasher(buf, entries, Sip)
+}
+
+/// Store a multimap based on a perfect hash function into a buffer using the
+/// custom `hasher`.
+///
+/// The hasher is part of the type of the returned [`MultimapRef`], so the map
+/// can only be queried using the same hasher.
+///
+/// See [`store_multimap`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf::{self, Fx};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_multimap_with_hasher(&mut buf, [(1u32, 2u32), (1, 3)], Fx)?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1u32)?, &[2, 3]);
+/// assert!(map.get(&2u32)?.is_empty());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_multimap_with_hasher<H, K, V, S, I>(
+    buf: &mut S,
+    entries: I,
+    _hasher: H,
+) -> Result<MultimapRef<K, V, S::ByteOrder, S::Size, H>, Error>
+where
+    H: Hasher,
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Ord + Hash,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+
+    // Keys might reference data which is not yet aligned in the buffer.
+    buf.align_in_place();
+
+    let compare = |a: &K, b: &K| a.visit(buf.as_buf(), |a| b.visit(buf.as_buf(), |b| a.cmp(b)))?;
+
+    // NB: The sort is stable, so values which share a key retain the order in
+    // which they were provided.
+    try_sort_by(&mut entries, |(a, _), (b, _)| compare(a, b))?;
+
+    let mut keys = Vec::new();
+    let mut counts = Vec::new();
+    let mut values = Vec::with_capacity(entries.len());
+
+    for (key, value) in entries {
+        match (keys.last(), counts.last_mut()) {
+            (Some(previous), Some(count)) if compare(previous, &key)?.is_eq() => {
+                *count += 1;
+            }
+            _ => {
+                keys.push(key);
+                counts.push(1);
+            }
+        }
+
+        values.push(value);
+    }
+
+    let mut values = build_slice(buf, values);
+
+    let groups = keys.into_iter().zip(counts).map(|(key, count)| {
+        let (group, rest) = values.split_at(count);
+        values = rest;
+        Entry::new(key, group)
+    });
+
+    let (state, entries, displacements) =
+        store_raw::<H, _, _, _, _>(buf, groups, |entry| &entry.key)?;
+    Ok(MultimapRef::new(MapRef::new(
+        state.key,
+        entries,
+        displacements,
+    )))
 }
 
 /// Store a set based on a perfect hash function into a buffer.
@@ -139,23 +496,64 @@ where
     <I::Item as Visit>::Target: Hash,
     I::IntoIter: ExactSizeIterator,
 {
-    let (key, entries, displacements) = store_raw(buf, entries, |entry| entry)?;
-    Ok(SetRef::new(key, entries, displacements))
+    store_set_with_hasher(buf, entries, Sip)
+}
+
+/// Store a set based on a perfect hash function into a buffer using the
+/// custom `hasher`.
+///
+/// The hasher is part of the type of the returned [`SetRef`], so the set can
+/// only be queried using the same hasher.
+///
+/// See [`store_set`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf::{self, Fx};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let set = phf::store_set_with_hasher(&mut buf, [1u32, 2], Fx)?;
+/// let set = buf.bind(set)?;
+///
+/// assert!(set.contains(&1u32)?);
+/// assert!(set.contains(&2u32)?);
+/// assert!(!set.contains(&3u32)?);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_set_with_hasher<H, S, I>(
+    buf: &mut S,
+    entries: I,
+    _hasher: H,
+) -> Result<SetRef<I::Item, S::ByteOrder, S::Size, H>, Error>
+where
+    H: Hasher,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator,
+    I::Item: Visit + ZeroCopy,
+    <I::Item as Visit>::Target: Hash,
+    I::IntoIter: ExactSizeIterator,
+{
+    let (state, entries, displacements) = store_raw::<H, _, _, _, _>(buf, entries, |entry| entry)?;
+    Ok(SetRef::new(state.key, entries, displacements))
 }
 
-fn store_raw<K, I, S, F>(
+fn store_raw<H, K, I, S, F>(
     buf: &mut S,
     entries: I,
     access: F,
 ) -> Result<
     (
-        HashKey,
+        HashState,
         Ref<[I::Item], S::ByteOrder, S::Size>,
         Ref<[Entry<u32, u32>], S::ByteOrder, S::Size>,
     ),
     Error,
 >
 where
+    H: Hasher,
     K: Visit + ZeroCopy,
     K::Target: Hash,
     I: IntoIterator,
@@ -165,6 +563,25 @@ where
     F: Fn(&I::Item) -> &K,
 {
     let entries = build_slice(buf, entries);
+    let (state, displacements) = build_hash::<H, _, _, _, _>(buf, entries, access)?;
+    Ok((state, entries, displacements))
+}
+
+// Construct the perfect hash function over entries which have already been
+// stored in the buffer, reordering them accordingly.
+fn build_hash<H, K, T, S, F>(
+    buf: &mut S,
+    entries: Ref<[T], S::ByteOrder, S::Size>,
+    access: F,
+) -> Result<(HashState, Ref<[Entry<u32, u32>], S::ByteOrder, S::Size>), Error>
+where
+    H: Hasher,
+    K: Visit + ZeroCopy,
+    K::Target: Hash,
+    T: ZeroCopy,
+    S: ?Sized + StoreBuf,
+    F: Fn(&T) -> &K,
+{
     let len = crate::phf::generator::displacements_len(entries.len());
     let displacements = build_slice(buf, (0..len).map(|_| Entry::new(0, 0)));
 
@@ -174,7 +591,7 @@ where
 
     let hash_state = {
         buf.align_in_place();
-        crate::phf::generator::generate_hash(
+        crate::phf::generator::generate_hash::<H, _, _, _, _, _>(
             buf.as_mut_buf(),
             &entries,
             &displacements,
@@ -199,7 +616,7 @@ where
 
     // Free up temporary memory we needed to build the map.
     buf.truncate(len);
-    Ok((hash_state.key, entries, displacements))
+    Ok((hash_state, displacements))
 }
 
 fn build_slice<S, I>(buf: &mut S, entries: I) -> Ref<[I::Item], S::ByteOrder, S::Size>
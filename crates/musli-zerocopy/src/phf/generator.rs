@@ -1,3 +1,4 @@
+use core::cmp::Reverse;
 use core::hash::Hash;
 
 use alloc::vec;
@@ -6,7 +7,7 @@ use alloc::vec::Vec;
 use crate::buf::{Buf, Visit};
 use crate::error::{Error, ErrorKind};
 use crate::phf::hashing::{displace, hash, HashKey, Hashes};
-use crate::phf::Entry;
+use crate::phf::{Entry, Hasher};
 use crate::{ByteOrder, Ref, Size, ZeroCopy};
 
 use rand::distributions::Standard;
@@ -15,9 +16,19 @@ use rand::{Rng, SeedableRng};
 
 const DEFAULT_LAMBDA: usize = 5;
 const FIXED_SEED: u64 = 1234567890;
+/// The number of hash keys to try before giving up on generating a map.
+const MAX_ATTEMPTS: usize = 64;
+
+struct Bucket {
+    index: usize,
+    keys: Vec<usize>,
+}
 
 pub(crate) struct HashState {
     pub(crate) key: HashKey,
+    /// The number of keys which were discarded before a perfect hash was
+    /// found.
+    pub(crate) retries: usize,
 }
 
 /// Calculate displacements length.
@@ -25,7 +36,7 @@ pub(crate) fn displacements_len(len: usize) -> usize {
     (len + DEFAULT_LAMBDA - 1) / DEFAULT_LAMBDA
 }
 
-pub(crate) fn generate_hash<K, T, F, E: ByteOrder, O: Size>(
+pub(crate) fn generate_hash<H, K, T, F, E: ByteOrder, O: Size>(
     buf: &mut Buf,
     entries: &Ref<[T], E, O>,
     displacements: &Ref<[Entry<u32, u32>], E, O>,
@@ -33,14 +44,19 @@ pub(crate) fn generate_hash<K, T, F, E: ByteOrder, O: Size>(
     access: F,
 ) -> Result<HashState, Error>
 where
+    H: Hasher,
     K: Visit,
     K::Target: Hash,
     F: Fn(&T) -> &K,
     T: ZeroCopy,
 {
-    for key in SmallRng::seed_from_u64(FIXED_SEED).sample_iter(Standard) {
-        if let Some(hash) = try_generate_hash(buf, entries, displacements, map, key, &access)? {
-            return Ok(hash);
+    let keys = SmallRng::seed_from_u64(FIXED_SEED).sample_iter(Standard);
+
+    for (retries, key) in keys.take(MAX_ATTEMPTS).enumerate() {
+        if let Some(key) =
+            try_generate_hash::<H, _, _, _, _, _>(buf, entries, displacements, map, key, &access)?
+        {
+            return Ok(HashState { key, retries });
         }
 
         // Reset the state of displacements and maps since we're trying again.
@@ -56,15 +72,16 @@ where
     Err(Error::new(ErrorKind::FailedPhf))
 }
 
-fn try_generate_hash<K, T, F, E: ByteOrder, O: Size>(
+fn try_generate_hash<H, K, T, F, E: ByteOrder, O: Size>(
     buf: &mut Buf,
     entries: &Ref<[T], E, O>,
     displacements: &Ref<[Entry<u32, u32>], E, O>,
     map: &Ref<[usize], E, O>,
     key: HashKey,
     access: &F,
-) -> Result<Option<HashState>, Error>
+) -> Result<Option<HashKey>, Error>
 where
+    H: Hasher,
     K: Visit,
     K::Target: Hash,
     F: ?Sized + Fn(&T) -> &K,
@@ -75,18 +92,25 @@ where
     for entry in entries.iter() {
         let entry = buf.load(entry)?;
         let entry_key = access(entry);
-        let h = hash(buf, entry_key, &key)?;
+        let h = hash::<H, _>(buf, entry_key, &key)?;
         hashes.push(h);
     }
 
-    let mut buckets = vec![Vec::<usize>::new(); displacements.len()];
+    let mut buckets = (0..displacements.len())
+        .map(|index| Bucket {
+            index,
+            keys: Vec::new(),
+        })
+        .collect::<Vec<_>>();
 
     for (index, hash) in hashes.iter().enumerate() {
         let to = hash.g % buckets.len();
-        buckets[to].push(index);
+        buckets[to].keys.push(index);
     }
 
-    buckets.sort_by_key(|a| a.len());
+    // Place the largest buckets first, while the table still has the most
+    // free slots.
+    buckets.sort_by_key(|bucket| Reverse(bucket.keys.len()));
 
     let table_len = hashes.len();
     // let mut map = vec![usize::MAX; table_len];
@@ -99,6 +123,8 @@ where
     // are equal. (A u64 is far too large to overflow in a reasonable
     // time for current hardware.)
     let mut try_map = vec![0u64; table_len];
+    // Slots which have been claimed by buckets placed so far.
+    let mut occupied = vec![false; table_len];
     let mut generation = 0u64;
 
     // the actual values corresponding to the markers above, as
@@ -106,18 +132,20 @@ where
     // chosen the right displacements.
     let mut values_to_add = vec![];
 
-    'outer: for (bucket, d_ref) in buckets.iter().zip(displacements.iter()) {
+    'outer: for bucket in &buckets {
+        let d_ref = displacements.at(bucket.index);
+
         for d1 in 0..(table_len as u32) {
             'inner: for d2 in 0..(table_len as u32) {
                 values_to_add.clear();
                 generation += 1;
 
-                for &key in bucket {
+                for &key in &bucket.keys {
                     let Hashes { f1, f2, .. } = hashes[key];
                     let index = displace(f1, f2, d1, d2) as usize;
                     let index = index % table_len;
 
-                    if *buf.load(map.at(index))? != usize::MAX || try_map[index] == generation {
+                    if occupied[index] || try_map[index] == generation {
                         continue 'inner;
                     }
 
@@ -128,9 +156,10 @@ where
                 // We've picked a good set of displacements
                 *buf.load_mut(d_ref)? = Entry::new(d1, d2);
 
+                // NB: The map records which slot each entry is moved to.
                 for &(i, key) in &values_to_add {
-                    let m = buf.load_mut(map.at(i))?;
-                    *m = key;
+                    occupied[i] = true;
+                    *buf.load_mut(map.at(key))? = i;
                 }
 
                 continue 'outer;
@@ -141,5 +170,5 @@ where
         return Ok(None);
     }
 
-    Ok(Some(HashState { key }))
+    Ok(Some(key))
 }
@@ -0,0 +1,193 @@
+//! Hashers which can be used to construct and query perfect hash maps and
+//! sets.
+//!
+//! The hasher used is part of the type of a [`MapRef`] or [`SetRef`], which
+//! ensures that a map is queried using the same hasher it was constructed
+//! with. By default [`Sip`] is used.
+//!
+//! [`MapRef`]: crate::phf::MapRef
+//! [`SetRef`]: crate::phf::SetRef
+
+use core::hash::{self, Hash};
+
+use crate::sip::{Hash128, Hasher128, SipHasher13};
+
+/// A hasher used by perfect hash maps and sets.
+///
+/// Implementations must be deterministic across platforms and program runs,
+/// since a map which is constructed using one hasher is only useful if it
+/// can be queried using the same hasher.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf::{self, Fx};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_map_with_hasher(&mut buf, [(1u32, 2u32), (2, 3)], Fx)?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1u32)?, Some(&2));
+/// assert_eq!(map.get(&2u32)?, Some(&3));
+/// assert_eq!(map.get(&3u32)?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait Hasher: 'static {
+    /// Hash the given `value` using `seed`.
+    fn hash<T>(value: &T, seed: u64) -> u64
+    where
+        T: ?Sized + Hash;
+
+    /// Hash the given `value` using `seed` into two 64-bit words.
+    ///
+    /// By default this derives the second word by mixing the output of
+    /// [`Hasher::hash`], hashers which natively produce wider output should
+    /// override it.
+    #[inline]
+    fn hash128<T>(value: &T, seed: u64) -> (u64, u64)
+    where
+        T: ?Sized + Hash,
+    {
+        let h1 = Self::hash(value, seed);
+        (h1, mix(h1))
+    }
+}
+
+/// The default hasher for perfect hash maps and sets, based on SipHash 1-3.
+///
+/// This is resistant to HashDoS attacks, which makes it a good default when
+/// the keys being stored or queried are not trusted.
+#[derive(Debug, Clone, Copy)]
+pub struct Sip;
+
+impl Hasher for Sip {
+    #[inline]
+    fn hash<T>(value: &T, seed: u64) -> u64
+    where
+        T: ?Sized + Hash,
+    {
+        Self::hash128(value, seed).0
+    }
+
+    #[inline]
+    fn hash128<T>(value: &T, seed: u64) -> (u64, u64)
+    where
+        T: ?Sized + Hash,
+    {
+        let mut hasher = SipHasher13::new_with_keys(0, seed);
+        value.hash(&mut hasher);
+        let Hash128 { h1, h2 } = hasher.finish128();
+        (h1, h2)
+    }
+}
+
+/// A fast non-cryptographic hasher based on the hash function used by
+/// `rustc`, commonly known as FxHash.
+///
+/// This provides faster lookups than [`Sip`], but must only be used with
+/// trusted input since it provides no resistance against HashDoS attacks.
+#[derive(Debug, Clone, Copy)]
+pub struct Fx;
+
+impl Hasher for Fx {
+    #[inline]
+    fn hash<T>(value: &T, seed: u64) -> u64
+    where
+        T: ?Sized + Hash,
+    {
+        let mut hasher = FxHasher { hash: mix(seed) };
+        value.hash(&mut hasher);
+        // NB: The raw FxHash state is poorly distributed in its low bits,
+        // which are used to compute displacements, so it is finalized here.
+        mix(hash::Hasher::finish(&hasher))
+    }
+}
+
+/// The multiplication constant used by FxHash.
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    #[inline]
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(FX_SEED);
+    }
+}
+
+impl hash::Hasher for FxHasher {
+    #[inline]
+    fn write(&mut self, mut bytes: &[u8]) {
+        while let Some((chunk, rest)) = split_array::<8>(bytes) {
+            self.add_to_hash(u64::from_le_bytes(*chunk));
+            bytes = rest;
+        }
+
+        if let Some((chunk, rest)) = split_array::<4>(bytes) {
+            self.add_to_hash(u32::from_le_bytes(*chunk) as u64);
+            bytes = rest;
+        }
+
+        for &b in bytes {
+            self.add_to_hash(b as u64);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        // NB: Always hash as 64-bit so that the hash is the same across
+        // platforms.
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[inline]
+fn split_array<const N: usize>(bytes: &[u8]) -> Option<(&[u8; N], &[u8])> {
+    if bytes.len() < N {
+        return None;
+    }
+
+    let (head, tail) = bytes.split_at(N);
+    Some((head.try_into().ok()?, tail))
+}
+
+/// Finalizer from MurmurHash3, used to derive a second word of output and to
+/// finalize [`Fx`].
+#[inline]
+fn mix(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    h ^= h >> 33;
+    h
+}
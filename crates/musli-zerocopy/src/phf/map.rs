@@ -14,12 +14,14 @@
 
 use core::borrow::Borrow;
 use core::hash::Hash;
+use core::marker::PhantomData;
+use core::mem::{size_of, size_of_val};
 
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
 use crate::error::Error;
 use crate::phf::hashing::HashKey;
-use crate::phf::Entry;
+use crate::phf::{Entry, Hasher, Sip};
 use crate::pointer::{DefaultSize, Ref, Size};
 use crate::{Endian, ZeroCopy};
 
@@ -44,15 +46,17 @@ use crate::{Endian, ZeroCopy};
 /// assert!(!map.contains_key(&3)?);
 /// # Ok::<_, musli_zerocopy::Error>(())
 /// ```
-pub struct Map<'a, K, V> {
+pub struct Map<'a, K, V, H = Sip> {
     key: HashKey,
     entries: &'a [Entry<K, V>],
     displacements: &'a [Entry<u32, u32>],
     buf: &'a Buf,
+    _marker: PhantomData<H>,
 }
 
-impl<'a, K, V> Map<'a, K, V>
+impl<'a, K, V, H> Map<'a, K, V, H>
 where
+    H: Hasher,
     K: ZeroCopy,
     V: ZeroCopy,
 {
@@ -145,7 +149,7 @@ where
             return Ok(None);
         }
 
-        let hashes = crate::phf::hashing::hash(self.buf, key, &self.key)?;
+        let hashes = crate::phf::hashing::hash::<H, _>(self.buf, key, &self.key)?;
         let index =
             crate::phf::hashing::get_index(&hashes, self.displacements, self.entries.len())?;
 
@@ -159,15 +163,79 @@ where
             Ok(None)
         }
     }
+
+    /// Get the number of entries in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of entries the map has space for.
+    ///
+    /// Since a perfect hash map is constructed for a fixed set of keys, this
+    /// is always the same as [`len()`].
+    ///
+    /// [`len()`]: Self::len
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Get the number of bytes used by the displacement table of the map,
+    /// which is the storage needed in addition to the entries themselves.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use core::mem::size_of;
+    ///
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let (map, report) = phf::store_map_with_report(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.table_overhead_bytes(), report.buckets * 2 * size_of::<u32>());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn table_overhead_bytes(&self) -> usize {
+        size_of_val(self.displacements)
+    }
 }
 
 /// Bind a [`MapRef`] into a [`Map`].
-impl<K, V, E: ByteOrder, O: Size> Bindable for MapRef<K, V, E, O>
+impl<K, V, E: ByteOrder, O: Size, H> Bindable for MapRef<K, V, E, O, H>
 where
+    H: Hasher,
     K: ZeroCopy,
     V: ZeroCopy,
 {
-    type Bound<'a> = Map<'a, K, V> where Self: 'a;
+    type Bound<'a> = Map<'a, K, V, H> where Self: 'a;
 
     #[inline]
     fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
@@ -176,6 +244,7 @@ where
             entries: buf.load(self.entries)?,
             displacements: buf.load(self.displacements)?,
             buf,
+            _marker: PhantomData,
         })
     }
 }
@@ -213,7 +282,7 @@ where
 #[derive(Debug, ZeroCopy)]
 #[repr(C)]
 #[zero_copy(crate)]
-pub struct MapRef<K, V, E: ByteOrder = Native, O: Size = DefaultSize>
+pub struct MapRef<K, V, E: ByteOrder = Native, O: Size = DefaultSize, H = Sip>
 where
     K: ZeroCopy,
     V: ZeroCopy,
@@ -221,10 +290,13 @@ where
     key: Endian<HashKey, E>,
     entries: Ref<[Entry<K, V>], E, O>,
     displacements: Ref<[Entry<u32, u32>], E, O>,
+    #[zero_copy(ignore)]
+    _marker: PhantomData<H>,
 }
 
-impl<K, V, E: ByteOrder, O: Size> MapRef<K, V, E, O>
+impl<K, V, E: ByteOrder, O: Size, H> MapRef<K, V, E, O, H>
 where
+    H: Hasher,
     K: ZeroCopy,
     V: ZeroCopy,
 {
@@ -238,12 +310,14 @@ where
             key: Endian::new(key),
             entries,
             displacements,
+            _marker: PhantomData,
         }
     }
 }
 
-impl<K, V, E: ByteOrder, O: Size> MapRef<K, V, E, O>
+impl<K, V, E: ByteOrder, O: Size, H> MapRef<K, V, E, O, H>
 where
+    H: Hasher,
     K: ZeroCopy,
     V: ZeroCopy,
 {
@@ -333,7 +407,7 @@ where
             return Ok(None);
         }
 
-        let hashes = crate::phf::hashing::hash(buf, key, &self.key.to_ne())?;
+        let hashes = crate::phf::hashing::hash::<H, _>(buf, key, &self.key.to_ne())?;
 
         let displacements = |index| match self.displacements.get(index) {
             Some(entry) => Ok(Some(buf.load(entry)?)),
@@ -359,9 +433,68 @@ where
             Ok(None)
         }
     }
+
+    /// Get the number of entries in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the number of entries the map has space for.
+    ///
+    /// Since a perfect hash map is constructed for a fixed set of keys, this
+    /// is always the same as [`len()`].
+    ///
+    /// [`len()`]: Self::len
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Get the number of bytes used by the displacement table of the map,
+    /// which is the storage needed in addition to the entries themselves.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    ///
+    /// assert_eq!(map.table_overhead_bytes(), 16);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn table_overhead_bytes(&self) -> usize {
+        self.displacements.len() * size_of::<Entry<u32, u32>>()
+    }
 }
 
-impl<K, V, E: ByteOrder, O: Size> Clone for MapRef<K, V, E, O>
+impl<K, V, E: ByteOrder, O: Size, H> Clone for MapRef<K, V, E, O, H>
 where
     K: ZeroCopy,
     V: ZeroCopy,
@@ -371,7 +504,7 @@ where
     }
 }
 
-impl<K, V, E: ByteOrder, O: Size> Copy for MapRef<K, V, E, O>
+impl<K, V, E: ByteOrder, O: Size, H> Copy for MapRef<K, V, E, O, H>
 where
     K: ZeroCopy,
     V: ZeroCopy,
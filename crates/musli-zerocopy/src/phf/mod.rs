@@ -5,6 +5,13 @@
 //!
 //! For very large maps and sets, prefer to use [`swiss`] instead.
 //!
+//! Keys which map to multiple values can be stored in a [`Multimap`] through
+//! [`store_multimap`].
+//!
+//! Maps and sets are hashed using [`Sip`] by default, a different [`Hasher`]
+//! such as [`Fx`] can be used through [`store_map_with_hasher`] and
+//! [`store_set_with_hasher`].
+//!
 //! [`swiss`]: crate::swiss
 
 // Map internals copied from rust-phf under the MIT license.
@@ -17,6 +24,10 @@ pub(crate) mod generator;
 
 pub(crate) mod hashing;
 
+#[doc(inline)]
+pub use self::hasher::{Fx, Hasher, Sip};
+pub mod hasher;
+
 pub(crate) use self::entry::Entry;
 mod entry;
 
@@ -28,6 +39,10 @@ pub mod map;
 pub use self::set::{Set, SetRef};
 pub mod set;
 
+#[doc(inline)]
+pub use self::multimap::{Multimap, MultimapRef};
+pub mod multimap;
+
 #[cfg(feature = "alloc")]
 #[doc(inline)]
 pub use self::factory::*;
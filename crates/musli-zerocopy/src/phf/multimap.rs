@@ -0,0 +1,322 @@
+//! A map which implements a hash-map like interface, where each key maps to
+//! multiple values.
+//!
+//! This map are implemented using a perfect hash functions, and are inserted
+//! into a buffering using [`phf::store_multimap`].
+//!
+//! There's two types provided by this module:
+//! * [`Multimap<K, V>`] which is a *bound* reference to a multimap, providing
+//!   a convenient map-like access.
+//! * [`MultimapRef<K, V>`] which is the *pointer* of the multimap. This is
+//!   what you store in [`ZeroCopy`] types and is what is returned by
+//!   [`phf::store_multimap`].
+//!
+//! [`phf::store_multimap`]: crate::phf::store_multimap
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+
+use crate::buf::{Bindable, Buf, Visit};
+use crate::endian::{ByteOrder, Native};
+use crate::error::Error;
+use crate::phf::{Hasher, Map, MapRef, Sip};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::ZeroCopy;
+
+/// A multimap bound to a [`Buf`] through [`Buf::bind`] for convenience.
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1)?, &[2, 4]);
+/// assert_eq!(map.get(&2)?, &[3]);
+/// assert_eq!(map.get(&3)?, &[]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Multimap<'a, K, V, E: ByteOrder = Native, O: Size = DefaultSize, H = Sip>
+where
+    V: ZeroCopy,
+{
+    map: Map<'a, K, Ref<[V], E, O>, H>,
+    buf: &'a Buf,
+}
+
+impl<'a, K, V, E: ByteOrder, O: Size, H> Multimap<'a, K, V, E, O, H>
+where
+    H: Hasher,
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    /// Get the values associated with the given `key`, in the order they were
+    /// inserted.
+    ///
+    /// Returns an empty slice if the key is not present in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get(&1)?, &[2, 4]);
+    /// assert_eq!(map.get(&2)?, &[3]);
+    /// assert_eq!(map.get(&3)?, &[]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<T>(&self, key: &T) -> Result<&'a [V], Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let Some(values) = self.map.get(key)? else {
+            return Ok(&[]);
+        };
+
+        self.buf.load(*values)
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.contains_key(&1)?);
+    /// assert!(map.contains_key(&2)?);
+    /// assert!(!map.contains_key(&3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<T>(&self, key: &T) -> Result<bool, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Get the number of distinct keys in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Bind a [`MultimapRef`] into a [`Multimap`].
+impl<K, V, E: ByteOrder, O: Size, H> Bindable for MultimapRef<K, V, E, O, H>
+where
+    H: Hasher,
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    type Bound<'a> = Multimap<'a, K, V, E, O, H> where Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        Ok(Multimap {
+            map: self.map.bind(buf)?,
+            buf,
+        })
+    }
+}
+
+/// A stored reference to a multimap.
+///
+/// Note that operating over the methods provided in [`MultimapRef`] does not
+/// demand that the entire contents of the map is validated as would be the
+/// case when [`bind()`] is used and might result in better performance if the
+/// data is infrequently accessed.
+///
+/// Constructed through [`phf::store_multimap`].
+///
+/// [`phf::store_multimap`]: crate::phf::store_multimap
+/// [`bind()`]: crate::buf::Buf::bind
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::phf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+///
+/// assert_eq!(buf.load(map.get(&buf, &1)?)?, &[2, 4]);
+/// assert_eq!(buf.load(map.get(&buf, &2)?)?, &[3]);
+/// assert!(map.get(&buf, &3)?.is_empty());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct MultimapRef<K, V, E: ByteOrder = Native, O: Size = DefaultSize, H = Sip>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    map: MapRef<K, Ref<[V], E, O>, E, O, H>,
+}
+
+impl<K, V, E: ByteOrder, O: Size, H> MultimapRef<K, V, E, O, H>
+where
+    H: Hasher,
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn new(map: MapRef<K, Ref<[V], E, O>, E, O, H>) -> Self {
+        Self { map }
+    }
+
+    /// Get a reference to the values associated with the given `key`, in the
+    /// order they were inserted.
+    ///
+    /// Returns an empty reference if the key is not present in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    ///
+    /// assert_eq!(buf.load(map.get(&buf, &1)?)?, &[2, 4]);
+    /// assert_eq!(buf.load(map.get(&buf, &2)?)?, &[3]);
+    /// assert!(map.get(&buf, &3)?.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<T>(&self, buf: &Buf, key: &T) -> Result<Ref<[V], E, O>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let Some(values) = self.map.get(buf, key)? else {
+            return Ok(Ref::with_metadata(0usize, 0));
+        };
+
+        Ok(*values)
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    ///
+    /// assert!(map.contains_key(&buf, &1)?);
+    /// assert!(map.contains_key(&buf, &2)?);
+    /// assert!(!map.contains_key(&buf, &3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<T>(&self, buf: &Buf, key: &T) -> Result<bool, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        self.map.contains_key(buf, key)
+    }
+
+    /// Get the number of distinct keys in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_multimap(&mut buf, [(1, 2), (2, 3), (1, 4)])?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size, H> Clone for MultimapRef<K, V, E, O, H>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size, H> Copy for MultimapRef<K, V, E, O, H>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+}
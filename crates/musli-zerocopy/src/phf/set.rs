@@ -14,12 +14,13 @@
 
 use core::borrow::Borrow;
 use core::hash::Hash;
+use core::marker::PhantomData;
 
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
 use crate::error::Error;
 use crate::phf::hashing::HashKey;
-use crate::phf::Entry;
+use crate::phf::{Entry, Hasher, Sip};
 use crate::pointer::{DefaultSize, Ref, Size};
 use crate::{Endian, ZeroCopy};
 
@@ -41,15 +42,17 @@ use crate::{Endian, ZeroCopy};
 /// assert!(!set.contains(&3)?);
 /// # Ok::<_, musli_zerocopy::Error>(())
 /// ```
-pub struct Set<'a, T> {
+pub struct Set<'a, T, H = Sip> {
     key: HashKey,
     entries: &'a [T],
     displacements: &'a [Entry<u32, u32>],
     buf: &'a Buf,
+    _marker: PhantomData<H>,
 }
 
-impl<'a, T> Set<'a, T>
+impl<'a, T, H> Set<'a, T, H>
 where
+    H: Hasher,
     T: ZeroCopy,
 {
     /// Get a value from the set.
@@ -81,7 +84,7 @@ where
             return Ok(false);
         }
 
-        let hashes = crate::phf::hashing::hash(self.buf, key, &self.key)?;
+        let hashes = crate::phf::hashing::hash::<H, _>(self.buf, key, &self.key)?;
         let index =
             crate::phf::hashing::get_index(&hashes, self.displacements, self.entries.len())?;
 
@@ -94,11 +97,12 @@ where
 }
 
 /// Bind a [`SetRef`] into a [`Set`].
-impl<T, E: ByteOrder, O: Size> Bindable for SetRef<T, E, O>
+impl<T, E: ByteOrder, O: Size, H> Bindable for SetRef<T, E, O, H>
 where
+    H: Hasher,
     T: ZeroCopy,
 {
-    type Bound<'a> = Set<'a, T> where Self: 'a;
+    type Bound<'a> = Set<'a, T, H> where Self: 'a;
 
     #[inline]
     fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
@@ -107,6 +111,7 @@ where
             entries: buf.load(self.entries)?,
             displacements: buf.load(self.displacements)?,
             buf,
+            _marker: PhantomData,
         })
     }
 }
@@ -141,17 +146,20 @@ where
 #[derive(Debug, ZeroCopy)]
 #[repr(C)]
 #[zero_copy(crate)]
-pub struct SetRef<T, E: ByteOrder = Native, O: Size = DefaultSize>
+pub struct SetRef<T, E: ByteOrder = Native, O: Size = DefaultSize, H = Sip>
 where
     T: ZeroCopy,
 {
     key: Endian<HashKey, E>,
     entries: Ref<[T], E, O>,
     displacements: Ref<[Entry<u32, u32>], E, O>,
+    #[zero_copy(ignore)]
+    _marker: PhantomData<H>,
 }
 
-impl<T, E: ByteOrder, O: Size> SetRef<T, E, O>
+impl<T, E: ByteOrder, O: Size, H> SetRef<T, E, O, H>
 where
+    H: Hasher,
     T: ZeroCopy,
 {
     #[cfg(feature = "alloc")]
@@ -165,12 +173,14 @@ where
             key: Endian::new(key),
             entries,
             displacements,
+            _marker: PhantomData,
         }
     }
 }
 
-impl<T, E: ByteOrder, O: Size> SetRef<T, E, O>
+impl<T, E: ByteOrder, O: Size, H> SetRef<T, E, O, H>
 where
+    H: Hasher,
     T: ZeroCopy,
 {
     /// Get a value from the set.
@@ -202,7 +212,7 @@ where
             return Ok(false);
         }
 
-        let hashes = crate::phf::hashing::hash(buf, key, &self.key.to_ne())?;
+        let hashes = crate::phf::hashing::hash::<H, _>(buf, key, &self.key.to_ne())?;
 
         let displacements = |index| match self.displacements.get(index) {
             Some(entry) => Ok(Some(buf.load(entry)?)),
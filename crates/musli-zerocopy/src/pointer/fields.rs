@@ -0,0 +1,57 @@
+use core::marker::PhantomData;
+
+/// The offset of a field of type `F` inside of the struct `T`.
+///
+/// These are generated by the [`ZeroCopy`] derive and are used by
+/// [`ref_field!`] to project references.
+///
+/// [`ZeroCopy`]: derive@crate::ZeroCopy
+/// [`ref_field!`]: crate::pointer::ref_field
+#[doc(hidden)]
+pub struct FieldOffset<T, F> {
+    offset: usize,
+    _marker: PhantomData<fn(T) -> F>,
+}
+
+impl<T, F> FieldOffset<T, F> {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new(offset: usize) -> Self {
+        Self {
+            offset,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the offset of the field.
+    #[inline]
+    pub(crate) fn offset(self) -> usize {
+        self.offset
+    }
+}
+
+impl<T, F> Clone for FieldOffset<T, F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, F> Copy for FieldOffset<T, F> {}
+
+/// Trait implemented by the [`ZeroCopy`] derive for structs, which describes
+/// the offsets of all of their fields.
+///
+/// [`Fields`] is a struct with the same fields as the implementing type, where
+/// each field is a [`FieldOffset`].
+///
+/// [`ZeroCopy`]: derive@crate::ZeroCopy
+/// [`Fields`]: Fields::Fields
+#[doc(hidden)]
+pub trait Fields: Sized {
+    /// The type holding the offsets of fields.
+    type Fields;
+
+    /// The offsets of fields.
+    const FIELDS: Self::Fields;
+}
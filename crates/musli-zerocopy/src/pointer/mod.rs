@@ -8,6 +8,8 @@
 //!   [`ZeroCopy`]. It loads into `&T`.
 //! * [`Ref<[T]>`] is a wide pointer encoding both a plain pointer and a length
 //!   where `T` implements [`ZeroCopy`]. It loads into `&[T]`.
+//! * [`RelativeRef<T>`] is like [`Ref<T>`], except that it stores a signed
+//!   delta relative to where the reference itself is located.
 //! * [`Ref<T>`] where `T: ?Sized` is a wide pointer encoding both a plain
 //!   pointer and a size to a typed reference where `T` implements
 //!   [`UnsizedZeroCopy`]. It loads into `&T` and is implemented by types such
@@ -26,9 +28,13 @@ mod size;
 pub use self::r#ref::Ref;
 mod r#ref;
 
+#[doc(inline)]
+pub use self::relative_ref::RelativeRef;
+mod relative_ref;
+
 #[doc(inline)]
 pub use self::pointee::Pointee;
-mod pointee;
+pub(crate) mod pointee;
 
 #[doc(inline)]
 pub use self::packable::Packable;
@@ -39,3 +45,69 @@ mod coerce;
 
 pub use self::coerce_slice::CoerceSlice;
 mod coerce_slice;
+
+pub(crate) mod fields;
+
+/// Project a [`Ref`] to a struct into a [`Ref`] to one of its fields.
+///
+/// This produces a narrow reference to the field without loading the struct,
+/// which can be used to load or patch the field individually. The offset of the
+/// field is determined from the layout of the struct by the [`ZeroCopy`]
+/// derive, so it accounts for any padding. Fields can be named or tuple
+/// indexes, and projections can be nested.
+///
+/// [`ZeroCopy`]: derive@crate::ZeroCopy
+///
+/// # Panics
+///
+/// Panics if the projected offset can't be represented by the [`Size`] of the
+/// reference.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::pointer::ref_field;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Person {
+///     age: u8,
+///     name: Ref<str>,
+///     stats: Stats,
+/// }
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Stats(u16, u64);
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let name = buf.store_unsized("Aristotle");
+/// let person = buf.store(&Person { age: 61, name, stats: Stats(1, 2) });
+///
+/// let age: Ref<u8> = ref_field!(person, age);
+/// let name: Ref<Ref<str>> = ref_field!(person, name);
+/// let second: Ref<u64> = ref_field!(ref_field!(person, stats), 1);
+///
+/// assert_eq!(age.offset(), person.offset());
+/// assert_eq!(buf.load(*buf.load(name)?)?, "Aristotle");
+///
+/// *buf.load_mut(age)? += 1;
+/// *buf.load_mut(second)? = 42;
+///
+/// let person = buf.load(person)?;
+/// assert_eq!(person.age, 62);
+/// assert_eq!(person.stats.1, 42);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __ref_field {
+    ($reference:expr, $field:tt) => {
+        $crate::pointer::Ref::__project($reference, |fields| fields.$field)
+    };
+}
+
+#[doc(inline)]
+pub use __ref_field as ref_field;
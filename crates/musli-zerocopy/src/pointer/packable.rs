@@ -1,3 +1,4 @@
+use crate::endian::ByteOrder;
 use crate::pointer::Size;
 use crate::traits::ZeroCopy;
 
@@ -19,6 +20,19 @@ pub trait Packable: self::sealed::Sealed {
     fn try_from_metadata<O>(metadata: Self) -> Option<Self::Packed<O>>
     where
         O: Size;
+
+    /// Convert packed metadata into a raw `usize`.
+    #[doc(hidden)]
+    fn packed_as_usize<E, O>(packed: Self::Packed<O>) -> usize
+    where
+        E: ByteOrder,
+        O: Size;
+
+    /// Construct metadata from a raw `u32`.
+    #[doc(hidden)]
+    fn from_u32(raw: u32) -> Option<Self>
+    where
+        Self: Sized;
 }
 
 impl Packable for () {
@@ -31,6 +45,20 @@ impl Packable for () {
     {
         Some(())
     }
+
+    #[inline(always)]
+    fn packed_as_usize<E, O>((): ()) -> usize
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        0
+    }
+
+    #[inline(always)]
+    fn from_u32(raw: u32) -> Option<Self> {
+        (raw == 0).then_some(())
+    }
 }
 
 impl Packable for usize {
@@ -43,4 +71,18 @@ impl Packable for usize {
     {
         O::try_from_usize(metadata)
     }
+
+    #[inline(always)]
+    fn packed_as_usize<E, O>(packed: O) -> usize
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        packed.as_usize::<E>()
+    }
+
+    #[inline(always)]
+    fn from_u32(raw: u32) -> Option<Self> {
+        usize::try_from(raw).ok()
+    }
 }
@@ -4,17 +4,21 @@ use crate::error::IntoRepr;
 use crate::pointer::Packable;
 use crate::traits::ZeroCopy;
 
-mod sealed {
+pub(crate) mod sealed {
     use crate::mem::MaybeUninit;
     use crate::pointer::Pointee;
     use crate::traits::ZeroCopy;
 
+    /// Seals [`Pointee`], only implemented through this crate and its
+    /// derives.
+    #[doc(hidden)]
     pub trait Sealed {}
 
     impl<T> Sealed for MaybeUninit<T> where T: Pointee {}
     impl<T> Sealed for T where T: ZeroCopy {}
     impl<T> Sealed for [T] where T: ZeroCopy {}
     impl Sealed for str {}
+    impl Sealed for core::ffi::CStr {}
 }
 
 /// The trait for a value that can be pointed to by a [`Ref<T>`].
@@ -54,3 +58,11 @@ where
 impl Pointee for str {
     type Metadata = usize;
 }
+
+/// The metadata of a [`CStr`] is its length including the terminating NUL
+/// byte.
+///
+/// [`CStr`]: core::ffi::CStr
+impl Pointee for core::ffi::CStr {
+    type Metadata = usize;
+}
@@ -1,13 +1,16 @@
 use core::cmp::Ordering;
+use core::ffi::CStr;
 use core::hash::Hash;
 use core::marker::PhantomData;
 use core::mem::size_of;
+use core::ops::Range;
 use core::{any, fmt};
 
 use crate::endian::{Big, ByteOrder, Little, Native};
 use crate::error::{Error, ErrorKind, IntoRepr};
 use crate::mem::MaybeUninit;
 use crate::pointer::Coerce;
+use crate::pointer::fields::{FieldOffset, Fields};
 use crate::pointer::{DefaultSize, Packable, Pointee, Size};
 use crate::ZeroCopy;
 
@@ -340,7 +343,10 @@ where
             return None;
         }
 
-        let offset = self.offset.as_usize::<E>() + size_of::<T>() * index;
+        let offset = size_of::<T>()
+            .checked_mul(index)?
+            .checked_add(self.offset())?;
+
         Some(Ref::new(offset))
     }
 
@@ -371,7 +377,13 @@ where
     /// # Ok::<_, musli_zerocopy::Error>(())
     /// ```
     pub fn get_unchecked(self, index: usize) -> Ref<T, E, O> {
-        let offset = self.offset.as_usize::<E>() + size_of::<T>() * index;
+        let Some(offset) = size_of::<T>()
+            .checked_mul(index)
+            .and_then(|offset| offset.checked_add(self.offset()))
+        else {
+            panic!("Offset {} to index {index} overflows", self.offset());
+        };
+
         Ref::new(offset)
     }
 
@@ -405,11 +417,52 @@ where
         let offset = self.offset();
         let len = self.len();
         assert!(at <= len, "Split point {at} is out of bounds 0..={len}");
+        let Some(split) = size_of::<T>()
+            .checked_mul(at)
+            .and_then(|split| split.checked_add(offset))
+        else {
+            panic!("Offset {offset} to split point {at} overflows");
+        };
+
         let a = Self::with_metadata(offset, at);
-        let b = Self::with_metadata(offset + at * size_of::<T>(), len - at);
+        let b = Self::with_metadata(split, len - at);
         (a, b)
     }
 
+    /// Try to construct a reference to a sub-range of the slice.
+    ///
+    /// Returns [`None`] if the range is out of bounds of the slice, or if the
+    /// resulting offset can't be represented by the [`Size`] of the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let slice = buf.store_slice(&[1, 2, 3, 4]);
+    ///
+    /// let middle = slice.try_slice(1..3).expect("in bounds");
+    /// assert_eq!(buf.load(middle)?, &[2, 3]);
+    ///
+    /// assert!(slice.try_slice(4..4).is_some());
+    /// assert!(slice.try_slice(3..5).is_none());
+    /// assert!(slice.try_slice(3..2).is_none());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn try_slice(self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+
+        let offset = size_of::<T>()
+            .checked_mul(range.start)?
+            .checked_add(self.offset())?;
+
+        Self::try_with_metadata(offset, range.end - range.start).ok()
+    }
+
     /// Perform an fetch like `get` which panics with diagnostics in case the
     /// index is out-of-bounds.
     #[inline]
@@ -452,7 +505,14 @@ where
     #[inline]
     pub fn iter(self) -> Iter<T, E, O> {
         let start = self.offset.as_usize::<E>();
-        let end = start + self.metadata.as_usize::<E>() * size_of::<T>();
+        let len = self.metadata.as_usize::<E>();
+
+        let Some(end) = size_of::<T>()
+            .checked_mul(len)
+            .and_then(|end| end.checked_add(start))
+        else {
+            panic!("Offset {start} to end of slice of length {len} overflows");
+        };
 
         Iter {
             start,
@@ -499,6 +559,94 @@ where
     pub fn is_empty(self) -> bool {
         self.metadata.is_zero()
     }
+
+    /// Construct a reference to a sub-range of the string.
+    ///
+    /// This only adjusts the offset and length of the reference and does not
+    /// check that the range falls on character boundaries, which would cause
+    /// the reference to fail to load. To check this use [`Buf::substr`].
+    ///
+    /// [`Buf::substr`]: crate::Buf::substr
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let text = buf.store_unsized("Hello World");
+    ///
+    /// let world = text.slice(6..11);
+    /// assert_eq!(world.offset(), text.offset() + 6);
+    /// assert_eq!(buf.load(world)?, "World");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn slice(self, range: Range<usize>) -> Self {
+        let len = self.len();
+
+        assert!(
+            range.start <= range.end && range.end <= len,
+            "Range {range:?} is out of bounds 0..{len}"
+        );
+
+        let Some(offset) = self.offset().checked_add(range.start) else {
+            panic!("Offset {} to {range:?} overflows", self.offset());
+        };
+
+        Self::with_metadata(offset, range.end - range.start)
+    }
+}
+
+impl<E, O> Ref<CStr, E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    /// Return the length of the string, including its terminating NUL byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::CStr;
+    ///
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let string = buf.store_unsized(CStr::from_bytes_with_nul(b"Hi\0").unwrap());
+    /// assert_eq!(string.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(self) -> usize {
+        self.metadata.as_usize::<E>()
+    }
+
+    /// Test if the reference has a length of zero.
+    ///
+    /// Note that a valid string always has a length of at least one, since it
+    /// includes its terminating NUL byte.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ffi::CStr;
+    ///
+    /// use musli_zerocopy::pointer::Ref;
+    ///
+    /// let string = Ref::<CStr>::with_metadata(0, 0);
+    /// assert!(string.is_empty());
+    ///
+    /// let string = Ref::<CStr>::with_metadata(0, 1);
+    /// assert!(!string.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.metadata.is_zero()
+    }
 }
 
 /// An iterator over a `Ref<[T]>` which produces `Ref<T>` values.
@@ -617,6 +765,153 @@ where
         }
     }
 
+    /// Project the reference to a field, as done by [`ref_field!`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset of the projected field can't be represented by the
+    /// [`Size`] of the reference.
+    ///
+    /// [`ref_field!`]: crate::pointer::ref_field
+    #[doc(hidden)]
+    #[inline]
+    pub fn __project<F>(self, field: impl FnOnce(&T::Fields) -> FieldOffset<T, F>) -> Ref<F, E, O>
+    where
+        T: Fields,
+        F: ZeroCopy,
+    {
+        let offset = field(&T::FIELDS).offset();
+
+        let Some(offset) = self.offset().checked_add(offset) else {
+            panic!("Offset {} + {offset} overflows", self.offset());
+        };
+
+        Ref::new(offset)
+    }
+
+    /// Offset the reference by `count` elements of `T`, as if it was
+    /// referencing an element in a densely packed slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting offset overflows or can't be represented by the
+    /// [`Size`] of the reference. See [`checked_offset_by()`] for a
+    /// non-panicking variant.
+    ///
+    /// [`checked_offset_by()`]: Self::checked_offset_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let slice = buf.store_slice(&[1u32, 2, 3, 4]);
+    ///
+    /// let first = slice.get(0).expect("missing element");
+    /// let third = first.offset_by(2);
+    /// assert_eq!(buf.load(third)?, &3);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn offset_by(self, count: usize) -> Self {
+        let Some(reference) = self.checked_offset_by(count) else {
+            panic!(
+                "Offset {} by {count} elements of `{}` is out of range 0-{}",
+                self.offset(),
+                any::type_name::<T>(),
+                O::MAX
+            );
+        };
+
+        reference
+    }
+
+    /// Offset the reference by `count` elements of `T`, returning [`None`] if
+    /// the resulting offset overflows or can't be represented by the [`Size`]
+    /// of the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    ///
+    /// let reference = Ref::<u32>::new(8);
+    /// assert_eq!(reference.checked_offset_by(2).map(Ref::offset), Some(16));
+    ///
+    /// let reference = Ref::<u32, musli_zerocopy::endian::Native, u16>::new(8);
+    /// assert!(reference.checked_offset_by(20000).is_none());
+    /// assert!(reference.checked_offset_by(usize::MAX).is_none());
+    /// ```
+    #[inline]
+    pub fn checked_offset_by(self, count: usize) -> Option<Self> {
+        let offset = size_of::<T>()
+            .checked_mul(count)?
+            .checked_add(self.offset())?;
+
+        Self::try_with_metadata(offset, ()).ok()
+    }
+
+    /// Calculate the distance in elements of `T` from `origin` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is located before `origin`, if the distance between
+    /// them is not a multiple of the size of `T`, or if `T` is zero-sized. See
+    /// [`checked_offset_from()`] for a non-panicking variant.
+    ///
+    /// [`checked_offset_from()`]: Self::checked_offset_from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    ///
+    /// let origin = Ref::<u32>::new(8);
+    /// assert_eq!(origin.offset_by(3).offset_from(origin), 3);
+    /// ```
+    #[inline]
+    pub fn offset_from(self, origin: Self) -> usize {
+        let Some(count) = self.checked_offset_from(origin) else {
+            panic!(
+                "Offset {} is not a whole number of elements of `{}` from {}",
+                self.offset(),
+                any::type_name::<T>(),
+                origin.offset()
+            );
+        };
+
+        count
+    }
+
+    /// Calculate the distance in elements of `T` from `origin` to `self`,
+    /// returning [`None`] if `self` is located before `origin`, if the
+    /// distance between them is not a multiple of the size of `T`, or if `T`
+    /// is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    ///
+    /// let origin = Ref::<u32>::new(8);
+    ///
+    /// assert_eq!(Ref::<u32>::new(16).checked_offset_from(origin), Some(2));
+    /// assert_eq!(Ref::<u32>::new(4).checked_offset_from(origin), None);
+    /// assert_eq!(Ref::<u32>::new(10).checked_offset_from(origin), None);
+    /// ```
+    #[inline]
+    pub fn checked_offset_from(self, origin: Self) -> Option<usize> {
+        let distance = self.offset().checked_sub(origin.offset())?;
+        let size = size_of::<T>();
+
+        if size == 0 || distance % size != 0 {
+            return None;
+        }
+
+        Some(distance / size)
+    }
+
     /// Construct a typed reference to the zeroeth offset in a buffer.
     ///
     /// # Examples
@@ -757,6 +1052,20 @@ where
         })
     }
 
+    /// Construct a reference out of its raw components, which are expected to
+    /// already be byte-ordered according to `E`.
+    #[inline]
+    pub(crate) fn from_raw_parts(
+        offset: O,
+        metadata: <T::Metadata as Packable>::Packed<O>,
+    ) -> Self {
+        Self {
+            offset,
+            metadata,
+            _marker: PhantomData,
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn cast<U: ?Sized>(self) -> Ref<U, E, O>
     where
@@ -0,0 +1,420 @@
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::{any, fmt};
+
+use crate::buf::Buf;
+use crate::endian::{ByteOrder, Native};
+use crate::error::{Error, ErrorKind, IntoRepr};
+use crate::pointer::{DefaultSize, Packable, Pointee, Ref, Size};
+use crate::ZeroCopy;
+
+/// A stored reference to a type `T` which is relative to the location of the
+/// reference itself.
+///
+/// Unlike [`Ref<T>`] which stores an absolute offset into a [`Buf`], a
+/// relative reference stores a signed [`delta()`] which is added to the offset
+/// at which the relative reference itself is located. The delta is stored in
+/// the two's complement representation of `O`, so the pointee might be
+/// located either before or after the reference.
+///
+/// Relative references are loaded through a reference to where they are
+/// stored inside of a buffer, which is how their own location is determined.
+/// A loaded `&RelativeRef<T>` therefore implements [`Load`].
+///
+/// [`Buf`]: crate::buf::Buf
+/// [`Load`]: crate::buf::Load
+/// [`delta()`]: RelativeRef::delta
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+/// use musli_zerocopy::pointer::RelativeRef;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Person {
+///     name: RelativeRef<str>,
+///     age: u8,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let name = buf.store_unsized("Aristotle");
+/// let at = buf.next_offset::<Person>();
+///
+/// let person = buf.store(&Person {
+///     name: RelativeRef::with_metadata(at, name.offset(), name.len()),
+///     age: 61,
+/// });
+///
+/// let person = buf.load(person)?;
+/// assert!(person.name.delta() < 0);
+/// assert_eq!(buf.load(&person.name)?, "Aristotle");
+/// assert_eq!(person.age, 61);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate, swap_bytes, bounds = {<T::Metadata as Packable>::Packed<O>: ZeroCopy})]
+pub struct RelativeRef<T, E = Native, O = DefaultSize>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    delta: O,
+    metadata: <T::Metadata as Packable>::Packed<O>,
+    #[zero_copy(ignore)]
+    _marker: PhantomData<(E, T)>,
+}
+
+impl<T, E, O> RelativeRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    /// Construct a relative reference stored at offset `from` which points to
+    /// offset `to` with custom metadata.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if either:
+    /// * The delta or `metadata` can't be byte swapped as per
+    ///   [`ZeroCopy::CAN_SWAP_BYTES`].
+    /// * The delta between `from` and `to` cannot be represented by `O`.
+    /// * Packed [`metadata()`] cannot be constructed from `T::Metadata` (reason
+    ///   depends on the exact metadata).
+    ///
+    /// [`metadata()`]: RelativeRef::metadata
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<[u64]>::with_metadata(42, 10, 4);
+    /// assert_eq!(reference.delta(), -32);
+    /// assert_eq!(reference.metadata(), 4);
+    /// ```
+    #[inline]
+    pub fn with_metadata(from: usize, to: usize, metadata: T::Metadata) -> Self {
+        assert!(
+            O::CAN_SWAP_BYTES,
+            "Offset `{}` cannot be byte-ordered since it would not inhabit valid types",
+            any::type_name::<O>()
+        );
+
+        let Some(delta) = delta(from, to).and_then(O::try_from_isize) else {
+            panic!(
+                "Delta from {from} to {to} not in legal range of `{}`",
+                any::type_name::<O>()
+            );
+        };
+
+        let Some(metadata) = <T::Metadata as Packable>::try_from_metadata(metadata) else {
+            panic!("Metadata {metadata:?} not in legal range 0-{}", O::MAX);
+        };
+
+        Self {
+            delta: O::swap_bytes::<E>(delta),
+            metadata: <T::Metadata as Packable>::Packed::<O>::swap_bytes::<E>(metadata),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Fallibly try to construct a relative reference stored at offset `from`
+    /// which points to offset `to` with custom metadata.
+    ///
+    /// # Errors
+    ///
+    /// This will error if either:
+    /// * The delta or `metadata` can't be byte swapped as per
+    ///   [`ZeroCopy::CAN_SWAP_BYTES`].
+    /// * The delta between `from` and `to` cannot be represented by `O`.
+    /// * Packed [`metadata()`] cannot be constructed from `T::Metadata` (reason
+    ///   depends on the exact metadata).
+    ///
+    /// [`metadata()`]: RelativeRef::metadata
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<[u64]>::try_with_metadata(10, 42, 4)?;
+    /// assert_eq!(reference.delta(), 32);
+    ///
+    /// assert!(RelativeRef::<[u64], Native, u8>::try_with_metadata(0, 128, 4).is_err());
+    /// assert!(RelativeRef::<[u64], Native, u8>::try_with_metadata(128, 0, 4).is_ok());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn try_with_metadata(from: usize, to: usize, metadata: T::Metadata) -> Result<Self, Error> {
+        if !O::CAN_SWAP_BYTES {
+            return Err(Error::new(ErrorKind::InvalidOffset {
+                ty: any::type_name::<O>(),
+            }));
+        }
+
+        if !<T::Metadata as Packable>::Packed::<O>::CAN_SWAP_BYTES {
+            return Err(Error::new(ErrorKind::InvalidMetadata {
+                ty: any::type_name::<T::Metadata>(),
+                packed: any::type_name::<<T::Metadata as Packable>::Packed<O>>(),
+            }));
+        }
+
+        let Some(delta) = delta(from, to).and_then(O::try_from_isize) else {
+            return Err(Error::new(ErrorKind::InvalidDeltaRange {
+                from,
+                to,
+                ty: any::type_name::<O>(),
+            }));
+        };
+
+        let Some(metadata) = <T::Metadata as Packable>::try_from_metadata(metadata) else {
+            return Err(Error::new(ErrorKind::InvalidMetadataRange {
+                metadata: T::Metadata::into_repr(metadata),
+                max: O::into_repr(O::MAX),
+            }));
+        };
+
+        Ok(Self {
+            delta: O::swap_bytes::<E>(delta),
+            metadata: <T::Metadata as Packable>::Packed::<O>::swap_bytes::<E>(metadata),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the signed delta from the location of the reference to the pointee.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<u64>::new(16, 8);
+    /// assert_eq!(reference.delta(), -8);
+    /// ```
+    #[inline]
+    pub fn delta(&self) -> isize {
+        self.delta.as_isize::<E>()
+    }
+
+    /// The metadata of the reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<str>::with_metadata(0, 8, 10);
+    /// assert_eq!(reference.metadata(), 10);
+    /// ```
+    #[inline]
+    pub fn metadata(&self) -> <T::Metadata as Packable>::Packed<O> {
+        self.metadata
+    }
+
+    /// Convert into an absolute [`Ref<T>`] assuming that the relative reference
+    /// is located at the offset `at`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the resulting offset overflows, underflows or can't be
+    /// represented by `O`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<u64>::new(16, 8);
+    /// assert_eq!(reference.to_ref_at(16)?.offset(), 8);
+    /// assert_eq!(reference.to_ref_at(32)?.offset(), 24);
+    /// assert!(reference.to_ref_at(4).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn to_ref_at(&self, at: usize) -> Result<Ref<T, E, O>, Error> {
+        let delta = self.delta();
+        let len = delta.unsigned_abs();
+
+        let offset = if delta < 0 {
+            let Some(offset) = at.checked_sub(len) else {
+                return Err(Error::new(ErrorKind::Underflow { at, len }));
+            };
+
+            offset
+        } else {
+            let Some(offset) = at.checked_add(len) else {
+                return Err(Error::new(ErrorKind::Overflow { at, len }));
+            };
+
+            offset
+        };
+
+        let Some(offset) = O::try_from_usize(offset) else {
+            return Err(Error::new(ErrorKind::InvalidOffsetRange {
+                offset: offset.into_repr(),
+                max: O::into_repr(O::MAX),
+            }));
+        };
+
+        Ok(Ref::from_raw_parts(
+            O::swap_bytes::<E>(offset),
+            self.metadata,
+        ))
+    }
+
+    /// Resolve the relative reference into an absolute [`Ref<T>`] based on
+    /// where it is located inside of `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the relative reference is not located inside of `buf`, or if
+    /// the resolved offset is not valid as per [`to_ref_at()`].
+    ///
+    /// [`to_ref_at()`]: RelativeRef::to_ref_at
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let reference = buf.store_uninit::<RelativeRef<u32>>();
+    /// let value = buf.store(&42u32);
+    ///
+    /// buf.load_uninit_mut(reference)
+    ///     .write(&RelativeRef::new(reference.offset(), value.offset()));
+    ///
+    /// let reference = buf.load(reference.assume_init())?;
+    /// assert_eq!(reference.resolve(&buf)?, value);
+    /// assert_eq!(buf.load(reference)?, &42);
+    ///
+    /// // The reference doesn't live inside of this buffer.
+    /// let detached = RelativeRef::<u32>::new(0, 4);
+    /// assert!(detached.resolve(&buf).is_err());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn resolve(&self, buf: &Buf) -> Result<Ref<T, E, O>, Error> {
+        let range = buf.range();
+        let addr = self as *const Self as usize;
+
+        if addr < range.start || addr.saturating_add(size_of::<Self>()) > range.end {
+            return Err(Error::new(ErrorKind::NotInBuffer { addr, range }));
+        }
+
+        self.to_ref_at(addr - range.start)
+    }
+}
+
+impl<T, E, O> RelativeRef<T, E, O>
+where
+    T: Pointee<Metadata = ()>,
+    E: ByteOrder,
+    O: Size,
+{
+    /// Construct a relative reference stored at offset `from` which points to
+    /// offset `to`.
+    ///
+    /// # Panics
+    ///
+    /// This will panic if either:
+    /// * The delta can't be byte swapped as per [`ZeroCopy::CAN_SWAP_BYTES`].
+    /// * The delta between `from` and `to` cannot be represented by `O`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::RelativeRef;
+    ///
+    /// let reference = RelativeRef::<u64>::new(8, 16);
+    /// assert_eq!(reference.delta(), 8);
+    /// ```
+    #[inline]
+    pub fn new(from: usize, to: usize) -> Self {
+        Self::with_metadata(from, to, ())
+    }
+}
+
+/// Calculate the signed delta between two offsets.
+#[inline]
+fn delta(from: usize, to: usize) -> Option<isize> {
+    if to >= from {
+        isize::try_from(to - from).ok()
+    } else {
+        isize::try_from(from - to).ok().map(|delta| -delta)
+    }
+}
+
+impl<T, E, O> fmt::Debug for RelativeRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    <T::Metadata as Packable>::Packed<O>: fmt::Debug,
+    E: ByteOrder,
+    O: Size + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RelativeRef<{}> {{ delta: {:?}, metadata: {:?} }}",
+            core::any::type_name::<T>(),
+            self.delta(),
+            self.metadata,
+        )
+    }
+}
+
+impl<T: ?Sized, E: ByteOrder, O: Size> Clone for RelativeRef<T, E, O>
+where
+    T: Pointee,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, E: ByteOrder, O: Size> Copy for RelativeRef<T, E, O> where T: Pointee {}
+
+impl<T: ?Sized, E: ByteOrder, O: Size> PartialEq for RelativeRef<T, E, O>
+where
+    T: Pointee,
+    O: PartialEq,
+    <T::Metadata as Packable>::Packed<O>: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.delta == other.delta && self.metadata == other.metadata
+    }
+}
+
+impl<T: ?Sized, E: ByteOrder, O: Size> Eq for RelativeRef<T, E, O>
+where
+    T: Pointee,
+    O: Eq,
+    <T::Metadata as Packable>::Packed<O>: Eq,
+{
+}
+
+impl<T: ?Sized, E: ByteOrder, O: Size> Hash for RelativeRef<T, E, O>
+where
+    T: Pointee,
+    O: Hash,
+    <T::Metadata as Packable>::Packed<O>: Hash,
+{
+    #[inline]
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: core::hash::Hasher,
+    {
+        self.delta.hash(state);
+        self.metadata.hash(state);
+    }
+}
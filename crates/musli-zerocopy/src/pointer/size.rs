@@ -8,7 +8,15 @@ use crate::traits::ZeroCopy;
 pub type DefaultSize = u32;
 
 #[cfg(not(any(target_pointer_width = "32", target_pointer_width = "64",)))]
-compile_error!("musli-zerocopy is only supported on 32, 64-bit platforms");
+compile_error!(
+    "musli-zerocopy is only supported on 32, 64-bit platforms, since it requires a `usize` which is at least 32 bits wide"
+);
+
+// Every offset representable by the default size must fit in a `usize`.
+const _: () = assert!(
+    core::mem::size_of::<DefaultSize>() <= core::mem::size_of::<usize>(),
+    "musli-zerocopy requires a `usize` which can represent every `DefaultSize`"
+);
 
 mod sealed {
     pub trait Sealed {}
@@ -80,13 +88,23 @@ pub trait Size:
     #[doc(hidden)]
     fn as_usize<E: ByteOrder>(self) -> usize;
 
+    /// Try to construct this value from a signed value, which is stored using
+    /// its two's complement representation.
+    #[doc(hidden)]
+    fn try_from_isize(value: isize) -> Option<Self>;
+
+    /// Convert the pointer to an isize by interpreting it as a two's complement
+    /// signed value.
+    #[doc(hidden)]
+    fn as_isize<E: ByteOrder>(self) -> isize;
+
     /// Test if the value is zero.
     #[doc(hidden)]
     fn is_zero(self) -> bool;
 }
 
 macro_rules! impl_size {
-    ($ty:ty, $swap:path) => {
+    ($ty:ty, $signed:ty, $swap:path) => {
         #[doc = concat!("Size implementation for `", stringify!($ty), "`")]
         ///
         /// # Examples
@@ -134,6 +152,17 @@ macro_rules! impl_size {
                 }
             }
 
+            #[inline]
+            fn try_from_isize(value: isize) -> Option<Self> {
+                let value = <$signed>::try_from(value).ok()?;
+                Some(value as $ty)
+            }
+
+            #[inline]
+            fn as_isize<E: ByteOrder>(self) -> isize {
+                $swap(self) as $signed as isize
+            }
+
             #[inline]
             fn is_zero(self) -> bool {
                 self == 0
@@ -142,9 +171,9 @@ macro_rules! impl_size {
     };
 }
 
-impl_size!(u8, core::convert::identity);
-impl_size!(u16, E::swap_u16);
-impl_size!(u32, E::swap_u32);
+impl_size!(u8, i8, core::convert::identity);
+impl_size!(u16, i16, E::swap_u16);
+impl_size!(u32, i32, E::swap_u32);
 #[cfg(target_pointer_width = "64")]
-impl_size!(u64, E::swap_u64);
-impl_size!(usize, core::convert::identity);
+impl_size!(u64, i64, E::swap_u64);
+impl_size!(usize, isize, core::convert::identity);
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+
+use crate::buf::sort::try_sort_by;
+use crate::buf::{OwnedBuf, Visit};
+use crate::endian::ByteOrder;
+use crate::error::Error;
+use crate::pointer::Size;
+use crate::sorted::{Entry, MapRef};
+use crate::ZeroCopy;
+
+/// Store a sorted map into an [`OwnedBuf`].
+///
+/// The entries are sorted by key before they are stored, where keys are
+/// compared through [`Visit`]. This means that keys which are references into
+/// the buffer like [`Ref<str>`] are compared by the value they reference.
+///
+/// This returns a [`MapRef`] which can be bound into a [`Map`] through the
+/// [`bind()`] method for convenience.
+///
+/// See the [module level documentation] for more information.
+///
+/// [`bind()`]: crate::buf::Buf::bind
+/// [`Map`]: crate::sorted::Map
+/// [`Ref<str>`]: crate::Ref
+/// [module level documentation]: crate::sorted
+///
+/// # Duplicates
+///
+/// The caller is responsible for ensuring that no duplicate keys are provided
+/// to the constructor. In the face of duplicate keys every entry is stored,
+/// and lookups return any one of them.
+///
+/// # Errors
+///
+/// Errors if any key fails to be visited in the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let pairs = [
+///     (buf.store_unsized("second"), 2u32),
+///     (buf.store_unsized("first"), 1u32),
+/// ];
+///
+/// let map = sorted::store_map(&mut buf, pairs)?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get("second")?, Some(&2));
+/// assert_eq!(map.get("third")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// Using non-references as keys:
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(20u64, 2u32), (10u64, 1u32)])?;
+///
+/// assert_eq!(map.get(&buf, &10u64)?, Some(&1));
+/// assert_eq!(map.get(&buf, &20u64)?, Some(&2));
+/// assert_eq!(map.get(&buf, &30u64)?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map<K, V, I, E: ByteOrder, O: Size>(
+    buf: &mut OwnedBuf<E, O>,
+    entries: I,
+) -> Result<MapRef<K, V, E, O>, Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Ord,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut entries = entries
+        .into_iter()
+        .map(|(k, v)| Entry::new(k, v))
+        .collect::<Vec<_>>();
+
+    try_sort_by(&mut entries, |a, b| {
+        a.key.visit(buf, |a| b.key.visit(buf, |b| a.cmp(b)))?
+    })?;
+
+    let entries = buf.store_slice(&entries);
+    Ok(MapRef::new(entries))
+}
@@ -0,0 +1,674 @@
+//! A map which implements an ordered map-like interface, where values can be
+//! looked up by keys.
+//!
+//! This map is implemented by storing entries sorted by key, and are inserted
+//! into a buffer using [`sorted::store_map`].
+//!
+//! There's two types provided by this module:
+//! * [`Map<K, V>`] which is a *bound* reference to a map, providing a
+//!   convenient map-like access.
+//! * [`MapRef<K, V>`] which is the *pointer* of the map. This is what you store
+//!   in [`ZeroCopy`] types and is what is returned by [`sorted::store_map`].
+//!
+//! [`sorted::store_map`]: crate::sorted::store_map
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::ops::{Bound, Range, RangeBounds};
+use core::slice;
+
+use crate::buf::{Bindable, Buf, Visit};
+use crate::endian::{ByteOrder, Native};
+use crate::error::Error;
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::sorted::Entry;
+use crate::ZeroCopy;
+
+/// A map bound to a [`Buf`] through [`Buf::bind`] for convenience.
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1)?, Some(&2));
+/// assert_eq!(map.get(&2)?, Some(&3));
+/// assert_eq!(map.get(&3)?, None);
+///
+/// assert!(map.contains_key(&1)?);
+/// assert!(!map.contains_key(&3)?);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Map<'a, K, V> {
+    entries: &'a [Entry<K, V>],
+    buf: &'a Buf,
+}
+
+impl<'a, K, V> Map<'a, K, V>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get(&1)?, Some(&2));
+    /// assert_eq!(map.get(&2)?, Some(&3));
+    /// assert_eq!(map.get(&3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Result<Option<&'a V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        Ok(self.get_entry(key)?.map(|(_, value)| value))
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.contains_key(&1)?);
+    /// assert!(map.contains_key(&2)?);
+    /// assert!(!map.contains_key(&3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        Ok(self.get_entry(key)?.is_some())
+    }
+
+    /// Get an entry from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get_entry(&1)?, Some((&1, &2)));
+    /// assert_eq!(map.get_entry(&2)?, Some((&2, &3)));
+    /// assert_eq!(map.get_entry(&3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_entry<Q>(&self, key: &Q) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        let entries = self.entries;
+
+        let index = search(self.entries.len(), |index| {
+            compare(self.buf, &entries[index].key, key)
+        })?;
+
+        Ok(index.map(|index| {
+            let e = &entries[index];
+            (&e.key, &e.value)
+        }))
+    }
+
+    /// Iterate over all entries in the map whose keys are within the given
+    /// `range`, in key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(4, 'd'), (1, 'a'), (3, 'c'), (2, 'b')])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let values = map.range(2..4)?.map(|(_, v)| *v).collect::<String>();
+    /// assert_eq!(values, "bc");
+    ///
+    /// let values = map.range(3..)?.map(|(_, v)| *v).collect::<String>();
+    /// assert_eq!(values, "cd");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Result<Iter<'a, K, V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+        R: RangeBounds<Q>,
+    {
+        let range = find_range(self.buf, self.entries, &range)?;
+        Ok(Iter::new(&self.entries[range]))
+    }
+
+    /// Iterate over all entries in the map, in key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let entries = map.iter().collect::<Vec<_>>();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        Iter::new(self.entries)
+    }
+
+    /// Get the length of the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Bind a [`MapRef`] into a [`Map`].
+impl<K, V, E: ByteOrder, O: Size> Bindable for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    type Bound<'a> = Map<'a, K, V> where Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        Ok(Map {
+            entries: buf.load(self.entries)?,
+            buf,
+        })
+    }
+}
+
+/// A stored reference to a map.
+///
+/// Note that operating over the methods provided in [`MapRef`] does not demand
+/// that the entire contents of the map is validated as would be the case when
+/// [`bind()`] is used, since lookups only load the entries visited by the
+/// binary search.
+///
+/// Constructed through [`sorted::store_map`].
+///
+/// [`sorted::store_map`]: crate::sorted::store_map
+/// [`bind()`]: crate::buf::Buf::bind
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+///
+/// assert_eq!(map.get(&buf, &1)?, Some(&2));
+/// assert_eq!(map.get(&buf, &2)?, Some(&3));
+/// assert_eq!(map.get(&buf, &3)?, None);
+///
+/// assert!(map.contains_key(&buf, &1)?);
+/// assert!(!map.contains_key(&buf, &3)?);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct MapRef<K, V, E: ByteOrder = Native, O: Size = DefaultSize>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    entries: Ref<[Entry<K, V>], E, O>,
+}
+
+impl<K, V, E: ByteOrder, O: Size> MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(entries: Ref<[Entry<K, V>], E, O>) -> Self {
+        Self { entries }
+    }
+
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// assert_eq!(map.get(&buf, &2)?, Some(&3));
+    /// assert_eq!(map.get(&buf, &3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<'a, Q>(&self, buf: &'a Buf, key: &Q) -> Result<Option<&'a V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: 'a + Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        Ok(self.get_entry(buf, key)?.map(|(_, value)| value))
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert!(map.contains_key(&buf, &1)?);
+    /// assert!(map.contains_key(&buf, &2)?);
+    /// assert!(!map.contains_key(&buf, &3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<Q>(&self, buf: &Buf, key: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        Ok(self.get_entry(buf, key)?.is_some())
+    }
+
+    /// Get an entry from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let pairs = [
+    ///     (buf.store_unsized("b"), 2u32),
+    ///     (buf.store_unsized("a"), 1u32),
+    /// ];
+    ///
+    /// let map = sorted::store_map(&mut buf, pairs)?;
+    ///
+    /// let (key, value) = map.get_entry(&buf, "a")?.expect("missing entry");
+    /// assert_eq!(buf.load(*key)?, "a");
+    /// assert_eq!(*value, 1);
+    ///
+    /// assert!(map.get_entry(&buf, "c")?.is_none());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_entry<'a, Q>(&self, buf: &'a Buf, key: &Q) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: 'a + Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        let index = search(self.entries.len(), |index| {
+            compare(buf, &self.entry(buf, index)?.key, key)
+        })?;
+
+        let Some(index) = index else {
+            return Ok(None);
+        };
+
+        let e = self.entry(buf, index)?;
+        Ok(Some((&e.key, &e.value)))
+    }
+
+    /// Iterate over all entries in the map whose keys are within the given
+    /// `range`, in key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let pairs = [
+    ///     (buf.store_unsized("cherry"), 3u32),
+    ///     (buf.store_unsized("apple"), 1u32),
+    ///     (buf.store_unsized("banana"), 2u32),
+    ///     (buf.store_unsized("date"), 4u32),
+    /// ];
+    ///
+    /// let map = sorted::store_map(&mut buf, pairs)?;
+    ///
+    /// let values = map.range(&buf, "b".."d")?.map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, [2, 3]);
+    ///
+    /// let values = map.range(&buf, ..="banana")?.map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, [1, 2]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<'a, Q, R>(&self, buf: &'a Buf, range: R) -> Result<Iter<'a, K, V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        K: 'a + Visit,
+        K::Target: Borrow<Q::Target>,
+        R: RangeBounds<Q>,
+    {
+        let entries = buf.load(self.entries)?;
+        let range = find_range(buf, entries, &range)?;
+        Ok(Iter::new(&entries[range]))
+    }
+
+    /// Iterate over all entries in the map, in key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    ///
+    /// let entries = map.iter(&buf)?.collect::<Vec<_>>();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter<'a>(&self, buf: &'a Buf) -> Result<Iter<'a, K, V>, Error> {
+        Ok(Iter::new(buf.load(self.entries)?))
+    }
+
+    /// Get the length of the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn entry<'a>(&self, buf: &'a Buf, index: usize) -> Result<&'a Entry<K, V>, Error> {
+        // NB: The index is always in bounds, since it's produced by a search
+        // over the length of the entries.
+        let entry = self.entries.get(index).expect("index out of bounds");
+        buf.load(entry)
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size> Clone for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size> Copy for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+}
+
+/// An iterator over the entries of a sorted map.
+///
+/// Constructed through [`Map::iter`], [`Map::range`], [`MapRef::iter`] or
+/// [`MapRef::range`].
+pub struct Iter<'a, K, V> {
+    iter: slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    #[inline]
+    fn new(entries: &'a [Entry<K, V>]) -> Self {
+        Self {
+            iter: entries.iter(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next()?;
+        Some((&e.key, &e.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next_back()?;
+        Some((&e.key, &e.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// Compare a stored key with the key being searched for.
+fn compare<K, Q>(buf: &Buf, stored: &K, key: &Q) -> Result<Ordering, Error>
+where
+    Q: ?Sized + Visit,
+    Q::Target: Ord,
+    K: Visit,
+    K::Target: Borrow<Q::Target>,
+{
+    key.visit(buf, |b| stored.visit(buf, |a| a.borrow().cmp(b)))?
+}
+
+/// Binary search over `len` elements, where `cmp` compares the element at the
+/// given index with the element being searched for.
+fn search(
+    len: usize,
+    mut cmp: impl FnMut(usize) -> Result<Ordering, Error>,
+) -> Result<Option<usize>, Error> {
+    let mut lo = 0;
+    let mut hi = len;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        match cmp(mid)? {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => return Ok(Some(mid)),
+        }
+    }
+
+    Ok(None)
+}
+
+/// Find the first index in `entries` for which `pred` returns `false`.
+fn partition_point<K, V>(
+    entries: &[Entry<K, V>],
+    mut pred: impl FnMut(&K) -> Result<bool, Error>,
+) -> Result<usize, Error> {
+    let mut lo = 0;
+    let mut hi = entries.len();
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if pred(&entries[mid].key)? {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(lo)
+}
+
+/// Find the range of indexes in `entries` which are covered by `range`.
+fn find_range<K, V, Q, R>(
+    buf: &Buf,
+    entries: &[Entry<K, V>],
+    range: &R,
+) -> Result<Range<usize>, Error>
+where
+    Q: ?Sized + Visit,
+    Q::Target: Ord,
+    K: Visit,
+    K::Target: Borrow<Q::Target>,
+    R: RangeBounds<Q>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(key) => {
+            partition_point(entries, |k| Ok(compare(buf, k, key)? == Ordering::Less))?
+        }
+        Bound::Excluded(key) => {
+            partition_point(entries, |k| Ok(compare(buf, k, key)? != Ordering::Greater))?
+        }
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(key) => {
+            partition_point(entries, |k| Ok(compare(buf, k, key)? != Ordering::Greater))?
+        }
+        Bound::Excluded(key) => {
+            partition_point(entries, |k| Ok(compare(buf, k, key)? == Ordering::Less))?
+        }
+        Bound::Unbounded => entries.len(),
+    };
+
+    // NB: Inverted ranges are empty.
+    Ok(start..end.max(start))
+}
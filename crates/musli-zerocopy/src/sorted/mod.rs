@@ -0,0 +1,30 @@
+//! A ZeroCopy [`Map`] which stores its entries sorted by key, and looks them
+//! up using a binary search.
+//!
+//! Constructing these maps is cheap and they have no storage overhead beyond
+//! the entries themselves, which makes them well suited for small to medium
+//! sized data sets. Since entries are ordered they also support [range
+//! queries], which neither [`phf`] nor [`swiss`] can do.
+//!
+//! Keys are compared through [`Visit`], so plain keys like `u32` are compared
+//! directly while keys like [`Ref<str>`] are compared by the value they
+//! reference in the buffer.
+//!
+//! [range queries]: MapRef::range
+//! [`phf`]: crate::phf
+//! [`swiss`]: crate::swiss
+//! [`Visit`]: crate::buf::Visit
+//! [`Ref<str>`]: crate::Ref
+
+pub(crate) use self::entry::Entry;
+mod entry;
+
+#[doc(inline)]
+pub use self::map::{Map, MapRef};
+pub mod map;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::factory::*;
+#[cfg(feature = "alloc")]
+mod factory;
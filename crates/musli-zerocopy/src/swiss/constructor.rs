@@ -8,7 +8,7 @@ use core::marker::PhantomData;
 use core::mem::size_of;
 use core::ptr::NonNull;
 
-use crate::buf::{self, Buf, StoreBuf};
+use crate::buf::{self, StoreBuf};
 use crate::error::{Error, ErrorKind};
 use crate::swiss::raw::{h2, is_full, probe_seq, special_is_empty, Group, ProbeSeq};
 use crate::traits::ZeroCopy;
@@ -72,9 +72,12 @@ where
         }
     }
 
-    /// Access the underlying buffer.
-    pub(crate) fn buf(&self) -> &Buf {
-        self.buf.as_buf()
+    /// Access the underlying buffer mutably.
+    ///
+    /// Anything stored through the returned buffer is appended after the
+    /// table, which is not affected.
+    pub(crate) fn buf_mut(&mut self) -> &mut S {
+        self.buf
     }
 
     /// Export bucket mask.
@@ -84,7 +87,7 @@ where
 
     /// Get the length of the table.
     pub(crate) fn len(&self) -> usize {
-        self.bucket_mask - self.growth_left
+        bucket_mask_to_capacity(self.bucket_mask) - self.growth_left
     }
 
     /// Returns the number of buckets in the table.
@@ -93,8 +96,9 @@ where
         self.bucket_mask + 1
     }
 
-    /// Insert the given zero copy value into the table.
-    pub(crate) fn insert(&mut self, hash: u64, value: &T) -> Result<Bucket<'_, T>, Error>
+    /// Insert the given zero copy value into the table, returning the index of
+    /// the bucket it was inserted into.
+    pub(crate) fn insert(&mut self, hash: u64, value: &T) -> Result<usize, Error>
     where
         T: ZeroCopy,
     {
@@ -114,7 +118,9 @@ where
             return Err(Error::new(ErrorKind::CapacityError));
         }
 
-        Ok(self.insert_in_slot(hash, slot, value))
+        let index = slot.index;
+        self.insert_in_slot(hash, slot, value);
+        Ok(index)
     }
 
     /// Inserts a new element into the table in the given slot, and returns its
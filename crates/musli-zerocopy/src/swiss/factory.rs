@@ -1,19 +1,23 @@
+#![allow(clippy::type_complexity)]
+
 use core::hash::{Hash, Hasher};
 use core::mem::size_of;
 
-use crate::buf::{Buf, OwnedBuf, StoreBuf, Visit};
-use crate::endian::ByteOrder;
-use crate::error::Error;
+use alloc::vec::Vec;
+
+use crate::buf::sort::try_sort_by;
+use crate::buf::{OwnedBuf, StoreBuf, StoreReport, Visit};
+use crate::endian::{ByteOrder, Endian};
+use crate::error::{Error, ErrorKind};
 use crate::pointer::{Ref, Size};
 use crate::sip::SipHasher13;
 use crate::swiss::constructor::Constructor;
+use crate::swiss::hashing::FIXED_SEED;
 use crate::swiss::map::RawTableRef;
 use crate::swiss::raw::{self};
-use crate::swiss::{Entry, MapRef, SetRef};
+use crate::swiss::{Entry, MapRef, OrderedMapRef, SetRef};
 use crate::ZeroCopy;
 
-const FIXED_SEED: u64 = 1234567890;
-
 /// Store a [SwissTable] map into an [`OwnedBuf`].
 ///
 /// This returns a [`MapRef`] which can be bound into a [`Map`] through the
@@ -116,7 +120,243 @@ where
     I::IntoIter: ExactSizeIterator,
 {
     let (key, ctrl, buckets, bucket_mask, len) = store_raw(entries, buf, |buf, (k, v), hasher| {
-        k.visit(buf, |key| key.hash(hasher))?;
+        k.visit(buf.as_buf(), |key| key.hash(hasher))?;
+        Ok(Entry::new(k, v))
+    })?;
+
+    Ok(MapRef::new(
+        key,
+        RawTableRef::new(ctrl, buckets, bucket_mask, len),
+    ))
+}
+
+/// Store a [SwissTable] map into a buffer, returning a [`StoreReport`] with
+/// statistics about its construction.
+///
+/// See [`store_map`] for more information.
+///
+/// [SwissTable]: https://abseil.io/about/design/swisstables
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let (map, report) = swiss::store_map_with_report(&mut buf, (0..10u32).map(|n| (n, n)))?;
+///
+/// assert_eq!(report.entries, 10);
+/// assert_eq!(report.buckets, 16);
+/// assert_eq!(report.bytes, buf.len());
+///
+/// let map = buf.bind(map)?;
+/// assert_eq!(map.get(&5u32)?, Some(&5));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_report<K, V, S, I>(
+    buf: &mut S,
+    entries: I,
+) -> Result<(MapRef<K, V, S::ByteOrder, S::Size>, StoreReport), Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, V)>,
+    I::IntoIter: ExactSizeIterator,
+{
+    let start = buf.len();
+
+    let (key, ctrl, buckets, bucket_mask, len) = store_raw(entries, buf, |buf, (k, v), hasher| {
+        k.visit(buf.as_buf(), |key| key.hash(hasher))?;
+        Ok(Entry::new(k, v))
+    })?;
+
+    let map = MapRef::new(key, RawTableRef::new(ctrl, buckets, bucket_mask, len));
+
+    let report = StoreReport {
+        entries: map.len(),
+        buckets: map.capacity(),
+        retries: 0,
+        bytes: buf.len() - start,
+    };
+
+    Ok((map, report))
+}
+
+/// Store a [SwissTable] map into an [`OwnedBuf`], which in addition to the
+/// table stores an index of its entries sorted by key.
+///
+/// This returns an [`OrderedMapRef`] which can be bound into an
+/// [`OrderedMap`] through the [`bind()`] method for convenience. Lookups still
+/// use the hash table, while [`OrderedMap::iter_ordered()`] walks the index.
+/// Since entries are sorted before they are inserted, both the layout of the
+/// table and the iteration order are a pure function of the stored entries.
+///
+/// The index costs 4 bytes per entry.
+///
+/// See [`store_map`] for more information.
+///
+/// [`bind()`]: crate::buf::Buf::bind
+/// [`OrderedMap`]: crate::swiss::OrderedMap
+/// [`OrderedMap::iter_ordered()`]: crate::swiss::OrderedMap::iter_ordered
+/// [SwissTable]: https://abseil.io/about/design/swisstables
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let pairs = [
+///     (buf.store_unsized("c"), 3u32),
+///     (buf.store_unsized("a"), 1u32),
+///     (buf.store_unsized("b"), 2u32),
+/// ];
+///
+/// let map = swiss::store_map_sorted(&mut buf, pairs)?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("a")?, Some(&1));
+/// assert_eq!(map.get("d")?, None);
+///
+/// let mut entries = Vec::new();
+///
+/// for entry in map.iter_ordered() {
+///     let (key, value) = entry?;
+///     entries.push((buf.load(*key)?, *value));
+/// }
+///
+/// assert_eq!(entries, [("a", 1), ("b", 2), ("c", 3)]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_sorted<K, V, I, E: ByteOrder, O: Size>(
+    buf: &mut OwnedBuf<E, O>,
+    entries: I,
+) -> Result<OrderedMapRef<K, V, E, O>, Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Ord + Hash,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+
+    // Keys might reference data which is not yet aligned in the buffer.
+    buf.align_in_place();
+
+    try_sort_by(&mut entries, |(a, _), (b, _)| {
+        a.visit(buf, |a| b.visit(buf, |b| a.cmp(b)))?
+    })?;
+
+    let mut order = Vec::with_capacity(entries.len());
+    let mut overflow = false;
+
+    let (key, ctrl, buckets, bucket_mask, len) = store_raw_with(
+        entries,
+        buf,
+        |buf, (k, v), hasher| {
+            k.visit(buf.as_buf(), |key| key.hash(hasher))?;
+            Ok(Entry::new(k, v))
+        },
+        |index| match u32::try_from(index) {
+            Ok(index) => order.push(Endian::new(index)),
+            Err(..) => overflow = true,
+        },
+    )?;
+
+    if overflow {
+        return Err(Error::new(ErrorKind::CapacityError));
+    }
+
+    let map = MapRef::new(key, RawTableRef::new(ctrl, buckets, bucket_mask, len));
+    let order = buf.store_slice(&order);
+    Ok(OrderedMapRef::new(map, order))
+}
+
+/// Store a [SwissTable] map into a buffer, using `store_key` to materialize
+/// each key into the buffer as the map is being constructed.
+///
+/// This is useful when converting an existing collection, like a `HashMap`
+/// or a `BTreeMap`, since the keys don't have to be stored up front and
+/// collected before calling [`store_map`]. Keys are stored after the table in
+/// the buffer.
+///
+/// See [`store_map`] for more information.
+///
+/// [SwissTable]: https://abseil.io/about/design/swisstables
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::from("first"), 1u32);
+/// map.insert(String::from("second"), 2u32);
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map_with_keys(&mut buf, map, |buf, key| {
+///     buf.store_unsized(key.as_str())
+/// })?;
+///
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get("second")?, Some(&2));
+/// assert_eq!(map.get("third")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// Values which are [`Copy`] can be stored from a borrowed map:
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let map = BTreeMap::from([(String::from("a"), 1u32), (String::from("b"), 2u32)]);
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let stored = swiss::store_map_with_keys(
+///     &mut buf,
+///     map.iter().map(|(k, v)| (k, *v)),
+///     |buf, key| buf.store_unsized(key.as_str()),
+/// )?;
+///
+/// let stored = buf.bind(stored)?;
+///
+/// assert_eq!(stored.get("a")?, Some(&1));
+/// assert_eq!(stored.get("b")?, Some(&2));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map_with_keys<Q, K, V, I, S, F>(
+    buf: &mut S,
+    entries: I,
+    mut store_key: F,
+) -> Result<MapRef<K, V, S::ByteOrder, S::Size>, Error>
+where
+    K: Visit + ZeroCopy,
+    V: ZeroCopy,
+    K::Target: Hash,
+    I: IntoIterator<Item = (Q, V)>,
+    I::IntoIter: ExactSizeIterator,
+    S: ?Sized + StoreBuf,
+    F: FnMut(&mut S, Q) -> K,
+{
+    let (key, ctrl, buckets, bucket_mask, len) = store_raw(entries, buf, |buf, (q, v), hasher| {
+        let k = store_key(buf, q);
+        k.visit(buf.as_buf(), |key| key.hash(hasher))?;
         Ok(Entry::new(k, v))
     })?;
 
@@ -213,7 +453,7 @@ where
     S: ?Sized + StoreBuf,
 {
     let (key, ctrl, buckets, bucket_mask, len) = store_raw(entries, buf, |buf, v, hasher| {
-        v.visit(buf, |key| key.hash(hasher))?;
+        v.visit(buf.as_buf(), |key| key.hash(hasher))?;
         Ok(v)
     })?;
 
@@ -228,16 +468,36 @@ type Raw<U, E, O> = (u64, Ref<[u8], E, O>, Ref<[U], E, O>, usize, usize);
 
 // Raw store function which is capable of storing any value using a hashing
 // adapter.
-fn store_raw<T, U, I, S>(
+fn store_raw<T, U, I, S, F>(
+    entries: I,
+    buf: &mut S,
+    hash: F,
+) -> Result<Raw<U, S::ByteOrder, S::Size>, Error>
+where
+    U: ZeroCopy,
+    I: IntoIterator<Item = T>,
+    I::IntoIter: ExactSizeIterator,
+    S: ?Sized + StoreBuf,
+    F: FnMut(&mut S, T, &mut SipHasher13) -> Result<U, Error>,
+{
+    store_raw_with(entries, buf, hash, |_| {})
+}
+
+// Same as `store_raw`, but `inserted` is called with the index of the bucket
+// each value was inserted into, in the order they were inserted.
+fn store_raw_with<T, U, I, S, F, G>(
     entries: I,
     buf: &mut S,
-    hash: fn(&Buf, T, &mut SipHasher13) -> Result<U, Error>,
+    mut hash: F,
+    mut inserted: G,
 ) -> Result<Raw<U, S::ByteOrder, S::Size>, Error>
 where
     U: ZeroCopy,
     I: IntoIterator<Item = T>,
     I::IntoIter: ExactSizeIterator,
     S: ?Sized + StoreBuf,
+    F: FnMut(&mut S, T, &mut SipHasher13) -> Result<U, Error>,
+    G: FnMut(usize),
 {
     let entries = entries.into_iter();
     let key = FIXED_SEED;
@@ -259,7 +519,7 @@ where
     buf.fill(raw::EMPTY, ctrl_len + size_of::<raw::Group>());
 
     let base_ptr = buf.next_offset::<U>();
-    buf.fill(0, size_of::<T>().wrapping_mul(buckets));
+    buf.fill(0, size_of::<U>().wrapping_mul(buckets));
 
     let (bucket_mask, len) = {
         buf.align_in_place();
@@ -267,9 +527,12 @@ where
 
         for v in entries {
             let mut hasher = SipHasher13::new_with_keys(0, key);
-            let v = hash(table.buf(), v, &mut hasher)?;
+            let v = hash(table.buf_mut(), v, &mut hasher)?;
+            // The hash adapter might store data in the buffer, so ensure that
+            // it is still aligned for the table.
+            table.buf_mut().align_in_place();
             let hash = hasher.finish();
-            table.insert(hash, &v)?;
+            inserted(table.insert(hash, &v)?);
         }
 
         (table.bucket_mask(), table.len())
@@ -0,0 +1,76 @@
+use core::hash::{Hash, Hasher};
+
+use crate::sip::SipHasher13;
+
+/// The seed used by maps and sets constructed through this crate.
+pub(crate) const FIXED_SEED: u64 = 1234567890;
+
+/// Compute the hash of a key the same way as maps and sets constructed through
+/// [`swiss::store_map`] and [`swiss::store_set`].
+///
+/// This allows the hash of a key to be computed once and used to look it up in
+/// any number of maps through [`MapRef::get_hashed`]. Note that for stored
+/// keys like [`Ref<str>`] this should be the hash of the value being
+/// referenced, like `str`.
+///
+/// [`swiss::store_map`]: crate::swiss::store_map
+/// [`swiss::store_set`]: crate::swiss::store_set
+/// [`MapRef::get_hashed`]: crate::swiss::MapRef::get_hashed
+/// [`Ref<str>`]: crate::Ref
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let first = swiss::store_map(&mut buf, [(1u32, 2u32), (2, 3)])?;
+/// let second = swiss::store_map(&mut buf, [(1u32, 4u32), (3, 5)])?;
+///
+/// let hash = swiss::hash_key(&1u32);
+///
+/// assert_eq!(first.get_hashed(&buf, hash, |k| *k == 1)?, Some((&1, &2)));
+/// assert_eq!(second.get_hashed(&buf, hash, |k| *k == 1)?, Some((&1, &4)));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[inline]
+pub fn hash_key<Q>(key: &Q) -> u64
+where
+    Q: ?Sized + Hash,
+{
+    hash_key_with_seed(FIXED_SEED, key)
+}
+
+/// Compute the hash of a key using the given `seed`.
+///
+/// The seed used by a map can be accessed through [`MapRef::seed`], which
+/// ensures that the computed hash matches the map even if it was constructed
+/// with a different seed than [`hash_key`] uses.
+///
+/// [`MapRef::seed`]: crate::swiss::MapRef::seed
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map(&mut buf, [(1u32, 2u32), (2, 3)])?;
+/// let hash = swiss::hash_key_with_seed(map.seed(), &2u32);
+///
+/// assert_eq!(map.get_hashed(&buf, hash, |k| *k == 2)?, Some((&2, &3)));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[inline]
+pub fn hash_key_with_seed<Q>(seed: u64, key: &Q) -> u64
+where
+    Q: ?Sized + Hash,
+{
+    let mut hasher = SipHasher13::new_with_keys(0, seed);
+    key.hash(&mut hasher);
+    hasher.finish()
+}
@@ -14,16 +14,15 @@
 
 use core::borrow::Borrow;
 use core::convert::identity as likely;
-use core::hash::{Hash, Hasher};
+use core::hash::Hash;
 use core::mem::size_of;
 
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
 use crate::error::{Error, ErrorKind};
 use crate::pointer::{DefaultSize, Ref, Size};
-use crate::sip::SipHasher13;
 use crate::swiss::raw::{h2, probe_seq, Group};
-use crate::swiss::Entry;
+use crate::swiss::{hash_key_with_seed, Entry};
 use crate::{Endian, ZeroCopy};
 
 /// A map bound to a [`Buf`] through [`Buf::bind`] for convenience.
@@ -138,6 +137,69 @@ where
         self.table.len == 0
     }
 
+    /// Get the number of buckets in the table of the map, which is the number
+    /// of entries it has space for.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 10);
+    /// assert_eq!(map.capacity(), 16);
+    /// assert_eq!(map.load_factor(), 0.625);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.table.entries.len()
+    }
+
+    /// Get the fraction of buckets in the table of the map which are
+    /// occupied.
+    ///
+    /// This is `0.0` for a map without any buckets.
+    #[inline]
+    pub fn load_factor(&self) -> f64 {
+        if self.capacity() == 0 {
+            return 0.0;
+        }
+
+        self.table.len as f64 / self.capacity() as f64
+    }
+
+    /// Get the number of bytes used by the table of the map in addition to
+    /// the entries themselves.
+    ///
+    /// This includes the control bytes of the table and any unoccupied
+    /// buckets.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.table_overhead_bytes() >= 16 + 6 * 8);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn table_overhead_bytes(&self) -> usize {
+        let unused = self.capacity().saturating_sub(self.table.len);
+        self.table.ctrl.len() + unused * size_of::<Entry<K, V>>()
+    }
+
     /// Test if the map contains the given `key`.
     ///
     /// ## Examples
@@ -172,13 +234,81 @@ where
         Ok(entry.is_some())
     }
 
+    /// Get an entry from the map using a precomputed `hash` and a custom
+    /// equality function `eq` which is called with candidate stored keys.
+    ///
+    /// The hash must be computed with [`swiss::hash_key_with_seed`] using the
+    /// [seed of the map], or with [`swiss::hash_key`] for maps constructed by
+    /// this crate. A mismatching hash will simply not find the key.
+    ///
+    /// [`swiss::hash_key_with_seed`]: crate::swiss::hash_key_with_seed
+    /// [`swiss::hash_key`]: crate::swiss::hash_key
+    /// [seed of the map]: Self::seed
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let pairs = [
+    ///     (buf.store_unsized("first"), 1u32),
+    ///     (buf.store_unsized("second"), 2u32),
+    /// ];
+    ///
+    /// let map = swiss::store_map(&mut buf, pairs)?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let hash = swiss::hash_key("second");
+    /// let entry = map.get_hashed(hash, |k| matches!(buf.load(*k), Ok("second")))?;
+    ///
+    /// assert_eq!(entry.map(|(_, v)| v), Some(&2));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_hashed<F>(&self, hash: u64, mut eq: F) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        F: FnMut(&K) -> bool,
+    {
+        let entry = self.table.find(hash, |e| Ok(eq(&e.key)))?;
+        Ok(entry.map(|entry| (&entry.key, &entry.value)))
+    }
+
+    /// Get the seed used when hashing keys in this map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let hash = swiss::hash_key_with_seed(map.seed(), &1);
+    /// assert_eq!(hash, swiss::hash_key(&1));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.key
+    }
+
+    /// Access the buckets of the table, which includes unoccupied ones.
+    #[inline]
+    pub(crate) fn entries(&self) -> &'a [Entry<K, V>] {
+        self.table.entries
+    }
+
+    #[inline]
     fn hash<H>(&self, value: &H) -> u64
     where
         H: ?Sized + Hash,
     {
-        let mut hasher = SipHasher13::new_with_keys(0, self.key);
-        value.hash(&mut hasher);
-        hasher.finish()
+        hash_key_with_seed(self.key, value)
     }
 }
 
@@ -332,6 +462,67 @@ where
         self.table.len.to_ne() == 0
     }
 
+    /// Get the number of buckets in the table of the map, which is the number
+    /// of entries it has space for.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    ///
+    /// assert_eq!(map.len(), 10);
+    /// assert_eq!(map.capacity(), 16);
+    /// assert_eq!(map.load_factor(), 0.625);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.table.entries.len()
+    }
+
+    /// Get the fraction of buckets in the table of the map which are
+    /// occupied.
+    ///
+    /// This is `0.0` for a map without any buckets.
+    #[inline]
+    pub fn load_factor(&self) -> f64 {
+        if self.capacity() == 0 {
+            return 0.0;
+        }
+
+        self.table.len.to_ne() as f64 / self.capacity() as f64
+    }
+
+    /// Get the number of bytes used by the table of the map in addition to
+    /// the entries themselves.
+    ///
+    /// This includes the control bytes of the table and any unoccupied
+    /// buckets.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, (0..10u32).map(|n| (n, n)))?;
+    ///
+    /// assert!(map.table_overhead_bytes() >= 16 + 6 * 8);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn table_overhead_bytes(&self) -> usize {
+        let unused = self.capacity().saturating_sub(self.table.len.to_ne());
+        self.table.ctrl.len() + unused * size_of::<Entry<K, V>>()
+    }
+
     /// Test if the map contains the given `key`.
     ///
     /// ## Examples
@@ -365,14 +556,89 @@ where
         Ok(entry.is_some())
     }
 
+    /// Get an entry from the map using a precomputed `hash` and a custom
+    /// equality function `eq` which is called with candidate stored keys.
+    ///
+    /// This is useful when looking up the same key in many maps, since the
+    /// hash only needs to be computed once. It also permits lookups using
+    /// custom equality semantics.
+    ///
+    /// The hash must be computed with [`swiss::hash_key_with_seed`] using the
+    /// [seed of the map], or with [`swiss::hash_key`] for maps constructed by
+    /// this crate. A mismatching hash will simply not find the key.
+    ///
+    /// [`swiss::hash_key_with_seed`]: crate::swiss::hash_key_with_seed
+    /// [`swiss::hash_key`]: crate::swiss::hash_key
+    /// [seed of the map]: Self::seed
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let maps = [
+    ///     swiss::store_map(&mut buf, [(1u64, 2u32), (2, 3)])?,
+    ///     swiss::store_map(&mut buf, [(2u64, 4u32), (3, 5)])?,
+    ///     swiss::store_map(&mut buf, [(3u64, 6u32)])?,
+    /// ];
+    ///
+    /// let hash = swiss::hash_key(&2u64);
+    ///
+    /// let mut found = Vec::new();
+    ///
+    /// for map in &maps {
+    ///     if let Some((_, value)) = map.get_hashed(&buf, hash, |k| *k == 2)? {
+    ///         found.push(*value);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(found, [3, 4]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_hashed<'a, F>(
+        &self,
+        buf: &'a Buf,
+        hash: u64,
+        mut eq: F,
+    ) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        K: 'a,
+        F: FnMut(&K) -> bool,
+    {
+        let entry = self.table.find(buf, hash, |e| Ok(eq(&e.key)))?;
+        Ok(entry.map(|entry| (&entry.key, &entry.value)))
+    }
+
+    /// Get the seed used when hashing keys in this map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let hash = swiss::hash_key_with_seed(map.seed(), &1);
+    /// assert_eq!(hash, swiss::hash_key(&1));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.key.to_ne()
+    }
+
     #[inline]
     fn hash<H>(&self, value: &H) -> u64
     where
         H: ?Sized + Hash,
     {
-        let mut hasher = SipHasher13::new_with_keys(0, self.key.to_ne());
-        value.hash(&mut hasher);
-        hasher.finish()
+        hash_key_with_seed(self.key.to_ne(), value)
     }
 }
 
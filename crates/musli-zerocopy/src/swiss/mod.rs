@@ -27,6 +27,9 @@ mod raw;
 pub(crate) use self::entry::Entry;
 mod entry;
 
+pub use self::hashing::{hash_key, hash_key_with_seed};
+mod hashing;
+
 #[doc(inline)]
 pub use self::map::{Map, MapRef};
 pub mod map;
@@ -35,6 +38,10 @@ pub mod map;
 pub use self::set::{Set, SetRef};
 pub mod set;
 
+#[doc(inline)]
+pub use self::ordered_map::{OrderedMap, OrderedMapRef};
+pub mod ordered_map;
+
 #[cfg(feature = "alloc")]
 mod constructor;
 
@@ -0,0 +1,438 @@
+//! A map which in addition to a [SwissTable] stores an index of its entries
+//! sorted by key, allowing for deterministic iteration.
+//!
+//! These maps are inserted into a buffer using [`swiss::store_map_sorted`].
+//!
+//! There's two types provided by this module:
+//! * [`OrderedMap<K, V>`] which is a *bound* reference to a map, providing a
+//!   convenient map-like access.
+//! * [`OrderedMapRef<K, V>`] which is the *pointer* of the map. This is what
+//!   you store in [`ZeroCopy`] types and is what is returned by
+//!   [`swiss::store_map_sorted`].
+//!
+//! [SwissTable]: https://abseil.io/about/design/swisstables
+//! [`swiss::store_map_sorted`]: crate::swiss::store_map_sorted
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::iter::FusedIterator;
+use core::slice;
+
+use crate::buf::{Bindable, Buf, Visit};
+use crate::endian::{ByteOrder, Native};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::swiss::{Entry, Map, MapRef};
+use crate::{Endian, ZeroCopy};
+
+/// An ordered map bound to a [`Buf`] through [`Buf::bind`] for convenience.
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map_sorted(&mut buf, [(2, 3), (1, 2)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1)?, Some(&2));
+/// assert_eq!(map.get(&2)?, Some(&3));
+/// assert_eq!(map.get(&3)?, None);
+///
+/// let entries = map.iter_ordered().collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct OrderedMap<'a, K, V, E: ByteOrder = Native> {
+    map: Map<'a, K, V>,
+    order: &'a [Endian<u32, E>],
+}
+
+impl<'a, K, V, E: ByteOrder> OrderedMap<'a, K, V, E>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get(&1)?, Some(&2));
+    /// assert_eq!(map.get(&3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn get<Q>(&self, key: &Q) -> Result<Option<&V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.get(key)
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.contains_key(&1)?);
+    /// assert!(!map.contains_key(&3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn contains_key<Q>(&self, key: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Iterate over all entries in the map, in key order.
+    ///
+    /// Each entry is looked up through the index stored alongside the table,
+    /// so an error is produced if the index refers to a bucket which doesn't
+    /// exist.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(3, 4), (1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let entries = map.iter_ordered().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(entries, [(&1, &2), (&2, &3), (&3, &4)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter_ordered(&self) -> IterOrdered<'a, K, V, E> {
+        IterOrdered::new(self.map.entries(), self.order)
+    }
+
+    /// Get the length of the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Bind an [`OrderedMapRef`] into an [`OrderedMap`].
+impl<K, V, E: ByteOrder, O: Size> Bindable for OrderedMapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    type Bound<'a> = OrderedMap<'a, K, V, E> where Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        Ok(OrderedMap {
+            map: self.map.bind(buf)?,
+            order: buf.load(self.order)?,
+        })
+    }
+}
+
+/// A stored reference to an ordered map.
+///
+/// Constructed through [`swiss::store_map_sorted`].
+///
+/// [`swiss::store_map_sorted`]: crate::swiss::store_map_sorted
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map_sorted(&mut buf, [(2, 3), (1, 2)])?;
+///
+/// assert_eq!(map.get(&buf, &1)?, Some(&2));
+/// assert_eq!(map.get(&buf, &2)?, Some(&3));
+/// assert_eq!(map.get(&buf, &3)?, None);
+///
+/// let entries = map.iter_ordered(&buf)?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct OrderedMapRef<K, V, E: ByteOrder = Native, O: Size = DefaultSize>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    map: MapRef<K, V, E, O>,
+    order: Ref<[Endian<u32, E>], E, O>,
+}
+
+impl<K, V, E: ByteOrder, O: Size> OrderedMapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(map: MapRef<K, V, E, O>, order: Ref<[Endian<u32, E>], E, O>) -> Self {
+        Self { map, order }
+    }
+
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// assert_eq!(map.get(&buf, &3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn get<'a, Q>(&self, buf: &'a Buf, key: &Q) -> Result<Option<&'a V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: 'a + Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.get(buf, key)
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert!(map.contains_key(&buf, &1)?);
+    /// assert!(!map.contains_key(&buf, &3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn contains_key<Q>(&self, buf: &Buf, key: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.contains_key(buf, key)
+    }
+
+    /// Iterate over all entries in the map, in key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(3, 4), (1, 2), (2, 3)])?;
+    ///
+    /// let entries = map.iter_ordered(&buf)?.collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(entries, [(&1, &2), (&2, &3), (&3, &4)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter_ordered<'a>(&self, buf: &'a Buf) -> Result<IterOrdered<'a, K, V, E>, Error> {
+        Ok(self.bind(buf)?.iter_ordered())
+    }
+
+    /// Get the length of the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map_sorted(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size> Clone for OrderedMapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, E: ByteOrder, O: Size> Copy for OrderedMapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+}
+
+/// An iterator over the entries of an ordered map, in key order.
+///
+/// Constructed through [`OrderedMap::iter_ordered`] or
+/// [`OrderedMapRef::iter_ordered`].
+pub struct IterOrdered<'a, K, V, E: ByteOrder = Native> {
+    entries: &'a [Entry<K, V>],
+    order: slice::Iter<'a, Endian<u32, E>>,
+}
+
+impl<'a, K, V, E: ByteOrder> IterOrdered<'a, K, V, E> {
+    #[inline]
+    fn new(entries: &'a [Entry<K, V>], order: &'a [Endian<u32, E>]) -> Self {
+        Self {
+            entries,
+            order: order.iter(),
+        }
+    }
+
+    #[inline]
+    fn entry(&self, index: &Endian<u32, E>) -> Result<(&'a K, &'a V), Error> {
+        let index = index.to_ne() as usize;
+
+        let Some(entry) = self.entries.get(index) else {
+            return Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index,
+                len: self.entries.len(),
+            }));
+        };
+
+        Ok((&entry.key, &entry.value))
+    }
+}
+
+impl<'a, K, V, E: ByteOrder> Iterator for IterOrdered<'a, K, V, E> {
+    type Item = Result<(&'a K, &'a V), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.order.next()?;
+        Some(self.entry(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<K, V, E: ByteOrder> DoubleEndedIterator for IterOrdered<'_, K, V, E> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.order.next_back()?;
+        Some(self.entry(index))
+    }
+}
+
+impl<K, V, E: ByteOrder> ExactSizeIterator for IterOrdered<'_, K, V, E> {}
+
+impl<K, V, E: ByteOrder> FusedIterator for IterOrdered<'_, K, V, E> {}
@@ -7,7 +7,7 @@ use core::ptr;
 use anyhow::Result;
 
 use crate::pointer::Ref;
-use crate::{Error, OwnedBuf, ZeroCopy};
+use crate::{Error, OwnedBuf, UnsizedZeroCopy, ZeroCopy};
 
 #[test]
 fn test_ref_to_slice() -> Result<()> {
@@ -52,6 +52,42 @@ fn test_ref_to_slice() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn phf_every_key() -> Result<()> {
+    use crate::phf;
+
+    for len in [1u32, 7, 64, 1000] {
+        let mut buf = OwnedBuf::new();
+        let map = phf::store_map(&mut buf, (0..len).map(|n| (n, n * 2)))?;
+        let map = buf.bind(map)?;
+
+        for n in 0..len {
+            assert_eq!(map.get(&n)?, Some(&(n * 2)), "{n} of {len}");
+        }
+
+        assert_eq!(map.get(&len)?, None);
+    }
+
+    // Duplicate keys can never be perfectly hashed.
+    let mut buf = OwnedBuf::new();
+    assert!(phf::store_map(&mut buf, [(1u32, 1u32), (1, 2)]).is_err());
+    Ok(())
+}
+
+#[test]
+fn swiss_len() -> Result<()> {
+    use crate::swiss;
+
+    for len in [0u32, 2, 7, 8, 100, 1000] {
+        let mut buf = OwnedBuf::new();
+        let map = swiss::store_map(&mut buf, (0..len).map(|n| (n, n)))?;
+        let map = buf.bind(map)?;
+        assert_eq!(map.len(), len as usize);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_zero_padded() {
     #[derive(ZeroCopy)]
@@ -733,3 +769,1251 @@ fn padded_enum() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn relative_ref_negative_delta() -> Result<()> {
+    use crate::pointer::RelativeRef;
+
+    let mut buf = OwnedBuf::new();
+
+    let value = buf.store(&42u32);
+    let at = buf.next_offset::<RelativeRef<u32>>();
+    let reference = buf.store(&RelativeRef::<u32>::new(at, value.offset()));
+
+    let reference = buf.load(reference)?;
+    assert_eq!(reference.delta(), -(at as isize));
+    assert_eq!(reference.resolve(&buf)?, value);
+    assert_eq!(buf.load(reference)?, &42);
+
+    let reference = RelativeRef::<u32>::new(16, 8);
+    assert_eq!(reference.to_ref_at(8)?.offset(), 0);
+    assert!(reference.to_ref_at(4).is_err());
+    Ok(())
+}
+
+#[test]
+fn relative_ref_delta_overflow() -> Result<()> {
+    use crate::endian::Native;
+    use crate::error::{ErrorKind, IntoRepr};
+    use crate::pointer::RelativeRef;
+
+    type Small = RelativeRef<u32, Native, u8>;
+
+    assert_eq!(Small::try_with_metadata(0, 127, ())?.delta(), 127);
+    assert_eq!(Small::try_with_metadata(128, 0, ())?.delta(), -128);
+
+    assert_eq!(
+        Small::try_with_metadata(10, 138, ()),
+        Err(Error::new(ErrorKind::InvalidDeltaRange {
+            from: 10,
+            to: 138,
+            ty: "u8"
+        }))
+    );
+
+    assert_eq!(
+        Small::try_with_metadata(139, 10, ()),
+        Err(Error::new(ErrorKind::InvalidDeltaRange {
+            from: 139,
+            to: 10,
+            ty: "u8"
+        }))
+    );
+
+    type Wide = RelativeRef<u32, Native, usize>;
+
+    assert!(Wide::try_with_metadata(0, usize::MAX, ()).is_err());
+
+    assert_eq!(
+        Wide::new(0, 8).to_ref_at(usize::MAX),
+        Err(Error::new(ErrorKind::Overflow {
+            at: usize::MAX,
+            len: 8
+        }))
+    );
+
+    assert_eq!(
+        Small::new(0, 100).to_ref_at(200),
+        Err(Error::new(ErrorKind::InvalidOffsetRange {
+            offset: 300usize.into_repr(),
+            max: u8::MAX.into_repr(),
+        }))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn load_owned_nested() -> Result<()> {
+    use alloc::string::String;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::buf::LoadOwned;
+
+    #[derive(ZeroCopy)]
+    #[repr(C, packed)]
+    #[zero_copy(crate, owned = OwnedPacked)]
+    struct Packed(u8, u32);
+
+    #[derive(Debug, PartialEq)]
+    struct OwnedPacked(u8, u32);
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, owned = OwnedOuter)]
+    struct Outer {
+        names: Ref<[Ref<str>]>,
+        values: [Ref<[u32]>; 2],
+        packed: Ref<Packed>,
+        #[zero_copy(ignore)]
+        _marker: PhantomData<u32>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct OwnedOuter {
+        names: Vec<String>,
+        values: [Vec<u32>; 2],
+        packed: OwnedPacked,
+        _marker: PhantomData<u32>,
+    }
+
+    let mut buf = OwnedBuf::new();
+
+    let names = buf.store_strings(["a", "b"]);
+    let first = buf.store_slice(&[1, 2, 3]);
+    let second = buf.store_slice(&[]);
+    let packed = buf.store(&Packed(1, 2));
+
+    let outer = buf.store(&Outer {
+        names,
+        values: [first, second],
+        packed,
+        _marker: PhantomData,
+    });
+
+    let expected = OwnedOuter {
+        names: vec![String::from("a"), String::from("b")],
+        values: [vec![1, 2, 3], vec![]],
+        packed: OwnedPacked(1, 2),
+        _marker: PhantomData,
+    };
+
+    assert_eq!(outer.to_owned_in(&buf)?, expected);
+
+    let broken = Ref::<Outer>::new(buf.len());
+    assert!(broken.to_owned_in(&buf).is_err());
+    Ok(())
+}
+
+#[test]
+fn error_context() -> Result<()> {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    #[zero_copy(crate)]
+    enum Inner {
+        Empty,
+        Flag { value: bool },
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate)]
+    struct Nested(Inner);
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate)]
+    struct Outer {
+        first: Nested,
+        second: [Nested; 1],
+    }
+
+    let mut buf = OwnedBuf::new();
+    buf.store(&0u32);
+
+    let outer = buf.store(&Outer {
+        first: Nested(Inner::Empty),
+        second: [Nested(Inner::Flag { value: true })],
+    });
+
+    let offset = outer.offset();
+    buf.as_mut_slice()[offset + 3] = 2;
+
+    let Err(error) = buf.load(outer) else {
+        panic!("expected error");
+    };
+
+    assert_eq!(error.type_name(), Some("Outer"));
+    assert_eq!(error.offset(), Some(offset + 3));
+    assert_eq!(
+        error.path().collect::<Vec<_>>(),
+        ["second", "0", "Flag.value"]
+    );
+    assert_eq!(
+        error.to_string(),
+        "Outer.second.0.Flag.value @ 0x0007: Illegal bool representation 2"
+    );
+
+    // The context of an error doesn't participate in comparisons.
+    assert_eq!(
+        buf.load(outer).map(|_| ()),
+        Err(Error::new(crate::error::ErrorKind::IllegalBool { repr: 2 }))
+    );
+
+    let Err(error) = buf.load(Ref::<bool>::new(offset + 3)) else {
+        panic!("expected error");
+    };
+
+    assert_eq!(error.type_name(), Some("bool"));
+    assert_eq!(error.offset(), Some(offset + 3));
+    assert_eq!(error.path().count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn error_size() {
+    use crate::error::ErrorKind;
+
+    // The context of an error is boxed, so that it only costs a pointer.
+    assert!(size_of::<Error>() <= size_of::<(ErrorKind, usize)>());
+}
+
+#[test]
+fn debug_in_nested() -> Result<()> {
+    use alloc::format;
+
+    #[derive(ZeroCopy)]
+    #[repr(C, packed)]
+    #[zero_copy(crate, debug)]
+    struct Packed(u8, u32);
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    #[zero_copy(crate, debug)]
+    enum Pet {
+        Cat { name: Ref<str> },
+        Fish(u32),
+        Rock,
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, debug)]
+    struct Outer {
+        pets: Ref<[Pet]>,
+        packed: Ref<Packed>,
+        broken: Ref<str>,
+        #[zero_copy(ignore)]
+        _marker: PhantomData<u32>,
+    }
+
+    let mut buf = OwnedBuf::new();
+
+    let name = buf.store_unsized("Nyx");
+    let pets = buf.store_slice(&[Pet::Cat { name }, Pet::Fish(7), Pet::Rock]);
+    let packed = buf.store(&Packed(1, 2));
+
+    let outer = buf.store(&Outer {
+        pets,
+        packed,
+        broken: Ref::with_metadata(0, 1000),
+        _marker: PhantomData,
+    });
+
+    let output = format!("{:?}", buf.debug(outer));
+
+    assert!(output.starts_with(
+        r#"Outer { pets: [Cat { name: "Nyx" }, Fish(7), Rock], packed: Packed(1, 2), broken: <invalid: "#
+    ));
+
+    // At depth one only the outer structure is loaded.
+    assert_eq!(
+        format!("{:?}", buf.debug(pets).with_depth(1)),
+        format!("[Cat {{ name: {name:?} }}, Fish(7), Rock]")
+    );
+    Ok(())
+}
+
+#[test]
+fn eq_in_across_buffers() -> Result<()> {
+    use crate::buf::EqIn;
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    #[zero_copy(crate, eq)]
+    enum Pet {
+        Cat { name: Ref<str> },
+        Fish(u32),
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, eq)]
+    struct Outer {
+        pets: Ref<[Pet]>,
+        #[zero_copy(ignore)]
+        _marker: PhantomData<u32>,
+    }
+
+    fn store(buf: &mut OwnedBuf, name: &str, fish: u32) -> Ref<Outer> {
+        let name = buf.store_unsized(name);
+        let pets = buf.store_slice(&[Pet::Cat { name }, Pet::Fish(fish)]);
+
+        buf.store(&Outer {
+            pets,
+            _marker: PhantomData,
+        })
+    }
+
+    let mut a = OwnedBuf::new();
+    let mut b = OwnedBuf::new();
+    b.store_unsized("padding");
+
+    let first = store(&mut a, "Nyx", 1);
+    let second = store(&mut b, "Nyx", 1);
+    let third = store(&mut b, "Nyx", 2);
+    let fourth = store(&mut b, "Luna", 1);
+
+    assert_ne!(first, second);
+    assert!(first.eq_in(&a, &second, &b)?);
+    assert!(!first.eq_in(&a, &third, &b)?);
+    assert!(!first.eq_in(&a, &fourth, &b)?);
+
+    let broken = Ref::<Outer>::new(b.len());
+    assert!(first.eq_in(&a, &broken, &b).is_err());
+    Ok(())
+}
+
+#[test]
+fn sorted_map_ranges() -> Result<()> {
+    use core::ops::Bound;
+
+    use alloc::vec::Vec;
+
+    use crate::sorted;
+
+    let mut buf = OwnedBuf::new();
+
+    let empty = sorted::store_map(&mut buf, Vec::<(u32, u32)>::new())?;
+    assert!(empty.is_empty());
+    assert_eq!(empty.get(&buf, &1u32)?, None);
+    assert_eq!(empty.range(&buf, 0..10u32)?.count(), 0);
+
+    let map = sorted::store_map(&mut buf, (0..10u32).rev().map(|n| (n * 2, n)))?;
+
+    let collect = |range: (Bound<u32>, Bound<u32>)| -> Result<Vec<u32>> {
+        Ok(map.range(&buf, range)?.map(|(_, v)| *v).collect())
+    };
+
+    assert_eq!(
+        collect((Bound::Excluded(4), Bound::Included(10)))?,
+        [3, 4, 5]
+    );
+    assert_eq!(
+        collect((Bound::Included(3), Bound::Excluded(9)))?,
+        [2, 3, 4]
+    );
+    assert!(collect((Bound::Unbounded, Bound::Excluded(0)))?.is_empty());
+    assert!(collect((Bound::Included(10), Bound::Included(2)))?.is_empty());
+    assert_eq!(collect((Bound::Unbounded, Bound::Unbounded))?.len(), 10);
+
+    for n in 0..10u32 {
+        assert_eq!(map.get(&buf, &(n * 2))?, Some(&n));
+        assert_eq!(map.get(&buf, &(n * 2 + 1))?, None);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn sorted_map_broken_keys() -> Result<()> {
+    use alloc::vec::Vec;
+
+    use crate::sorted;
+
+    let mut buf = OwnedBuf::new();
+
+    let mut entries = Vec::new();
+
+    for n in (0..40u32).rev() {
+        let key = buf.store_unsized(alloc::format!("key{n:02}").as_str());
+        entries.push((key, n));
+    }
+
+    let map = sorted::store_map(&mut buf, entries.iter().copied())?;
+    let map = buf.bind(map)?;
+    assert_eq!(map.get("key07")?, Some(&7));
+    assert_eq!(map.get("key39")?, Some(&39));
+
+    // A key which can't be loaded from the buffer is reported instead of
+    // silently producing a map which is out of order.
+    let broken = Ref::<str>::with_metadata(buf.len(), 4);
+    entries.insert(20, (broken, 100));
+
+    let Err(error) = sorted::store_map(&mut buf, entries) else {
+        panic!("expected sorting to fail");
+    };
+
+    assert!(error.is_out_of_bounds(), "{error}");
+    Ok(())
+}
+
+#[test]
+fn unsized_struct_trailing_slice() -> Result<()> {
+    use alloc::vec::Vec;
+
+    #[derive(UnsizedZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate)]
+    struct Record<T: ?Sized = [u8]> {
+        id: u32,
+        data: T,
+    }
+
+    const _: () = assert!(<Record as UnsizedZeroCopy>::PADDED);
+
+    let mut buf = OwnedBuf::new();
+
+    let record: &Record = &Record {
+        id: 42,
+        data: [1, 2, 3],
+    };
+
+    let record = buf.store_unsized(record);
+    assert_eq!(record.offset(), 0);
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&42u32.to_ne_bytes());
+    expected.extend_from_slice(&[1, 2, 3, 0]);
+    assert_eq!(buf.as_slice(), &expected[..]);
+
+    let loaded = buf.load(record)?;
+    assert_eq!(loaded.id, 42);
+    assert_eq!(&loaded.data, &[1, 2, 3]);
+
+    buf.load_mut(record)?.data[1] = 20;
+    assert_eq!(&buf.load(record)?.data, &[1, 20, 3]);
+
+    let fits = Ref::<Record>::with_metadata(0, 4);
+    assert_eq!(buf.load(fits)?.data.len(), 4);
+
+    let overflow = Ref::<Record>::with_metadata(0, 5);
+    assert!(buf.load(overflow).is_err());
+    Ok(())
+}
+
+#[test]
+fn hash_value_ignores_offsets() -> Result<()> {
+    use core::hash::Hasher;
+    use std::collections::hash_map::DefaultHasher;
+
+    #[derive(ZeroCopy)]
+    #[repr(u8)]
+    #[zero_copy(crate, hash)]
+    enum Pet {
+        Cat { name: Ref<str> },
+        Fish(u32),
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, hash)]
+    struct Outer {
+        pets: Ref<[Pet]>,
+        #[zero_copy(ignore)]
+        _marker: PhantomData<u32>,
+    }
+
+    fn store(buf: &mut OwnedBuf, name: &str, fish: u32) -> Ref<Outer> {
+        let name = buf.store_unsized(name);
+        let pets = buf.store_slice(&[Pet::Cat { name }, Pet::Fish(fish)]);
+
+        buf.store(&Outer {
+            pets,
+            _marker: PhantomData,
+        })
+    }
+
+    fn hash(buf: &OwnedBuf, value: Ref<Outer>) -> Result<u64, Error> {
+        let mut hasher = DefaultHasher::new();
+        buf.hash_value(value, &mut hasher)?;
+        Ok(hasher.finish())
+    }
+
+    let mut a = OwnedBuf::new();
+    let mut b = OwnedBuf::new();
+    b.store_unsized("padding");
+
+    let first = store(&mut a, "Nyx", 1);
+    let second = store(&mut b, "Nyx", 1);
+    let third = store(&mut b, "Nyx", 2);
+    let fourth = store(&mut b, "Luna", 1);
+
+    assert_ne!(first, second);
+    assert_eq!(hash(&a, first)?, hash(&b, second)?);
+    assert_ne!(hash(&a, first)?, hash(&b, third)?);
+    assert_ne!(hash(&a, first)?, hash(&b, fourth)?);
+
+    let broken = Ref::<Outer>::new(b.len());
+    assert!(hash(&b, broken).is_err());
+    Ok(())
+}
+
+#[test]
+fn ref_field_offsets() -> Result<()> {
+    use crate::pointer::ref_field;
+
+    #[derive(ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate)]
+    struct Padded {
+        a: u8,
+        b: u64,
+        c: u16,
+        d: u32,
+    }
+
+    #[derive(ZeroCopy)]
+    #[repr(C, packed)]
+    #[zero_copy(crate)]
+    struct Packed {
+        a: u8,
+        b: u64,
+    }
+
+    let padded = Ref::<Padded>::new(16);
+    assert_eq!(ref_field!(padded, a).offset(), 16);
+    assert_eq!(ref_field!(padded, b).offset(), 24);
+    assert_eq!(ref_field!(padded, c).offset(), 32);
+    assert_eq!(ref_field!(padded, d).offset(), 36);
+
+    #[derive(ZeroCopy)]
+    #[repr(C, packed(2))]
+    #[zero_copy(crate)]
+    struct Packed2(u8, u64, u16);
+
+    #[derive(ZeroCopy)]
+    #[repr(transparent)]
+    #[zero_copy(crate)]
+    struct Transparent<T: ZeroCopy>(T, #[zero_copy(ignore)] PhantomData<T>);
+
+    let packed = Ref::<Packed>::new(3);
+    assert_eq!(ref_field!(packed, a).offset(), 3);
+    assert_eq!(ref_field!(packed, b).offset(), 4);
+
+    let packed = Ref::<Packed2>::new(0);
+    assert_eq!(ref_field!(packed, 1).offset(), 2);
+    assert_eq!(ref_field!(packed, 2).offset(), 10);
+
+    let transparent = Ref::<Transparent<Padded>>::new(8);
+    assert_eq!(ref_field!(ref_field!(transparent, 0), d).offset(), 28);
+
+    let mut buf = OwnedBuf::new();
+
+    let padded = buf.store(&Padded {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+    });
+
+    *buf.load_mut(ref_field!(padded, c))? = 30;
+
+    let value = buf.load(padded)?;
+    assert_eq!((value.a, value.b, value.c, value.d), (1, 2, 30, 4));
+    Ok(())
+}
+
+#[test]
+fn checked_ref_arithmetic() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    let slice = buf.store_slice(&[1u32, 2, 3, 4, 5]);
+
+    let first = slice.get(0).expect("missing element");
+    let last = first.offset_by(4);
+    assert_eq!(buf.load(last)?, &5);
+    assert_eq!(last.offset_from(first), 4);
+    assert_eq!(first.checked_offset_from(last), None);
+
+    let narrow = Ref::<u32, crate::endian::Native, u8>::new(250u32);
+    assert_eq!(narrow.checked_offset_by(1).map(Ref::offset), Some(254));
+    assert!(narrow.checked_offset_by(2).is_none());
+    assert!(narrow.checked_offset_by(usize::MAX).is_none());
+
+    let unit = Ref::<()>::new(8u32);
+    assert_eq!(unit.offset_by(usize::MAX).offset(), 8);
+    assert_eq!(unit.checked_offset_from(unit), None);
+
+    let tail = slice.try_slice(2..5).expect("in bounds");
+    assert_eq!(buf.load(tail)?, &[3, 4, 5][..]);
+    assert_eq!(
+        tail.try_slice(1..3).map(|s| s.offset()),
+        Some(slice.offset() + 12)
+    );
+    assert!(tail.try_slice(1..4).is_none());
+    assert!(slice.try_slice(usize::MAX..usize::MAX).is_none());
+    Ok(())
+}
+
+#[test]
+fn phf_custom_hasher() -> Result<()> {
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    use crate::phf::{self, Fx, Sip};
+
+    let mut buf = OwnedBuf::new();
+
+    let mut entries = Vec::new();
+
+    for n in 0..256u32 {
+        let key = buf.store_unsized(format!("key{n}").as_str());
+        entries.push((key, n));
+    }
+
+    let sip = phf::store_map_with_hasher(&mut buf, entries.clone(), Sip)?;
+    let fx = phf::store_map_with_hasher(&mut buf, entries, Fx)?;
+    let fx_set = phf::store_set_with_hasher(&mut buf, 0..256u32, Fx)?;
+
+    let sip = buf.bind(sip)?;
+    let fx = buf.bind(fx)?;
+    let fx_set = buf.bind(fx_set)?;
+
+    for n in 0..256u32 {
+        let key = format!("key{n}");
+        assert_eq!(sip.get(key.as_str())?, Some(&n));
+        assert_eq!(fx.get(key.as_str())?, Some(&n));
+        assert!(fx_set.contains(&n)?);
+    }
+
+    assert_eq!(sip.get("missing")?, None);
+    assert_eq!(fx.get("missing")?, None);
+    assert!(!fx_set.contains(&256u32)?);
+    Ok(())
+}
+
+#[test]
+fn store_maps_with_keys() -> Result<()> {
+    use std::collections::HashMap;
+
+    use alloc::format;
+    use alloc::string::String;
+
+    use crate::{phf, swiss};
+
+    let mut map = HashMap::new();
+
+    for n in 0..64u32 {
+        map.insert(format!("key{n}"), n);
+    }
+
+    let mut buf = OwnedBuf::new();
+
+    let store_key = |buf: &mut OwnedBuf, key: &String| buf.store_unsized(key.as_str());
+
+    let swiss_map =
+        swiss::store_map_with_keys(&mut buf, map.iter().map(|(k, v)| (k, *v)), store_key)?;
+    let phf_map = phf::store_map_with_keys(&mut buf, map.iter().map(|(k, v)| (k, *v)), store_key)?;
+    let fx_map = phf::store_map_with_keys_and_hasher(
+        &mut buf,
+        map.iter().map(|(k, v)| (k, *v)),
+        phf::Fx,
+        store_key,
+    )?;
+
+    let empty_swiss =
+        swiss::store_map_with_keys(&mut buf, HashMap::<String, u32>::new(), |buf, key| {
+            buf.store_unsized(key.as_str())
+        })?;
+
+    let empty_phf =
+        phf::store_map_with_keys(&mut buf, HashMap::<String, u32>::new(), |buf, key| {
+            buf.store_unsized(key.as_str())
+        })?;
+
+    let swiss_map = buf.bind(swiss_map)?;
+    let phf_map = buf.bind(phf_map)?;
+    let fx_map = buf.bind(fx_map)?;
+    let empty_swiss = buf.bind(empty_swiss)?;
+    let empty_phf = buf.bind(empty_phf)?;
+
+    assert_eq!(swiss_map.len(), 64);
+
+    for (key, value) in &map {
+        assert_eq!(swiss_map.get(key.as_str())?, Some(value));
+        assert_eq!(phf_map.get(key.as_str())?, Some(value));
+        assert_eq!(fx_map.get(key.as_str())?, Some(value));
+    }
+
+    assert_eq!(swiss_map.get("missing")?, None);
+    assert_eq!(phf_map.get("missing")?, None);
+    assert_eq!(empty_swiss.get("key0")?, None);
+    assert_eq!(empty_phf.get("key0")?, None);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn zerocopy_interop() -> Result<()> {
+    use zerocopy::{AsBytes, FromBytes, FromZeroes};
+
+    #[derive(Debug, PartialEq, ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, zerocopy)]
+    struct Header {
+        magic: [u8; 4],
+        version: u32,
+        len: u64,
+    }
+
+    #[derive(Debug, PartialEq, ZeroCopy)]
+    #[repr(C, packed)]
+    #[zero_copy(crate, zerocopy(unaligned))]
+    struct Packed {
+        tag: u8,
+        bytes: [u8; 3],
+    }
+
+    let header = Header {
+        magic: *b"MUSL",
+        version: 1,
+        len: 42,
+    };
+
+    assert_eq!(Header::new_zeroed().len, 0);
+    assert_eq!(Header::read_from(header.as_bytes()).as_ref(), Some(&header));
+
+    let mut buf = OwnedBuf::new();
+    buf.store(&1u8);
+
+    let header_ref = buf.store(&header);
+
+    let packed = buf.store_as_bytes(&Packed {
+        tag: 1,
+        bytes: [2, 3, 4],
+    });
+
+    let header_bytes = buf.store_as_bytes(&header);
+
+    assert_eq!(header_bytes.offset() % align_of::<Header>(), 0);
+    assert_eq!(buf.load_from_bytes::<Header, _, _>(header_bytes)?, &header);
+    assert_eq!(buf.load(header_ref)?.as_bytes(), buf.load(header_bytes)?);
+    assert_eq!(buf.load(packed)?, &[1, 2, 3, 4]);
+    assert_eq!(
+        Packed::ref_from(buf.load(packed)?),
+        Some(&Packed {
+            tag: 1,
+            bytes: [2, 3, 4]
+        })
+    );
+    assert!(buf.load_from_bytes::<u64, _, _>(packed).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_interop() -> Result<()> {
+    #[derive(Debug, Clone, Copy, PartialEq, ZeroCopy)]
+    #[repr(C)]
+    #[zero_copy(crate, bytemuck)]
+    struct Vertex {
+        position: [f32; 3],
+        color: u32,
+    }
+
+    let vertices = [
+        Vertex {
+            position: [0.0, 1.0, 2.0],
+            color: 0xff0000ff,
+        },
+        Vertex {
+            position: [3.0, 4.0, 5.0],
+            color: 0x00ff00ff,
+        },
+    ];
+
+    let mut buf = OwnedBuf::new();
+    buf.store(&1u8);
+    let slice = buf.store_slice(&vertices);
+
+    let bytes = buf.load_bytes_of(slice)?;
+    assert_eq!(bytes, bytemuck::cast_slice::<Vertex, u8>(&vertices));
+    assert_eq!(bytemuck::cast_slice::<u8, Vertex>(bytes), &vertices[..]);
+    assert_eq!(
+        <Vertex as bytemuck::Zeroable>::zeroed(),
+        Vertex {
+            position: [0.0; 3],
+            color: 0,
+        }
+    );
+
+    let out_of_bounds = Ref::<[Vertex]>::with_metadata(slice.offset(), 3);
+    assert!(buf.load_bytes_of(out_of_bounds).is_err());
+    Ok(())
+}
+
+#[test]
+fn tuple_types() -> Result<()> {
+    use crate::phf;
+    use crate::tuple::{Pair, Quad, Triple};
+
+    assert!(!<Pair<u32, u32> as ZeroCopy>::PADDED);
+    assert!(<Pair<u8, u32> as ZeroCopy>::PADDED);
+    assert!(<Triple<u32, u8, u32> as ZeroCopy>::PADDED);
+    assert!(!<Quad<u8, u8, u16, u32> as ZeroCopy>::PADDED);
+    assert!(<Pair<u32, bool> as ZeroCopy>::PADDED);
+    assert!(!<Pair<u32, bool> as ZeroCopy>::ANY_BITS);
+    assert_eq!(size_of::<Quad<u8, u8, u16, u32>>(), 8);
+
+    let mut buf = OwnedBuf::new();
+
+    let name = buf.store_unsized("name");
+    let pair = buf.store(&Pair::from((name, 42u64)));
+    let quad = buf.store(&Quad(1u8, 2u8, 3u16, 4u32));
+
+    let map = phf::store_map(
+        &mut buf,
+        [(1u32, Pair(1u8, 2u32)), (2, Pair(3, 4)), (3, Pair(5, 6))],
+    )?;
+
+    let Pair(name, number) = *buf.load(pair)?;
+    assert_eq!(buf.load(name)?, "name");
+    assert_eq!(number, 42);
+    assert_eq!(buf.load(quad)?.into_tuple(), (1, 2, 3, 4));
+
+    let map = buf.bind(map)?;
+    assert_eq!(map.get(&2u32)?, Some(&Pair(3, 4)));
+    assert_eq!(map.get(&4u32)?, None);
+
+    // Padding is zeroed when storing padded tuples.
+    let mut buf = OwnedBuf::new();
+    buf.store(&Pair(0xffu8, 0xffffffffu32));
+    assert_eq!(buf.as_slice(), &[0xff, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+    Ok(())
+}
+
+#[test]
+fn map_statistics() -> Result<()> {
+    use crate::{phf, swiss};
+
+    let mut buf = OwnedBuf::new();
+
+    let (phf_map, phf_report) =
+        phf::store_map_with_report(&mut buf, (0..100u32).map(|n| (n, n as u64)))?;
+
+    assert_eq!(phf_report.entries, 100);
+    assert_eq!(phf_report.buckets, 20);
+    assert_eq!(phf_report.bytes, buf.len());
+    assert_eq!(phf_map.len(), 100);
+    assert_eq!(phf_map.capacity(), 100);
+    assert_eq!(phf_map.table_overhead_bytes(), 20 * 8);
+
+    let start = buf.len();
+    let (swiss_map, swiss_report) =
+        swiss::store_map_with_report(&mut buf, (0..100u32).map(|n| (n, n as u64)))?;
+
+    assert_eq!(swiss_report.entries, 100);
+    assert_eq!(swiss_report.buckets, 128);
+    assert_eq!(swiss_report.bytes, buf.len() - start);
+    assert_eq!(swiss_report.retries, 0);
+
+    // Both kinds of maps report the same statistics.
+    let reports: [crate::buf::StoreReport; 2] = [phf_report, swiss_report];
+    assert!(reports.iter().all(|report| report.entries == 100));
+    assert_eq!(swiss_map.capacity(), 128);
+    assert_eq!(swiss_map.load_factor(), 100.0 / 128.0);
+
+    let empty = swiss::store_map(&mut buf, core::iter::empty::<(u32, u32)>())?;
+    assert_eq!(empty.len(), 0);
+    assert_eq!(empty.load_factor(), 0.0);
+
+    let phf_map = buf.bind(phf_map)?;
+    let swiss_unbound = swiss_map;
+    let swiss_map = buf.bind(swiss_map)?;
+
+    assert_eq!(phf_map.len(), 100);
+    assert_eq!(phf_map.table_overhead_bytes(), 20 * 8);
+    assert_eq!(swiss_map.capacity(), 128);
+    assert_eq!(swiss_map.load_factor(), swiss_unbound.load_factor());
+    assert_eq!(
+        swiss_map.table_overhead_bytes(),
+        swiss_unbound.table_overhead_bytes()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn swiss_sorted_iteration() -> Result<()> {
+    use alloc::vec::Vec;
+
+    use crate::swiss;
+
+    let mut a = OwnedBuf::new();
+    let map_a = swiss::store_map_sorted(&mut a, (0..100u32).map(|n| (n, n * 2)))?;
+
+    let mut b = OwnedBuf::new();
+    let map_b = swiss::store_map_sorted(&mut b, (0..100u32).rev().map(|n| (n, n * 2)))?;
+
+    // The order in which entries are provided doesn't affect the output.
+    assert_eq!(a.as_slice(), b.as_slice());
+
+    let map_a = a.bind(map_a)?;
+    let map_b = b.bind(map_b)?;
+
+    assert_eq!(map_a.len(), 100);
+    assert_eq!(map_a.get(&42u32)?, Some(&84));
+    assert_eq!(map_a.get(&100u32)?, None);
+
+    let entries = map_a.iter_ordered().collect::<Result<Vec<_>, _>>()?;
+    let expected = (0..100u32).map(|n| (n, n * 2)).collect::<Vec<_>>();
+    let entries = entries
+        .into_iter()
+        .map(|(k, v)| (*k, *v))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+
+    let last = map_b.iter_ordered().next_back().transpose()?;
+    assert_eq!(last, Some((&99, &198)));
+
+    let mut buf = OwnedBuf::new();
+
+    let pairs = [
+        (buf.store_unsized("b"), 2u32),
+        (buf.store_unsized("c"), 3u32),
+        (buf.store_unsized("a"), 1u32),
+    ];
+
+    let map = swiss::store_map_sorted(&mut buf, pairs)?;
+
+    let mut keys = Vec::new();
+
+    for entry in map.iter_ordered(&buf)? {
+        let (key, _) = entry?;
+        keys.push(buf.load(*key)?);
+    }
+
+    assert_eq!(keys, ["a", "b", "c"]);
+    assert_eq!(map.get(&buf, "c")?, Some(&3));
+    Ok(())
+}
+
+#[test]
+fn swiss_sorted_broken_keys() -> Result<()> {
+    use alloc::vec::Vec;
+
+    use crate::swiss;
+
+    let mut buf = OwnedBuf::new();
+
+    let mut entries = Vec::new();
+
+    for n in 0..20u32 {
+        entries.push((buf.store_unsized(alloc::format!("key{n}").as_str()), n));
+    }
+
+    entries.insert(10, (Ref::<str>::with_metadata(buf.len(), 4), 100));
+
+    let Err(error) = swiss::store_map_sorted(&mut buf, entries) else {
+        panic!("expected sorting to fail");
+    };
+
+    assert!(error.is_out_of_bounds(), "{error}");
+    Ok(())
+}
+
+#[test]
+fn fixed_str() -> Result<()> {
+    use alloc::string::ToString;
+
+    use crate::{phf, Buf, FixedStr};
+
+    assert_eq!(size_of::<FixedStr<4>>(), 5);
+    assert_eq!(align_of::<FixedStr<4>>(), 1);
+
+    let mut buf = OwnedBuf::new();
+
+    let sek = FixedStr::<4>::try_from("SEK")?;
+    let empty = FixedStr::<4>::try_from("")?;
+    let full = FixedStr::<4>::try_from("åä")?;
+
+    let sek_ref = buf.store(&sek);
+    let empty_ref = buf.store(&empty);
+    let full_ref = buf.store(&full);
+
+    assert_eq!(&buf[..5], &[3, b'S', b'E', b'K', 0]);
+    assert_eq!(buf.load(sek_ref)?, "SEK");
+    assert_eq!(buf.load(empty_ref)?, "");
+    assert_eq!(buf.load(full_ref)?, "åä");
+    assert_eq!(*buf.load(sek_ref)?, sek);
+
+    let error = FixedStr::<4>::try_from("EURO!").unwrap_err();
+    assert_eq!(
+        error,
+        Error::new(crate::error::ErrorKind::FixedStrOverflow {
+            len: 5,
+            capacity: 4
+        })
+    );
+
+    // Length exceeding capacity.
+    let buf = Buf::new(&[5, b'a', b'b', b'c', b'd']);
+    let error = buf.load(Ref::<FixedStr<4>>::zero()).unwrap_err();
+    assert!(error.is_illegal_value());
+
+    // Unused bytes must be zeroed.
+    let buf = Buf::new(&[2, b'a', b'b', 0, b'd']);
+    let error = buf.load(Ref::<FixedStr<4>>::zero()).unwrap_err();
+    assert!(error.is_illegal_value());
+    assert!(error
+        .to_string()
+        .ends_with("Unused byte at index 3 of fixed string is not zero"));
+
+    // Only the used prefix has to be valid UTF-8, so a truncated character is
+    // an error.
+    let buf = Buf::new(&[1, 0xc3, 0xa5, 0, 0]);
+    let error = buf.load(Ref::<FixedStr<4>>::zero()).unwrap_err();
+    assert!(error.is_utf8());
+
+    let buf = Buf::new(&[2, 0xc3, 0xa5, 0, 0]);
+    assert_eq!(buf.load(Ref::<FixedStr<4>>::zero())?, "å");
+
+    // Fixed strings can be used as inline map keys.
+    let mut buf = OwnedBuf::new();
+
+    let map = phf::store_map(
+        &mut buf,
+        [
+            (FixedStr::<4>::try_from("SEK")?, 1u32),
+            (FixedStr::<4>::try_from("EUR")?, 2u32),
+        ],
+    )?;
+
+    let map = buf.bind(map)?;
+    assert_eq!(map.get("SEK")?, Some(&1));
+    assert_eq!(map.get(&FixedStr::<4>::try_from("EUR")?)?, Some(&2));
+    assert_eq!(map.get("USD")?, None);
+    Ok(())
+}
+
+#[test]
+fn usize_offset_overflow() -> Result<()> {
+    use crate::endian::Native;
+
+    let mut buf = OwnedBuf::new().with_size::<usize>();
+    let value = buf.store(&42u64);
+    assert_eq!(buf.load(value)?, &42);
+
+    let end = Ref::<u64, Native, usize>::new(usize::MAX - 3);
+    assert!(buf.load(end).unwrap_err().is_arithmetic());
+    assert!(buf.load_unaligned(end).unwrap_err().is_arithmetic());
+    assert!(buf.swap(end, value).unwrap_err().is_arithmetic());
+
+    let slice = Ref::<[u32], Native, usize>::with_metadata(usize::MAX - 7, 4);
+    assert!(slice.get(1).is_some());
+    assert!(slice.get(2).is_none());
+    assert!(slice.try_slice(2..4).is_none());
+    assert!(buf.load(slice).is_err());
+    Ok(())
+}
+
+#[test]
+fn error_base_saturates() {
+    use crate::error::ErrorKind;
+
+    let error = Error::new(ErrorKind::OutOfRangeBounds {
+        range: usize::MAX - 4..usize::MAX,
+        len: usize::MAX - 8,
+    });
+
+    let error = error.with_base(16);
+    assert_eq!(error.range(), Some(usize::MAX..usize::MAX));
+}
+
+#[test]
+fn time_types() -> Result<()> {
+    use alloc::string::ToString;
+
+    use crate::swiss;
+    use crate::time::{Duration, UnixTimestamp};
+
+    assert!(<Duration as ZeroCopy>::PADDED);
+    assert!(!<Duration as ZeroCopy>::ANY_BITS);
+    assert_eq!(size_of::<Duration>(), 16);
+    assert_eq!(size_of::<UnixTimestamp>(), 16);
+
+    let max = core::time::Duration::new(u64::MAX, 999_999_999);
+    assert_eq!(core::time::Duration::from(Duration::from(max)), max);
+    assert!(Duration::new(1, 0) < Duration::new(1, 1));
+    assert!(UnixTimestamp::new(-1, 999_999_999) < UnixTimestamp::UNIX_EPOCH);
+
+    let mut buf = OwnedBuf::new();
+
+    let durations = buf.store_slice(&[Duration::new(1, 2), Duration::from(max)]);
+    let timestamp = buf.store(&UnixTimestamp::new(-3, 4));
+    let map = swiss::store_map(
+        &mut buf,
+        [(Duration::new(1, 0), 1u32), (Duration::new(0, 1), 2u32)],
+    )?;
+
+    assert_eq!(
+        buf.load(durations)?,
+        &[Duration::new(1, 2), Duration::from(max)]
+    );
+    assert_eq!(buf.load(timestamp)?, &UnixTimestamp::new(-3, 4));
+
+    let map = buf.bind(map)?;
+    assert_eq!(map.get(&Duration::new(1, 0))?, Some(&1));
+    assert_eq!(map.get(&Duration::new(0, 1))?, Some(&2));
+    assert_eq!(map.get(&Duration::ZERO)?, None);
+
+    // Corrupt the nanoseconds of the second duration.
+    let nanos = durations.offset() + size_of::<Duration>() + size_of::<u64>();
+    buf.store_at(Ref::<u32>::new(nanos), &1_000_000_000u32)?;
+
+    let error = buf.load(durations).unwrap_err();
+    assert!(error.is_illegal_value());
+    assert!(error
+        .to_string()
+        .ends_with("Nanoseconds 1000000000 is out of range, must be less than one second"));
+    assert!(buf.load(durations.get_unchecked(0)).is_ok());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn unix_timestamp_system_time() -> Result<()> {
+    use std::time::SystemTime;
+
+    use crate::time::UnixTimestamp;
+
+    let cases = [
+        (UnixTimestamp::UNIX_EPOCH, SystemTime::UNIX_EPOCH),
+        (
+            UnixTimestamp::new(1, 500),
+            SystemTime::UNIX_EPOCH + core::time::Duration::new(1, 500),
+        ),
+        (
+            UnixTimestamp::new(-1, 0),
+            SystemTime::UNIX_EPOCH - core::time::Duration::from_secs(1),
+        ),
+        (
+            UnixTimestamp::new(-1, 1),
+            SystemTime::UNIX_EPOCH - core::time::Duration::new(0, 999_999_999),
+        ),
+    ];
+
+    for (timestamp, time) in cases {
+        assert_eq!(UnixTimestamp::try_from(time)?, timestamp);
+        assert_eq!(SystemTime::try_from(timestamp)?, time);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn phf_multimap() -> Result<()> {
+    use alloc::vec::Vec;
+
+    use crate::phf;
+
+    let mut buf = OwnedBuf::new();
+
+    let words = ["b", "a", "c", "a", "b", "a"]
+        .into_iter()
+        .map(|word| buf.store_unsized(word))
+        .collect::<Vec<_>>();
+
+    let map = phf::store_multimap(
+        &mut buf,
+        words.iter().enumerate().map(|(n, &word)| (word, n as u32)),
+    )?;
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(buf.load(map.get(&buf, "a")?)?, &[1, 3, 5]);
+    assert!(buf.load(map.get(&buf, "d")?)?.is_empty());
+
+    let map = buf.bind(map)?;
+    assert_eq!(map.get("a")?, &[1, 3, 5]);
+    assert_eq!(map.get("b")?, &[0, 4]);
+    assert_eq!(map.get("c")?, &[2]);
+    assert!(map.get("d")?.is_empty());
+    assert!(map.contains_key(&words[0])?);
+    assert!(!map.contains_key("d")?);
+
+    let mut buf = OwnedBuf::new();
+    let map = phf::store_multimap(&mut buf, (0..1000u32).map(|n| (n % 7, n)))?;
+    let map = buf.bind(map)?;
+
+    assert_eq!(map.len(), 7);
+
+    for key in 0..7u32 {
+        let values = map.get(&key)?;
+        assert!(values.iter().all(|value| value % 7 == key));
+        assert!(values.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    let mut buf = OwnedBuf::new();
+    let map = phf::store_multimap::<u32, u32, _, _>(&mut buf, [])?;
+    let map = buf.bind(map)?;
+    assert!(map.is_empty());
+    assert!(map.get(&1u32)?.is_empty());
+
+    let mut buf = OwnedBuf::new();
+    let mut entries = words.iter().map(|&word| (word, 0u32)).collect::<Vec<_>>();
+    entries.push((Ref::<str>::with_metadata(1024u32, 4), 1));
+
+    let Err(error) = phf::store_multimap(&mut buf, entries) else {
+        panic!("expected sorting to fail");
+    };
+
+    assert!(error.is_out_of_bounds(), "{error}");
+    Ok(())
+}
+
+#[cfg(target_pointer_width = "32")]
+mod pointer_width_32 {
+    use anyhow::Result;
+
+    use crate::endian::Native;
+    use crate::{OwnedBuf, Ref};
+
+    #[test]
+    fn usize_matches_default_size() {
+        assert_eq!(
+            Ref::<u32, Native, usize>::new(u32::MAX as usize).offset(),
+            Ref::<u32>::new(u32::MAX as usize).offset()
+        );
+    }
+
+    #[test]
+    fn usize_offsets() -> Result<()> {
+        let mut buf = OwnedBuf::new().with_size::<usize>();
+
+        let value = buf.store(&42u32);
+        let slice = buf.store_slice(&[1u32, 2, 3, 4]);
+        let string = buf.store_unsized("Hello World");
+
+        assert_eq!(buf.load(value)?, &42);
+        assert_eq!(buf.load(slice)?, &[1, 2, 3, 4]);
+        assert_eq!(buf.load(string)?, "Hello World");
+
+        let end = Ref::<u32, Native, usize>::new(usize::MAX);
+        assert!(buf.load(end).is_err());
+        assert!(end.checked_offset_by(1).is_none());
+
+        let slice = Ref::<[u32], Native, usize>::with_metadata(usize::MAX - 3, usize::MAX / 4);
+        assert!(slice.get(0).is_some());
+        assert!(slice.get(1).is_none());
+        assert!(buf.load(slice).is_err());
+
+        let string = Ref::<str, Native, usize>::with_metadata(usize::MAX, usize::MAX);
+        assert!(buf.load(string).is_err());
+        Ok(())
+    }
+}
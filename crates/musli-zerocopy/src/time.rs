@@ -0,0 +1,404 @@
+//! `repr(C)` types for durations and timestamps which implement [`ZeroCopy`].
+//!
+//! The layout of [`core::time::Duration`] and [`SystemTime`] is unspecified, so
+//! they can't be stored directly. The types in this module can be used instead,
+//! and can be converted to and from their corresponding standard types.
+//!
+//! [`SystemTime`]: std::time::SystemTime
+//!
+//! # Examples
+//!
+//! ```
+//! use musli_zerocopy::OwnedBuf;
+//! use musli_zerocopy::time::{Duration, UnixTimestamp};
+//!
+//! let mut buf = OwnedBuf::new();
+//!
+//! let elapsed = buf.store(&Duration::from(core::time::Duration::from_millis(1500)));
+//! let created = buf.store(&UnixTimestamp::new(1_700_000_000, 500));
+//!
+//! let elapsed = core::time::Duration::from(*buf.load(elapsed)?);
+//! assert_eq!(elapsed.as_millis(), 1500);
+//!
+//! let created = buf.load(created)?;
+//! assert_eq!(created.as_secs(), 1_700_000_000);
+//! assert_eq!(created.subsec_nanos(), 500);
+//! # Ok::<_, musli_zerocopy::Error>(())
+//! ```
+
+use crate::buf::{Buf, Padder, Validator, Visit};
+use crate::endian::ByteOrder;
+use crate::error::{Error, ErrorKind};
+use crate::traits::ZeroCopy;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// A `repr(C)` span of time, corresponding to [`core::time::Duration`].
+///
+/// The duration is stored as a `u64` number of whole seconds followed by a
+/// `u32` number of nanoseconds.
+///
+/// # Validation
+///
+/// When a duration is loaded from a buffer the number of nanoseconds must be
+/// less than one second.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref};
+/// use musli_zerocopy::time::Duration;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let durations = buf.store_slice(&[Duration::new(1, 0), Duration::new(2, 500)]);
+/// let durations = buf.load(durations)?;
+/// assert_eq!(durations[1].as_secs(), 2);
+/// assert_eq!(durations[1].subsec_nanos(), 500);
+///
+/// let mut buf = OwnedBuf::new();
+/// buf.store(&0u64);
+/// buf.store(&1_000_000_000u32);
+/// buf.store(&0u32);
+///
+/// let error = buf.load(Ref::<Duration>::zero()).unwrap_err();
+/// assert!(error.is_illegal_value());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct Duration {
+    secs: u64,
+    nanos: u32,
+}
+
+impl Duration {
+    /// A duration of zero time.
+    pub const ZERO: Self = Self { secs: 0, nanos: 0 };
+
+    /// Construct a new duration from a number of whole seconds and additional
+    /// nanoseconds.
+    ///
+    /// If the number of nanoseconds is greater than one second, it carries
+    /// over into the number of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carry from nanoseconds overflows the number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::Duration;
+    ///
+    /// let duration = Duration::new(1, 1_500_000_000);
+    /// assert_eq!(duration.as_secs(), 2);
+    /// assert_eq!(duration.subsec_nanos(), 500_000_000);
+    /// ```
+    #[inline]
+    pub const fn new(secs: u64, nanos: u32) -> Self {
+        let Some(secs) = secs.checked_add((nanos / NANOS_PER_SEC) as u64) else {
+            panic!("Overflow in Duration::new");
+        };
+
+        Self {
+            secs,
+            nanos: nanos % NANOS_PER_SEC,
+        }
+    }
+
+    /// Get the number of whole seconds in the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730).as_secs(), 5);
+    /// ```
+    #[inline]
+    pub const fn as_secs(&self) -> u64 {
+        self.secs
+    }
+
+    /// Get the fractional part of the duration in nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::Duration;
+    ///
+    /// assert_eq!(Duration::new(5, 730).subsec_nanos(), 730);
+    /// ```
+    #[inline]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+
+impl From<core::time::Duration> for Duration {
+    #[inline]
+    fn from(value: core::time::Duration) -> Self {
+        Self {
+            secs: value.as_secs(),
+            nanos: value.subsec_nanos(),
+        }
+    }
+}
+
+impl From<Duration> for core::time::Duration {
+    #[inline]
+    fn from(value: Duration) -> Self {
+        core::time::Duration::new(value.secs, value.nanos)
+    }
+}
+
+unsafe impl ZeroCopy for Duration {
+    const ANY_BITS: bool = false;
+    const PADDED: bool = true;
+    const CAN_SWAP_BYTES: bool = false;
+
+    #[inline]
+    unsafe fn pad(padder: &mut Padder<'_, Self>) {
+        padder.pad::<u64>();
+        padder.pad::<u32>();
+    }
+
+    #[inline]
+    unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error> {
+        validator.field::<u64>()?;
+        validate_nanos(*validator.field::<u32>()?)
+    }
+
+    #[inline]
+    fn swap_bytes<E: ByteOrder>(self) -> Self {
+        self
+    }
+}
+
+impl Visit for Duration {
+    type Target = Self;
+
+    #[inline]
+    fn visit<V, O>(&self, _: &Buf, visitor: V) -> Result<O, Error>
+    where
+        V: FnOnce(&Self::Target) -> O,
+    {
+        Ok(visitor(self))
+    }
+}
+
+/// A `repr(C)` point in time relative to the unix epoch, corresponding to
+/// [`SystemTime`].
+///
+/// The timestamp is stored as an `i64` number of whole seconds since the unix
+/// epoch followed by a `u32` number of nanoseconds after that second. Points
+/// in time before the epoch have a negative number of seconds, but the
+/// nanoseconds always count forward in time.
+///
+/// [`SystemTime`]: std::time::SystemTime
+///
+/// # Validation
+///
+/// When a timestamp is loaded from a buffer the number of nanoseconds must be
+/// less than one second.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+///
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::time::UnixTimestamp;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let now = SystemTime::now();
+/// let timestamp = buf.store(&UnixTimestamp::try_from(now)?);
+/// assert_eq!(SystemTime::try_from(*buf.load(timestamp)?)?, now);
+///
+/// let before = SystemTime::UNIX_EPOCH - Duration::from_millis(1500);
+/// let before = UnixTimestamp::try_from(before)?;
+/// assert_eq!(before.as_secs(), -2);
+/// assert_eq!(before.subsec_nanos(), 500_000_000);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct UnixTimestamp {
+    secs: i64,
+    nanos: u32,
+}
+
+impl UnixTimestamp {
+    /// The unix epoch, `1970-01-01 00:00:00 UTC`.
+    pub const UNIX_EPOCH: Self = Self { secs: 0, nanos: 0 };
+
+    /// Construct a new timestamp from a number of whole seconds since the unix
+    /// epoch and additional nanoseconds.
+    ///
+    /// If the number of nanoseconds is greater than one second, it carries
+    /// over into the number of seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the carry from nanoseconds overflows the number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::UnixTimestamp;
+    ///
+    /// let timestamp = UnixTimestamp::new(-1, 1_500_000_000);
+    /// assert_eq!(timestamp.as_secs(), 0);
+    /// assert_eq!(timestamp.subsec_nanos(), 500_000_000);
+    /// ```
+    #[inline]
+    pub const fn new(secs: i64, nanos: u32) -> Self {
+        let Some(secs) = secs.checked_add((nanos / NANOS_PER_SEC) as i64) else {
+            panic!("Overflow in UnixTimestamp::new");
+        };
+
+        Self {
+            secs,
+            nanos: nanos % NANOS_PER_SEC,
+        }
+    }
+
+    /// Get the number of whole seconds since the unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::UnixTimestamp;
+    ///
+    /// assert_eq!(UnixTimestamp::new(-5, 730).as_secs(), -5);
+    /// ```
+    #[inline]
+    pub const fn as_secs(&self) -> i64 {
+        self.secs
+    }
+
+    /// Get the number of nanoseconds after the second returned by
+    /// [`as_secs()`].
+    ///
+    /// [`as_secs()`]: Self::as_secs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::time::UnixTimestamp;
+    ///
+    /// assert_eq!(UnixTimestamp::new(-5, 730).subsec_nanos(), 730);
+    /// ```
+    #[inline]
+    pub const fn subsec_nanos(&self) -> u32 {
+        self.nanos
+    }
+}
+
+/// Convert a [`SystemTime`] into a timestamp, erroring if the number of seconds
+/// relative to the unix epoch does not fit in an `i64`.
+///
+/// [`SystemTime`]: std::time::SystemTime
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for UnixTimestamp {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let out_of_range = || Error::new(ErrorKind::TimestampOutOfRange);
+
+        match value.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(after) => Ok(Self {
+                secs: i64::try_from(after.as_secs()).map_err(|_| out_of_range())?,
+                nanos: after.subsec_nanos(),
+            }),
+            Err(error) => {
+                let before = error.duration();
+                let secs = i64::try_from(before.as_secs()).map_err(|_| out_of_range())?;
+
+                if before.subsec_nanos() == 0 {
+                    return Ok(Self {
+                        secs: -secs,
+                        nanos: 0,
+                    });
+                }
+
+                Ok(Self {
+                    secs: (-secs).checked_sub(1).ok_or_else(out_of_range)?,
+                    nanos: NANOS_PER_SEC - before.subsec_nanos(),
+                })
+            }
+        }
+    }
+}
+
+/// Convert a timestamp into a [`SystemTime`], erroring if it can't be
+/// represented on the current platform.
+///
+/// [`SystemTime`]: std::time::SystemTime
+#[cfg(feature = "std")]
+impl TryFrom<UnixTimestamp> for std::time::SystemTime {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: UnixTimestamp) -> Result<Self, Self::Error> {
+        let epoch = std::time::SystemTime::UNIX_EPOCH;
+        let secs = core::time::Duration::from_secs(value.secs.unsigned_abs());
+        let nanos = core::time::Duration::from_nanos(u64::from(value.nanos));
+
+        let time = if value.secs < 0 {
+            epoch.checked_sub(secs)
+        } else {
+            epoch.checked_add(secs)
+        };
+
+        time.and_then(|time| time.checked_add(nanos))
+            .ok_or_else(|| Error::new(ErrorKind::TimestampOutOfRange))
+    }
+}
+
+unsafe impl ZeroCopy for UnixTimestamp {
+    const ANY_BITS: bool = false;
+    const PADDED: bool = true;
+    const CAN_SWAP_BYTES: bool = false;
+
+    #[inline]
+    unsafe fn pad(padder: &mut Padder<'_, Self>) {
+        padder.pad::<i64>();
+        padder.pad::<u32>();
+    }
+
+    #[inline]
+    unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error> {
+        validator.field::<i64>()?;
+        validate_nanos(*validator.field::<u32>()?)
+    }
+
+    #[inline]
+    fn swap_bytes<E: ByteOrder>(self) -> Self {
+        self
+    }
+}
+
+impl Visit for UnixTimestamp {
+    type Target = Self;
+
+    #[inline]
+    fn visit<V, O>(&self, _: &Buf, visitor: V) -> Result<O, Error>
+    where
+        V: FnOnce(&Self::Target) -> O,
+    {
+        Ok(visitor(self))
+    }
+}
+
+#[inline]
+fn validate_nanos(nanos: u32) -> Result<(), Error> {
+    if nanos >= NANOS_PER_SEC {
+        return Err(Error::new(ErrorKind::IllegalNanos { nanos }));
+    }
+
+    Ok(())
+}
@@ -17,6 +17,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use core::array;
+use core::ffi::CStr;
 use core::marker::PhantomData;
 use core::mem::{align_of, size_of, transmute};
 use core::num::Wrapping;
@@ -29,11 +30,17 @@ use crate::endian::ByteOrder;
 use crate::error::{Error, ErrorKind};
 use crate::pointer::{Packable, Pointee, Size};
 
-mod sealed {
+pub(crate) mod sealed {
     use crate::ZeroCopy;
 
+    /// Seals [`UnsizedZeroCopy`], only implemented through this crate and its
+    /// derives.
+    ///
+    /// [`UnsizedZeroCopy`]: super::UnsizedZeroCopy
+    #[doc(hidden)]
     pub trait Sealed {}
     impl Sealed for str {}
+    impl Sealed for core::ffi::CStr {}
     impl<T> Sealed for [T] where T: ZeroCopy {}
 }
 
@@ -41,8 +48,10 @@ mod sealed {
 /// handle.
 ///
 /// We only support slice-like, unaligned unsized types, such as `str` and
-/// `[u8]`. We can't support types such as `dyn Debug` because metadata is a
-/// vtable which can't be serialized.
+/// `[u8]`, and structs which end with such a field through the
+/// [`UnsizedZeroCopy`][derive@crate::UnsizedZeroCopy] derive. We can't support
+/// types such as `dyn Debug` because metadata is a vtable which can't be
+/// serialized.
 ///
 /// [`Ref<T>`]: crate::pointer::Ref
 ///
@@ -52,8 +61,11 @@ mod sealed {
 /// * Can only be implemented for base types which can inhabit any bit-pattern.
 ///   All though custom validation can be performed during coercion (such as for
 ///   `str`).
-/// * Must only be implemented for types which are not padded (as per
-///   [`ZeroCopy::PADDED`]).
+/// * Must correctly indicate if the type is padded through [`PADDED`], in
+///   which case [`pad()`] must initialize every padding byte.
+///
+/// [`PADDED`]: Self::PADDED
+/// [`pad()`]: Self::pad
 ///
 /// # Examples
 ///
@@ -404,6 +416,10 @@ unsafe impl<T: ?Sized> ZeroSized for PhantomData<T> {}
 pub unsafe trait ZeroCopy: Sized {
     /// Indicates if the type can inhabit all possible bit patterns within its
     /// [`size_of::<Self>()`] bytes.
+    ///
+    /// Types for which this is `true` are never validated when loaded, which
+    /// means that slices and arrays of them only require a bounds and
+    /// alignment check regardless of their length.
     const ANY_BITS: bool;
 
     /// Indicates if a type is padded.
@@ -672,6 +688,85 @@ unsafe impl UnsizedZeroCopy for str {
     }
 }
 
+/// [`UnsizedZeroCopy`] implementation for [`CStr`].
+///
+/// The string is stored including its terminating NUL byte, and the metadata
+/// of a [`Ref<CStr>`] is the length of the string *including* the terminator.
+/// Loading a string validates that the referenced range ends with a NUL byte
+/// and contains no interior NUL bytes.
+///
+/// [`Ref<CStr>`]: crate::Ref
+///
+/// # Examples
+///
+/// ```
+/// use std::ffi::CStr;
+///
+/// use musli_zerocopy::OwnedBuf;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let c_str = CStr::from_bytes_with_nul(b"Hello\0").unwrap();
+/// let string = buf.store_unsized(c_str);
+///
+/// assert_eq!(string.len(), 6);
+/// assert_eq!(buf.load(string)?, c_str);
+/// assert_eq!(&buf[..], b"Hello\0");
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+unsafe impl UnsizedZeroCopy for CStr {
+    const ALIGN: usize = align_of::<u8>();
+    const PADDED: bool = false;
+
+    #[inline]
+    fn as_ptr(&self) -> *const u8 {
+        CStr::as_ptr(self).cast()
+    }
+
+    #[inline]
+    fn metadata(&self) -> Self::Metadata {
+        self.to_bytes_with_nul().len()
+    }
+
+    #[inline]
+    unsafe fn pad(&self, _: &mut Padder<'_, Self>) {}
+
+    #[inline]
+    unsafe fn validate_unsized<E: ByteOrder, O: Size>(
+        data: NonNull<u8>,
+        len: usize,
+        metadata: <Self::Metadata as Packable>::Packed<O>,
+    ) -> Result<Self::Metadata, Error> {
+        let metadata = metadata.as_usize::<E>();
+
+        if metadata > len {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds {
+                range: 0..metadata,
+                len,
+            }));
+        };
+
+        let buf = slice::from_raw_parts(data.as_ptr(), metadata);
+        CStr::from_bytes_with_nul(buf)
+            .map_err(|error| Error::new(ErrorKind::CStrError { error }))?;
+        Ok(metadata)
+    }
+
+    #[inline]
+    unsafe fn with_metadata(data: NonNull<u8>, metadata: Self::Metadata) -> *const Self {
+        let slice = slice::from_raw_parts(data.as_ptr(), metadata);
+        CStr::from_bytes_with_nul_unchecked(slice)
+    }
+
+    #[inline]
+    unsafe fn with_metadata_mut(data: NonNull<u8>, metadata: Self::Metadata) -> *mut Self {
+        // NB: `CStr` doesn't provide any API to mutate its contents, so it is
+        // never written to through the returned pointer.
+        let slice = slice::from_raw_parts_mut(data.as_ptr(), metadata);
+        (CStr::from_bytes_with_nul_unchecked(slice) as *const Self).cast_mut()
+    }
+}
+
 unsafe impl<T> UnsizedZeroCopy for [T]
 where
     T: ZeroCopy,
@@ -0,0 +1,126 @@
+//! `repr(C)` equivalents of tuples which implement [`ZeroCopy`].
+//!
+//! The layout of a tuple is `repr(Rust)`, so there is no way to construct
+//! legal references to them. The types in this module can be used instead, and
+//! can be converted to and from their corresponding tuple.
+//!
+//! # Examples
+//!
+//! ```
+//! use musli_zerocopy::OwnedBuf;
+//! use musli_zerocopy::tuple::{Pair, Triple};
+//!
+//! let mut buf = OwnedBuf::new();
+//!
+//! let name = buf.store_unsized("Hello");
+//! let pair = buf.store(&Pair::from((name, 42u64)));
+//! let triple = buf.store(&Triple(1u8, 2u16, 3u32));
+//!
+//! let Pair(name, number) = *buf.load(pair)?;
+//! assert_eq!(buf.load(name)?, "Hello");
+//! assert_eq!(number, 42);
+//!
+//! let triple: (u8, u16, u32) = (*buf.load(triple)?).into();
+//! assert_eq!(triple, (1, 2, 3));
+//! # Ok::<_, musli_zerocopy::Error>(())
+//! ```
+//!
+//! They can also be used as keys and values in maps:
+//!
+//! ```
+//! use musli_zerocopy::{swiss, OwnedBuf};
+//! use musli_zerocopy::tuple::Pair;
+//!
+//! let mut buf = OwnedBuf::new();
+//!
+//! let map = swiss::store_map(&mut buf, [
+//!     (Pair(1u32, 2u32), Pair(3u32, 4u32)),
+//!     (Pair(5, 6), Pair(7, 8)),
+//! ])?;
+//!
+//! let map = buf.bind(map)?;
+//! assert_eq!(map.get(&Pair(1, 2))?, Some(&Pair(3, 4)));
+//! assert_eq!(map.get(&Pair(5, 6))?, Some(&Pair(7, 8)));
+//! assert_eq!(map.get(&Pair(2, 1))?, None);
+//! # Ok::<_, musli_zerocopy::Error>(())
+//! ```
+
+use crate::buf::{Buf, Visit};
+use crate::error::Error;
+use crate::ZeroCopy;
+
+macro_rules! tuple {
+    ($(#[$meta:meta])* $name:ident, $($ty:ident $var:ident),*) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, ZeroCopy)]
+        #[zero_copy(crate, bounds = {$($ty: ZeroCopy),*})]
+        #[repr(C)]
+        pub struct $name<$($ty),*>($(pub $ty),*);
+
+        impl<$($ty),*> $name<$($ty),*> {
+            /// Convert into the corresponding tuple.
+            #[inline]
+            pub fn into_tuple(self) -> ($($ty,)*) {
+                let Self($($var),*) = self;
+                ($($var,)*)
+            }
+        }
+
+        impl<$($ty),*> From<($($ty,)*)> for $name<$($ty),*> {
+            #[inline]
+            fn from(($($var,)*): ($($ty,)*)) -> Self {
+                Self($($var),*)
+            }
+        }
+
+        impl<$($ty),*> From<$name<$($ty),*>> for ($($ty,)*) {
+            #[inline]
+            fn from(value: $name<$($ty),*>) -> Self {
+                value.into_tuple()
+            }
+        }
+
+        impl<$($ty),*> Visit for $name<$($ty),*> {
+            type Target = Self;
+
+            #[inline]
+            fn visit<V, O>(&self, _: &Buf, visitor: V) -> Result<O, Error>
+            where
+                V: FnOnce(&Self::Target) -> O,
+            {
+                Ok(visitor(self))
+            }
+        }
+    };
+}
+
+tuple! {
+    /// A `repr(C)` pair of values, corresponding to the tuple `(A, B)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, ZeroCopy};
+    /// use musli_zerocopy::tuple::Pair;
+    ///
+    /// assert!(!<Pair<u32, u32> as ZeroCopy>::PADDED);
+    /// assert!(<Pair<u8, u32> as ZeroCopy>::PADDED);
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let pair = buf.store(&Pair(1u8, 2u32));
+    /// assert_eq!(buf.load(pair)?.into_tuple(), (1, 2));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    Pair, A a, B b
+}
+
+tuple! {
+    /// A `repr(C)` triple of values, corresponding to the tuple `(A, B, C)`.
+    Triple, A a, B b, C c
+}
+
+tuple! {
+    /// A `repr(C)` quadruple of values, corresponding to the tuple `(A, B, C,
+    /// D)`.
+    Quad, A a, B b, C c, D d
+}
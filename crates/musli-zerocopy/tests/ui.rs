@@ -4,4 +4,7 @@ fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/*_error.rs");
     t.pass("tests/ui/*_ok.rs");
+
+    #[cfg(feature = "bytemuck")]
+    t.compile_fail("tests/ui/bytemuck/*_error.rs");
 }
@@ -0,0 +1,14 @@
+//! This test ensures that types with padding can't implement `Pod`.
+
+use musli_zerocopy::ZeroCopy;
+
+#[derive(Clone, Copy, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(bytemuck)]
+struct Padded {
+    first: u8,
+    second: u32,
+}
+
+fn main() {
+}
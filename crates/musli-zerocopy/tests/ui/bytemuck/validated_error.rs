@@ -0,0 +1,14 @@
+//! This test ensures that types with fields which require validation can't
+//! implement `Pod`.
+
+use musli_zerocopy::ZeroCopy;
+
+#[derive(Clone, Copy, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(bytemuck)]
+struct Validated {
+    character: char,
+}
+
+fn main() {
+}
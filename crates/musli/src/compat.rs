@@ -3,6 +3,7 @@
 //! Note that most types in this module have an attribute equivalent:
 //! * [`Bytes`] corresponds to using `#[musli(bytes)]` on a field.
 //! * [`Packed`] corresponds to using `#[musli(packed)]` on a field.
+//! * [`Serde`] corresponds to using `#[musli(with = musli::serde)]` on a field.
 
 use crate::de::{Decode, DecodeBytes, DecodePacked, Decoder};
 use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder};
@@ -158,3 +159,71 @@ where
 #[musli(mode = Text, bound = {T: EncodePacked<Text>}, decode_bound = {T: DecodePacked<'de, Text>})]
 #[repr(transparent)]
 pub struct Packed<T>(#[musli(packed)] pub T);
+
+/// Encode and decode `T` using its [`serde`] implementations.
+///
+/// This is the equivalent of using `#[musli(with = musli::serde)]` on a field,
+/// see the [`serde` module] for how serde types are represented.
+///
+/// [`serde`]: https://serde.rs
+/// [`serde` module]: crate::serde
+///
+/// # Examples
+///
+/// ```
+/// use musli::compat::Serde;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Address {
+///     street: String,
+///     zip: u32,
+/// }
+///
+/// let address = Serde(Address {
+///     street: "Main St.".to_string(),
+///     zip: 12345,
+/// });
+///
+/// let json = musli::json::to_string(&address)?;
+/// assert_eq!(json, r#"{"street":"Main St.","zip":12345}"#);
+///
+/// let decoded: Serde<Address> = musli::json::from_str(&json)?;
+/// assert_eq!(decoded, address);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Serde<T>(pub T);
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<M, T> Encode<M> for Serde<T>
+where
+    T: ::serde::Serialize,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        crate::serde::encode(&self.0, cx, encoder)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "serde")))]
+impl<'de, M, T> Decode<'de, M> for Serde<T>
+where
+    T: ::serde::Deserialize<'de>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Self(crate::serde::decode(cx, decoder)?))
+    }
+}
@@ -0,0 +1,363 @@
+use core::cell::{Cell, UnsafeCell};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::buf::{self, BufString};
+use crate::de::Coercion;
+use crate::{Allocator, Context};
+
+use super::access::Access;
+use super::limits::{Limiter, Limits};
+use super::rich_error::{PathFormat, RichError, Step};
+use super::{Checkpoint, ErrorMarker};
+
+/// A rich context which reports diagnostics to a callback as they happen,
+/// instead of collecting them.
+///
+/// Each reported error carries the structural path and byte range where it
+/// happened, just like the errors collected by [`SystemContext`]. This makes it
+/// straight forward to forward diagnostics to something like `log` or
+/// `tracing`. Decoding still fails as usual once an error has been reported.
+///
+/// Note that errors raised while speculatively decoding, like when trying each
+/// variant of a `#[musli(untagged)]` enum, are also reported even if decoding
+/// eventually succeeds.
+///
+/// [`SystemContext`]: super::SystemContext
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::RefCell;
+///
+/// use musli::allocator::System;
+/// use musli::context::CallbackContext;
+/// use musli::Decode;
+///
+/// #[derive(Decode)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let reported = RefCell::new(Vec::new());
+///
+/// let alloc = System::new();
+/// let cx = CallbackContext::new(&alloc, |error| {
+///     // This could be `tracing::error!(path = %error.path_display(), ...)`.
+///     reported.borrow_mut().push(error.to_string());
+/// });
+///
+/// let encoding = musli::json::Encoding::new();
+/// let result = encoding.from_slice_with::<_, Person>(&cx, br#"{"name":"Alice","age":"old"}"#);
+/// assert!(result.is_err());
+///
+/// assert_eq!(*reported.borrow(), [".age: Invalid numeric (at bytes 22-23)"]);
+/// ```
+pub struct CallbackContext<A, M, F> {
+    access: Access,
+    mark: Cell<usize>,
+    alloc: A,
+    callback: F,
+    path: UnsafeCell<Vec<Step<String>>>,
+    include_type: bool,
+    path_format: PathFormat,
+    limiter: Limiter,
+    coercion: Coercion,
+    _marker: PhantomData<M>,
+}
+
+impl<A, M, F> CallbackContext<A, M, F> {
+    /// Construct a new context which reports errors to the given callback.
+    pub fn new(alloc: A, callback: F) -> Self
+    where
+        F: Fn(RichError<'_, String, String>),
+    {
+        Self {
+            access: Access::new(),
+            mark: Cell::new(0),
+            alloc,
+            callback,
+            path: UnsafeCell::new(Vec::new()),
+            include_type: false,
+            path_format: PathFormat::new(),
+            limiter: Limiter::new(),
+            coercion: Coercion::Widening,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Configure the context to visualize type information, and not just
+    /// variant and fields.
+    pub fn include_type(&mut self) -> &mut Self {
+        self.include_type = true;
+        self
+    }
+
+    /// Configure how the paths of reported errors are rendered.
+    pub fn with_path_format(&mut self, path_format: PathFormat) -> &mut Self {
+        self.path_format = path_format;
+        self
+    }
+
+    /// Configure the [`Limits`] enforced when decoding collections through
+    /// this context.
+    pub fn with_limits(&mut self, limits: Limits) -> &mut Self {
+        self.limiter.set(limits);
+        self
+    }
+
+    /// Configure the [`Coercion`] policy used when decoding numbers through
+    /// this context.
+    pub fn with_coercion(&mut self, coercion: Coercion) -> &mut Self {
+        self.coercion = coercion;
+        self
+    }
+}
+
+impl<A, M, F> CallbackContext<A, M, F>
+where
+    A: Allocator,
+    F: Fn(RichError<'_, String, String>),
+{
+    fn report(&self, range: Range<usize>, message: String) {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        let path = unsafe { &*self.path.get() };
+
+        let error = RichError::new(path, 0, range, &message).with_path_format(self.path_format);
+        (self.callback)(error);
+    }
+
+    fn push_path(&self, step: Step<String>) {
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        let path = unsafe { &mut (*self.path.get()) };
+
+        path.push(step);
+    }
+
+    fn pop_path(&self) {
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        let path = unsafe { &mut (*self.path.get()) };
+
+        path.pop();
+    }
+}
+
+impl<A, M, F> Context for CallbackContext<A, M, F>
+where
+    A: Allocator,
+    F: Fn(RichError<'_, String, String>),
+{
+    type Mode = M;
+    type Error = ErrorMarker;
+    type Mark = usize;
+    type Buf<'this> = A::Buf<'this> where Self: 'this;
+    type BufString<'this> = BufString<A::Buf<'this>> where Self: 'this;
+
+    #[inline]
+    fn clear(&self) {
+        self.mark.set(0);
+        self.limiter.clear();
+        let _access = self.access.exclusive();
+
+        // SAFETY: We have acquired exclusive access just above.
+        unsafe {
+            (*self.path.get()).clear();
+        }
+    }
+
+    #[inline]
+    fn alloc(&self) -> Option<Self::Buf<'_>> {
+        self.alloc.alloc()
+    }
+
+    #[inline]
+    fn check_sequence_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.sequence_len(self, len)
+    }
+
+    #[inline]
+    fn check_map_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.map_len(self, len)
+    }
+
+    #[inline]
+    fn check_bytes_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.bytes_len(self, len)
+    }
+
+    #[inline]
+    fn consume_elements(&self, n: usize) -> Result<(), Self::Error> {
+        self.limiter.consume(self, n)
+    }
+
+    #[inline]
+    fn coercion(&self) -> Coercion {
+        self.coercion
+    }
+
+    #[inline]
+    fn collect_string<T>(&self, value: &T) -> Result<Self::BufString<'_>, Self::Error>
+    where
+        T: ?Sized + fmt::Display,
+    {
+        buf::collect_string(self, value)
+    }
+
+    #[inline]
+    fn custom<T>(&self, message: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + fmt::Display + fmt::Debug,
+    {
+        self.report(self.mark.get()..self.mark.get(), message.to_string());
+        ErrorMarker
+    }
+
+    #[inline]
+    fn message<T>(&self, message: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.report(self.mark.get()..self.mark.get(), message.to_string());
+        ErrorMarker
+    }
+
+    #[inline]
+    fn marked_message<T>(&self, mark: Self::Mark, message: T) -> Self::Error
+    where
+        T: fmt::Display,
+    {
+        self.report(mark..self.mark.get(), message.to_string());
+        ErrorMarker
+    }
+
+    #[inline]
+    fn marked_custom<T>(&self, mark: Self::Mark, message: T) -> Self::Error
+    where
+        T: 'static + Send + Sync + fmt::Display + fmt::Debug,
+    {
+        self.report(mark..self.mark.get(), message.to_string());
+        ErrorMarker
+    }
+
+    #[inline]
+    fn mark(&self) -> Self::Mark {
+        self.mark.get()
+    }
+
+    #[inline]
+    fn advance(&self, n: usize) {
+        self.mark.set(self.mark.get().wrapping_add(n));
+    }
+
+    #[inline]
+    fn checkpoint(&self) -> Checkpoint {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        unsafe { Checkpoint::new(self.mark.get(), 0, (*self.path.get()).len()) }
+    }
+
+    #[inline]
+    fn restore(&self, checkpoint: Checkpoint) {
+        self.mark.set(checkpoint.mark);
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        unsafe {
+            (*self.path.get()).truncate(checkpoint.path);
+        }
+    }
+
+    #[inline]
+    fn enter_named_field<T>(&self, name: &'static str, _: &T)
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.push_path(Step::Named(name));
+    }
+
+    #[inline]
+    fn enter_unnamed_field<T>(&self, index: u32, _: &T)
+    where
+        T: ?Sized + fmt::Display,
+    {
+        self.push_path(Step::Unnamed(index));
+    }
+
+    #[inline]
+    fn leave_field(&self) {
+        self.pop_path();
+    }
+
+    #[inline]
+    fn enter_struct(&self, name: &'static str) {
+        if self.include_type {
+            self.push_path(Step::Struct(name));
+        }
+    }
+
+    #[inline]
+    fn leave_struct(&self) {
+        if self.include_type {
+            self.pop_path();
+        }
+    }
+
+    #[inline]
+    fn enter_enum(&self, name: &'static str) {
+        if self.include_type {
+            self.push_path(Step::Enum(name));
+        }
+    }
+
+    #[inline]
+    fn leave_enum(&self) {
+        if self.include_type {
+            self.pop_path();
+        }
+    }
+
+    #[inline]
+    fn enter_variant<T>(&self, name: &'static str, _: T) {
+        self.push_path(Step::Variant(name));
+    }
+
+    #[inline]
+    fn leave_variant(&self) {
+        self.pop_path();
+    }
+
+    #[inline]
+    fn enter_sequence_index(&self, index: usize) {
+        self.push_path(Step::Index(index));
+    }
+
+    #[inline]
+    fn leave_sequence_index(&self) {
+        self.pop_path();
+    }
+
+    #[inline]
+    fn enter_map_key<T>(&self, field: T)
+    where
+        T: fmt::Display,
+    {
+        self.push_path(Step::Key(field.to_string()));
+    }
+
+    #[inline]
+    fn leave_map_key(&self) {
+        self.pop_path();
+    }
+}
@@ -0,0 +1,195 @@
+use core::cell::Cell;
+
+use crate::Context;
+
+/// Limits on the size of containers which are decoded through a context.
+///
+/// These are consulted by the [`Decode`] implementations of collections such
+/// as `Vec`, `HashMap`, `BTreeMap`, `String` and owned bytes before they
+/// reserve space for the number of elements the input claims to have, and
+/// again as elements are decoded. This protects against payloads which claim
+/// to contain enormous collections.
+///
+/// By default no limits are enforced. Limits are installed using
+/// `with_limits` on a context, like [`SystemContext::with_limits`].
+///
+/// [`Decode`]: crate::Decode
+/// [`SystemContext::with_limits`]: crate::context::SystemContext::with_limits
+///
+/// # Examples
+///
+/// ```
+/// use musli::allocator::System;
+/// use musli::context::{Limits, SystemContext};
+/// use musli::mode::Binary;
+/// use musli::storage::Encoding;
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// let alloc = System::new();
+/// let mut cx = SystemContext::<_, Binary>::new(&alloc);
+/// cx.with_limits(Limits::new().max_sequence_len(2));
+///
+/// let bytes = ENCODING.to_vec(&vec![1u32, 2, 3])?;
+/// let result = ENCODING.from_slice_with::<_, Vec<u32>>(&cx, &bytes);
+/// assert!(result.is_err());
+/// assert_eq!(
+///     cx.report().to_string(),
+///     ": Sequence length 3 exceeds the maximum of 2 (at byte 1)\n"
+/// );
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    max_sequence_len: usize,
+    max_map_len: usize,
+    max_bytes_len: usize,
+    max_total_elements: usize,
+}
+
+impl Limits {
+    /// Construct limits which don't limit anything.
+    pub const fn new() -> Self {
+        Self {
+            max_sequence_len: usize::MAX,
+            max_map_len: usize::MAX,
+            max_bytes_len: usize::MAX,
+            max_total_elements: usize::MAX,
+        }
+    }
+
+    /// Limit the number of elements in any single sequence.
+    pub const fn max_sequence_len(self, max_sequence_len: usize) -> Self {
+        Self {
+            max_sequence_len,
+            ..self
+        }
+    }
+
+    /// Limit the number of entries in any single map.
+    pub const fn max_map_len(self, max_map_len: usize) -> Self {
+        Self {
+            max_map_len,
+            ..self
+        }
+    }
+
+    /// Limit the length of any single string or byte array which is copied
+    /// into an owned value.
+    pub const fn max_bytes_len(self, max_bytes_len: usize) -> Self {
+        Self {
+            max_bytes_len,
+            ..self
+        }
+    }
+
+    /// Limit the total number of sequence elements and map entries decoded
+    /// across all containers, including nested ones.
+    ///
+    /// The count is reset when the context is cleared.
+    pub const fn max_total_elements(self, max_total_elements: usize) -> Self {
+        Self {
+            max_total_elements,
+            ..self
+        }
+    }
+}
+
+impl Default for Limits {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state of [`Limits`] installed in a context.
+pub(super) struct Limiter {
+    limits: Limits,
+    elements: Cell<usize>,
+}
+
+impl Limiter {
+    #[inline]
+    pub(super) const fn new() -> Self {
+        Self {
+            limits: Limits::new(),
+            elements: Cell::new(0),
+        }
+    }
+
+    #[inline]
+    pub(super) fn set(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    #[inline]
+    pub(super) fn clear(&self) {
+        self.elements.set(0);
+    }
+
+    #[inline]
+    pub(super) fn sequence_len<C>(&self, cx: &C, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_sequence_len;
+
+        if len > max {
+            return Err(cx.message(format_args!(
+                "Sequence length {len} exceeds the maximum of {max}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub(super) fn map_len<C>(&self, cx: &C, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_map_len;
+
+        if len > max {
+            return Err(cx.message(format_args!(
+                "Map length {len} exceeds the maximum of {max}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub(super) fn bytes_len<C>(&self, cx: &C, len: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_bytes_len;
+
+        if len > max {
+            return Err(cx.message(format_args!(
+                "Length of {len} bytes exceeds the maximum of {max}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub(super) fn consume<C>(&self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        let max = self.limits.max_total_elements;
+        let total = self.elements.get().saturating_add(n);
+
+        if total > max {
+            return Err(cx.message(format_args!(
+                "Decoding more than the maximum of {max} elements in total"
+            )));
+        }
+
+        self.elements.set(total);
+        Ok(())
+    }
+}
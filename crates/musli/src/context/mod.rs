@@ -3,7 +3,10 @@
 //! [`Context`]: crate::Context
 
 mod access;
+#[cfg(feature = "alloc")]
+mod callback_context;
 mod error_marker;
+mod limits;
 mod rich_error;
 mod stack_context;
 #[cfg(feature = "alloc")]
@@ -17,6 +20,7 @@ use core::fmt;
 use core::marker::PhantomData;
 
 use crate::buf::{self, BufString};
+use crate::de::Coercion;
 use crate::mode::Binary;
 use crate::no_std;
 use crate::{Allocator, Context};
@@ -24,9 +28,17 @@ use crate::{Allocator, Context};
 #[cfg(feature = "alloc")]
 pub use self::system_context::SystemContext;
 
+#[cfg(feature = "alloc")]
+pub use self::callback_context::CallbackContext;
+
 pub use self::stack_context::StackContext;
 
-pub use self::rich_error::RichError;
+use self::limits::Limiter;
+pub use self::limits::Limits;
+
+pub use self::rich_error::{PathFormat, RichError, Step};
+
+pub use musli_core::Checkpoint;
 
 /// A simple non-diagnostical capturing context which simply emits the original
 /// error.
@@ -35,6 +47,8 @@ pub use self::rich_error::RichError;
 /// error type directly.
 pub struct Same<A, M, E> {
     alloc: A,
+    limiter: Limiter,
+    coercion: Coercion,
     _marker: PhantomData<(M, E)>,
 }
 
@@ -43,9 +57,25 @@ impl<A, M, E> Same<A, M, E> {
     pub fn new(alloc: A) -> Self {
         Self {
             alloc,
+            limiter: Limiter::new(),
+            coercion: Coercion::Widening,
             _marker: PhantomData,
         }
     }
+
+    /// Configure the [`Limits`] enforced when decoding collections through
+    /// this context.
+    pub fn with_limits(&mut self, limits: Limits) -> &mut Self {
+        self.limiter.set(limits);
+        self
+    }
+
+    /// Configure the [`Coercion`] policy used when decoding numbers through
+    /// this context.
+    pub fn with_coercion(&mut self, coercion: Coercion) -> &mut Self {
+        self.coercion = coercion;
+        self
+    }
 }
 
 impl<A> Same<A, Binary, ErrorMarker> {
@@ -63,10 +93,7 @@ where
 {
     #[inline]
     fn default() -> Self {
-        Self {
-            alloc: A::default(),
-            _marker: PhantomData,
-        }
+        Self::new(A::default())
     }
 }
 
@@ -82,13 +109,40 @@ where
     type BufString<'this> = BufString<A::Buf<'this>> where Self: 'this;
 
     #[inline]
-    fn clear(&self) {}
+    fn clear(&self) {
+        self.limiter.clear();
+    }
 
     #[inline]
     fn alloc(&self) -> Option<Self::Buf<'_>> {
         self.alloc.alloc()
     }
 
+    #[inline]
+    fn check_sequence_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.sequence_len(self, len)
+    }
+
+    #[inline]
+    fn check_map_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.map_len(self, len)
+    }
+
+    #[inline]
+    fn check_bytes_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.bytes_len(self, len)
+    }
+
+    #[inline]
+    fn consume_elements(&self, n: usize) -> Result<(), Self::Error> {
+        self.limiter.consume(self, n)
+    }
+
+    #[inline]
+    fn coercion(&self) -> Coercion {
+        self.coercion
+    }
+
     #[inline]
     fn collect_string<T>(&self, value: &T) -> Result<Self::BufString<'_>, Self::Error>
     where
@@ -2,10 +2,71 @@ use core::fmt;
 use core::mem::take;
 use core::ops::Range;
 
+/// Configuration for how the path of a [`RichError`] is rendered.
+///
+/// By default paths are rendered like `.values[Hello][3]`, where map keys are
+/// included and sequence indices use brackets.
+///
+/// # Examples
+///
+/// ```
+/// use musli::context::PathFormat;
+///
+/// let format = PathFormat::new().without_keys().dotted_indices().max_depth(8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathFormat {
+    keys: bool,
+    dotted_indices: bool,
+    max_depth: usize,
+}
+
+impl PathFormat {
+    /// Construct the default path format.
+    pub const fn new() -> Self {
+        Self {
+            keys: true,
+            dotted_indices: false,
+            max_depth: usize::MAX,
+        }
+    }
+
+    /// Don't render map keys, which might contain sensitive data. They are
+    /// replaced with `[*]`.
+    pub const fn without_keys(self) -> Self {
+        Self {
+            keys: false,
+            ..self
+        }
+    }
+
+    /// Render sequence indices like `.3` instead of `[3]`.
+    pub const fn dotted_indices(self) -> Self {
+        Self {
+            dotted_indices: true,
+            ..self
+        }
+    }
+
+    /// Only render up to `max_depth` steps of the path, any remaining steps
+    /// are counted as capped.
+    pub const fn max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+}
+
+impl Default for PathFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A collected error which has been context decorated.
 pub struct RichError<'a, S, E> {
     path: &'a [Step<S>],
     path_cap: usize,
+    path_format: PathFormat,
     range: Range<usize>,
     error: &'a E,
 }
@@ -20,10 +81,56 @@ impl<'a, S, E> RichError<'a, S, E> {
         Self {
             path,
             path_cap,
+            path_format: PathFormat::new(),
             range,
             error,
         }
     }
+
+    pub(crate) fn with_path_format(self, path_format: PathFormat) -> Self {
+        Self {
+            path_format,
+            ..self
+        }
+    }
+
+    /// The structured path at which the error occurred.
+    pub fn path(&self) -> &'a [Step<S>] {
+        self.path
+    }
+
+    /// The number of steps which were not stored in [`path`] because the
+    /// context ran out of space.
+    ///
+    /// [`path`]: Self::path
+    pub fn capped_steps(&self) -> usize {
+        self.path_cap
+    }
+
+    /// The range of bytes in the input at which the error occurred.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// The error message.
+    pub fn error(&self) -> &'a E {
+        self.error
+    }
+}
+
+impl<'a, S, E> RichError<'a, S, E>
+where
+    S: fmt::Display,
+{
+    /// Display only the path at which the error occurred, rendered according
+    /// to the configured [`PathFormat`].
+    pub fn path_display(&self) -> impl fmt::Display + 'a {
+        FormatPath {
+            path: self.path,
+            path_cap: self.path_cap,
+            format: self.path_format,
+        }
+    }
 }
 
 impl<'a, S, E> fmt::Display for RichError<'a, S, E>
@@ -32,7 +139,7 @@ where
     E: fmt::Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let path = format_path(self.path, self.path_cap);
+        let path = self.path_display();
 
         if self.range.start != 0 || self.range.end != 0 {
             if self.range.start == self.range.end {
@@ -52,28 +159,30 @@ where
     }
 }
 
-/// A single traced step.
+/// A single traced step in the path of a [`RichError`].
 #[derive(Debug, Clone)]
-pub(crate) enum Step<S> {
+#[non_exhaustive]
+pub enum Step<S> {
+    /// A struct, only recorded if the context includes type information.
     Struct(&'static str),
+    /// An enum, only recorded if the context includes type information.
     Enum(&'static str),
+    /// An enum variant.
     Variant(&'static str),
+    /// A named field.
     Named(&'static str),
+    /// An unnamed field.
     Unnamed(u32),
+    /// An index in a sequence.
     Index(usize),
+    /// A key in a map.
     Key(S),
 }
 
-fn format_path<S>(path: &[Step<S>], path_cap: usize) -> impl fmt::Display + '_
-where
-    S: fmt::Display,
-{
-    FormatPath { path, path_cap }
-}
-
 struct FormatPath<'a, S> {
     path: &'a [Step<S>],
     path_cap: usize,
+    format: PathFormat,
 }
 
 impl<'a, S> fmt::Display for FormatPath<'a, S>
@@ -85,7 +194,10 @@ where
         let mut has_field = false;
         let mut level = 0;
 
-        for step in self.path {
+        let depth = self.path.len().min(self.format.max_depth);
+        let path_cap = self.path_cap + (self.path.len() - depth);
+
+        for step in &self.path[..depth] {
             match step {
                 Step::Struct(name) => {
                     if take(&mut has_field) {
@@ -134,7 +246,12 @@ where
                         level += 1;
                     }
 
-                    write!(f, "[{index}]")?;
+                    if self.format.dotted_indices {
+                        write!(f, ".{index}")?;
+                    } else {
+                        write!(f, "[{index}]")?;
+                    }
+
                     has_field = true;
                 }
                 Step::Key(key) => {
@@ -143,7 +260,12 @@ where
                         level += 1;
                     }
 
-                    write!(f, "[{}]", key)?;
+                    if self.format.keys {
+                        write!(f, "[{key}]")?;
+                    } else {
+                        write!(f, "[*]")?;
+                    }
+
                     has_field = true;
                 }
             }
@@ -153,7 +275,7 @@ where
             write!(f, " }}")?;
         }
 
-        match self.path_cap {
+        match path_cap {
             0 => {}
             1 => write!(f, " .. *one capped step*")?,
             n => write!(f, " .. *{n} capped steps*")?,
@@ -4,12 +4,14 @@ use core::marker::PhantomData;
 use core::ops::Range;
 
 use crate::buf::{self, BufString};
+use crate::de::Coercion;
 use crate::fixed::FixedVec;
-use crate::{Allocator, Context};
+use crate::{Allocator, Buf, Context};
 
 use super::access::{Access, Shared};
-use super::rich_error::{RichError, Step};
-use super::ErrorMarker;
+use super::limits::{Limiter, Limits};
+use super::rich_error::{PathFormat, RichError, Step};
+use super::{Checkpoint, ErrorMarker};
 
 type BufPair<'a, A> = (Range<usize>, BufString<<A as Allocator>::Buf<'a>>);
 
@@ -29,7 +31,10 @@ where
     // How many elements of `path` we've gone over capacity.
     path_cap: Cell<usize>,
     include_type: bool,
+    path_format: PathFormat,
     access: Access,
+    limiter: Limiter,
+    coercion: Coercion,
     _marker: PhantomData<M>,
 }
 
@@ -62,7 +67,10 @@ where
             path: UnsafeCell::new(FixedVec::new()),
             path_cap: Cell::new(0),
             include_type: false,
+            path_format: PathFormat::new(),
             access: Access::new(),
+            limiter: Limiter::new(),
+            coercion: Coercion::Widening,
             _marker: PhantomData,
         }
     }
@@ -81,6 +89,63 @@ where
         }
     }
 
+    /// Configure how the paths of collected errors are rendered.
+    pub fn with_path_format(&mut self, path_format: PathFormat) -> &mut Self {
+        self.path_format = path_format;
+        self
+    }
+
+    /// Configure the [`Limits`] enforced when decoding collections through
+    /// this context.
+    pub fn with_limits(&mut self, limits: Limits) -> &mut Self {
+        self.limiter.set(limits);
+        self
+    }
+
+    /// Configure the [`Coercion`] policy used when decoding numbers through
+    /// this context.
+    pub fn with_coercion(&mut self, coercion: Coercion) -> &mut Self {
+        self.coercion = coercion;
+        self
+    }
+
+    /// Render the first collected error together with the path at which it
+    /// occurred into a string allocated from the allocator of the context.
+    ///
+    /// If the rendered error doesn't fit in the allocator it is truncated.
+    /// Returns `None` if no errors have been collected, or if no string could
+    /// be allocated at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::Decode;
+    /// use musli::allocator::{Stack, StackBuffer};
+    /// use musli::context::StackContext;
+    ///
+    /// #[derive(Decode)]
+    /// struct Person {
+    ///     age: u32,
+    /// }
+    ///
+    /// let mut buf = StackBuffer::<1024>::new();
+    /// let alloc = Stack::new(&mut buf);
+    /// let cx = StackContext::new(&alloc);
+    ///
+    /// let encoding = musli::json::Encoding::new();
+    /// let result = encoding.from_slice_with::<_, Person>(&cx, br#"{"age": "old"}"#);
+    /// assert!(result.is_err());
+    ///
+    /// let error = cx.first_error().unwrap();
+    /// assert!(error.starts_with(".age: "));
+    /// ```
+    pub fn first_error(&self) -> Option<BufString<A::Buf<'a>>> {
+        let error = self.errors().next()?;
+        let mut string = BufString::new(self.alloc.alloc()?);
+        _ = write!(Truncate::new(&mut string), "{error}");
+        Some(string)
+    }
+
     /// Iterate over all collected errors.
     pub fn errors(&self) -> Errors<'_, 'a, A> {
         let access = self.access.shared();
@@ -90,6 +155,7 @@ where
             errors: unsafe { &*self.errors.get() },
             index: 0,
             path_cap: self.path_cap.get(),
+            path_format: self.path_format,
             _access: access,
         }
     }
@@ -139,11 +205,53 @@ where
     {
         let buf = self.alloc.alloc()?;
         let mut string = BufString::new(buf);
-        write!(string, "{value}").ok()?;
+        _ = write!(Truncate::new(&mut string), "{value}");
         Some(string)
     }
 }
 
+/// A writer which truncates its output instead of failing once the
+/// underlying string runs out of space.
+struct Truncate<'a, B> {
+    string: &'a mut BufString<B>,
+    full: bool,
+}
+
+impl<'a, B> Truncate<'a, B> {
+    #[inline]
+    fn new(string: &'a mut BufString<B>) -> Self {
+        Self {
+            string,
+            full: false,
+        }
+    }
+}
+
+impl<B> fmt::Write for Truncate<'_, B>
+where
+    B: Buf,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.full {
+            return Ok(());
+        }
+
+        if self.string.write_str(s).is_err() {
+            // Write as much as we can one character at a time, so that we
+            // never split a character.
+            for c in s.chars() {
+                if self.string.write_char(c).is_err() {
+                    break;
+                }
+            }
+
+            self.full = true;
+        }
+
+        Ok(())
+    }
+}
+
 impl<'a, const E: usize, const P: usize, A, M> Context for StackContext<'a, E, P, A, M>
 where
     A: ?Sized + Allocator,
@@ -157,6 +265,7 @@ where
     #[inline]
     fn clear(&self) {
         self.mark.set(0);
+        self.limiter.clear();
         let _access = self.access.exclusive();
 
         // SAFETY: We have acquired exclusive access just above.
@@ -171,6 +280,31 @@ where
         self.alloc.alloc()
     }
 
+    #[inline]
+    fn check_sequence_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.sequence_len(self, len)
+    }
+
+    #[inline]
+    fn check_map_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.map_len(self, len)
+    }
+
+    #[inline]
+    fn check_bytes_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.bytes_len(self, len)
+    }
+
+    #[inline]
+    fn consume_elements(&self, n: usize) -> Result<(), Self::Error> {
+        self.limiter.consume(self, n)
+    }
+
+    #[inline]
+    fn coercion(&self) -> Coercion {
+        self.coercion
+    }
+
     #[inline]
     fn collect_string<T>(&self, value: &T) -> Result<Self::BufString<'_>, Self::Error>
     where
@@ -237,6 +371,38 @@ where
         self.mark.set(self.mark.get().wrapping_add(n));
     }
 
+    #[inline]
+    fn checkpoint(&self) -> Checkpoint {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        unsafe {
+            Checkpoint::new(
+                self.mark.get(),
+                (*self.errors.get()).len(),
+                (*self.path.get()).len() + self.path_cap.get(),
+            )
+        }
+    }
+
+    #[inline]
+    fn restore(&self, checkpoint: Checkpoint) {
+        self.mark.set(checkpoint.mark);
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        unsafe {
+            (*self.errors.get()).truncate(checkpoint.errors);
+
+            // Path elements which went over capacity are only counted, so
+            // whatever we can't truncate is restored as overflow.
+            let path = &mut *self.path.get();
+            let len = checkpoint.path.min(path.len());
+            path.truncate(len);
+            self.path_cap.set(checkpoint.path - len);
+        }
+    }
+
     #[inline]
     fn enter_named_field<T>(&self, name: &'static str, _: &T)
     where
@@ -352,6 +518,7 @@ where
     errors: &'a [(Range<usize>, BufString<A::Buf<'buf>>)],
     index: usize,
     path_cap: usize,
+    path_format: PathFormat,
     _access: Shared<'a>,
 }
 
@@ -366,12 +533,10 @@ where
         let (range, error) = self.errors.get(self.index)?;
         self.index += 1;
 
-        Some(RichError::new(
-            self.path,
-            self.path_cap,
-            range.clone(),
-            error,
-        ))
+        Some(
+            RichError::new(self.path, self.path_cap, range.clone(), error)
+                .with_path_format(self.path_format),
+        )
     }
 }
 
@@ -385,6 +550,7 @@ where
             errors: self.errors,
             index: self.index,
             path_cap: self.path_cap,
+            path_format: self.path_format,
             _access: self._access.clone(),
         }
     }
@@ -7,11 +7,13 @@ use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use crate::buf::{self, BufString};
+use crate::de::Coercion;
 use crate::{Allocator, Context};
 
 use super::access::{self, Access};
-use super::rich_error::{RichError, Step};
-use super::ErrorMarker;
+use super::limits::{Limiter, Limits};
+use super::rich_error::{PathFormat, RichError, Step};
+use super::{Checkpoint, ErrorMarker};
 
 type BufTriplet<E> = (Vec<Step<String>>, Range<usize>, E);
 
@@ -23,6 +25,12 @@ pub struct SystemContext<A, M> {
     errors: UnsafeCell<Vec<BufTriplet<String>>>,
     path: UnsafeCell<Vec<Step<String>>>,
     include_type: bool,
+    path_format: PathFormat,
+    max_errors: usize,
+    dropped_errors: Cell<usize>,
+    limiter: Limiter,
+    coercion: Coercion,
+    recover: bool,
     _marker: PhantomData<M>,
 }
 
@@ -39,6 +47,12 @@ impl<A, M> SystemContext<A, M> {
             errors: UnsafeCell::new(Vec::new()),
             path: UnsafeCell::new(Vec::new()),
             include_type: false,
+            path_format: PathFormat::new(),
+            max_errors: usize::MAX,
+            dropped_errors: Cell::new(0),
+            limiter: Limiter::new(),
+            coercion: Coercion::Widening,
+            recover: false,
             _marker: PhantomData,
         }
     }
@@ -50,10 +64,86 @@ impl<A, M> SystemContext<A, M> {
         self
     }
 
+    /// Configure how the paths of collected errors are rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::allocator::System;
+    /// use musli::context::{PathFormat, SystemContext};
+    /// use musli::mode::Binary;
+    ///
+    /// let alloc = System::new();
+    /// let mut cx = SystemContext::<_, Binary>::new(&alloc);
+    /// cx.with_path_format(PathFormat::new().without_keys());
+    /// ```
+    pub fn with_path_format(&mut self, path_format: PathFormat) -> &mut Self {
+        self.path_format = path_format;
+        self
+    }
+
+    /// Configure the maximum number of errors which will be stored by the
+    /// context. By default there is no limit.
+    ///
+    /// Errors reported after the limit has been reached are not stored, but
+    /// they are counted and can be accessed through [`dropped_errors`].
+    ///
+    /// [`dropped_errors`]: Self::dropped_errors
+    pub fn with_max_errors(&mut self, max_errors: usize) -> &mut Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    /// Configure the [`Limits`] enforced when decoding collections through
+    /// this context.
+    ///
+    /// See [`Limits`] for an example.
+    pub fn with_limits(&mut self, limits: Limits) -> &mut Self {
+        self.limiter.set(limits);
+        self
+    }
+
+    /// Configure the [`Coercion`] policy used when decoding numbers through
+    /// this context.
+    ///
+    /// This defaults to [`Coercion::Widening`], which unlike earlier versions
+    /// doesn't truncate floats or round integers when decoding numbers from a
+    /// `value`. See [`Coercion`] for details.
+    pub fn with_coercion(&mut self, coercion: Coercion) -> &mut Self {
+        self.coercion = coercion;
+        self
+    }
+
+    /// Configure the context to recover from errors in containers marked
+    /// with `#[musli(recover)]`.
+    ///
+    /// Fields which fail to decode in such containers have their errors
+    /// collected by the context and are replaced with their default value,
+    /// so that as many errors as possible are reported at once alongside a
+    /// best-effort value. Since decoding succeeds when every error was
+    /// recovered from, the collected errors should be checked through
+    /// [`errors`] or [`report`].
+    ///
+    /// [`errors`]: Self::errors
+    /// [`report`]: Self::report
+    pub fn with_recovery(&mut self, recover: bool) -> &mut Self {
+        self.recover = recover;
+        self
+    }
+
+    /// Get the number of errors which were not stored because the limit
+    /// configured through [`with_max_errors`] was reached.
+    ///
+    /// [`with_max_errors`]: Self::with_max_errors
+    pub fn dropped_errors(&self) -> usize {
+        self.dropped_errors.get()
+    }
+
     /// Generate a line-separated report of all collected errors.
     pub fn report(&self) -> Report<'_> {
         Report {
             errors: self.errors(),
+            dropped: self.dropped_errors.get(),
         }
     }
 
@@ -65,6 +155,7 @@ impl<A, M> SystemContext<A, M> {
         Errors {
             errors: unsafe { &*self.errors.get() },
             index: 0,
+            path_format: self.path_format,
             _access: access,
         }
     }
@@ -78,9 +169,16 @@ where
         let _access = self.access.exclusive();
 
         // SAFETY: We've restricted access to the context, so this is safe.
-        let path = unsafe { (*self.path.get()).clone() };
         let errors = unsafe { &mut (*self.errors.get()) };
 
+        if errors.len() >= self.max_errors {
+            self.dropped_errors
+                .set(self.dropped_errors.get().wrapping_add(1));
+            return;
+        }
+
+        // SAFETY: We've restricted access to the context, so this is safe.
+        let path = unsafe { (*self.path.get()).clone() };
         errors.push((path, range, message));
     }
 
@@ -116,6 +214,8 @@ where
     #[inline]
     fn clear(&self) {
         self.mark.set(0);
+        self.dropped_errors.set(0);
+        self.limiter.clear();
         let _access = self.access.exclusive();
 
         // SAFETY: We have acquired exclusive access just above.
@@ -130,6 +230,31 @@ where
         self.alloc.alloc()
     }
 
+    #[inline]
+    fn check_sequence_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.sequence_len(self, len)
+    }
+
+    #[inline]
+    fn check_map_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.map_len(self, len)
+    }
+
+    #[inline]
+    fn check_bytes_len(&self, len: usize) -> Result<(), Self::Error> {
+        self.limiter.bytes_len(self, len)
+    }
+
+    #[inline]
+    fn consume_elements(&self, n: usize) -> Result<(), Self::Error> {
+        self.limiter.consume(self, n)
+    }
+
+    #[inline]
+    fn coercion(&self) -> Coercion {
+        self.coercion
+    }
+
     #[inline]
     fn collect_string<T>(&self, value: &T) -> Result<Self::BufString<'_>, Self::Error>
     where
@@ -184,6 +309,49 @@ where
         self.mark.set(self.mark.get().wrapping_add(n));
     }
 
+    #[inline]
+    fn checkpoint(&self) -> Checkpoint {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        unsafe {
+            Checkpoint::new(
+                self.mark.get(),
+                (*self.errors.get()).len(),
+                (*self.path.get()).len(),
+            )
+            .with_dropped_errors(self.dropped_errors.get())
+        }
+    }
+
+    #[inline]
+    fn restore(&self, checkpoint: Checkpoint) {
+        self.mark.set(checkpoint.mark);
+        self.dropped_errors.set(checkpoint.dropped_errors);
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        unsafe {
+            (*self.errors.get()).truncate(checkpoint.errors);
+            (*self.path.get()).truncate(checkpoint.path);
+        }
+    }
+
+    #[inline]
+    fn recover(&self) -> bool {
+        self.recover
+    }
+
+    #[inline]
+    fn recovered(&self, checkpoint: Checkpoint) {
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        unsafe {
+            (*self.path.get()).truncate(checkpoint.path);
+        }
+    }
+
     #[inline]
     fn enter_named_field<T>(&self, name: &'static str, _: &T)
     where
@@ -270,6 +438,7 @@ where
 /// A line-separated report of all errors.
 pub struct Report<'a> {
     errors: Errors<'a>,
+    dropped: usize,
 }
 
 impl fmt::Display for Report<'_> {
@@ -278,6 +447,10 @@ impl fmt::Display for Report<'_> {
             writeln!(f, "{error}")?;
         }
 
+        if self.dropped > 0 {
+            writeln!(f, "... and {} more errors", self.dropped)?;
+        }
+
         Ok(())
     }
 }
@@ -287,6 +460,7 @@ impl fmt::Display for Report<'_> {
 pub struct Errors<'a> {
     errors: &'a [BufTriplet<String>],
     index: usize,
+    path_format: PathFormat,
     // NB: Drop order is significant, drop the shared access last.
     _access: access::Shared<'a>,
 }
@@ -297,6 +471,6 @@ impl<'a> Iterator for Errors<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         let (path, range, error) = self.errors.get(self.index)?;
         self.index += 1;
-        Some(RichError::new(path, 0, range.clone(), error))
+        Some(RichError::new(path, 0, range.clone(), error).with_path_format(self.path_format))
     }
 }
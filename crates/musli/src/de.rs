@@ -18,7 +18,7 @@
 
 #[doc(inline)]
 pub use musli_core::de::{
-    AsDecoder, Decode, DecodeBytes, DecodeOwned, DecodePacked, DecodeTrace, DecodeUnsized,
-    DecodeUnsizedBytes, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
+    AsDecoder, Coercion, Decode, DecodeBytes, DecodeOwned, DecodePacked, DecodeTrace,
+    DecodeUnsized, DecodeUnsizedBytes, Decoder, EntriesDecoder, EntryDecoder, MapDecoder,
+    SequenceDecoder, SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
 };
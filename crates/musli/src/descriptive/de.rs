@@ -17,8 +17,10 @@ use crate::storage::de::StorageDecoder;
 use crate::Context;
 use crate::{Options, Reader};
 
-use super::integer_encoding::{decode_typed_signed, decode_typed_unsigned};
-use super::tag::{Kind, Mark, Tag, F32, F64, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8};
+use super::integer_encoding::{decode_typed_float, decode_typed_signed, decode_typed_unsigned};
+use super::tag::{
+    Kind, Mark, Tag, F32, F64, I128, I16, I32, I64, I8, ISIZE, U128, U16, U32, U64, U8, USIZE,
+};
 
 #[cfg(feature = "value")]
 const BUFFER_OPTIONS: Options = options::new().build();
@@ -281,6 +283,12 @@ where
         Ok(Skip::Skipped)
     }
 
+    #[cfg(feature = "value")]
+    #[inline]
+    fn is_bufferable(&self) -> bool {
+        true
+    }
+
     #[cfg(feature = "value")]
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
@@ -339,9 +347,9 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M>(V, M);
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -356,26 +364,29 @@ where
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8_owned(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_ref(cx, string)
             }
         }
 
         let pos = self.cx.mark();
         let len = self.decode_prefix(Kind::String, pos)?;
-        self.reader.read_bytes(self.cx, len, Visitor(visitor))
+        self.reader.read_bytes(self.cx, len, Visitor(visitor, pos))
     }
 
     #[inline]
@@ -425,6 +436,7 @@ where
         V: Visitor<'de, C>,
     {
         let cx = self.cx;
+        let mark = cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(cx)?);
 
         match tag.kind() {
@@ -477,86 +489,87 @@ where
                     let value = self.decode_f64()?;
                     visitor.visit_f64(cx, value)
                 }
-                _ => Err(cx.message(format_args!("Unsupported number tag, got {tag:?}"))),
+                _ => {
+                    let error = format_args!("Unsupported number tag, got {tag:?}");
+                    Err(cx.marked_message(mark, error))
+                }
             },
-            _ => Err(cx.message(format_args!("Expected number, but got {tag:?}"))),
+            _ => Err(cx.marked_message(mark, format_args!("Expected number, but got {tag:?}"))),
         }
     }
 
     #[inline]
     fn decode_u8(self) -> Result<u8, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader)
+        decode_typed_unsigned(self.cx, self.reader, U8)
     }
 
     #[inline]
     fn decode_u16(self) -> Result<u16, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader)
+        decode_typed_unsigned(self.cx, self.reader, U16)
     }
 
     #[inline]
     fn decode_u32(self) -> Result<u32, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader)
+        decode_typed_unsigned(self.cx, self.reader, U32)
     }
 
     #[inline]
     fn decode_u64(self) -> Result<u64, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader)
+        decode_typed_unsigned(self.cx, self.reader, U64)
     }
 
     #[inline]
     fn decode_u128(self) -> Result<u128, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader)
+        decode_typed_unsigned(self.cx, self.reader, U128)
     }
 
     #[inline]
     fn decode_i8(self) -> Result<i8, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, I8)
     }
 
     #[inline]
     fn decode_i16(self) -> Result<i16, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, I16)
     }
 
     #[inline]
     fn decode_i32(self) -> Result<i32, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, I32)
     }
 
     #[inline]
     fn decode_i64(self) -> Result<i64, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, I64)
     }
 
     #[inline]
     fn decode_i128(self) -> Result<i128, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, I128)
     }
 
     #[inline]
     fn decode_usize(mut self) -> Result<usize, C::Error> {
-        decode_typed_unsigned(self.cx, self.reader.borrow_mut())
+        decode_typed_unsigned(self.cx, self.reader.borrow_mut(), USIZE)
     }
 
     #[inline]
     fn decode_isize(self) -> Result<isize, C::Error> {
-        decode_typed_signed(self.cx, self.reader)
+        decode_typed_signed(self.cx, self.reader, ISIZE)
     }
 
     /// Decode a 32-bit floating point value by reading the 32-bit in-memory
     /// IEEE 754 encoding byte-by-byte.
     #[inline]
     fn decode_f32(self) -> Result<f32, C::Error> {
-        let bits = self.decode_u32()?;
-        Ok(f32::from_bits(bits))
+        decode_typed_float(self.cx, self.reader, F32)
     }
 
     /// Decode a 64-bit floating point value by reading the 64-bit in-memory
     /// IEEE 754 encoding byte-by-byte.
     #[inline]
     fn decode_f64(self) -> Result<f64, C::Error> {
-        let bits = self.decode_u64()?;
-        Ok(f64::from_bits(bits))
+        decode_typed_float(self.cx, self.reader, F64)
     }
 
     #[inline]
@@ -630,13 +643,17 @@ where
     {
         const VARIANT: Tag = Tag::from_mark(Mark::Variant);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         if tag != VARIANT {
-            return Err(self.cx.message(Expected {
-                expected: Kind::Mark,
-                actual: tag,
-            }));
+            return Err(self.cx.marked_message(
+                mark,
+                Expected {
+                    expected: Kind::Mark,
+                    actual: tag,
+                },
+            ));
         }
 
         f(&mut self)
@@ -1,9 +1,14 @@
 use crate::int::continuation as c;
 use crate::int::zigzag as zig;
-use crate::int::{Signed, Unsigned};
+use core::fmt;
+
+use crate::de::Coercion;
+use crate::int::{float_to_integer, Float, Signed, Unsigned};
 use crate::{Context, Reader, Writer};
 
-use super::tag::{Kind, NumberKind, Tag};
+use super::tag::{
+    Kind, NumberKind, Tag, F32, F64, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8,
+};
 
 #[inline]
 pub(crate) fn encode_typed_unsigned<C, W, T>(
@@ -21,17 +26,30 @@ where
 }
 
 #[inline]
-pub(crate) fn decode_typed_unsigned<'de, C, R, T>(cx: &C, reader: R) -> Result<T, C::Error>
+pub(crate) fn decode_typed_unsigned<'de, C, R, T>(
+    cx: &C,
+    mut reader: R,
+    expected: u8,
+) -> Result<T, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
-    T: Unsigned + TryFrom<T::Signed>,
+    T: Unsigned + TryFrom<T::Signed> + TryFrom<u128> + TryFrom<i128>,
 {
-    let (value, kind): (T, NumberKind) = decode_typed(cx, reader)?;
+    let tag = decode_number_tag(cx, reader.borrow_mut())?;
+    let coercion = cx.coercion();
+
+    if coercion == Coercion::Strict && tag.data_raw() != expected {
+        return Err(cx.message(format_args!(
+            "Expected {}, got {}",
+            NumberType(expected),
+            NumberType(tag.data_raw())
+        )));
+    }
 
-    match kind {
+    match tag.number_kind() {
         NumberKind::Signed => {
-            let value = zig::decode(value);
+            let value = zig::decode(c::decode::<_, _, T>(cx, reader)?);
 
             let Ok(value) = T::try_from(value) else {
                 return Err(cx.message(format_args!("Unsigned value outside of signed range")));
@@ -39,7 +57,10 @@ where
 
             Ok(value)
         }
-        NumberKind::Unsigned | NumberKind::Float => Ok(value),
+        NumberKind::Unsigned => c::decode(cx, reader),
+        NumberKind::Float if coercion == Coercion::Lenient => {
+            decode_float_as_integer(cx, reader, tag)
+        }
         kind => Err(cx.message(format_args!(
             "Expected signed or unsigned number, got {:?}",
             kind
@@ -59,11 +80,10 @@ where
 }
 
 #[inline]
-fn decode_typed<'de, C, R, T>(cx: &C, mut reader: R) -> Result<(T, NumberKind), C::Error>
+fn decode_number_tag<'de, C, R>(cx: &C, mut reader: R) -> Result<Tag, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
-    T: Unsigned,
 {
     let tag = Tag::from_byte(reader.read_byte(cx)?);
 
@@ -71,8 +91,35 @@ where
         return Err(cx.message(format_args!("Expected {:?}, got {tag:?}", Kind::Number)));
     }
 
-    let kind = tag.number_kind();
-    Ok((c::decode(cx, reader)?, kind))
+    Ok(tag)
+}
+
+/// Decode a float with the given tag, which must have no fractional part and
+/// be in range of the integer it's being coerced into.
+#[inline]
+fn decode_float_as_integer<'de, C, R, T>(cx: &C, reader: R, tag: Tag) -> Result<T, C::Error>
+where
+    C: ?Sized + Context,
+    R: Reader<'de>,
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    let value = match tag.data_raw() {
+        F32 => f64::from(f32::from_bits(c::decode(cx, reader)?)),
+        F64 => f64::from_bits(c::decode(cx, reader)?),
+        _ => {
+            return Err(cx.message(format_args!(
+                "Unsupported float {}",
+                NumberType(tag.data_raw())
+            )))
+        }
+    };
+
+    match float_to_integer(value) {
+        Some(value) => Ok(value),
+        None => Err(cx.message(format_args!(
+            "Float {value} can't be represented as an integer"
+        ))),
+    }
 }
 
 #[inline]
@@ -91,26 +138,116 @@ where
 }
 
 #[inline]
-pub(crate) fn decode_typed_signed<'de, C, R, T>(cx: &C, reader: R) -> Result<T, C::Error>
+pub(crate) fn decode_typed_signed<'de, C, R, T>(
+    cx: &C,
+    mut reader: R,
+    expected: u8,
+) -> Result<T, C::Error>
 where
     C: ?Sized + Context,
     R: Reader<'de>,
-    T: Signed + TryFrom<<T as Signed>::Unsigned>,
+    T: Signed + TryFrom<<T as Signed>::Unsigned> + TryFrom<u128> + TryFrom<i128>,
 {
-    let (value, kind): (T::Unsigned, NumberKind) = decode_typed(cx, reader)?;
+    let tag = decode_number_tag(cx, reader.borrow_mut())?;
+    let coercion = cx.coercion();
+
+    if coercion == Coercion::Strict && tag.data_raw() != expected {
+        return Err(cx.message(format_args!(
+            "Expected {}, got {}",
+            NumberType(expected),
+            NumberType(tag.data_raw())
+        )));
+    }
 
-    match kind {
-        NumberKind::Signed => Ok(zig::decode(value)),
+    match tag.number_kind() {
+        NumberKind::Signed => Ok(zig::decode(c::decode::<_, _, T::Unsigned>(cx, reader)?)),
         NumberKind::Unsigned => {
+            let value = c::decode::<_, _, T::Unsigned>(cx, reader)?;
+
             let Ok(value) = T::try_from(value) else {
                 return Err(cx.message(format_args!("Unsigned value outside of signed range")));
             };
 
             Ok(value)
         }
+        NumberKind::Float if coercion == Coercion::Lenient => {
+            decode_float_as_integer(cx, reader, tag)
+        }
         kind => Err(cx.message(format_args!(
             "Expected signed or unsigned number, got {:?}",
             kind
         ))),
     }
 }
+
+/// Decode a float, coercing it from other kinds of numbers according to the
+/// [`Coercion`] policy of the context.
+#[inline]
+pub(crate) fn decode_typed_float<'de, C, R, T>(
+    cx: &C,
+    mut reader: R,
+    expected: u8,
+) -> Result<T, C::Error>
+where
+    C: ?Sized + Context,
+    R: Reader<'de>,
+    T: Float,
+{
+    let tag = decode_number_tag(cx, reader.borrow_mut())?;
+
+    if cx.coercion() == Coercion::Strict && tag.data_raw() != expected {
+        return Err(cx.message(format_args!(
+            "Expected {}, got {}",
+            NumberType(expected),
+            NumberType(tag.data_raw())
+        )));
+    }
+
+    let value = match (tag.number_kind(), tag.data_raw()) {
+        (NumberKind::Float, F32) => T::from_f64(f64::from(f32::from_bits(c::decode(cx, reader)?))),
+        (NumberKind::Float, F64) => T::from_f64(f64::from_bits(c::decode(cx, reader)?)),
+        (NumberKind::Unsigned, _) => T::from_unsigned(c::decode(cx, reader)?),
+        (NumberKind::Signed, _) => {
+            T::from_signed(zig::decode(c::decode::<_, _, u128>(cx, reader)?))
+        }
+        _ => {
+            return Err(cx.message(format_args!(
+                "Expected {}, got {}",
+                NumberType(expected),
+                NumberType(tag.data_raw())
+            )))
+        }
+    };
+
+    match value {
+        Some(value) => Ok(value),
+        None => Err(cx.message(format_args!(
+            "Value of type {} can't be represented exactly as {}",
+            NumberType(tag.data_raw()),
+            NumberType(expected)
+        ))),
+    }
+}
+
+/// Display the type of number corresponding to a number tag.
+struct NumberType(u8);
+
+impl fmt::Display for NumberType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            U8 => write!(f, "u8"),
+            U16 => write!(f, "u16"),
+            U32 => write!(f, "u32"),
+            U64 => write!(f, "u64"),
+            U128 => write!(f, "u128"),
+            I8 => write!(f, "i8"),
+            I16 => write!(f, "i16"),
+            I32 => write!(f, "i32"),
+            I64 => write!(f, "i64"),
+            I128 => write!(f, "i128"),
+            F32 => write!(f, "f32"),
+            F64 => write!(f, "f64"),
+            byte => write!(f, "number with tag {byte:#04x}"),
+        }
+    }
+}
@@ -257,6 +257,12 @@ impl<T, const N: usize> FixedVec<T, N> {
         }
     }
 
+    /// Get the number of elements in the fixed vector.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
     #[inline]
     pub(crate) fn as_ptr(&self) -> *const T {
         self.data.as_ptr() as *const T
@@ -304,6 +310,23 @@ impl<T, const N: usize> FixedVec<T, N> {
         }
     }
 
+    /// Shorten the fixed vector, keeping the first `len` elements.
+    pub(crate) fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail_len = self.len - len;
+        self.len = len;
+
+        if mem::needs_drop::<T>() {
+            unsafe {
+                let tail = slice::from_raw_parts_mut(self.as_mut_ptr().wrapping_add(len), tail_len);
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
     pub(crate) fn clear(&mut self) {
         if self.len == 0 {
             return;
@@ -26,6 +26,96 @@
 //! * Tuples are serialized as sequences.
 //! * Enums are serialized as variants, where the key is the `#[musli(name =
 //!   ..)]` of the variant.
+//! * [`Result`] is serialized as a variant in the same way as an enum with
+//!   transparent `Ok` and `Err` variants would be.
+//! * [`Duration`] is serialized as a sequence of whole seconds as a [u64]
+//!   followed by subsecond nanoseconds as a [u32].
+//! * [`SystemTime`] is serialized as a sequence of whole seconds since the
+//!   unix epoch as an [i64], which is negative for times before it, followed
+//!   by subsecond nanoseconds as a [u32] which always count forward. So half a
+//!   second before the epoch is `[-1, 500000000]`.
+//!
+//! When decoding either of these, nanoseconds which are not less than
+//! `1_000_000_000` or values which can't be represented result in an error.
+//!
+//! Types providing interior mutability such as [`Cell`], [`RefCell`],
+//! [`Mutex`] and [`RwLock`] are serialized as the value they contain. Encoding
+//! a [`RefCell`] which is mutably borrowed or a lock which is poisoned results
+//! in an error.
+//!
+//! Atomic types such as [`AtomicU64`] are serialized as the value they contain,
+//! which is loaded using [`Ordering::Relaxed`].
+//!
+//! [`OsString`] and [`PathBuf`] are serialized as a variant identifying the
+//! platform they were encoded on, where the value is the raw bytes on unix and
+//! the little-endian UTF-16 code units on windows. On other platforms they're
+//! encoded as UTF-8 using the unix variant, and encoding a string which is not
+//! valid UTF-8 results in an error. There both variants can be decoded as long
+//! as they contain valid unicode.
+//!
+//! Network addresses from [`std::net`] are encoded differently depending on the
+//! [`mode`] in use:
+//!
+//! * In the [`Text`] mode, which is used by formats such as [`json`], every
+//!   address is encoded as a string using its standard textual representation,
+//!   such as `"127.0.0.1"` or `"[::1]:8080"`. Note that this omits the
+//!   `flowinfo` of a [`SocketAddrV6`].
+//! * In the [`Binary`] mode, an [`Ipv4Addr`] is encoded as 4 bytes and an
+//!   [`Ipv6Addr`] as 16 bytes. A [`SocketAddrV4`] is a pack of the address and
+//!   the port, and a [`SocketAddrV6`] a pack of the address, port, `flowinfo`
+//!   and `scope_id`. [`IpAddr`] and [`SocketAddr`] are variants where the tag
+//!   is `0` for the V4 and `1` for the V6 address.
+//!
+//! Other modes have no implementations for these types, so they need to be
+//! wrapped using `#[musli(with = ..)]` or similar.
+//!
+//! With the `uuid` feature enabled, [`Uuid`] follows the same convention. In
+//! the [`Text`] mode it's encoded as a lowercase hyphenated string like
+//! `"67e55044-10b1-426f-9247-bb680e5fe0c8"`, and decoding also accepts the
+//! simple form without hyphens. In the [`Binary`] mode it's encoded as an array
+//! of its 16 bytes.
+//!
+//! With the `bytes` feature enabled, [`Bytes`] and [`BytesMut`] from the
+//! [`bytes` crate] are encoded as bytes in every mode, the same way as a
+//! `Vec<u8>` with `#[musli(bytes)]`.
+//!
+//! With the `smallvec` and `arrayvec` features enabled, [`SmallVec`],
+//! [`ArrayVec`] and [`ArrayString`] are encoded the same way as a `Vec` and a
+//! `String`, so the formats can't tell them apart. Decoding into an
+//! [`ArrayVec`] or an [`ArrayString`] errors if the input has more elements or
+//! bytes than fit in its capacity.
+//!
+//! With the `heapless` feature enabled, the same applies to [`heapless::Vec`],
+//! [`heapless::String`] and [`heapless::IndexMap`], which is encoded like a
+//! `HashMap`. These don't require the `alloc` feature, so together with the
+//! [`Stack`] allocator and a fixed-size buffer as a [`Writer`] values can be
+//! encoded and decoded without using the heap.
+//!
+//! With the `time` feature enabled, the date and time types of the [`time`
+//! crate] are also encoded depending on the mode. This representation is
+//! stable, so that it can be relied on by other implementations:
+//!
+//! * In the [`Text`] mode, an [`OffsetDateTime`] is encoded as an [RFC 3339]
+//!   string like `"1985-04-12T23:20:50.52+01:00"`, using `Z` for UTC and
+//!   omitting the fractional seconds when they are zero. A
+//!   [`PrimitiveDateTime`] is encoded the same way without an offset, like
+//!   `"1985-04-12T23:20:50.52"`, and a [`Date`] as a full date like
+//!   `"1985-04-12"`. Only years between 0 and 9999 and offsets in whole
+//!   minutes can be represented, so anything else results in an error when
+//!   encoding.
+//! * In the [`Binary`] mode, an [`OffsetDateTime`] is encoded as a sequence of
+//!   whole seconds since the unix epoch in UTC as an [i64], followed by the
+//!   subsecond nanoseconds as a [u32], just like a [`SystemTime`]. The offset is
+//!   not preserved, so it's decoded in UTC. A [`PrimitiveDateTime`] is encoded
+//!   the same way as if it were in UTC, and a [`Date`] is encoded as the number
+//!   of days since the unix epoch as an [i32].
+//!
+//! When decoding, timestamps and dates which are out of the range supported by
+//! the [`time` crate] result in an error. RFC 3339 permits leap seconds such as
+//! `"2016-12-31T23:59:60Z"`, which can't be represented. These are accepted at
+//! the end of a month in UTC where leap seconds occur, and are decoded as the
+//! last nanosecond of the preceding second. Leap seconds at any other time
+//! result in an error.
 //!
 //! To control the exact behavior of serialization, see the [`derives`] section.
 //!
@@ -48,5 +138,42 @@
 //!
 //! [`storage`]: crate::storage
 //! [`derives`]: super::derives
+//! [`Duration`]: core::time::Duration
+//! [`SystemTime`]: std::time::SystemTime
+//! [`Cell`]: core::cell::Cell
+//! [`RefCell`]: core::cell::RefCell
+//! [`Mutex`]: std::sync::Mutex
+//! [`RwLock`]: std::sync::RwLock
+//! [`AtomicU64`]: core::sync::atomic::AtomicU64
+//! [`Ordering::Relaxed`]: core::sync::atomic::Ordering::Relaxed
+//! [`mode`]: crate::mode
+//! [`Text`]: crate::mode::Text
+//! [`Binary`]: crate::mode::Binary
+//! [`json`]: crate::json
+//! [`IpAddr`]: std::net::IpAddr
+//! [`OsString`]: std::ffi::OsString
+//! [`PathBuf`]: std::path::PathBuf
+//! [`Ipv4Addr`]: std::net::Ipv4Addr
+//! [`Ipv6Addr`]: std::net::Ipv6Addr
+//! [`SocketAddr`]: std::net::SocketAddr
+//! [`SocketAddrV4`]: std::net::SocketAddrV4
+//! [`SocketAddrV6`]: std::net::SocketAddrV6
+//! [`Uuid`]: https://docs.rs/uuid/latest/uuid/struct.Uuid.html
+//! [`bytes` crate]: https://docs.rs/bytes
+//! [`Bytes`]: https://docs.rs/bytes/latest/bytes/struct.Bytes.html
+//! [`BytesMut`]: https://docs.rs/bytes/latest/bytes/struct.BytesMut.html
+//! [`SmallVec`]: https://docs.rs/smallvec/latest/smallvec/struct.SmallVec.html
+//! [`ArrayVec`]: https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayVec.html
+//! [`ArrayString`]: https://docs.rs/arrayvec/latest/arrayvec/struct.ArrayString.html
+//! [`heapless::Vec`]: https://docs.rs/heapless/latest/heapless/struct.Vec.html
+//! [`heapless::String`]: https://docs.rs/heapless/latest/heapless/struct.String.html
+//! [`heapless::IndexMap`]: https://docs.rs/heapless/latest/heapless/struct.IndexMap.html
+//! [`Stack`]: crate::allocator::Stack
+//! [`Writer`]: crate::Writer
+//! [`time` crate]: https://docs.rs/time
+//! [`OffsetDateTime`]: https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+//! [`PrimitiveDateTime`]: https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+//! [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+//! [RFC 3339]: https://datatracker.ietf.org/doc/html/rfc3339
 //! [`Encode`]: crate::Encode
 //! [`Decode`]: crate::Decode
@@ -187,6 +187,14 @@
 //! * `kebab-case` - the field will be converted to kebab case.
 //! * `SCREAMING-KEBAB-CASE` - the field will be converted to screaming kebab case.
 //!
+//! When converting, identifiers are split into words on underscores and on
+//! changes from lowercase to uppercase. Runs of uppercase letters are treated as
+//! a single word, so `HTTPServer` becomes `http_server` in snake case. Digits
+//! are kept with the word they follow, so `Field1` becomes `field1`.
+//!
+//! Individual fields or variants can still be given a different name through
+//! `#[musli(name = ..)]`, which takes precedence over `name_all`.
+//!
 //! ```
 //! use musli::{Encode, Decode};
 //!
@@ -237,6 +245,10 @@
 //! field to define how that variant is encoded or decoded transparently without
 //! being treated as a field.
 //!
+//! Other fields are allowed as long as they are marked with
+//! `#[musli(skip)]`, and field attributes such as `#[musli(with = <path>)]` on
+//! the single field still apply.
+//!
 //! ```
 //! use musli::{Encode, Decode};
 //!
@@ -286,6 +298,72 @@
 //! # Ok::<_, musli::storage::Error>(())
 //! ```
 //!
+//! Like other attributes it can be limited to a single mode, so that a
+//! structure is packed in binary formats while text formats such as JSON still
+//! use named fields.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(mode = Binary, packed)]
+//! struct Point {
+//!     x: u32,
+//!     y: u32,
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//!
+//! assert_eq!(musli::storage::to_vec(&point)?, [1, 2]);
+//! assert_eq!(musli::json::to_string(&point)?, r#"{"x":1,"y":2}"#);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(packed_size)]`
+//!
+//! This attribute can be used on `#[musli(packed)]` structs to implement
+//! [`PackedSize`], which is the number of bytes the structure is encoded into
+//! by the `storage` format when using [`Integer::Fixed`] in the default
+//! `Binary` mode. The size is the sum of the sizes of all fields, or `None` if
+//! any field has a variable size.
+//!
+//! This is checked when decoding with [`Encoding::from_packed_slice`], which
+//! makes it possible to seek in a slice of records of the same type.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//! use musli::en::PackedSize;
+//! use musli::options::{self, Integer, Options};
+//! use musli::storage::Encoding;
+//!
+//! const OPTIONS: Options = options::new().with_integer(Integer::Fixed).build();
+//! const ENCODING: Encoding<OPTIONS> = Encoding::new().with_options();
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(packed, packed_size)]
+//! struct Struct {
+//!     field1: u32,
+//!     field2: u16,
+//!     #[musli(packed)]
+//!     field3: [u8; 2],
+//! }
+//!
+//! const _: () = assert!(matches!(Struct::PACKED_SIZE, Some(8)));
+//!
+//! let value = Struct {
+//!     field1: 1,
+//!     field2: 2,
+//!     field3: [3, 4],
+//! };
+//!
+//! let data = ENCODING.to_vec(&value)?;
+//! assert_eq!(data.len(), 8);
+//! assert_eq!(ENCODING.from_packed_slice::<Struct>(&data)?, value);
+//! # Ok::<_, musli::storage::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(name_type = ..)]`
@@ -354,8 +432,27 @@
 //! These attributes can be used to apply bounds to an [`Encode`] or [`Decode`]
 //! implementation.
 //!
-//! These are necessary to use when a generic container is used to ensure that
-//! the given parameter implements the necessary bounds.
+//! By default bounds are inferred for generic types. Every field whose type
+//! mentions a type parameter is required to implement the trait it's encoded
+//! with, so a field of type `Vec<(T, Option<T>)>` results in a bound like
+//! `Vec<(T, Option<T>)>: Encode<Binary>`. Fields which are skipped or use
+//! `#[musli(with = ..)]` don't contribute any bounds, and neither do fields
+//! whose type refers to the type being derived, which makes directly recursive
+//! types like trees work.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Clone, Debug, PartialEq, Encode, Decode)]
+//! pub struct Tree<T> {
+//!     value: T,
+//!     children: Vec<Tree<T>>,
+//! }
+//! ```
+//!
+//! If bounds are specified, they replace the inferred ones. This is necessary
+//! when the inferred bounds are wrong, such as for associated types or types
+//! which are recursive through another type.
 //!
 //! `#[musli(bound = {..})]` applies to all implementations while
 //! `#[musli(decode_bound = {..})]` only applies to the [`Decode`]
@@ -366,11 +463,168 @@
 //! use musli::{Decode, Encode};
 //! use musli::mode::{Binary, Text};
 //!
-//! #[derive(Clone, Debug, PartialEq, Encode, Decode)]
-//! #[musli(mode = Binary, bound = {T: Encode<Binary>}, decode_bound = {T: Decode<'de, Binary>})]
-//! #[musli(mode = Text, bound = {T: Encode<Text>}, decode_bound = {T: Decode<'de, Text>})]
-//! pub struct GenericWithBound<T> {
-//!     value: T,
+//! pub trait Family {
+//!     type Member;
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(mode = Binary, bound = {F::Member: Encode<Binary>}, decode_bound = {F::Member: Decode<'de, Binary>})]
+//! #[musli(mode = Text, bound = {F::Member: Encode<Text>}, decode_bound = {F::Member: Decode<'de, Text>})]
+//! pub struct Members<F>
+//! where
+//!     F: Family,
+//! {
+//!     members: Vec<F::Member>,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(deny_unknown_fields)]`
+//!
+//! By default fields which are not recognized are skipped when decoding. This
+//! attribute instead causes decoding to fail with an error naming the unknown
+//! field and where it was encountered. Only field names are checked, so any
+//! renamed field is known under its new name, and fields marked with
+//! `#[musli(skip)]` are known and still silently ignored.
+//!
+//! When used on an enum it applies to every variant encoded as a map. It
+//! cannot be used on `#[musli(packed)]` or `#[musli(transparent)]` structs.
+//!
+//! ```
+//! use musli::Decode;
+//!
+//! #[derive(Decode)]
+//! #[musli(deny_unknown_fields)]
+//! struct Strict {
+//!     number: u32,
+//! }
+//!
+//! let error = musli::json::from_str::<Strict>(r#"{"number":42,"other":1}"#);
+//! assert!(error.is_err());
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(name_case_insensitive)]`
+//!
+//! Matches the names of fields and variants ignoring ASCII case when decoding,
+//! so that `userName`, `UserName` and `username` are all accepted for a field
+//! named `userName`. Encoding always uses the canonical name. Aliases are
+//! matched the same way.
+//!
+//! This only affects names which are decoded as strings, like the default
+//! names in the [`Text`] mode. Names which only differ by case are reported as
+//! conflicting.
+//!
+//! When used on an enum it applies to both its variants and the fields of its
+//! variants.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(name_all = "camelCase", name_case_insensitive)]
+//! struct User {
+//!     user_name: String,
+//! }
+//!
+//! for input in [r#"{"userName":"Alice"}"#, r#"{"USERNAME":"Alice"}"#] {
+//!     let user: User = musli::json::from_str(input)?;
+//!     assert_eq!(user.user_name, "Alice");
+//! }
+//!
+//! let user = User { user_name: String::from("Alice") };
+//! assert_eq!(musli::json::to_string(&user)?, r#"{"userName":"Alice"}"#);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! [`Text`]: crate::mode::Text
+//!
+//! <br>
+//!
+//! #### `#[musli(recover)]`
+//!
+//! Allows decoding to recover from errors in the fields of this container when
+//! the context asks for it, like when [`SystemContext::with_recovery`] is
+//! enabled. A field which fails to decode has its error reported to the
+//! context and is replaced with its default value, and a missing field is
+//! reported and defaulted the same way. Decoding then continues with the next
+//! field so that as many errors as possible are collected at once, alongside a
+//! best-effort value.
+//!
+//! Since a field which fails to decode needs to be skipped over, its value is
+//! buffered first. This requires a format which supports buffering like
+//! [`json`] or [`descriptive`], and field types which don't borrow from the
+//! input. Fields use `#[musli(default = <path>)]` if specified, otherwise
+//! their [`Default`] implementation.
+//!
+//! Errors are recovered from at the level of fields, so a collection which
+//! fails to decode is defaulted as a whole. Elements which are themselves
+//! marked with `#[musli(recover)]` recover from errors in their own fields.
+//!
+//! When used on an enum it applies to every variant encoded as a map. It
+//! cannot be used on `#[musli(packed)]` or `#[musli(transparent)]` structs.
+//!
+//! ```
+//! use musli::Decode;
+//! use musli::allocator::System;
+//! use musli::context::SystemContext;
+//!
+//! #[derive(Debug, PartialEq, Decode)]
+//! #[musli(recover)]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//!     verbose: bool,
+//! }
+//!
+//! let alloc = System::new();
+//! let mut cx = SystemContext::new(&alloc);
+//! cx.with_recovery(true);
+//!
+//! let config: Config = musli::json::Encoding::new()
+//!     .from_str_with(&cx, r#"{"name": "app", "port": 70000, "verbose": 1}"#)
+//!     .unwrap();
+//!
+//! assert_eq!(config, Config { name: String::from("app"), port: 0, verbose: false });
+//! assert_eq!(cx.errors().count(), 2);
+//! ```
+//!
+//! [`SystemContext::with_recovery`]: crate::context::SystemContext::with_recovery
+//! [`json`]: crate::json
+//! [`descriptive`]: crate::descriptive
+//!
+//! <br>
+//!
+//! #### `#[musli(remote = <path>)]`
+//!
+//! Implements encoding and decoding for a type defined in another crate,
+//! through a local mirror type with identical fields or variants. The fields
+//! and variants of the remote type must be public.
+//!
+//! Instead of [`Encode`] and [`Decode`], the mirror type gets an `encode` and a
+//! `decode` function operating on the remote type, which can be used with
+//! `#[musli(with = <path>)]`. All other attributes apply to the mirror like
+//! they normally would.
+//!
+//! ```
+//! # mod glam { pub struct Vec3 { pub x: f32, pub y: f32, pub z: f32 } }
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(remote = glam::Vec3)]
+//! # #[allow(dead_code)]
+//! struct Vec3Def {
+//!     x: f32,
+//!     y: f32,
+//!     z: f32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! struct Camera {
+//!     #[musli(with = Vec3Def)]
+//!     position: glam::Vec3,
 //! }
 //! ```
 //!
@@ -400,6 +654,90 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(content = ..)]`
+//!
+//! When combined with `#[musli(tag = ..)]` this causes the enum to be
+//! adjacently tagged, with the content of the variant stored under the given
+//! field. See [enum representations](#enum-representations) for details on
+//! this representation.
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! # #[derive(Encode, Decode)] struct Params;
+//! # #[derive(Encode, Decode)] struct Value;
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", tag = "t", content = "c")]
+//! enum Message {
+//!     Request { id: String, method: String, params: Params },
+//!     Response { id: String, result: Value },
+//!     Ping,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(untagged)]`
+//!
+//! This attribute causes the enum to be untagged, so that only the content of
+//! each variant is encoded. See [enum representations](#enum-representations)
+//! for details on this representation.
+//!
+//! It cannot be combined with `#[musli(tag = ..)]`, `#[musli(content = ..)]`,
+//! `#[musli(packed)]` or `#[musli(transparent)]` on the enum.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(untagged)]
+//! enum Value {
+//!     #[musli(transparent)]
+//!     Number(u32),
+//!     #[musli(transparent)]
+//!     String(String),
+//!     Person { name: String, age: u32 },
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(tag_from_repr)]`
+//!
+//! This attribute uses the discriminant of each variant as its name, which
+//! requires the enum to have an integer representation like `#[repr(u16)]`.
+//! The representation is used as the `name_type`, and variants without an
+//! explicit discriminant use the value of the previous variant plus one just
+//! like in Rust.
+//!
+//! It cannot be combined with `#[musli(name = ..)]` on variants, or with
+//! `#[musli(name_type = ..)]`, `#[musli(name_all = "..")]` or
+//! `#[musli(name_method = ..)]` on the enum.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//! use musli::options::{self, Integer, Options};
+//! use musli::storage::Encoding;
+//!
+//! const OPTIONS: Options = options::new().with_integer(Integer::Fixed).build();
+//! const ENCODING: Encoding<OPTIONS> = Encoding::new().with_options();
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! #[musli(tag_from_repr)]
+//! #[repr(u16)]
+//! enum Opcode {
+//!     Nop = 0,
+//!     Read = 0x10,
+//!     Write,
+//! }
+//!
+//! let data = ENCODING.to_vec(&Opcode::Write)?;
+//! assert_eq!(data, 0x11u16.to_le_bytes());
+//! assert_eq!(ENCODING.from_slice::<Opcode>(&data)?, Opcode::Write);
+//! # Ok::<_, musli::storage::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! ## Variant attributes
 //!
 //! *Variant attributes* are attributes which apply to each individual variant
@@ -440,6 +778,30 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(alias = ..)]`
+//!
+//! An additional name which the variant is decoded from. The attribute can be
+//! repeated, and the variant is always encoded using its primary name.
+//!
+//! This is useful when renaming a variant without breaking compatibility with
+//! previously encoded data.
+//!
+//! It is a compile error for two variants to claim the same name or alias.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! enum Shape {
+//!     #[musli(alias = "Circle")]
+//!     Round,
+//!     Square,
+//! }
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(pattern = ..)]`
 //!
 //! A pattern to match for decoding a variant.
@@ -591,6 +953,58 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(other)]`
+//!
+//! Like `#[musli(default)]`, this defines the variant that will be used in case
+//! no other variant matches. The variant may either be empty or have a single
+//! field, in which case the unknown tag is stored in that field. The field
+//! must implement `From` for the name type of the enum, or for a reference to
+//! it if the name is decoded by reference such as `&str`.
+//!
+//! A variant which has captured a tag is encoded using that tag and without
+//! any content, since the content of an unknown variant is skipped when it's
+//! decoded. Capturing the tag is not supported for untagged enums.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+//! #[musli(name_all = "kebab-case")]
+//! enum Animal {
+//!     Cat,
+//!     Dog,
+//!     #[musli(other)]
+//!     Unknown(String),
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(skip)]`
+//!
+//! This attribute means that the variant is skipped. A skipped variant is never
+//! decoded, its tag is treated as unknown. Trying to encode a skipped variant
+//! results in an error. This is typically combined with `#[musli(mode = ..)]`
+//! to only include a variant in some modes.
+//!
+//! A skipped variant cannot be the fallback variant through
+//! `#[musli(default)]` or `#[musli(other)]`.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! enum Public {}
+//!
+//! #[derive(Encode, Decode)]
+//! enum Event {
+//!     LoggedIn { user: u32 },
+//!     #[musli(mode = Public, skip)]
+//!     Audit { user: u32 },
+//! }
+//! ```
+//!
+//! <br>
+//!
 //! ## Field attributes
 //!
 //! *Field attributes* are attributes which apply to each individual field
@@ -649,6 +1063,18 @@
 //! }
 //! ```
 //!
+//! If no `#[musli(default = <path>)]` is specified the type of the field must
+//! implement [`Default`], or a compile error is emitted pointing to the
+//! `skip` attribute.
+//!
+//! A skipped field still reserves its index. So if fields are named by index,
+//! the fields following it keep the same index as if the skipped field was
+//! present. This means that adding or removing `#[musli(skip)]` is compatible
+//! with a field that uses `#[musli(default)]` in the same position.
+//!
+//! In `#[musli(packed)]` and `#[musli(transparent)]` containers, skipped fields
+//! don't occupy a slot and are not counted towards the number of fields.
+//!
 //! <br>
 //!
 //! #### `#[musli(default [= <path>])]`
@@ -662,6 +1088,12 @@
 //! If `#[musli(default = <path>)]` is specified, the default value is
 //! constructed by calling the function at `<path>`.
 //!
+//! Without this attribute, decoding a container where a field is missing
+//! results in an error. This makes it possible to add new fields to an existing
+//! model while still being able to decode data which was encoded before they
+//! were added. Note that this requires that the container is encoded as a map,
+//! so it cannot be used in `#[musli(packed)]` containers.
+//!
 //! ```
 //! use musli::{Encode, Decode};
 //!
@@ -702,6 +1134,51 @@
 //! If the type of the tag is ambiguous it can be explicitly specified through
 //! the `#[musli(name_type)]` variant or container attributes.
 //!
+//! Integer names can be used to assign stable tags to fields, like in
+//! protobuf. Together with `#[musli(name_all = "index")]` fields without an
+//! explicit name keep using their index, and the tags may contain gaps. Tags
+//! which are claimed by more than one field, including through aliases, are
+//! reported as a compile error. When decoding, fields can appear in any order
+//! and unknown tags are skipped over.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "index")]
+//! struct Message {
+//!     #[musli(name = 1)]
+//!     id: u32,
+//!     #[musli(name = 2)]
+//!     name: String,
+//!     #[musli(name = 5, alias = 4)]
+//!     score: u32,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(alias = ..)]`
+//!
+//! An additional name which the field is decoded from. The attribute can be
+//! repeated, and the field is always encoded using its primary name.
+//!
+//! This is useful when renaming a field without breaking compatibility with
+//! previously encoded data.
+//!
+//! It is a compile error for two fields to claim the same name or alias.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct User {
+//!     #[musli(alias = "userName", alias = "login")]
+//!     user_name: String,
+//! }
+//! ```
+//!
 //! <br>
 //!
 //! #### `#[musli(pattern = ..)]`
@@ -783,7 +1260,8 @@
 //! This specifies the path to a module to use instead of the fields default
 //! [`Encode`] or [`Decode`] implementations.
 //!
-//! It expects `encode` and `decode` and decodee function to be defined in the path being specified, like this:
+//! It expects `encode` and `decode` functions to be defined in the path being
+//! specified, like this:
 //!
 //! ```
 //! # mod example {
@@ -916,10 +1394,85 @@
 //!
 //! <br>
 //!
+//! Like other attributes, this can be combined with `mode` so that a custom
+//! implementation is only used in a particular mode. Below the hash is encoded
+//! as a hex string in [`Text`] modes, while the default implementation is used
+//! everywhere else:
+//!
+//! ```
+//! # mod example {
+//! use musli::mode::Text;
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! struct Commit {
+//!     #[musli(mode = Text, with = self::hex)]
+//!     hash: [u8; 32],
+//! }
+//!
+//! mod hex {
+//!     use musli::{Decoder, Encoder};
+//!
+//!     pub fn encode<E>(hash: &[u8; 32], cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+//!     where
+//!         E: Encoder,
+//! # { todo!() }
+//!
+//!     pub fn decode<'de, D>(cx: &D::Cx, decoder: D) -> Result<[u8; 32], D::Error>
+//!     where
+//!         D: Decoder<'de>,
+//! # { todo!() }
+//! }
+//! # }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(encode_with = <path>)]` and `#[musli(decode_with = <path>)]`
+//!
+//! These specify the path to a single function which is used instead of the
+//! field's default [`Encode`] or [`Decode`] implementation respectively, while
+//! the other direction is left unaffected. The functions have the same
+//! signatures as the `encode` and `decode` functions used with
+//! `#[musli(with = <path>)]`.
+//!
+//! ```
+//! # mod example {
+//! use musli::{Decode, Decoder, Encode};
+//!
+//! #[derive(Encode, Decode)]
+//! struct Event {
+//!     #[musli(decode_with = self::decode_lenient)]
+//!     count: u32,
+//! }
+//!
+//! /// Accept counts which have been encoded as larger integers, saturating
+//! /// them to fit.
+//! fn decode_lenient<'de, D>(cx: &D::Cx, decoder: D) -> Result<u32, D::Error>
+//! where
+//!     D: Decoder<'de>,
+//! {
+//!     let count: u64 = decoder.decode()?;
+//!     Ok(u32::try_from(count).unwrap_or(u32::MAX))
+//! }
+//! # }
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(skip_encoding_if = <path>)]`
 //!
 //! This adds a condition to skip encoding a field entirely if the condition is
 //! true. This is very commonly used to skip over encoding `Option<T>` fields.
+//! The path is called with a reference to the field, like `&Option<u32>`.
+//!
+//! Since the field might be absent, decoding uses [`Default::default`] when the
+//! field is missing, unless another default has been specified with
+//! [`#[musli(default = <path>)]`][#muslidefault--path].
+//!
+//! This can only be used in containers which are encoded as maps, since there
+//! is no way to tell that a field is absent in a `#[musli(packed)]` or
+//! `#[musli(transparent)]` container. Doing so results in a compile error.
 //!
 //! ```
 //! use musli::{Encode, Decode};
@@ -929,9 +1482,55 @@
 //!     name: String,
 //!     #[musli(skip_encoding_if = Option::is_none)]
 //!     age: Option<u32>,
+//!     #[musli(skip_encoding_if = Vec::is_empty)]
+//!     aliases: Vec<String>,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(flatten)]`
+//!
+//! Inline the entries of a field into the map of the containing struct, instead
+//! of encoding the field as a nested map. The field must be encoded as a map,
+//! such as a struct deriving [`Encode`] and [`Decode`].
+//!
+//! When decoding, the map of the containing struct is buffered and every
+//! flattened field is decoded from the entries which the containing struct
+//! doesn't recognize. Entries which a flattened field doesn't recognize either
+//! are skipped. This requires a format which supports
+//! [`Decoder::decode_buffer`].
+//!
+//! Field names can't be shared between the containing struct and its flattened
+//! fields, which is checked at compile time for names which are literals. Note
+//! that fields are named by their index by default in the [`Binary`] mode, so
+//! structs with flattened fields usually need to be named through
+//! `#[musli(name_all = "..")]`.
+//!
+//! This is only supported on fields of structs which are encoded as maps, and
+//! cannot be combined with `#[musli(deny_unknown_fields)]`.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Pagination {
+//!     page: u32,
+//!     per_page: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Users {
+//!     users: Vec<String>,
+//!     #[musli(flatten)]
+//!     pagination: Pagination,
 //! }
 //! ```
 //!
+//! <br>
+//!
 //! #### `#[musli(trace)]`
 //!
 //! This causes the field to use the [`DecodeTrace`] / [`EncodeTrace`] when
@@ -964,6 +1563,25 @@
 //! }
 //! ```
 //!
+//! Tracing also passes through `Option`, `Box`, sequences like `Vec`, and the
+//! values of maps, so that the path composes through nested containers. Below
+//! an error in a value would be reported as `.values[3][Hello]`, and in a map
+//! of maps as `.nested[Hello][World]`.
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! struct Collection {
+//!     #[musli(trace)]
+//!     values: Option<Vec<HashMap<String, u32>>>,
+//!     #[musli(trace)]
+//!     nested: HashMap<String, HashMap<String, u32>>,
+//! }
+//! ```
+//!
 //! <br>
 //!
 //! # Enum representations
@@ -973,8 +1591,9 @@
 //!
 //! * Externally tagged (*default*).
 //! * Internally tagged when `#[musli(tag = ..)]` is specified on the enum.
-//! * Adjacently tagged when both `#[musli(tag = ..)]` and `#[musli(content)]`
-//!   are specified.
+//! * Adjacently tagged when both `#[musli(tag = ..)]` and
+//!   `#[musli(content = ..)]` are specified.
+//! * Untagged when `#[musli(untagged)]` is specified on the enum.
 //!
 //! <br>
 //!
@@ -1034,8 +1653,89 @@
 //! a map for the field corresponding to the `tag`, and then use this to
 //! determine which decoder implementation to call.
 //!
+//! <br>
+//!
+//! ## Adjacently tagged
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! # #[derive(Encode, Decode)] struct Params;
+//! # #[derive(Encode, Decode)] struct Value;
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name", tag = "t", content = "c")]
+//! enum Message {
+//!     Request { id: String, method: String, params: Params },
+//!     Response { id: String, result: Value },
+//!     Ping,
+//! }
+//! ```
+//!
+//! In JSON, the `Message::Request` would be represented as a map with exactly
+//! two entries, the tag followed by the content:
+//!
+//! ```json
+//! {"t": "Request", "c": {"id": "...", "method": "...", "params": {...}}}
+//! ```
+//!
+//! Empty variants like `Message::Ping` omit the content entirely:
+//!
+//! ```json
+//! {"t": "Ping"}
+//! ```
+//!
+//! When decoding, the tag and the content are accepted in either order, and an
+//! empty variant is also accepted with content present. An unknown tag results
+//! in an error naming the tag, unless a variant is marked with
+//! `#[musli(default)]`.
+//!
+//! The content is only accepted before the tag by formats which are *self
+//! descriptive*, where the value can be buffered through
+//! [`Decoder::decode_buffer`]. Other formats decode the tag and the content in
+//! the order in which they were encoded.
+//!
+//! <br>
+//!
+//! ## Untagged
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! #[derive(Encode, Decode)]
+//! #[musli(untagged)]
+//! enum Value {
+//!     #[musli(transparent)]
+//!     Number(u32),
+//!     #[musli(transparent)]
+//!     String(String),
+//!     Person { name: String, age: u32 },
+//! }
+//! ```
+//!
+//! In JSON, `Value::Number(42)` would be represented as `42`, and
+//! `Value::Person` as:
+//!
+//! ```json
+//! {"name": "...", "age": 61}
+//! ```
+//!
+//! Since no tag is encoded, decoding buffers the value through
+//! [`Decoder::decode_buffer`] and then tries each variant in the order they are
+//! declared, picking the first one which decodes successfully. Errors from
+//! failed attempts are discarded, and if no variant matches the error lists
+//! every variant which was tried. If a variant is marked with
+//! `#[musli(default)]` it is used instead of erroring.
+//!
+//! Since variants are tried in order, more specific variants should be
+//! declared before less specific ones. An empty variant for example is encoded
+//! as an empty map, and decoding it accepts any map.
+//!
+//! Like internal tagging this is only supported by formats which are *self
+//! descriptive*. Other formats like [`storage`] and [`wire`] produce an error
+//! when decoding an untagged enum.
+//!
 //! [`Binary`]: crate::mode::Binary
 //! [`Text`]: crate::mode::Text
+//! [`storage`]: crate::storage
+//! [`wire`]: crate::wire
 //! [`Decode`]: crate::Decode
 //! [`DecodeBytes`]: crate::de::DecodeBytes
 //! [`DecodePacked`]: crate::de::DecodePacked
@@ -1049,6 +1749,9 @@
 //! [`Encoder::encode_variant`]: crate::Encoder::encode_variant
 //! [`Encoder`]: crate::Encoder
 //! [`EncodeTrace`]: crate::en::EncodeTrace
+//! [`Encoding::from_packed_slice`]: crate::storage::Encoding::from_packed_slice
+//! [`Integer::Fixed`]: crate::options::Integer::Fixed
+//! [`PackedSize`]: crate::en::PackedSize
 //! [default mode]: crate::mode::Binary
 
 // Parts of this documentation
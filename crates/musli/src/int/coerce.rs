@@ -0,0 +1,101 @@
+//! Lossless conversions between integers and floats, used when coercing
+//! numbers during decoding.
+
+/// A floating point type which numbers can be coerced into.
+pub(crate) trait Float: Copy {
+    /// Convert from a 64-bit float if it can be represented exactly.
+    fn from_f64(value: f64) -> Option<Self>;
+
+    /// Convert from an unsigned integer if it can be represented exactly.
+    fn from_unsigned(value: u128) -> Option<Self>;
+
+    /// Convert from a signed integer if it can be represented exactly.
+    fn from_signed(value: i128) -> Option<Self>;
+}
+
+macro_rules! float {
+    ($ty:ty) => {
+        impl Float for $ty {
+            #[inline]
+            fn from_f64(value: f64) -> Option<Self> {
+                let out = value as $ty;
+
+                if out as f64 == value || value.is_nan() {
+                    Some(out)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn from_unsigned(value: u128) -> Option<Self> {
+                if is_exact(value, <$ty>::MANTISSA_DIGITS) {
+                    Some(value as $ty)
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn from_signed(value: i128) -> Option<Self> {
+                if is_exact(value.unsigned_abs(), <$ty>::MANTISSA_DIGITS) {
+                    Some(value as $ty)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+float!(f32);
+float!(f64);
+
+/// Test if an integer with the given magnitude fits in a float mantissa with
+/// the given number of digits.
+#[inline]
+fn is_exact(magnitude: u128, digits: u32) -> bool {
+    magnitude == 0 || u128::BITS - magnitude.leading_zeros() - magnitude.trailing_zeros() <= digits
+}
+
+/// Convert a float without a fractional part into an integer, returning `None`
+/// if it has a fractional part or is out of range for `T`.
+#[inline]
+pub(crate) fn float_to_integer<T>(value: f64) -> Option<T>
+where
+    T: TryFrom<u128> + TryFrom<i128>,
+{
+    // 2^127 and 2^128, which are the first values out of range.
+    const I128_END: f64 = 170141183460469231731687303715884105728.0;
+    const U128_END: f64 = 340282366920938463463374607431768211456.0;
+
+    if !value.is_finite() {
+        return None;
+    }
+
+    if value < 0.0 {
+        if value < -I128_END {
+            return None;
+        }
+
+        let n = value as i128;
+
+        if n as f64 != value {
+            return None;
+        }
+
+        T::try_from(n).ok()
+    } else {
+        if value >= U128_END {
+            return None;
+        }
+
+        let n = value as u128;
+
+        if n as f64 != value {
+            return None;
+        }
+
+        T::try_from(n).ok()
+    }
+}
@@ -17,6 +17,7 @@ where
     R: Reader<'de>,
     T: int::Unsigned,
 {
+    let start = cx.mark();
     let mut b = r.read_byte(cx)?;
 
     if b & CONT_BYTE == 0 {
@@ -30,11 +31,18 @@ where
         shift += 7;
 
         if shift >= T::BITS {
-            return Err(cx.message("Bits overflow"));
+            return Err(cx.marked_message(start, "Bits overflow"));
         }
 
         b = r.read_byte(cx)?;
-        value = value.wrapping_add(T::from_byte(b & MASK_BYTE).wrapping_shl(shift));
+        let part = T::from_byte(b & MASK_BYTE);
+
+        // Bits which don't fit in the target type are an overflow.
+        if part.wrapping_shl(shift) >> shift != part {
+            return Err(cx.marked_message(start, "Bits overflow"));
+        }
+
+        value = value.wrapping_add(part.wrapping_shl(shift));
     }
 
     Ok(value)
@@ -126,13 +126,14 @@ where
         crate::options::Integer::Variable => c::decode(cx, reader),
         _ => {
             let bo = crate::options::byteorder::<OPT>();
+            let start = cx.mark();
 
             macro_rules! fixed {
                 ($ty:ty) => {{
                     let Ok(value) =
                         usize::try_from(<$ty as UnsignedOps>::read_bytes(cx, reader, bo)?)
                     else {
-                        return Err(cx.message("Value type out of bounds for usize"));
+                        return Err(cx.marked_message(start, "Value type out of bounds for usize"));
                     };
 
                     Ok(value)
@@ -13,6 +13,8 @@
     feature = "value"
 ))]
 
+#[cfg(any(feature = "descriptive", feature = "value"))]
+mod coerce;
 pub(crate) mod continuation;
 mod encoding;
 mod traits;
@@ -21,6 +23,8 @@ pub(crate) mod zigzag;
 pub(crate) use self::encoding::{
     decode_signed, decode_unsigned, decode_usize, encode_signed, encode_unsigned, encode_usize,
 };
+#[cfg(any(feature = "descriptive", feature = "value"))]
+pub(crate) use self::coerce::{float_to_integer, Float};
 pub(crate) use self::traits::{Signed, Unsigned, UnsignedOps};
 
 #[cfg(test)]
@@ -164,6 +164,14 @@ where
         JsonDecoder::new(self.cx, self.parser).decode_string(visitor)
     }
 
+    #[inline]
+    fn decode_bytes<V>(self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        self.decode_escaped_bytes(visitor)
+    }
+
     #[inline]
     fn decode_any<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
     where
@@ -26,7 +26,8 @@ use core::str;
 use alloc::vec::Vec;
 
 use crate::de::{
-    Decode, DecodeUnsized, Decoder, SequenceDecoder, SizeHint, Skip, UnsizedVisitor, Visitor,
+    Coercion, Decode, DecodeUnsized, Decoder, SequenceDecoder, SizeHint, Skip, UnsizedVisitor,
+    Visitor,
 };
 use crate::hint::{MapHint, SequenceHint};
 #[cfg(feature = "value")]
@@ -43,7 +44,7 @@ use super::parser::integer::{
 use super::parser::integer::{
     parse_signed_full as parse_signed, parse_unsigned_full as parse_unsigned,
 };
-use super::parser::{integer, string, Parser, StringReference, Token};
+use super::parser::{integer, string, Parser, SliceParser, StringReference, Token};
 
 #[cfg(feature = "value")]
 const BUFFER_OPTIONS: Options = options::new().with_map_keys_as_numbers(true).build();
@@ -88,6 +89,109 @@ where
         }
     }
 
+    /// Decode an unsigned integer according to the [`Coercion`] policy of the
+    /// context.
+    #[inline]
+    fn decode_unsigned<T>(mut self) -> Result<T, C::Error>
+    where
+        T: integer::Unsigned,
+    {
+        match self.cx.coercion() {
+            Coercion::Strict => {
+                let value = integer::parse_unsigned_base(self.cx, self.parser.borrow_mut())?;
+                self.ensure_integer_end()?;
+                Ok(value)
+            }
+            Coercion::Widening => parse_unsigned(self.cx, self.parser.borrow_mut()),
+            Coercion::Lenient => {
+                if self.is_lenient_string()? {
+                    return self.decode_quoted(|cx, p| integer::parse_unsigned_full(cx, p));
+                }
+
+                integer::parse_unsigned_full(self.cx, self.parser.borrow_mut())
+            }
+        }
+    }
+
+    /// Decode a signed integer according to the [`Coercion`] policy of the
+    /// context.
+    #[inline]
+    fn decode_signed<T>(mut self) -> Result<T, C::Error>
+    where
+        T: integer::Signed,
+    {
+        match self.cx.coercion() {
+            Coercion::Strict => {
+                let value = integer::parse_signed_base(self.cx, self.parser.borrow_mut())?;
+                self.ensure_integer_end()?;
+                Ok(value)
+            }
+            Coercion::Widening => parse_signed(self.cx, self.parser.borrow_mut()),
+            Coercion::Lenient => {
+                if self.is_lenient_string()? {
+                    return self.decode_quoted(|cx, p| integer::parse_signed_full(cx, p));
+                }
+
+                integer::parse_signed_full(self.cx, self.parser.borrow_mut())
+            }
+        }
+    }
+
+    /// Ensure that a strictly parsed integer isn't followed by a fraction or an
+    /// exponent.
+    #[inline]
+    fn ensure_integer_end(&mut self) -> Result<(), C::Error> {
+        if matches!(self.parser.peek_byte(self.cx)?, Some(b'.' | b'e' | b'E')) {
+            return Err(self
+                .cx
+                .message("Expected integer without a fraction or an exponent"));
+        }
+
+        Ok(())
+    }
+
+    /// Test if the next value is a string which should be parsed as a number.
+    #[inline]
+    fn is_lenient_string(&mut self) -> Result<bool, C::Error> {
+        if self.cx.coercion() != Coercion::Lenient {
+            return Ok(false);
+        }
+
+        Ok(matches!(self.parser.peek(self.cx)?, Token::String))
+    }
+
+    /// Decode a number from inside of a string, like `"42"`.
+    fn decode_quoted<T>(
+        mut self,
+        parse: impl FnOnce(&C, &mut SliceParser<'_>) -> Result<T, C::Error>,
+    ) -> Result<T, C::Error> {
+        let Some(mut scratch) = self.cx.alloc() else {
+            return Err(self.cx.message("Failed to allocate scratch buffer"));
+        };
+
+        let start = self.cx.mark();
+
+        let string = match self.parser.parse_string(self.cx, true, &mut scratch)? {
+            StringReference::Borrowed(string) => string,
+            StringReference::Scratch(string) => string,
+        };
+
+        // NB: Parsing the string advances the context, so it's rolled back to
+        // report errors as spanning the whole string.
+        let checkpoint = self.cx.checkpoint();
+        let mut parser = SliceParser::new(string.as_bytes());
+        let result = parse(self.cx, &mut parser);
+        self.cx.restore(checkpoint);
+
+        match result {
+            Ok(value) if parser.index == string.len() => Ok(value),
+            _ => Err(self.cx.marked_message(
+                start,
+                format_args!("Number in string {string:?} is invalid or out of range"),
+            )),
+        }
+    }
+
     #[inline]
     fn parse_true(mut self) -> Result<(), C::Error> {
         self.parser.parse_exact(self.cx, "true")
@@ -171,6 +275,12 @@ where
         Ok(Skip::Skipped)
     }
 
+    #[cfg(feature = "value")]
+    #[inline]
+    fn is_bufferable(&self) -> bool {
+        true
+    }
+
     #[cfg(feature = "value")]
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
@@ -228,72 +338,80 @@ where
     }
 
     #[inline]
-    fn decode_u8(mut self) -> Result<u8, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_u8(self) -> Result<u8, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_u16(mut self) -> Result<u16, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_u16(self) -> Result<u16, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_u32(mut self) -> Result<u32, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_u32(self) -> Result<u32, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_u64(mut self) -> Result<u64, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_u64(self) -> Result<u64, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_u128(mut self) -> Result<u128, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_u128(self) -> Result<u128, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_i8(mut self) -> Result<i8, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_i8(self) -> Result<i8, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
-    fn decode_i16(mut self) -> Result<i16, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_i16(self) -> Result<i16, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
-    fn decode_i32(mut self) -> Result<i32, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_i32(self) -> Result<i32, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
-    fn decode_i64(mut self) -> Result<i64, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_i64(self) -> Result<i64, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
-    fn decode_i128(mut self) -> Result<i128, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_i128(self) -> Result<i128, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
-    fn decode_usize(mut self) -> Result<usize, C::Error> {
-        parse_unsigned(self.cx, self.parser.borrow_mut())
+    fn decode_usize(self) -> Result<usize, C::Error> {
+        self.decode_unsigned()
     }
 
     #[inline]
-    fn decode_isize(mut self) -> Result<isize, C::Error> {
-        parse_signed(self.cx, self.parser.borrow_mut())
+    fn decode_isize(self) -> Result<isize, C::Error> {
+        self.decode_signed()
     }
 
     #[inline]
     fn decode_f32(mut self) -> Result<f32, C::Error> {
+        if self.is_lenient_string()? {
+            return self.decode_quoted(|cx, p| p.parse_f32(cx));
+        }
+
         self.parser.parse_f32(self.cx)
     }
 
     #[inline]
     fn decode_f64(mut self) -> Result<f64, C::Error> {
+        if self.is_lenient_string()? {
+            return self.decode_quoted(|cx, p| p.parse_f64(cx));
+        }
+
         self.parser.parse_f64(self.cx)
     }
 
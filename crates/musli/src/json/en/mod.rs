@@ -247,13 +247,14 @@ where
         encode_string(self.cx, self.writer.borrow_mut(), string.as_bytes())
     }
 
+    /// The value is escaped as it's being written, so this doesn't need to
+    /// allocate.
     #[inline]
-    fn collect_string<T>(self, value: &T) -> Result<Self::Ok, <Self::Cx as Context>::Error>
+    fn collect_string<T>(mut self, value: &T) -> Result<Self::Ok, <Self::Cx as Context>::Error>
     where
         T: ?Sized + fmt::Display,
     {
-        let buf = self.cx.collect_string(value)?;
-        self.encode_string(buf.as_ref())
+        encode_display(self.cx, self.writer.borrow_mut(), value)
     }
 
     #[inline]
@@ -330,7 +331,18 @@ where
     W: Writer,
 {
     w.write_byte(cx, b'"')?;
+    encode_escaped(cx, w.borrow_mut(), bytes)?;
+    w.write_byte(cx, b'"')?;
+    Ok(())
+}
 
+/// Write the given bytes escaped, without the surrounding quotes.
+#[inline]
+fn encode_escaped<C, W>(cx: &C, mut w: W, bytes: &[u8]) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+    W: Writer,
+{
     let mut start = 0;
 
     for (i, &b) in bytes.iter().enumerate() {
@@ -352,10 +364,66 @@ where
         w.write_bytes(cx, &bytes[start..])?;
     }
 
+    Ok(())
+}
+
+/// Encode a displayable value as a string, escaping it as it's being written.
+fn encode_display<C, W, T>(cx: &C, mut w: W, value: &T) -> Result<(), C::Error>
+where
+    C: ?Sized + Context,
+    W: Writer,
+    T: ?Sized + fmt::Display,
+{
+    w.write_byte(cx, b'"')?;
+
+    let mut escaping = EscapingWriter {
+        cx,
+        writer: w.borrow_mut(),
+        error: None,
+    };
+
+    let result = fmt::write(&mut escaping, format_args!("{value}"));
+    let error = escaping.error.take();
+    drop(escaping);
+
+    if result.is_err() {
+        return Err(match error {
+            Some(error) => error,
+            None => cx.message("Failed to write to string"),
+        });
+    }
+
     w.write_byte(cx, b'"')?;
     Ok(())
 }
 
+/// Adapter which escapes anything formatted into it, capturing the first error
+/// raised by the underlying writer.
+struct EscapingWriter<'a, C, W>
+where
+    C: ?Sized + Context,
+{
+    cx: &'a C,
+    writer: W,
+    error: Option<C::Error>,
+}
+
+impl<C, W> fmt::Write for EscapingWriter<'_, C, W>
+where
+    C: ?Sized + Context,
+    W: Writer,
+{
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if let Err(error) = encode_escaped(self.cx, self.writer.borrow_mut(), s.as_bytes()) {
+            self.error = Some(error);
+            return Err(fmt::Error);
+        }
+
+        Ok(())
+    }
+}
+
 // Parts below copied from serde-json under the MIT license:
 //
 // https://github.com/serde-rs/json
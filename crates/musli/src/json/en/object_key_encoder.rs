@@ -134,7 +134,6 @@ where
     where
         T: ?Sized + fmt::Display,
     {
-        let buf = self.cx.collect_string(value)?;
-        self.encode_string(buf.as_ref())
+        super::encode_display(self.cx, self.writer, value)
     }
 }
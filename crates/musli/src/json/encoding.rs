@@ -2,6 +2,7 @@
 //! encoding format, and the [`DEFAULT`] encoding configuration.
 
 use core::marker;
+use core::ops::ControlFlow;
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
@@ -311,6 +312,112 @@ impl<M> Encoding<M> {
         JsonDecoder::new(cx, SliceParser::new(bytes)).decode()
     }
 
+    /// Decode the given slice into an existing value of type `T` using the
+    /// current configuration, reusing any allocations it holds where possible.
+    ///
+    /// See [`Decode::decode_in_place`] for details.
+    #[inline]
+    pub fn from_slice_into<'de, T>(self, bytes: &'de [u8], place: &mut T) -> Result<(), Error>
+    where
+        T: Decode<'de, M>,
+    {
+        default_allocator!(|alloc| {
+            let cx = crate::context::Same::<_, M, _>::new(alloc);
+            self.from_slice_into_with(&cx, bytes, place)
+        })
+    }
+
+    /// Decode the given slice into an existing value of type `T` using the
+    /// current configuration.
+    ///
+    /// This is the same as [`Encoding::from_slice_into`] but allows for using a
+    /// configurable [`Context`].
+    #[inline]
+    pub fn from_slice_into_with<'de, C, T>(
+        self,
+        cx: &C,
+        bytes: &'de [u8],
+        place: &mut T,
+    ) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+        T: Decode<'de, M>,
+    {
+        cx.clear();
+        T::decode_in_place(cx, JsonDecoder::new(cx, SliceParser::new(bytes)), place)
+    }
+
+    /// Decode a sequence of `T` from the given slice one element at a time
+    /// using the current configuration, without collecting the elements.
+    ///
+    /// Decoded elements may borrow from `bytes`. Returning
+    /// [`ControlFlow::Break`] from the callback stops the callback from being
+    /// called, but the remaining elements are still decoded and discarded. See
+    /// [`Decoder::decode_sequence_each`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    ///
+    /// use musli::json::Encoding;
+    /// # use musli::json::Error;
+    ///
+    /// const ENCODING: Encoding = Encoding::new();
+    ///
+    /// let mut sum = 0;
+    ///
+    /// let output = ENCODING.from_slice_seq(b"[1, 2, 3, 4]", |n: u32| {
+    ///     sum += n;
+    ///
+    ///     if sum > 2 {
+    ///         return ControlFlow::Break(n);
+    ///     }
+    ///
+    ///     ControlFlow::Continue(())
+    /// })?;
+    ///
+    /// assert_eq!(output, ControlFlow::Break(2));
+    /// assert_eq!(sum, 3);
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub fn from_slice_seq<'de, T, F, B>(
+        self,
+        bytes: &'de [u8],
+        f: F,
+    ) -> Result<ControlFlow<B>, Error>
+    where
+        T: Decode<'de, M>,
+        F: FnMut(T) -> ControlFlow<B>,
+    {
+        default_allocator!(|alloc| {
+            let cx = crate::context::Same::<_, M, _>::new(alloc);
+            self.from_slice_seq_with(&cx, bytes, f)
+        })
+    }
+
+    /// Decode a sequence of `T` from the given slice one element at a time
+    /// using the current configuration.
+    ///
+    /// This is the same as [`Encoding::from_slice_seq`] but allows for using a
+    /// configurable [`Context`].
+    #[inline]
+    pub fn from_slice_seq_with<'de, C, T, F, B>(
+        self,
+        cx: &C,
+        bytes: &'de [u8],
+        f: F,
+    ) -> Result<ControlFlow<B>, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+        T: Decode<'de, M>,
+        F: FnMut(T) -> ControlFlow<B>,
+    {
+        cx.clear();
+        JsonDecoder::new(cx, SliceParser::new(bytes)).decode_sequence_each(f)
+    }
+
     crate::encode_with_extensions!(M, json);
 }
 
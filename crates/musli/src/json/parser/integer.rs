@@ -262,7 +262,6 @@ where
 }
 
 /// Partially parse an unsigned value.
-#[cfg_attr(feature = "parse-full", allow(unused))]
 #[inline(never)]
 pub(crate) fn parse_unsigned_base<'de, T, C, P>(cx: &C, mut p: P) -> Result<T, C::Error>
 where
@@ -277,7 +276,6 @@ where
 }
 
 /// Fully parse an unsigned value.
-#[cfg_attr(not(feature = "parse-full"), allow(unused))]
 #[inline(never)]
 pub(crate) fn parse_unsigned_full<'de, T, C, P>(cx: &C, mut p: P) -> Result<T, C::Error>
 where
@@ -361,7 +359,6 @@ where
 }
 
 /// Fully parse a signed value.
-#[cfg_attr(feature = "parse-full", allow(unused))]
 #[inline(never)]
 pub(crate) fn parse_signed_base<'de, T, C, P>(cx: &C, mut p: P) -> Result<T, C::Error>
 where
@@ -380,7 +377,6 @@ where
 }
 
 /// Fully parse a signed value.
-#[cfg_attr(not(feature = "parse-full"), allow(unused))]
 #[inline(never)]
 pub(crate) fn parse_signed_full<'de, T, C, P>(cx: &C, mut p: P) -> Result<T, C::Error>
 where
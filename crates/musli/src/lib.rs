@@ -556,7 +556,7 @@ pub use musli_core::decoder;
 pub use musli_core::visitor;
 
 #[doc(inline)]
-pub use musli_core::{Allocator, Buf, Context, Decode, Decoder, Encode, Encoder};
+pub use musli_core::{Allocator, Buf, Context, Decode, DecodeOwned, Decoder, Encode, Encoder};
 
 #[doc(hidden)]
 pub use musli_core::__priv;
@@ -292,6 +292,178 @@ macro_rules! encode_with_extensions {
             self.encode_with(cx, &mut bytes, value)?;
             Ok(bytes)
         }
+
+        /// Feed the encoded form of the given value into the given
+        /// [`Hasher`][::core::hash::Hasher] using the current configuration.
+        ///
+        /// This doesn't allocate, see [`HashWriter`] for details.
+        ///
+        /// [`HashWriter`]: crate::writer::HashWriter
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use std::collections::hash_map::DefaultHasher;
+        /// use std::hash::Hasher;
+        ///
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let person = Person {
+        ///     name: "Alice".to_string(),
+        ///     age: 35,
+        /// };
+        ///
+        /// let mut hasher = DefaultHasher::new();
+        /// ENCODING.hash(&person, &mut hasher)?;
+        ///
+        /// let mut expected = DefaultHasher::new();
+        /// expected.write(&ENCODING.to_vec(&person)?);
+        /// assert_eq!(hasher.finish(), expected.finish());
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn hash<T, H>(self, value: &T, hasher: &mut H) -> Result<(), Error>
+        where
+            T: ?Sized + Encode<$mode>,
+            H: ?Sized + ::core::hash::Hasher,
+        {
+            self.encode($crate::writer::HashWriter::new(hasher), value)
+        }
+
+        /// Feed the encoded form of the given value into the given
+        /// [`Hasher`][::core::hash::Hasher] using the current configuration.
+        ///
+        /// This is the same as [`Encoding::hash`], but allows for using a
+        /// configurable [`Context`].
+        #[inline]
+        pub fn hash_with<C, T, H>(self, cx: &C, value: &T, hasher: &mut H) -> Result<(), C::Error>
+        where
+            C: ?Sized + Context<Mode = $mode>,
+            T: ?Sized + Encode<$mode>,
+            H: ?Sized + ::core::hash::Hasher,
+        {
+            self.encode_with(cx, $crate::writer::HashWriter::new(hasher), value)
+        }
+
+        /// Calculate the number of bytes the given value is encoded into using
+        /// the current configuration.
+        ///
+        /// This encodes the value into a [`CountWriter`], which discards the
+        /// output so that no allocation is necessary.
+        ///
+        /// [`CountWriter`]: crate::writer::CountWriter
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let person = Person {
+        ///     name: "Alice".to_string(),
+        ///     age: 35,
+        /// };
+        ///
+        /// let len = ENCODING.encoded_len(&person)?;
+        /// assert_eq!(len, ENCODING.to_vec(&person)?.len());
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn encoded_len<T>(self, value: &T) -> Result<usize, Error>
+        where
+            T: ?Sized + Encode<$mode>,
+        {
+            let mut writer = $crate::writer::CountWriter::new();
+            self.encode(&mut writer, value)?;
+            Ok(writer.len())
+        }
+
+        /// Calculate the number of bytes the given value is encoded into using
+        /// the current configuration.
+        ///
+        /// This is the same as [`Encoding::encoded_len`], but allows for using
+        /// a configurable [`Context`].
+        #[inline]
+        pub fn encoded_len_with<C, T>(self, cx: &C, value: &T) -> Result<usize, C::Error>
+        where
+            C: ?Sized + Context<Mode = $mode>,
+            T: ?Sized + Encode<$mode>,
+        {
+            let mut writer = $crate::writer::CountWriter::new();
+            self.encode_with(cx, &mut writer, value)?;
+            Ok(writer.len())
+        }
+
+        /// Test if the two given values would encode identically using the
+        /// current configuration.
+        ///
+        /// The first value is encoded into a [`Vec`], after which the encoding
+        /// of the second value is compared against it as it is being written
+        /// through a [`CompareWriter`]. Encoding stops at the first byte which
+        /// differs.
+        ///
+        /// [`CompareWriter`]: crate::writer::CompareWriter
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let a = Person { name: "Alice".to_string(), age: 35 };
+        /// let b = Person { name: "Alice".to_string(), age: 36 };
+        ///
+        /// assert!(ENCODING.encoded_eq(&a, &a)?);
+        /// assert!(!ENCODING.encoded_eq(&a, &b)?);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[cfg(feature = "alloc")]
+        #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+        #[inline]
+        pub fn encoded_eq<A, B>(self, a: &A, b: &B) -> Result<bool, Error>
+        where
+            A: ?Sized + Encode<$mode>,
+            B: ?Sized + Encode<$mode>,
+        {
+            let reference = self.to_vec(a)?;
+            let mut writer = $crate::writer::CompareWriter::new(&reference);
+            let result = self.encode(&mut writer, b);
+
+            if writer.mismatch().is_some() {
+                return Ok(false);
+            }
+
+            result?;
+            Ok(writer.is_complete())
+        }
     };
 }
 
@@ -430,6 +602,12 @@ macro_rules! encoding_impls {
         /// Decode the given type `T` from the given slice using the current
         /// configuration.
         ///
+        /// To call this from a generic function which decodes owned values,
+        /// bound the type on [`DecodeOwned`] instead of `for<'de> Decode<'de,
+        /// _>`.
+        ///
+        /// [`DecodeOwned`]: crate::de::DecodeOwned
+        ///
         /// # Examples
         ///
         /// ```
@@ -538,6 +716,148 @@ macro_rules! encoding_impls {
             self.from_slice_with(cx, string.as_bytes())
         }
 
+        /// Decode the given slice into an existing value of type `T` using the
+        /// current configuration, reusing any allocations it holds where
+        /// possible.
+        ///
+        /// See [`Decode::decode_in_place`] for details.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use musli::{Decode, Encode};
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// #[derive(Decode, Encode)]
+        /// struct Person {
+        ///     name: String,
+        ///     age: u32,
+        /// }
+        ///
+        /// let data = ENCODING.to_vec(&Person {
+        ///     name: "Alice".to_string(),
+        ///     age: 35,
+        /// })?;
+        ///
+        /// let mut person = Person {
+        ///     name: String::with_capacity(32),
+        ///     age: 0,
+        /// };
+        ///
+        /// ENCODING.from_slice_into(&data[..], &mut person)?;
+        /// assert_eq!(person.name, "Alice");
+        /// assert_eq!(person.age, 35);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn from_slice_into<'de, T>(self, bytes: &'de [u8], place: &mut T) -> Result<(), Error>
+        where
+            T: Decode<'de, $mode>,
+        {
+            $crate::default_allocator!(|alloc| {
+                let cx = $crate::context::Same::new(alloc);
+                self.from_slice_into_with(&cx, bytes, place)
+            })
+        }
+
+        /// Decode the given slice into an existing value of type `T` using the
+        /// current configuration.
+        ///
+        /// This is the same as [`Encoding::from_slice_into`], but allows for
+        /// using a configurable [`Context`].
+        #[inline]
+        pub fn from_slice_into_with<'de, C, T>(
+            self,
+            cx: &C,
+            bytes: &'de [u8],
+            place: &mut T,
+        ) -> Result<(), C::Error>
+        where
+            C: ?Sized + Context<Mode = $mode>,
+            T: Decode<'de, $mode>,
+        {
+            cx.clear();
+            let reader = $crate::reader::SliceReader::new(bytes);
+            T::decode_in_place(cx, $decoder_new(cx, reader), place)
+        }
+
+        /// Decode a sequence of `T` from the given slice one element at a time
+        /// using the current configuration, without collecting the elements.
+        ///
+        /// Decoded elements may borrow from `bytes`. Returning
+        /// [`ControlFlow::Break`] from the callback stops the callback from
+        /// being called, but the remaining elements are still decoded and
+        /// discarded. See [`Decoder::decode_sequence_each`] for details.
+        ///
+        /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+        /// [`Decoder::decode_sequence_each`]: crate::Decoder::decode_sequence_each
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::ops::ControlFlow;
+        ///
+        #[doc = concat!("use musli::", stringify!($what), "::Encoding;")]
+        #[doc = concat!("# use musli::", stringify!($what), "::Error;")]
+        ///
+        /// const ENCODING: Encoding = Encoding::new();
+        ///
+        /// let data = ENCODING.to_vec(&["Aristotle", "Plato", "Socrates"])?;
+        ///
+        /// let mut short = Vec::new();
+        ///
+        /// ENCODING.from_slice_seq(&data[..], |name: &str| {
+        ///     if name.len() < 8 {
+        ///         short.push(name);
+        ///     }
+        ///
+        ///     ControlFlow::<()>::Continue(())
+        /// })?;
+        ///
+        /// assert_eq!(short, ["Plato"]);
+        /// # Ok::<(), Error>(())
+        /// ```
+        #[inline]
+        pub fn from_slice_seq<'de, T, F, B>(
+            self,
+            bytes: &'de [u8],
+            f: F,
+        ) -> Result<::core::ops::ControlFlow<B>, Error>
+        where
+            T: Decode<'de, $mode>,
+            F: FnMut(T) -> ::core::ops::ControlFlow<B>,
+        {
+            $crate::default_allocator!(|alloc| {
+                let cx = $crate::context::Same::new(alloc);
+                self.from_slice_seq_with(&cx, bytes, f)
+            })
+        }
+
+        /// Decode a sequence of `T` from the given slice one element at a time
+        /// using the current configuration.
+        ///
+        /// This is the same as [`Encoding::from_slice_seq`], but allows for
+        /// using a configurable [`Context`].
+        #[inline]
+        pub fn from_slice_seq_with<'de, C, T, F, B>(
+            self,
+            cx: &C,
+            bytes: &'de [u8],
+            f: F,
+        ) -> Result<::core::ops::ControlFlow<B>, C::Error>
+        where
+            C: ?Sized + Context<Mode = $mode>,
+            T: Decode<'de, $mode>,
+            F: FnMut(T) -> ::core::ops::ControlFlow<B>,
+        {
+            cx.clear();
+            let reader = $crate::reader::SliceReader::new(bytes);
+            $crate::Decoder::decode_sequence_each($decoder_new(cx, reader), f)
+        }
+
         $crate::encode_with_extensions!($mode, $what);
     };
 }
@@ -795,7 +1115,7 @@ macro_rules! assert_decode_eq {
 
         macro_rules! decode {
             ($name:ident) => {{
-                $crate::$name::test::decode($expr, &mut bytes, &$expected);
+                let _ = $crate::$name::test::decode($expr, &mut bytes, &$expected);
             }}
         }
 
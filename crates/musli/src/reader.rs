@@ -116,9 +116,8 @@ pub trait Reader<'de> {
             }
 
             #[inline]
-            fn visit_ref(mut self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+            fn visit_ref(mut self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
                 self.0.copy_from_slice(bytes);
-                cx.advance(bytes.len());
                 Ok(self.0)
             }
         }
@@ -192,9 +191,8 @@ impl<'de> Reader<'de> for &'de [u8] {
 
         let (head, tail) = self.split_at(n);
         *self = tail;
-        let ok = visitor.visit_borrowed(cx, head)?;
         cx.advance(n);
-        Ok(ok)
+        visitor.visit_borrowed(cx, head)
     }
 
     #[inline]
@@ -235,6 +233,98 @@ impl<'de> Reader<'de> for &'de [u8] {
     }
 }
 
+/// A [`Reader`] which consumes a [`Bytes`] buffer, advancing past the bytes
+/// which are read.
+///
+/// Since the bytes are not borrowed for `'de`, they are handed to visitors by
+/// reference and values which own their data have to copy it. To decode values
+/// borrowing from the buffer, read from `&bytes[..]` instead.
+///
+/// [`Bytes`]: bytes::Bytes
+///
+/// # Examples
+///
+/// ```
+/// use bytes::BytesMut;
+///
+/// let mut buf = BytesMut::new();
+/// musli::storage::encode(&mut buf, &42u32)?;
+/// musli::storage::encode(&mut buf, "Hello World")?;
+///
+/// let mut bytes = buf.freeze();
+/// let number: u32 = musli::storage::decode(&mut bytes)?;
+/// let string: String = musli::storage::decode(&mut bytes)?;
+///
+/// assert_eq!(number, 42);
+/// assert_eq!(string, "Hello World");
+/// assert!(bytes.is_empty());
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+impl<'de> Reader<'de> for bytes::Bytes {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn skip<C>(&mut self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.len() < n {
+            return Err(cx.message(SliceUnderflow {
+                n,
+                remaining: self.len(),
+            }));
+        }
+
+        bytes::Buf::advance(self, n);
+        cx.advance(n);
+        Ok(())
+    }
+
+    #[inline]
+    fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if self.len() < buf.len() {
+            return Err(cx.message("Buffer underflow"));
+        }
+
+        bytes::Buf::copy_to_slice(self, buf);
+        cx.advance(buf.len());
+        Ok(())
+    }
+
+    #[inline]
+    fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        C: ?Sized + Context,
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        if self.len() < n {
+            return Err(cx.message("Buffer underflow"));
+        }
+
+        let head = self.split_to(n);
+        cx.advance(n);
+        visitor.visit_ref(cx, &head)
+    }
+
+    #[inline]
+    fn peek<C>(&mut self, _: &C) -> Result<Option<u8>, C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        Ok(self.first().copied())
+    }
+}
+
 /// An efficient [`Reader`] wrapper around a slice.
 pub struct SliceReader<'de> {
     range: Range<*const u8>,
@@ -329,14 +419,11 @@ impl<'de> Reader<'de> for SliceReader<'de> {
     {
         let outcome = bounds_check_add(cx, &self.range, n)?;
 
-        let ok = unsafe {
-            let bytes = slice::from_raw_parts(self.range.start, n);
-            self.range.start = outcome;
-            visitor.visit_borrowed(cx, bytes)?
-        };
-
+        // SAFETY: The range has been bounds checked just above.
+        let bytes = unsafe { slice::from_raw_parts(self.range.start, n) };
+        self.range.start = outcome;
         cx.advance(n);
-        Ok(ok)
+        visitor.visit_borrowed(cx, bytes)
     }
 
     #[inline]
@@ -10,7 +10,36 @@
 //! different model and Müsli metadata such as `#[musli(name = ..)]` is not
 //! available in [`serde`].
 //!
+//! The [`Serde`] wrapper can be used instead of the attribute where a value
+//! needs to be encoded or decoded directly.
+//!
 //! [`serde`]: https://serde.rs
+//! [`Serde`]: crate::compat::Serde
+//!
+//! <br>
+//!
+//! ## Representation
+//!
+//! The [`serde` data model] is mapped onto the one of Müsli like this:
+//!
+//! * 128-bit integers are passed along as such, so they are encoded in the
+//!   same way as a [`u128`] or [`i128`] would be natively.
+//! * Byte arrays, as produced by `serialize_bytes`, are encoded as bytes. Note
+//!   that serde serializes `Vec<u8>` and `[u8; N]` as sequences unless told
+//!   otherwise, such as through [`serde_bytes`].
+//! * Unit variants are encoded as a variant with an empty value, which is the
+//!   same as a unit variant in an enum deriving [`Encode`] which also has
+//!   variants that are not units.
+//! * Units and unit structs are encoded as empty values, and newtype structs
+//!   are encoded as the value they contain.
+//!
+//! Only embedding serde types in Müsli types is supported. Encoding Müsli
+//! types through a [`serde::Serializer`] is not.
+//!
+//! [`serde` data model]: https://serde.rs/data-model.html
+//! [`serde_bytes`]: https://docs.rs/serde_bytes
+//! [`Encode`]: crate::Encode
+//! [`serde::Serializer`]: https://docs.rs/serde/latest/serde/trait.Serializer.html
 //!
 //! <br>
 //!
@@ -103,6 +132,8 @@ use self::deserializer::Deserializer;
 use self::serializer::Serializer;
 
 use crate::buf::{self, BufString};
+use crate::context::Checkpoint;
+use crate::de::Coercion;
 use crate::no_std;
 use crate::{Context, Decoder, Encoder};
 
@@ -139,11 +170,36 @@ where
         self.inner.mark()
     }
 
+    #[inline]
+    fn checkpoint(&self) -> Checkpoint {
+        self.inner.checkpoint()
+    }
+
+    #[inline]
+    fn restore(&self, checkpoint: Checkpoint) {
+        self.inner.restore(checkpoint);
+    }
+
+    #[inline]
+    fn recover(&self) -> bool {
+        self.inner.recover()
+    }
+
+    #[inline]
+    fn recovered(&self, checkpoint: Checkpoint) {
+        self.inner.recovered(checkpoint);
+    }
+
     #[inline]
     fn alloc(&self) -> Option<Self::Buf<'_>> {
         self.inner.alloc()
     }
 
+    #[inline]
+    fn coercion(&self) -> Coercion {
+        self.inner.coercion()
+    }
+
     #[inline]
     fn collect_string<T>(&self, value: &T) -> Result<Self::BufString<'_>, Self::Error>
     where
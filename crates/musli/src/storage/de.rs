@@ -129,9 +129,9 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M>(V, M);
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -146,24 +146,28 @@ where
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8_owned(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_ref(cx, string)
             }
         }
 
-        self.decode_bytes(Visitor(visitor))
+        let mark = self.cx.mark();
+        self.decode_bytes(Visitor(visitor, mark))
     }
 
     #[inline]
@@ -277,11 +281,15 @@ where
         F: FnOnce(&mut Self::DecodeSequence) -> Result<O, C::Error>,
     {
         let cx = self.cx;
+        let mark = cx.mark();
         let mut decoder = LimitedStorageDecoder::new(self.cx, self.reader)?;
         let output = f(&mut decoder)?;
 
         if decoder.remaining != 0 {
-            return Err(cx.message("Caller did not decode all available map entries"));
+            return Err(cx.marked_message(
+                mark,
+                "Caller did not decode all available map entries",
+            ));
         }
 
         Ok(output)
@@ -301,11 +309,15 @@ where
         F: FnOnce(&mut Self::DecodeMap) -> Result<O, C::Error>,
     {
         let cx = self.cx;
+        let mark = cx.mark();
         let mut decoder = LimitedStorageDecoder::new(self.cx, self.reader)?;
         let output = f(&mut decoder)?;
 
         if decoder.remaining != 0 {
-            return Err(cx.message("Caller did not decode all available map entries"));
+            return Err(cx.marked_message(
+                mark,
+                "Caller did not decode all available map entries",
+            ));
         }
 
         Ok(output)
@@ -7,9 +7,12 @@ use core::marker;
 #[cfg(feature = "std")]
 use std::io;
 
+use crate::en::PackedSize;
 use crate::fixed::FixedBytes;
 use crate::mode::Binary;
 use crate::options;
+use crate::options::Integer;
+use crate::reader::SliceReader;
 use crate::{Context, Decode, Encode, Options, Reader, Writer};
 
 use super::de::StorageDecoder;
@@ -189,6 +192,100 @@ impl<const OPT: Options, M> Encoding<OPT, M> {
     );
 }
 
+impl<const OPT: Options> Encoding<OPT, Binary> {
+    /// Decode a value of type `T` with a fixed [`PackedSize`] from the start of
+    /// the given slice, and verify that exactly that many bytes were used.
+    ///
+    /// This requires the encoding to use [`Integer::Fixed`], since that is
+    /// what the size is computed for. Any bytes following the value are
+    /// ignored, which allows for seeking in a slice of records.
+    ///
+    /// This is only available in the [`Binary`] mode, which is the mode
+    /// [`PackedSize`] is derived for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Encode};
+    /// use musli::options::{self, Integer, Options};
+    /// use musli::storage::Encoding;
+    /// # use musli::storage::Error;
+    ///
+    /// const OPTIONS: Options = options::new().with_integer(Integer::Fixed).build();
+    /// const ENCODING: Encoding<OPTIONS> = Encoding::new().with_options();
+    ///
+    /// #[derive(Debug, PartialEq, Decode, Encode)]
+    /// #[musli(packed, packed_size)]
+    /// struct Point {
+    ///     x: u32,
+    ///     y: u32,
+    /// }
+    ///
+    /// let mut data = Vec::new();
+    ///
+    /// for n in 0..4 {
+    ///     ENCODING.to_writer(&mut data, &Point { x: n, y: n * 2 })?;
+    /// }
+    ///
+    /// let point: Point = ENCODING.from_packed_slice(&data[2 * 8..])?;
+    /// assert_eq!(point, Point { x: 2, y: 4 });
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub fn from_packed_slice<'de, T>(self, bytes: &'de [u8]) -> Result<T, Error>
+    where
+        T: Decode<'de, Binary> + PackedSize,
+    {
+        crate::default_allocator!(|alloc| {
+            let cx = crate::context::Same::new(alloc);
+            self.from_packed_slice_with(&cx, bytes)
+        })
+    }
+
+    /// Decode a value of type `T` with a fixed [`PackedSize`] from the start of
+    /// the given slice, and verify that exactly that many bytes were used.
+    ///
+    /// This is the same as [`Encoding::from_packed_slice`], but allows for
+    /// using a configurable [`Context`].
+    #[inline]
+    pub fn from_packed_slice_with<'de, C, T>(self, cx: &C, bytes: &'de [u8]) -> Result<T, C::Error>
+    where
+        C: ?Sized + Context<Mode = Binary>,
+        T: Decode<'de, Binary> + PackedSize,
+    {
+        cx.clear();
+
+        if !matches!(options::integer::<OPT>(), Integer::Fixed) {
+            return Err(cx.message("Packed values can only be decoded with fixed integers"));
+        }
+
+        let Some(size) = T::PACKED_SIZE else {
+            return Err(cx.message("Type does not have a fixed packed size"));
+        };
+
+        let Some(bytes) = bytes.get(..size) else {
+            return Err(cx.message(format_args!(
+                "Expected {size} bytes for packed value, but only {} are available",
+                bytes.len()
+            )));
+        };
+
+        let mut reader = SliceReader::new(bytes);
+        let value = self.decode_with(cx, &mut reader)?;
+
+        let remaining = reader.remaining();
+
+        if remaining != 0 {
+            return Err(cx.message(format_args!(
+                "Packed value used {} bytes, but expected {size}",
+                size - remaining
+            )));
+        }
+
+        Ok(value)
+    }
+}
+
 impl<const OPT: Options, M> Clone for Encoding<OPT, M> {
     #[inline]
     fn clone(&self) -> Self {
@@ -4,11 +4,12 @@ use core::slice;
 #[cfg(feature = "alloc")]
 use crate::de::UnsizedVisitor;
 use crate::de::{
-    AsDecoder, Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder,
+    AsDecoder, Coercion, Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder,
     SequenceDecoder, SizeHint, Skip, VariantDecoder, Visitor,
 };
 #[cfg(feature = "alloc")]
 use crate::hint::{MapHint, SequenceHint};
+use crate::int::{float_to_integer, Float};
 use crate::reader::SliceReader;
 use crate::storage::de::StorageDecoder;
 use crate::{Context, Options};
@@ -51,9 +52,9 @@ impl<'a, 'de, const OPT: Options, C: ?Sized> ValueDecoder<'a, 'de, OPT, C> {
 macro_rules! ensure_number {
     ($self:expr, $opt:expr, $hint:ident, $ident:ident $tt:tt, Value::$variant:ident($block:ident) => $ty:ty) => {
         match $self.value {
-            Value::$variant($block) => <$ty>::from_number($block).map_err($self.cx.map_message()),
+            Value::$variant($block) => <$ty>::from_number($block, $self.cx.coercion()).map_err($self.cx.map_message()),
             #[cfg(feature = "alloc")]
-            Value::String(string) if crate::options::is_map_keys_as_numbers::<$opt>() && $self.map_key => {
+            Value::String(string) if (crate::options::is_map_keys_as_numbers::<$opt>() && $self.map_key) || $self.cx.coercion() == Coercion::Lenient => {
                 match <$ty>::parse_number(string) {
                     Some(value) => Ok(value),
                     None => Err($self.cx.message(ErrorMessage::ExpectedStringAsNumber)),
@@ -142,6 +143,11 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
         Ok(Skip::Skipped)
     }
 
+    #[inline]
+    fn is_bufferable(&self) -> bool {
+        true
+    }
+
     #[inline]
     fn decode_buffer(self) -> Result<Self::DecodeBuffer, C::Error> {
         Ok(AsValueDecoder::new(self.cx, self.value.clone()))
@@ -224,12 +230,12 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
 
     #[inline]
     fn decode_f32(self) -> Result<f32, C::Error> {
-        ensure!(self, hint, ExpectedNumber(NumberHint::F32, hint), Value::Number(Number::F32(n)) => Ok(*n))
+        ensure_number!(self, OPT, hint, ExpectedNumber(NumberHint::F32, hint), Value::Number(n) => f32)
     }
 
     #[inline]
     fn decode_f64(self) -> Result<f64, C::Error> {
-        ensure!(self, hint, ExpectedNumber(NumberHint::F64, hint), Value::Number(Number::F64(n)) => Ok(*n))
+        ensure_number!(self, OPT, hint, ExpectedNumber(NumberHint::F64, hint), Value::Number(n) => f64)
     }
 
     #[cfg(feature = "alloc")]
@@ -616,7 +622,7 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> VariantDecoder<'de>
 trait FromNumber: Sized {
     const NUMBER_HINT: NumberHint;
 
-    fn from_number(number: &Number) -> Result<Self, ErrorMessage>;
+    fn from_number(number: &Number, coercion: Coercion) -> Result<Self, ErrorMessage>;
 
     #[cfg(feature = "alloc")]
     fn parse_number(string: &str) -> Option<Self>;
@@ -628,22 +634,24 @@ macro_rules! integer_from {
             const NUMBER_HINT: NumberHint = NumberHint::$variant;
 
             #[inline]
-            fn from_number(number: &Number) -> Result<Self, ErrorMessage> {
-                let out = match number {
-                    Number::U8(n) => Self::try_from(*n).ok(),
-                    Number::U16(n) => Self::try_from(*n).ok(),
-                    Number::U32(n) => Self::try_from(*n).ok(),
-                    Number::U64(n) => Self::try_from(*n).ok(),
-                    Number::U128(n) => Self::try_from(*n).ok(),
-                    Number::I8(n) => Self::try_from(*n).ok(),
-                    Number::I16(n) => Self::try_from(*n).ok(),
-                    Number::I32(n) => Self::try_from(*n).ok(),
-                    Number::I64(n) => Self::try_from(*n).ok(),
-                    Number::I128(n) => Self::try_from(*n).ok(),
-                    Number::Usize(n) => Self::try_from(*n).ok(),
-                    Number::Isize(n) => Self::try_from(*n).ok(),
-                    Number::F32(v) => Some(*v as $ty),
-                    Number::F64(v) => Some(*v as $ty),
+            fn from_number(number: &Number, coercion: Coercion) -> Result<Self, ErrorMessage> {
+                let out = match *number {
+                    _ if coercion == Coercion::Strict && !matches!(number, Number::$variant(..)) => None,
+                    Number::U8(n) => Self::try_from(n).ok(),
+                    Number::U16(n) => Self::try_from(n).ok(),
+                    Number::U32(n) => Self::try_from(n).ok(),
+                    Number::U64(n) => Self::try_from(n).ok(),
+                    Number::U128(n) => Self::try_from(n).ok(),
+                    Number::I8(n) => Self::try_from(n).ok(),
+                    Number::I16(n) => Self::try_from(n).ok(),
+                    Number::I32(n) => Self::try_from(n).ok(),
+                    Number::I64(n) => Self::try_from(n).ok(),
+                    Number::I128(n) => Self::try_from(n).ok(),
+                    Number::Usize(n) => Self::try_from(n).ok(),
+                    Number::Isize(n) => Self::try_from(n).ok(),
+                    Number::F32(v) if coercion == Coercion::Lenient => float_to_integer(f64::from(v)),
+                    Number::F64(v) if coercion == Coercion::Lenient => float_to_integer(v),
+                    Number::F32(..) | Number::F64(..) => None,
                 };
 
                 match out {
@@ -670,22 +678,23 @@ macro_rules! float_from {
             const NUMBER_HINT: NumberHint = NumberHint::$variant;
 
             #[inline]
-            fn from_number(number: &Number) -> Result<Self, ErrorMessage> {
-                let out = match number {
-                    Number::U8(n) => Some(*n as $ty),
-                    Number::U16(n) => Some(*n as $ty),
-                    Number::U32(n) => Some(*n as $ty),
-                    Number::U64(n) => Some(*n as $ty),
-                    Number::U128(n) => Some(*n as $ty),
-                    Number::I8(n) => Some(*n as $ty),
-                    Number::I16(n) => Some(*n as $ty),
-                    Number::I32(n) => Some(*n as $ty),
-                    Number::I64(n) => Some(*n as $ty),
-                    Number::I128(n) => Some(*n as $ty),
-                    Number::Usize(n) => Some(*n as $ty),
-                    Number::Isize(n) => Some(*n as $ty),
-                    Number::F32(v) => Some(*v as $ty),
-                    Number::F64(v) => Some(*v as $ty),
+            fn from_number(number: &Number, coercion: Coercion) -> Result<Self, ErrorMessage> {
+                let out = match *number {
+                    _ if coercion == Coercion::Strict && !matches!(number, Number::$variant(..)) => None,
+                    Number::U8(n) => Self::from_unsigned(u128::from(n)),
+                    Number::U16(n) => Self::from_unsigned(u128::from(n)),
+                    Number::U32(n) => Self::from_unsigned(u128::from(n)),
+                    Number::U64(n) => Self::from_unsigned(u128::from(n)),
+                    Number::U128(n) => Self::from_unsigned(n),
+                    Number::I8(n) => Self::from_signed(i128::from(n)),
+                    Number::I16(n) => Self::from_signed(i128::from(n)),
+                    Number::I32(n) => Self::from_signed(i128::from(n)),
+                    Number::I64(n) => Self::from_signed(i128::from(n)),
+                    Number::I128(n) => Self::from_signed(n),
+                    Number::Usize(n) => Self::from_unsigned(n as u128),
+                    Number::Isize(n) => Self::from_signed(n as i128),
+                    Number::F32(v) => Self::from_f64(f64::from(v)),
+                    Number::F64(v) => Self::from_f64(v),
                 };
 
                 match out {
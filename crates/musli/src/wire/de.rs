@@ -100,6 +100,7 @@ where
 
     #[inline]
     fn decode_sequence_len(&mut self) -> Result<usize, C::Error> {
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag.kind() {
@@ -108,10 +109,13 @@ where
             } else {
                 crate::int::decode_usize::<_, _, OPT>(self.cx, self.reader.borrow_mut())?
             }),
-            _ => Err(self.cx.message(Expected {
-                expected: Kind::Sequence,
-                actual: tag,
-            })),
+            _ => Err(self.cx.marked_message(
+                mark,
+                Expected {
+                    expected: Kind::Sequence,
+                    actual: tag,
+                },
+            )),
         }
     }
 
@@ -318,9 +322,9 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        struct Visitor<V>(V);
+        struct Visitor<V, M>(V, M);
 
-        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V>
+        impl<'de, C, V> UnsizedVisitor<'de, C, [u8]> for Visitor<V, C::Mark>
         where
             C: ?Sized + Context,
             V: UnsizedVisitor<'de, C, str>,
@@ -335,24 +339,28 @@ where
             #[cfg(feature = "alloc")]
             #[inline]
             fn visit_owned(self, cx: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8_owned(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8_owned(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_owned(cx, string)
             }
 
             #[inline]
             fn visit_borrowed(self, cx: &C, bytes: &'de [u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_borrowed(cx, string)
             }
 
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
-                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                let string = crate::str::from_utf8(bytes)
+                    .map_err(|error| cx.marked_custom(self.1, error))?;
                 self.0.visit_ref(cx, string)
             }
         }
 
-        self.decode_bytes(Visitor(visitor))
+        let mark = self.cx.mark();
+        self.decode_bytes(Visitor(visitor, mark))
     }
 
     #[inline]
@@ -360,23 +368,25 @@ where
         const FALSE: Tag = Tag::new(Kind::Continuation, 0);
         const TRUE: Tag = Tag::new(Kind::Continuation, 1);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag {
             FALSE => Ok(false),
             TRUE => Ok(true),
-            tag => Err(self.cx.message(BadBoolean { actual: tag })),
+            tag => Err(self.cx.marked_message(mark, BadBoolean { actual: tag })),
         }
     }
 
     #[inline]
     fn decode_char(self) -> Result<char, C::Error> {
         let cx = self.cx;
+        let mark = cx.mark();
         let num = self.decode_u32()?;
 
         match char::from_u32(num) {
             Some(d) => Ok(d),
-            None => Err(cx.message(BadCharacter(num))),
+            None => Err(cx.marked_message(mark, BadCharacter(num))),
         }
     }
 
@@ -462,12 +472,13 @@ where
         const NONE: Tag = Tag::new(Kind::Sequence, 0);
         const SOME: Tag = Tag::new(Kind::Sequence, 1);
 
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         match tag {
             NONE => Ok(None),
             SOME => Ok(Some(self)),
-            tag => Err(self.cx.message(ExpectedOption { tag })),
+            tag => Err(self.cx.marked_message(mark, ExpectedOption { tag })),
         }
     }
 
@@ -519,13 +530,17 @@ where
     where
         F: FnOnce(&mut Self::DecodeVariant) -> Result<O, C::Error>,
     {
+        let mark = self.cx.mark();
         let tag = Tag::from_byte(self.reader.read_byte(self.cx)?);
 
         if tag != Tag::new(Kind::Sequence, 2) {
-            return Err(self.cx.message(Expected {
-                expected: Kind::Sequence,
-                actual: tag,
-            }));
+            return Err(self.cx.marked_message(
+                mark,
+                Expected {
+                    expected: Kind::Sequence,
+                    actual: tag,
+                },
+            ));
         }
 
         f(&mut self)
@@ -56,12 +56,14 @@ where
     C: ?Sized + Context,
     R: Reader<'de>,
 {
+    let start = cx.mark();
+
     match crate::options::length::<OPT>() {
         crate::options::Integer::Variable => {
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag.kind() != Kind::Continuation {
-                return Err(cx.message("Expected continuation"));
+                return Err(cx.marked_message(start, "Expected continuation"));
             }
 
             if let Some(data) = tag.data() {
@@ -78,16 +80,16 @@ where
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag != Tag::new(Kind::Prefix, bytes) {
-                return Err(cx.message(format_args!(
-                    "Expected fixed {} bytes prefix tag, but got {tag:?}",
-                    bytes
-                )));
+                return Err(cx.marked_message(
+                    start,
+                    format_args!("Expected fixed {} bytes prefix tag, but got {tag:?}", bytes),
+                ));
             }
 
             macro_rules! fixed {
                 ($ty:ty) => {{
                     let Ok(value) = usize::try_from(<$ty>::read_bytes(cx, reader, bo)?) else {
-                        return Err(cx.message("Value type out of bounds for usize"));
+                        return Err(cx.marked_message(start, "Value type out of bounds for usize"));
                     };
 
                     Ok(value)
@@ -139,12 +141,14 @@ where
     R: Reader<'de>,
     T: UnsignedOps,
 {
+    let start = cx.mark();
+
     match crate::options::integer::<OPT>() {
         crate::options::Integer::Variable => {
             let tag = Tag::from_byte(reader.read_byte(cx)?);
 
             if tag.kind() != Kind::Continuation {
-                return Err(cx.message("Expected continuation"));
+                return Err(cx.marked_message(start, "Expected continuation"));
             }
 
             if let Some(data) = tag.data() {
@@ -157,7 +161,7 @@ where
             let bo = crate::options::byteorder::<OPT>();
 
             if Tag::from_byte(reader.read_byte(cx)?) != Tag::new(Kind::Prefix, T::BYTES) {
-                return Err(cx.message("Expected fixed integer"));
+                return Err(cx.marked_message(start, "Expected fixed integer"));
             }
 
             T::read_bytes(cx, reader, bo)
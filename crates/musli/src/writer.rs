@@ -5,6 +5,7 @@
 //! [`wrap`]: crate::wrap::wrap
 
 use core::fmt;
+use core::hash::Hasher;
 use core::mem::take;
 
 use crate::{Buf, Context};
@@ -129,6 +130,36 @@ impl Writer for Vec<u8> {
     }
 }
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+impl Writer for bytes::BytesMut {
+    type Mut<'this> = &'this mut Self where Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn write_buffer<C, B>(&mut self, cx: &C, buffer: B) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        B: Buf,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.extend_from_slice(bytes);
+        cx.advance(bytes.len());
+        Ok(())
+    }
+}
+
 impl Writer for &mut [u8] {
     type Mut<'this> = &'this mut Self where Self: 'this;
 
@@ -240,6 +271,276 @@ where
     }
 }
 
+/// A writer which feeds everything written to it into a [`Hasher`].
+///
+/// This allows for computing the hash of the encoded form of a value without
+/// allocating. The encoded bytes are fed to [`Hasher::write`] in pieces, so
+/// for hashers which treat consecutive writes as a single stream of bytes like
+/// [`DefaultHasher`] the result is the same as hashing the output of `to_vec`.
+///
+/// [`DefaultHasher`]: std::collections::hash_map::DefaultHasher
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::Hasher;
+///
+/// use musli::storage::Encoding;
+/// use musli::writer::HashWriter;
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// let mut hasher = DefaultHasher::new();
+/// ENCODING.encode(HashWriter::new(&mut hasher), &(42u32, "Hello World"))?;
+///
+/// let mut expected = DefaultHasher::new();
+/// expected.write(&ENCODING.to_vec(&(42u32, "Hello World"))?);
+///
+/// assert_eq!(hasher.finish(), expected.finish());
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+pub struct HashWriter<H> {
+    hasher: H,
+}
+
+impl<H> HashWriter<H> {
+    /// Construct a new hashing writer.
+    pub fn new(hasher: H) -> Self {
+        Self { hasher }
+    }
+
+    /// Coerce into inner hasher.
+    pub fn into_inner(self) -> H {
+        self.hasher
+    }
+}
+
+impl<H> Writer for HashWriter<H>
+where
+    H: Hasher,
+{
+    type Mut<'this> = &'this mut Self
+    where
+        Self: 'this;
+
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline(always)]
+    fn write_buffer<C, B>(&mut self, cx: &C, buffer: B) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        B: Buf,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline(always)]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.hasher.write(bytes);
+        cx.advance(bytes.len());
+        Ok(())
+    }
+}
+
+/// A writer which discards everything written to it and only counts the
+/// number of bytes.
+///
+/// This can be used to calculate the length of an encoded value without
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// use musli::storage::Encoding;
+/// use musli::writer::CountWriter;
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// let mut writer = CountWriter::new();
+/// ENCODING.encode(&mut writer, &(42u32, "Hello World"))?;
+///
+/// assert_eq!(writer.len(), ENCODING.to_vec(&(42u32, "Hello World"))?.len());
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+#[derive(Default)]
+pub struct CountWriter {
+    len: usize,
+}
+
+impl CountWriter {
+    /// Construct a new counting writer.
+    pub const fn new() -> Self {
+        Self { len: 0 }
+    }
+
+    /// The number of bytes written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if no bytes have been written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Writer for CountWriter {
+    type Mut<'this> = &'this mut Self
+    where
+        Self: 'this;
+
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline(always)]
+    fn write_buffer<C, B>(&mut self, cx: &C, buffer: B) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        B: Buf,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline(always)]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.len += bytes.len();
+        cx.advance(bytes.len());
+        Ok(())
+    }
+}
+
+/// A writer which compares everything written to it against a reference slice
+/// of bytes.
+///
+/// Writing fails as soon as a byte is written which differs from the
+/// reference, or which goes beyond the end of it. The offset at which this
+/// happened is available through [`CompareWriter::mismatch`].
+///
+/// # Examples
+///
+/// ```
+/// use musli::storage::Encoding;
+/// use musli::writer::CompareWriter;
+///
+/// const ENCODING: Encoding = Encoding::new();
+///
+/// let reference = ENCODING.to_vec(&(1u32, 2u32, 3u32))?;
+///
+/// let mut writer = CompareWriter::new(&reference);
+/// assert!(ENCODING.encode(&mut writer, &(1u32, 5u32, 3u32)).is_err());
+/// assert_eq!(writer.mismatch(), Some(2));
+///
+/// let mut writer = CompareWriter::new(&reference);
+/// ENCODING.encode(&mut writer, &(1u32, 2u32, 3u32))?;
+/// assert!(writer.is_complete());
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+pub struct CompareWriter<'a> {
+    reference: &'a [u8],
+    position: usize,
+    mismatch: Option<usize>,
+}
+
+impl<'a> CompareWriter<'a> {
+    /// Construct a new writer comparing against the given reference.
+    pub fn new(reference: &'a [u8]) -> Self {
+        Self {
+            reference,
+            position: 0,
+            mismatch: None,
+        }
+    }
+
+    /// The number of bytes written which matched the reference.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The offset of the first byte which didn't match the reference, if any.
+    pub fn mismatch(&self) -> Option<usize> {
+        self.mismatch
+    }
+
+    /// Test if everything written matched the reference in its entirety.
+    pub fn is_complete(&self) -> bool {
+        self.mismatch.is_none() && self.position == self.reference.len()
+    }
+}
+
+impl Writer for CompareWriter<'_> {
+    type Mut<'this> = &'this mut Self
+    where
+        Self: 'this;
+
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline(always)]
+    fn write_buffer<C, B>(&mut self, cx: &C, buffer: B) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+        B: Buf,
+    {
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if let Some(offset) = self.mismatch {
+            return Err(cx.message(CompareMismatch { offset }));
+        }
+
+        let remaining = &self.reference[self.position..];
+
+        let matching = remaining
+            .iter()
+            .zip(bytes)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        if matching < bytes.len() {
+            let offset = self.position + matching;
+            self.position = offset;
+            self.mismatch = Some(offset);
+            return Err(cx.message(CompareMismatch { offset }));
+        }
+
+        self.position += bytes.len();
+        cx.advance(bytes.len());
+        Ok(())
+    }
+}
+
+/// Mismatch when comparing written bytes against a reference.
+#[derive(Debug)]
+struct CompareMismatch {
+    offset: usize,
+}
+
+impl fmt::Display for CompareMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let CompareMismatch { offset } = self;
+        write!(f, "Written bytes differ from reference at byte {offset}")
+    }
+}
+
 /// Overflow when trying to write to a slice.
 #[derive(Debug)]
 struct SliceOverflow {
@@ -0,0 +1,157 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub struct UserV1 {
+    #[musli(name = "userName")]
+    user_name: String,
+    #[musli(name = "years")]
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct UserV2 {
+    #[musli(alias = "userName")]
+    user_name: String,
+    #[musli(alias = "years", alias = "yearsOld")]
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub struct UserV0 {
+    #[musli(name = "userName")]
+    user_name: String,
+    #[musli(name = "yearsOld")]
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "index")]
+pub struct IndexedV1 {
+    #[musli(name = 10)]
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "index")]
+pub struct IndexedV2 {
+    #[musli(alias = 10)]
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub enum ShapeV1 {
+    Circle { radius: u32 },
+    Square { side: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum ShapeV2 {
+    #[musli(alias = "Circle")]
+    Round {
+        radius: u32,
+    },
+    Square {
+        side: u32,
+    },
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub enum ColorV1 {
+    Red,
+    Green,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum ColorV2 {
+    #[musli(alias = "Red")]
+    Crimson,
+    Green,
+}
+
+#[test]
+fn field_alias() {
+    musli::rt!(
+        descriptive,
+        UserV2 {
+            user_name: String::from("Aristotle"),
+            age: 61,
+        },
+        json = r#"{"user_name":"Aristotle","age":61}"#
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        UserV1 {
+            user_name: String::from("Aristotle"),
+            age: 61,
+        },
+        UserV2 {
+            user_name: String::from("Aristotle"),
+            age: 61,
+        },
+        json = r#"{"userName":"Aristotle","years":61}"#,
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        UserV0 {
+            user_name: String::from("Aristotle"),
+            age: 61,
+        },
+        UserV2 {
+            user_name: String::from("Aristotle"),
+            age: 61,
+        },
+        json = r#"{"userName":"Aristotle","yearsOld":61}"#,
+    );
+}
+
+#[test]
+fn field_index_alias() {
+    musli::rt!(descriptive, IndexedV2 { value: 42 });
+
+    musli::assert_decode_eq!(
+        descriptive,
+        IndexedV1 { value: 42 },
+        IndexedV2 { value: 42 }
+    );
+}
+
+#[test]
+fn variant_alias() {
+    musli::rt!(
+        descriptive,
+        ShapeV2::Round { radius: 3 },
+        json = r#"{"Round":{"radius":3}}"#
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        ShapeV1::Circle { radius: 3 },
+        ShapeV2::Round { radius: 3 },
+        json = r#"{"Circle":{"radius":3}}"#,
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        ShapeV1::Square { side: 2 },
+        ShapeV2::Square { side: 2 },
+        json = r#"{"Square":{"side":2}}"#,
+    );
+}
+
+#[test]
+fn unit_variant_alias() {
+    musli::rt!(descriptive, ColorV2::Crimson);
+    musli::assert_decode_eq!(descriptive, ColorV1::Red, ColorV2::Crimson);
+    musli::assert_decode_eq!(descriptive, ColorV1::Green, ColorV2::Green);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "test")]
+
+use std::cell::Cell;
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+thread_local! {
+    static DROPS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Take the number of values dropped so far on the current thread.
+fn take_drops() -> usize {
+    DROPS.with(|drops| drops.replace(0))
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+pub struct Tracked {
+    value: String,
+}
+
+impl Tracked {
+    fn new(value: &str) -> Self {
+        Self {
+            value: String::from(value),
+        }
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Arrays {
+    strings: [String; 3],
+    empty: [String; 0],
+    nested: [[Vec<u32>; 2]; 2],
+}
+
+#[test]
+fn non_copy_arrays() {
+    musli::rt!(
+        full,
+        Arrays {
+            strings: [
+                String::from("foo"),
+                String::from("bar"),
+                String::from("baz")
+            ],
+            empty: [],
+            nested: [[vec![1], vec![]], [vec![2, 3], vec![4]]],
+        },
+        json = r#"{"strings":["foo","bar","baz"],"empty":[],"nested":[[[1],[]],[[2,3],[4]]]}"#,
+    );
+}
+
+#[test]
+fn drop_partially_decoded() {
+    let alloc = System::new();
+    let encoding = musli::json::Encoding::new();
+
+    // The last element fails to decode.
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, [Tracked; 3]>(&cx, br#"["a","b",1]"#)
+        .is_err());
+    assert_eq!(take_drops(), 2);
+
+    // Too few elements.
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, [Tracked; 3]>(&cx, br#"["a"]"#)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("Array with length 1 "), "{error}");
+    assert_eq!(take_drops(), 1);
+
+    // Too many elements, which are counted without being decoded.
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, [Tracked; 3]>(&cx, br#"["a","b","c","d"]"#)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("Array with length 4 "), "{error}");
+    assert_eq!(take_drops(), 3);
+
+    let array = encoding.from_slice::<[Tracked; 3]>(br#"["a","b","c"]"#);
+    assert_eq!(
+        array.ok(),
+        Some([Tracked::new("a"), Tracked::new("b"), Tracked::new("c")])
+    );
+    assert_eq!(take_drops(), 6);
+}
+
+#[test]
+fn length_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+    let alloc = System::new();
+    let encoding = musli::descriptive::Encoding::new();
+
+    let bytes = musli::descriptive::to_vec(&vec![
+        String::from("a"),
+        String::from("b"),
+        String::from("c"),
+        String::from("d"),
+    ])?;
+
+    // The length is known up front, so nothing is decoded.
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, [Tracked; 3]>(&cx, &bytes)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("Array with length 4 "), "{error}");
+    assert_eq!(take_drops(), 0);
+
+    assert_eq!(
+        musli::descriptive::from_slice::<[String; 4]>(&bytes)?,
+        [
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+            String::from("d")
+        ]
+    );
+
+    Ok(())
+}
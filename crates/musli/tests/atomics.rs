@@ -0,0 +1,153 @@
+#![cfg(feature = "test")]
+
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, Encode, Decode)]
+pub struct Metrics {
+    bool: AtomicBool,
+    u8: AtomicU8,
+    u16: AtomicU16,
+    u32: AtomicU32,
+    u64: AtomicU64,
+    usize: AtomicUsize,
+    i8: AtomicI8,
+    i16: AtomicI16,
+    i32: AtomicI32,
+    i64: AtomicI64,
+    isize: AtomicIsize,
+}
+
+#[derive(Debug, Encode, Decode)]
+#[musli(packed)]
+pub struct PackedMetrics {
+    u64: AtomicU64,
+    i32: AtomicI32,
+    bool: AtomicBool,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct PlainMetrics {
+    bool: bool,
+    u8: u8,
+    u16: u16,
+    u32: u32,
+    u64: u64,
+    usize: usize,
+    i8: i8,
+    i16: i16,
+    i32: i32,
+    i64: i64,
+    isize: isize,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+pub struct PackedPlainMetrics {
+    u64: u64,
+    i32: i32,
+    bool: bool,
+}
+
+fn metrics() -> Metrics {
+    Metrics {
+        bool: AtomicBool::new(true),
+        u8: AtomicU8::new(u8::MAX),
+        u16: AtomicU16::new(u16::MAX),
+        u32: AtomicU32::new(u32::MAX),
+        u64: AtomicU64::new(u64::MAX),
+        usize: AtomicUsize::new(usize::MAX),
+        i8: AtomicI8::new(i8::MIN),
+        i16: AtomicI16::new(i16::MIN),
+        i32: AtomicI32::new(i32::MIN),
+        i64: AtomicI64::new(i64::MIN),
+        isize: AtomicIsize::new(isize::MIN),
+    }
+}
+
+fn plain_metrics() -> PlainMetrics {
+    PlainMetrics {
+        bool: true,
+        u8: u8::MAX,
+        u16: u16::MAX,
+        u32: u32::MAX,
+        u64: u64::MAX,
+        usize: usize::MAX,
+        i8: i8::MIN,
+        i16: i16::MIN,
+        i32: i32::MIN,
+        i64: i64::MIN,
+        isize: isize::MIN,
+    }
+}
+
+fn load(metrics: &Metrics) -> PlainMetrics {
+    PlainMetrics {
+        bool: metrics.bool.load(Ordering::Relaxed),
+        u8: metrics.u8.load(Ordering::Relaxed),
+        u16: metrics.u16.load(Ordering::Relaxed),
+        u32: metrics.u32.load(Ordering::Relaxed),
+        u64: metrics.u64.load(Ordering::Relaxed),
+        usize: metrics.usize.load(Ordering::Relaxed),
+        i8: metrics.i8.load(Ordering::Relaxed),
+        i16: metrics.i16.load(Ordering::Relaxed),
+        i32: metrics.i32.load(Ordering::Relaxed),
+        i64: metrics.i64.load(Ordering::Relaxed),
+        isize: metrics.isize.load(Ordering::Relaxed),
+    }
+}
+
+#[test]
+fn atomics() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(musli::json::to_string(&AtomicU64::new(42))?, "42");
+    assert_eq!(musli::json::to_string(&AtomicBool::new(true))?, "true");
+
+    let value = musli::json::from_str::<AtomicU64>("42")?;
+    assert_eq!(value.load(Ordering::Relaxed), 42);
+
+    // Atomics are encoded exactly like the values they hold.
+    macro_rules! test_case {
+        ($($format:ident),*) => {
+            $(
+                let bytes = musli::$format::to_vec(&metrics())?;
+                assert_eq!(bytes, musli::$format::to_vec(&plain_metrics())?);
+
+                let decoded = musli::$format::from_slice::<Metrics>(&bytes)?;
+                assert_eq!(load(&decoded), plain_metrics());
+            )*
+        };
+    }
+
+    test_case!(storage, wire, descriptive, json);
+    Ok(())
+}
+
+#[test]
+fn packed() -> Result<(), Box<dyn std::error::Error>> {
+    let packed = PackedMetrics {
+        u64: AtomicU64::new(7),
+        i32: AtomicI32::new(-1),
+        bool: AtomicBool::new(true),
+    };
+
+    let plain = PackedPlainMetrics {
+        u64: 7,
+        i32: -1,
+        bool: true,
+    };
+
+    assert_eq!(musli::json::to_string(&packed)?, r#"[7,-1,true]"#);
+
+    let bytes = musli::storage::to_vec(&packed)?;
+    assert_eq!(bytes, musli::storage::to_vec(&plain)?);
+
+    let decoded = musli::storage::from_slice::<PackedMetrics>(&bytes)?;
+    assert_eq!(decoded.u64.into_inner(), 7);
+    assert_eq!(decoded.i32.into_inner(), -1);
+    assert!(decoded.bool.into_inner());
+    Ok(())
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "test")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::Text;
+
+/// Test if the given bytes are borrowed from within the given input.
+fn borrowed_from(value: &[u8], input: &[u8]) -> bool {
+    let input = input.as_ptr_range();
+    let value = value.as_ptr_range();
+    input.start <= value.start && value.end <= input.end
+}
+
+#[test]
+fn json_str_keys() {
+    let input = br#"{"first":1,"second":2}"#;
+    let map = musli::json::from_slice::<HashMap<&str, u32>>(input).unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("first"), Some(&1));
+    assert_eq!(map.get("second"), Some(&2));
+
+    for key in map.keys() {
+        assert!(borrowed_from(key.as_bytes(), input), "{key}");
+    }
+}
+
+#[test]
+fn json_bytes_keys() {
+    let input = br#"{"first":1,"second":2}"#;
+    let map = musli::json::from_slice::<BTreeMap<&[u8], u32>>(input).unwrap();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&b"first"[..]), Some(&1));
+    assert_eq!(map.get(&b"second"[..]), Some(&2));
+
+    for key in map.keys() {
+        assert!(borrowed_from(key, input));
+    }
+}
+
+#[test]
+fn json_escaped_keys() {
+    let alloc = System::new();
+    let encoding = musli::json::Encoding::new();
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let input = br#"{"first":1,"sec\nond":2}"#;
+    assert!(encoding
+        .from_slice_with::<_, HashMap<&str, u32>>(&cx, input)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("string borrowed from the source"), "{error}");
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, BTreeMap<&[u8], u32>>(&cx, input)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("bytes borrowed from the source"), "{error}");
+
+    // Owned keys can still be decoded.
+    let map = musli::json::from_slice::<HashMap<String, u32>>(input).unwrap();
+    assert_eq!(map.get("sec\nond"), Some(&2));
+}
+
+#[test]
+fn storage_keys() {
+    let mut map = BTreeMap::new();
+    map.insert("first", 1u32);
+    map.insert("second", 2u32);
+
+    let input = musli::storage::to_vec(&map).unwrap();
+
+    let decoded = musli::storage::from_slice::<HashMap<&str, u32>>(&input).unwrap();
+    assert_eq!(decoded.len(), 2);
+    assert_eq!(decoded.get("first"), Some(&1));
+
+    for key in decoded.keys() {
+        assert!(borrowed_from(key.as_bytes(), &input), "{key}");
+    }
+
+    // Strings are stored as length-prefixed bytes, so they can be borrowed as
+    // bytes as well.
+    let decoded = musli::storage::from_slice::<BTreeMap<&[u8], u32>>(&input).unwrap();
+    assert_eq!(decoded.get(&b"second"[..]), Some(&2));
+
+    for key in decoded.keys() {
+        assert!(borrowed_from(key, &input));
+    }
+}
+
+#[test]
+fn value_keys() {
+    let mut map = BTreeMap::new();
+    map.insert(String::from("first"), 1u32);
+
+    let input = musli::value::encode(&map).unwrap();
+    let decoded = musli::value::decode::<HashMap<&str, u32>>(&input).unwrap();
+    assert_eq!(decoded.get("first"), Some(&1));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "test")]
+
+use bytes::{Bytes, BytesMut};
+use musli::allocator::System;
+use musli::context::{Limits, SystemContext};
+use musli::mode::Binary;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Frame {
+    header: u32,
+    payload: Bytes,
+    #[musli(bytes)]
+    scratch: BytesMut,
+}
+
+#[test]
+fn bytes() {
+    musli::rt!(full, Bytes::new(), json = r#"[]"#);
+    musli::rt!(full, Bytes::from_static(b"abc"), json = r#"[97,98,99]"#);
+    musli::rt!(full, BytesMut::from(&b"abc"[..]), json = r#"[97,98,99]"#);
+
+    musli::rt!(
+        full,
+        Frame {
+            header: 42,
+            payload: Bytes::from_static(&[1, 2, 3, 4]),
+            scratch: BytesMut::from(&[5, 6][..]),
+        },
+        json = r#"{"header":42,"payload":[1,2,3,4],"scratch":[5,6]}"#
+    );
+}
+
+#[test]
+fn encoded_like_vec() -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = Bytes::from_static(b"Hello World");
+
+    let expected = musli::storage::to_vec(&musli::compat::Bytes(b"Hello World".to_vec()))?;
+    assert_eq!(musli::storage::to_vec(&bytes)?, expected);
+
+    let expected = musli::descriptive::to_vec(&musli::compat::Bytes(b"Hello World".to_vec()))?;
+    assert_eq!(musli::descriptive::to_vec(&bytes)?, expected);
+    Ok(())
+}
+
+#[test]
+fn bytes_reader() -> Result<(), Box<dyn std::error::Error>> {
+    let frame = Frame {
+        header: 7,
+        payload: Bytes::from_static(b"payload"),
+        scratch: BytesMut::new(),
+    };
+
+    let mut buf = BytesMut::new();
+    musli::storage::encode(&mut buf, &frame)?;
+    musli::storage::encode(&mut buf, &frame)?;
+
+    let mut bytes = buf.freeze();
+    assert_eq!(musli::storage::decode::<_, Frame>(&mut bytes)?, frame);
+    assert_eq!(musli::storage::decode::<_, Frame>(&mut bytes)?, frame);
+    assert!(bytes.is_empty());
+
+    assert!(musli::storage::decode::<_, Frame>(&mut bytes).is_err());
+    Ok(())
+}
+
+#[test]
+fn limits() {
+    let bytes = musli::storage::to_vec(&Bytes::from_static(b"abcde")).unwrap();
+
+    let alloc = System::new();
+    let mut cx = SystemContext::<_, Binary>::new(&alloc);
+    cx.with_limits(Limits::new().max_bytes_len(4));
+
+    let encoding = musli::storage::Encoding::new();
+    assert!(encoding.from_slice_with::<_, Bytes>(&cx, &bytes).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Length of 5 bytes exceeds the maximum of 4 (at byte 6)\n"
+    );
+}
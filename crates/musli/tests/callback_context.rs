@@ -0,0 +1,99 @@
+#![cfg(feature = "test")]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use musli::allocator::System;
+use musli::context::{CallbackContext, PathFormat};
+use musli::Decode;
+
+#[derive(Debug, Decode)]
+struct Inventory {
+    items: Vec<Item>,
+    #[musli(trace)]
+    prices: HashMap<String, u32>,
+}
+
+#[derive(Debug, Decode)]
+enum Item {
+    Tool { weight: u32 },
+}
+
+/// An event as it would be emitted to a logging facility.
+#[derive(Debug, PartialEq)]
+struct Event {
+    path: String,
+    range: (usize, usize),
+    message: String,
+}
+
+fn decode(path_format: PathFormat, input: &str) -> Vec<Event> {
+    let events = RefCell::new(Vec::new());
+
+    let alloc = System::new();
+    let mut cx = CallbackContext::new(&alloc, |error| {
+        events.borrow_mut().push(Event {
+            path: error.path_display().to_string(),
+            range: (error.range().start, error.range().end),
+            message: error.error().clone(),
+        });
+    });
+
+    cx.with_path_format(path_format);
+
+    let encoding = musli::json::Encoding::new();
+    let result = encoding.from_slice_with::<_, Inventory>(&cx, input.as_bytes());
+    assert!(result.is_err(), "Expected decoding to fail");
+    drop(cx);
+    events.into_inner()
+}
+
+#[test]
+fn reports_path_and_range() {
+    let events = decode(
+        PathFormat::new(),
+        r#"{"items":[{"Tool":{"weight":1}},{"Tool":{"weight":"x"}}],"prices":{}}"#,
+    );
+
+    assert_eq!(
+        events,
+        [Event {
+            path: String::from(".items[1] = Tool { .weight }"),
+            range: (50, 51),
+            message: String::from("Invalid numeric"),
+        }]
+    );
+}
+
+#[test]
+fn reports_map_keys() {
+    let events = decode(PathFormat::new(), r#"{"items":[],"prices":{"apple":true}}"#);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].path, ".prices[apple]");
+
+    let events = decode(
+        PathFormat::new().without_keys(),
+        r#"{"items":[],"prices":{"apple":true}}"#,
+    );
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].path, ".prices[*]");
+}
+
+#[test]
+fn decodes_valid_input() {
+    let inventory = musli::json::from_slice::<Inventory>(
+        br#"{"items":[{"Tool":{"weight":1}},{"Tool":{"weight":2}}],"prices":{"apple":3}}"#,
+    )
+    .unwrap();
+
+    let weights = inventory
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::Tool { weight } => *weight,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(weights, [1, 2]);
+    assert_eq!(inventory.prices.get("apple"), Some(&3));
+}
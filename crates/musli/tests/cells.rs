@@ -0,0 +1,108 @@
+#![cfg(feature = "test")]
+
+use std::cell::{Cell, RefCell};
+use std::sync::{Mutex, RwLock};
+use std::thread;
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::Text;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Cells {
+    cell: Cell<u32>,
+    ref_cell: RefCell<String>,
+}
+
+#[derive(Debug, Encode, Decode)]
+pub struct Locks {
+    mutex: Mutex<u32>,
+    rw_lock: RwLock<String>,
+}
+
+#[test]
+fn cells() {
+    musli::rt!(full, Cell::new(42u32), json = r#"42"#);
+    musli::rt!(
+        full,
+        RefCell::new(String::from("hello")),
+        json = r#""hello""#
+    );
+
+    musli::rt!(
+        full,
+        Cells {
+            cell: Cell::new(42),
+            ref_cell: RefCell::new(String::from("hello")),
+        },
+        json = r#"{"cell":42,"ref_cell":"hello"}"#
+    );
+
+    musli::assert_decode_eq!(full, 42u32, Cell::new(42u32), json = r#"42"#);
+    musli::assert_decode_eq!(full, RefCell::new(42u32), 42u32, json = r#"42"#);
+}
+
+#[test]
+fn mutably_borrowed_ref_cell() {
+    let alloc = System::new();
+    let encoding = musli::json::Encoding::new();
+
+    let value = RefCell::new(String::from("hello"));
+    let _guard = value.borrow_mut();
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding.to_string_with(&cx, &value).is_err());
+    assert!(cx.errors().next().is_some());
+}
+
+#[test]
+fn locks() {
+    let locks = Locks {
+        mutex: Mutex::new(42),
+        rw_lock: RwLock::new(String::from("hello")),
+    };
+
+    let json = musli::json::to_string(&locks).unwrap();
+    assert_eq!(json, r#"{"mutex":42,"rw_lock":"hello"}"#);
+
+    let locks = musli::json::from_str::<Locks>(&json).unwrap();
+    assert_eq!(locks.mutex.into_inner().unwrap(), 42);
+    assert_eq!(locks.rw_lock.into_inner().unwrap(), "hello");
+
+    let bytes = musli::storage::to_vec(&Mutex::new(42u32)).unwrap();
+    assert_eq!(bytes, musli::storage::to_vec(&42u32).unwrap());
+    let mutex = musli::storage::from_slice::<Mutex<u32>>(&bytes).unwrap();
+    assert_eq!(mutex.into_inner().unwrap(), 42);
+}
+
+#[test]
+fn poisoned_locks() {
+    let alloc = System::new();
+    let encoding = musli::json::Encoding::new();
+
+    let mutex = Mutex::new(42u32);
+    let rw_lock = RwLock::new(42u32);
+
+    thread::scope(|s| {
+        let result = s
+            .spawn(|| {
+                let _mutex = mutex.lock();
+                let _rw_lock = rw_lock.write();
+                panic!("poison the locks");
+            })
+            .join();
+
+        assert!(result.is_err());
+    });
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding.to_string_with(&cx, &mutex).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("Mutex is poisoned"), "{error}");
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    assert!(encoding.to_string_with(&cx, &rw_lock).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("RwLock is poisoned"), "{error}");
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "test")]
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use musli::allocator::{Stack, StackBuffer};
+use musli::context::StackContext;
+use musli::{Encode, Encoder};
+
+/// A value which is encoded using its `Display` implementation.
+#[derive(Debug, PartialEq)]
+pub struct Decimal {
+    units: i64,
+    scale: u32,
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.units < 0 { "-" } else { "" };
+        let units = self.units.unsigned_abs();
+        let div = 10u64.pow(self.scale);
+        let scale = self.scale as usize;
+        write!(f, "{sign}{}.{:0scale$}", units / div, units % div)
+    }
+}
+
+impl<M> Encode<M> for Decimal {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.collect_string(self)
+    }
+}
+
+/// A value which needs escaping, and which is written in multiple parts.
+#[derive(Debug, PartialEq)]
+pub struct Quoted(usize);
+
+impl fmt::Display for Quoted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for n in 0..self.0 {
+            writeln!(f, "\"{n}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M> Encode<M> for Quoted {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.collect_string(self)
+    }
+}
+
+/// A key which is encoded through `Encoder::encode_display`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Label(usize);
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for n in 0..self.0 {
+            write!(f, "\"{n}\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M> Encode<M> for Label {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_display(self)
+    }
+}
+
+#[test]
+fn display() {
+    musli::assert_decode_eq!(
+        full,
+        Decimal {
+            units: -12345,
+            scale: 2
+        },
+        String::from("-123.45"),
+        json = r#""-123.45""#
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        Quoted(2),
+        String::from("\"0\"\n\"1\"\n"),
+        json = r#""\"0\"\n\"1\"\n""#
+    );
+}
+
+#[test]
+fn stack_capacity() {
+    let value = Quoted(1000);
+    let expected = value.to_string();
+
+    let mut buf = StackBuffer::<256>::new();
+    let alloc = Stack::new(&mut buf);
+
+    // JSON escapes the value as it's being written, so it doesn't need to
+    // buffer it.
+    let cx = StackContext::new(&alloc);
+    let json = musli::json::Encoding::new().to_vec_with(&cx, &value);
+    let json = json.expect("encoding to json should succeed");
+    let decoded = musli::json::from_slice::<String>(&json).unwrap();
+    assert_eq!(decoded, expected);
+
+    // Other formats have to buffer the string, which fails gracefully since it
+    // doesn't fit in the stack buffer.
+    let cx = StackContext::new(&alloc);
+    let result = musli::storage::Encoding::new().to_vec_with(&cx, &value);
+    assert!(result.is_err());
+
+    // But smaller values still fit.
+    let cx = StackContext::new(&alloc);
+    let value = Decimal {
+        units: 42,
+        scale: 1,
+    };
+    let bytes = musli::storage::Encoding::new()
+        .to_vec_with(&cx, &value)
+        .expect("encoding to storage should succeed");
+    let decoded = musli::storage::from_slice::<String>(&bytes).unwrap();
+    assert_eq!(decoded, "4.2");
+}
+
+#[test]
+fn encode_display() {
+    let mut map = BTreeMap::new();
+    map.insert(Label(1000), Label(1000));
+    let expected = Label(1000).to_string();
+
+    let mut buf = StackBuffer::<256>::new();
+    let alloc = Stack::new(&mut buf);
+
+    // JSON streams both object keys and values, so neither is buffered.
+    let cx = StackContext::new(&alloc);
+    let json = musli::json::Encoding::new().to_vec_with(&cx, &map);
+    let json = json.expect("encoding to json should succeed");
+    let decoded = musli::json::from_slice::<BTreeMap<String, String>>(&json).unwrap();
+    assert_eq!(decoded.get(&expected), Some(&expected));
+
+    // The value encoder collects the string through the context.
+    let value = musli::value::encode(Label(1000)).unwrap();
+    let decoded = musli::value::decode::<String>(&value).unwrap();
+    assert_eq!(decoded, expected);
+
+    // Formats which don't stream collected strings need to buffer them.
+    let cx = StackContext::new(&alloc);
+    let result = musli::storage::Encoding::new().to_vec_with(&cx, &Label(1000));
+    assert!(result.is_err());
+}
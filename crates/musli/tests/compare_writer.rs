@@ -0,0 +1,98 @@
+#![cfg(feature = "test")]
+
+use std::cell::Cell;
+
+use musli::mode::Binary;
+use musli::writer::CompareWriter;
+use musli::{Encode, Encoder};
+
+#[derive(Encode)]
+struct Document {
+    version: u32,
+    title: String,
+    body: Vec<u8>,
+}
+
+fn document(version: u32) -> Document {
+    Document {
+        version,
+        title: String::from("Hello World"),
+        body: vec![7; 1024],
+    }
+}
+
+/// A value which counts how many times it has been encoded.
+struct Counted<'a> {
+    count: &'a Cell<usize>,
+}
+
+impl Encode<Binary> for Counted<'_> {
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        self.count.set(self.count.get() + 1);
+        encoder.encode_u32(42)
+    }
+}
+
+macro_rules! test_encoded_eq {
+    ($name:ident, $what:ident) => {
+        #[test]
+        fn $name() {
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            assert!(ENCODING.encoded_eq(&document(1), &document(1)).unwrap());
+            assert!(!ENCODING.encoded_eq(&document(1), &document(2)).unwrap());
+
+            let mut shorter = document(1);
+            shorter.body.pop();
+            assert!(!ENCODING.encoded_eq(&document(1), &shorter).unwrap());
+            assert!(!ENCODING.encoded_eq(&shorter, &document(1)).unwrap());
+        }
+    };
+}
+
+test_encoded_eq!(storage_encoded_eq, storage);
+test_encoded_eq!(wire_encoded_eq, wire);
+test_encoded_eq!(descriptive_encoded_eq, descriptive);
+test_encoded_eq!(json_encoded_eq, json);
+
+#[test]
+fn aborts_on_first_mismatch() {
+    const ENCODING: musli::storage::Encoding = musli::storage::Encoding::new();
+
+    let count = Cell::new(0);
+
+    let reference = ENCODING.to_vec(&(1u32, Counted { count: &count })).unwrap();
+    assert_eq!(count.get(), 1);
+
+    let mut writer = CompareWriter::new(&reference);
+
+    assert!(ENCODING
+        .encode(&mut writer, &(2u32, Counted { count: &count }))
+        .is_err());
+
+    let offset = writer.mismatch().expect("expected a mismatch");
+    assert_eq!(reference[offset], 1);
+    assert_eq!(writer.position(), offset);
+    assert!(!writer.is_complete());
+    // The value following the mismatch is never encoded.
+    assert_eq!(count.get(), 1);
+}
+
+#[test]
+fn mismatch_past_end() {
+    const ENCODING: musli::storage::Encoding = musli::storage::Encoding::new();
+
+    let reference = ENCODING.to_vec(&String::from("abc")).unwrap();
+
+    let mut writer = CompareWriter::new(&reference);
+    assert!(ENCODING.encode(&mut writer, &String::from("abcd")).is_err());
+    assert_eq!(writer.mismatch(), Some(0));
+
+    let mut writer = CompareWriter::new(&reference[..2]);
+    assert!(ENCODING.encode(&mut writer, &String::from("abc")).is_err());
+    assert_eq!(writer.mismatch(), Some(2));
+    assert_eq!(writer.position(), 2);
+}
@@ -0,0 +1,59 @@
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::Binary;
+use musli::Context;
+
+#[test]
+fn unlimited_by_default() {
+    let alloc = System::new();
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+
+    for n in 0..200 {
+        cx.message(format_args!("Error {n}"));
+    }
+
+    assert_eq!(cx.errors().count(), 200);
+    assert_eq!(cx.dropped_errors(), 0);
+}
+
+#[test]
+fn errors_past_limit_are_counted() {
+    let alloc = System::new();
+    let mut cx = SystemContext::<_, Binary>::new(&alloc);
+    cx.with_max_errors(2);
+
+    for n in 0..5 {
+        cx.message(format_args!("Error {n}"));
+    }
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors, [": Error 0", ": Error 1"]);
+    assert_eq!(cx.dropped_errors(), 3);
+    assert_eq!(
+        cx.report().to_string(),
+        ": Error 0\n: Error 1\n... and 3 more errors\n"
+    );
+
+    cx.clear();
+    assert_eq!(cx.errors().count(), 0);
+    assert_eq!(cx.dropped_errors(), 0);
+}
+
+#[test]
+fn restore_resets_dropped_errors() {
+    let alloc = System::new();
+    let mut cx = SystemContext::<_, Binary>::new(&alloc);
+    cx.with_max_errors(1);
+
+    cx.message("First");
+    cx.message("Second");
+    let checkpoint = cx.checkpoint();
+
+    cx.message("Third");
+    cx.message("Fourth");
+    assert_eq!(cx.dropped_errors(), 3);
+
+    cx.restore(checkpoint);
+    assert_eq!(cx.errors().count(), 1);
+    assert_eq!(cx.dropped_errors(), 1);
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "test")]
+
+use std::borrow::Cow;
+
+use musli::compat::Bytes;
+use musli::value::Value;
+
+/// Test if the given value is borrowed from within the given input.
+fn borrowed_from<T>(value: Cow<'_, [T]>, input: &[u8]) -> bool
+where
+    T: Clone,
+{
+    let Cow::Borrowed(value) = value else {
+        return false;
+    };
+
+    let input = input.as_ptr_range();
+    let value = value.as_ptr_range();
+    input.start as usize <= value.start as usize && value.end as usize <= input.end as usize
+}
+
+fn str_borrowed_from(value: Cow<'_, str>, input: &[u8]) -> bool {
+    match value {
+        Cow::Borrowed(value) => borrowed_from(Cow::Borrowed(value.as_bytes()), input),
+        Cow::Owned(..) => false,
+    }
+}
+
+#[test]
+fn slices() {
+    musli::rt!(
+        full,
+        Cow::<[u32]>::Borrowed(&[1, 2, 3]),
+        json = r#"[1,2,3]"#
+    );
+    musli::rt!(
+        full,
+        Cow::<[String]>::Owned(vec![String::from("a"), String::from("b")]),
+        json = r#"["a","b"]"#
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        vec![1u32, 2, 3],
+        Cow::<[u32]>::Owned(vec![1, 2, 3]),
+        json = r#"[1,2,3]"#
+    );
+
+    let input = br#"[1,2,3]"#;
+    let value = musli::json::from_slice::<Cow<'_, [u32]>>(input).unwrap();
+    assert!(matches!(value, Cow::Owned(..)));
+}
+
+#[test]
+fn json() {
+    let input = br#""hello world""#;
+    let value = musli::json::from_slice::<Cow<'_, str>>(input).unwrap();
+    assert_eq!(value, "hello world");
+    assert!(str_borrowed_from(value, input));
+
+    // Strings with escapes have to be unescaped into an owned value.
+    let input = br#""hello\nworld""#;
+    let value = musli::json::from_slice::<Cow<'_, str>>(input).unwrap();
+    assert_eq!(value, "hello\nworld");
+    assert!(matches!(value, Cow::Owned(..)));
+
+    // Bytes are encoded as a sequence of numbers, so they can't be borrowed.
+    let input = br#"[1,2,3]"#;
+    let Bytes(value) = musli::json::from_slice::<Bytes<Cow<'_, [u8]>>>(input).unwrap();
+    assert_eq!(value.as_ref(), [1, 2, 3]);
+    assert!(matches!(value, Cow::Owned(..)));
+}
+
+#[test]
+fn storage() {
+    let input = musli::storage::to_vec(&"hello world").unwrap();
+    let value = musli::storage::from_slice::<Cow<'_, str>>(&input).unwrap();
+    assert_eq!(value, "hello world");
+    assert!(str_borrowed_from(value, &input));
+
+    let input = musli::storage::to_vec(&Bytes(&[1u8, 2, 3][..])).unwrap();
+    let Bytes(value) = musli::storage::from_slice::<Bytes<Cow<'_, [u8]>>>(&input).unwrap();
+    assert_eq!(value.as_ref(), [1, 2, 3]);
+    assert!(borrowed_from(value, &input));
+}
+
+#[test]
+fn value() {
+    let input = musli::value::encode("hello world").unwrap();
+    let value = musli::value::decode::<Cow<'_, str>>(&input).unwrap();
+    assert_eq!(value, "hello world");
+
+    let Value::String(string) = &input else {
+        panic!("expected string, got {input:?}");
+    };
+
+    assert!(str_borrowed_from(value, string.as_bytes()));
+
+    let input = musli::value::encode(Bytes(&[1u8, 2, 3][..])).unwrap();
+    let Bytes(value) = musli::value::decode::<Bytes<Cow<'_, [u8]>>>(&input).unwrap();
+    assert_eq!(value.as_ref(), [1, 2, 3]);
+
+    let Value::Bytes(bytes) = &input else {
+        panic!("expected bytes, got {input:?}");
+    };
+
+    assert!(borrowed_from(value, bytes));
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+use time::macros::{date, datetime};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Event {
+    at: OffsetDateTime,
+    local: PrimitiveDateTime,
+    day: Date,
+}
+
+#[test]
+fn offset_date_time() {
+    musli::rt!(
+        full,
+        OffsetDateTime::UNIX_EPOCH,
+        json = r#""1970-01-01T00:00:00Z""#
+    );
+
+    musli::rt!(
+        full,
+        datetime!(2024-02-29 12:30:45.123456789 UTC),
+        json = r#""2024-02-29T12:30:45.123456789Z""#
+    );
+
+    musli::rt!(
+        full,
+        datetime!(1985-04-12 23:20:50.52 +01:00),
+        json = r#""1985-04-12T23:20:50.52+01:00""#
+    );
+
+    musli::rt!(
+        full,
+        datetime!(1996-12-19 16:39:57 -08:00),
+        json = r#""1996-12-19T16:39:57-08:00""#
+    );
+
+    // Before the unix epoch.
+    musli::rt!(
+        full,
+        datetime!(1969-12-31 23:59:59.5 UTC),
+        json = r#""1969-12-31T23:59:59.5Z""#
+    );
+
+    musli::rt!(
+        full,
+        datetime!(0001-01-01 00:00:00 UTC),
+        json = r#""0001-01-01T00:00:00Z""#
+    );
+}
+
+#[test]
+fn primitive_date_time() {
+    musli::rt!(
+        full,
+        datetime!(2024-02-29 12:30:45.5),
+        json = r#""2024-02-29T12:30:45.5""#
+    );
+
+    musli::rt!(
+        full,
+        datetime!(1900-01-01 00:00:00),
+        json = r#""1900-01-01T00:00:00""#
+    );
+}
+
+#[test]
+fn date() {
+    musli::rt!(full, date!(1970 - 01 - 01), json = r#""1970-01-01""#);
+    musli::rt!(full, date!(2024 - 02 - 29), json = r#""2024-02-29""#);
+    musli::rt!(full, date!(1066 - 10 - 14), json = r#""1066-10-14""#);
+
+    musli::rt!(
+        full,
+        Event {
+            at: datetime!(2024-02-29 12:30:45 +05:30),
+            local: datetime!(2024-02-29 12:30:45),
+            day: date!(2024 - 02 - 29),
+        },
+        json = r#"{"at":"2024-02-29T12:30:45+05:30","local":"2024-02-29T12:30:45","day":"2024-02-29"}"#
+    );
+}
+
+#[test]
+fn binary_representation() -> Result<(), Box<dyn std::error::Error>> {
+    let time = datetime!(1969-12-31 23:59:59.5 -01:00);
+    let bytes = musli::storage::to_vec(&time)?;
+    assert_eq!(
+        bytes,
+        musli::storage::to_vec(&(-1i64 + 3600, 500_000_000u32))?
+    );
+
+    // The offset isn't preserved.
+    let decoded = musli::storage::from_slice::<OffsetDateTime>(&bytes)?;
+    assert_eq!(decoded, time);
+    assert_eq!(decoded.offset(), UtcOffset::UTC);
+
+    let bytes = musli::storage::to_vec(&(-1i64, 500_000_000u32))?;
+    assert_eq!(
+        musli::storage::from_slice::<PrimitiveDateTime>(&bytes)?,
+        datetime!(1969-12-31 23:59:59.5)
+    );
+
+    let bytes = musli::storage::to_vec(&date!(1969 - 12 - 31))?;
+    assert_eq!(bytes, musli::storage::to_vec(&-1i32)?);
+    Ok(())
+}
+
+#[test]
+fn json_offsets() -> Result<(), Box<dyn std::error::Error>> {
+    let time = musli::json::from_str::<OffsetDateTime>(r#""2024-02-29T12:30:45+05:30""#)?;
+    assert_eq!(time, datetime!(2024-02-29 07:00:45 UTC));
+    assert_eq!(time.offset(), UtcOffset::from_hms(5, 30, 0)?);
+
+    let time = musli::json::from_str::<OffsetDateTime>(r#""2024-02-29t12:30:45z""#)?;
+    assert_eq!(time, datetime!(2024-02-29 12:30:45 UTC));
+    Ok(())
+}
+
+#[test]
+fn leap_seconds() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        musli::json::from_str::<OffsetDateTime>(r#""2016-12-31T23:59:60Z""#)?,
+        datetime!(2016-12-31 23:59:59.999999999 UTC)
+    );
+
+    assert_eq!(
+        musli::json::from_str::<OffsetDateTime>(r#""2016-12-31T15:59:60-08:00""#)?,
+        datetime!(2016-12-31 23:59:59.999999999 UTC)
+    );
+
+    assert!(musli::json::from_str::<OffsetDateTime>(r#""2016-12-30T23:59:60Z""#).is_err());
+    assert!(musli::json::from_str::<OffsetDateTime>(r#""2016-12-31T12:00:60Z""#).is_err());
+    Ok(())
+}
+
+#[test]
+fn malformed() {
+    assert!(musli::json::from_str::<OffsetDateTime>(r#""2024-02-29T12:30:45""#).is_err());
+    assert!(musli::json::from_str::<OffsetDateTime>(r#""2023-02-29T12:30:45Z""#).is_err());
+    assert!(musli::json::from_str::<OffsetDateTime>(r#""2024-02-29""#).is_err());
+    assert!(musli::json::from_str::<OffsetDateTime>("1700000000").is_err());
+    assert!(musli::json::from_str::<PrimitiveDateTime>(r#""2024-02-29""#).is_err());
+    assert!(musli::json::from_str::<Date>(r#""2024-13-01""#).is_err());
+    assert!(musli::json::from_str::<Date>(r#""2024-02-29T12:30:45""#).is_err());
+}
+
+#[test]
+fn out_of_range() {
+    let alloc = System::new();
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+
+    let bytes = musli::storage::to_vec(&(i64::MAX, 0u32)).unwrap();
+    let storage = musli::storage::Encoding::new();
+    assert!(storage
+        .from_slice_with::<_, OffsetDateTime>(&cx, &bytes)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        format!(
+            ": Timestamp {}s 0ns is out of range (at bytes 0-12)\n",
+            i64::MAX
+        )
+    );
+
+    let bytes = musli::storage::to_vec(&(0i64, 1_000_000_000u32)).unwrap();
+    assert!(storage
+        .from_slice_with::<_, OffsetDateTime>(&cx, &bytes)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Nanoseconds 1000000000 out of range, expected less than 1000000000 (at bytes 0-7)\n"
+    );
+
+    let bytes = musli::storage::to_vec(&i32::MAX).unwrap();
+    assert!(storage.from_slice_with::<_, Date>(&cx, &bytes).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        format!(
+            ": Date {} days from the unix epoch is out of range (at bytes 0-5)\n",
+            i32::MAX
+        )
+    );
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let json = musli::json::Encoding::new();
+
+    let mut out = Vec::new();
+    let time = datetime!(2024-02-29 12:30:45 +01:02:03);
+    assert!(json.to_writer_with(&cx, &mut out, &time).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Offset +01:02:03 can't be represented in RFC 3339\n"
+    );
+
+    let date = Date::MIN;
+    assert!(json.to_writer_with(&cx, &mut out, &date).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Year -9999 can't be represented in RFC 3339\n"
+    );
+
+    assert!(json
+        .from_str_with::<_, OffsetDateTime>(&cx, r#""2023-02-29T12:30:45Z""#)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": day was not in range (at byte 22)\n"
+    );
+}
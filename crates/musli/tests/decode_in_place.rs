@@ -0,0 +1,133 @@
+#![cfg(feature = "test")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use musli::{Decode, Encode};
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let output = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    (output, after - before)
+}
+
+#[derive(Debug, Default, PartialEq, Encode, Decode)]
+struct Inner {
+    label: String,
+}
+
+#[derive(Debug, Default, PartialEq, Encode, Decode)]
+struct Record {
+    id: u64,
+    name: String,
+    values: Vec<u32>,
+    counts: HashMap<u32, u32>,
+    inner: Inner,
+    #[musli(default)]
+    note: Option<u32>,
+    #[musli(skip)]
+    cached: u32,
+}
+
+fn record(id: u64, name: &str, values: &[u32]) -> Record {
+    Record {
+        id,
+        name: name.to_owned(),
+        values: values.to_vec(),
+        counts: values.iter().map(|v| (*v, v * 2)).collect(),
+        inner: Inner {
+            label: name.to_uppercase(),
+        },
+        note: Some(id as u32),
+        cached: 0,
+    }
+}
+
+macro_rules! test_in_place {
+    ($name:ident, $what:ident) => {
+        #[test]
+        fn $name() {
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            let first = ENCODING.to_vec(&record(1, "first record", &[1, 2, 3, 4])).unwrap();
+            let second = ENCODING.to_vec(&record(2, "second", &[5, 6])).unwrap();
+
+            let mut place = Record::default();
+            ENCODING.from_slice_into(&first, &mut place).unwrap();
+            assert_eq!(place, record(1, "first record", &[1, 2, 3, 4]));
+
+            // Steady state, decoding a value which fits in the existing
+            // allocations doesn't allocate.
+            let (result, count) = allocations(|| ENCODING.from_slice_into(&second, &mut place));
+            result.unwrap();
+            assert_eq!(place, record(2, "second", &[5, 6]));
+            assert_eq!(count, 0, "expected no allocations");
+
+            let (result, count) = allocations(|| ENCODING.from_slice_into(&first, &mut place));
+            result.unwrap();
+            assert_eq!(place, record(1, "first record", &[1, 2, 3, 4]));
+            assert_eq!(count, 0, "expected no allocations");
+        }
+    };
+}
+
+test_in_place!(storage_in_place, storage);
+test_in_place!(wire_in_place, wire);
+test_in_place!(descriptive_in_place, descriptive);
+
+#[test]
+fn json_in_place() {
+    const ENCODING: musli::json::Encoding = musli::json::Encoding::new();
+
+    let mut place = record(1, "first record", &[1, 2, 3, 4]);
+    place.cached = 42;
+
+    ENCODING
+        .from_slice_into(
+            br#"{"id":2,"name":"second","values":[5],"counts":{"5":10},"inner":{"label":"SECOND"}}"#,
+            &mut place,
+        )
+        .unwrap();
+
+    let mut expected = record(2, "second", &[5]);
+    // Missing fields with a default are reset, as are skipped fields.
+    expected.note = None;
+    assert_eq!(place, expected);
+}
+
+#[test]
+fn missing_field_errors() {
+    const ENCODING: musli::json::Encoding = musli::json::Encoding::new();
+
+    let mut place = record(1, "first", &[1]);
+    assert!(ENCODING
+        .from_slice_into(br#"{"id":2,"name":"second"}"#, &mut place)
+        .is_err());
+}
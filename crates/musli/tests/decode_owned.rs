@@ -0,0 +1,74 @@
+#![cfg(feature = "test")]
+
+use std::collections::HashMap;
+
+use musli::mode::{Binary, Text};
+use musli::{Decode, DecodeOwned, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Owned {
+    name: String,
+    values: Vec<u32>,
+    map: HashMap<String, u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Borrowed<'de> {
+    name: &'de str,
+}
+
+fn load_json<T>(json: &str) -> T
+where
+    T: DecodeOwned<Text>,
+{
+    // The input is dropped before the value is returned, which is only
+    // possible since the value can't borrow from it.
+    let json = json.to_owned();
+    musli::json::from_str(&json).unwrap()
+}
+
+fn load_storage<T>(bytes: &[u8]) -> T
+where
+    T: DecodeOwned<Binary>,
+{
+    let bytes = bytes.to_vec();
+    musli::storage::from_slice(&bytes).unwrap()
+}
+
+fn load_borrowed<'de, T>(json: &'de str) -> T
+where
+    T: Decode<'de, Text>,
+{
+    musli::json::from_str(json).unwrap()
+}
+
+#[test]
+fn owned() {
+    let value = Owned {
+        name: String::from("Aristotle"),
+        values: vec![1, 2, 3],
+        map: HashMap::from([(String::from("age"), 61)]),
+    };
+
+    let json = musli::json::to_string(&value).unwrap();
+    assert_eq!(load_json::<Owned>(&json), value);
+    assert_eq!(load_json::<String>(r#""hello""#), "hello");
+    assert_eq!(load_json::<Option<Vec<u32>>>("[1,2]"), Some(vec![1, 2]));
+
+    let bytes = musli::storage::to_vec(&value).unwrap();
+    assert_eq!(load_storage::<Owned>(&bytes), value);
+}
+
+#[test]
+fn borrowed() {
+    let json = String::from(r#"{"name":"Aristotle"}"#);
+
+    // Borrowing types don't implement `DecodeOwned`, so they have to be
+    // decoded with a bound which ties them to the lifetime of the input.
+    let value = load_borrowed::<Borrowed<'_>>(&json);
+    assert_eq!(value, Borrowed { name: "Aristotle" });
+
+    // Owned types can be decoded with the borrowing bound as well.
+    let value = load_borrowed::<HashMap<String, String>>(&json);
+    assert_eq!(value.get("name").map(String::as_str), Some("Aristotle"));
+}
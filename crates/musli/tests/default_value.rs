@@ -1,5 +1,9 @@
 #![cfg(feature = "test")]
 
+use std::collections::HashMap;
+
+use musli::allocator::System;
+use musli::context::SystemContext;
 use musli::{Decode, Encode};
 
 #[derive(Encode, Decode)]
@@ -193,3 +197,82 @@ fn decode_with_default() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version1 {
+    #[musli(mode = Binary, name = 0)]
+    #[musli(mode = Text, name = "identifier")]
+    id: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Version2 {
+    #[musli(mode = Binary, name = 0)]
+    #[musli(mode = Text, name = "identifier")]
+    id: u32,
+    #[musli(mode = Binary, name = 10)]
+    #[musli(mode = Text, name = "displayName")]
+    #[musli(default = default_display_name)]
+    display_name: String,
+    #[musli(mode = Binary, name = 11)]
+    #[musli(default, trace)]
+    tags: HashMap<String, u32>,
+}
+
+fn default_display_name() -> String {
+    String::from("anonymous")
+}
+
+// Ensure that fields with defaults which have been renamed or are traced can be
+// added to an existing model.
+#[test]
+fn decode_renamed_default() {
+    musli::assert_decode_eq!(
+        full,
+        Version1 { id: 42 },
+        Version2 {
+            id: 42,
+            display_name: String::from("anonymous"),
+            tags: HashMap::new(),
+        },
+        json = r#"{"identifier":42}"#,
+    );
+
+    musli::rt!(
+        full,
+        Version2 {
+            id: 42,
+            display_name: String::from("Aristotle"),
+            tags: HashMap::from([(String::from("greek"), 1)]),
+        },
+        json = r#"{"identifier":42,"displayName":"Aristotle","tags":{"greek":1}}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Version2 {
+            id: 42,
+            display_name: String::from("Aristotle"),
+            tags: HashMap::new(),
+        },
+        Version1 { id: 42 },
+        json = r#"{"identifier":42,"displayName":"Aristotle","tags":{}}"#,
+    );
+}
+
+// Without a default, a missing field is reported through the renamed tag.
+#[test]
+fn decode_missing_field() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let Err(..) = encoding.from_slice_with::<_, Version1>(&cx, br#"{}"#) else {
+        panic!("Expected decoding to error");
+    };
+
+    let error = cx.errors().next().expect("Expected an error");
+    let error = error.to_string();
+    assert!(error.contains(r#"Expected tag: "identifier""#), "{error}");
+}
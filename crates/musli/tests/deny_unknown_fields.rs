@@ -0,0 +1,151 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", deny_unknown_fields)]
+pub struct Strict {
+    number: u32,
+    #[musli(name = "renamed")]
+    string: String,
+    #[musli(skip)]
+    skipped: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub struct Extended {
+    number: u32,
+    renamed: String,
+    skipped: u32,
+    extra: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(deny_unknown_fields)]
+pub struct StrictIndexed {
+    number: u32,
+    #[musli(skip)]
+    skipped: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+pub struct ExtendedIndexed {
+    number: u32,
+    skipped: u32,
+    extra: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", deny_unknown_fields)]
+pub struct StrictNamed {
+    number: u32,
+    #[musli(skip)]
+    skipped: u32,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub struct ExtendedNamed {
+    number: u32,
+    skipped: u32,
+    extra: u32,
+}
+
+#[test]
+fn deny_unknown_fields_json() -> Result<(), Box<dyn std::error::Error>> {
+    musli::rt!(
+        full,
+        Strict {
+            number: 42,
+            string: String::from("Hello World"),
+            skipped: 0,
+        },
+        json = r#"{"number":42,"renamed":"Hello World"}"#
+    );
+
+    // Skipped fields are known and are ignored.
+    assert_eq!(
+        musli::json::from_str::<Strict>(r#"{"number":42,"renamed":"Hello","skipped":7}"#)?,
+        Strict {
+            number: 42,
+            string: String::from("Hello"),
+            skipped: 0,
+        }
+    );
+
+    let alloc = System::new();
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let encoding = musli::json::Encoding::new();
+
+    let bytes = musli::json::to_vec(&Extended {
+        number: 42,
+        renamed: String::from("Hello"),
+        skipped: 7,
+        extra: 10,
+    })?;
+
+    assert!(encoding.from_slice_with::<_, Strict>(&cx, &bytes).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains(r#"Unknown field tag "extra""#), "{error}");
+    assert!(error.contains("(at bytes "), "{error}");
+    Ok(())
+}
+
+#[test]
+fn deny_unknown_fields_descriptive() -> Result<(), Box<dyn std::error::Error>> {
+    let alloc = System::new();
+    let encoding = musli::descriptive::Encoding::new();
+
+    let bytes = musli::descriptive::to_vec(&ExtendedIndexed {
+        number: 42,
+        skipped: 7,
+        extra: 10,
+    })?;
+
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, StrictIndexed>(&cx, &bytes)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains("Unknown field tag 2"), "{error}");
+
+    let bytes = musli::descriptive::to_vec(&ExtendedNamed {
+        number: 42,
+        skipped: 7,
+        extra: 10,
+    })?;
+
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+    assert!(encoding
+        .from_slice_with::<_, StrictNamed>(&cx, &bytes)
+        .is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains(r#"Unknown field tag "extra""#), "{error}");
+
+    // Without the unknown field, the skipped field is still accepted.
+    #[derive(Encode)]
+    #[musli(name_all = "name")]
+    pub struct Known {
+        number: u32,
+        skipped: u32,
+    }
+
+    let bytes = musli::descriptive::to_vec(&Known {
+        number: 42,
+        skipped: 7,
+    })?;
+
+    assert_eq!(
+        musli::descriptive::from_slice::<StrictNamed>(&bytes)?,
+        StrictNamed {
+            number: 42,
+            skipped: 0,
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "test")]
+//! Tests that the precomputed encoded length agrees with the length of the
+//! encoded bytes.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use musli::mode::{Binary, Text};
+use musli::writer::CountWriter;
+use musli::Encode;
+use tests::{Generate, Rng};
+
+/// Default random seed to use.
+const RNG_SEED: u64 = 2718281828459045235;
+
+/// The number of random samples to test for each type.
+const SAMPLES: usize = 100;
+
+#[derive(Debug, Generate, Encode)]
+#[generate(crate)]
+enum Event {
+    Empty,
+    Tuple(u32, i64),
+    Struct { name: String, data: Vec<u8> },
+}
+
+#[derive(Debug, Generate, Encode)]
+#[generate(crate)]
+struct Sample {
+    id: u64,
+    small: u8,
+    signed: i32,
+    ratio: f64,
+    flag: bool,
+    name: String,
+    payload: Vec<u8>,
+    tags: Vec<String>,
+    events: Vec<Event>,
+    map: HashMap<u32, String>,
+}
+
+#[derive(Debug, Generate, Encode)]
+#[generate(crate)]
+#[musli(packed)]
+struct Packed {
+    a: u16,
+    b: u64,
+    c: f32,
+}
+
+/// Check a value against every format.
+#[track_caller]
+fn check<T>(value: &T)
+where
+    T: ?Sized + fmt::Debug + Encode<Binary> + Encode<Text>,
+{
+    macro_rules! check {
+        ($what:ident) => {{
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            let expected = ENCODING.to_vec(value).unwrap().len();
+            let actual = ENCODING.encoded_len(value).unwrap();
+
+            assert_eq!(
+                actual,
+                expected,
+                "{}: encoded length of {value:?}",
+                stringify!($what)
+            );
+        }};
+    }
+
+    check!(storage);
+    check!(wire);
+    check!(descriptive);
+    check!(json);
+}
+
+fn random<T>()
+where
+    T: fmt::Debug + Generate + Encode<Binary> + Encode<Text>,
+{
+    let mut rng: Rng = tests::rng_with_seed(RNG_SEED);
+
+    for _ in 0..SAMPLES {
+        check(&rng.next::<T>());
+    }
+}
+
+#[test]
+fn random_values() {
+    random::<Sample>();
+    random::<Event>();
+    random::<Packed>();
+    random::<Vec<String>>();
+    random::<(u32, String, i64)>();
+}
+
+#[test]
+fn edge_values() {
+    check(&0u8);
+    check(&u64::MAX);
+    check(&i64::MIN);
+    check(&u128::MAX);
+    check(&-0.0f64);
+    check(&f32::MAX);
+    check("");
+    check("escape \"\\\n\t\u{1}");
+    check("non-ascii åäö 🦀");
+    check(&Vec::<u32>::new());
+    check(&[Some(1u32), None]);
+    check(&Event::Empty);
+}
+
+#[test]
+fn count_writer() {
+    const ENCODING: musli::storage::Encoding = musli::storage::Encoding::new();
+
+    let mut writer = CountWriter::new();
+    assert!(writer.is_empty());
+
+    ENCODING.encode(&mut writer, &(1u32, "foo")).unwrap();
+    ENCODING.encode(&mut writer, &2u32).unwrap();
+
+    let mut expected = ENCODING.to_vec(&(1u32, "foo")).unwrap();
+    expected.extend(ENCODING.to_vec(&2u32).unwrap());
+    assert_eq!(writer.len(), expected.len());
+}
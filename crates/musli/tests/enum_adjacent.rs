@@ -1,3 +1,6 @@
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::{Binary, Text};
 use musli::{Decode, Encode};
 
 #[test]
@@ -120,7 +123,7 @@ fn named_with_skip() {
         descriptive,
         NamedWithSkip::Empty,
         NamedWithSkip::Empty,
-        json = r#"{"type":"Empty","content":{}}"#
+        json = r#"{"type":"Empty"}"#
     };
 
     musli::assert_decode_eq! {
@@ -138,3 +141,90 @@ fn named_with_skip() {
         json = r#"{"type":"Struct","content":{"string":"Hello World","number":42}}"#,
     };
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", tag = "t", content = "c")]
+pub enum Short {
+    Unit,
+    #[musli(name_all = "name")]
+    Struct { number: u32 },
+    Tuple(u32, String),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", tag = "t", content = "c")]
+pub enum ShortExtended {
+    Unit,
+    #[musli(name_all = "name")]
+    Struct { number: u32 },
+    Tuple(u32, String),
+    #[musli(name_all = "name")]
+    Added { number: u32 },
+}
+
+#[test]
+fn short_keys() {
+    musli::rt! {
+        full,
+        Short::Unit,
+        json = r#"{"t":"Unit"}"#
+    };
+
+    musli::rt! {
+        full,
+        Short::Struct { number: 42 },
+        json = r#"{"t":"Struct","c":{"number":42}}"#
+    };
+
+    musli::rt! {
+        full,
+        Short::Tuple(42, String::from("Hello World")),
+    };
+}
+
+/// The tag and the content are accepted in any order, and empty variants may
+/// still be decoded with empty content.
+#[test]
+fn any_order() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        musli::json::from_str::<Short>(r#"{"c":{"number":42},"t":"Struct"}"#)?,
+        Short::Struct { number: 42 }
+    );
+
+    assert_eq!(
+        musli::json::from_str::<Short>(r#"{"t":"Unit","c":{}}"#)?,
+        Short::Unit
+    );
+
+    assert_eq!(
+        musli::json::from_str::<Short>(r#"{"c":{},"t":"Unit"}"#)?,
+        Short::Unit
+    );
+
+    assert!(musli::json::from_str::<Short>(r#"{"t":"Struct"}"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn unknown_tag() -> Result<(), Box<dyn std::error::Error>> {
+    let alloc = System::new();
+
+    let bytes = musli::json::to_vec(&ShortExtended::Added { number: 42 })?;
+    assert_eq!(bytes, br#"{"t":"Added","c":{"number":42}}"#);
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let encoding = musli::json::Encoding::new();
+    assert!(encoding.from_slice_with::<_, Short>(&cx, &bytes).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains(r#"Invalid variant tag "Added""#), "{error}");
+
+    let bytes = musli::descriptive::to_vec(&ShortExtended::Added { number: 42 })?;
+
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+    let encoding = musli::descriptive::Encoding::new();
+    assert!(encoding.from_slice_with::<_, Short>(&cx, &bytes).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.contains(r#"Invalid variant tag "Added""#), "{error}");
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Future {
+    Cat,
+    Dog { age: u32 },
+    Bird,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Current {
+    Cat,
+    #[musli(other)]
+    Unknown(String),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum CurrentEmpty {
+    Cat,
+    Fish { weight: u32 },
+    #[musli(other)]
+    Unknown,
+}
+
+#[test]
+fn other_name() {
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Future::Cat,
+        Current::Cat,
+        json = r#"{"Cat":{}}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Future::Dog { age: 4 },
+        Current::Unknown(String::from("Dog")),
+        json = r#"{"Dog":{"age":4}}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Future::Bird,
+        Current::Unknown(String::from("Bird")),
+        json = r#"{"Bird":{}}"#,
+    );
+
+    musli::rt!(
+        upgrade_stable,
+        Current::Unknown(String::from("Dog")),
+        json = r#"{"Dog":{}}"#,
+    );
+}
+
+#[test]
+fn other_empty() {
+    musli::assert_decode_eq!(upgrade_stable, Future::Cat, CurrentEmpty::Cat);
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Future::Dog { age: 4 },
+        CurrentEmpty::Unknown,
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = u32)]
+pub enum FutureIndex {
+    #[musli(name = 0)]
+    First,
+    #[musli(name = 1)]
+    Second { value: u32 },
+    #[musli(name = 2)]
+    Third,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = u32)]
+pub enum CurrentIndex {
+    #[musli(name = 0)]
+    First,
+    #[musli(other)]
+    Unknown(u32),
+}
+
+#[test]
+fn other_index() {
+    musli::assert_decode_eq!(upgrade_stable, FutureIndex::First, CurrentIndex::First);
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        FutureIndex::Second { value: 42 },
+        CurrentIndex::Unknown(1),
+    );
+
+    musli::assert_decode_eq!(upgrade_stable, FutureIndex::Third, CurrentIndex::Unknown(2));
+    musli::rt!(upgrade_stable, CurrentIndex::Unknown(2));
+    musli::assert_decode_eq!(upgrade_stable, CurrentIndex::Unknown(2), FutureIndex::Third);
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag = "type", name_all = "name")]
+pub enum FutureInternal {
+    Cat,
+    Dog { age: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag = "type", name_all = "name")]
+pub enum CurrentInternal {
+    Cat,
+    #[musli(other)]
+    Unknown(String),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag = "type", name_all = "name")]
+pub enum CurrentInternalDefault {
+    Cat,
+    #[musli(default)]
+    Unknown,
+}
+
+#[test]
+fn other_internal() {
+    musli::assert_decode_eq!(
+        descriptive,
+        FutureInternal::Cat,
+        CurrentInternal::Cat,
+        json = r#"{"type":"Cat"}"#,
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        FutureInternal::Dog { age: 4 },
+        CurrentInternal::Unknown(String::from("Dog")),
+    );
+
+    musli::rt!(
+        descriptive,
+        CurrentInternal::Unknown(String::from("Dog")),
+        json = r#"{"type":"Dog"}"#,
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        FutureInternal::Dog { age: 4 },
+        CurrentInternalDefault::Unknown,
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag = "type", content = "content", name_all = "name")]
+pub enum FutureAdjacent {
+    Cat,
+    Dog { age: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag = "type", content = "content", name_all = "name")]
+pub enum CurrentAdjacent {
+    Cat,
+    #[musli(other)]
+    Unknown(String),
+}
+
+#[test]
+fn other_adjacent() {
+    musli::assert_decode_eq!(
+        descriptive,
+        FutureAdjacent::Cat,
+        CurrentAdjacent::Cat,
+        json = r#"{"type":"Cat"}"#,
+    );
+
+    musli::assert_decode_eq!(
+        descriptive,
+        FutureAdjacent::Dog { age: 4 },
+        CurrentAdjacent::Unknown(String::from("Dog")),
+        json = r#"{"type":"Dog","content":{"age":4}}"#,
+    );
+
+    musli::rt!(
+        descriptive,
+        CurrentAdjacent::Unknown(String::from("Dog")),
+        json = r#"{"type":"Dog"}"#,
+    );
+}
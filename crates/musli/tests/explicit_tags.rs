@@ -0,0 +1,132 @@
+//! Fields with explicit integer tags which leave gaps, like protobuf.
+
+use musli::{Decode, Encode};
+
+const JSON: musli::json::Encoding = musli::json::Encoding::new();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "index")]
+struct Message {
+    #[musli(name = 1)]
+    id: u32,
+    #[musli(name = 2)]
+    name: String,
+    #[musli(name = 5, alias = 6)]
+    score: u32,
+    #[musli(name = 9, default)]
+    flags: u32,
+}
+
+/// Fields without an explicit name use their index, which coexists with
+/// explicit overrides as long as they don't collide.
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "index")]
+struct Mixed {
+    a: u32,
+    b: u32,
+    #[musli(name = 10)]
+    c: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "index")]
+struct Unordered {
+    #[musli(name = 4)]
+    score: u32,
+    #[musli(name = 1)]
+    id: u32,
+    #[musli(name = 7)]
+    tags: Vec<String>,
+    #[musli(name = 3)]
+    name: String,
+}
+
+fn message() -> Message {
+    Message {
+        id: 1,
+        name: String::from("Hello"),
+        score: 42,
+        flags: 3,
+    }
+}
+
+#[test]
+fn explicit_tags_with_gaps() {
+    let out = JSON.to_string(&message()).unwrap();
+    assert_eq!(out, r#"{"1":1,"2":"Hello","5":42,"9":3}"#);
+
+    let mixed = Mixed { a: 1, b: 2, c: 3 };
+    let out = JSON.to_string(&mixed).unwrap();
+    assert_eq!(out, r#"{"0":1,"1":2,"10":3}"#);
+    assert_eq!(JSON.from_str::<Mixed>(&out).unwrap(), mixed);
+}
+
+#[test]
+fn reordered_tags() {
+    let value = JSON
+        .from_str::<Message>(r#"{"9":3,"5":42,"2":"Hello","1":1}"#)
+        .unwrap();
+    assert_eq!(value, message());
+
+    let value = JSON
+        .from_str::<Message>(r#"{"6":42,"1":1,"2":"Hello","9":3}"#)
+        .unwrap();
+    assert_eq!(value, message());
+}
+
+#[test]
+fn omitted_and_unknown_tags() {
+    let value = JSON
+        .from_str::<Message>(r#"{"1":1,"3":[1,{"x":2}],"2":"Hello","5":42,"100":"skipped"}"#)
+        .unwrap();
+
+    assert_eq!(
+        value,
+        Message {
+            flags: 0,
+            ..message()
+        }
+    );
+}
+
+#[test]
+fn reordered_binary_tags() {
+    #[derive(Debug, PartialEq, Encode)]
+    #[musli(name_all = "index")]
+    struct Newer {
+        #[musli(name = 3)]
+        name: String,
+        #[musli(name = 5)]
+        extra: Vec<u32>,
+        #[musli(name = 1)]
+        id: u32,
+        #[musli(name = 4)]
+        score: u32,
+        #[musli(name = 7)]
+        tags: Vec<String>,
+    }
+
+    let newer = Newer {
+        name: String::from("Hello"),
+        extra: vec![1, 2, 3],
+        id: 7,
+        score: 42,
+        tags: vec![String::from("a")],
+    };
+
+    let expected = Unordered {
+        score: 42,
+        id: 7,
+        tags: vec![String::from("a")],
+        name: String::from("Hello"),
+    };
+
+    let bytes = musli::wire::to_vec(&newer).unwrap();
+    assert_eq!(musli::wire::from_slice::<Unordered>(&bytes).unwrap(), expected);
+
+    let bytes = musli::descriptive::to_vec(&newer).unwrap();
+    assert_eq!(
+        musli::descriptive::from_slice::<Unordered>(&bytes).unwrap(),
+        expected
+    );
+}
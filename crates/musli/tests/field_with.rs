@@ -0,0 +1,166 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Commit {
+    #[musli(mode = Text, with = self::hex)]
+    hash: [u8; 4],
+    #[musli(with = self::timestamp)]
+    timestamp: Timestamp,
+}
+
+#[derive(Debug, PartialEq)]
+struct Timestamp {
+    seconds: i64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Counter {
+    #[musli(encode_with = self::encode_doubled)]
+    count: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Lenient {
+    #[musli(decode_with = self::decode_saturating)]
+    count: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Wide {
+    count: u64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct TextOnly {
+    #[musli(mode = Text, decode_only, decode_with = self::decode_saturating)]
+    #[musli(mode = Binary, encode_with = self::encode_doubled)]
+    count: u32,
+}
+
+mod hex {
+    use musli::{Context, Decoder, Encoder};
+
+    pub fn encode<E>(hash: &[u8; 4], _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+    {
+        let mut string = String::new();
+
+        for b in hash {
+            string.push_str(&format!("{b:02x}"));
+        }
+
+        encoder.encode_string(&string)
+    }
+
+    pub fn decode<'de, D>(cx: &D::Cx, decoder: D) -> Result<[u8; 4], D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        let string: String = decoder.decode()?;
+        let mut hash = [0; 4];
+
+        if string.len() != hash.len() * 2 {
+            return Err(cx.message("Bad hash length"));
+        }
+
+        for (n, b) in hash.iter_mut().enumerate() {
+            let Ok(value) = u8::from_str_radix(&string[n * 2..n * 2 + 2], 16) else {
+                return Err(cx.message("Bad hex digit"));
+            };
+
+            *b = value;
+        }
+
+        Ok(hash)
+    }
+}
+
+mod timestamp {
+    use musli::{Decoder, Encode, Encoder};
+
+    use super::Timestamp;
+
+    pub fn encode<E>(value: &Timestamp, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+    {
+        value.seconds.encode(cx, encoder)
+    }
+
+    pub fn decode<'de, D>(_: &D::Cx, decoder: D) -> Result<Timestamp, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        Ok(Timestamp {
+            seconds: decoder.decode()?,
+        })
+    }
+}
+
+fn encode_doubled<E>(value: &u32, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+where
+    E: musli::Encoder,
+{
+    musli::Encode::encode(&value.saturating_mul(2), cx, encoder)
+}
+
+fn decode_saturating<'de, D>(_: &D::Cx, decoder: D) -> Result<u32, D::Error>
+where
+    D: musli::Decoder<'de>,
+{
+    let value: u64 = decoder.decode()?;
+    Ok(u32::try_from(value).unwrap_or(u32::MAX))
+}
+
+#[test]
+fn field_with() {
+    musli::rt!(
+        full,
+        Commit {
+            hash: [0xde, 0xad, 0xbe, 0xef],
+            timestamp: Timestamp { seconds: -42 },
+        },
+        json = r#"{"hash":"deadbeef","timestamp":-42}"#,
+    );
+}
+
+#[test]
+fn field_encode_decode_with() {
+    musli::assert_decode_eq!(
+        full,
+        Counter { count: 21 },
+        Counter { count: 42 },
+        json = r#"{"count":42}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Wide { count: u64::MAX },
+        Lenient { count: u32::MAX },
+        json = r#"{"count":18446744073709551615}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        Wide { count: 42 },
+        Lenient { count: 42 },
+        json = r#"{"count":42}"#,
+    );
+}
+
+#[test]
+fn field_with_modes() {
+    // Text modes only override decoding.
+    musli::assert_decode_eq!(
+        json,
+        TextOnly { count: 21 },
+        TextOnly { count: 21 },
+        json = r#"{"count":21}"#,
+    );
+
+    // Binary modes only override encoding.
+    musli::assert_decode_eq!(no_json, TextOnly { count: 21 }, TextOnly { count: 42 });
+}
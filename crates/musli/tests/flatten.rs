@@ -0,0 +1,193 @@
+#![cfg(feature = "test")]
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Sorting {
+    order: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Users {
+    users: Vec<String>,
+    #[musli(flatten)]
+    pagination: Pagination,
+    total: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Search {
+    #[musli(flatten)]
+    pagination: Pagination,
+    #[musli(flatten)]
+    sorting: Sorting,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Nested {
+    query: String,
+    #[musli(flatten)]
+    search: Search,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", deny_unknown_fields)]
+pub struct Strict {
+    page: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Routed {
+    id: u32,
+    #[musli(flatten)]
+    strict: Strict,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Filter {
+    #[musli(default, skip_encoding_if = Vec::is_empty)]
+    tags: Vec<String>,
+}
+
+impl Filter {
+    fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Conditional {
+    id: u32,
+    #[musli(flatten, skip_encoding_if = Filter::is_empty)]
+    filter: Filter,
+}
+
+#[derive(Debug, PartialEq, Encode)]
+#[musli(name_all = "name")]
+pub struct UsersFlat {
+    users: Vec<String>,
+    page: u32,
+    per_page: u32,
+    total: u32,
+    unknown: u32,
+}
+
+#[test]
+fn disjoint_keys() {
+    musli::rt!(
+        descriptive,
+        Users {
+            users: vec![String::from("Aristotle")],
+            pagination: Pagination {
+                page: 2,
+                per_page: 10,
+            },
+            total: 11,
+        },
+        json = r#"{"users":["Aristotle"],"page":2,"per_page":10,"total":11}"#
+    );
+
+    musli::rt!(
+        descriptive,
+        Search {
+            pagination: Pagination {
+                page: 1,
+                per_page: 20,
+            },
+            sorting: Sorting {
+                order: String::from("asc"),
+            },
+        },
+        json = r#"{"page":1,"per_page":20,"order":"asc"}"#
+    );
+
+    musli::rt!(
+        descriptive,
+        Nested {
+            query: String::from("greek"),
+            search: Search {
+                pagination: Pagination {
+                    page: 1,
+                    per_page: 20,
+                },
+                sorting: Sorting {
+                    order: String::from("desc"),
+                },
+            },
+        },
+        json = r#"{"query":"greek","page":1,"per_page":20,"order":"desc"}"#
+    );
+}
+
+#[test]
+fn unknown_keys() {
+    // Only keys which are unknown to the container are routed to flattened
+    // fields, so they can deny unknown fields.
+    musli::rt!(
+        descriptive,
+        Routed {
+            id: 1,
+            strict: Strict { page: 2 },
+        },
+        json = r#"{"id":1,"page":2}"#
+    );
+
+    // Keys are routed to flattened fields regardless of where they appear and
+    // keys which are unknown to everyone are skipped.
+    musli::assert_decode_eq!(
+        descriptive,
+        UsersFlat {
+            users: vec![String::from("Aristotle")],
+            page: 2,
+            per_page: 10,
+            total: 11,
+            unknown: 42,
+        },
+        Users {
+            users: vec![String::from("Aristotle")],
+            pagination: Pagination {
+                page: 2,
+                per_page: 10,
+            },
+            total: 11,
+        },
+        json = r#"{"users":["Aristotle"],"page":2,"per_page":10,"total":11,"unknown":42}"#,
+    );
+}
+
+#[test]
+fn skipped_flatten() {
+    musli::rt!(
+        descriptive,
+        Conditional {
+            id: 1,
+            filter: Filter {
+                tags: vec![String::from("greek")],
+            },
+        },
+        json = r#"{"id":1,"tags":["greek"]}"#
+    );
+
+    musli::rt!(
+        descriptive,
+        Conditional {
+            id: 1,
+            filter: Filter { tags: Vec::new() },
+        },
+        json = r#"{"id":1}"#
+    );
+}
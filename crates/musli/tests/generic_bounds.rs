@@ -1,3 +1,5 @@
+#![cfg(feature = "test")]
+
 use musli::mode::{Binary, Text};
 use musli::{Decode, Encode};
 
@@ -17,3 +19,78 @@ fn generic_with_bound() {
         }
     );
 }
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct Wrapper<T> {
+    items: Vec<(T, Option<T>)>,
+}
+
+#[test]
+fn inferred_bounds() {
+    musli::rt!(
+        full,
+        Wrapper {
+            items: vec![(1u32, Some(2u32)), (3, None)],
+        }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+    Both(L, Option<R>),
+}
+
+#[test]
+fn inferred_enum_bounds() {
+    musli::rt!(full, Either::<u8, String>::Left(1));
+    musli::rt!(
+        full,
+        Either::<u8, String>::Right {
+            value: String::from("Hello"),
+        }
+    );
+    musli::rt!(full, Either::<u8, String>::Both(2, None));
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[test]
+fn inferred_recursive_bounds() {
+    musli::rt!(
+        full,
+        Tree {
+            value: String::from("root"),
+            children: vec![Tree {
+                value: String::from("leaf"),
+                children: Vec::new(),
+            }],
+        }
+    );
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+struct Private<T>(T);
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode)]
+pub struct WithPrivate<T> {
+    inner: Private<T>,
+    #[musli(bytes)]
+    bytes: [T; 4],
+}
+
+#[test]
+fn inferred_private_field_bounds() {
+    musli::rt!(
+        full,
+        WithPrivate {
+            inner: Private(7u8),
+            bytes: [1, 2, 3, 4],
+        }
+    );
+}
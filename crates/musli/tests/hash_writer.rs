@@ -0,0 +1,92 @@
+#![cfg(feature = "test")]
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+use musli::writer::HashWriter;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Message {
+    id: u64,
+    topic: String,
+    payload: Vec<u8>,
+    tags: Vec<String>,
+    priority: Option<i16>,
+}
+
+/// Hasher which records every byte written to it.
+#[derive(Default)]
+struct Recording {
+    bytes: Vec<u8>,
+}
+
+impl Hasher for Recording {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+}
+
+fn message() -> Message {
+    Message {
+        id: 0xdead_beef,
+        topic: String::from("events"),
+        payload: (0..=255).collect(),
+        tags: vec![String::from("a"), String::from("bc")],
+        priority: Some(-4),
+    }
+}
+
+fn default_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+macro_rules! test_hash {
+    ($name:ident, $what:ident) => {
+        #[test]
+        fn $name() {
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            let message = message();
+            let bytes = ENCODING.to_vec(&message).unwrap();
+
+            let mut recording = Recording::default();
+            ENCODING
+                .encode(HashWriter::new(&mut recording), &message)
+                .unwrap();
+            assert_eq!(recording.bytes, bytes);
+
+            let mut hasher = DefaultHasher::new();
+            ENCODING.hash(&message, &mut hasher).unwrap();
+            assert_eq!(hasher.finish(), default_hash(&bytes));
+        }
+    };
+}
+
+test_hash!(storage_hash, storage);
+test_hash!(wire_hash, wire);
+test_hash!(descriptive_hash, descriptive);
+test_hash!(json_hash, json);
+
+#[test]
+fn different_values_different_hashes() {
+    const ENCODING: musli::storage::Encoding = musli::storage::Encoding::new();
+
+    let a = message();
+    let mut b = message();
+    b.priority = None;
+
+    let mut hasher_a = DefaultHasher::new();
+    ENCODING.hash(&a, &mut hasher_a).unwrap();
+
+    let mut hasher_b = DefaultHasher::new();
+    ENCODING.hash(&b, &mut hasher_b).unwrap();
+
+    assert_ne!(hasher_a.finish(), hasher_b.finish());
+}
@@ -0,0 +1,154 @@
+//! Error reporting for heapless containers. That these build without `std`
+//! or `alloc` is checked by the `heapless` example in the `no-std` crate.
+
+#![cfg(feature = "test")]
+
+use heapless::{FnvIndexMap, String, Vec};
+use musli::allocator::{Stack, StackBuffer};
+use musli::context::StackContext;
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Sensor {
+    name: String<16>,
+    readings: Vec<u32, 4>,
+    labels: FnvIndexMap<String<8>, u8, 4>,
+}
+
+fn sensor() -> Sensor {
+    let mut labels = FnvIndexMap::new();
+    labels.insert(String::try_from("room").unwrap(), 1).unwrap();
+    labels
+        .insert(String::try_from("floor").unwrap(), 2)
+        .unwrap();
+
+    Sensor {
+        name: String::try_from("thermometer").unwrap(),
+        readings: Vec::from_slice(&[20, 21, 19]).unwrap(),
+        labels,
+    }
+}
+
+/// Encode with the storage format into a fixed buffer, returning the number
+/// of bytes written.
+fn storage_encode<T>(value: &T, out: &mut [u8]) -> usize
+where
+    T: ?Sized + Encode<Binary>,
+{
+    let mut buf = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx = StackContext::new(&alloc);
+
+    let capacity = out.len();
+    let mut writer = &mut out[..];
+
+    musli::storage::Encoding::new()
+        .encode_with(&cx, &mut writer, value)
+        .unwrap();
+
+    capacity - writer.len()
+}
+
+/// Decode with the storage format, returning the error report on failure.
+fn storage_decode<T>(bytes: &[u8]) -> Result<T, std::string::String>
+where
+    T: for<'de> Decode<'de, Binary>,
+{
+    let mut buf = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx = StackContext::new(&alloc);
+
+    musli::storage::Encoding::new()
+        .from_slice_with(&cx, bytes)
+        .map_err(|_| cx.report().to_string())
+}
+
+/// Decode with the json format, returning the error report on failure.
+fn json_decode<T>(string: &str) -> Result<T, std::string::String>
+where
+    T: for<'de> Decode<'de, Text>,
+{
+    let mut buf = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx = StackContext::new(&alloc);
+
+    musli::json::Encoding::new()
+        .from_str_with(&cx, string)
+        .map_err(|_| cx.report().to_string())
+}
+
+#[test]
+fn storage_roundtrip() {
+    let value = sensor();
+
+    let mut out = [0u8; 128];
+    let len = storage_encode(&value, &mut out);
+    assert_eq!(storage_decode::<Sensor>(&out[..len]), Ok(value));
+}
+
+#[test]
+fn json_roundtrip() {
+    let value = sensor();
+
+    let mut buf = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx = StackContext::new(&alloc);
+
+    let mut out = [0u8; 128];
+    let mut writer = &mut out[..];
+
+    musli::json::Encoding::new()
+        .encode_with(&cx, &mut writer, &value)
+        .unwrap();
+
+    let len = 128 - writer.len();
+    let json = core::str::from_utf8(&out[..len]).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"name":"thermometer","readings":[20,21,19],"labels":{"room":1,"floor":2}}"#
+    );
+
+    assert_eq!(json_decode::<Sensor>(json), Ok(value));
+}
+
+#[test]
+fn over_capacity() {
+    let mut out = [0u8; 128];
+
+    let len = storage_encode(&[1u32, 2, 3][..], &mut out);
+
+    assert_eq!(
+        storage_decode::<Vec<u32, 2>>(&out[..len]),
+        Err(std::string::String::from(
+            ": Sequence of 3 elements exceeds the heapless::Vec capacity of 2 (at byte 1)\n"
+        ))
+    );
+
+    assert_eq!(
+        json_decode::<Vec<u32, 2>>("[1, 2, 3]"),
+        Err(std::string::String::from(
+            "[2]: Sequence exceeds the heapless::Vec capacity of 2 (at byte 8)\n"
+        ))
+    );
+
+    assert_eq!(
+        json_decode::<String<4>>(r#""hello""#),
+        Err(std::string::String::from(
+            ": String of 5 bytes exceeds the heapless::String capacity of 4 (at byte 7)\n"
+        ))
+    );
+
+    assert_eq!(
+        json_decode::<FnvIndexMap<u32, u32, 2>>(r#"{"1": 1, "2": 2, "3": 3}"#),
+        Err(std::string::String::from(
+            ": Map exceeds the heapless::IndexMap capacity of 2 (at byte 26)\n"
+        ))
+    );
+
+    // Keys which are already present don't need more capacity.
+    let map = json_decode::<FnvIndexMap<u32, u32, 2>>(r#"{"1": 1, "2": 2, "1": 3}"#).unwrap();
+    assert_eq!(map.get(&1), Some(&3));
+    assert_eq!(map.get(&2), Some(&2));
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "test")]
+
+use arrayvec::{ArrayString, ArrayVec};
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+use smallvec::{smallvec, SmallVec};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Inline {
+    small: SmallVec<[u32; 4]>,
+    array: ArrayVec<String, 4>,
+    string: ArrayString<8>,
+}
+
+#[derive(Encode)]
+pub struct PackedArrayVec {
+    #[musli(packed)]
+    value: ArrayVec<u8, 3>,
+}
+
+#[derive(Encode)]
+pub struct PackedArray {
+    #[musli(packed)]
+    value: [u8; 3],
+}
+
+#[test]
+fn smallvec() {
+    let inline: SmallVec<[u32; 4]> = smallvec![1, 2, 3];
+    let value = musli::rt!(full, inline.clone(), json = "[1,2,3]");
+    assert!(!value.spilled());
+
+    let spilled: SmallVec<[u32; 4]> = (0..10).collect();
+    let value = musli::rt!(full, spilled.clone(), json = "[0,1,2,3,4,5,6,7,8,9]");
+    assert!(value.spilled());
+
+    // Encoded the same way as a vector.
+    assert_eq!(
+        musli::storage::to_vec(&spilled).unwrap(),
+        musli::storage::to_vec(&spilled.to_vec()).unwrap()
+    );
+}
+
+#[test]
+fn arrayvec() {
+    let mut array = ArrayVec::<String, 4>::new();
+    array.push(String::from("a"));
+    array.push(String::from("b"));
+    musli::rt!(full, array.clone(), json = r#"["a","b"]"#);
+
+    musli::rt!(
+        full,
+        ArrayString::<8>::from("hello").unwrap(),
+        json = r#""hello""#
+    );
+
+    musli::rt!(
+        full,
+        Inline {
+            small: smallvec![1, 2, 3, 4, 5],
+            array: ArrayVec::from([
+                String::from("a"),
+                String::from("b"),
+                String::from("c"),
+                String::from("d"),
+            ]),
+            string: ArrayString::from("12345678").unwrap(),
+        }
+    );
+}
+
+#[test]
+fn packed() {
+    // A full ArrayVec is packed the same way as an array.
+    assert_eq!(
+        musli::storage::to_vec(&PackedArrayVec {
+            value: ArrayVec::from([1, 2, 3]),
+        })
+        .unwrap(),
+        musli::storage::to_vec(&PackedArray { value: [1, 2, 3] }).unwrap()
+    );
+}
+
+/// Decode the output of encoding `T` as `U`, returning the error report on
+/// failure.
+fn binary<T, U>(value: &T) -> Result<U, String>
+where
+    T: Encode<Binary>,
+    U: for<'de> Decode<'de, Binary>,
+{
+    let encoding = musli::storage::Encoding::new();
+    let bytes = encoding.to_vec(value).unwrap();
+
+    let alloc = System::new();
+    let cx = SystemContext::<_, Binary>::new(&alloc);
+    encoding
+        .from_slice_with(&cx, &bytes)
+        .map_err(|_| cx.report().to_string())
+}
+
+fn json<U>(input: &str) -> Result<U, String>
+where
+    U: for<'de> Decode<'de, Text>,
+{
+    let encoding = musli::json::Encoding::new();
+
+    let alloc = System::new();
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    encoding
+        .from_str_with(&cx, input)
+        .map_err(|_| cx.report().to_string())
+}
+
+#[test]
+fn over_capacity() {
+    assert_eq!(
+        binary::<_, ArrayVec<u32, 2>>(&vec![1u32, 2, 3]),
+        Err(String::from(
+            ": Sequence of 3 elements exceeds the ArrayVec capacity of 2 (at byte 1)\n"
+        ))
+    );
+
+    assert_eq!(
+        json::<ArrayVec<u32, 2>>("[1, 2, 3]"),
+        Err(String::from(
+            "[2]: Sequence exceeds the ArrayVec capacity of 2 (at byte 7)\n"
+        ))
+    );
+
+    assert_eq!(
+        binary::<_, ArrayString<4>>(&"hello"),
+        Err(String::from(
+            ": String of 5 bytes exceeds the ArrayString capacity of 4 (at byte 6)\n"
+        ))
+    );
+
+    assert_eq!(
+        json::<ArrayString<4>>(r#""hello""#),
+        Err(String::from(
+            ": String of 5 bytes exceeds the ArrayString capacity of 4 (at byte 7)\n"
+        ))
+    );
+
+    assert_eq!(
+        binary::<_, ArrayVec<u32, 3>>(&vec![1u32, 2, 3]),
+        Ok(ArrayVec::from([1, 2, 3]))
+    );
+}
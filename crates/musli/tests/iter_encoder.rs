@@ -0,0 +1,91 @@
+#![cfg(feature = "test")]
+
+use musli::en::IterEncoder;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Row {
+    id: u32,
+    name: String,
+}
+
+fn rows() -> Vec<Row> {
+    (0..5)
+        .map(|id| Row {
+            id,
+            name: format!("row{id}"),
+        })
+        .collect()
+}
+
+macro_rules! test_iter {
+    ($name:ident, $what:ident) => {
+        mod $name {
+            use super::*;
+
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            #[test]
+            fn exact_size() {
+                let expected = rows();
+
+                let iter = IterEncoder::new(expected.iter());
+                assert_eq!(iter.known_len(), Some(5));
+
+                let bytes = ENCODING.to_vec(&iter).unwrap();
+                assert_eq!(bytes, ENCODING.to_vec(&expected).unwrap());
+
+                let actual: Vec<Row> = ENCODING.from_slice(&bytes).unwrap();
+                assert_eq!(actual, expected);
+            }
+
+            #[test]
+            fn unknown_size() {
+                let expected = rows();
+
+                let iter = IterEncoder::new(expected.iter().filter(|row| row.id % 2 == 0));
+                assert_eq!(iter.known_len(), None);
+
+                let bytes = ENCODING.to_vec(&iter).unwrap();
+
+                let actual: Vec<Row> = ENCODING.from_slice(&bytes).unwrap();
+                let ids = actual.iter().map(|row| row.id).collect::<Vec<_>>();
+                assert_eq!(ids, [0, 2, 4]);
+                assert_eq!(actual[1].name, "row2");
+            }
+
+            #[test]
+            fn with_len() {
+                let expected = rows();
+
+                let iter = IterEncoder::with_len(expected.iter().filter(|row| row.id < 3), 3);
+
+                let bytes = ENCODING.to_vec(&iter).unwrap();
+
+                let actual: Vec<Row> = ENCODING.from_slice(&bytes).unwrap();
+                assert_eq!(actual, &expected[..3]);
+            }
+
+            #[test]
+            fn with_len_mismatch() {
+                let too_few = IterEncoder::with_len(0..2u32, 3);
+                assert!(ENCODING.to_vec(&too_few).is_err());
+
+                let too_many = IterEncoder::with_len(0..4u32, 3);
+                assert!(ENCODING.to_vec(&too_many).is_err());
+            }
+
+            #[test]
+            fn encode_twice() {
+                let iter = IterEncoder::new(0..3u32);
+                assert!(ENCODING.to_vec(&iter).is_ok());
+                assert!(ENCODING.to_vec(&iter).is_err());
+            }
+        }
+    };
+}
+
+test_iter!(storage, storage);
+test_iter!(wire, wire);
+test_iter!(descriptive, descriptive);
+test_iter!(json, json);
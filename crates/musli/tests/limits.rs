@@ -0,0 +1,180 @@
+#![cfg(feature = "test")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use musli::allocator::System;
+use musli::context::{Limits, SystemContext};
+use musli::en::IterEncoder;
+use musli::mode::Binary;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Payload {
+    values: Vec<u64>,
+}
+
+/// Encode a sequence header which claims to have `len` elements, without
+/// any elements following it.
+macro_rules! claim_sequence {
+    ($encoding:expr, $len:expr) => {{
+        let mut out = Vec::new();
+        let iter = IterEncoder::with_len(core::iter::empty::<u64>(), $len);
+        // Errors since the iterator is empty, but the header has been written.
+        assert!($encoding.encode(&mut out, &iter).is_err());
+        out
+    }};
+}
+
+macro_rules! test_limits {
+    ($name:ident, $what:ident) => {
+        mod $name {
+            use super::*;
+
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            fn decode<T>(limits: Limits, bytes: &[u8]) -> Result<T, String>
+            where
+                T: for<'de> Decode<'de, Binary>,
+            {
+                let alloc = System::new();
+                let mut cx = SystemContext::new(&alloc);
+                cx.with_limits(limits);
+
+                match ENCODING.from_slice_with(&cx, bytes) {
+                    Ok(value) => Ok(value),
+                    Err(..) => {
+                        let error = cx.errors().next().expect("missing error");
+                        Err(format!("{}: {}", error.path_display(), error.error()))
+                    }
+                }
+            }
+
+            #[test]
+            fn adversarial_sequence_len() {
+                let bytes = claim_sequence!(ENCODING, 1 << 60);
+
+                let limits = Limits::new().max_sequence_len(1024);
+                let error = decode::<Vec<u64>>(limits, &bytes).unwrap_err();
+
+                assert_eq!(
+                    error,
+                    format!(
+                        ": Sequence length {} exceeds the maximum of 1024",
+                        1u64 << 60
+                    )
+                );
+            }
+
+            #[test]
+            fn adversarial_total_elements() {
+                let bytes = claim_sequence!(ENCODING, 1 << 60);
+
+                let limits = Limits::new().max_total_elements(0);
+                let error = decode::<Vec<Vec<u64>>>(limits, &bytes).unwrap_err();
+
+                assert_eq!(
+                    error,
+                    "[0]: Decoding more than the maximum of 0 elements in total"
+                );
+            }
+
+            #[test]
+            fn sequence_len() {
+                let payload = Payload {
+                    values: (0..16).collect(),
+                };
+
+                let bytes = ENCODING.to_vec(&payload).unwrap();
+
+                let limits = Limits::new().max_sequence_len(16);
+                assert_eq!(decode::<Payload>(limits, &bytes).unwrap(), payload);
+
+                let limits = Limits::new().max_sequence_len(15);
+                let error = decode::<Payload>(limits, &bytes).unwrap_err();
+                assert_eq!(
+                    error,
+                    ".values: Sequence length 16 exceeds the maximum of 15"
+                );
+            }
+
+            #[test]
+            fn map_len() {
+                let hash_map = (0..8u32).map(|n| (n, n)).collect::<HashMap<_, _>>();
+                let btree_map = (0..8u32).map(|n| (n, n)).collect::<BTreeMap<_, _>>();
+
+                let limits = Limits::new().max_map_len(8);
+
+                let bytes = ENCODING.to_vec(&hash_map).unwrap();
+                assert_eq!(
+                    decode::<HashMap<u32, u32>>(limits, &bytes).unwrap(),
+                    hash_map
+                );
+
+                let bytes = ENCODING.to_vec(&btree_map).unwrap();
+                assert_eq!(
+                    decode::<BTreeMap<u32, u32>>(limits, &bytes).unwrap(),
+                    btree_map
+                );
+
+                let limits = Limits::new().max_map_len(7);
+
+                let bytes = ENCODING.to_vec(&hash_map).unwrap();
+                let error = decode::<HashMap<u32, u32>>(limits, &bytes).unwrap_err();
+                assert_eq!(error, ": Map length 8 exceeds the maximum of 7");
+
+                let bytes = ENCODING.to_vec(&btree_map).unwrap();
+                let error = decode::<BTreeMap<u32, u32>>(limits, &bytes).unwrap_err();
+                assert_eq!(error, ": Map length 8 exceeds the maximum of 7");
+            }
+
+            #[test]
+            fn bytes_len() {
+                let limits = Limits::new().max_bytes_len(4);
+
+                let bytes = ENCODING.to_vec("abcd").unwrap();
+                assert_eq!(decode::<String>(limits, &bytes).unwrap(), "abcd");
+
+                let bytes = ENCODING.to_vec("abcde").unwrap();
+                let error = decode::<String>(limits, &bytes).unwrap_err();
+                assert_eq!(error, ": Length of 5 bytes exceeds the maximum of 4");
+            }
+
+            #[test]
+            fn total_elements_across_nested() {
+                let value: Vec<Vec<u32>> = vec![vec![1, 2], vec![3, 4], vec![5]];
+                let bytes = ENCODING.to_vec(&value).unwrap();
+
+                // 3 outer elements and 5 inner elements.
+                let limits = Limits::new().max_total_elements(8);
+                assert_eq!(decode::<Vec<Vec<u32>>>(limits, &bytes).unwrap(), value);
+
+                let limits = Limits::new().max_total_elements(7);
+                let error = decode::<Vec<Vec<u32>>>(limits, &bytes).unwrap_err();
+                assert_eq!(
+                    error,
+                    "[2][0]: Decoding more than the maximum of 7 elements in total"
+                );
+            }
+        }
+    };
+}
+
+test_limits!(storage, storage);
+test_limits!(wire, wire);
+test_limits!(descriptive, descriptive);
+
+#[test]
+fn limits_reset_on_clear() {
+    const ENCODING: musli::storage::Encoding = musli::storage::Encoding::new();
+
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_limits(Limits::new().max_total_elements(4));
+
+    let bytes = ENCODING.to_vec(&vec![1u32, 2, 3]).unwrap();
+
+    for _ in 0..3 {
+        let value: Vec<u32> = ENCODING.from_slice_with(&cx, &bytes).unwrap();
+        assert_eq!(value, [1, 2, 3]);
+    }
+}
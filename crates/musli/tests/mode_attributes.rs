@@ -0,0 +1,114 @@
+use musli::options::{self, Integer, Options};
+use musli::{Decode, Encode};
+
+enum Public {}
+
+const PUBLIC: musli::json::Encoding<Public> = musli::json::Encoding::new().with_mode();
+const STORAGE: musli::storage::Encoding = musli::storage::Encoding::new();
+
+const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+const FIXED_STORAGE: musli::storage::Encoding<FIXED> =
+    musli::storage::Encoding::new().with_options();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Public, name_all = "name")]
+struct User {
+    #[musli(mode = Public, name = "userName")]
+    user_name: String,
+    #[musli(mode = Public, skip)]
+    internal_id: u32,
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Public, name_all = "name")]
+enum Event {
+    #[musli(mode = Public, name = "loggedIn", name_all = "name")]
+    LoggedIn { user: u32 },
+    #[musli(mode = Public, skip)]
+    Audit { user: u32 },
+}
+
+/// Packed in the binary mode, but a map with named fields in the text mode.
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Binary, packed)]
+struct Point {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[test]
+fn field_per_mode() {
+    let user = User {
+        user_name: String::from("jane"),
+        internal_id: 7,
+        age: 42,
+    };
+
+    let json = PUBLIC.to_string(&user).unwrap();
+    assert_eq!(json, r#"{"userName":"jane","age":42}"#);
+
+    let decoded: User = PUBLIC.from_slice(json.as_bytes()).unwrap();
+
+    assert_eq!(
+        decoded,
+        User {
+            user_name: String::from("jane"),
+            internal_id: 0,
+            age: 42,
+        }
+    );
+
+    let bytes = STORAGE.to_vec(&user).unwrap();
+    assert_eq!(bytes, [3, 0, 4, b'j', b'a', b'n', b'e', 1, 7, 2, 42]);
+
+    let decoded: User = STORAGE.from_slice(&bytes).unwrap();
+    assert_eq!(decoded, user);
+}
+
+#[test]
+fn variant_per_mode() {
+    let event = Event::LoggedIn { user: 1 };
+
+    let json = PUBLIC.to_string(&event).unwrap();
+    assert_eq!(json, r#"{"loggedIn":{"user":1}}"#);
+    assert_eq!(PUBLIC.from_slice::<Event>(json.as_bytes()).unwrap(), event);
+
+    let bytes = STORAGE.to_vec(&event).unwrap();
+    assert_eq!(STORAGE.from_slice::<Event>(&bytes).unwrap(), event);
+
+    let audit = Event::Audit { user: 2 };
+    assert!(PUBLIC.to_string(&audit).is_err());
+    assert!(PUBLIC
+        .from_slice::<Event>(br#"{"Audit":{"user":2}}"#)
+        .is_err());
+
+    let bytes = STORAGE.to_vec(&audit).unwrap();
+    assert_eq!(bytes, [1, 1, 0, 2]);
+    assert_eq!(STORAGE.from_slice::<Event>(&bytes).unwrap(), audit);
+}
+
+#[test]
+fn packed_per_mode() {
+    let point = Point {
+        x: 1.0,
+        y: -2.5,
+        z: 3.0,
+    };
+
+    let bytes = FIXED_STORAGE.to_vec(&point).unwrap();
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(&bytes[..4], 1.0f32.to_bits().to_le_bytes());
+    assert_eq!(FIXED_STORAGE.from_slice::<Point>(&bytes).unwrap(), point);
+
+    let bytes = STORAGE.to_vec(&point).unwrap();
+    assert_eq!(STORAGE.from_slice::<Point>(&bytes).unwrap(), point);
+
+    let bytes = musli::wire::to_vec(&point).unwrap();
+    assert_eq!(musli::wire::from_slice::<Point>(&bytes).unwrap(), point);
+
+    let json = musli::json::to_string(&point).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":-2.5,"z":3.0}"#);
+    assert_eq!(musli::json::from_str::<Point>(&json).unwrap(), point);
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "test")]
+//! Containers which match the names of fields and variants ignoring case.
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "camelCase", name_case_insensitive)]
+struct User {
+    user_name: String,
+    #[musli(alias = "years")]
+    age: u32,
+    status: Status,
+    role: Role,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_case_insensitive)]
+enum Status {
+    Active,
+    Suspended,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "camelCase", name_case_insensitive)]
+enum Role {
+    Guest,
+    Member { since: u32 },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "camelCase", name_case_insensitive, deny_unknown_fields)]
+struct Strict {
+    user_name: String,
+}
+
+fn user() -> User {
+    User {
+        user_name: String::from("Alice"),
+        age: 42,
+        status: Status::Active,
+        role: Role::Member { since: 2001 },
+    }
+}
+
+#[test]
+fn mixed_case_input() {
+    let inputs = [
+        r#"{"userName":"Alice","age":42,"status":"Active","role":{"member":{"since":2001}}}"#,
+        r#"{"UserName":"Alice","AGE":42,"status":"active","role":{"Member":{"SINCE":2001}}}"#,
+        r#"{"username":"Alice","Years":42,"STATUS":"ACTIVE","Role":{"MEMBER":{"Since":2001}}}"#,
+    ];
+
+    for input in inputs {
+        assert_eq!(
+            musli::json::from_str::<User>(input).unwrap(),
+            user(),
+            "{input}"
+        );
+    }
+
+    assert_eq!(
+        musli::json::from_str::<Role>(r#"{"GUEST":{}}"#).unwrap(),
+        Role::Guest
+    );
+}
+
+#[test]
+fn canonical_names() {
+    musli::rt!(
+        full,
+        user(),
+        json =
+            r#"{"userName":"Alice","age":42,"status":"Active","role":{"member":{"since":2001}}}"#
+    );
+}
+
+#[test]
+fn unknown_names() {
+    assert!(musli::json::from_str::<Status>(r#""Activ""#).is_err());
+    assert!(musli::json::from_str::<Strict>(r#"{"USERNAME":"Alice"}"#).is_ok());
+    assert!(musli::json::from_str::<Strict>(r#"{"user_name":"Alice"}"#).is_err());
+}
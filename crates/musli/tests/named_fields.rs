@@ -74,3 +74,67 @@ fn test_name_all() {
         json = r#"{"FIELD-NAME":42}"#,
     );
 }
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[musli(name_all = "camelCase")]
+struct CamelCaseWords {
+    http_server: i32,
+    field_1: i32,
+    sha256_sum: i32,
+    #[musli(name = "override")]
+    overridden_name: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[musli(name_all = "kebab-case")]
+struct KebabCaseWords {
+    http_server: i32,
+    field_1: i32,
+    sha256_sum: i32,
+    #[musli(name = "override")]
+    overridden_name: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[musli(name_all = "SCREAMING_SNAKE_CASE")]
+#[allow(non_snake_case)]
+struct ScreamingSnakeCaseWords {
+    HTTPServer: i32,
+    Field1: i32,
+    IOError: i32,
+}
+
+#[test]
+fn test_name_all_words() {
+    musli::rt!(
+        full,
+        CamelCaseWords {
+            http_server: 1,
+            field_1: 2,
+            sha256_sum: 3,
+            overridden_name: 4,
+        },
+        json = r#"{"httpServer":1,"field1":2,"sha256Sum":3,"override":4}"#,
+    );
+
+    musli::rt!(
+        full,
+        KebabCaseWords {
+            http_server: 1,
+            field_1: 2,
+            sha256_sum: 3,
+            overridden_name: 4,
+        },
+        json = r#"{"http-server":1,"field-1":2,"sha256-sum":3,"override":4}"#,
+    );
+
+    musli::rt!(
+        full,
+        ScreamingSnakeCaseWords {
+            HTTPServer: 1,
+            Field1: 2,
+            IOError: 3,
+        },
+        json = r#"{"HTTP_SERVER":1,"FIELD1":2,"IO_ERROR":3}"#,
+    );
+}
@@ -58,3 +58,36 @@ fn test_name_all() {
         json = r#""VARIANT-NAME""#,
     );
 }
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[musli(name_all = "snake_case")]
+enum SnakeCaseWords {
+    HTTPServer,
+    IOError,
+    Variant1,
+    #[musli(name = "override")]
+    OverriddenName,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[musli(name_all = "camelCase")]
+enum CamelCaseWords {
+    HTTPServer,
+    IOError,
+    Variant1,
+    #[musli(name = "override")]
+    OverriddenName,
+}
+
+#[test]
+fn test_name_all_words() {
+    musli::rt!(full, SnakeCaseWords::HTTPServer, json = r#""http_server""#);
+    musli::rt!(full, SnakeCaseWords::IOError, json = r#""io_error""#);
+    musli::rt!(full, SnakeCaseWords::Variant1, json = r#""variant1""#);
+    musli::rt!(full, SnakeCaseWords::OverriddenName, json = r#""override""#);
+
+    musli::rt!(full, CamelCaseWords::HTTPServer, json = r#""httpServer""#);
+    musli::rt!(full, CamelCaseWords::IOError, json = r#""ioError""#);
+    musli::rt!(full, CamelCaseWords::Variant1, json = r#""variant1""#);
+    musli::rt!(full, CamelCaseWords::OverriddenName, json = r#""override""#);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "test")]
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Peer {
+    ip: IpAddr,
+    addr: SocketAddr,
+}
+
+#[test]
+fn ip_addr() {
+    musli::rt!(full, Ipv4Addr::new(127, 0, 0, 1), json = r#""127.0.0.1""#);
+    musli::rt!(full, Ipv6Addr::LOCALHOST, json = r#""::1""#);
+    musli::rt!(
+        full,
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        json = r#""10.0.0.1""#
+    );
+    musli::rt!(
+        full,
+        IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)),
+        json = r#""fe80::1""#
+    );
+}
+
+#[test]
+fn socket_addr() {
+    musli::rt!(
+        full,
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080),
+        json = r#""127.0.0.1:8080""#
+    );
+
+    musli::rt!(
+        full,
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0),
+        json = r#""[::1]:443""#
+    );
+
+    musli::rt!(
+        full,
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 3),
+        json = r#""[::1%3]:443""#
+    );
+
+    musli::rt!(
+        full,
+        Peer {
+            ip: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+            addr: SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 0, 0)),
+        },
+        json = r#"{"ip":"192.168.0.1","addr":"[::1]:80"}"#
+    );
+}
+
+#[test]
+fn flowinfo() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 7, 3);
+
+    // Binary formats preserve the flowinfo, the textual representation does not.
+    let bytes = musli::storage::to_vec(&addr)?;
+    assert_eq!(musli::storage::from_slice::<SocketAddrV6>(&bytes)?, addr);
+
+    let json = musli::json::to_string(&addr)?;
+    assert_eq!(json, r#""[::1%3]:443""#);
+    assert_eq!(
+        musli::json::from_str::<SocketAddrV6>(&json)?,
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 3)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn malformed_text() {
+    assert!(musli::json::from_str::<Ipv4Addr>(r#""256.0.0.1""#).is_err());
+    assert!(musli::json::from_str::<Ipv4Addr>(r#""::1""#).is_err());
+    assert!(musli::json::from_str::<Ipv6Addr>(r#""[::1]""#).is_err());
+    assert!(musli::json::from_str::<IpAddr>(r#""localhost""#).is_err());
+    assert!(musli::json::from_str::<IpAddr>(r#"{"ipv4":"127.0.0.1"}"#).is_err());
+    assert!(musli::json::from_str::<SocketAddrV4>(r#""127.0.0.1""#).is_err());
+    assert!(musli::json::from_str::<SocketAddrV4>(r#""127.0.0.1:65536""#).is_err());
+    assert!(musli::json::from_str::<SocketAddrV6>(r#""::1:80""#).is_err());
+    assert!(musli::json::from_str::<SocketAddr>(r#""example.com:80""#).is_err());
+    assert!(musli::json::from_str::<SocketAddr>("42").is_err());
+}
@@ -0,0 +1,329 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::de::Coercion;
+use musli::mode::{Binary, Text};
+use musli::value::Value;
+use musli::{Decode, Encode};
+
+const POLICIES: [Coercion; 3] = [Coercion::Strict, Coercion::Widening, Coercion::Lenient];
+
+const DESCRIPTIVE: musli::descriptive::Encoding = musli::descriptive::Encoding::new();
+const JSON: musli::json::Encoding = musli::json::Encoding::new();
+
+/// Encode a value with the descriptive format.
+fn desc<T>(value: T) -> Vec<u8>
+where
+    T: Encode<Binary>,
+{
+    DESCRIPTIVE.to_vec(&value).unwrap()
+}
+
+/// Encode a value into a dynamic value.
+fn value<T>(value: T) -> Value
+where
+    T: Encode<Binary>,
+{
+    musli::value::encode(value).unwrap()
+}
+
+/// Decode descriptive bytes under every policy, in the order strict,
+/// widening and lenient.
+fn descriptive<T>(bytes: &[u8]) -> [Option<T>; 3]
+where
+    T: for<'de> Decode<'de, Binary>,
+{
+    POLICIES.map(|coercion| {
+        let alloc = System::new();
+        let mut cx = SystemContext::new(&alloc);
+        cx.with_coercion(coercion);
+        DESCRIPTIVE.from_slice_with(&cx, bytes).ok()
+    })
+}
+
+/// Decode JSON under every policy, in the order strict, widening and lenient.
+fn json<T>(string: &str) -> [Option<T>; 3]
+where
+    T: for<'de> Decode<'de, Text>,
+{
+    POLICIES.map(|coercion| {
+        let alloc = System::new();
+        let mut cx = SystemContext::new(&alloc);
+        cx.with_coercion(coercion);
+        JSON.from_str_with(&cx, string).ok()
+    })
+}
+
+/// Decode a dynamic value under every policy, in the order strict, widening
+/// and lenient.
+fn dynamic<T>(value: &Value) -> [Option<T>; 3]
+where
+    T: for<'de> Decode<'de, Binary>,
+{
+    POLICIES.map(|coercion| {
+        let alloc = System::new();
+        let mut cx = SystemContext::new(&alloc);
+        cx.with_coercion(coercion);
+        musli::value::decode_with(&cx, value).ok()
+    })
+}
+
+/// Check the outcome of decoding an input into each listed type under each
+/// policy, where `None` means that decoding errors.
+macro_rules! matrix {
+    ($decode:ident($input:expr) { $($ty:ty => [$strict:expr, $widening:expr, $lenient:expr]),* $(,)? }) => {
+        $(
+            assert_eq!(
+                $decode::<$ty>($input),
+                [$strict, $widening, $lenient],
+                "{} into {}",
+                stringify!($input),
+                stringify!($ty),
+            );
+        )*
+    };
+}
+
+#[test]
+fn descriptive_integers() {
+    matrix!(descriptive(&desc(1u8)) {
+        u8 => [Some(1), Some(1), Some(1)],
+        u64 => [None, Some(1), Some(1)],
+        i32 => [None, Some(1), Some(1)],
+        f64 => [None, Some(1.0), Some(1.0)],
+    });
+
+    matrix!(descriptive(&desc(300u64)) {
+        u8 => [None, None, None],
+        u64 => [Some(300), Some(300), Some(300)],
+        i16 => [None, Some(300), Some(300)],
+        f32 => [None, Some(300.0), Some(300.0)],
+    });
+
+    matrix!(descriptive(&desc(-1i32)) {
+        u32 => [None, None, None],
+        i32 => [Some(-1), Some(-1), Some(-1)],
+        i64 => [None, Some(-1), Some(-1)],
+        f64 => [None, Some(-1.0), Some(-1.0)],
+    });
+
+    // Integers which can't be represented exactly by the float.
+    matrix!(descriptive(&desc(u64::MAX)) {
+        f64 => [None, None, None],
+    });
+
+    matrix!(descriptive(&desc((1u32 << 24) + 1)) {
+        f32 => [None, None, None],
+        f64 => [None, Some(16777217.0), Some(16777217.0)],
+    });
+}
+
+#[test]
+fn descriptive_floats() {
+    matrix!(descriptive(&desc(1.0f64)) {
+        u8 => [None, None, Some(1)],
+        i32 => [None, None, Some(1)],
+        f32 => [None, Some(1.0), Some(1.0)],
+        f64 => [Some(1.0), Some(1.0), Some(1.0)],
+    });
+
+    matrix!(descriptive(&desc(-2.0f32)) {
+        u8 => [None, None, None],
+        i8 => [None, None, Some(-2)],
+        f64 => [None, Some(-2.0), Some(-2.0)],
+    });
+
+    matrix!(descriptive(&desc(1.5f64)) {
+        u8 => [None, None, None],
+        f32 => [None, Some(1.5), Some(1.5)],
+    });
+
+    matrix!(descriptive(&desc(1.1f64)) {
+        f32 => [None, None, None],
+    });
+
+    matrix!(descriptive(&desc(1e10f64)) {
+        u32 => [None, None, None],
+        u64 => [None, None, Some(10_000_000_000)],
+    });
+
+    matrix!(descriptive(&desc(f64::NAN)) {
+        u64 => [None, None, None],
+    });
+}
+
+#[test]
+fn json_numbers() {
+    matrix!(json("1") {
+        u8 => [Some(1), Some(1), Some(1)],
+        i32 => [Some(1), Some(1), Some(1)],
+        f64 => [Some(1.0), Some(1.0), Some(1.0)],
+    });
+
+    matrix!(json("300") {
+        u8 => [None, None, None],
+        u64 => [Some(300), Some(300), Some(300)],
+    });
+
+    matrix!(json("-1") {
+        u8 => [None, None, None],
+        i32 => [Some(-1), Some(-1), Some(-1)],
+    });
+
+    // Widening uses the full number syntax since the `test` feature enables
+    // `parse-full`.
+    matrix!(json("1.0") {
+        u8 => [None, Some(1), Some(1)],
+        i32 => [None, Some(1), Some(1)],
+        f64 => [Some(1.0), Some(1.0), Some(1.0)],
+    });
+
+    matrix!(json("1e2") {
+        u8 => [None, Some(100), Some(100)],
+        f32 => [Some(100.0), Some(100.0), Some(100.0)],
+    });
+
+    matrix!(json("1.5") {
+        u8 => [None, None, None],
+        f64 => [Some(1.5), Some(1.5), Some(1.5)],
+    });
+
+    matrix!(json("3e2") {
+        u8 => [None, None, None],
+    });
+}
+
+#[test]
+fn json_strings() {
+    matrix!(json("\"42\"") {
+        u8 => [None, None, Some(42)],
+        i64 => [None, None, Some(42)],
+        f64 => [None, None, Some(42.0)],
+    });
+
+    matrix!(json("\"-1.0\"") {
+        u8 => [None, None, None],
+        i32 => [None, None, Some(-1)],
+        f32 => [None, None, Some(-1.0)],
+    });
+
+    matrix!(json("\"300\"") {
+        u8 => [None, None, None],
+    });
+
+    matrix!(json("\"42 apples\"") {
+        u8 => [None, None, None],
+        f64 => [None, None, None],
+    });
+
+    matrix!(json("\"\"") {
+        u8 => [None, None, None],
+    });
+}
+
+#[test]
+fn value_numbers() {
+    matrix!(dynamic(&value(1u8)) {
+        u8 => [Some(1), Some(1), Some(1)],
+        u64 => [None, Some(1), Some(1)],
+        f64 => [None, Some(1.0), Some(1.0)],
+    });
+
+    matrix!(dynamic(&value(300u64)) {
+        u8 => [None, None, None],
+        i16 => [None, Some(300), Some(300)],
+    });
+
+    matrix!(dynamic(&value(-1i32)) {
+        u32 => [None, None, None],
+        i64 => [None, Some(-1), Some(-1)],
+    });
+
+    matrix!(dynamic(&value(1.0f64)) {
+        u8 => [None, None, Some(1)],
+        f32 => [None, Some(1.0), Some(1.0)],
+        f64 => [Some(1.0), Some(1.0), Some(1.0)],
+    });
+
+    matrix!(dynamic(&value(1.5f32)) {
+        i32 => [None, None, None],
+        f64 => [None, Some(1.5), Some(1.5)],
+    });
+
+    matrix!(dynamic(&value(1e3f64)) {
+        u8 => [None, None, None],
+        u16 => [None, None, Some(1000)],
+    });
+}
+
+#[test]
+fn value_compatibility() {
+    // Numbers used to be converted with `as` casts, which truncated floats
+    // and rounded integers which couldn't be represented exactly.
+    matrix!(dynamic(&value(1.5f64)) {
+        u32 => [None, None, None],
+        f64 => [Some(1.5), Some(1.5), Some(1.5)],
+    });
+
+    matrix!(dynamic(&value(u64::MAX)) {
+        f32 => [None, None, None],
+        u64 => [Some(u64::MAX), Some(u64::MAX), Some(u64::MAX)],
+    });
+
+    // The old behavior is kept by decoding the number as it was encoded and
+    // converting it.
+    let old = musli::value::decode::<f64>(&value(1.5f64)).unwrap() as u32;
+    assert_eq!(old, 1);
+
+    let old = musli::value::decode::<u64>(&value(u64::MAX)).unwrap() as f32;
+    assert_eq!(old, u64::MAX as f32);
+}
+
+#[test]
+fn value_strings() {
+    matrix!(dynamic(&value("42")) {
+        u8 => [None, None, Some(42)],
+        f64 => [None, None, Some(42.0)],
+    });
+
+    matrix!(dynamic(&value("300")) {
+        u8 => [None, None, None],
+    });
+
+    matrix!(dynamic(&value("forty-two")) {
+        u8 => [None, None, None],
+    });
+}
+
+#[test]
+fn errors() {
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_coercion(Coercion::Strict);
+
+    let bytes = desc(1u8);
+    assert!(DESCRIPTIVE.from_slice_with::<_, u64>(&cx, &bytes).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Expected u64, got u8 (at byte 1)\n"
+    );
+
+    cx.with_coercion(Coercion::Widening);
+
+    let bytes = desc(1.1f64);
+    assert!(DESCRIPTIVE.from_slice_with::<_, f32>(&cx, &bytes).is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Value of type f64 can't be represented exactly as f32 (at byte 10)\n"
+    );
+
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_coercion(Coercion::Lenient);
+
+    assert!(JSON.from_str_with::<_, u32>(&cx, "\"42 apples\"").is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Number in string \"42 apples\" is invalid or out of range (at bytes 0-11)\n"
+    );
+}
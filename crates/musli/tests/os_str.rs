@@ -0,0 +1,84 @@
+#![cfg(feature = "test")]
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use musli::{Decode, Encode};
+
+/// Mirrors the tagged platform representation used for `OsString` and
+/// `PathBuf`, so that data from a particular platform can be constructed
+/// regardless of which platform the test runs on.
+#[derive(Encode)]
+pub enum Platform {
+    #[musli(transparent)]
+    Unix(#[musli(bytes)] Vec<u8>),
+    #[musli(transparent)]
+    Windows(#[musli(bytes)] Vec<u8>),
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Paths {
+    path: PathBuf,
+    args: Vec<OsString>,
+}
+
+fn utf16(string: &str) -> Vec<u8> {
+    string.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+#[test]
+fn roundtrip() {
+    musli::rt!(
+        full,
+        Paths {
+            path: PathBuf::from("src").join("main.rs"),
+            args: vec![OsString::from("--verbose"), OsString::from("åäö")],
+        }
+    );
+}
+
+#[test]
+fn unix_tagged() {
+    let data = Platform::Unix(b"/var/log".to_vec());
+
+    let bytes = musli::storage::to_vec(&data).unwrap();
+    let result = musli::storage::from_slice::<PathBuf>(&bytes);
+
+    if cfg!(windows) {
+        assert!(result.is_err());
+    } else {
+        assert_eq!(result.unwrap(), PathBuf::from("/var/log"));
+    }
+}
+
+#[test]
+fn windows_tagged() {
+    let data = Platform::Windows(utf16("C:\\Windows"));
+
+    let bytes = musli::storage::to_vec(&data).unwrap();
+    let result = musli::storage::from_slice::<PathBuf>(&bytes);
+
+    if cfg!(unix) {
+        assert!(result.is_err());
+    } else {
+        assert_eq!(result.unwrap(), PathBuf::from("C:\\Windows"));
+    }
+}
+
+/// On platforms other than unix and windows, strings are converted to and from
+/// UTF-8.
+#[test]
+#[cfg(not(any(unix, windows)))]
+fn fallback() {
+    let bytes = musli::storage::to_vec(&Platform::Unix(vec![0xff, 0xfe])).unwrap();
+    assert!(musli::storage::from_slice::<OsString>(&bytes).is_err());
+
+    let bytes = musli::storage::to_vec(&Platform::Windows(vec![0x00, 0xd8])).unwrap();
+    assert!(musli::storage::from_slice::<OsString>(&bytes).is_err());
+
+    // Encoding uses the same representation as unix.
+    assert_eq!(
+        musli::storage::to_vec(&PathBuf::from("/var/log")).unwrap(),
+        musli::storage::to_vec(&Platform::Unix(b"/var/log".to_vec())).unwrap()
+    );
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::en::PackedSize;
+use musli::options::{self, Integer, Options};
+use musli::storage::Encoding;
+use musli::{Decode, Encode};
+
+const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+const ENCODING: Encoding<FIXED> = Encoding::new().with_options();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, packed_size)]
+struct Record {
+    id: u32,
+    flags: u8,
+    #[musli(packed)]
+    position: [f32; 3],
+    #[musli(packed)]
+    pair: (u16, i64),
+    #[musli(skip)]
+    cache: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, packed_size)]
+struct Nested {
+    record: Record,
+    enabled: bool,
+    letter: char,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, packed_size)]
+struct Unsized {
+    id: u32,
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, packed_size)]
+struct Prefixed {
+    // Arrays which aren't packed are prefixed with their length.
+    position: [f32; 3],
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed, packed_size)]
+struct Generic<T> {
+    value: T,
+    tail: u16,
+}
+
+const _: () = assert!(matches!(Record::PACKED_SIZE, Some(27)));
+const _: () = assert!(matches!(Nested::PACKED_SIZE, Some(32)));
+
+fn record(id: u32) -> Record {
+    Record {
+        id,
+        flags: 0b101,
+        position: [1.0, -2.5, id as f32],
+        pair: (7, -42),
+        cache: Vec::new(),
+    }
+}
+
+#[test]
+fn sizes() {
+    assert_eq!(Record::PACKED_SIZE, Some(27));
+    assert_eq!(Nested::PACKED_SIZE, Some(32));
+    assert_eq!(Unsized::PACKED_SIZE, None);
+    assert_eq!(Prefixed::PACKED_SIZE, None);
+    assert_eq!(Generic::<u64>::PACKED_SIZE, Some(10));
+    assert_eq!(Generic::<usize>::PACKED_SIZE, None);
+}
+
+#[test]
+fn matches_encoded_size() {
+    let bytes = ENCODING.to_vec(&record(1)).unwrap();
+    assert_eq!(Some(bytes.len()), Record::PACKED_SIZE);
+
+    let nested = Nested {
+        record: record(2),
+        enabled: true,
+        letter: 'ä',
+    };
+
+    let bytes = ENCODING.to_vec(&nested).unwrap();
+    assert_eq!(Some(bytes.len()), Nested::PACKED_SIZE);
+    assert_eq!(
+        ENCODING.from_packed_slice::<Nested>(&bytes).unwrap(),
+        nested
+    );
+}
+
+#[test]
+fn seek_records() {
+    let size = Record::PACKED_SIZE.unwrap();
+    let mut bytes = Vec::new();
+
+    for id in 0..16 {
+        ENCODING.to_writer(&mut bytes, &record(id)).unwrap();
+    }
+
+    assert_eq!(bytes.len(), size * 16);
+
+    for id in [0, 5, 15] {
+        let start = id as usize * size;
+        let decoded: Record = ENCODING.from_packed_slice(&bytes[start..]).unwrap();
+        assert_eq!(decoded, record(id));
+    }
+}
+
+#[test]
+fn errors() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let bytes = ENCODING.to_vec(&record(1)).unwrap();
+
+    assert!(ENCODING
+        .from_packed_slice_with::<_, Record>(&cx, &bytes[..10])
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Expected 27 bytes for packed value, but only 10 are available\n"
+    );
+
+    let unsized_value = Unsized {
+        id: 1,
+        name: String::from("Alice"),
+    };
+
+    let bytes = ENCODING.to_vec(&unsized_value).unwrap();
+    assert!(ENCODING
+        .from_packed_slice_with::<_, Unsized>(&cx, &bytes)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Type does not have a fixed packed size\n"
+    );
+
+    let variable = musli::storage::Encoding::new();
+    let bytes = variable.to_vec(&record(1)).unwrap();
+    assert!(variable
+        .from_packed_slice_with::<_, Record>(&cx, &bytes)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Packed values can only be decoded with fixed integers\n"
+    );
+}
+
+/// A type which misreports its size, which is caught when decoding.
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Misreported {
+    value: u32,
+}
+
+impl PackedSize for Misreported {
+    const PACKED_SIZE: Option<usize> = Some(6);
+}
+
+#[test]
+fn misreported_size() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let mut bytes = ENCODING.to_vec(&Misreported { value: 1 }).unwrap();
+    ENCODING.to_writer(&mut bytes, &2u16).unwrap();
+
+    assert!(ENCODING
+        .from_packed_slice_with::<_, Misreported>(&cx, &bytes)
+        .is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ": Packed value used 4 bytes, but expected 6 (at byte 4)\n"
+    );
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "test")]
+
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use musli::json::Encoding;
+use musli::{Decode, Encode};
+
+enum Alt {}
+
+const ALT: Encoding<Alt> = Encoding::new().with_mode();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Alt, name_all = "name")]
+pub struct AltWindow {
+    span: Range<u32>,
+    inclusive: RangeInclusive<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Window {
+    span: Range<u64>,
+    lower: Bound<u64>,
+    upper: Bound<u64>,
+}
+
+#[test]
+fn ranges() {
+    musli::rt!(full, 1u64..5, json = r#"{"start":1,"end":5}"#);
+    musli::rt!(full, 1u32..=5, json = r#"{"start":1,"end":5}"#);
+    musli::rt!(full, 3u32.., json = r#"{"start":3}"#);
+    musli::rt!(full, ..3u32, json = r#"{"end":3}"#);
+    musli::rt!(full, ..=3u32, json = r#"{"end":3}"#);
+    musli::rt!(full, RangeFull, json = r#"{}"#);
+
+    musli::rt!(
+        full,
+        String::from("a")..String::from("z"),
+        json = r#"{"start":"a","end":"z"}"#
+    );
+}
+
+#[test]
+fn binary_ranges() {
+    // Ranges are encoded as a sequence of their bounds in the binary mode.
+    let range = musli::storage::to_vec(&(1u32..5)).unwrap();
+    assert_eq!(range, musli::storage::to_vec(&(1u32, 5u32)).unwrap());
+    assert_eq!(
+        musli::storage::from_slice::<Range<u32>>(&range).unwrap(),
+        1..5
+    );
+
+    let range = musli::storage::to_vec(&(1u32..=5)).unwrap();
+    assert_eq!(range, musli::storage::to_vec(&(1u32, 5u32)).unwrap());
+    assert_eq!(
+        musli::storage::from_slice::<RangeInclusive<u32>>(&range).unwrap(),
+        1..=5
+    );
+}
+
+#[test]
+fn custom_mode_ranges() {
+    // Modes other than text encode ranges as a sequence of their bounds.
+    let window = AltWindow {
+        span: 1..5,
+        inclusive: 2..=3,
+    };
+
+    let json = ALT.to_string(&window).unwrap();
+    assert_eq!(json, r#"{"span":[1,5],"inclusive":[2,3]}"#);
+    assert_eq!(ALT.from_str::<AltWindow>(&json).unwrap(), window);
+
+    assert_eq!(ALT.to_string(&RangeFull).unwrap(), "[]");
+    assert_eq!(ALT.to_string(&(..3u32)).unwrap(), "[3]");
+    assert_eq!(ALT.from_str::<RangeFrom<u32>>("[3]").unwrap(), 3..);
+}
+
+#[test]
+fn bounds() {
+    musli::rt!(full, Bound::Included(1u32), json = r#"{"Included":1}"#);
+    musli::rt!(full, Bound::Excluded(1u32), json = r#"{"Excluded":1}"#);
+    musli::rt!(
+        full,
+        Bound::<u32>::Unbounded,
+        json = r#"{"Unbounded":null}"#
+    );
+
+    musli::rt!(
+        full,
+        Window {
+            span: 10..20,
+            lower: Bound::Included(10),
+            upper: Bound::Unbounded,
+        },
+        json =
+            r#"{"span":{"start":10,"end":20},"lower":{"Included":10},"upper":{"Unbounded":null}}"#
+    );
+}
+
+#[test]
+fn exhausted_inclusive() {
+    let mut range = 1u32..=1;
+    assert_eq!(range.next(), Some(1));
+    assert!(range.is_empty());
+
+    // Only the bounds are preserved.
+    let json = musli::json::to_string(&range).unwrap();
+    assert_eq!(json, r#"{"start":1,"end":1}"#);
+    let decoded = musli::json::from_str::<RangeInclusive<u32>>(&json).unwrap();
+    assert_eq!(decoded, 1..=1);
+    assert!(!decoded.is_empty());
+}
+
+#[test]
+fn malformed() {
+    assert!(musli::json::from_str::<Range<u32>>(r#"{"start":1}"#).is_err());
+    assert!(musli::json::from_str::<RangeInclusive<u32>>(r#"{"end":1}"#).is_err());
+    assert!(musli::json::from_str::<RangeFrom<u32>>(r#"{}"#).is_err());
+    assert!(musli::json::from_str::<RangeTo<u32>>(r#"[1]"#).is_err());
+    assert!(musli::json::from_str::<RangeToInclusive<u32>>(r#"{"start":1}"#).is_err());
+    assert!(musli::json::from_str::<Bound<u32>>(r#"{"Between":1}"#).is_err());
+
+    // Unknown fields are ignored.
+    assert_eq!(
+        musli::json::from_str::<Range<u32>>(r#"{"start":1,"step":2,"end":3}"#).unwrap(),
+        1..3
+    );
+}
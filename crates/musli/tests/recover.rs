@@ -0,0 +1,148 @@
+#![cfg(feature = "test")]
+//! Containers which recover from errors in their fields when decoding.
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::Decode;
+
+#[derive(Debug, Default, PartialEq, Decode)]
+#[musli(recover)]
+struct Server {
+    host: String,
+    timeout: u32,
+}
+
+#[derive(Debug, Default, PartialEq, Decode)]
+#[musli(recover)]
+struct Config {
+    name: String,
+    port: u16,
+    #[musli(default = default_retries)]
+    retries: u32,
+    server: Server,
+    tags: Vec<String>,
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+#[derive(Debug, PartialEq, Decode)]
+#[musli(recover)]
+enum Message {
+    Move { x: i32, y: i32 },
+}
+
+const INPUT: &[u8] = br#"{
+    "name": "app",
+    "port": 70000,
+    "retries": "many",
+    "server": {"host": "localhost", "timeout": -1},
+    "tags": ["a", "b"]
+}"#;
+
+fn expected() -> Config {
+    Config {
+        name: String::from("app"),
+        port: 0,
+        retries: 3,
+        server: Server {
+            host: String::from("localhost"),
+            timeout: 0,
+        },
+        tags: vec![String::from("a"), String::from("b")],
+    }
+}
+
+#[test]
+fn collect_errors() {
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_recovery(true);
+
+    let config = musli::json::Encoding::new()
+        .from_slice_with::<_, Config>(&cx, INPUT)
+        .unwrap();
+
+    assert_eq!(config, expected());
+
+    let paths = cx
+        .errors()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(paths.len(), 3, "{paths:?}");
+    assert!(paths[0].starts_with(".port: "), "{paths:?}");
+    assert!(paths[1].starts_with(".retries: "), "{paths:?}");
+    assert!(paths[2].starts_with(".server.timeout: "), "{paths:?}");
+}
+
+#[test]
+fn collect_errors_in_place() {
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_recovery(true);
+
+    let mut config = Config::default();
+
+    musli::json::Encoding::new()
+        .from_slice_into_with(&cx, INPUT, &mut config)
+        .unwrap();
+
+    assert_eq!(config, expected());
+    assert_eq!(cx.errors().count(), 3, "{}", cx.report());
+}
+
+#[test]
+fn missing_fields() {
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_recovery(true);
+
+    let server = musli::json::Encoding::new()
+        .from_slice_with::<_, Server>(&cx, br#"{"host": "localhost"}"#)
+        .unwrap();
+
+    assert_eq!(
+        server,
+        Server {
+            host: String::from("localhost"),
+            timeout: 0,
+        }
+    );
+
+    assert_eq!(cx.errors().count(), 1, "{}", cx.report());
+}
+
+#[test]
+fn variants() {
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_recovery(true);
+
+    let message = musli::json::Encoding::new()
+        .from_slice_with::<_, Message>(&cx, br#"{"Move": {"x": 1, "y": true}}"#)
+        .unwrap();
+
+    assert_eq!(message, Message::Move { x: 1, y: 0 });
+
+    let paths = cx
+        .errors()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(paths.len(), 1, "{paths:?}");
+    assert!(paths[0].starts_with("Move { .y }: "), "{paths:?}");
+}
+
+#[test]
+fn without_recovery() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    assert!(musli::json::Encoding::new()
+        .from_slice_with::<_, Config>(&cx, INPUT)
+        .is_err());
+
+    assert_eq!(cx.errors().count(), 1, "{}", cx.report());
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "test")]
+
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+/// Types which are assumed to be defined in a different crate.
+mod foreign {
+    #[derive(Debug, PartialEq)]
+    pub struct Vec3 {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Pair<T>(pub T, pub T);
+
+    #[derive(Debug, PartialEq)]
+    pub enum Shape {
+        Point,
+        Circle { radius: f32 },
+        Line(super::foreign::Vec3, super::foreign::Vec3),
+        Unknown,
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = foreign::Vec3)]
+#[allow(dead_code)]
+struct Vec3Def {
+    x: f32,
+    #[musli(mode = Text, name = "why")]
+    y: f32,
+    z: f32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = foreign::Pair<T>)]
+#[musli(mode = Binary, bound = {T: Encode<Binary>}, decode_bound = {T: Decode<'de, Binary>})]
+#[musli(mode = Text, bound = {T: Encode<Text>}, decode_bound = {T: Decode<'de, Text>})]
+struct PairDef<T>(T, T);
+
+#[derive(Encode, Decode)]
+#[musli(remote = foreign::Shape, name_all = "name")]
+#[allow(dead_code)]
+enum ShapeDef {
+    Point,
+    Circle {
+        radius: f32,
+    },
+    Line(
+        #[musli(with = Vec3Def)] foreign::Vec3,
+        #[musli(with = Vec3Def)] foreign::Vec3,
+    ),
+    #[musli(other)]
+    Unknown,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Scene {
+    #[musli(with = Vec3Def)]
+    origin: foreign::Vec3,
+    #[musli(with = PairDef)]
+    range: foreign::Pair<u32>,
+    #[musli(with = ShapeDef)]
+    shape: foreign::Shape,
+}
+
+#[test]
+fn remote_struct() {
+    musli::rt!(
+        full,
+        Scene {
+            origin: foreign::Vec3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+            },
+            range: foreign::Pair(10, 20),
+            shape: foreign::Shape::Circle { radius: 4.0 },
+        },
+        json = r#"{"origin":{"x":1.0,"why":2.0,"z":3.0},"range":{"0":10,"1":20},"shape":{"Circle":{"radius":4.0}}}"#,
+    );
+}
+
+#[test]
+fn remote_enum() {
+    musli::rt!(
+        full,
+        Scene {
+            origin: foreign::Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            range: foreign::Pair(1, 2),
+            shape: foreign::Shape::Line(
+                foreign::Vec3 {
+                    x: 1.0,
+                    y: 2.0,
+                    z: 3.0,
+                },
+                foreign::Vec3 {
+                    x: 4.0,
+                    y: 5.0,
+                    z: 6.0,
+                },
+            ),
+        },
+    );
+
+    musli::rt!(
+        full,
+        Scene {
+            origin: foreign::Vec3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            range: foreign::Pair(1, 2),
+            shape: foreign::Shape::Point,
+        },
+    );
+}
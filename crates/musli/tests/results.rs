@@ -0,0 +1,115 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::Text;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Payload {
+    value: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct ErrorInfo {
+    code: u32,
+    message: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Response {
+    field: Result<Payload, ErrorInfo>,
+    optional: Option<Result<u32, String>>,
+}
+
+/// A derived enum which is encoded exactly like a [`Result`].
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub enum Mirror {
+    #[musli(transparent)]
+    Ok(u32),
+    #[musli(transparent)]
+    Err(String),
+}
+
+#[test]
+fn results() {
+    let _ = musli::rt!(full, Ok::<u32, String>(42), json = r#"{"Ok":42}"#);
+    let _ = musli::rt!(
+        full,
+        Err::<u32, String>(String::from("failed")),
+        json = r#"{"Err":"failed"}"#
+    );
+
+    musli::rt!(
+        full,
+        Response {
+            field: Err(ErrorInfo {
+                code: 404,
+                message: String::from("not found"),
+            }),
+            optional: Some(Ok(42)),
+        },
+        json = r#"{"field":{"Err":{"code":404,"message":"not found"}},"optional":{"Ok":42}}"#
+    );
+
+    musli::rt!(
+        full,
+        Response {
+            field: Ok(Payload {
+                value: String::from("hello"),
+            }),
+            optional: None,
+        }
+    );
+}
+
+#[test]
+fn nested_options() {
+    musli::rt!(full, Some(Ok::<u32, String>(42)), json = r#"{"Ok":42}"#);
+    musli::rt!(full, None::<Result<u32, String>>, json = r#"null"#);
+    musli::rt!(
+        full,
+        Some(Err::<Option<u32>, String>(String::from("failed"))),
+        json = r#"{"Err":"failed"}"#
+    );
+    let _ = musli::rt!(
+        full,
+        Ok::<Option<u32>, String>(None),
+        json = r#"{"Ok":null}"#
+    );
+}
+
+#[test]
+fn same_as_derived_enum() {
+    musli::assert_decode_eq!(
+        full,
+        Ok::<u32, String>(42),
+        Mirror::Ok(42),
+        json = r#"{"Ok":42}"#
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        Mirror::Err(String::from("failed")),
+        Err::<u32, String>(String::from("failed")),
+        json = r#"{"Err":"failed"}"#
+    );
+}
+
+#[test]
+fn trace_err() {
+    let alloc = System::new();
+    let encoding = musli::json::Encoding::new();
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let json = br#"{"field":{"Err":{"code":"404","message":"not found"}},"optional":null}"#;
+    assert!(encoding.from_slice_with::<_, Response>(&cx, json).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.starts_with(".field = Err { .code }: "), "{error}");
+
+    let cx = SystemContext::<_, Text>::new(&alloc);
+    let json = br#"{"field":{"Ok":{"value":"hello"}},"optional":{"Err":42}}"#;
+    assert!(encoding.from_slice_with::<_, Response>(&cx, json).is_err());
+    let error = cx.errors().next().expect("expected an error").to_string();
+    assert!(error.starts_with(".optional = Err: "), "{error}");
+}
@@ -0,0 +1,139 @@
+#![cfg(feature = "test")]
+
+use core::ops::ControlFlow;
+
+use musli::{Decode, Decoder, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Entry<'a> {
+    id: u32,
+    name: &'a str,
+}
+
+/// Sums up to the first three elements of a sequence, stopping early.
+#[derive(Debug, PartialEq)]
+struct FirstThree(u32);
+
+impl<'de, M> Decode<'de, M> for FirstThree {
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let mut seen = 0;
+        let mut sum = 0;
+
+        let output = decoder.decode_sequence_each(|value: u32| {
+            sum += value;
+            seen += 1;
+
+            if seen == 3 {
+                return ControlFlow::Break(seen);
+            }
+
+            ControlFlow::Continue(())
+        })?;
+
+        assert_eq!(output, ControlFlow::Break(3));
+        Ok(Self(sum))
+    }
+}
+
+#[derive(Debug, PartialEq, Encode)]
+struct Source {
+    values: Vec<u32>,
+    after: String,
+}
+
+#[derive(Debug, PartialEq, Decode)]
+struct Target {
+    values: FirstThree,
+    after: String,
+}
+
+macro_rules! test_each {
+    ($name:ident, $what:ident) => {
+        mod $name {
+            use super::*;
+
+            const ENCODING: musli::$what::Encoding = musli::$what::Encoding::new();
+
+            #[test]
+            fn borrowed() {
+                let entries = [
+                    Entry { id: 1, name: "a" },
+                    Entry { id: 2, name: "bc" },
+                    Entry { id: 3, name: "def" },
+                ];
+
+                let bytes = ENCODING.to_vec(&entries).unwrap();
+
+                let mut names = Vec::new();
+
+                let output = ENCODING
+                    .from_slice_seq(&bytes, |entry: Entry<'_>| {
+                        names.push(entry.name);
+                        ControlFlow::<()>::Continue(())
+                    })
+                    .unwrap();
+
+                assert_eq!(output, ControlFlow::Continue(()));
+                assert_eq!(names, ["a", "bc", "def"]);
+            }
+
+            #[test]
+            fn break_early() {
+                let bytes = ENCODING.to_vec(&[10u32, 20, 30, 40]).unwrap();
+
+                let mut calls = 0;
+
+                let output = ENCODING
+                    .from_slice_seq(&bytes, |value: u32| {
+                        calls += 1;
+
+                        if value == 20 {
+                            return ControlFlow::Break(value);
+                        }
+
+                        ControlFlow::Continue(())
+                    })
+                    .unwrap();
+
+                assert_eq!(output, ControlFlow::Break(20));
+                assert_eq!(calls, 2);
+            }
+
+            #[test]
+            fn break_leaves_decoder_after_sequence() {
+                let bytes = ENCODING
+                    .to_vec(&Source {
+                        values: vec![1, 2, 3, 4, 5],
+                        after: String::from("tail"),
+                    })
+                    .unwrap();
+
+                let target: Target = ENCODING.from_slice(&bytes).unwrap();
+
+                assert_eq!(
+                    target,
+                    Target {
+                        values: FirstThree(6),
+                        after: String::from("tail"),
+                    }
+                );
+            }
+        }
+    };
+}
+
+test_each!(storage, storage);
+test_each!(wire, wire);
+test_each!(descriptive, descriptive);
+test_each!(json, json);
+
+#[test]
+fn error_in_remaining_elements() {
+    const ENCODING: musli::json::Encoding = musli::json::Encoding::new();
+
+    let result = ENCODING.from_slice_seq(b"[1, 2, \"three\"]", |_: u32| ControlFlow::Break(()));
+    assert!(result.is_err());
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test")]
+
+use musli::compat::Serde;
+use musli::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Newtype(u32);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum Kind {
+    Unit,
+    Newtype(u32),
+    Tuple(u32, String),
+    Struct { value: u32 },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SerdeStruct {
+    string: String,
+    large: u128,
+    negative: i128,
+    bytes: Vec<u8>,
+    unit: (),
+    newtype: Newtype,
+    kinds: Vec<Kind>,
+    optional: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct MusliStruct {
+    name: String,
+    #[musli(with = musli::serde)]
+    inner: SerdeStruct,
+    wrapped: Serde<Kind>,
+}
+
+fn serde_struct() -> SerdeStruct {
+    SerdeStruct {
+        string: String::from("hello"),
+        large: u128::MAX,
+        negative: i128::MIN,
+        bytes: vec![1, 2, 3],
+        unit: (),
+        newtype: Newtype(42),
+        kinds: vec![
+            Kind::Unit,
+            Kind::Newtype(1),
+            Kind::Tuple(2, String::from("two")),
+            Kind::Struct { value: 3 },
+        ],
+        optional: Some(4),
+    }
+}
+
+#[test]
+fn wrapper() {
+    musli::rt!(full, Serde(serde_struct()));
+    musli::rt!(full, Serde(Newtype(42)), json = r#"42"#);
+    musli::rt!(full, Serde(Kind::Newtype(1)), json = r#"{"Newtype":1}"#);
+    musli::rt!(
+        full,
+        Serde(Kind::Struct { value: 3 }),
+        json = r#"{"Struct":{"value":3}}"#
+    );
+}
+
+#[test]
+fn embedded() {
+    musli::rt!(
+        full,
+        MusliStruct {
+            name: String::from("embedded"),
+            inner: serde_struct(),
+            wrapped: Serde(Kind::Unit),
+        }
+    );
+}
@@ -53,3 +53,120 @@ fn skip() {
         json = r#"{}"#,
     );
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct SkipMiddle {
+    a: u32,
+    #[musli(skip)]
+    b: u32,
+    c: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct DefaultMiddle {
+    a: u32,
+    #[musli(default)]
+    b: u32,
+    c: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct SkipUnnamed(u32, #[musli(skip, default = skip_default)] u32, u32);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct DefaultUnnamed(u32, #[musli(default)] u32, u32);
+
+/// Skipped fields keep their index reserved, so the remaining fields are
+/// decoded as if the skipped field was simply absent.
+#[test]
+fn skip_preserves_index() {
+    musli::rt!(
+        full,
+        SkipMiddle { a: 1, b: 0, c: 3 },
+        json = r#"{"a":1,"c":3}"#,
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        SkipMiddle { a: 1, b: 2, c: 3 },
+        DefaultMiddle { a: 1, b: 0, c: 3 },
+        json = r#"{"a":1,"c":3}"#,
+    );
+
+    musli::assert_decode_eq!(
+        upgrade_stable,
+        DefaultMiddle { a: 1, b: 2, c: 3 },
+        SkipMiddle { a: 1, b: 0, c: 3 },
+        json = r#"{"a":1,"b":2,"c":3}"#,
+    );
+
+    musli::assert_decode_eq!(full, SkipUnnamed(1, 2, 3), DefaultUnnamed(1, 0, 3));
+
+    musli::assert_decode_eq!(full, SkipUnnamed(1, 2, 3), SkipUnnamed(1, 42, 3));
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct PackedSkip {
+    a: u32,
+    #[musli(skip)]
+    b: u32,
+    #[musli(skip, default = skip_default)]
+    c: u32,
+    d: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    a: u32,
+    d: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+struct TransparentSkip {
+    value: u32,
+    #[musli(skip)]
+    cache: Option<u32>,
+}
+
+/// Skipped fields in packed containers don't occupy a slot.
+#[test]
+fn skip_packed() {
+    musli::rt!(
+        full,
+        PackedSkip {
+            a: 1,
+            b: 0,
+            c: 42,
+            d: 4
+        },
+        json = r#"[1,4]"#,
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        PackedSkip {
+            a: 1,
+            b: 2,
+            c: 3,
+            d: 4
+        },
+        Packed { a: 1, d: 4 },
+        json = r#"[1,4]"#,
+    );
+
+    musli::assert_decode_eq!(
+        full,
+        TransparentSkip {
+            value: 1,
+            cache: Some(2),
+        },
+        TransparentSkip {
+            value: 1,
+            cache: None,
+        },
+        json = r#"1"#,
+    );
+}
@@ -11,37 +11,117 @@ pub struct SkipSerialize {
 }
 
 #[derive(Debug, PartialEq, Decode, Encode)]
-#[musli(packed)]
-pub struct SkipSerializeUntagged {
+pub struct SkipSerializeVec {
     before: u32,
-    #[musli(skip_encoding_if = Option::is_none)]
+    #[musli(skip_encoding_if = Vec::is_empty)]
+    values: Vec<u32>,
+    after: u32,
+}
+
+#[derive(Debug, PartialEq, Decode, Encode)]
+pub struct SkipSerializeDefault {
+    before: u32,
+    #[musli(skip_encoding_if = Option::is_none, default = default_skipped)]
     skipped: Option<u32>,
     after: u32,
 }
 
 #[derive(Debug, PartialEq, Eq, Decode)]
-#[musli(packed)]
-struct Unpacked(u32, u32);
+struct Unskipped {
+    before: u32,
+    #[musli(mode = Binary, name = 2)]
+    after: u32,
+}
+
+fn default_skipped() -> Option<u32> {
+    Some(42)
+}
 
 #[test]
 fn skip_serialize() {
     musli::rt!(
         full,
-        SkipSerializeUntagged {
+        SkipSerialize {
             before: 1,
             skipped: Some(2),
             after: 3,
         },
-        json = r#"[1,2,3]"#,
+        json = r#"{"before":1,"skipped":2,"after":3}"#,
+    );
+
+    musli::rt!(
+        full,
+        SkipSerialize {
+            before: 1,
+            skipped: None,
+            after: 3,
+        },
+        json = r#"{"before":1,"after":3}"#,
+    );
+
+    musli::assert_decode_eq! {
+        full,
+        SkipSerialize {
+            before: 1,
+            skipped: None,
+            after: 3,
+        },
+        Unskipped { before: 1, after: 3 },
+    };
+}
+
+#[test]
+fn skip_serialize_vec() {
+    musli::rt!(
+        full,
+        SkipSerializeVec {
+            before: 1,
+            values: vec![2, 3],
+            after: 4,
+        },
+        json = r#"{"before":1,"values":[2,3],"after":4}"#,
     );
 
+    musli::rt!(
+        full,
+        SkipSerializeVec {
+            before: 1,
+            values: Vec::new(),
+            after: 4,
+        },
+        json = r#"{"before":1,"after":4}"#,
+    );
+}
+
+#[test]
+fn skip_serialize_default() {
     musli::assert_decode_eq! {
         full,
-        SkipSerializeUntagged {
+        SkipSerialize {
             before: 1,
             skipped: None,
             after: 3,
         },
-        Unpacked(1, 3),
+        SkipSerializeDefault {
+            before: 1,
+            skipped: Some(42),
+            after: 3,
+        },
+        json = r#"{"before":1,"after":3}"#,
+    };
+
+    musli::assert_decode_eq! {
+        full,
+        SkipSerialize {
+            before: 1,
+            skipped: Some(2),
+            after: 3,
+        },
+        SkipSerializeDefault {
+            before: 1,
+            skipped: Some(2),
+            after: 3,
+        },
+        json = r#"{"before":1,"skipped":2,"after":3}"#,
     };
 }
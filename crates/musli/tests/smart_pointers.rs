@@ -0,0 +1,68 @@
+#![cfg(feature = "test")]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Shared {
+    arc_str: Arc<str>,
+    rc_str: Rc<str>,
+    box_str: Box<str>,
+    arc_slice: Arc<[u32]>,
+    rc_slice: Rc<[String]>,
+    box_slice: Box<[u32]>,
+    #[musli(bytes)]
+    arc_bytes: Arc<[u8]>,
+    #[musli(bytes)]
+    rc_bytes: Rc<[u8]>,
+    #[musli(bytes)]
+    box_bytes: Box<[u8]>,
+}
+
+#[test]
+fn unsized_pointers() {
+    musli::rt!(full, Arc::<str>::from("hello"), json = r#""hello""#);
+    musli::rt!(full, Rc::<str>::from("hello"), json = r#""hello""#);
+    musli::rt!(full, Arc::<[u32]>::from([1, 2, 3]), json = r#"[1,2,3]"#);
+    musli::rt!(full, Rc::<[u32]>::from([1, 2, 3]), json = r#"[1,2,3]"#);
+
+    musli::rt!(
+        full,
+        Shared {
+            arc_str: Arc::from("arc"),
+            rc_str: Rc::from("rc"),
+            box_str: Box::from("box"),
+            arc_slice: Arc::from([1, 2]),
+            rc_slice: Rc::from([String::from("a"), String::from("b")]),
+            box_slice: Box::from([3, 4]),
+            arc_bytes: Arc::from(&b"arc"[..]),
+            rc_bytes: Rc::from(&b"rc"[..]),
+            box_bytes: Box::from(&b"box"[..]),
+        },
+        json = r#"{"arc_str":"arc","rc_str":"rc","box_str":"box","arc_slice":[1,2],"rc_slice":["a","b"],"box_slice":[3,4],"arc_bytes":[97,114,99],"rc_bytes":[114,99],"box_bytes":[98,111,120]}"#
+    );
+}
+
+#[test]
+fn empty() {
+    musli::rt!(full, Arc::<str>::from(""), json = r#""""#);
+    musli::rt!(full, Rc::<[u32]>::from([]), json = r#"[]"#);
+
+    musli::rt!(
+        full,
+        Shared {
+            arc_str: Arc::from(""),
+            rc_str: Rc::from(""),
+            box_str: Box::from(""),
+            arc_slice: Arc::from([]),
+            rc_slice: Rc::from([]),
+            box_slice: Box::from([]),
+            arc_bytes: Arc::from(&b""[..]),
+            rc_bytes: Rc::from(&b""[..]),
+            box_bytes: Box::from(&b""[..]),
+        },
+        json = r#"{"arc_str":"","rc_str":"","box_str":"","arc_slice":[],"rc_slice":[],"box_slice":[],"arc_bytes":[],"rc_bytes":[],"box_bytes":[]}"#
+    );
+}
@@ -0,0 +1,67 @@
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::{Decode, Encode};
+
+#[derive(Debug, Encode, Decode)]
+struct Record {
+    id: u32,
+    name: String,
+    flag: bool,
+}
+
+fn decode_error(bytes: &[u8]) -> (std::ops::Range<usize>, String) {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let encoding = musli::storage::Encoding::new();
+
+    let Ok(..) = encoding.from_slice_with::<_, Record>(&cx, bytes) else {
+        let error = cx.errors().next().expect("expected an error");
+        return (error.range(), error.to_string());
+    };
+
+    panic!("Expected decoding to error");
+}
+
+fn encode() -> Vec<u8> {
+    let record = Record {
+        id: 10,
+        name: String::from("hello"),
+        flag: true,
+    };
+
+    musli::storage::to_vec(&record).unwrap()
+}
+
+#[test]
+fn invalid_utf8_range() {
+    let mut bytes = encode();
+    assert_eq!(bytes, [3, 0, 10, 1, 5, b'h', b'e', b'l', b'l', b'o', 2, 1]);
+    bytes[6] = 0xff;
+
+    let (range, error) = decode_error(&bytes);
+    assert_eq!(range, 4..10);
+    assert_eq!(&bytes[range], [5, b'h', 0xff, b'l', b'l', b'o']);
+    assert!(error.starts_with(".name: "), "{error}");
+    assert!(error.ends_with("(at bytes 4-10)"), "{error}");
+}
+
+#[test]
+fn bad_boolean_range() {
+    let mut bytes = encode();
+    bytes[11] = 2;
+
+    let (range, error) = decode_error(&bytes);
+    assert_eq!(range, 11..12);
+    assert_eq!(error, ".flag: Bad boolean byte 0x02 (at bytes 11-12)");
+}
+
+#[test]
+fn integer_overflow_range() {
+    let mut bytes = encode();
+    bytes.splice(2..3, [0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+
+    let (range, error) = decode_error(&bytes);
+    assert_eq!(range, 2..7);
+    assert_eq!(error, ".id: Bits overflow (at bytes 2-7)");
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "test")]
+//! Enums which use their discriminant as the encoded tag.
+
+use musli::options::{self, Integer, Options};
+use musli::{Decode, Encode};
+
+const FIXED: Options = options::new().with_integer(Integer::Fixed).build();
+const STORAGE: musli::storage::Encoding<FIXED> = musli::storage::Encoding::new().with_options();
+
+const BASE: i32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[musli(tag_from_repr)]
+#[repr(u16)]
+enum Opcode {
+    Nop = 0,
+    Read = 0x10,
+    Write = 0x11,
+    // Implicit successor of the previous variant.
+    Flush,
+    Close = 0x100,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[musli(tag_from_repr)]
+#[repr(i32)]
+enum Signed {
+    Negative = -2,
+    Implicit,
+    Constant = BASE,
+    AfterConstant,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(tag_from_repr)]
+#[repr(C, u8)]
+enum Message {
+    Ping = 7,
+    Data(u32),
+    Named { id: u32, name: String },
+}
+
+/// The same layout as `Message`, but with hand-written names.
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = u8)]
+enum ExplicitMessage {
+    #[musli(name = 7)]
+    Ping,
+    #[musli(name = 8)]
+    Data(u32),
+    #[musli(name = 9)]
+    Named { id: u32, name: String },
+}
+
+#[test]
+fn storage_golden() {
+    let cases = [
+        (Opcode::Nop, [0x00, 0x00]),
+        (Opcode::Read, [0x10, 0x00]),
+        (Opcode::Write, [0x11, 0x00]),
+        (Opcode::Flush, [0x12, 0x00]),
+        (Opcode::Close, [0x00, 0x01]),
+    ];
+
+    for (opcode, expected) in cases {
+        let bytes = STORAGE.to_vec(&opcode).unwrap();
+        assert_eq!(bytes, expected, "{opcode:?}");
+        assert_eq!(STORAGE.from_slice::<Opcode>(&bytes).unwrap(), opcode);
+    }
+
+    let cases = [
+        (Signed::Negative, -2i32),
+        (Signed::Implicit, -1),
+        (Signed::Constant, 100),
+        (Signed::AfterConstant, 101),
+    ];
+
+    for (value, tag) in cases {
+        let bytes = STORAGE.to_vec(&value).unwrap();
+        assert_eq!(bytes, tag.to_le_bytes(), "{value:?}");
+        assert_eq!(STORAGE.from_slice::<Signed>(&bytes).unwrap(), value);
+    }
+}
+
+#[test]
+fn wire_golden() {
+    let cases = [
+        (Opcode::Nop, 0u16),
+        (Opcode::Read, 0x10),
+        (Opcode::Write, 0x11),
+        (Opcode::Flush, 0x12),
+        (Opcode::Close, 0x100),
+    ];
+
+    for (opcode, tag) in cases {
+        let bytes = musli::wire::to_vec(&opcode).unwrap();
+        assert_eq!(bytes, musli::wire::to_vec(&tag).unwrap(), "{opcode:?}");
+        assert_eq!(musli::wire::from_slice::<Opcode>(&bytes).unwrap(), opcode);
+    }
+}
+
+#[test]
+fn variants_with_fields() {
+    let cases = [
+        (Message::Ping, ExplicitMessage::Ping),
+        (Message::Data(42), ExplicitMessage::Data(42)),
+        (
+            Message::Named {
+                id: 1,
+                name: String::from("Alice"),
+            },
+            ExplicitMessage::Named {
+                id: 1,
+                name: String::from("Alice"),
+            },
+        ),
+    ];
+
+    for (value, explicit) in cases {
+        let bytes = STORAGE.to_vec(&value).unwrap();
+        assert_eq!(bytes, STORAGE.to_vec(&explicit).unwrap());
+        assert_eq!(STORAGE.from_slice::<Message>(&bytes).unwrap(), value);
+
+        let bytes = musli::wire::to_vec(&value).unwrap();
+        assert_eq!(bytes, musli::wire::to_vec(&explicit).unwrap());
+        assert_eq!(musli::wire::from_slice::<Message>(&bytes).unwrap(), value);
+    }
+
+    musli::rt!(full, Message::Data(42));
+    musli::rt!(full, Opcode::Flush, json = r#"18"#);
+}
+
+#[test]
+fn unknown_tag() {
+    let bytes = STORAGE.to_vec(&0x20u16).unwrap();
+    assert!(STORAGE.from_slice::<Opcode>(&bytes).is_err());
+}
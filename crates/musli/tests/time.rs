@@ -0,0 +1,87 @@
+#![cfg(feature = "test")]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Times {
+    duration: Duration,
+    time: SystemTime,
+}
+
+#[test]
+fn duration() {
+    musli::rt!(full, Duration::ZERO, json = r#"[0,0]"#);
+    musli::rt!(
+        full,
+        Duration::new(1, 500_000_000),
+        json = r#"[1,500000000]"#
+    );
+    musli::rt!(
+        full,
+        Duration::MAX,
+        json = r#"[18446744073709551615,999999999]"#
+    );
+}
+
+#[test]
+fn system_time() {
+    musli::rt!(full, UNIX_EPOCH, json = r#"[0,0]"#);
+
+    musli::rt!(
+        full,
+        UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+        json = r#"[1700000000,123456789]"#
+    );
+
+    musli::rt!(
+        full,
+        UNIX_EPOCH - Duration::from_millis(500),
+        json = r#"[-1,500000000]"#
+    );
+
+    musli::rt!(
+        full,
+        UNIX_EPOCH - Duration::from_secs(86_400),
+        json = r#"[-86400,0]"#
+    );
+
+    musli::rt!(
+        full,
+        Times {
+            duration: Duration::from_micros(1_500),
+            time: UNIX_EPOCH + Duration::from_secs(60),
+        },
+        json = r#"{"duration":[0,1500000],"time":[60,0]}"#
+    );
+}
+
+#[test]
+fn malformed() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(musli::json::from_str::<Duration>("[1,1000000000]").is_err());
+    assert!(musli::json::from_str::<SystemTime>("[-1,1000000000]").is_err());
+
+    // Whether the extremes are representable depends on the platform, but
+    // decoding them must never panic.
+    if let Ok(time) = musli::json::from_str::<SystemTime>("[9223372036854775807,999999999]") {
+        let since = time.duration_since(UNIX_EPOCH)?;
+        assert_eq!(since, Duration::new(i64::MAX as u64, 999_999_999));
+    }
+
+    if let Ok(time) = musli::json::from_str::<SystemTime>("[-9223372036854775808,0]") {
+        let before = UNIX_EPOCH.duration_since(time)?;
+        assert_eq!(before, Duration::from_secs(i64::MIN.unsigned_abs()));
+    }
+
+    let bytes = musli::storage::to_vec(&(1u64, 1_000_000_000u32))?;
+    assert!(musli::storage::from_slice::<Duration>(&bytes).is_err());
+
+    let bytes = musli::storage::to_vec(&(-1i64, 999_999_999u32))?;
+    assert_eq!(
+        musli::storage::from_slice::<SystemTime>(&bytes)?,
+        UNIX_EPOCH - Duration::from_nanos(1)
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-#![allow(unused)]
+#![allow(unused, clippy::box_collection)]
 
 use std::collections::HashMap;
 
@@ -54,3 +54,138 @@ fn trace_collection() {
 
     panic!("Expected decoding to error");
 }
+
+#[derive(Encode)]
+struct NestedFrom {
+    values: Vec<HashMap<String, String>>,
+}
+
+#[derive(Encode, Decode)]
+struct Nested {
+    #[musli(trace)]
+    values: Vec<HashMap<String, u32>>,
+}
+
+#[derive(Encode)]
+struct OptionalFrom {
+    values: Option<Box<HashMap<String, (u32, String)>>>,
+}
+
+#[derive(Encode, Decode)]
+struct Optional {
+    #[musli(trace)]
+    values: Option<Box<HashMap<String, Vec<u32>>>>,
+}
+
+#[derive(Encode)]
+struct MapOfMapsFrom {
+    values: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(Encode, Decode)]
+struct MapOfMaps {
+    #[musli(trace)]
+    values: HashMap<String, HashMap<String, u32>>,
+}
+
+/// Decode `T` from the encoding of `from`, and return the first reported
+/// error.
+fn first_error<F, T>(from: &F) -> String
+where
+    F: Encode<musli::mode::Text>,
+    T: for<'de> Decode<'de, musli::mode::Text>,
+{
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let bytes = encoding.to_vec(from).unwrap();
+
+    let Ok(..) = encoding.from_slice_with::<_, T>(&cx, &bytes) else {
+        let Some(error) = cx.errors().next() else {
+            unreachable!()
+        };
+
+        return error.to_string();
+    };
+
+    panic!("Expected decoding to error");
+}
+
+#[test]
+fn trace_nested_collection() {
+    let mut values = vec![HashMap::new(); 4];
+    values[3].insert("Hello".to_string(), "World".to_string());
+
+    assert_eq!(
+        first_error::<_, Nested>(&NestedFrom { values }),
+        ".values[3][Hello]: Invalid numeric (at bytes 29-30)"
+    );
+}
+
+#[test]
+fn trace_option_box() {
+    let mut values = HashMap::new();
+    values.insert("Hello".to_string(), (1, "World".to_string()));
+
+    assert_eq!(
+        first_error::<_, Optional>(&OptionalFrom {
+            values: Some(Box::new(values)),
+        }),
+        ".values[Hello][1]: Invalid numeric (at bytes 22-23)"
+    );
+}
+
+#[test]
+fn trace_map_of_maps() {
+    let mut inner = HashMap::new();
+    inner.insert("b".to_string(), "World".to_string());
+
+    let mut values = HashMap::new();
+    values.insert("a".to_string(), inner);
+
+    assert_eq!(
+        first_error::<_, MapOfMaps>(&MapOfMapsFrom { values }),
+        ".values[a][b]: Invalid numeric (at bytes 20-21)"
+    );
+}
+
+#[test]
+fn trace_encode_nested() {
+    let mut map = HashMap::new();
+    map.insert("Hello".to_string(), 42);
+
+    let nested = Nested {
+        values: vec![HashMap::new(), map.clone()],
+    };
+
+    let json = musli::json::to_string(&nested).unwrap();
+    assert_eq!(json, r#"{"values":[{},{"Hello":42}]}"#);
+    assert_eq!(
+        musli::json::from_str::<Nested>(&json).unwrap().values,
+        nested.values
+    );
+
+    let optional = Optional {
+        values: Some(Box::new(HashMap::from([("Hello".to_string(), vec![1, 2])]))),
+    };
+
+    let json = musli::json::to_string(&optional).unwrap();
+    assert_eq!(json, r#"{"values":{"Hello":[1,2]}}"#);
+    assert_eq!(
+        musli::json::from_str::<Optional>(&json).unwrap().values,
+        optional.values
+    );
+
+    let map_of_maps = MapOfMaps {
+        values: HashMap::from([("a".to_string(), HashMap::from([("b".to_string(), 1)]))]),
+    };
+
+    let json = musli::json::to_string(&map_of_maps).unwrap();
+    assert_eq!(json, r#"{"values":{"a":{"b":1}}}"#);
+    assert_eq!(
+        musli::json::from_str::<MapOfMaps>(&json).unwrap().values,
+        map_of_maps.values
+    );
+}
@@ -55,3 +55,42 @@ fn trace_no_std() {
 
     panic!("Expected decoding to error");
 }
+
+#[test]
+fn trace_no_std_first_error() {
+    let mut buf = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx = StackContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let Ok(..) =
+        encoding.from_slice_with::<_, Collection>(&cx, br#"{"values": {"Hello": "World"}}"#)
+    else {
+        let error = cx.first_error().expect("expected an error");
+        assert!(error.starts_with(".values[Hello]: Invalid numeric"));
+        return;
+    };
+
+    panic!("Expected decoding to error");
+}
+
+#[test]
+fn trace_no_std_truncated_message() {
+    use musli::mode::Binary;
+    use musli::Context;
+
+    let mut buf = StackBuffer::<256>::new();
+    let alloc = Stack::new(&mut buf);
+    let cx: StackContext<16, 4, _, Binary> = StackContext::new(&alloc);
+
+    let message = "abcdefghijklmnopqrstuvwxyz".repeat(64);
+    cx.message(&message);
+
+    let error = cx.errors().next().expect("expected a truncated error");
+    let error = error.to_string();
+    let error = error.strip_prefix(": ").unwrap();
+    assert!(!error.is_empty());
+    assert!(error.len() < message.len());
+    assert!(message.starts_with(error));
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use musli::allocator::System;
+use musli::context::{PathFormat, Step, SystemContext};
+use musli::Decode;
+
+#[derive(Debug, Decode)]
+struct Collection {
+    #[musli(trace)]
+    values: HashMap<String, u32>,
+}
+
+#[derive(Debug, Decode)]
+struct Sequence {
+    values: Vec<u32>,
+}
+
+#[derive(Debug, Decode)]
+struct Outer {
+    inner: Collection,
+}
+
+fn decode<T>(format: PathFormat, input: &str) -> String
+where
+    T: for<'de> Decode<'de, musli::mode::Text>,
+{
+    let alloc = System::new();
+    let mut cx = SystemContext::new(&alloc);
+    cx.with_path_format(format);
+
+    let encoding = musli::json::Encoding::new();
+
+    let Ok(..) = encoding.from_slice_with::<_, T>(&cx, input.as_bytes()) else {
+        let error = cx.errors().next().expect("expected an error");
+        return error.to_string();
+    };
+
+    panic!("Expected decoding to error");
+}
+
+#[test]
+fn default_format() {
+    assert_eq!(
+        decode::<Collection>(PathFormat::new(), r#"{"values":{"Hello":"World"}}"#),
+        ".values[Hello]: Invalid numeric (at bytes 19-20)"
+    );
+
+    assert_eq!(
+        decode::<Sequence>(PathFormat::new(), r#"{"values":[1,"x"]}"#),
+        ".values[1]: Invalid numeric (at bytes 13-14)"
+    );
+}
+
+#[test]
+fn without_keys() {
+    assert_eq!(
+        decode::<Collection>(
+            PathFormat::new().without_keys(),
+            r#"{"values":{"Hello":"World"}}"#
+        ),
+        ".values[*]: Invalid numeric (at bytes 19-20)"
+    );
+}
+
+#[test]
+fn dotted_indices() {
+    assert_eq!(
+        decode::<Sequence>(PathFormat::new().dotted_indices(), r#"{"values":[1,"x"]}"#),
+        ".values.1: Invalid numeric (at bytes 13-14)"
+    );
+}
+
+#[test]
+fn max_depth() {
+    let input = r#"{"inner":{"values":{"Hello":"World"}}}"#;
+
+    assert_eq!(
+        decode::<Outer>(PathFormat::new().max_depth(1), input),
+        ".inner .. *2 capped steps*: Invalid numeric (at bytes 28-29)"
+    );
+
+    assert_eq!(
+        decode::<Outer>(PathFormat::new().max_depth(2), input),
+        ".inner.values .. *one capped step*: Invalid numeric (at bytes 28-29)"
+    );
+}
+
+#[test]
+fn structured_path() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let Ok(..) = encoding.from_slice_with::<_, Collection>(&cx, br#"{"values":{"Hello":"World"}}"#)
+    else {
+        let error = cx.errors().next().expect("expected an error");
+
+        assert!(matches!(
+            error.path(),
+            [Step::Named("values"), Step::Key(key)] if key == "Hello"
+        ));
+        assert_eq!(error.capped_steps(), 0);
+        assert_eq!(error.range(), 19..20);
+        assert_eq!(error.error(), "Invalid numeric");
+        return;
+    };
+
+    panic!("Expected decoding to error");
+}
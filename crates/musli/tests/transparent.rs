@@ -65,3 +65,88 @@ fn transparent_enum() {
     musli::rt!(full, TransparentEnum::Transparent(42));
     musli::rt!(full, TransparentEnum::NotTransparent { a: 1, b: 2 });
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+struct UserId(u64);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct User {
+    id: UserId,
+    friends: Vec<UserId>,
+}
+
+#[test]
+fn transparent_newtype_has_no_framing() {
+    musli::assert_decode_eq!(full, UserId(42), 42u64, json = "42");
+
+    musli::assert_decode_eq!(
+        full,
+        vec![UserId(1), UserId(2)],
+        vec![1u64, 2u64],
+        json = "[1,2]",
+    );
+
+    musli::rt!(
+        full,
+        User {
+            id: UserId(7),
+            friends: vec![UserId(1), UserId(2)],
+        },
+        json = r#"{"id":7,"friends":[1,2]}"#,
+    );
+}
+
+mod as_string {
+    use musli::{Context, Decoder, Encoder};
+
+    pub fn encode<E>(value: &u64, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+    {
+        encoder.collect_string(value)
+    }
+
+    pub fn decode<'de, D>(cx: &D::Cx, decoder: D) -> Result<u64, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        decoder.decode_unsized(|string: &str| string.parse().map_err(|error| cx.custom(error)))
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+struct StringId(#[musli(with = self::as_string)] u64);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(transparent)]
+struct WithSkipped {
+    value: u32,
+    #[musli(skip)]
+    cached: Option<u32>,
+}
+
+#[test]
+fn transparent_field_attributes() {
+    musli::assert_decode_eq!(full, StringId(42), String::from("42"), json = r#""42""#,);
+
+    musli::assert_decode_eq!(
+        full,
+        WithSkipped {
+            value: 42,
+            cached: None,
+        },
+        42u32,
+        json = "42",
+    );
+}
+
+#[test]
+fn transparent_variant_golden() {
+    musli::rt!(
+        full,
+        TransparentEnum::Transparent(42),
+        json = r#"{"Transparent":42}"#,
+    );
+}
@@ -27,3 +27,115 @@ enum Enum {
 fn tuple_enum() {
     musli::rt!(full, Enum::Tuple(11, 13));
 }
+
+#[test]
+fn arities() {
+    musli::rt!(full, (42u64,), json = r#"[42]"#);
+    musli::rt!(full, (42u64, String::from("a")), json = r#"[42,"a"]"#);
+    musli::rt!(
+        full,
+        (1u64, 2.5f32, 3.5f32, String::from("row"), 5u8, -6i32, true),
+        json = r#"[1,2.5,3.5,"row",5,-6,true]"#
+    );
+}
+
+type Sixteen = (
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    bool,
+    char,
+    String,
+    Option<u32>,
+    Vec<u8>,
+    (u32, u32),
+);
+
+fn sixteen() -> Sixteen {
+    (
+        1,
+        2,
+        3,
+        4,
+        -5,
+        -6,
+        -7,
+        -8,
+        9.5,
+        10.5,
+        true,
+        'c',
+        String::from("thirteen"),
+        Some(14),
+        vec![15],
+        (16, 17),
+    )
+}
+
+/// Tuples of this arity don't implement `PartialEq` or `Debug`, so we test
+/// that decoding and encoding again produces the same output.
+#[test]
+fn arity_sixteen() {
+    let json = musli::json::to_string(&sixteen()).unwrap();
+    assert_eq!(
+        json,
+        r#"[1,2,3,4,-5,-6,-7,-8,9.5,10.5,true,"c","thirteen",14,[15],[16,17]]"#
+    );
+    let decoded = musli::json::from_str::<Sixteen>(&json).unwrap();
+    assert_eq!(musli::json::to_string(&decoded).unwrap(), json);
+
+    let bytes = musli::storage::to_vec(&sixteen()).unwrap();
+    let decoded = musli::storage::from_slice::<Sixteen>(&bytes).unwrap();
+    assert_eq!(musli::storage::to_vec(&decoded).unwrap(), bytes);
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    #[musli(packed)]
+    tuple: (u32, u8, u16),
+}
+
+#[test]
+fn packed() {
+    let packed = musli::storage::to_vec(&Packed { tuple: (1, 2, 3) }).unwrap();
+
+    let mut expected = musli::storage::to_vec(&1u32).unwrap();
+    expected.extend(musli::storage::to_vec(&2u8).unwrap());
+    expected.extend(musli::storage::to_vec(&3u16).unwrap());
+    assert_eq!(packed, expected);
+
+    musli::rt!(full, Packed { tuple: (1, 2, 3) }, json = r#"[[1,2,3]]"#);
+}
+
+#[test]
+fn length_mismatch() {
+    let error = musli::json::from_str::<(u32, u32, u32)>("[1,2]").unwrap_err();
+    let error = error.to_string();
+    assert!(
+        error.contains("Tuple with length 2 does not have the expected 3 number of elements"),
+        "{error}"
+    );
+
+    let error = musli::json::from_str::<(u32, u32, u32)>("[1,2,3,4]").unwrap_err();
+    let error = error.to_string();
+    assert!(
+        error.contains("Tuple with length 4 does not have the expected 3 number of elements"),
+        "{error}"
+    );
+
+    let bytes = musli::storage::to_vec(&(1u32, 2u32)).unwrap();
+    let error = musli::storage::from_slice::<(u32, u32, u32)>(&bytes).unwrap_err();
+    let error = error.to_string();
+    assert!(
+        error.contains("Tuple with length 2 does not have the expected 3 number of elements"),
+        "{error}"
+    );
+}
@@ -0,0 +1,21 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(name_all = "name")]
+struct FieldConflict {
+    first: u32,
+    #[musli(alias = "first")]
+    second: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(name_all = "name")]
+enum VariantConflict {
+    #[musli(alias = "Old")]
+    First,
+    #[musli(alias = "Old")]
+    Second,
+}
+
+fn main() {
+}
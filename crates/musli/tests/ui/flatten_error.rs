@@ -0,0 +1,31 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+struct Inner {
+    field: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    #[musli(flatten)]
+    inner: Inner,
+}
+
+#[derive(Encode, Decode)]
+#[musli(deny_unknown_fields)]
+struct DenyUnknown {
+    #[musli(flatten)]
+    inner: Inner,
+}
+
+#[derive(Encode, Decode)]
+enum Variant {
+    Struct {
+        #[musli(flatten)]
+        inner: Inner,
+    },
+}
+
+fn main() {
+}
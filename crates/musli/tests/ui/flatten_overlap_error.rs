@@ -0,0 +1,19 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(name_all = "name")]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(name_all = "name")]
+struct Overlapping {
+    page: u32,
+    #[musli(flatten)]
+    pagination: Pagination,
+}
+
+fn main() {
+}
@@ -0,0 +1,19 @@
+use musli::mode::Binary;
+use musli::{Decode, Encode};
+
+struct NotEncode;
+
+#[derive(Encode, Decode)]
+struct Wrapper<T> {
+    items: Vec<(T, Option<T>)>,
+}
+
+fn encode<T>()
+where
+    T: Encode<Binary>,
+{
+}
+
+fn main() {
+    encode::<Wrapper<NotEncode>>();
+}
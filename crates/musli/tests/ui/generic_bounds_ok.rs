@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+#[derive(Encode, Decode)]
+struct Wrapper<T> {
+    items: Vec<(T, Option<T>)>,
+}
+
+#[derive(Encode, Decode)]
+struct Borrowed<'a, T> {
+    name: &'a str,
+    value: Box<T>,
+    #[musli(skip)]
+    marker: PhantomData<T>,
+}
+
+#[derive(Encode, Decode)]
+struct Map<K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    #[musli(mode = Text, name = "values")]
+    entries: HashMap<K, Vec<V>>,
+}
+
+#[derive(Encode, Decode)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+    Both(L, Option<R>),
+    Neither,
+}
+
+#[derive(Encode, Decode)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[derive(Encode, Decode)]
+enum List<T> {
+    Cons(T, Box<List<T>>),
+    Nil,
+}
+
+trait Family {
+    type Member;
+}
+
+struct Numbers;
+
+impl Family for Numbers {
+    type Member = u32;
+}
+
+#[derive(Encode, Decode)]
+#[musli(mode = Binary, bound = {F::Member: Encode<Binary>}, decode_bound = {F::Member: Decode<'de, Binary>})]
+#[musli(mode = Text, bound = {F::Member: Encode<Text>}, decode_bound = {F::Member: Decode<'de, Text>})]
+struct Members<F>
+where
+    F: Family,
+{
+    members: Vec<F::Member>,
+}
+
+fn encode<T>()
+where
+    T: Encode<Binary> + Encode<Text>,
+{
+}
+
+fn decode<'de, T>()
+where
+    T: Decode<'de, Binary> + Decode<'de, Text>,
+{
+}
+
+fn main() {
+    encode::<Wrapper<String>>();
+    decode::<Wrapper<String>>();
+    encode::<Borrowed<'static, u32>>();
+    decode::<Borrowed<'static, u32>>();
+    encode::<Map<String, u64>>();
+    decode::<Map<String, u64>>();
+    encode::<Either<u8, String>>();
+    decode::<Either<u8, String>>();
+    encode::<Tree<u32>>();
+    decode::<Tree<u32>>();
+    encode::<List<String>>();
+    decode::<List<String>>();
+    encode::<Members<Numbers>>();
+    decode::<Members<Numbers>>();
+}
@@ -0,0 +1,35 @@
+use musli::{Encode, Decode};
+
+/// Fallback variant with more than one field.
+#[derive(Encode, Decode)]
+enum Enum1 {
+    #[musli(other)]
+    Variant(u32, u32),
+}
+
+/// Multiple fallback variants.
+#[derive(Encode, Decode)]
+enum Enum2 {
+    #[musli(default)]
+    Fallback1,
+    #[musli(other)]
+    Fallback2(String),
+}
+
+/// Capturing the tag of a packed variant.
+#[derive(Encode, Decode)]
+enum Enum3 {
+    #[musli(other, packed)]
+    Fallback(String),
+}
+
+/// Capturing the tag of an untagged enum.
+#[derive(Encode, Decode)]
+#[musli(untagged)]
+enum Enum4 {
+    #[musli(other)]
+    Fallback(String),
+}
+
+fn main() {
+}
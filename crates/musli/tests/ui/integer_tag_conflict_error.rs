@@ -0,0 +1,23 @@
+use musli::{Encode, Decode};
+
+/// An explicit tag which collides with the index of another field.
+#[derive(Encode, Decode)]
+#[musli(name_all = "index")]
+struct IndexConflict {
+    first: u32,
+    #[musli(name = 0)]
+    second: u32,
+}
+
+/// An alias which collides with the explicit tag of another field.
+#[derive(Encode, Decode)]
+#[musli(name_all = "index")]
+struct AliasConflict {
+    #[musli(name = 5)]
+    first: u32,
+    #[musli(name = 9, alias = 5)]
+    second: u32,
+}
+
+fn main() {
+}
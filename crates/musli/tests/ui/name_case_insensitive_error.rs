@@ -0,0 +1,22 @@
+use musli::Decode;
+
+#[derive(Decode)]
+#[musli(name_case_insensitive)]
+struct Fields {
+    #[musli(name = "userName")]
+    first: String,
+    #[musli(name = "username")]
+    second: String,
+}
+
+#[derive(Decode)]
+#[musli(name_case_insensitive)]
+enum Variants {
+    #[musli(alias = "ACTIVE")]
+    Active,
+    #[musli(name = "active")]
+    Enabled,
+}
+
+fn main() {
+}
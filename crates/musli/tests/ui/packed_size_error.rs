@@ -0,0 +1,16 @@
+use musli::Encode;
+
+#[derive(Encode)]
+#[musli(packed_size)]
+struct Tagged {
+    field: u32,
+}
+
+#[derive(Encode)]
+#[musli(packed, packed_size)]
+enum Enum {
+    Variant(u32),
+}
+
+fn main() {
+}
@@ -0,0 +1,16 @@
+use musli::Decode;
+
+#[derive(Decode)]
+#[musli(packed, recover)]
+struct Packed {
+    field: u32,
+}
+
+#[derive(Decode)]
+#[musli(transparent, recover)]
+struct Transparent {
+    field: u32,
+}
+
+fn main() {
+}
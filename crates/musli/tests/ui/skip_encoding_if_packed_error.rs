@@ -0,0 +1,19 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(packed)]
+struct Packed {
+    before: u32,
+    #[musli(skip_encoding_if = Option::is_none)]
+    skipped: Option<u32>,
+}
+
+#[derive(Encode, Decode)]
+#[musli(transparent)]
+struct Transparent {
+    #[musli(skip_encoding_if = Option::is_none)]
+    skipped: Option<u32>,
+}
+
+fn main() {
+}
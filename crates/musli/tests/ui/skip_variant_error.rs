@@ -0,0 +1,22 @@
+use musli::{Encode, Decode};
+
+enum Public {}
+
+/// Skipping the default variant.
+#[derive(Encode, Decode)]
+enum Enum1 {
+    Variant,
+    #[musli(default, mode = Public, skip)]
+    Fallback,
+}
+
+/// Skipping the variant which captures unknown tags.
+#[derive(Encode, Decode)]
+enum Enum2 {
+    Variant,
+    #[musli(other, skip)]
+    Fallback(String),
+}
+
+fn main() {
+}
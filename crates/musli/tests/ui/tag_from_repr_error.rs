@@ -0,0 +1,32 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(tag_from_repr)]
+#[repr(u8)]
+enum Named {
+    First = 1,
+    #[musli(name = 2)]
+    Second,
+}
+
+#[derive(Encode, Decode)]
+#[musli(tag_from_repr)]
+enum MissingRepr {
+    First = 1,
+}
+
+#[derive(Encode, Decode)]
+#[musli(tag_from_repr, name_type = u32)]
+#[repr(u8)]
+enum NameType {
+    First = 1,
+}
+
+#[derive(Encode, Decode)]
+#[musli(tag_from_repr)]
+struct Struct {
+    field: u32,
+}
+
+fn main() {
+}
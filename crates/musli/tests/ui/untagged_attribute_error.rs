@@ -0,0 +1,22 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(untagged)]
+struct UntaggedStruct {
+    field: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(untagged, tag = "type")]
+enum UntaggedAndTag {
+    Variant1 { field: u32 },
+}
+
+#[derive(Encode, Decode)]
+#[musli(untagged, transparent)]
+enum UntaggedAndTransparent {
+    Variant1(String),
+}
+
+fn main() {
+}
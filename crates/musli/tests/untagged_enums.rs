@@ -1,5 +1,7 @@
 #![cfg(feature = "test")]
 
+use musli::allocator::System;
+use musli::context::SystemContext;
 use musli::{Decode, Encode};
 
 #[derive(Debug, PartialEq, Encode)]
@@ -37,7 +39,7 @@ pub struct IntegerVariant {
 #[musli(packed)]
 pub struct EmptyVariant;
 
-/// Untagged enums may only implement `Encode`, and will be encoded according to
+/// Packed enums may only implement `Encode`, and will be encoded according to
 /// the exact specification of fields part of the variant.
 #[test]
 fn untagged_enums() -> Result<(), Box<dyn std::error::Error>> {
@@ -85,3 +87,92 @@ fn untagged_enums() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(untagged)]
+pub enum Untagged {
+    #[musli(transparent)]
+    Number(u32),
+    #[musli(transparent)]
+    String(String),
+    Person {
+        name: String,
+        age: u32,
+    },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(untagged)]
+pub enum UntaggedFallback {
+    #[musli(transparent)]
+    Number(u32),
+    #[musli(default)]
+    Unknown,
+}
+
+/// Untagged enums only encode the content of the variant, and decoding tries
+/// each variant in order.
+#[test]
+fn untagged_enums_roundtrip() {
+    musli::rt!(descriptive, Untagged::Number(42), json = r#"42"#);
+
+    musli::rt!(
+        descriptive,
+        Untagged::String(String::from("Hello World")),
+        json = r#""Hello World""#
+    );
+
+    musli::rt!(
+        descriptive,
+        Untagged::Person {
+            name: String::from("Aristotle"),
+            age: 61,
+        },
+        json = r#"{"name":"Aristotle","age":61}"#
+    );
+}
+
+#[test]
+fn untagged_enums_fallback() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        musli::json::from_str::<UntaggedFallback>("42")?,
+        UntaggedFallback::Number(42)
+    );
+
+    assert_eq!(
+        musli::json::from_str::<UntaggedFallback>(r#""Hello World""#)?,
+        UntaggedFallback::Unknown
+    );
+
+    Ok(())
+}
+
+/// When no variant matches, the error lists every variant which was tried and
+/// none of the errors from the failed attempts are retained.
+#[test]
+fn untagged_enums_no_match() {
+    let alloc = System::new();
+    let cx = SystemContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let Err(..) = encoding.from_slice_with::<_, Untagged>(&cx, b"true") else {
+        panic!("Expected decoding to error");
+    };
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(
+        errors[0].contains("No variant of Untagged matched, tried: Number, String, Person"),
+        "{errors:?}"
+    );
+}
+
+/// Untagged enums require buffering, which formats that are not
+/// self-describing do not support.
+#[test]
+fn untagged_enums_not_self_describing() -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = musli::storage::to_vec(&Untagged::Number(42))?;
+    assert!(musli::storage::from_slice::<Untagged>(&bytes).is_err());
+    Ok(())
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "test")]
+
+use musli::allocator::System;
+use musli::context::SystemContext;
+use musli::mode::Text;
+use musli::{Decode, Encode};
+use uuid::Uuid;
+
+const UUID: Uuid = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Record {
+    id: Uuid,
+    parent: Option<Uuid>,
+}
+
+#[test]
+fn uuid() {
+    musli::rt!(
+        full,
+        UUID,
+        json = r#""67e55044-10b1-426f-9247-bb680e5fe0c8""#
+    );
+    musli::rt!(
+        full,
+        Uuid::nil(),
+        json = r#""00000000-0000-0000-0000-000000000000""#
+    );
+    musli::rt!(
+        full,
+        Record {
+            id: UUID,
+            parent: Some(Uuid::max()),
+        },
+        json = r#"{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8","parent":"ffffffff-ffff-ffff-ffff-ffffffffffff"}"#
+    );
+}
+
+#[test]
+fn binary_is_raw_bytes() -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = musli::storage::to_vec(&UUID)?;
+    assert_eq!(bytes, UUID.as_bytes());
+    assert_eq!(musli::storage::from_slice::<Uuid>(&bytes)?, UUID);
+
+    let bytes = musli::descriptive::to_vec(&UUID)?;
+    assert_eq!(musli::descriptive::from_slice::<Uuid>(&bytes)?, UUID);
+    Ok(())
+}
+
+#[test]
+fn json_forms() -> Result<(), Box<dyn std::error::Error>> {
+    for string in [
+        r#""67e55044-10b1-426f-9247-bb680e5fe0c8""#,
+        r#""67E55044-10B1-426F-9247-BB680E5FE0C8""#,
+        r#""67e5504410b1426f9247bb680e5fe0c8""#,
+    ] {
+        assert_eq!(musli::json::from_str::<Uuid>(string)?, UUID);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn malformed() {
+    assert!(musli::storage::from_slice::<Uuid>(&UUID.as_bytes()[..15]).is_err());
+
+    let bytes = musli::descriptive::to_vec(&[1u8; 15]).unwrap();
+    assert!(musli::descriptive::from_slice::<Uuid>(&bytes).is_err());
+
+    assert!(musli::json::from_str::<Uuid>("42").is_err());
+    assert!(musli::json::from_str::<Uuid>(r#""""#).is_err());
+    assert!(musli::json::from_str::<Uuid>(r#""67e55044-10b1-426f-9247-bb680e5fe0c""#).is_err());
+    assert!(musli::json::from_str::<Uuid>(r#""67e55044-10b1-426f-9247-bb680e5fe0cg""#).is_err());
+}
+
+#[test]
+fn malformed_position() {
+    const JSON: musli::json::Encoding = musli::json::Encoding::new();
+
+    #[derive(Debug, Decode)]
+    #[musli(mode = Text)]
+    struct Record {
+        #[allow(unused)]
+        id: Uuid,
+    }
+
+    let alloc = System::new();
+    let cx = SystemContext::<_, Text>::new(&alloc);
+
+    let result = JSON.from_str_with::<_, Record>(&cx, r#"{"id": "not-a-uuid"}"#);
+    assert!(result.is_err());
+    assert_eq!(
+        cx.report().to_string(),
+        ".id: failed to parse a UUID (at byte 19)\n"
+    );
+}
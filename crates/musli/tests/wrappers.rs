@@ -0,0 +1,115 @@
+#![cfg(feature = "test")]
+
+use std::num::{Saturating, Wrapping};
+
+use musli::{Decode, Encode};
+
+macro_rules! numbers {
+    ($name:ident, $wrapper:ident) => {
+        #[derive(Debug, PartialEq, Encode, Decode)]
+        pub struct $name {
+            u8: $wrapper<u8>,
+            u16: $wrapper<u16>,
+            u32: $wrapper<u32>,
+            u64: $wrapper<u64>,
+            u128: $wrapper<u128>,
+            usize: $wrapper<usize>,
+            i8: $wrapper<i8>,
+            i16: $wrapper<i16>,
+            i32: $wrapper<i32>,
+            i64: $wrapper<i64>,
+            i128: $wrapper<i128>,
+            isize: $wrapper<isize>,
+        }
+
+        impl $name {
+            fn max() -> Self {
+                Self {
+                    u8: $wrapper(u8::MAX),
+                    u16: $wrapper(u16::MAX),
+                    u32: $wrapper(u32::MAX),
+                    u64: $wrapper(u64::MAX),
+                    u128: $wrapper(u128::MAX),
+                    usize: $wrapper(usize::MAX),
+                    i8: $wrapper(i8::MIN),
+                    i16: $wrapper(i16::MIN),
+                    i32: $wrapper(i32::MIN),
+                    i64: $wrapper(i64::MIN),
+                    i128: $wrapper(i128::MIN),
+                    isize: $wrapper(isize::MIN),
+                }
+            }
+        }
+    };
+}
+
+numbers!(WrappingNumbers, Wrapping);
+numbers!(SaturatingNumbers, Saturating);
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+pub struct PackedCounters {
+    wrapping: Wrapping<u32>,
+    saturating: Saturating<i64>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+pub struct PackedPlain {
+    wrapping: u32,
+    saturating: i64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct PackedFields {
+    #[musli(packed)]
+    wrapping: Wrapping<[u32; 2]>,
+    #[musli(packed)]
+    saturating: Saturating<(u16, u32)>,
+}
+
+#[test]
+fn transparent() {
+    musli::rt!(full, Wrapping(42u32), json = r#"42"#);
+    musli::rt!(full, Saturating(-42i64), json = r#"-42"#);
+    musli::rt!(full, WrappingNumbers::max());
+    musli::rt!(full, SaturatingNumbers::max());
+
+    musli::assert_decode_eq!(full, 42u32, Wrapping(42u32), json = r#"42"#);
+    musli::assert_decode_eq!(full, Saturating(42u32), 42u32, json = r#"42"#);
+}
+
+#[test]
+fn packed() {
+    musli::rt!(
+        full,
+        PackedCounters {
+            wrapping: Wrapping(7),
+            saturating: Saturating(i64::MAX),
+        },
+        json = r#"[7,9223372036854775807]"#
+    );
+
+    // Packed layout is the same as for the inner values.
+    musli::assert_decode_eq!(
+        full,
+        PackedCounters {
+            wrapping: Wrapping(7),
+            saturating: Saturating(-1),
+        },
+        PackedPlain {
+            wrapping: 7,
+            saturating: -1,
+        },
+        json = r#"[7,-1]"#
+    );
+
+    musli::rt!(
+        full,
+        PackedFields {
+            wrapping: Wrapping([1, 2]),
+            saturating: Saturating((3, 4)),
+        },
+        json = r#"{"wrapping":[1,2],"saturating":[3,4]}"#
+    );
+}
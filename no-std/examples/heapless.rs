@@ -0,0 +1,113 @@
+#![no_std]
+#![allow(internal_features)]
+#![feature(alloc_error_handler, start, core_intrinsics, lang_items, link_cfg)]
+
+use heapless::{FnvIndexMap, String, Vec};
+use musli::allocator::{Stack, StackBuffer};
+use musli::context::StackContext;
+use musli::{Decode, Encode};
+
+#[cfg(all(windows, target_env = "msvc"))]
+#[link(name = "msvcrt")]
+extern "C" {}
+
+#[cfg(unix)]
+#[link(name = "c")]
+extern "C" {}
+
+#[alloc_error_handler]
+fn err_handler(_: core::alloc::Layout) -> ! {
+    core::intrinsics::abort();
+}
+
+#[panic_handler]
+#[lang = "panic_impl"]
+fn rust_begin_panic(_: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[cfg(unix)]
+#[no_mangle]
+pub extern "C" fn _Unwind_Resume() {}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+struct Sensor {
+    name: String<16>,
+    readings: Vec<u32, 4>,
+    labels: FnvIndexMap<String<8>, u8, 4>,
+}
+
+fn sensor() -> Option<Sensor> {
+    let mut labels = FnvIndexMap::new();
+    labels.insert(String::try_from("room").ok()?, 1).ok()?;
+    labels.insert(String::try_from("floor").ok()?, 2).ok()?;
+
+    Some(Sensor {
+        name: String::try_from("thermometer").ok()?,
+        readings: Vec::from_slice(&[20, 21, 19]).ok()?,
+        labels,
+    })
+}
+
+/// Round-trip through the storage format.
+fn storage(value: &Sensor, buf: &mut [u8]) -> Option<()> {
+    let mut stack = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut stack);
+    let cx = StackContext::new(&alloc);
+
+    let encoding = musli::storage::Encoding::new();
+
+    let capacity = buf.len();
+    let mut w = &mut buf[..];
+    encoding.encode_with(&cx, &mut w, value).ok()?;
+    let written = capacity - w.len();
+
+    let decoded: Sensor = encoding.from_slice_with(&cx, &buf[..written]).ok()?;
+    (decoded == *value).then_some(())
+}
+
+/// Round-trip through the json format, and check that decoding more elements
+/// than fit is an error.
+fn json(value: &Sensor, buf: &mut [u8]) -> Option<()> {
+    let mut stack = StackBuffer::<1024>::new();
+    let alloc = Stack::new(&mut stack);
+    let cx = StackContext::new(&alloc);
+
+    let encoding = musli::json::Encoding::new();
+
+    let capacity = buf.len();
+    let mut w = &mut buf[..];
+    encoding.encode_with(&cx, &mut w, value).ok()?;
+    let written = capacity - w.len();
+
+    let decoded: Sensor = encoding.from_slice_with(&cx, &buf[..written]).ok()?;
+
+    if decoded != *value {
+        return None;
+    }
+
+    let result = encoding.from_slice_with::<_, Vec<u32, 2>>(&cx, b"[1, 2, 3]");
+    result.is_err().then_some(())
+}
+
+#[start]
+fn main(_argc: isize, _argv: *const *const u8) -> isize {
+    let Some(value) = sensor() else {
+        return 1;
+    };
+
+    let mut buf = [0u8; 1024];
+
+    if storage(&value, &mut buf).is_none() {
+        return 2;
+    }
+
+    if json(&value, &mut buf).is_none() {
+        return 3;
+    }
+
+    0
+}